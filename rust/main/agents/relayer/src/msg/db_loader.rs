@@ -19,19 +19,21 @@ use prometheus::IntGauge;
 use tokio::sync::mpsc::UnboundedSender;
 use tracing::{debug, instrument, trace};
 
-use super::{blacklist::AddressBlacklist, metadata::AppContextClassifier, pending_message::*};
+use super::{
+    message_filters::{FilterDecision, MessageFilters},
+    metadata::AppContextClassifier,
+    pending_message::*,
+};
 use crate::{db_loader::DbLoaderExt, settings::matching_list::MatchingList};
 
 /// Finds unprocessed messages from an origin and submits then through a channel
 /// for to the appropriate destination.
 #[allow(clippy::too_many_arguments)]
 pub struct MessageDbLoader {
-    /// A matching list of messages that should be whitelisted.
-    message_whitelist: Arc<MatchingList>,
-    /// A matching list of messages that should be blacklisted.
-    message_blacklist: Arc<MatchingList>,
-    /// Addresses that messages may not interact with.
-    address_blacklist: Arc<AddressBlacklist>,
+    /// Whitelist and blacklists of messages, which can be changed at runtime.
+    /// Skipped messages aren't revisited when a rule is removed, they are picked
+    /// up again after a restart or when reprocessed through the API.
+    message_filters: Arc<MessageFilters>,
     metrics: MessageDbLoaderMetrics,
     /// channel for each destination chain to send operations (i.e. message
     /// submissions) to
@@ -228,8 +230,8 @@ impl Debug for MessageDbLoader {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "MessageDbLoader {{ message_whitelist: {:?}, message_blacklist: {:?}, address_blacklist: {:?}, nonce_iterator: {:?}}}",
-            self.message_whitelist, self.message_blacklist, self.address_blacklist, self.nonce_iterator
+            "MessageDbLoader {{ message_filters: {:?}, nonce_iterator: {:?}}}",
+            self.message_filters, self.nonce_iterator
         )
     }
 }
@@ -262,27 +264,25 @@ impl DbLoaderExt for MessageDbLoader {
             );
             let destination = msg.destination;
 
-            // Skip if not whitelisted.
-            if !self.message_whitelist.msg_matches(&msg, true) {
-                debug!(?msg, "Message not whitelisted, skipping");
-                return Ok(());
-            }
-
-            // Skip if the message is blacklisted
-            if self.message_blacklist.msg_matches(&msg, false) {
-                debug!(?msg, "Message blacklisted, skipping");
-                return Ok(());
-            }
-
-            // Skip if the message involves a blacklisted address
-            if let Some(blacklisted_address) = self.address_blacklist.find_blacklisted_address(&msg)
-            {
-                debug!(
-                    ?msg,
-                    blacklisted_address = hex::encode(blacklisted_address),
-                    "Message involves blacklisted address, skipping"
-                );
-                return Ok(());
+            // Skip if not whitelisted, blacklisted or involving a blacklisted address
+            match self.message_filters.check(&msg).await {
+                FilterDecision::Allowed => {}
+                FilterDecision::NotWhitelisted => {
+                    debug!(?msg, "Message not whitelisted, skipping");
+                    return Ok(());
+                }
+                FilterDecision::Blacklisted => {
+                    debug!(?msg, "Message blacklisted, skipping");
+                    return Ok(());
+                }
+                FilterDecision::BlacklistedAddress(blacklisted_address) => {
+                    debug!(
+                        ?msg,
+                        blacklisted_address = hex::encode(blacklisted_address),
+                        "Message involves blacklisted address, skipping"
+                    );
+                    return Ok(());
+                }
             }
 
             // Skip if the message is intended for a destination we do not service
@@ -329,9 +329,7 @@ impl MessageDbLoader {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         db: HyperlaneRocksDB,
-        message_whitelist: Arc<MatchingList>,
-        message_blacklist: Arc<MatchingList>,
-        address_blacklist: Arc<AddressBlacklist>,
+        message_filters: Arc<MessageFilters>,
         metrics: MessageDbLoaderMetrics,
         send_channels: HashMap<u32, UnboundedSender<QueueOperation>>,
         destination_ctxs: HashMap<u32, Arc<MessageContext>>,
@@ -339,9 +337,7 @@ impl MessageDbLoader {
         max_retries: u32,
     ) -> Self {
         Self {
-            message_whitelist,
-            message_blacklist,
-            address_blacklist,
+            message_filters,
             metrics,
             send_channels,
             destination_ctxs,
@@ -9,7 +9,10 @@ use tokio_metrics::TaskMonitor;
 use tracing::info_span;
 
 use hyperlane_base::{
-    cache::{LocalCache, MeteredCache, MeteredCacheConfig, MeteredCacheMetrics, OptionalCache},
+    cache::{
+        CacheBackend, LocalCache, MeteredCache, MeteredCacheConfig, MeteredCacheMetrics,
+        OptionalCache,
+    },
     db::{test_utils, HyperlaneRocksDB},
     tests::mock_hyperlane_db::MockHyperlaneDb as MockDb,
 };
@@ -67,7 +70,7 @@ fn dummy_message_loader(
     origin_domain: &HyperlaneDomain,
     destination_domain: &HyperlaneDomain,
     db: &HyperlaneRocksDB,
-    cache: OptionalCache<MeteredCache<LocalCache>>,
+    cache: OptionalCache<MeteredCache<CacheBackend>>,
 ) -> (MessageDbLoader, UnboundedReceiver<QueueOperation>) {
     let base_metadata_builder =
         dummy_metadata_builder(origin_domain, destination_domain, db, cache.clone());
@@ -82,8 +85,6 @@ fn dummy_message_loader(
         MessageDbLoader::new(
             db.clone(),
             Default::default(),
-            Default::default(),
-            Default::default(),
             dummy_message_loader_metrics(),
             HashMap::from([(destination_domain.id(), send_channel)]),
             HashMap::from([(destination_domain.id(), message_context)]),
@@ -137,7 +138,7 @@ async fn get_first_n_operations_from_db_loader(
     origin_domain: &HyperlaneDomain,
     destination_domain: &HyperlaneDomain,
     db: &HyperlaneRocksDB,
-    cache: OptionalCache<MeteredCache<LocalCache>>,
+    cache: OptionalCache<MeteredCache<CacheBackend>>,
     num_operations: usize,
 ) -> Vec<QueueOperation> {
     let (message_db_loader, mut receive_channel) =
@@ -169,7 +170,7 @@ async fn test_full_pending_message_persistence_flow() {
         let destination_domain = dummy_domain(1, "dummy_destination_domain");
         let db = HyperlaneRocksDB::new(&origin_domain, db);
         let cache = OptionalCache::new(Some(MeteredCache::new(
-            LocalCache::new("test-cache"),
+            CacheBackend::from(LocalCache::new("test-cache")),
             dummy_cache_metrics(),
             MeteredCacheConfig {
                 cache_name: "test-cache".to_owned(),
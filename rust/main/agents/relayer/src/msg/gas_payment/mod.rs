@@ -1,23 +1,27 @@
 use std::{fmt::Debug, ops::Mul};
 
 use async_trait::async_trait;
-use eyre::Result;
-use hyperlane_base::db::HyperlaneRocksDB;
+use eyre::{eyre, Result};
+use hyperlane_base::{db::HyperlaneRocksDB, CoreMetrics};
 use hyperlane_core::{
     FixedPointNumber, GasPaymentKey, HyperlaneMessage, InterchainGasExpenditure,
     InterchainGasPayment, TxCostEstimate, TxOutcome, U256,
 };
+use prometheus::GaugeVec;
 use tracing::{debug, error, trace};
 
-use self::policies::{GasPaymentPolicyMinimum, GasPaymentPolicyNone};
-use crate::{
-    msg::gas_payment::policies::GasPaymentPolicyOnChainFeeQuoting,
-    settings::{
-        matching_list::MatchingList, GasPaymentEnforcementConf, GasPaymentEnforcementPolicy,
-    },
+use self::policies::{
+    GasPaymentPolicyMinimum, GasPaymentPolicyNone, GasPaymentPolicyOnChainFeeQuoting,
+    GasPaymentPolicyProfitability,
+};
+use crate::settings::{
+    matching_list::MatchingList, GasPaymentEnforcementConf, GasPaymentEnforcementPolicy,
 };
 
 mod policies;
+mod price_source;
+
+pub use price_source::PriceSources;
 
 pub const GAS_EXPENDITURE_LOG_MESSAGE: &str = "Recording gas expenditure for message";
 
@@ -47,6 +51,27 @@ pub enum GasPolicyStatus {
     PolicyMet(U256),
 }
 
+/// Metrics shared by the gas payment enforcers of all origins.
+#[derive(Debug, Clone)]
+pub struct GasPaymentEnforcerMetrics {
+    /// The last margin computed by a profitability policy, labeled by origin
+    /// and destination.
+    profit_margin: GaugeVec,
+}
+
+impl GasPaymentEnforcerMetrics {
+    pub fn new(metrics: &CoreMetrics) -> Self {
+        let profit_margin = metrics
+            .new_gauge(
+                "gas_payment_profit_margin",
+                "Margin by which the gas payment of the last evaluated message exceeded its estimated cost",
+                &["origin", "remote"],
+            )
+            .expect("failed to register gas_payment_profit_margin metric");
+        Self { profit_margin }
+    }
+}
+
 #[derive(Debug)]
 pub struct GasPaymentEnforcer {
     /// List of policies and a whitelist to decide if it should be used for a
@@ -56,6 +81,9 @@ pub struct GasPaymentEnforcer {
     /// whitelists, then whichever is first in the list will be used.
     policies: Vec<(Box<dyn GasPaymentPolicy>, MatchingList)>,
     db: HyperlaneRocksDB,
+    metrics: Option<GasPaymentEnforcerMetrics>,
+    /// Price sources available to profitability policies.
+    price_sources: PriceSources,
 }
 
 impl GasPaymentEnforcer {
@@ -64,13 +92,32 @@ impl GasPaymentEnforcer {
     pub fn new(
         policy_configs: impl IntoIterator<Item = GasPaymentEnforcementConf>,
         db: HyperlaneRocksDB,
-    ) -> Self {
-        let policies = policy_configs
-            .into_iter()
-            .map(|cfg| (Self::create_policy(&cfg.policy), cfg.matching_list))
-            .collect();
+    ) -> Result<Self> {
+        let policy_configs: Vec<_> = policy_configs.into_iter().collect();
+        let price_sources = PriceSources::new(policy_configs.iter().map(|cfg| &cfg.policy))?;
+        Self::new_with_metrics(policy_configs, db, None, price_sources)
+    }
 
-        Self { policies, db }
+    /// Like `new`, but policies report to `metrics` where they support it and
+    /// use the price sources of `price_sources`, which must include those of
+    /// `policy_configs`.
+    pub fn new_with_metrics(
+        policy_configs: impl IntoIterator<Item = GasPaymentEnforcementConf>,
+        db: HyperlaneRocksDB,
+        metrics: Option<GasPaymentEnforcerMetrics>,
+        price_sources: PriceSources,
+    ) -> Result<Self> {
+        let mut enforcer = Self {
+            policies: Vec::new(),
+            db,
+            metrics,
+            price_sources,
+        };
+        enforcer.policies = policy_configs
+            .into_iter()
+            .map(|cfg| Ok((enforcer.create_policy(&cfg.policy)?, cfg.matching_list)))
+            .collect::<Result<_>>()?;
+        Ok(enforcer)
     }
 
     pub fn insert_new_policy(
@@ -82,8 +129,13 @@ impl GasPaymentEnforcer {
         self.policies.insert(index, (policy, matching_list));
     }
 
-    pub fn create_policy(policy: &GasPaymentEnforcementPolicy) -> Box<dyn GasPaymentPolicy> {
-        match policy {
+    /// Creates the policy for `policy`. Profitability policies can only use the
+    /// price sources of the configured policies.
+    pub fn create_policy(
+        &self,
+        policy: &GasPaymentEnforcementPolicy,
+    ) -> Result<Box<dyn GasPaymentPolicy>> {
+        Ok(match policy {
             GasPaymentEnforcementPolicy::None => Box::new(GasPaymentPolicyNone),
             GasPaymentEnforcementPolicy::Minimum { payment } => {
                 Box::new(GasPaymentPolicyMinimum::new(*payment))
@@ -92,7 +144,20 @@ impl GasPaymentEnforcer {
                 gas_fraction_numerator: n,
                 gas_fraction_denominator: d,
             } => Box::new(GasPaymentPolicyOnChainFeeQuoting::new(*n, *d)),
-        }
+            GasPaymentEnforcementPolicy::Profitability {
+                min_margin,
+                price_source,
+            } => Box::new(GasPaymentPolicyProfitability::new(
+                *min_margin,
+                price_source.clone(),
+                self.price_sources.get(price_source).ok_or_else(|| {
+                    eyre!("Price source {price_source:?} is not used by any configured policy")
+                })?,
+                self.metrics
+                    .as_ref()
+                    .map(|metrics| metrics.profit_margin.clone()),
+            )),
+        })
     }
 
     pub fn remove_policy(&mut self, index: usize) {
@@ -242,7 +307,8 @@ mod test {
                     matching_list: Default::default(),
                 }],
                 hyperlane_db,
-            );
+            )
+            .unwrap();
 
             // Ensure that message without any payment is considered as not meeting the
             // requirement because it doesn't match the GasPaymentEnforcementPolicy
@@ -274,7 +340,8 @@ mod test {
                     matching_list,
                 }],
                 hyperlane_db,
-            );
+            )
+            .unwrap();
 
             assert!(matches!(
                 enforcer
@@ -310,7 +377,8 @@ mod test {
                     matching_list: MatchingList::default(),
                 }],
                 hyperlane_db.clone(),
-            );
+            )
+            .unwrap();
 
             let wrong_destination_payment = InterchainGasPayment {
                 message_id: msg.id(),
@@ -371,7 +439,8 @@ mod test {
                     matching_list: MatchingList::default(),
                 }],
                 hyperlane_db.clone(),
-            );
+            )
+            .unwrap();
 
             let initial_payment = InterchainGasPayment {
                 message_id: msg.id(),
@@ -436,7 +505,7 @@ mod test {
                     },
                 ],
                 hyperlane_db,
-            );
+            ).unwrap();
 
             let sender: H256 = H160::from_str(sender_address).unwrap().into();
             let recipient: H256 = H160::from_str(recipient_address).unwrap().into();
@@ -497,7 +566,8 @@ mod test {
                     matching_list: MatchingList::default(),
                 }],
                 hyperlane_db,
-            );
+            )
+            .unwrap();
 
             assert!(matches!(
                 enforcer
@@ -538,7 +608,8 @@ mod test {
                     matching_list: MatchingList::default(),
                 }],
                 hyperlane_db,
-            );
+            )
+            .unwrap();
 
             assert_eq!(
                 enforcer
@@ -583,7 +654,8 @@ mod test {
                     matching_list: MatchingList::default(),
                 }],
                 hyperlane_db,
-            );
+            )
+            .unwrap();
 
             assert_eq!(
                 enforcer
@@ -618,7 +690,8 @@ mod test {
                     matching_list: MatchingList::default(),
                 }],
                 hyperlane_db,
-            );
+            )
+            .unwrap();
 
             assert_eq!(
                 enforcer
@@ -658,7 +731,8 @@ mod test {
                     matching_list: MatchingList::default(),
                 }],
                 hyperlane_db,
-            );
+            )
+            .unwrap();
 
             assert_eq!(
                 enforcer
@@ -696,7 +770,8 @@ mod test {
                     matching_list: MatchingList::default(),
                 }],
                 hyperlane_db,
-            );
+            )
+            .unwrap();
 
             assert!(matches!(
                 enforcer
@@ -738,7 +813,8 @@ mod test {
                     matching_list: MatchingList::default(),
                 }],
                 hyperlane_db,
-            );
+            )
+            .unwrap();
 
             assert_eq!(
                 enforcer
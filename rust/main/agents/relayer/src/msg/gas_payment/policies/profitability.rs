@@ -0,0 +1,282 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use eyre::{eyre, Result};
+use prometheus::GaugeVec;
+
+use hyperlane_core::{
+    utils::fmt_domain, FixedPointNumber, HyperlaneMessage, InterchainGasExpenditure,
+    InterchainGasPayment, TxCostEstimate, U256,
+};
+use tracing::debug;
+
+use crate::{
+    msg::gas_payment::{price_source::PriceSource, GasPaymentPolicy},
+    settings::{GasPaymentEnforcementPolicy, PriceSourceConf},
+};
+
+#[derive(Debug)]
+pub struct GasPaymentPolicyProfitability {
+    /// The minimum margin by which the payment has to exceed the estimated
+    /// cost, relative to the estimated cost.
+    min_margin: f64,
+    price_source_conf: PriceSourceConf,
+    price_source: Arc<dyn PriceSource>,
+    /// Gauge for the last computed margin, labeled by origin and destination.
+    margin_metric: Option<GaugeVec>,
+}
+
+impl GasPaymentPolicyProfitability {
+    pub fn new(
+        min_margin: f64,
+        price_source_conf: PriceSourceConf,
+        price_source: Arc<dyn PriceSource>,
+        margin_metric: Option<GaugeVec>,
+    ) -> Self {
+        Self {
+            min_margin,
+            price_source,
+            price_source_conf,
+            margin_metric,
+        }
+    }
+
+    #[cfg(test)]
+    fn with_price_source(min_margin: f64, price_source: Arc<dyn PriceSource>) -> Self {
+        Self {
+            min_margin,
+            price_source_conf: PriceSourceConf::Static {
+                path: Default::default(),
+            },
+            price_source,
+            margin_metric: None,
+        }
+    }
+}
+
+/// The margin by which `revenue` exceeds `cost`, relative to `cost`.
+fn margin(revenue: f64, cost: f64) -> f64 {
+    if cost > 0. {
+        (revenue - cost) / cost
+    } else if revenue >= 0. {
+        f64::INFINITY
+    } else {
+        f64::NEG_INFINITY
+    }
+}
+
+#[async_trait]
+impl GasPaymentPolicy for GasPaymentPolicyProfitability {
+    /// Profitability converts the payment made on the origin and the estimated
+    /// cost on the destination into a common unit using the native token
+    /// prices of both chains, and requires the remaining payment to exceed the
+    /// cost by at least the configured margin. Gas already spent on earlier
+    /// attempts is deducted from the payment. Like the Minimum policy,
+    /// Profitability requires a payment to exist on the IGP specified in the
+    /// config.
+    async fn message_meets_gas_payment_requirement(
+        &self,
+        message: &HyperlaneMessage,
+        current_payment: &InterchainGasPayment,
+        current_expenditure: &InterchainGasExpenditure,
+        tx_cost_estimate: &TxCostEstimate,
+    ) -> Result<Option<U256>> {
+        let prices = self.price_source.prices().await?;
+        let origin_price = prices
+            .get(&message.origin)
+            .ok_or_else(|| eyre!("No price for origin domain {}", message.origin))?;
+        let destination_price = prices
+            .get(&message.destination)
+            .ok_or_else(|| eyre!("No price for destination domain {}", message.destination))?;
+
+        let tx_cost: U256 = (FixedPointNumber::try_from(tx_cost_estimate.gas_limit)?
+            * tx_cost_estimate.gas_price.clone())
+        .try_into()?;
+        let revenue = origin_price.value_of(current_payment.payment)
+            - destination_price.value_of(current_expenditure.tokens_used);
+        let cost = destination_price.value_of(tx_cost);
+        let margin = margin(revenue, cost);
+
+        debug!(
+            hyp_message=%message,
+            revenue,
+            cost,
+            margin,
+            min_margin=self.min_margin,
+            "Computed relaying margin"
+        );
+        if let Some(metric) = &self.margin_metric {
+            metric
+                .with_label_values(&[
+                    &fmt_domain(message.origin),
+                    &fmt_domain(message.destination),
+                ])
+                .set(margin);
+        }
+
+        if margin >= self.min_margin {
+            Ok(Some(tx_cost_estimate.gas_limit))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn requires_payment_found(&self) -> bool {
+        true
+    }
+
+    fn enforcement_type(&self) -> GasPaymentEnforcementPolicy {
+        GasPaymentEnforcementPolicy::Profitability {
+            min_margin: self.min_margin,
+            price_source: self.price_source_conf.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use hyperlane_core::H256;
+
+    use super::*;
+    use crate::msg::gas_payment::price_source::{TokenPrice, TokenPrices};
+
+    const ORIGIN: u32 = 1;
+    const DESTINATION: u32 = 2;
+
+    #[derive(Debug)]
+    struct FixedPrices(TokenPrices);
+
+    #[async_trait]
+    impl PriceSource for FixedPrices {
+        async fn prices(&self) -> Result<TokenPrices> {
+            Ok(self.0.clone())
+        }
+    }
+
+    /// The origin token is worth 10x the destination token. Prices have no
+    /// decimals to keep the margins exact.
+    fn policy(min_margin: f64) -> GasPaymentPolicyProfitability {
+        let price = |price| TokenPrice { price, decimals: 0 };
+        GasPaymentPolicyProfitability::with_price_source(
+            min_margin,
+            Arc::new(FixedPrices(HashMap::from([
+                (ORIGIN, price(10.)),
+                (DESTINATION, price(1.)),
+            ]))),
+        )
+    }
+
+    fn message() -> HyperlaneMessage {
+        HyperlaneMessage {
+            origin: ORIGIN,
+            destination: DESTINATION,
+            ..Default::default()
+        }
+    }
+
+    fn current_payment(payment: u64) -> InterchainGasPayment {
+        InterchainGasPayment {
+            message_id: H256::zero(),
+            destination: DESTINATION,
+            payment: payment.into(),
+            gas_amount: U256::zero(),
+        }
+    }
+
+    fn current_expenditure(tokens_used: u64) -> InterchainGasExpenditure {
+        InterchainGasExpenditure {
+            message_id: H256::zero(),
+            gas_used: U256::zero(),
+            tokens_used: tokens_used.into(),
+        }
+    }
+
+    /// Costs 100_000 destination wei, i.e. 10_000 origin wei.
+    fn cost_estimate() -> TxCostEstimate {
+        TxCostEstimate {
+            gas_limit: 1000u32.into(),
+            gas_price: 100u32.into(),
+            l2_gas_limit: None,
+        }
+    }
+
+    async fn meets_requirement(
+        policy: &GasPaymentPolicyProfitability,
+        payment: u64,
+        tokens_used: u64,
+    ) -> Option<U256> {
+        policy
+            .message_meets_gas_payment_requirement(
+                &message(),
+                &current_payment(payment),
+                &current_expenditure(tokens_used),
+                &cost_estimate(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_break_even() {
+        let policy = policy(0.);
+
+        assert_eq!(meets_requirement(&policy, 9_999, 0).await, None);
+        assert_eq!(
+            meets_requirement(&policy, 10_000, 0).await,
+            Some(cost_estimate().gas_limit)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_min_margin() {
+        let policy = policy(0.5);
+
+        assert_eq!(meets_requirement(&policy, 10_000, 0).await, None);
+        assert_eq!(meets_requirement(&policy, 14_999, 0).await, None);
+        assert_eq!(
+            meets_requirement(&policy, 15_000, 0).await,
+            Some(cost_estimate().gas_limit)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expenditure_is_deducted() {
+        let policy = policy(0.);
+
+        // 50_000 destination wei already spent is worth 5_000 origin wei
+        assert_eq!(meets_requirement(&policy, 10_000, 50_000).await, None);
+        assert_eq!(
+            meets_requirement(&policy, 15_000, 50_000).await,
+            Some(cost_estimate().gas_limit)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_price() {
+        let policy = policy(0.);
+        let message = HyperlaneMessage {
+            destination: 3,
+            ..message()
+        };
+
+        assert!(policy
+            .message_meets_gas_payment_requirement(
+                &message,
+                &current_payment(10_000),
+                &current_expenditure(0),
+                &cost_estimate(),
+            )
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn test_margin() {
+        assert_eq!(margin(150., 100.), 0.5);
+        assert_eq!(margin(50., 100.), -0.5);
+        assert_eq!(margin(0., 0.), f64::INFINITY);
+        assert_eq!(margin(-1., 0.), f64::NEG_INFINITY);
+    }
+}
@@ -0,0 +1,359 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use eyre::{Context, Result};
+use serde::Deserialize;
+use tokio::sync::{OnceCell, RwLock};
+use tracing::warn;
+
+use hyperlane_core::U256;
+
+use crate::settings::{GasPaymentEnforcementPolicy, PriceSourceConf};
+
+/// Timeout for fetching prices from an HTTP price source
+const PRICE_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The price of a chain's native token.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct TokenPrice {
+    /// The value of one whole token, in the unit shared by all prices of a
+    /// price source (e.g. USD).
+    pub price: f64,
+    /// The number of decimals of the token.
+    pub decimals: u32,
+}
+
+impl TokenPrice {
+    /// The value of `amount` of the token's smallest unit.
+    pub fn value_of(&self, amount: U256) -> f64 {
+        amount.to_f64_lossy() * self.price / 10f64.powi(self.decimals as i32)
+    }
+}
+
+/// Native token prices keyed by domain id.
+pub type TokenPrices = HashMap<u32, TokenPrice>;
+
+#[async_trait]
+pub trait PriceSource: Debug + Send + Sync {
+    /// Returns the current native token prices.
+    async fn prices(&self) -> Result<TokenPrices>;
+}
+
+pub fn create_price_source(conf: &PriceSourceConf) -> Result<Arc<dyn PriceSource>> {
+    Ok(match conf {
+        PriceSourceConf::Static { path } => Arc::new(StaticPriceSource::new(path.clone())),
+        PriceSourceConf::Http {
+            url,
+            cache_seconds,
+            max_stale_seconds,
+        } => Arc::new(HttpPriceSource::new(
+            url.clone(),
+            Duration::from_secs(*cache_seconds),
+            Duration::from_secs(*max_stale_seconds),
+        )?),
+    })
+}
+
+/// The price sources of the configured gas payment enforcement policies,
+/// shared by the enforcers of all origins so that each source is only
+/// fetched once.
+#[derive(Debug, Clone, Default)]
+pub struct PriceSources(Arc<HashMap<PriceSourceConf, Arc<dyn PriceSource>>>);
+
+impl PriceSources {
+    /// Creates one price source per distinct source used by `policies`.
+    pub fn new<'a>(
+        policies: impl IntoIterator<Item = &'a GasPaymentEnforcementPolicy>,
+    ) -> Result<Self> {
+        let mut sources = HashMap::new();
+        for policy in policies {
+            if let GasPaymentEnforcementPolicy::Profitability { price_source, .. } = policy {
+                if !sources.contains_key(price_source) {
+                    sources.insert(price_source.clone(), create_price_source(price_source)?);
+                }
+            }
+        }
+        Ok(Self(Arc::new(sources)))
+    }
+
+    /// Returns the price source for `conf`, if it is used by a configured policy.
+    pub fn get(&self, conf: &PriceSourceConf) -> Option<Arc<dyn PriceSource>> {
+        self.0.get(conf).cloned()
+    }
+}
+
+/// Reads prices from a JSON file the first time they are needed.
+#[derive(Debug)]
+pub struct StaticPriceSource {
+    path: PathBuf,
+    prices: OnceCell<TokenPrices>,
+}
+
+impl StaticPriceSource {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            prices: OnceCell::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl PriceSource for StaticPriceSource {
+    async fn prices(&self) -> Result<TokenPrices> {
+        self.prices
+            .get_or_try_init(|| async {
+                let contents = tokio::fs::read_to_string(&self.path)
+                    .await
+                    .with_context(|| {
+                        format!("Failed to read price file {}", self.path.display())
+                    })?;
+                serde_json::from_str(&contents)
+                    .with_context(|| format!("Failed to parse price file {}", self.path.display()))
+            })
+            .await
+            .cloned()
+    }
+}
+
+/// Fetches prices from an HTTP endpoint, reusing them for `cache_duration`.
+/// If fetching fails, the last fetched prices keep being used until they are
+/// older than `max_staleness`.
+#[derive(Debug)]
+pub struct HttpPriceSource {
+    url: String,
+    cache_duration: Duration,
+    max_staleness: Duration,
+    client: reqwest::Client,
+    cached: RwLock<Option<(Instant, TokenPrices)>>,
+}
+
+impl HttpPriceSource {
+    pub fn new(url: String, cache_duration: Duration, max_staleness: Duration) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(PRICE_REQUEST_TIMEOUT)
+            .build()?;
+        Ok(Self {
+            url,
+            cache_duration,
+            max_staleness,
+            client,
+            cached: RwLock::new(None),
+        })
+    }
+
+    async fn fetch(&self) -> Result<TokenPrices> {
+        self.client
+            .get(&self.url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .with_context(|| format!("Failed to fetch prices from {}", self.url))?
+            .json()
+            .await
+            .with_context(|| format!("Failed to parse prices from {}", self.url))
+    }
+}
+
+#[async_trait]
+impl PriceSource for HttpPriceSource {
+    async fn prices(&self) -> Result<TokenPrices> {
+        if let Some((fetched_at, prices)) = self.cached.read().await.as_ref() {
+            if fetched_at.elapsed() < self.cache_duration {
+                return Ok(prices.clone());
+            }
+        }
+
+        match self.fetch().await {
+            Ok(prices) => {
+                *self.cached.write().await = Some((Instant::now(), prices.clone()));
+                Ok(prices)
+            }
+            Err(err) => match self.cached.read().await.as_ref() {
+                Some((fetched_at, prices)) if fetched_at.elapsed() < self.max_staleness => {
+                    warn!(
+                        url = self.url,
+                        ?err,
+                        "Failed to refresh prices, using stale prices"
+                    );
+                    Ok(prices.clone())
+                }
+                _ => Err(err),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use axum::{http::StatusCode, routing::get, Router};
+
+    use super::*;
+
+    const PRICES: &str =
+        r#"{"1": {"price": 2500.0, "decimals": 18}, "1399811149": {"price": 150, "decimals": 9}}"#;
+
+    fn expected_prices() -> TokenPrices {
+        HashMap::from([
+            (
+                1,
+                TokenPrice {
+                    price: 2500.,
+                    decimals: 18,
+                },
+            ),
+            (
+                1399811149,
+                TokenPrice {
+                    price: 150.,
+                    decimals: 9,
+                },
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_token_price_value_of() {
+        let price = TokenPrice {
+            price: 2500.,
+            decimals: 18,
+        };
+        assert_eq!(price.value_of(U256::exp10(18)), 2500.);
+        assert_eq!(price.value_of(U256::exp10(15)), 2.5);
+        assert_eq!(price.value_of(U256::zero()), 0.);
+    }
+
+    #[tokio::test]
+    async fn test_static_price_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("prices.json");
+        std::fs::write(&path, PRICES).unwrap();
+
+        let source = StaticPriceSource::new(path.clone());
+        assert_eq!(source.prices().await.unwrap(), expected_prices());
+
+        // Prices are only read once
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(source.prices().await.unwrap(), expected_prices());
+    }
+
+    #[tokio::test]
+    async fn test_static_price_source_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = StaticPriceSource::new(dir.path().join("prices.json"));
+        assert!(source.prices().await.is_err());
+    }
+
+    /// Serves `PRICES` under `/prices` on a random local port, counting requests
+    async fn serve_prices(requests: Arc<AtomicUsize>) -> String {
+        serve_prices_until(requests, usize::MAX).await
+    }
+
+    /// Like `serve_prices`, but responds with an error after `successes` requests
+    async fn serve_prices_until(requests: Arc<AtomicUsize>, successes: usize) -> String {
+        let app = Router::new().route(
+            "/prices",
+            get(move || {
+                let served = requests.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if served < successes {
+                        Ok(PRICES)
+                    } else {
+                        Err(StatusCode::SERVICE_UNAVAILABLE)
+                    }
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        format!("http://{addr}/prices")
+    }
+
+    #[tokio::test]
+    async fn test_http_price_source() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let url = serve_prices(requests.clone()).await;
+
+        let source =
+            HttpPriceSource::new(url.clone(), Duration::from_secs(60), Duration::ZERO).unwrap();
+        assert_eq!(source.prices().await.unwrap(), expected_prices());
+        assert_eq!(source.prices().await.unwrap(), expected_prices());
+        // The second call is served from the cache
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        let source = HttpPriceSource::new(url, Duration::ZERO, Duration::ZERO).unwrap();
+        source.prices().await.unwrap();
+        source.prices().await.unwrap();
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_http_price_source_error_status() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let url = serve_prices(requests).await;
+
+        let source = HttpPriceSource::new(
+            format!("{url}/missing"),
+            Duration::from_secs(60),
+            Duration::from_secs(60),
+        )
+        .unwrap();
+        assert!(source.prices().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_http_price_source_serves_stale_prices() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let url = serve_prices_until(requests.clone(), 1).await;
+
+        let source =
+            HttpPriceSource::new(url.clone(), Duration::ZERO, Duration::from_secs(60)).unwrap();
+        assert_eq!(source.prices().await.unwrap(), expected_prices());
+        // The refresh fails, so the last fetched prices are used
+        assert_eq!(source.prices().await.unwrap(), expected_prices());
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        let source = HttpPriceSource::new(url, Duration::ZERO, Duration::ZERO).unwrap();
+        assert!(source.prices().await.is_err());
+    }
+
+    #[test]
+    fn test_price_sources_are_shared() {
+        let conf = PriceSourceConf::Static {
+            path: "/prices.json".into(),
+        };
+        let profitability = |min_margin| GasPaymentEnforcementPolicy::Profitability {
+            min_margin,
+            price_source: conf.clone(),
+        };
+        let policies = [
+            profitability(0.),
+            GasPaymentEnforcementPolicy::None,
+            profitability(0.5),
+        ];
+
+        let sources = PriceSources::new(&policies).unwrap();
+        assert_eq!(sources.0.len(), 1);
+        assert!(Arc::ptr_eq(
+            &sources.get(&conf).unwrap(),
+            &sources.clone().get(&conf).unwrap()
+        ));
+        assert!(sources
+            .get(&PriceSourceConf::Static {
+                path: "/other.json".into(),
+            })
+            .is_none());
+    }
+}
@@ -0,0 +1,448 @@
+use ethers::utils::hex;
+use eyre::{eyre, Context, Result};
+use hyperlane_base::db::DB;
+use hyperlane_core::HyperlaneMessage;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tracing::info;
+
+use super::blacklist::AddressBlacklist;
+use crate::settings::matching_list::{ListElement, MatchingList};
+
+/// Key of the rules persisted in the relayer DB. They aren't specific to an
+/// origin, so they are stored outside of the per domain key space.
+const PERSISTED_RULES_KEY: &[u8] = b"relayer_message_filter_rules";
+
+/// The lists deciding which messages are relayed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterListKind {
+    /// Only matching messages are relayed
+    Whitelist,
+    /// Matching messages are not relayed
+    Blacklist,
+    /// Messages whose sender, recipient or body contain one of the addresses are not relayed
+    AddressBlacklist,
+}
+
+/// A rule to add to one of the lists
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterRule {
+    Whitelist(ListElement),
+    Blacklist(ListElement),
+    AddressBlacklist(Vec<u8>),
+}
+
+impl FilterRule {
+    /// Parse a rule for the given list. Whitelist and blacklist rules are matching
+    /// list elements, address blacklist rules are hex encoded addresses.
+    pub fn parse(kind: FilterListKind, rule: serde_json::Value) -> Result<Self> {
+        Ok(match kind {
+            FilterListKind::Whitelist => Self::Whitelist(serde_json::from_value(rule)?),
+            FilterListKind::Blacklist => Self::Blacklist(serde_json::from_value(rule)?),
+            FilterListKind::AddressBlacklist => {
+                let address: String = serde_json::from_value(rule)?;
+                Self::AddressBlacklist(decode_address(&address)?)
+            }
+        })
+    }
+}
+
+/// A rule added at runtime
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RuntimeRule<T> {
+    pub rule: T,
+    /// Whether the rule is stored in the DB and restored on restart
+    pub persisted: bool,
+}
+
+/// Rules added at runtime. Addresses are hex encoded to keep the persisted JSON readable.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+struct RuntimeRules {
+    whitelist: Vec<RuntimeRule<ListElement>>,
+    blacklist: Vec<RuntimeRule<ListElement>>,
+    address_blacklist: Vec<RuntimeRule<String>>,
+}
+
+impl RuntimeRules {
+    fn persisted(&self) -> Self {
+        fn persisted<T: Clone>(rules: &[RuntimeRule<T>]) -> Vec<RuntimeRule<T>> {
+            rules.iter().filter(|r| r.persisted).cloned().collect()
+        }
+        Self {
+            whitelist: persisted(&self.whitelist),
+            blacklist: persisted(&self.blacklist),
+            address_blacklist: persisted(&self.address_blacklist),
+        }
+    }
+}
+
+/// One of the lists, split into the rules from the config and the ones added at runtime
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FilterList<T> {
+    /// Can only be changed by changing the config and restarting
+    pub config: Vec<T>,
+    /// Can be removed by their index in this list
+    pub runtime: Vec<RuntimeRule<T>>,
+}
+
+/// All lists deciding which messages are relayed
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FilterLists {
+    pub whitelist: FilterList<ListElement>,
+    pub blacklist: FilterList<ListElement>,
+    pub address_blacklist: FilterList<String>,
+}
+
+/// Whether a message may be relayed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterDecision {
+    Allowed,
+    NotWhitelisted,
+    Blacklisted,
+    /// The message involves the contained blacklisted address
+    BlacklistedAddress(Vec<u8>),
+}
+
+#[derive(Debug, Default)]
+struct FilterState {
+    runtime: RuntimeRules,
+    /// The config lists combined with the runtime rules
+    whitelist: MatchingList,
+    blacklist: MatchingList,
+    address_blacklist: AddressBlacklist,
+}
+
+/// The message whitelist, blacklist and address blacklist. The lists from the
+/// config can be extended at runtime, e.g. to block a sender during an incident.
+///
+/// Note that adding a runtime whitelist rule while the config has no whitelist
+/// restricts relaying to the messages matching the runtime whitelist.
+#[derive(Debug, Default)]
+pub struct MessageFilters {
+    config_whitelist: MatchingList,
+    config_blacklist: MatchingList,
+    config_address_blacklist: Vec<Vec<u8>>,
+    /// Where rules added with `persist` are stored. Without a DB, nothing is persisted.
+    db: Option<DB>,
+    state: RwLock<FilterState>,
+}
+
+impl MessageFilters {
+    /// Create the filters from the config lists, restoring the rules persisted in the DB
+    pub fn new(
+        whitelist: MatchingList,
+        blacklist: MatchingList,
+        address_blacklist: Vec<Vec<u8>>,
+        db: Option<DB>,
+    ) -> Result<Self> {
+        let runtime = match &db {
+            Some(db) => Self::load(db)?,
+            None => RuntimeRules::default(),
+        };
+        let mut filters = Self {
+            config_whitelist: whitelist,
+            config_blacklist: blacklist,
+            config_address_blacklist: address_blacklist,
+            db,
+            state: Default::default(),
+        };
+        filters.state = RwLock::new(filters.build_state(runtime)?);
+        Ok(filters)
+    }
+
+    fn load(db: &DB) -> Result<RuntimeRules> {
+        let Some(bytes) = db.retrieve(PERSISTED_RULES_KEY)? else {
+            return Ok(RuntimeRules::default());
+        };
+        let rules: RuntimeRules =
+            serde_json::from_slice(&bytes).context("Invalid persisted message filter rules")?;
+        info!(?rules, "Restored persisted message filter rules");
+        Ok(rules)
+    }
+
+    fn store(&self, runtime: &RuntimeRules) -> Result<()> {
+        let Some(db) = &self.db else {
+            return Err(eyre!("No DB to persist message filter rules to"));
+        };
+        let bytes = serde_json::to_vec(&runtime.persisted())?;
+        db.store(PERSISTED_RULES_KEY, &bytes)
+            .context("Failed to persist message filter rules")
+    }
+
+    fn build_state(&self, runtime: RuntimeRules) -> Result<FilterState> {
+        let combine = |config: &MatchingList, rules: &[RuntimeRule<ListElement>]| {
+            let elements = config
+                .0
+                .iter()
+                .flatten()
+                .chain(rules.iter().map(|r| &r.rule))
+                .cloned()
+                .collect::<Vec<_>>();
+            // an empty list must stay `None`, which is how "no list" is represented
+            MatchingList((!elements.is_empty()).then_some(elements))
+        };
+        let mut addresses = self.config_address_blacklist.clone();
+        for rule in &runtime.address_blacklist {
+            addresses.push(decode_address(&rule.rule)?);
+        }
+        Ok(FilterState {
+            whitelist: combine(&self.config_whitelist, &runtime.whitelist),
+            blacklist: combine(&self.config_blacklist, &runtime.blacklist),
+            address_blacklist: AddressBlacklist::new(addresses),
+            runtime,
+        })
+    }
+
+    /// Decide whether a message may be relayed
+    pub async fn check(&self, message: &HyperlaneMessage) -> FilterDecision {
+        let state = self.state.read().await;
+        if !state.whitelist.msg_matches(message, true) {
+            FilterDecision::NotWhitelisted
+        } else if state.blacklist.msg_matches(message, false) {
+            FilterDecision::Blacklisted
+        } else if let Some(address) = state.address_blacklist.find_blacklisted_address(message) {
+            FilterDecision::BlacklistedAddress(address)
+        } else {
+            FilterDecision::Allowed
+        }
+    }
+
+    /// All rules, from the config and added at runtime
+    pub async fn lists(&self) -> FilterLists {
+        let state = self.state.read().await;
+        let config = |list: &MatchingList| list.0.clone().unwrap_or_default();
+        FilterLists {
+            whitelist: FilterList {
+                config: config(&self.config_whitelist),
+                runtime: state.runtime.whitelist.clone(),
+            },
+            blacklist: FilterList {
+                config: config(&self.config_blacklist),
+                runtime: state.runtime.blacklist.clone(),
+            },
+            address_blacklist: FilterList {
+                config: self
+                    .config_address_blacklist
+                    .iter()
+                    .map(|address| format!("0x{}", hex::encode(address)))
+                    .collect(),
+                runtime: state.runtime.address_blacklist.clone(),
+            },
+        }
+    }
+
+    /// Add a rule, taking effect for all messages checked afterwards
+    pub async fn add_rule(&self, rule: FilterRule, persist: bool) -> Result<()> {
+        let mut state = self.state.write().await;
+        let mut runtime = state.runtime.clone();
+        match rule {
+            FilterRule::Whitelist(rule) => runtime.whitelist.push(RuntimeRule {
+                rule,
+                persisted: persist,
+            }),
+            FilterRule::Blacklist(rule) => runtime.blacklist.push(RuntimeRule {
+                rule,
+                persisted: persist,
+            }),
+            FilterRule::AddressBlacklist(address) => {
+                if address.is_empty() {
+                    return Err(eyre!("Blacklisted address must not be empty"));
+                }
+                runtime.address_blacklist.push(RuntimeRule {
+                    rule: format!("0x{}", hex::encode(address)),
+                    persisted: persist,
+                })
+            }
+        }
+        self.apply(&mut state, runtime, persist)
+    }
+
+    /// Remove the runtime rule at `index` of a list. Returns false if there is no such rule.
+    pub async fn remove_rule(&self, kind: FilterListKind, index: usize) -> Result<bool> {
+        let mut state = self.state.write().await;
+        let mut runtime = state.runtime.clone();
+        let persisted = match kind {
+            FilterListKind::Whitelist => remove(&mut runtime.whitelist, index),
+            FilterListKind::Blacklist => remove(&mut runtime.blacklist, index),
+            FilterListKind::AddressBlacklist => remove(&mut runtime.address_blacklist, index),
+        };
+        let Some(persisted) = persisted else {
+            return Ok(false);
+        };
+        self.apply(&mut state, runtime, persisted)?;
+        Ok(true)
+    }
+
+    /// Swap in the new rules, only once they are persisted if they need to be
+    fn apply(&self, state: &mut FilterState, runtime: RuntimeRules, persist: bool) -> Result<()> {
+        let new_state = self.build_state(runtime)?;
+        if persist {
+            self.store(&new_state.runtime)?;
+        }
+        info!(rules = ?new_state.runtime, "Updated message filter rules");
+        *state = new_state;
+        Ok(())
+    }
+}
+
+/// Removes the rule at `index`, returning whether it was persisted
+fn remove<T>(rules: &mut Vec<RuntimeRule<T>>, index: usize) -> Option<bool> {
+    (index < rules.len()).then(|| rules.remove(index).persisted)
+}
+
+fn decode_address(address: &str) -> Result<Vec<u8>> {
+    let address = address.trim();
+    hex::decode(address.strip_prefix("0x").unwrap_or(address))
+        .with_context(|| format!("Invalid hex address {address}"))
+}
+
+#[cfg(test)]
+mod test {
+    use hyperlane_core::H256;
+
+    use super::*;
+    use crate::settings::matching_list::Filter;
+
+    fn sender_rule(sender: H256) -> ListElement {
+        ListElement::new(
+            Filter::Wildcard,
+            Filter::Wildcard,
+            Filter::Enumerated(vec![sender]),
+            Filter::Wildcard,
+            Filter::Wildcard,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_runtime_rules_take_effect() {
+        let sender = H256::random();
+        let message = HyperlaneMessage {
+            sender,
+            ..Default::default()
+        };
+        let filters = MessageFilters::default();
+        assert_eq!(filters.check(&message).await, FilterDecision::Allowed);
+
+        filters
+            .add_rule(FilterRule::Blacklist(sender_rule(sender)), false)
+            .await
+            .unwrap();
+        assert_eq!(filters.check(&message).await, FilterDecision::Blacklisted);
+
+        assert!(filters
+            .remove_rule(FilterListKind::Blacklist, 0)
+            .await
+            .unwrap());
+        assert!(!filters
+            .remove_rule(FilterListKind::Blacklist, 0)
+            .await
+            .unwrap());
+        assert_eq!(filters.check(&message).await, FilterDecision::Allowed);
+
+        // a whitelist rule restricts relaying to the matching messages
+        filters
+            .add_rule(FilterRule::Whitelist(sender_rule(H256::random())), false)
+            .await
+            .unwrap();
+        assert_eq!(
+            filters.check(&message).await,
+            FilterDecision::NotWhitelisted
+        );
+    }
+
+    #[tokio::test]
+    async fn test_runtime_rules_extend_config() {
+        let blocked = b"blocked".to_vec();
+        let filters = MessageFilters::new(
+            MatchingList::default(),
+            MatchingList::default(),
+            vec![blocked.clone()],
+            None,
+        )
+        .unwrap();
+        filters
+            .add_rule(FilterRule::AddressBlacklist(b"other".to_vec()), false)
+            .await
+            .unwrap();
+
+        let lists = filters.lists().await;
+        assert_eq!(lists.address_blacklist.config, vec!["0x626c6f636b6564"]);
+        assert_eq!(
+            lists.address_blacklist.runtime,
+            vec![RuntimeRule {
+                rule: "0x6f74686572".to_owned(),
+                persisted: false
+            }]
+        );
+
+        let message = HyperlaneMessage {
+            body: blocked.clone(),
+            ..Default::default()
+        };
+        assert_eq!(
+            filters.check(&message).await,
+            FilterDecision::BlacklistedAddress(blocked)
+        );
+
+        // config rules can't be removed at runtime
+        assert!(!filters
+            .remove_rule(FilterListKind::AddressBlacklist, 1)
+            .await
+            .unwrap());
+        assert!(filters
+            .add_rule(FilterRule::AddressBlacklist(vec![]), false)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_persisted_rules_survive_restart() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let persisted = sender_rule(H256::random());
+        let transient = sender_rule(H256::random());
+        {
+            let db = DB::from_path(temp_dir.path()).unwrap();
+            let filters = MessageFilters::new(
+                MatchingList::default(),
+                MatchingList::default(),
+                vec![],
+                Some(db),
+            )
+            .unwrap();
+            filters
+                .add_rule(FilterRule::Blacklist(persisted.clone()), true)
+                .await
+                .unwrap();
+            filters
+                .add_rule(FilterRule::Blacklist(transient), false)
+                .await
+                .unwrap();
+        }
+
+        let db = DB::from_path(temp_dir.path()).unwrap();
+        let filters = MessageFilters::new(
+            MatchingList::default(),
+            MatchingList::default(),
+            vec![],
+            Some(db),
+        )
+        .unwrap();
+        assert_eq!(
+            filters.lists().await.blacklist.runtime,
+            vec![RuntimeRule {
+                rule: persisted,
+                persisted: true
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_persisting_without_db_fails() {
+        let filters = MessageFilters::default();
+        let rule = FilterRule::Blacklist(sender_rule(H256::random()));
+        assert!(filters.add_rule(rule, true).await.is_err());
+        assert!(filters.lists().await.blacklist.runtime.is_empty());
+    }
+}
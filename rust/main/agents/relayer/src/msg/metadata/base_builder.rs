@@ -11,7 +11,7 @@ use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
 
 use hyperlane_base::{
-    cache::{LocalCache, MeteredCache, OptionalCache},
+    cache::{CacheBackend, MeteredCache, OptionalCache},
     db::{HyperlaneDb, HyperlaneRocksDB},
     settings::{ChainConf, CheckpointSyncerBuildError, CheckpointSyncerConf},
     CheckpointSyncer, CoreMetrics, MultisigCheckpointSyncer,
@@ -48,7 +48,7 @@ pub struct BaseMetadataBuilder {
     origin_validator_announce: Arc<dyn ValidatorAnnounce>,
     allow_local_checkpoint_syncers: bool,
     metrics: Arc<CoreMetrics>,
-    cache: OptionalCache<MeteredCache<LocalCache>>,
+    cache: OptionalCache<MeteredCache<CacheBackend>>,
     db: HyperlaneRocksDB,
     app_context_classifier: IsmAwareAppContextClassifier,
     ism_cache_policy_classifier: IsmCachePolicyClassifier,
@@ -72,7 +72,7 @@ pub trait BuildsBaseMetadata: Send + Sync + Debug {
     fn destination_domain(&self) -> &HyperlaneDomain;
     fn app_context_classifier(&self) -> &IsmAwareAppContextClassifier;
     fn ism_cache_policy_classifier(&self) -> &IsmCachePolicyClassifier;
-    fn cache(&self) -> &OptionalCache<MeteredCache<LocalCache>>;
+    fn cache(&self) -> &OptionalCache<MeteredCache<CacheBackend>>;
     fn get_signer(&self) -> Option<&Signers>;
 
     fn update_ism_metric(&self, params: IsmBuildMetricsParams);
@@ -119,7 +119,7 @@ impl BuildsBaseMetadata for BaseMetadataBuilder {
         &self.ism_cache_policy_classifier
     }
 
-    fn cache(&self) -> &OptionalCache<MeteredCache<LocalCache>> {
+    fn cache(&self) -> &OptionalCache<MeteredCache<CacheBackend>> {
         &self.cache
     }
 
@@ -270,6 +270,17 @@ impl BuildsBaseMetadata for BaseMetadataBuilder {
                             continue;
                         }
 
+                        // Unencrypted HTTP checkpoint syncers are only meant for local testing
+                        if !self.allow_local_checkpoint_syncers
+                            && matches!(
+                                &config,
+                                CheckpointSyncerConf::Http { url } if url.scheme() == "http"
+                            )
+                        {
+                            debug!(?config, "Ignoring disallowed plain HTTP checkpoint syncer");
+                            continue;
+                        }
+
                         if let Some(syncer) = self.build_and_validate(&config, validator).await? {
                             // found the syncer for this validator
                             return Ok(Some((*validator, syncer)));
@@ -3,12 +3,11 @@
 use std::time::Duration;
 
 use async_trait::async_trait;
-use cache_types::SerializedOffchainLookup;
 use derive_more::Deref;
 use derive_new::new;
-use ethers::{abi::AbiDecode, core::utils::hex::decode as hex_decode};
+use ethers::core::utils::hex::decode as hex_decode;
 use hyperlane_base::cache::FunctionCallCache;
-use regex::{Regex, RegexSet, RegexSetBuilder};
+use regex::{RegexSet, RegexSetBuilder};
 use reqwest::{header::CONTENT_TYPE, Client, Method};
 use serde::{Deserialize, Serialize};
 use sha3::{digest::Update, Digest, Keccak256};
@@ -16,9 +15,9 @@ use tracing::{info, instrument, warn};
 
 use hyperlane_core::{
     h512_to_bytes, utils::bytes_to_hex, CcipReadIsm, HyperlaneMessage, HyperlaneSignerExt,
-    Metadata, ModuleType, RawHyperlaneMessage, Signable, H160, H256,
+    Metadata, ModuleType, OffchainLookup, RawHyperlaneMessage, Signable, H256,
 };
-use hyperlane_ethereum::{OffchainLookup, Signers};
+use hyperlane_ethereum::Signers;
 
 use crate::msg::metadata::base_builder::IsmBuildMetricsParams;
 
@@ -28,8 +27,6 @@ use super::{
     MetadataBuilder,
 };
 
-mod cache_types;
-
 pub const DEFAULT_TIMEOUT: u64 = 30;
 
 #[derive(Clone, Debug, Serialize)]
@@ -54,7 +51,7 @@ pub struct CcipReadIsmMetadataBuilder {
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug)]
 pub struct HyperlaneAuthenticatedOffchainLookup {
     url_template: Vec<u8>,
-    sender: H160,
+    sender: Vec<u8>,
     call_data: Vec<u8>,
 }
 
@@ -63,7 +60,7 @@ impl Signable for HyperlaneAuthenticatedOffchainLookup {
         H256::from_slice(
             Keccak256::new()
                 .chain(b"HYPERLANE_OFFCHAINLOOKUP")
-                .chain(self.sender)
+                .chain(self.sender.as_slice())
                 .chain(self.call_data.as_slice())
                 .chain(self.url_template.as_slice())
                 .finalize()
@@ -82,8 +79,8 @@ impl CcipReadIsmMetadataBuilder {
         // Derive the hash over call_data and sender
         let signable = HyperlaneAuthenticatedOffchainLookup {
             url_template: url.to_owned().into(),
-            call_data: info.call_data.clone().to_vec(),
-            sender: info.sender.into(),
+            call_data: info.call_data.clone(),
+            sender: info.sender.clone(),
         };
         // EIP-191 compliant signature over the signing hash of the HyperlaneOffchainLookupAttestation.
         let signed = signer
@@ -117,7 +114,7 @@ impl CcipReadIsmMetadataBuilder {
             .base
             .base_builder()
             .cache()
-            .get_cached_call_result::<SerializedOffchainLookup>(ism_domain, fn_key, &call_params)
+            .get_cached_call_result::<OffchainLookup>(ism_domain, fn_key, &call_params)
             .await
             .map_err(|err| {
                 warn!(error = %err, "Error when caching call result for {:?}", fn_key);
@@ -125,50 +122,21 @@ impl CcipReadIsmMetadataBuilder {
             .ok()
             .flatten();
 
-        let info: OffchainLookup = match info_from_cache {
-            Some(info) => info.into(),
-            None => {
-                let response = ism
-                    .get_offchain_verify_info(RawHyperlaneMessage::from(message).to_vec())
-                    .await;
-
-                match response {
-                    Ok(_) => {
-                        info!("incorrectly configured getOffchainVerifyInfo, expected revert");
-                        return Err(MetadataBuildError::CouldNotFetch);
-                    }
-                    Err(raw_error) => {
-                        let matching_regex = Regex::new(r"0x[[:xdigit:]]+").map_err(|err| {
-                            let msg = format!("Failed to parse regex: {err}");
-                            MetadataBuildError::FailedToBuild(msg)
-                        })?;
-                        if let Some(matching) = &matching_regex.captures(&raw_error.to_string()) {
-                            let hex_val = hex_decode(&matching[0][2..]).map_err(|err| {
-                                let msg = format!("Failed to decode hex from ISM response: {err}");
-                                MetadataBuildError::FailedToBuild(msg)
-                            })?;
-                            OffchainLookup::decode(hex_val).map_err(|err| {
-                                let msg = format!("Failed to decode offchain lookup struct: {err}");
-                                MetadataBuildError::FailedToBuild(msg)
-                            })?
-                        } else {
-                            info!(?raw_error, "unable to parse custom error out of revert");
-                            return Err(MetadataBuildError::CouldNotFetch);
-                        }
-                    }
-                }
-            }
+        let info = match info_from_cache {
+            Some(info) => info,
+            None => ism
+                .get_offchain_verify_info(RawHyperlaneMessage::from(message).to_vec())
+                .await
+                .map_err(|err| {
+                    info!(?err, "unable to get offchain verify info from ISM");
+                    MetadataBuildError::CouldNotFetch
+                })?,
         };
 
         self.base
             .base_builder()
             .cache()
-            .cache_call_result(
-                ism_domain,
-                fn_key,
-                &call_params,
-                &SerializedOffchainLookup::from(info.clone()),
-            )
+            .cache_call_result(ism_domain, fn_key, &call_params, &info)
             .await
             .map_err(|err| {
                 warn!(error = %err, "Error when caching call result for {:?}", fn_key);
@@ -278,11 +246,9 @@ async fn fetch_offchain_data(
         None
     };
 
-    // Need to explicitly convert the sender H160 the hex because the `ToString` implementation
-    // for `H160` truncates the output. (e.g. `0xc66a…7b6f` instead of returning
-    // the full address)
-    let sender_as_bytes = bytes_to_hex(info.sender.as_bytes());
-    let data_as_bytes = info.call_data.to_string();
+    // The sender is hex encoded in the native address width of the destination chain
+    let sender_as_bytes = bytes_to_hex(&info.sender);
+    let data_as_bytes = bytes_to_hex(&info.call_data);
     let interpolated_url = url
         .replace("{sender}", &sender_as_bytes)
         .replace("{data}", &data_as_bytes);
@@ -388,12 +354,13 @@ mod test {
         let url = "http://example.com/namespace".to_string();
         let info = OffchainLookup {
             // from TestCcipReadIsm.sol
-            call_data: "callDataToReturn".as_bytes().to_vec().into(),
+            call_data: "callDataToReturn".as_bytes().to_vec(),
             // from ccipread.hardhat-test.ts
-            sender: H160::from_str("4ee6ecad1c2dae9f525404de8555724e3c35d07b").unwrap(),
+            sender: H160::from_str("4ee6ecad1c2dae9f525404de8555724e3c35d07b")
+                .unwrap()
+                .as_bytes()
+                .to_vec(),
             urls: vec![url.clone()],
-            callback_function: [0, 0, 0, 0],
-            extra_data: vec![].into(),
         };
 
         let signature_hex =
@@ -412,8 +379,8 @@ mod test {
         // Test the signature is valid
         let signable = HyperlaneAuthenticatedOffchainLookup {
             url_template: url.into(),
-            sender: info.sender.into(),
-            call_data: info.call_data.clone().to_vec(),
+            sender: info.sender.clone(),
+            call_data: info.call_data.clone(),
         };
         let signed = SignedType {
             value: signable,
@@ -19,6 +19,7 @@
 pub(crate) mod blacklist;
 pub(crate) mod db_loader;
 pub(crate) mod gas_payment;
+pub(crate) mod message_filters;
 pub(crate) mod message_processor;
 pub(crate) mod metadata;
 pub(crate) mod op_batch;
@@ -187,7 +187,7 @@ mod tests {
     };
     use ethers::utils::hex;
     use hyperlane_base::{
-        cache::{LocalCache, MeteredCache, MeteredCacheConfig, OptionalCache},
+        cache::{CacheBackend, LocalCache, MeteredCache, MeteredCacheConfig, OptionalCache},
         db::{HyperlaneRocksDB, DB},
         settings::{ChainConf, ChainConnectionConf, CoreContractAddresses},
         CoreMetrics,
@@ -325,6 +325,7 @@ mod tests {
             domain: HyperlaneDomain::Known(hyperlane_core::KnownHyperlaneDomain::Arbitrum),
             // TODO
             signer: None,
+            signer_pool: vec![],
             submitter: SubmitterType::Classic,
             estimated_block_time: Duration::from_secs(1),
             reorg_period: ReorgPeriod::from_blocks(10),
@@ -354,6 +355,8 @@ mod tests {
                     ..Default::default()
                 },
                 consider_null_transaction_receipt: false,
+                log_subscription_url: None,
+                private_submission: None,
             }),
             metrics_conf: Default::default(),
             index: Default::default(),
@@ -380,7 +383,7 @@ mod tests {
             .into();
 
         let cache = OptionalCache::new(Some(MeteredCache::new(
-            LocalCache::new("test-cache"),
+            CacheBackend::from(LocalCache::new("test-cache")),
             dummy_cache_metrics(),
             MeteredCacheConfig {
                 cache_name: "test-cache".to_owned(),
@@ -408,16 +411,20 @@ mod tests {
             origin_db: Arc::new(base_db.clone()),
             cache: cache.clone(),
             metadata_builder: Arc::new(metadata_builder),
-            origin_gas_payment_enforcer: Arc::new(RwLock::new(GasPaymentEnforcer::new(
-                vec![GasPaymentEnforcementConf {
-                    policy: GasPaymentEnforcementPolicy::None,
-                    matching_list: MatchingList::default(),
-                }],
-                base_db.clone(),
-            ))),
+            origin_gas_payment_enforcer: Arc::new(RwLock::new(
+                GasPaymentEnforcer::new(
+                    vec![GasPaymentEnforcementConf {
+                        policy: GasPaymentEnforcementPolicy::None,
+                        matching_list: MatchingList::default(),
+                    }],
+                    base_db.clone(),
+                )
+                .unwrap(),
+            )),
             transaction_gas_limit: Default::default(),
             metrics: dummy_submission_metrics(),
             application_operation_verifier: Arc::new(DummyApplicationOperationVerifier {}),
+            message_filters: Default::default(),
         });
 
         let attempts = 2;
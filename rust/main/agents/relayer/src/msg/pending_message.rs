@@ -15,7 +15,7 @@ use tokio::sync::RwLock;
 use tracing::{debug, error, info, info_span, instrument, trace, warn, Instrument, Level};
 
 use hyperlane_base::{
-    cache::{FunctionCallCache, LocalCache, MeteredCache, OptionalCache},
+    cache::{CacheBackend, FunctionCallCache, MeteredCache, OptionalCache},
     db::HyperlaneDb,
 };
 use hyperlane_core::{
@@ -33,6 +33,7 @@ use crate::{
 
 use super::{
     gas_payment::{GasPaymentEnforcer, GasPolicyStatus},
+    message_filters::{FilterDecision, MessageFilters},
     metadata::{BuildsBaseMetadata, MessageMetadataBuilder, MetadataBuilder},
 };
 
@@ -48,6 +49,9 @@ pub const CONFIRM_DELAY: Duration = if cfg!(any(test, feature = "test-utils")) {
     Duration::from_secs(60 * 10)
 };
 
+/// How long to wait before checking again whether a message that is filtered out may be relayed
+pub const FILTERED_MESSAGE_RECHECK_DELAY: Duration = Duration::from_secs(60);
+
 pub const RETRIEVED_MESSAGE_LOG: &str = "Message status retrieved from db";
 pub const USE_CACHE_METADATA_LOG: &str = "Reusing cached metadata";
 pub const INVALIDATE_CACHE_METADATA_LOG: &str = "Invalidating cached metadata";
@@ -68,7 +72,7 @@ pub struct MessageContext {
     /// Origin chain database to verify gas payments.
     pub origin_db: Arc<dyn HyperlaneDb>,
     /// Cache to store commonly used data calls.
-    pub cache: OptionalCache<MeteredCache<LocalCache>>,
+    pub cache: OptionalCache<MeteredCache<CacheBackend>>,
     /// Used to construct the ISM metadata needed to verify a message from the
     /// origin.
     pub metadata_builder: Arc<dyn BuildsBaseMetadata>,
@@ -81,6 +85,9 @@ pub struct MessageContext {
     pub metrics: MessageSubmissionMetrics,
     /// Application operation verifier
     pub application_operation_verifier: Arc<dyn ApplicationOperationVerifier>,
+    /// Whitelist and blacklists, checked again before preparing so rules added
+    /// at runtime also apply to messages which are already queued.
+    pub message_filters: Arc<MessageFilters>,
 }
 
 /// A message that is pending processing and submission.
@@ -230,6 +237,15 @@ impl PendingOperation for PendingMessage {
             return PendingOperationResult::NotReady;
         }
 
+        // The filters may have changed since the message was queued. Filtered messages
+        // are held back rather than dropped, so they are relayed if the rule is removed.
+        let decision = self.ctx.message_filters.check(&self.message).await;
+        if decision != FilterDecision::Allowed {
+            debug!(?decision, "Message is filtered out, holding it back");
+            self.set_next_attempt_after(FILTERED_MESSAGE_RECHECK_DELAY);
+            return PendingOperationResult::NotReady;
+        }
+
         // If the message has already been processed, e.g. due to another relayer having
         // already processed, then mark it as already-processed, and move on to
         // the next tick.
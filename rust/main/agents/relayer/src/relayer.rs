@@ -19,13 +19,14 @@ use tokio::{
     task::JoinHandle,
 };
 use tokio_metrics::TaskMonitor;
-use tracing::{debug, error, info, info_span, Instrument};
+use tracing::{debug, error, info, info_span, warn, Instrument};
 
 use hyperlane_base::{
     broadcast::BroadcastMpscSender,
-    cache::{LocalCache, MeteredCache, MeteredCacheConfig, OptionalCache},
-    db::{HyperlaneRocksDB, DB},
+    cache::{CacheBackend, MeteredCache, MeteredCacheConfig, OptionalCache},
+    db::{HyperlaneRocksDB, ReorgReconciler, DB, DEFAULT_MAX_REORG_DEPTH},
     metrics::{AgentMetrics, ChainSpecificMetricsUpdater},
+    server::Server,
     settings::IndexSettings,
     AgentMetadata, BaseAgent, ChainMetrics, ContractSyncMetrics, ContractSyncer, CoreMetrics,
     HyperlaneAgentCore, RuntimeMetrics, SyncOptions,
@@ -45,8 +46,9 @@ use crate::{
 use crate::{
     metrics::message_submission::MessageSubmissionMetrics,
     msg::{
-        blacklist::AddressBlacklist,
         db_loader::{MessageDbLoader, MessageDbLoaderMetrics},
+        gas_payment::{GasPaymentEnforcerMetrics, PriceSources},
+        message_filters::MessageFilters,
         message_processor::{MessageProcessor, MessageProcessorMetrics},
         metadata::{
             BaseMetadataBuilder, DefaultIsmCache, IsmAwareAppContextClassifier,
@@ -54,7 +56,10 @@ use crate::{
         },
         pending_message::MessageContext,
     },
-    server::{self as relayer_server},
+    server::{
+        self as relayer_server,
+        auth::{ApiAuth, ServerAuthConf},
+    },
     settings::{matching_list::MatchingList, RelayerSettings},
 };
 
@@ -83,15 +88,14 @@ pub struct Relayer {
     /// sent between
     msg_ctxs: HashMap<ContextKey, Arc<MessageContext>>,
     /// The original reference to the relayer cache
-    _cache: OptionalCache<MeteredCache<LocalCache>>,
-    message_whitelist: Arc<MatchingList>,
-    message_blacklist: Arc<MatchingList>,
-    address_blacklist: Arc<AddressBlacklist>,
+    _cache: OptionalCache<MeteredCache<CacheBackend>>,
+    message_filters: Arc<MessageFilters>,
     transaction_gas_limit: Option<U256>,
     skip_transaction_gas_limit_for: HashSet<u32>,
     allow_local_checkpoint_syncers: bool,
     metric_app_contexts: Arc<Vec<(MatchingList, String)>>,
     max_retries: u32,
+    server_auth: Option<ServerAuthConf>,
     core_metrics: Arc<CoreMetrics>,
     // TODO: decide whether to consolidate `agent_metrics` and `chain_metrics` into a single struct
     // or move them in `core_metrics`, like the validator metrics
@@ -111,12 +115,10 @@ impl Debug for Relayer {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Relayer {{ origin_chains: {:?}, destination_chains: {:?}, message_whitelist: {:?}, message_blacklist: {:?}, address_blacklist: {:?}, transaction_gas_limit: {:?}, skip_transaction_gas_limit_for: {:?}, allow_local_checkpoint_syncers: {:?} }}",
+            "Relayer {{ origin_chains: {:?}, destination_chains: {:?}, message_filters: {:?}, transaction_gas_limit: {:?}, skip_transaction_gas_limit_for: {:?}, allow_local_checkpoint_syncers: {:?} }}",
             self.origin_chains,
             self.destinations.values(),
-            self.message_whitelist,
-            self.message_blacklist,
-            self.address_blacklist,
+            self.message_filters,
             self.transaction_gas_limit,
             self.skip_transaction_gas_limit_for,
             self.allow_local_checkpoint_syncers
@@ -155,7 +157,7 @@ impl BaseAgent for Relayer {
         let cache_name = "relayer_cache";
         let inner_cache = if settings.allow_contract_call_caching {
             Some(MeteredCache::new(
-                LocalCache::new(cache_name),
+                CacheBackend::build(&settings.contract_call_cache, cache_name)?,
                 core_metrics.cache_metrics(),
                 MeteredCacheConfig {
                     cache_name: cache_name.to_owned(),
@@ -170,8 +172,20 @@ impl BaseAgent for Relayer {
         let db = DB::from_path(&settings.db)?;
 
         start_entity_init = Instant::now();
-        let origins =
-            Self::build_origins(&settings, db.clone(), core_metrics.clone(), &chain_metrics).await;
+        let price_sources = PriceSources::new(
+            settings
+                .gas_payment_enforcement
+                .iter()
+                .map(|conf| &conf.policy),
+        )?;
+        let origins = Self::build_origins(
+            &settings,
+            db.clone(),
+            core_metrics.clone(),
+            &chain_metrics,
+            price_sources,
+        )
+        .await;
         debug!(elapsed = ?start_entity_init.elapsed(), event = "initialized origin chains", "Relayer startup duration measurement");
 
         start_entity_init = Instant::now();
@@ -187,16 +201,17 @@ impl BaseAgent for Relayer {
         .await;
         debug!(elapsed = ?start_entity_init.elapsed(), event = "initialized destination chains", "Relayer startup duration measurement");
 
-        let message_whitelist = Arc::new(settings.whitelist);
-        let message_blacklist = Arc::new(settings.blacklist);
-        let address_blacklist = Arc::new(AddressBlacklist::new(settings.address_blacklist));
+        let message_filters = Arc::new(MessageFilters::new(
+            settings.whitelist,
+            settings.blacklist,
+            settings.address_blacklist,
+            Some(db.clone()),
+        )?);
         let skip_transaction_gas_limit_for = settings.skip_transaction_gas_limit_for;
         let transaction_gas_limit = settings.transaction_gas_limit;
 
         info!(
-            %message_whitelist,
-            %message_blacklist,
-            ?address_blacklist,
+            filters = ?message_filters.lists().await,
             ?transaction_gas_limit,
             ?skip_transaction_gas_limit_for,
             "Whitelist configuration"
@@ -268,6 +283,7 @@ impl BaseAgent for Relayer {
                             destination_domain,
                         ),
                         application_operation_verifier: application_operation_verifier.clone(),
+                        message_filters: message_filters.clone(),
                     }),
                 );
             }
@@ -281,14 +297,13 @@ impl BaseAgent for Relayer {
             origin_chains: settings.origin_chains,
             msg_ctxs,
             core,
-            message_whitelist,
-            message_blacklist,
-            address_blacklist,
+            message_filters,
             transaction_gas_limit,
             skip_transaction_gas_limit_for,
             allow_local_checkpoint_syncers: settings.allow_local_checkpoint_syncers,
             metric_app_contexts: settings.metric_app_contexts,
             max_retries: settings.max_retries,
+            server_auth: settings.server_auth,
             core_metrics,
             agent_metrics,
             chain_metrics,
@@ -525,11 +540,19 @@ impl BaseAgent for Relayer {
         start_entity_init = Instant::now();
 
         let relayer_router = self.build_router(prep_queues, sender.clone()).await;
-        let server = self
-            .core
-            .settings
-            .server(self.core_metrics.clone())
-            .expect("Failed to create server");
+        let tls = self.server_auth.as_ref().and_then(|auth| auth.tls.as_ref());
+        let server = match tls {
+            Some(tls) => Arc::new(
+                Server::new(self.core.settings.metrics_port, self.core_metrics.clone())
+                    .with_tls(tls)
+                    .expect("Failed to load server TLS configuration"),
+            ),
+            None => self
+                .core
+                .settings
+                .server(self.core_metrics.clone())
+                .expect("Failed to create server"),
+        };
         let server_task = tokio::spawn(
             async move {
                 let _ = server.run_with_custom_router(relayer_router).await;
@@ -606,15 +629,47 @@ impl Relayer {
                 })
             })
             .collect();
-        relayer_server::Server::new(self.destinations.len())
+        let mut reorg_reconcilers = HashMap::new();
+        for (domain, origin) in &self.origins {
+            // the block hashes of logs on other chains can't be checked for reorgs
+            if !ReorgReconciler::supports_protocol(domain.domain_protocol()) {
+                continue;
+            }
+            match origin.chain_conf.build_provider(&self.core_metrics).await {
+                Ok(provider) => {
+                    let reconciler = ReorgReconciler::new(
+                        origin.database.clone(),
+                        Arc::from(provider),
+                        DEFAULT_MAX_REORG_DEPTH,
+                    );
+                    reorg_reconcilers.insert(domain.id(), Arc::new(reconciler));
+                }
+                Err(err) => {
+                    warn!(
+                        ?err,
+                        origin = domain.name(),
+                        "Failed to build provider for reorg reconciliation"
+                    );
+                }
+            }
+        }
+        let server = relayer_server::Server::new(self.destinations.len())
             .with_op_retry(sender)
             .with_message_queue(prep_queues)
             .with_dbs(dbs)
             .with_gas_enforcers(gas_enforcers)
+            .with_message_filters(self.message_filters.clone())
             .with_msg_ctxs(msg_ctxs)
             .with_prover_sync(prover_syncs)
             .with_dispatcher_command_entrypoints(dispatcher_entrypoints)
-            .router()
+            .with_reorg_reconcilers(reorg_reconcilers);
+        match &self.server_auth {
+            Some(auth) => server.with_auth(ApiAuth::new(auth)).router(),
+            None => {
+                warn!("No `serverAuth` configured, the relayer API is open to anyone who can reach the server port");
+                server.router()
+            }
+        }
     }
 
     fn record_critical_error(
@@ -863,9 +918,7 @@ impl Relayer {
 
         let message_db_loader = MessageDbLoader::new(
             origin.database.clone(),
-            self.message_whitelist.clone(),
-            self.message_blacklist.clone(),
-            self.address_blacklist.clone(),
+            self.message_filters.clone(),
             metrics,
             send_channels,
             destination_ctxs,
@@ -926,15 +979,19 @@ impl Relayer {
         db: DB,
         core_metrics: Arc<CoreMetrics>,
         chain_metrics: &ChainMetrics,
+        price_sources: PriceSources,
     ) -> HashMap<HyperlaneDomain, Origin> {
         use origin::Factory;
         use origin::OriginFactory;
 
         let contract_sync_metrics = Arc::new(ContractSyncMetrics::new(&core_metrics));
+        let gas_payment_enforcer_metrics = GasPaymentEnforcerMetrics::new(&core_metrics);
         let factory = OriginFactory::new(
             db,
             core_metrics,
             contract_sync_metrics,
+            gas_payment_enforcer_metrics,
+            price_sources,
             ADVANCED_LOG_META,
             settings.tx_id_indexing_enabled,
             settings.igp_indexing_enabled,
@@ -16,7 +16,7 @@ use hyperlane_core::{
 use tokio::sync::RwLock;
 
 use crate::merkle_tree::builder::MerkleTreeBuilder;
-use crate::msg::gas_payment::GasPaymentEnforcer;
+use crate::msg::gas_payment::{GasPaymentEnforcer, GasPaymentEnforcerMetrics, PriceSources};
 use crate::settings::GasPaymentEnforcementConf;
 
 type MessageSync = Arc<dyn ContractSyncer<HyperlaneMessage>>;
@@ -51,6 +51,8 @@ pub enum FactoryError {
     InterchainGasPaymentSync(String, String),
     #[error("Failed to create merkle tree hook sync for domain {0}: {1}")]
     MerkleTreeHookSync(String, String),
+    #[error("Failed to create gas payment enforcer for domain {0}: {1}")]
+    GasPaymentEnforcer(String, String),
 }
 
 pub trait Factory {
@@ -67,6 +69,8 @@ pub struct OriginFactory {
     db: DB,
     core_metrics: Arc<CoreMetrics>,
     sync_metrics: Arc<ContractSyncMetrics>,
+    gas_payment_enforcer_metrics: GasPaymentEnforcerMetrics,
+    price_sources: PriceSources,
     advanced_log_meta: bool,
     tx_id_indexing_enabled: bool,
     igp_indexing_enabled: bool,
@@ -77,6 +81,8 @@ impl OriginFactory {
         db: DB,
         core_metrics: Arc<CoreMetrics>,
         sync_metrics: Arc<ContractSyncMetrics>,
+        gas_payment_enforcer_metrics: GasPaymentEnforcerMetrics,
+        price_sources: PriceSources,
         advanced_log_meta: bool,
         tx_id_indexing_enabled: bool,
         igp_indexing_enabled: bool,
@@ -85,6 +91,8 @@ impl OriginFactory {
             db,
             core_metrics,
             sync_metrics,
+            gas_payment_enforcer_metrics,
+            price_sources,
             advanced_log_meta,
             tx_id_indexing_enabled,
             igp_indexing_enabled,
@@ -114,7 +122,7 @@ impl Factory for OriginFactory {
         let gas_payment_enforcer = {
             let start_entity_init = Instant::now();
             let res = self
-                .init_gas_payment_enforcer(gas_payment_enforcement, db.clone())
+                .init_gas_payment_enforcer(&domain, gas_payment_enforcement, db.clone())
                 .await?;
             self.measure(&domain, "gas_payment_enforcer", start_entity_init.elapsed());
             res
@@ -211,10 +219,17 @@ impl OriginFactory {
 
     async fn init_gas_payment_enforcer(
         &self,
+        domain: &HyperlaneDomain,
         gas_payment_enforcement: Vec<GasPaymentEnforcementConf>,
         db: HyperlaneRocksDB,
     ) -> Result<GasPaymentEnforcer, FactoryError> {
-        Ok(GasPaymentEnforcer::new(gas_payment_enforcement, db))
+        GasPaymentEnforcer::new_with_metrics(
+            gas_payment_enforcement,
+            db,
+            Some(self.gas_payment_enforcer_metrics.clone()),
+            self.price_sources.clone(),
+        )
+        .map_err(|err| FactoryError::GasPaymentEnforcer(domain.to_string(), err.to_string()))
     }
 
     async fn init_message_sync(
@@ -24,6 +24,7 @@ use hyperlane_core::{
 use hyperlane_ethereum as h_eth;
 use lander::DispatcherMetrics;
 
+use crate::msg::gas_payment::PriceSources;
 use crate::settings::{matching_list::MatchingList, RelayerSettings};
 
 use super::Relayer;
@@ -61,6 +62,7 @@ fn generate_test_chain_conf(
     ChainConf {
         domain,
         signer,
+        signer_pool: vec![],
         submitter: Default::default(),
         estimated_block_time: Duration::from_secs_f64(1.1),
         reorg_period: ReorgPeriod::None,
@@ -82,6 +84,8 @@ fn generate_test_chain_conf(
                 ..Default::default()
             },
             consider_null_transaction_receipt: false,
+            log_subscription_url: None,
+            private_submission: None,
         }),
         metrics_conf: PrometheusMiddlewareConf {
             contracts: HashMap::new(),
@@ -140,10 +144,12 @@ fn generate_test_relayer_settings(
         allow_local_checkpoint_syncers: true,
         metric_app_contexts: Vec::new().into(),
         allow_contract_call_caching: true,
+        contract_call_cache: Default::default(),
         ism_cache_configs: Default::default(),
         max_retries: 1,
         tx_id_indexing_enabled: true,
         igp_indexing_enabled: true,
+        server_auth: None,
     }
 }
 
@@ -275,8 +281,14 @@ async fn test_failed_build_origin() {
     let chain_metrics = generate_test_chain_metrics();
 
     let db = DB::from_path(db_path).expect("Failed to initialize database");
-    let origins =
-        Relayer::build_origins(&settings, db, Arc::new(core_metrics), &chain_metrics).await;
+    let origins = Relayer::build_origins(
+        &settings,
+        db,
+        Arc::new(core_metrics),
+        &chain_metrics,
+        PriceSources::default(),
+    )
+    .await;
 
     assert_eq!(origins.len(), 1);
     assert!(origins.contains_key(&HyperlaneDomain::Known(KnownHyperlaneDomain::Arbitrum)));
@@ -0,0 +1,409 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{header, HeaderMap, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use hyperlane_base::server::{PeerInfo, ServerTlsConf};
+use sha3::{Digest, Keccak256};
+use tracing::{info, warn};
+
+/// Target of the audit log, so it can be filtered and shipped separately
+pub const AUDIT_LOG_TARGET: &str = "relayer::server::audit";
+
+/// Routes that only admins may access, regardless of the method
+const ADMIN_ONLY_ROUTES: &[&str] = &["/environment_variable"];
+
+/// Access level of an API client. Admins can do everything read-only clients can.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, strum::EnumString)]
+#[strum(serialize_all = "camelCase")]
+pub enum ApiRole {
+    /// Can query state, but not change it
+    ReadOnly,
+    /// Can also call mutating routes
+    Admin,
+}
+
+/// A client authenticated with a bearer token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiToken {
+    /// Name of the client, used in the audit log
+    pub name: String,
+    /// The bearer token
+    pub token: String,
+    /// Access level of the client
+    pub role: ApiRole,
+}
+
+/// A client authenticated with a TLS client certificate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiClientCertificate {
+    /// Name of the client, used in the audit log
+    pub name: String,
+    /// Hex encoded SHA-256 fingerprint of the certificate
+    pub fingerprint: String,
+    /// Access level of the client
+    pub role: ApiRole,
+}
+
+/// Authentication settings for the relayer API
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServerAuthConf {
+    /// Clients authenticating with `Authorization: Bearer <token>`
+    pub tokens: Vec<ApiToken>,
+    /// Clients authenticating with a TLS client certificate.
+    /// Requires `tls` with a client CA.
+    pub client_certificates: Vec<ApiClientCertificate>,
+    /// Access level of clients without credentials. If unset, they are rejected.
+    pub anonymous_role: Option<ApiRole>,
+    /// Serve the API, and the metrics endpoint which shares its port, over TLS
+    pub tls: Option<ServerTlsConf>,
+}
+
+/// The authenticated client of a request
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Principal {
+    name: String,
+    role: ApiRole,
+}
+
+/// Authenticates requests and enforces the role required by each route
+#[derive(Debug)]
+pub struct ApiAuth {
+    /// `(keccak256(token), name, role)`, only hashes are compared so the
+    /// comparison time doesn't depend on how much of the token matched
+    token_hashes: Vec<([u8; 32], String, ApiRole)>,
+    client_certificates: Vec<ApiClientCertificate>,
+    anonymous_role: Option<ApiRole>,
+}
+
+impl ApiAuth {
+    pub fn new(conf: &ServerAuthConf) -> Self {
+        Self {
+            token_hashes: conf
+                .tokens
+                .iter()
+                .map(|token| (hash_token(&token.token), token.name.clone(), token.role))
+                .collect(),
+            client_certificates: conf
+                .client_certificates
+                .iter()
+                .map(|cert| ApiClientCertificate {
+                    fingerprint: normalize_fingerprint(&cert.fingerprint),
+                    ..cert.clone()
+                })
+                .collect(),
+            anonymous_role: conf.anonymous_role,
+        }
+    }
+
+    fn authenticate(&self, headers: &HeaderMap, peer: Option<&PeerInfo>) -> Option<Principal> {
+        let bearer = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        if let Some(token) = bearer {
+            // an invalid token is never downgraded to anonymous access
+            let hash = hash_token(token.trim());
+            return self
+                .token_hashes
+                .iter()
+                .find(|(token_hash, _, _)| *token_hash == hash)
+                .map(|(_, name, role)| Principal {
+                    name: name.clone(),
+                    role: *role,
+                });
+        }
+
+        let fingerprint = peer.and_then(|peer| peer.client_cert_fingerprint.as_ref());
+        if let Some(fingerprint) = fingerprint {
+            if let Some(cert) = self
+                .client_certificates
+                .iter()
+                .find(|cert| &cert.fingerprint == fingerprint)
+            {
+                return Some(Principal {
+                    name: cert.name.clone(),
+                    role: cert.role,
+                });
+            }
+        }
+
+        self.anonymous_role.map(|role| Principal {
+            name: "anonymous".to_owned(),
+            role,
+        })
+    }
+}
+
+fn hash_token(token: &str) -> [u8; 32] {
+    Keccak256::digest(token.as_bytes()).into()
+}
+
+/// Accepts the formats printed by common tools, e.g. `AB:CD:..` from openssl
+fn normalize_fingerprint(fingerprint: &str) -> String {
+    fingerprint
+        .trim()
+        .trim_start_matches("0x")
+        .replace(':', "")
+        .to_lowercase()
+}
+
+/// Reading routes are open to all clients, everything else requires an admin
+fn required_role(method: &Method, path: &str) -> ApiRole {
+    let admin_only = ADMIN_ONLY_ROUTES
+        .iter()
+        .any(|route| path == *route || path.starts_with(&format!("{route}/")));
+    let reading = matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS);
+    if reading && !admin_only {
+        ApiRole::ReadOnly
+    } else {
+        ApiRole::Admin
+    }
+}
+
+/// Middleware rejecting requests of clients without the required role.
+/// All calls to admin routes are written to the audit log, including rejected ones.
+pub async fn authorize(State(auth): State<Arc<ApiAuth>>, request: Request, next: Next) -> Response {
+    let method = request.method().clone();
+    let path = request.uri().path().to_owned();
+    let required = required_role(&method, &path);
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<PeerInfo>>()
+        .map(|ConnectInfo(peer)| peer.clone());
+    let remote_addr = peer.as_ref().map(|peer| peer.remote_addr.to_string());
+    let principal = auth.authenticate(request.headers(), peer.as_ref());
+
+    let Some(principal) = principal else {
+        warn!(
+            target: AUDIT_LOG_TARGET,
+            %method, %path, ?remote_addr, "Rejected unauthenticated API call"
+        );
+        return (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            "Unauthorized",
+        )
+            .into_response();
+    };
+    if principal.role < required {
+        warn!(
+            target: AUDIT_LOG_TARGET,
+            %method,
+            %path,
+            ?remote_addr,
+            principal = %principal.name,
+            role = ?principal.role,
+            "Rejected API call of client without the required role"
+        );
+        return (StatusCode::FORBIDDEN, "Forbidden").into_response();
+    }
+
+    // the query may identify what was changed, bodies are not logged as they can contain secrets
+    let query = request.uri().query().map(ToOwned::to_owned);
+    let response = next.run(request).await;
+    if required == ApiRole::Admin {
+        info!(
+            target: AUDIT_LOG_TARGET,
+            %method,
+            %path,
+            ?query,
+            ?remote_addr,
+            principal = %principal.name,
+            status = response.status().as_u16(),
+            "Admin API call"
+        );
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use axum::{
+        body::Body,
+        middleware,
+        routing::{get, post},
+        Router,
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn auth() -> Arc<ApiAuth> {
+        Arc::new(ApiAuth::new(&ServerAuthConf {
+            tokens: vec![
+                ApiToken {
+                    name: "dashboard".to_owned(),
+                    token: "read-token".to_owned(),
+                    role: ApiRole::ReadOnly,
+                },
+                ApiToken {
+                    name: "ops".to_owned(),
+                    token: "admin-token".to_owned(),
+                    role: ApiRole::Admin,
+                },
+            ],
+            client_certificates: vec![ApiClientCertificate {
+                name: "ops-cli".to_owned(),
+                fingerprint: "AB:CD:EF".to_owned(),
+                role: ApiRole::Admin,
+            }],
+            anonymous_role: None,
+            tls: None,
+        }))
+    }
+
+    fn router(auth: Arc<ApiAuth>) -> Router {
+        Router::new()
+            .route(
+                "/messages",
+                get(|| async { "list" }).post(|| async { "insert" }),
+            )
+            .route("/environment_variable", get(|| async { "env" }))
+            .layer(middleware::from_fn_with_state(auth, authorize))
+    }
+
+    async fn call(
+        auth: Arc<ApiAuth>,
+        method: Method,
+        path: &str,
+        token: Option<&str>,
+        peer: Option<PeerInfo>,
+    ) -> StatusCode {
+        let mut request = Request::builder().method(method).uri(path);
+        if let Some(token) = token {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {token}"));
+        }
+        let mut request = request.body(Body::empty()).unwrap();
+        if let Some(peer) = peer {
+            request.extensions_mut().insert(ConnectInfo(peer));
+        }
+        router(auth).oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn test_rejects_missing_and_invalid_tokens() {
+        let auth = auth();
+        assert_eq!(
+            call(auth.clone(), Method::GET, "/messages", None, None).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            call(auth, Method::GET, "/messages", Some("wrong"), None).await,
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    #[tokio::test]
+    async fn test_roles() {
+        let auth = auth();
+        let cases = [
+            (Method::GET, "/messages", "read-token", StatusCode::OK),
+            (
+                Method::POST,
+                "/messages",
+                "read-token",
+                StatusCode::FORBIDDEN,
+            ),
+            (
+                Method::GET,
+                "/environment_variable",
+                "read-token",
+                StatusCode::FORBIDDEN,
+            ),
+            (Method::GET, "/messages", "admin-token", StatusCode::OK),
+            (Method::POST, "/messages", "admin-token", StatusCode::OK),
+            (
+                Method::GET,
+                "/environment_variable",
+                "admin-token",
+                StatusCode::OK,
+            ),
+        ];
+        for (method, path, token, expected) in cases {
+            assert_eq!(
+                call(auth.clone(), method.clone(), path, Some(token), None).await,
+                expected,
+                "{method} {path} with {token}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_client_certificates() {
+        let auth = auth();
+        assert_eq!(auth.client_certificates[0].fingerprint, "abcdef");
+
+        let peer = |fingerprint: &str| PeerInfo {
+            remote_addr: SocketAddr::from(([127, 0, 0, 1], 1234)),
+            client_cert_fingerprint: Some(fingerprint.to_owned()),
+        };
+        assert_eq!(
+            call(
+                auth.clone(),
+                Method::POST,
+                "/messages",
+                None,
+                Some(peer("abcdef"))
+            )
+            .await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            call(auth, Method::POST, "/messages", None, Some(peer("012345"))).await,
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_role() {
+        let auth = Arc::new(ApiAuth::new(&ServerAuthConf {
+            anonymous_role: Some(ApiRole::ReadOnly),
+            ..Default::default()
+        }));
+        assert_eq!(
+            call(auth.clone(), Method::GET, "/messages", None, None).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            call(auth, Method::POST, "/messages", None, None).await,
+            StatusCode::FORBIDDEN
+        );
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_audit_log() {
+        let auth = auth();
+        call(
+            auth.clone(),
+            Method::GET,
+            "/messages",
+            Some("admin-token"),
+            None,
+        )
+        .await;
+        assert!(!logs_contain("Admin API call"));
+
+        call(
+            auth.clone(),
+            Method::POST,
+            "/messages",
+            Some("admin-token"),
+            None,
+        )
+        .await;
+        assert!(logs_contain("Admin API call"));
+        assert!(logs_contain("principal=ops"));
+
+        call(auth, Method::POST, "/messages", Some("read-token"), None).await;
+        assert!(logs_contain(
+            "Rejected API call of client without the required role"
+        ));
+    }
+}
@@ -5,6 +5,7 @@ use tracing::{debug, warn};
 use hyperlane_base::server::utils::{
     ServerErrorBody, ServerErrorResponse, ServerResult, ServerSuccessResponse,
 };
+use hyperlane_core::H160;
 use lander::AdaptsChainAction;
 
 use super::ServerState;
@@ -16,6 +17,9 @@ pub struct RequestBody {
     // If provided, will set to this value.
     // If not provided, will reset upper nonce to finalized nonce.
     pub new_upper_nonce: Option<u64>,
+    // If provided, overwrites the upper nonce of this signer of the signer pool.
+    // If not provided, overwrites the upper nonce of the chain's signer.
+    pub signer: Option<H160>,
 }
 
 /// Overwrite the upper nonce for an EVM chain
@@ -26,6 +30,7 @@ pub async fn handler(
     let RequestBody {
         domain_id,
         new_upper_nonce,
+        signer,
     } = payload;
 
     debug!(domain_id, "Fetching chain");
@@ -42,6 +47,7 @@ pub async fn handler(
 
     let action = AdaptsChainAction::OverwriteUpperNonce {
         nonce: new_upper_nonce,
+        signer,
     };
 
     dispatcher_entrypoint
@@ -51,6 +57,7 @@ pub async fn handler(
             warn!(
                 domain_id,
                 ?new_upper_nonce,
+                ?signer,
                 ?err,
                 "Failed to overwrite upper nonce"
             );
@@ -1,7 +1,9 @@
-use axum::{extract::State, Json};
+use axum::{extract::State, http::StatusCode, Json};
 use serde::{Deserialize, Serialize};
 
-use hyperlane_base::server::utils::{ServerResult, ServerSuccessResponse};
+use hyperlane_base::server::utils::{
+    ServerErrorBody, ServerErrorResponse, ServerResult, ServerSuccessResponse,
+};
 
 use crate::{
     msg::gas_payment::GasPaymentEnforcer,
@@ -19,6 +21,8 @@ pub struct RequestBody {
 pub struct ResponseBody {}
 
 /// Add a new interchain gas payment policy.
+/// Profitability policies can only use the price sources of the configured
+/// policies.
 ///
 /// Example request
 /// ```json
@@ -46,12 +50,23 @@ pub async fn handler(
 
     tracing::debug!(?policy, ?matching_list, "Adding new IGP rule");
 
+    // create the policy for every enforcer before inserting any, so that the
+    // rule is either added to all of them or to none
+    let mut policies = Vec::with_capacity(state.gas_enforcers.len());
     for gas_enforcer in state.gas_enforcers.values_mut() {
-        let policy_impl = GasPaymentEnforcer::create_policy(&policy);
-        gas_enforcer
-            .write()
-            .await
-            .insert_new_policy(0, policy_impl, matching_list.clone());
+        let gas_enforcer = gas_enforcer.write().await;
+        let policy_impl = gas_enforcer.create_policy(&policy).map_err(|err| {
+            let error_msg = format!("Failed to create policy: {err}");
+            tracing::debug!(?policy, ?err, "Failed to create policy");
+            ServerErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                ServerErrorBody { message: error_msg },
+            )
+        })?;
+        policies.push((gas_enforcer, policy_impl));
+    }
+    for (mut gas_enforcer, policy_impl) in policies {
+        gas_enforcer.insert_new_policy(0, policy_impl, matching_list.clone());
     }
     let resp = ResponseBody {};
     Ok(ServerSuccessResponse::new(resp))
@@ -93,7 +108,7 @@ mod tests {
                 let base_db = HyperlaneRocksDB::new(domain, db);
                 (
                     domain.clone(),
-                    Arc::new(RwLock::new(GasPaymentEnforcer::new([], base_db))),
+                    Arc::new(RwLock::new(GasPaymentEnforcer::new([], base_db).unwrap())),
                 )
             })
             .collect();
@@ -243,4 +258,35 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_add_igp_rule_rejects_unconfigured_price_source() {
+        let domains = &[
+            HyperlaneDomain::Known(KnownHyperlaneDomain::Arbitrum),
+            HyperlaneDomain::Known(KnownHyperlaneDomain::Ethereum),
+        ];
+        let TestServerSetup { app, gas_enforcers } = setup_test_server(domains);
+
+        let body = r#"{
+            "policy": {
+                "Profitability": {
+                    "min_margin": 0.1,
+                    "price_source": {
+                        "Http": {
+                            "url": "http://127.0.0.1:9/prices",
+                            "cache_seconds": 60,
+                            "max_stale_seconds": 600
+                        }
+                    }
+                }
+            },
+            "matching_list": []
+        }"#;
+        let response = send_request(app, body).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        for gas_enforcer in gas_enforcers.values() {
+            assert!(gas_enforcer.read().await.get_policies().is_empty());
+        }
+    }
 }
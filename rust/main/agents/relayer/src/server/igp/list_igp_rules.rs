@@ -81,7 +81,7 @@ mod tests {
                 let base_db = HyperlaneRocksDB::new(domain, db);
                 (
                     domain.clone(),
-                    Arc::new(RwLock::new(GasPaymentEnforcer::new([], base_db))),
+                    Arc::new(RwLock::new(GasPaymentEnforcer::new([], base_db).unwrap())),
                 )
             })
             .collect();
@@ -0,0 +1,197 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+
+use hyperlane_base::server::utils::{
+    ServerErrorBody, ServerErrorResponse, ServerResult, ServerSuccessResponse,
+};
+
+use crate::{
+    msg::message_filters::{FilterListKind, FilterRule},
+    server::message_filters::ServerState,
+};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RequestBody {
+    /// A matching list element for the whitelist and blacklist,
+    /// a hex encoded address for the address blacklist
+    pub rule: serde_json::Value,
+    /// Whether to store the rule in the DB, so it's restored on restart
+    #[serde(default)]
+    pub persist: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ResponseBody {}
+
+/// Add a rule to the whitelist, blacklist or address blacklist.
+/// It applies to all messages checked afterwards, including already queued ones.
+///
+/// Example request to `/message_filters/blacklist`
+/// ```json
+/// {
+///     "rule": {
+///         "origindomain": 100,
+///         "senderaddress": "0x9d4454B023096f34B160D6B654540c56A1F81688"
+///     },
+///     "persist": true
+/// }
+/// ```
+///
+/// Example request to `/message_filters/address_blacklist`
+/// ```json
+/// {
+///     "rule": "0x9d4454B023096f34B160D6B654540c56A1F81688"
+/// }
+/// ```
+pub async fn handler(
+    State(state): State<ServerState>,
+    Path(list): Path<FilterListKind>,
+    Json(payload): Json<RequestBody>,
+) -> ServerResult<ServerSuccessResponse<ResponseBody>> {
+    let RequestBody { rule, persist } = payload;
+
+    tracing::debug!(?list, ?rule, persist, "Adding new message filter rule");
+
+    let rule = FilterRule::parse(list, rule).map_err(|err| {
+        ServerErrorResponse::new(
+            StatusCode::BAD_REQUEST,
+            ServerErrorBody {
+                message: format!("Invalid rule: {err}"),
+            },
+        )
+    })?;
+    state
+        .message_filters
+        .add_rule(rule, persist)
+        .await
+        .map_err(|err| {
+            ServerErrorResponse::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ServerErrorBody {
+                    message: err.to_string(),
+                },
+            )
+        })?;
+    let resp = ResponseBody {};
+    Ok(ServerSuccessResponse::new(resp))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        body::{self, Body},
+        http::{header::CONTENT_TYPE, Method, Request, Response},
+        Router,
+    };
+    use tower::ServiceExt;
+
+    use hyperlane_core::{HyperlaneMessage, H160, H256};
+
+    use super::*;
+    use crate::msg::message_filters::{FilterDecision, FilterLists, MessageFilters};
+
+    fn setup_test_server() -> (Router, Arc<MessageFilters>) {
+        let message_filters = Arc::new(MessageFilters::default());
+        let app = ServerState::new(message_filters.clone()).router();
+        (app, message_filters)
+    }
+
+    async fn send_request(app: Router, method: Method, uri: &str, body: &str) -> Response<Body> {
+        let request = Request::builder()
+            .uri(uri)
+            .method(method)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.to_string())
+            .expect("Failed to build request");
+        app.oneshot(request).await.expect("Failed to send request")
+    }
+
+    #[tokio::test]
+    async fn test_add_blacklist_rule() {
+        let (app, message_filters) = setup_test_server();
+        let sender: H256 = "0x9d4454B023096f34B160D6B654540c56A1F81688"
+            .parse::<H160>()
+            .unwrap()
+            .into();
+
+        let body = r#"{
+            "rule": {
+                "senderaddress": "0x9d4454B023096f34B160D6B654540c56A1F81688"
+            }
+        }"#;
+        let response = send_request(
+            app.clone(),
+            Method::POST,
+            "/message_filters/blacklist",
+            body,
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let message = HyperlaneMessage {
+            sender,
+            ..Default::default()
+        };
+        assert_eq!(
+            message_filters.check(&message).await,
+            FilterDecision::Blacklisted
+        );
+
+        let response = send_request(app, Method::GET, "/message_filters", "").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let lists: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let expected: FilterLists = message_filters.lists().await;
+        assert_eq!(lists, serde_json::to_value(expected).unwrap());
+        assert_eq!(lists["blacklist"]["runtime"][0]["persisted"], false);
+    }
+
+    #[tokio::test]
+    async fn test_add_invalid_rules() {
+        let (app, message_filters) = setup_test_server();
+
+        let cases = [
+            (
+                "/message_filters/address_blacklist",
+                r#"{"rule": "0xnothex"}"#,
+            ),
+            ("/message_filters/whitelist", r#"{"rule": "0x1234"}"#),
+        ];
+        for (uri, body) in cases {
+            let response = send_request(app.clone(), Method::POST, uri, body).await;
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{uri} {body}");
+        }
+
+        // persisting requires a DB
+        let response = send_request(
+            app.clone(),
+            Method::POST,
+            "/message_filters/address_blacklist",
+            r#"{"rule": "0x1234", "persist": true}"#,
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        // unknown lists are rejected by the path extractor
+        let response = send_request(
+            app,
+            Method::POST,
+            "/message_filters/greylist",
+            r#"{"rule": "0x1234"}"#,
+        )
+        .await;
+        assert!(response.status().is_client_error());
+
+        let lists = message_filters.lists().await;
+        assert!(lists.whitelist.runtime.is_empty());
+        assert!(lists.address_blacklist.runtime.is_empty());
+    }
+}
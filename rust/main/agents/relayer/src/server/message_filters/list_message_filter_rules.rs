@@ -0,0 +1,15 @@
+use axum::extract::State;
+
+use hyperlane_base::server::utils::{ServerResult, ServerSuccessResponse};
+
+use crate::{msg::message_filters::FilterLists, server::message_filters::ServerState};
+
+/// Get the whitelist, blacklist and address blacklist, split into the rules
+/// from the config and the ones added at runtime
+pub async fn handler(
+    State(state): State<ServerState>,
+) -> ServerResult<ServerSuccessResponse<FilterLists>> {
+    Ok(ServerSuccessResponse::new(
+        state.message_filters.lists().await,
+    ))
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use axum::{
+    routing::{delete, get, post},
+    Router,
+};
+use derive_new::new;
+
+use crate::msg::message_filters::MessageFilters;
+
+pub mod add_message_filter_rule;
+pub mod list_message_filter_rules;
+pub mod remove_message_filter_rule;
+
+#[derive(Clone, Debug, new)]
+pub struct ServerState {
+    pub message_filters: Arc<MessageFilters>,
+}
+
+impl ServerState {
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/message_filters", get(list_message_filter_rules::handler))
+            .route(
+                "/message_filters/{list}",
+                post(add_message_filter_rule::handler),
+            )
+            .route(
+                "/message_filters/{list}/{index}",
+                delete(remove_message_filter_rule::handler),
+            )
+            .with_state(self)
+    }
+}
@@ -0,0 +1,105 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use serde::{Deserialize, Serialize};
+
+use hyperlane_base::server::utils::{
+    ServerErrorBody, ServerErrorResponse, ServerResult, ServerSuccessResponse,
+};
+
+use crate::{msg::message_filters::FilterListKind, server::message_filters::ServerState};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PathParams {
+    pub list: FilterListKind,
+    pub index: usize,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ResponseBody {}
+
+/// Remove a rule added at runtime, based on its index in the runtime rules of the list.
+///
+/// Messages that were skipped when loaded from the DB while the rule was active
+/// are not picked up again until they are reprocessed or the relayer restarts.
+pub async fn handler(
+    State(state): State<ServerState>,
+    Path(payload): Path<PathParams>,
+) -> ServerResult<ServerSuccessResponse<ResponseBody>> {
+    let PathParams { list, index } = payload;
+
+    let removed = state
+        .message_filters
+        .remove_rule(list, index)
+        .await
+        .map_err(|err| {
+            ServerErrorResponse::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ServerErrorBody {
+                    message: err.to_string(),
+                },
+            )
+        })?;
+    if !removed {
+        return Err(ServerErrorResponse::new(
+            StatusCode::NOT_FOUND,
+            ServerErrorBody {
+                message: format!("No runtime rule at index {index} of the {list:?}"),
+            },
+        ));
+    }
+    let resp = ResponseBody {};
+    Ok(ServerSuccessResponse::new(resp))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        body::Body,
+        http::{Method, Request},
+    };
+    use ethers::utils::hex;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::msg::message_filters::{FilterRule, MessageFilters};
+
+    #[tokio::test]
+    async fn test_remove_rule() {
+        let message_filters = Arc::new(MessageFilters::default());
+        for address in [b"first".to_vec(), b"second".to_vec()] {
+            message_filters
+                .add_rule(FilterRule::AddressBlacklist(address), false)
+                .await
+                .unwrap();
+        }
+        let app = ServerState::new(message_filters.clone()).router();
+
+        let send_request = |uri: &str| {
+            let request = Request::builder()
+                .uri(uri)
+                .method(Method::DELETE)
+                .body(Body::empty())
+                .expect("Failed to build request");
+            app.clone().oneshot(request)
+        };
+
+        let response = send_request("/message_filters/address_blacklist/0")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let runtime = message_filters.lists().await.address_blacklist.runtime;
+        assert_eq!(runtime.len(), 1);
+        assert_eq!(runtime[0].rule, format!("0x{}", hex::encode("second")));
+
+        let response = send_request("/message_filters/address_blacklist/1")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let response = send_request("/message_filters/blacklist/0").await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -2,30 +2,35 @@ use std::collections::HashMap;
 use std::env;
 use std::sync::Arc;
 
-use axum::Router;
+use axum::{middleware, Router};
 use derive_new::new;
 use tokio::sync::broadcast::Sender;
 use tokio::sync::RwLock;
 
-use hyperlane_base::db::HyperlaneRocksDB;
+use hyperlane_base::db::{HyperlaneRocksDB, ReorgReconciler};
 use hyperlane_core::HyperlaneDomain;
 use lander::CommandEntrypoint;
 
 use crate::merkle_tree::builder::MerkleTreeBuilder;
 use crate::msg::gas_payment::GasPaymentEnforcer;
+use crate::msg::message_filters::MessageFilters;
 use crate::msg::op_queue::OperationPriorityQueue;
 use crate::msg::pending_message::MessageContext;
+use crate::server::auth::ApiAuth;
 use crate::server::environment_variable::EnvironmentVariableApi;
 
 pub const ENDPOINT_MESSAGES_QUEUE_SIZE: usize = 100;
 
+pub mod auth;
 pub mod environment_variable;
 pub mod evm;
 pub mod igp;
 pub mod merkle_tree_insertions;
+pub mod message_filters;
 pub mod messages;
 pub mod operations;
 pub mod proofs;
+pub mod reorg_reconciliation;
 
 #[derive(new)]
 pub struct Server {
@@ -39,12 +44,18 @@ pub struct Server {
     #[new(default)]
     gas_enforcers: Option<HashMap<HyperlaneDomain, Arc<RwLock<GasPaymentEnforcer>>>>,
     #[new(default)]
+    message_filters: Option<Arc<MessageFilters>>,
+    #[new(default)]
     // (origin, destination)
     msg_ctxs: HashMap<(u32, u32), Arc<MessageContext>>,
     #[new(default)]
     prover_syncs: Option<HashMap<u32, Arc<RwLock<MerkleTreeBuilder>>>>,
     #[new(default)]
     dispatcher_command_entrypoints: Option<HashMap<u32, Arc<dyn CommandEntrypoint>>>,
+    #[new(default)]
+    reorg_reconcilers: Option<HashMap<u32, Arc<ReorgReconciler>>>,
+    #[new(default)]
+    auth: Option<Arc<ApiAuth>>,
 }
 
 impl Server {
@@ -74,6 +85,11 @@ impl Server {
         self
     }
 
+    pub fn with_message_filters(mut self, message_filters: Arc<MessageFilters>) -> Self {
+        self.message_filters = Some(message_filters);
+        self
+    }
+
     pub fn with_msg_ctxs(mut self, msg_ctxs: HashMap<(u32, u32), Arc<MessageContext>>) -> Self {
         self.msg_ctxs = msg_ctxs;
         self
@@ -95,6 +111,19 @@ impl Server {
         self
     }
 
+    pub fn with_reorg_reconcilers(
+        mut self,
+        reorg_reconcilers: HashMap<u32, Arc<ReorgReconciler>>,
+    ) -> Self {
+        self.reorg_reconcilers = Some(reorg_reconcilers);
+        self
+    }
+
+    pub fn with_auth(mut self, auth: ApiAuth) -> Self {
+        self.auth = Some(Arc::new(auth));
+        self
+    }
+
     // return a custom router that can be used in combination with other routers
     pub fn router(self) -> Router {
         let mut router = Router::new();
@@ -126,12 +155,18 @@ impl Server {
         if let Some(gas_enforcers) = self.gas_enforcers {
             router = router.merge(igp::ServerState::new(gas_enforcers.clone()).router());
         }
+        if let Some(message_filters) = self.message_filters {
+            router = router.merge(message_filters::ServerState::new(message_filters).router());
+        }
         if let Some(prover_syncs) = self.prover_syncs {
             router = router.merge(proofs::ServerState::new(prover_syncs).router());
         }
         if let Some(chains) = self.dispatcher_command_entrypoints {
             router = router.merge(evm::nonce::ServerState::new(chains).router());
         }
+        if let Some(reconcilers) = self.reorg_reconcilers {
+            router = router.merge(reorg_reconciliation::ServerState::new(reconcilers).router());
+        }
 
         let expose_environment_variable_endpoint =
             env::var("HYPERLANE_RELAYER_ENVIRONMENT_VARIABLE_ENDPOINT_ENABLED")
@@ -139,6 +174,9 @@ impl Server {
         if expose_environment_variable_endpoint {
             router = router.merge(EnvironmentVariableApi::new().router());
         }
+        if let Some(auth) = self.auth {
+            router = router.layer(middleware::from_fn_with_state(auth, auth::authorize));
+        }
         router
     }
 }
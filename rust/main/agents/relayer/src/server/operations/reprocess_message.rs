@@ -178,7 +178,7 @@ mod tests {
     use tower::ServiceExt;
 
     use hyperlane_base::{
-        cache::{LocalCache, MeteredCache, MeteredCacheConfig, OptionalCache},
+        cache::{CacheBackend, LocalCache, MeteredCache, MeteredCacheConfig, OptionalCache},
         db::{HyperlaneRocksDB, DB},
     };
     use hyperlane_core::{HyperlaneDomain, HyperlaneMessage, KnownHyperlaneDomain};
@@ -213,7 +213,7 @@ mod tests {
             .collect();
 
         let cache = OptionalCache::new(Some(MeteredCache::new(
-            LocalCache::new("test-cache"),
+            CacheBackend::from(LocalCache::new("test-cache")),
             dummy_cache_metrics(),
             MeteredCacheConfig {
                 cache_name: "test-cache".to_owned(),
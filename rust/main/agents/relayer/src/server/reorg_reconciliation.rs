@@ -0,0 +1,91 @@
+use std::{collections::HashMap, sync::Arc};
+
+use axum::{extract::State, http::StatusCode, routing::post, Json, Router};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use hyperlane_base::{
+    db::{ReorgReconciler, ReorgReconciliation},
+    server::utils::{ServerErrorBody, ServerErrorResponse, ServerResult, ServerSuccessResponse},
+};
+
+#[derive(Clone, Debug, new)]
+pub struct ServerState {
+    pub reconcilers: HashMap<u32, Arc<ReorgReconciler>>,
+}
+
+impl ServerState {
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/reorg_reconciliation", post(handler))
+            .with_state(self)
+    }
+}
+
+/// Request Body
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RequestBody {
+    pub domain_id: u32,
+}
+
+/// Prune the data of an origin that was indexed from reorged blocks.
+/// Messages already in the relayer's queues are only dropped on restart.
+pub async fn handler(
+    State(state): State<ServerState>,
+    Json(payload): Json<RequestBody>,
+) -> ServerResult<ServerSuccessResponse<ReorgReconciliation>> {
+    let RequestBody { domain_id } = payload;
+
+    let reconciler = state.reconcilers.get(&domain_id).ok_or_else(|| {
+        warn!(
+            domain_id,
+            "Domain does not exist or does not support reorg reconciliation"
+        );
+        ServerErrorResponse::new(
+            StatusCode::NOT_FOUND,
+            ServerErrorBody {
+                message: format!(
+                    "Domain {domain_id} does not exist or does not support reorg reconciliation"
+                ),
+            },
+        )
+    })?;
+
+    let reconciliation = reconciler.reconcile().await.map_err(|err| {
+        warn!(domain_id, ?err, "Failed to reconcile reorged data");
+        ServerErrorResponse::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            ServerErrorBody {
+                message: format!("Failed to reconcile reorged data: {err}"),
+            },
+        )
+    })?;
+    Ok(ServerSuccessResponse::new(reconciliation))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{header::CONTENT_TYPE, Method, Request},
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_unknown_domain() {
+        let app = ServerState::new(HashMap::new()).router();
+        let request = Request::builder()
+            .uri("/reorg_reconciliation")
+            .method(Method::POST)
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(
+                serde_json::to_string(&RequestBody { domain_id: 1 }).unwrap(),
+            ))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
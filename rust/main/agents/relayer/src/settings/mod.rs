@@ -10,7 +10,9 @@ use derive_more::{AsMut, AsRef, Deref, DerefMut};
 use ethers::utils::hex;
 use eyre::{eyre, Context};
 use hyperlane_base::{
+    cache::{CacheBackendConf, RedisCache, DEFAULT_REMOTE_CACHE_MAX_ENTRY_SIZE},
     impl_loadable_from_settings,
+    server::ServerTlsConf,
     settings::{
         parser::{parse_json_array, parse_matching_list, RawAgentConf, ValueParser},
         Settings,
@@ -23,6 +25,7 @@ use serde_json::Value;
 
 use crate::{
     msg::{metadata::IsmCacheConfig, pending_message::DEFAULT_MAX_MESSAGE_RETRIES},
+    server::auth::{ApiClientCertificate, ApiToken, ServerAuthConf},
     settings::matching_list::MatchingList,
 };
 
@@ -65,6 +68,9 @@ pub struct RelayerSettings {
     pub metric_app_contexts: Arc<Vec<(MatchingList, String)>>,
     /// Whether to allow contract call caching at all.
     pub allow_contract_call_caching: bool,
+    /// Where cached contract call results are stored. Relayers sharing a remote cache
+    /// reuse each other's results.
+    pub contract_call_cache: CacheBackendConf,
     /// The ISM cache policies to use
     pub ism_cache_configs: Vec<IsmCacheConfig>,
     /// Maximum number of retries per operation
@@ -73,6 +79,9 @@ pub struct RelayerSettings {
     pub tx_id_indexing_enabled: bool,
     /// Whether to enable IGP indexing.
     pub igp_indexing_enabled: bool,
+    /// Authentication for the relayer API. If unset, the API is open to
+    /// anyone who can reach the server port.
+    pub server_auth: Option<ServerAuthConf>,
 }
 
 /// Config for gas payment enforcement
@@ -106,8 +115,38 @@ pub enum GasPaymentEnforcementPolicy {
         gas_fraction_numerator: u64,
         gas_fraction_denominator: u64,
     },
+    /// The payment, converted into a common unit using token exchange rates,
+    /// exceeds the estimated cost of the transaction on the destination by at
+    /// least `min_margin` (e.g. `0.1` requires a 10% profit). Profitability
+    /// requires a payment to exist on the IGP specified in the config.
+    Profitability {
+        min_margin: f64,
+        price_source: PriceSourceConf,
+    },
+}
+
+/// Where the `Profitability` gas payment enforcement policy gets the prices of
+/// each chain's native token from. Both sources serve a JSON object keyed by
+/// domain id, e.g. `{"1": {"price": 2500.0, "decimals": 18}}`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub enum PriceSourceConf {
+    /// Prices are read once from a JSON file.
+    Static { path: PathBuf },
+    /// Prices are fetched from an HTTP endpoint and reused for `cache_seconds`.
+    /// If fetching fails, prices up to `max_stale_seconds` old are still used.
+    Http {
+        url: String,
+        cache_seconds: u64,
+        max_stale_seconds: u64,
+    },
 }
 
+/// How long prices fetched from an HTTP price source are reused by default.
+const DEFAULT_PRICE_CACHE_SECONDS: u64 = 60;
+/// How long prices fetched from an HTTP price source are used by default when
+/// they can't be refreshed.
+const DEFAULT_PRICE_MAX_STALE_SECONDS: u64 = 600;
+
 #[derive(Debug, Deserialize)]
 #[serde(transparent)]
 struct RawRelayerSettings(Value);
@@ -237,6 +276,20 @@ impl FromRawConf<RawRelayerSettings> for RelayerSettings {
                             gas_fraction_denominator,
                         })
                     }
+                    Some("profitability") => {
+                        let min_margin = policy.chain(&mut err)
+                            .get_opt_key("minMargin")
+                            .parse_f64()
+                            .unwrap_or(0.);
+                        let price_source = policy.chain(&mut err)
+                            .get_key("priceSource")
+                            .and_then(parse_price_source_conf)
+                            .end();
+                        price_source.map(|price_source| GasPaymentEnforcementPolicy::Profitability {
+                            min_margin,
+                            price_source,
+                        })
+                    }
                     Some(pt) => Err(eyre!("Unknown gas payment enforcement policy type `{pt}`"))
                         .take_err(&mut err, || cwp.add("type")),
                 }.map(|policy| GasPaymentEnforcementConf {
@@ -345,6 +398,12 @@ impl FromRawConf<RawRelayerSettings> for RelayerSettings {
             .parse_bool()
             .unwrap_or(true);
 
+        let contract_call_cache = p
+            .chain(&mut err)
+            .get_opt_key("contractCallCache")
+            .and_then(parse_cache_backend_conf)
+            .unwrap_or_default();
+
         let ism_cache_configs = p
             .chain(&mut err)
             .get_opt_key("ismCacheConfigs")
@@ -369,6 +428,12 @@ impl FromRawConf<RawRelayerSettings> for RelayerSettings {
             .parse_bool()
             .unwrap_or(true);
 
+        let server_auth = p
+            .chain(&mut err)
+            .get_opt_key("serverAuth")
+            .and_then(parse_server_auth_conf)
+            .end();
+
         err.into_result(RelayerSettings {
             base,
             db,
@@ -383,10 +448,12 @@ impl FromRawConf<RawRelayerSettings> for RelayerSettings {
             allow_local_checkpoint_syncers,
             metric_app_contexts,
             allow_contract_call_caching,
+            contract_call_cache,
             ism_cache_configs,
             max_retries: max_message_retries,
             tx_id_indexing_enabled,
             igp_indexing_enabled,
+            server_auth,
         })
     }
 }
@@ -407,6 +474,206 @@ fn parse_ism_cache_configs(p: ValueParser) -> ConfigResult<Vec<IsmCacheConfig>>
     err.into_result(ml)
 }
 
+fn parse_cache_backend_conf(p: ValueParser) -> ConfigResult<CacheBackendConf> {
+    let mut err = ConfigParsingError::default();
+    let cache_type = p.chain(&mut err).get_key("type").parse_string().end();
+
+    match cache_type {
+        Some("local") => err.into_result(CacheBackendConf::Local),
+        Some("redis") => {
+            let url = p
+                .chain(&mut err)
+                .get_key("url")
+                .parse_from_str("Invalid cache url")
+                .end();
+            let layered = p
+                .chain(&mut err)
+                .get_opt_key("layered")
+                .parse_bool()
+                .unwrap_or(true);
+            let key_prefix = p
+                .chain(&mut err)
+                .get_opt_key("keyPrefix")
+                .parse_string()
+                .end()
+                .map(str::to_owned);
+            let max_entry_size = p
+                .chain(&mut err)
+                .get_opt_key("maxEntrySize")
+                .parse_u64()
+                .end()
+                .map(|size| size as usize)
+                .unwrap_or(DEFAULT_REMOTE_CACHE_MAX_ENTRY_SIZE);
+
+            cfg_unwrap_all!(&p.cwp, err: [url]);
+            if let Err(e) = RedisCache::validate_url(&url) {
+                err.push((&p.cwp).add("url"), eyre!(e));
+            }
+            err.into_result(CacheBackendConf::Redis {
+                url,
+                layered,
+                key_prefix,
+                max_entry_size,
+            })
+        }
+        Some(_) => Err(eyre!("Unknown contract call cache type"))
+            .into_config_result(|| (&p.cwp).add("type")),
+        None => Err(err),
+    }
+}
+
+fn parse_price_source_conf(p: ValueParser) -> ConfigResult<PriceSourceConf> {
+    let mut err = ConfigParsingError::default();
+    let source_type = p.chain(&mut err).get_key("type").parse_string().end();
+
+    match source_type {
+        Some("static") => {
+            let path = p.chain(&mut err).get_key("path").parse_string().end();
+
+            cfg_unwrap_all!(&p.cwp, err: [path]);
+            err.into_result(PriceSourceConf::Static { path: path.into() })
+        }
+        Some("http") => {
+            let url = p
+                .chain(&mut err)
+                .get_key("url")
+                .parse_from_str::<reqwest::Url>("Invalid price source url")
+                .end();
+            let cache_seconds = p
+                .chain(&mut err)
+                .get_opt_key("cacheSeconds")
+                .parse_u64()
+                .unwrap_or(DEFAULT_PRICE_CACHE_SECONDS);
+            let max_stale_seconds = p
+                .chain(&mut err)
+                .get_opt_key("maxStaleSeconds")
+                .parse_u64()
+                .unwrap_or(DEFAULT_PRICE_MAX_STALE_SECONDS);
+
+            cfg_unwrap_all!(&p.cwp, err: [url]);
+            err.into_result(PriceSourceConf::Http {
+                url: url.to_string(),
+                cache_seconds,
+                max_stale_seconds,
+            })
+        }
+        Some(_) => {
+            Err(eyre!("Unknown price source type")).into_config_result(|| (&p.cwp).add("type"))
+        }
+        None => Err(err),
+    }
+}
+
+fn parse_server_auth_conf(p: ValueParser) -> ConfigResult<ServerAuthConf> {
+    let mut err = ConfigParsingError::default();
+
+    let tokens = p
+        .chain(&mut err)
+        .get_opt_key("tokens")
+        .into_array_iter()
+        .map(|tokens| {
+            tokens
+                .filter_map(|token| {
+                    let name = token.chain(&mut err).get_key("name").parse_string().end();
+                    let value = token.chain(&mut err).get_key("token").parse_string().end();
+                    let role = token
+                        .chain(&mut err)
+                        .get_key("role")
+                        .parse_from_str("Invalid API role")
+                        .end();
+                    Some(ApiToken {
+                        name: name?.to_owned(),
+                        token: value?.to_owned(),
+                        role: role?,
+                    })
+                })
+                .collect_vec()
+        })
+        .unwrap_or_default();
+
+    let client_certificates = p
+        .chain(&mut err)
+        .get_opt_key("clientCertificates")
+        .into_array_iter()
+        .map(|certs| {
+            certs
+                .filter_map(|cert| {
+                    let name = cert.chain(&mut err).get_key("name").parse_string().end();
+                    let fingerprint = cert
+                        .chain(&mut err)
+                        .get_key("fingerprint")
+                        .parse_string()
+                        .end();
+                    let role = cert
+                        .chain(&mut err)
+                        .get_key("role")
+                        .parse_from_str("Invalid API role")
+                        .end();
+                    Some(ApiClientCertificate {
+                        name: name?.to_owned(),
+                        fingerprint: fingerprint?.to_owned(),
+                        role: role?,
+                    })
+                })
+                .collect_vec()
+        })
+        .unwrap_or_default();
+
+    let anonymous_role = p
+        .chain(&mut err)
+        .get_opt_key("anonymousRole")
+        .parse_from_str("Invalid API role")
+        .end();
+
+    let tls = p
+        .chain(&mut err)
+        .get_opt_key("tls")
+        .and_then(parse_server_tls_conf)
+        .end();
+
+    let client_ca_configured = tls.as_ref().is_some_and(|tls| tls.client_ca_path.is_some());
+    if !client_certificates.is_empty() && !client_ca_configured {
+        err.push(
+            (&p.cwp).add("client_certificates"),
+            eyre!("Client certificates require `tls.clientCaPath` to be configured"),
+        );
+    }
+
+    err.into_result(ServerAuthConf {
+        tokens,
+        client_certificates,
+        anonymous_role,
+        tls,
+    })
+}
+
+fn parse_server_tls_conf(p: ValueParser) -> ConfigResult<ServerTlsConf> {
+    let mut err = ConfigParsingError::default();
+
+    let cert_path = p
+        .chain(&mut err)
+        .get_key("certPath")
+        .parse_from_str("Expected certificate file path")
+        .end();
+    let key_path = p
+        .chain(&mut err)
+        .get_key("keyPath")
+        .parse_from_str("Expected key file path")
+        .end();
+    let client_ca_path = p
+        .chain(&mut err)
+        .get_opt_key("clientCaPath")
+        .parse_from_str("Expected client CA file path")
+        .end();
+
+    cfg_unwrap_all!(&p.cwp, err: [cert_path, key_path]);
+    err.into_result(ServerTlsConf {
+        cert_path,
+        key_path,
+        client_ca_path,
+    })
+}
+
 fn parse_address_list(
     str: &str,
     err: &mut ConfigParsingError,
@@ -426,6 +693,7 @@ fn parse_address_list(
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::server::auth::ApiRole;
     use hyperlane_core::H160;
 
     #[test]
@@ -485,4 +753,132 @@ mod test {
         let configs = parse_ism_cache_configs(p).expect("Failed to parse ism cache config");
         assert_eq!(configs.len(), 2);
     }
+
+    #[test]
+    fn test_parse_cache_backend_conf() {
+        let parse = |raw: &str| {
+            let value = serde_json::from_str::<Value>(raw).expect("Failed to parse json");
+            parse_cache_backend_conf(ValueParser::new(ConfigPath::default(), &value))
+        };
+
+        assert_eq!(
+            parse(r#"{"type": "local"}"#).unwrap(),
+            CacheBackendConf::Local
+        );
+        assert_eq!(
+            parse(r#"{"type": "redis", "url": "redis://cache:6379/1", "keyprefix": "mainnet:"}"#)
+                .unwrap(),
+            CacheBackendConf::Redis {
+                url: "redis://cache:6379/1".parse().unwrap(),
+                layered: true,
+                key_prefix: Some("mainnet:".to_owned()),
+                max_entry_size: DEFAULT_REMOTE_CACHE_MAX_ENTRY_SIZE,
+            }
+        );
+        assert_eq!(
+            parse(r#"{"type": "redis", "url": "redis://cache", "layered": false, "maxEntrySize": "1024"}"#)
+                .unwrap(),
+            CacheBackendConf::Redis {
+                url: "redis://cache".parse().unwrap(),
+                layered: false,
+                key_prefix: None,
+                max_entry_size: 1024,
+            }
+        );
+        assert!(parse(r#"{"type": "redis"}"#).is_err());
+        assert_eq!(
+            parse(r#"{"type": "redis", "url": "rediss://cache:6380"}"#).unwrap(),
+            CacheBackendConf::Redis {
+                url: "rediss://cache:6380".parse().unwrap(),
+                layered: true,
+                key_prefix: None,
+                max_entry_size: DEFAULT_REMOTE_CACHE_MAX_ENTRY_SIZE,
+            }
+        );
+        assert!(parse(r#"{"type": "redis", "url": "http://cache"}"#).is_err());
+        assert!(parse(r#"{"type": "memcached"}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_price_source_conf() {
+        let parse = |raw: &str| {
+            let value = serde_json::from_str::<Value>(raw).expect("Failed to parse json");
+            parse_price_source_conf(ValueParser::new(ConfigPath::default(), &value))
+        };
+
+        assert_eq!(
+            parse(r#"{"type": "static", "path": "/prices.json"}"#).unwrap(),
+            PriceSourceConf::Static {
+                path: "/prices.json".into(),
+            }
+        );
+        assert_eq!(
+            parse(r#"{"type": "http", "url": "http://localhost:8080/prices"}"#).unwrap(),
+            PriceSourceConf::Http {
+                url: "http://localhost:8080/prices".to_owned(),
+                cache_seconds: DEFAULT_PRICE_CACHE_SECONDS,
+                max_stale_seconds: DEFAULT_PRICE_MAX_STALE_SECONDS,
+            }
+        );
+        assert_eq!(
+            parse(
+                r#"{"type": "http", "url": "http://localhost:8080/prices", "cacheseconds": 5, "maxstaleseconds": 30}"#
+            )
+            .unwrap(),
+            PriceSourceConf::Http {
+                url: "http://localhost:8080/prices".to_owned(),
+                cache_seconds: 5,
+                max_stale_seconds: 30,
+            }
+        );
+        assert!(parse(r#"{"type": "static"}"#).is_err());
+        assert!(parse(r#"{"type": "http", "url": "not a url"}"#).is_err());
+        assert!(parse(r#"{"type": "coingecko"}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_server_auth_conf() {
+        let raw = r#"
+        {
+            "tokens": [{"name": "ops", "token": "secret", "role": "admin"}],
+            "clientcertificates": [{"name": "cli", "fingerprint": "ab:cd", "role": "readOnly"}],
+            "anonymousrole": "readOnly",
+            "tls": {"certpath": "/tls/cert.pem", "keypath": "/tls/key.pem", "clientcapath": "/tls/ca.pem"}
+        }
+        "#;
+        let value = serde_json::from_str::<Value>(raw).expect("Failed to parse json");
+        let conf = parse_server_auth_conf(ValueParser::new(ConfigPath::default(), &value))
+            .expect("Failed to parse server auth config");
+        assert_eq!(
+            conf,
+            ServerAuthConf {
+                tokens: vec![ApiToken {
+                    name: "ops".to_owned(),
+                    token: "secret".to_owned(),
+                    role: ApiRole::Admin,
+                }],
+                client_certificates: vec![ApiClientCertificate {
+                    name: "cli".to_owned(),
+                    fingerprint: "ab:cd".to_owned(),
+                    role: ApiRole::ReadOnly,
+                }],
+                anonymous_role: Some(ApiRole::ReadOnly),
+                tls: Some(ServerTlsConf {
+                    cert_path: "/tls/cert.pem".into(),
+                    key_path: "/tls/key.pem".into(),
+                    client_ca_path: Some("/tls/ca.pem".into()),
+                }),
+            }
+        );
+
+        // client certificates can't be verified without a client CA
+        let raw =
+            r#"{"clientcertificates": [{"name": "cli", "fingerprint": "ab", "role": "admin"}]}"#;
+        let value = serde_json::from_str::<Value>(raw).expect("Failed to parse json");
+        assert!(parse_server_auth_conf(ValueParser::new(ConfigPath::default(), &value)).is_err());
+
+        let raw = r#"{"tokens": [{"name": "ops", "token": "secret", "role": "root"}]}"#;
+        let value = serde_json::from_str::<Value>(raw).expect("Failed to parse json");
+        assert!(parse_server_auth_conf(ValueParser::new(ConfigPath::default(), &value)).is_err());
+    }
 }
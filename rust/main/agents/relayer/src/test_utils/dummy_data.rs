@@ -5,7 +5,7 @@ use prometheus::{CounterVec, IntCounter, IntCounterVec, IntGauge, Opts, Registry
 use tokio::sync::RwLock;
 
 use hyperlane_base::{
-    cache::{LocalCache, MeteredCache, OptionalCache},
+    cache::{CacheBackend, MeteredCache, OptionalCache},
     db::HyperlaneRocksDB,
     settings::{ChainConf, ChainConnectionConf, Settings},
     CoreMetrics,
@@ -31,6 +31,7 @@ pub fn dummy_chain_conf(domain: &HyperlaneDomain) -> ChainConf {
     ChainConf {
         domain: domain.clone(),
         signer: Default::default(),
+        signer_pool: vec![],
         submitter: Default::default(),
         estimated_block_time: Duration::from_secs_f64(1.1),
         reorg_period: Default::default(),
@@ -42,6 +43,8 @@ pub fn dummy_chain_conf(domain: &HyperlaneDomain) -> ChainConf {
             transaction_overrides: Default::default(),
             op_submission_config: Default::default(),
             consider_null_transaction_receipt: false,
+            log_subscription_url: None,
+            private_submission: None,
         }),
         metrics_conf: Default::default(),
         index: Default::default(),
@@ -56,7 +59,7 @@ pub fn dummy_metadata_builder(
     origin_domain: &HyperlaneDomain,
     destination_domain: &HyperlaneDomain,
     db: &HyperlaneRocksDB,
-    cache: OptionalCache<MeteredCache<LocalCache>>,
+    cache: OptionalCache<MeteredCache<CacheBackend>>,
 ) -> BaseMetadataBuilder {
     let mut settings = Settings::default();
     let domains = HashMap::from([
@@ -117,16 +120,19 @@ pub fn dummy_submission_metrics() -> MessageSubmissionMetrics {
 pub fn dummy_message_context(
     base_metadata_builder: Arc<BaseMetadataBuilder>,
     db: &HyperlaneRocksDB,
-    cache: OptionalCache<MeteredCache<LocalCache>>,
+    cache: OptionalCache<MeteredCache<CacheBackend>>,
 ) -> MessageContext {
     MessageContext {
         destination_mailbox: Arc::new(MockMailboxContract::new_with_default_ism(H256::zero())),
         origin_db: Arc::new(db.clone()),
         cache,
         metadata_builder: base_metadata_builder,
-        origin_gas_payment_enforcer: Arc::new(RwLock::new(GasPaymentEnforcer::new([], db.clone()))),
+        origin_gas_payment_enforcer: Arc::new(RwLock::new(
+            GasPaymentEnforcer::new([], db.clone()).unwrap(),
+        )),
         transaction_gas_limit: Default::default(),
         metrics: dummy_submission_metrics(),
         application_operation_verifier: Arc::new(DummyApplicationOperationVerifier {}),
+        message_filters: Default::default(),
     }
 }
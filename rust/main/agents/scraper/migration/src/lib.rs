@@ -14,6 +14,9 @@ mod m20230309_000004_create_table_delivered_message;
 mod m20230309_000004_create_table_gas_payment;
 mod m20230309_000005_create_table_message;
 mod m20250224_000006_create_table_raw_message_dispatch;
+mod m20261017_000007_create_table_merkle_tree_insertion;
+mod m20261017_000007_create_table_validator_announcement;
+mod m20261017_000008_create_table_warp_route_transfer;
 
 pub struct Migrator;
 
@@ -32,6 +35,9 @@ impl MigratorTrait for Migrator {
             Box::new(m20230309_000004_create_table_delivered_message::Migration),
             Box::new(m20230309_000005_create_table_message::Migration),
             Box::new(m20250224_000006_create_table_raw_message_dispatch::Migration),
+            Box::new(m20261017_000007_create_table_merkle_tree_insertion::Migration),
+            Box::new(m20261017_000007_create_table_validator_announcement::Migration),
+            Box::new(m20261017_000008_create_table_warp_route_transfer::Migration),
         ]
     }
 }
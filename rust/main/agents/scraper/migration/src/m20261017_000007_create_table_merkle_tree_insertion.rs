@@ -0,0 +1,127 @@
+use sea_orm_migration::prelude::*;
+
+use crate::l20230309_types::*;
+use crate::m20230309_000001_create_table_domain::Domain;
+use crate::m20230309_000003_create_table_transaction::Transaction;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(MerkleTreeInsertion::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(MerkleTreeInsertion::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(MerkleTreeInsertion::TimeCreated)
+                            .timestamp()
+                            .not_null()
+                            .default(SimpleExpr::Custom("NOW()".to_owned())),
+                    )
+                    .col(
+                        ColumnDef::new(MerkleTreeInsertion::Domain)
+                            .unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new_with_type(MerkleTreeInsertion::MerkleTreeHook, Address)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MerkleTreeInsertion::LeafIndex)
+                            .unsigned()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new_with_type(MerkleTreeInsertion::MsgId, Hash).not_null())
+                    .col(
+                        ColumnDef::new(MerkleTreeInsertion::TxId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(MerkleTreeInsertion::LogIndex)
+                            .big_unsigned()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_col(MerkleTreeInsertion::Domain)
+                            .to(Domain::Table, Domain::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_col(MerkleTreeInsertion::TxId)
+                            .to(Transaction::Table, Transaction::Id),
+                    )
+                    .index(
+                        Index::create()
+                            .col(MerkleTreeInsertion::Domain)
+                            .col(MerkleTreeInsertion::MerkleTreeHook)
+                            .col(MerkleTreeInsertion::LeafIndex)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .table(MerkleTreeInsertion::Table)
+                    .name("merkle_tree_insertion_msg_id_idx")
+                    .col(MerkleTreeInsertion::MsgId)
+                    .index_type(IndexType::Hash)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .table(MerkleTreeInsertion::Table)
+                    .name("merkle_tree_insertion_domain_id_idx")
+                    .col(MerkleTreeInsertion::Domain)
+                    .col(MerkleTreeInsertion::Id)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MerkleTreeInsertion::Table).to_owned())
+            .await
+    }
+}
+
+/// Learn more at https://docs.rs/sea-query#iden
+#[derive(Iden)]
+pub enum MerkleTreeInsertion {
+    Table,
+    /// Unique database ID
+    Id,
+    /// Time of record creation
+    TimeCreated,
+    /// Domain ID of the chain the merkle tree hook is deployed on
+    Domain,
+    /// Address of the merkle tree hook the message was inserted into
+    MerkleTreeHook,
+    /// Index of the leaf in the merkle tree, which is the indexing sequence
+    LeafIndex,
+    /// Unique id of the inserted message
+    MsgId,
+    /// Transaction the insertion was made in
+    TxId,
+    /// Index of the insertion log within the block
+    LogIndex,
+}
@@ -0,0 +1,122 @@
+use sea_orm_migration::prelude::*;
+
+use crate::l20230309_types::*;
+use crate::m20230309_000001_create_table_domain::Domain;
+use crate::m20230309_000003_create_table_transaction::Transaction;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ValidatorAnnouncement::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ValidatorAnnouncement::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ValidatorAnnouncement::TimeCreated)
+                            .timestamp()
+                            .not_null()
+                            .default(SimpleExpr::Custom("NOW()".to_owned())),
+                    )
+                    .col(
+                        ColumnDef::new(ValidatorAnnouncement::Domain)
+                            .unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new_with_type(ValidatorAnnouncement::ValidatorAnnounce, Address)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new_with_type(ValidatorAnnouncement::Validator, Address)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ValidatorAnnouncement::StorageLocation)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ValidatorAnnouncement::TxId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ValidatorAnnouncement::LogIndex)
+                            .big_unsigned()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_col(ValidatorAnnouncement::Domain)
+                            .to(Domain::Table, Domain::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_col(ValidatorAnnouncement::TxId)
+                            .to(Transaction::Table, Transaction::Id),
+                    )
+                    .index(
+                        Index::create()
+                            // the contract rejects announcing the same location twice
+                            .col(ValidatorAnnouncement::Domain)
+                            .col(ValidatorAnnouncement::ValidatorAnnounce)
+                            .col(ValidatorAnnouncement::Validator)
+                            .col(ValidatorAnnouncement::StorageLocation)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .table(ValidatorAnnouncement::Table)
+                    .name("validator_announcement_domain_validator_idx")
+                    .col(ValidatorAnnouncement::Domain)
+                    .col(ValidatorAnnouncement::Validator)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ValidatorAnnouncement::Table).to_owned())
+            .await
+    }
+}
+
+/// Learn more at https://docs.rs/sea-query#iden
+#[derive(Iden)]
+pub enum ValidatorAnnouncement {
+    Table,
+    /// Unique database ID
+    Id,
+    /// Time of record creation
+    TimeCreated,
+    /// Domain ID of the chain the announcement was made on
+    Domain,
+    /// Address of the ValidatorAnnounce contract
+    ValidatorAnnounce,
+    /// Address of the announcing validator
+    Validator,
+    /// The announced location of signed checkpoints
+    StorageLocation,
+    /// Transaction the announcement was made in
+    TxId,
+    /// Index of the announcement log within the block
+    LogIndex,
+}
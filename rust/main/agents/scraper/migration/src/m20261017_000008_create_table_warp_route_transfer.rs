@@ -0,0 +1,234 @@
+use sea_orm::ConnectionTrait;
+use sea_orm_migration::prelude::*;
+
+use crate::l20230309_types::*;
+use crate::m20230309_000001_create_table_domain::Domain;
+use crate::m20230309_000003_create_table_transaction::Transaction;
+use crate::m20230309_000004_create_table_delivered_message::DeliveredMessage;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WarpRouteTransfer::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WarpRouteTransfer::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(WarpRouteTransfer::TimeCreated)
+                            .timestamp()
+                            .not_null()
+                            .default(SimpleExpr::Custom("NOW()".to_owned())),
+                    )
+                    .col(
+                        ColumnDef::new_with_type(WarpRouteTransfer::MsgId, Hash)
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(WarpRouteTransfer::Origin)
+                            .unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WarpRouteTransfer::Destination)
+                            .unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new_with_type(WarpRouteTransfer::OriginToken, Address)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new_with_type(WarpRouteTransfer::DestinationToken, Address)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new_with_type(WarpRouteTransfer::Recipient, Address).not_null())
+                    .col(ColumnDef::new_with_type(WarpRouteTransfer::Amount, Wei).not_null())
+                    .col(
+                        ColumnDef::new(WarpRouteTransfer::Metadata)
+                            .binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WarpRouteTransfer::OriginTxId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_col(WarpRouteTransfer::Origin)
+                            .to(Domain::Table, Domain::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_col(WarpRouteTransfer::OriginTxId)
+                            .to(Transaction::Table, Transaction::Id),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .table(WarpRouteTransfer::Table)
+                    .name("warp_route_transfer_origin_token_idx")
+                    .col(WarpRouteTransfer::Origin)
+                    .col(WarpRouteTransfer::OriginToken)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .table(WarpRouteTransfer::Table)
+                    .name("warp_route_transfer_destination_token_idx")
+                    .col(WarpRouteTransfer::Destination)
+                    .col(WarpRouteTransfer::DestinationToken)
+                    .index_type(IndexType::BTree)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .table(WarpRouteTransfer::Table)
+                    .name("warp_route_transfer_recipient_idx")
+                    .col(WarpRouteTransfer::Recipient)
+                    .index_type(IndexType::Hash)
+                    .to_owned(),
+            )
+            .await?;
+
+        // the delivery status is derived from the delivered messages, which may
+        // be scraped before or after the transfer itself
+        let sql = format!(
+            r#"
+            CREATE VIEW "{wrt_table}_view" AS
+            SELECT
+                "wrt"."{wrt_id}" AS "id",
+                "wrt"."{wrt_mid}" AS "msg_id",
+
+                "wrt"."{wrt_origin}" AS "origin_domain_id",
+                "origin_domain"."{domain_name}" AS "origin_domain",
+                "wrt"."{wrt_dest}" AS "destination_domain_id",
+                "dest_domain"."{domain_name}" AS "destination_domain",
+
+                "wrt"."{wrt_origin_token}" AS "origin_token",
+                "wrt"."{wrt_dest_token}" AS "destination_token",
+                "wrt"."{wrt_recipient}" AS "recipient",
+                "wrt"."{wrt_amount}" AS "amount",
+                "wrt"."{wrt_metadata}" AS "metadata",
+
+                "dmsg"."{dmsg_id}" IS NOT NULL AS "is_delivered",
+
+                "wrt"."{wrt_oti}" AS "origin_tx_id",
+                "origin_tx"."{tx_hash}" AS "origin_tx_hash",
+                "origin_tx"."{tx_sender}" AS "origin_tx_sender",
+                "dmsg"."{dmsg_dti}" AS "destination_tx_id",
+                "dest_tx"."{tx_hash}" AS "destination_tx_hash",
+
+                "wrt"."{wrt_time_created}" AS "send_scraped_at",
+                "dmsg"."{dmsg_time_created}" AS "delivery_scraped_at"
+            FROM "{wrt_table}" AS "wrt"
+                LEFT JOIN "{domain_table}"
+                    AS "origin_domain"
+                    ON "origin_domain"."{domain_id}" = "wrt"."{wrt_origin}"
+                LEFT JOIN "{domain_table}"
+                    AS "dest_domain"
+                    ON "dest_domain"."{domain_id}" = "wrt"."{wrt_dest}"
+                LEFT JOIN "{tx_table}"
+                    AS "origin_tx"
+                    ON "origin_tx"."{tx_id}" = "wrt"."{wrt_oti}"
+                LEFT JOIN "{dmsg_table}"
+                    AS "dmsg"
+                    ON "dmsg"."{dmsg_mid}" = "wrt"."{wrt_mid}"
+                LEFT JOIN "{tx_table}"
+                    AS "dest_tx"
+                    ON "dest_tx"."{tx_id}" = "dmsg"."{dmsg_dti}"
+            "#,
+            wrt_table = WarpRouteTransfer::Table.to_string(),
+            wrt_id = WarpRouteTransfer::Id.to_string(),
+            wrt_time_created = WarpRouteTransfer::TimeCreated.to_string(),
+            wrt_mid = WarpRouteTransfer::MsgId.to_string(),
+            wrt_origin = WarpRouteTransfer::Origin.to_string(),
+            wrt_dest = WarpRouteTransfer::Destination.to_string(),
+            wrt_origin_token = WarpRouteTransfer::OriginToken.to_string(),
+            wrt_dest_token = WarpRouteTransfer::DestinationToken.to_string(),
+            wrt_recipient = WarpRouteTransfer::Recipient.to_string(),
+            wrt_amount = WarpRouteTransfer::Amount.to_string(),
+            wrt_metadata = WarpRouteTransfer::Metadata.to_string(),
+            wrt_oti = WarpRouteTransfer::OriginTxId.to_string(),
+            domain_table = Domain::Table.to_string(),
+            domain_id = Domain::Id.to_string(),
+            domain_name = Domain::Name.to_string(),
+            tx_table = Transaction::Table.to_string(),
+            tx_id = Transaction::Id.to_string(),
+            tx_hash = Transaction::Hash.to_string(),
+            tx_sender = Transaction::Sender.to_string(),
+            dmsg_table = DeliveredMessage::Table.to_string(),
+            dmsg_id = DeliveredMessage::Id.to_string(),
+            dmsg_mid = DeliveredMessage::MsgId.to_string(),
+            dmsg_dti = DeliveredMessage::DestinationTxId.to_string(),
+            dmsg_time_created = DeliveredMessage::TimeCreated.to_string(),
+        );
+        manager.get_connection().execute_unprepared(&sql).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                r#"DROP VIEW IF EXISTS "{}_view""#,
+                WarpRouteTransfer::Table.to_string()
+            ))
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(WarpRouteTransfer::Table).to_owned())
+            .await
+    }
+}
+
+/// Learn more at https://docs.rs/sea-query#iden
+#[derive(Iden)]
+pub enum WarpRouteTransfer {
+    Table,
+    /// Unique database ID
+    Id,
+    /// Time of record creation
+    TimeCreated,
+    /// Unique id of the message carrying the transfer
+    MsgId,
+    /// Domain ID of the origin chain
+    Origin,
+    /// Domain ID of the destination chain
+    Destination,
+    /// Address of the warp route contract on the origin chain, i.e. the message
+    /// sender
+    OriginToken,
+    /// Address of the warp route contract on the destination chain, i.e. the
+    /// message recipient
+    DestinationToken,
+    /// Recipient of the transferred tokens on the destination chain
+    Recipient,
+    /// Amount of tokens, or the id of the token for non-fungible routes
+    Amount,
+    /// Metadata appended to the token message, empty for most routes
+    Metadata,
+    /// Transaction the transfer was dispatched in
+    OriginTxId,
+}
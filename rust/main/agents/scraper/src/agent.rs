@@ -4,8 +4,8 @@ use async_trait::async_trait;
 use derive_more::AsRef;
 use futures::future::try_join_all;
 use hyperlane_core::{
-    rpc_clients::RPC_RETRY_SLEEP_DURATION, Delivery, HyperlaneDomain, HyperlaneMessage,
-    InterchainGasPayment, H512,
+    rpc_clients::RPC_RETRY_SLEEP_DURATION, Delivery, HyperlaneDomain, HyperlaneDomainProtocol,
+    HyperlaneMessage, InterchainGasPayment, MerkleTreeInsertion, ValidatorAnnouncement, H512,
 };
 use tokio::{sync::mpsc::Receiver as MpscReceiver, task::JoinHandle, time::sleep};
 use tracing::{info, info_span, instrument, trace, Instrument};
@@ -16,7 +16,12 @@ use hyperlane_base::{
     CoreMetrics, HyperlaneAgentCore, RuntimeMetrics, SyncOptions,
 };
 
-use crate::{db::ScraperDb, settings::ScraperSettings, store::HyperlaneDbStore};
+use crate::{
+    db::ScraperDb,
+    server as scraper_server,
+    settings::ScraperSettings,
+    store::{HyperlaneDbAnnouncementStore, HyperlaneDbStore},
+};
 
 const CURSOR_INSTANTIATION_ATTEMPTS: usize = 10;
 
@@ -28,6 +33,7 @@ pub struct Scraper {
     core: HyperlaneAgentCore,
     contract_sync_metrics: Arc<ContractSyncMetrics>,
     scrapers: HashMap<u32, ChainScraper>,
+    db: ScraperDb,
     settings: ScraperSettings,
     core_metrics: Arc<CoreMetrics>,
     agent_metrics: AgentMetrics,
@@ -80,6 +86,7 @@ impl BaseAgent for Scraper {
             core,
             contract_sync_metrics,
             scrapers,
+            db,
             settings,
             core_metrics: metrics,
             agent_metrics,
@@ -93,6 +100,7 @@ impl BaseAgent for Scraper {
         let mut tasks = Vec::with_capacity(self.scrapers.len());
 
         // running http server
+        let scraper_router = scraper_server::Server::new(self.db.clone()).router();
         let server = self
             .core
             .settings
@@ -100,7 +108,7 @@ impl BaseAgent for Scraper {
             .expect("Failed to create server");
         let server_task = tokio::spawn(
             async move {
-                server.run();
+                server.run_with_custom_router(scraper_router);
             }
             .instrument(info_span!("Scraper server")),
         );
@@ -189,7 +197,7 @@ impl Scraper {
         let index_settings = scraper.index_settings.clone();
         let domain = scraper.domain.clone();
 
-        let mut tasks = Vec::with_capacity(2);
+        let mut tasks = Vec::with_capacity(5);
         let (message_indexer, maybe_broadcaster) = self
             .build_message_indexer(
                 domain.clone(),
@@ -214,16 +222,42 @@ impl Scraper {
 
         let gas_payment_indexer = self
             .build_interchain_gas_payment_indexer(
-                domain,
+                domain.clone(),
                 self.core_metrics.clone(),
                 self.contract_sync_metrics.clone(),
-                store,
+                store.clone(),
                 index_settings.clone(),
                 BroadcastMpscSender::<H512>::map_get_receiver(maybe_broadcaster.as_ref()).await,
             )
             .await?;
         tasks.push(gas_payment_indexer);
 
+        let merkle_tree_insertion_indexer = self
+            .build_merkle_tree_insertion_indexer(
+                domain.clone(),
+                self.core_metrics.clone(),
+                self.contract_sync_metrics.clone(),
+                store.clone(),
+                index_settings.clone(),
+                BroadcastMpscSender::<H512>::map_get_receiver(maybe_broadcaster.as_ref()).await,
+            )
+            .await?;
+        tasks.push(merkle_tree_insertion_indexer);
+
+        // announcements can only be indexed from the events of EVM ValidatorAnnounce contracts
+        if domain.domain_protocol() == HyperlaneDomainProtocol::Ethereum {
+            let validator_announcement_indexer = self
+                .build_validator_announcement_indexer(
+                    domain,
+                    self.core_metrics.clone(),
+                    self.contract_sync_metrics.clone(),
+                    store,
+                    index_settings.clone(),
+                )
+                .await?;
+            tasks.push(validator_announcement_indexer);
+        }
+
         Ok(tokio::spawn(
             async move {
                 try_join_all(tasks)
@@ -252,6 +286,9 @@ impl Scraper {
             domain.clone(),
             chain_setup.addresses.mailbox,
             chain_setup.addresses.interchain_gas_paymaster,
+            chain_setup.addresses.merkle_tree_hook,
+            chain_setup.addresses.validator_announce,
+            Arc::new(settings.warp_routes.clone()),
             provider,
             &chain_setup.index.clone(),
             Some(contract_sync_metrics.stored_events.clone()),
@@ -427,6 +464,91 @@ impl Scraper {
             .instrument(info_span!("ChainContractSync", chain=%domain.name(), event=label)),
         ))
     }
+
+    async fn build_merkle_tree_insertion_indexer(
+        &self,
+        domain: HyperlaneDomain,
+        metrics: Arc<CoreMetrics>,
+        contract_sync_metrics: Arc<ContractSyncMetrics>,
+        store: HyperlaneDbStore,
+        index_settings: IndexSettings,
+        tx_id_receiver: Option<MpscReceiver<H512>>,
+    ) -> eyre::Result<JoinHandle<()>> {
+        let label = "merkle_tree_insertion";
+        let sync = self
+            .as_ref()
+            .settings
+            .contract_sync::<MerkleTreeInsertion, _>(
+                &domain,
+                &metrics.clone(),
+                &contract_sync_metrics.clone(),
+                Arc::new(store.clone()) as _,
+                true,
+                true,
+            )
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    ?err,
+                    domain = domain.name(),
+                    label,
+                    "Error syncing contract"
+                );
+                err
+            })?;
+        let cursor = sync.cursor(index_settings.clone()).await.map_err(|err| {
+            tracing::error!(?err, domain = domain.name(), label, "Error getting cursor");
+            err
+        })?;
+        Ok(tokio::spawn(
+            async move {
+                sync.sync(label, SyncOptions::new(Some(cursor), tx_id_receiver))
+                    .await
+            }
+            .instrument(info_span!("ChainContractSync", chain=%domain.name(), event=label)),
+        ))
+    }
+
+    async fn build_validator_announcement_indexer(
+        &self,
+        domain: HyperlaneDomain,
+        metrics: Arc<CoreMetrics>,
+        contract_sync_metrics: Arc<ContractSyncMetrics>,
+        store: HyperlaneDbStore,
+        index_settings: IndexSettings,
+    ) -> eyre::Result<JoinHandle<()>> {
+        let label = "validator_announcement";
+        let sync = self
+            .as_ref()
+            .settings
+            .watermark_contract_sync::<ValidatorAnnouncement, _>(
+                &domain,
+                &metrics.clone(),
+                &contract_sync_metrics.clone(),
+                Arc::new(HyperlaneDbAnnouncementStore::new(store)),
+                true,
+                false,
+            )
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    ?err,
+                    domain = domain.name(),
+                    label,
+                    "Error syncing contract"
+                );
+                err
+            })?;
+        let cursor = sync.cursor(index_settings.clone()).await.map_err(|err| {
+            tracing::error!(?err, domain = domain.name(), label, "Error getting cursor");
+            err
+        })?;
+        // announcements are made in their own transactions, so there is no txid receiver
+        Ok(tokio::spawn(
+            async move { sync.sync(label, SyncOptions::new(Some(cursor), None)).await }
+                .instrument(info_span!("ChainContractSync", chain=%domain.name(), event=label)),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -459,6 +581,7 @@ mod test {
             ChainConf {
                 domain: HyperlaneDomain::Known(KnownHyperlaneDomain::Arbitrum),
                 signer: None,
+                signer_pool: vec![],
                 submitter: Default::default(),
                 estimated_block_time: Duration::from_secs_f64(1.1),
                 reorg_period: ReorgPeriod::None,
@@ -509,6 +632,8 @@ mod test {
                         ..Default::default()
                     },
                     consider_null_transaction_receipt: false,
+                    log_subscription_url: None,
+                    private_submission: None,
                 }),
                 metrics_conf: PrometheusMiddlewareConf {
                     contracts: HashMap::new(),
@@ -545,6 +670,7 @@ mod test {
             },
             db: String::new(),
             chains_to_scrape: vec![],
+            warp_routes: Default::default(),
         }
     }
 
@@ -52,7 +52,10 @@ pub enum Relation {
     Block,
     Cursor,
     DeliveredMessage,
+    MerkleTreeInsertion,
     Message,
+    ValidatorAnnouncement,
+    WarpRouteTransfer,
 }
 
 impl ColumnTrait for Column {
@@ -77,7 +80,14 @@ impl RelationTrait for Relation {
             Self::Block => Entity::has_many(super::block::Entity).into(),
             Self::Cursor => Entity::has_many(super::cursor::Entity).into(),
             Self::DeliveredMessage => Entity::has_many(super::delivered_message::Entity).into(),
+            Self::MerkleTreeInsertion => {
+                Entity::has_many(super::merkle_tree_insertion::Entity).into()
+            }
             Self::Message => Entity::has_many(super::message::Entity).into(),
+            Self::ValidatorAnnouncement => {
+                Entity::has_many(super::validator_announcement::Entity).into()
+            }
+            Self::WarpRouteTransfer => Entity::has_many(super::warp_route_transfer::Entity).into(),
         }
     }
 }
@@ -100,10 +110,28 @@ impl Related<super::delivered_message::Entity> for Entity {
     }
 }
 
+impl Related<super::merkle_tree_insertion::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MerkleTreeInsertion.def()
+    }
+}
+
 impl Related<super::message::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Message.def()
     }
 }
 
+impl Related<super::validator_announcement::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ValidatorAnnouncement.def()
+    }
+}
+
+impl Related<super::warp_route_transfer::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WarpRouteTransfer.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
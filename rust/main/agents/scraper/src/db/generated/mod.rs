@@ -7,6 +7,9 @@ pub mod cursor;
 pub mod delivered_message;
 pub mod domain;
 pub mod gas_payment;
+pub mod merkle_tree_insertion;
 pub mod message;
 pub mod raw_message_dispatch;
 pub mod transaction;
+pub mod validator_announcement;
+pub mod warp_route_transfer;
@@ -3,6 +3,8 @@
 pub use super::{
     block::Entity as Block, cursor::Entity as Cursor,
     delivered_message::Entity as DeliveredMessage, domain::Entity as Domain,
-    gas_payment::Entity as GasPayment, message::Entity as Message,
-    raw_message_dispatch::Entity as RawMessageDispatch, transaction::Entity as Transaction,
+    gas_payment::Entity as GasPayment, merkle_tree_insertion::Entity as MerkleTreeInsertion,
+    message::Entity as Message, raw_message_dispatch::Entity as RawMessageDispatch,
+    transaction::Entity as Transaction, validator_announcement::Entity as ValidatorAnnouncement,
+    warp_route_transfer::Entity as WarpRouteTransfer,
 };
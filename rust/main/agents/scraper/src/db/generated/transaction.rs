@@ -68,7 +68,10 @@ pub enum Relation {
     Block,
     DeliveredMessage,
     GasPayment,
+    MerkleTreeInsertion,
     Message,
+    ValidatorAnnouncement,
+    WarpRouteTransfer,
 }
 
 impl ColumnTrait for Column {
@@ -103,7 +106,14 @@ impl RelationTrait for Relation {
                 .into(),
             Self::DeliveredMessage => Entity::has_many(super::delivered_message::Entity).into(),
             Self::GasPayment => Entity::has_many(super::gas_payment::Entity).into(),
+            Self::MerkleTreeInsertion => {
+                Entity::has_many(super::merkle_tree_insertion::Entity).into()
+            }
             Self::Message => Entity::has_many(super::message::Entity).into(),
+            Self::ValidatorAnnouncement => {
+                Entity::has_many(super::validator_announcement::Entity).into()
+            }
+            Self::WarpRouteTransfer => Entity::has_many(super::warp_route_transfer::Entity).into(),
         }
     }
 }
@@ -126,10 +136,28 @@ impl Related<super::gas_payment::Entity> for Entity {
     }
 }
 
+impl Related<super::merkle_tree_insertion::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MerkleTreeInsertion.def()
+    }
+}
+
 impl Related<super::message::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Message.def()
     }
 }
 
+impl Related<super::validator_announcement::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ValidatorAnnouncement.def()
+    }
+}
+
+impl Related<super::warp_route_transfer::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WarpRouteTransfer.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,328 @@
+use eyre::{eyre, Result};
+use itertools::Itertools;
+use sea_orm::{prelude::*, ActiveValue::*, Insert, QuerySelect};
+use tracing::{debug, instrument, trace};
+
+use hyperlane_core::{address_to_bytes, h256_to_bytes, LogMeta, MerkleTreeInsertion, H256};
+use migration::OnConflict;
+
+use crate::date_time;
+use crate::db::ScraperDb;
+
+use super::generated::merkle_tree_insertion;
+
+#[derive(Debug)]
+pub struct StorableMerkleTreeInsertion<'a> {
+    pub insertion: &'a MerkleTreeInsertion,
+    pub meta: &'a LogMeta,
+    /// The database id of the transaction the insertion was made in
+    pub txn_id: i64,
+}
+
+impl ScraperDb {
+    /// Get the merkle tree insertion with the given leaf index.
+    #[instrument(skip(self))]
+    pub async fn retrieve_merkle_tree_insertion_by_leaf_index(
+        &self,
+        domain: u32,
+        merkle_tree_hook: &H256,
+        leaf_index: u32,
+    ) -> Result<Option<MerkleTreeInsertion>> {
+        let insertion = self
+            .find_merkle_tree_insertion(domain, merkle_tree_hook, leaf_index)
+            .await?
+            .map(|insertion| {
+                MerkleTreeInsertion::new(
+                    insertion.leaf_index as u32,
+                    H256::from_slice(&insertion.msg_id),
+                )
+            });
+        Ok(insertion)
+    }
+
+    /// Get the transaction id of the merkle tree insertion with the given leaf
+    /// index.
+    #[instrument(skip(self))]
+    pub async fn retrieve_merkle_tree_insertion_tx_id(
+        &self,
+        domain: u32,
+        merkle_tree_hook: &H256,
+        leaf_index: u32,
+    ) -> Result<Option<i64>> {
+        let tx_id = self
+            .find_merkle_tree_insertion(domain, merkle_tree_hook, leaf_index)
+            .await?
+            .map(|insertion| insertion.tx_id);
+        Ok(tx_id)
+    }
+
+    async fn find_merkle_tree_insertion(
+        &self,
+        domain: u32,
+        merkle_tree_hook: &H256,
+        leaf_index: u32,
+    ) -> Result<Option<merkle_tree_insertion::Model>> {
+        Ok(merkle_tree_insertion::Entity::find()
+            .filter(merkle_tree_insertion::Column::Domain.eq(domain))
+            .filter(
+                merkle_tree_insertion::Column::MerkleTreeHook
+                    .eq(address_to_bytes(merkle_tree_hook)),
+            )
+            .filter(merkle_tree_insertion::Column::LeafIndex.eq(leaf_index))
+            .one(&self.0)
+            .await?)
+    }
+
+    /// Store merkle tree insertions into the database (or update an existing
+    /// one).
+    #[instrument(skip_all)]
+    pub async fn store_merkle_tree_insertions(
+        &self,
+        domain: u32,
+        merkle_tree_hook: &H256,
+        insertions: &[StorableMerkleTreeInsertion<'_>],
+    ) -> Result<u64> {
+        let latest_id_before = self.latest_merkle_tree_insertion_id(domain).await?;
+        let merkle_tree_hook = address_to_bytes(merkle_tree_hook);
+
+        let models = insertions
+            .iter()
+            .map(|storable| merkle_tree_insertion::ActiveModel {
+                id: NotSet,
+                time_created: Set(date_time::now()),
+                domain: Unchanged(domain as i32),
+                merkle_tree_hook: Unchanged(merkle_tree_hook.clone()),
+                leaf_index: Unchanged(storable.insertion.index() as i32),
+                msg_id: Set(h256_to_bytes(&storable.insertion.message_id())),
+                tx_id: Set(storable.txn_id),
+                log_index: Set(storable.meta.log_index.as_u64() as i64),
+            })
+            .collect_vec();
+
+        trace!(?models, "Writing merkle tree insertions to database");
+
+        if models.is_empty() {
+            debug!("Wrote zero new merkle tree insertions to database");
+            return Ok(0);
+        }
+
+        Insert::many(models)
+            .on_conflict(
+                OnConflict::columns([
+                    merkle_tree_insertion::Column::Domain,
+                    merkle_tree_insertion::Column::MerkleTreeHook,
+                    merkle_tree_insertion::Column::LeafIndex,
+                ])
+                .update_columns([
+                    merkle_tree_insertion::Column::TimeCreated,
+                    merkle_tree_insertion::Column::MsgId,
+                    merkle_tree_insertion::Column::TxId,
+                    merkle_tree_insertion::Column::LogIndex,
+                ])
+                .to_owned(),
+            )
+            .exec(&self.0)
+            .await?;
+
+        let new_insertions_count = self
+            .merkle_tree_insertions_count_since_id(domain, latest_id_before)
+            .await?;
+
+        debug!(
+            insertions = new_insertions_count,
+            "Wrote new merkle tree insertions to database"
+        );
+        Ok(new_insertions_count)
+    }
+
+    async fn latest_merkle_tree_insertion_id(&self, domain: u32) -> Result<i64> {
+        let result = merkle_tree_insertion::Entity::find()
+            .select_only()
+            .column_as(merkle_tree_insertion::Column::Id.max(), "max_id")
+            .filter(merkle_tree_insertion::Column::Domain.eq(domain))
+            .into_tuple::<Option<i64>>()
+            .one(&self.0)
+            .await?;
+
+        Ok(result
+            // Top level Option indicates some kind of error
+            .ok_or_else(|| eyre!("Error getting latest merkle tree insertion id"))?
+            // Inner Option indicates whether there was any data in the filter -
+            // just default to 0 if there was no data
+            .unwrap_or(0))
+    }
+
+    async fn merkle_tree_insertions_count_since_id(
+        &self,
+        domain: u32,
+        prev_id: i64,
+    ) -> Result<u64> {
+        Ok(merkle_tree_insertion::Entity::find()
+            .filter(merkle_tree_insertion::Column::Domain.eq(domain))
+            .filter(merkle_tree_insertion::Column::Id.gt(prev_id))
+            .count(&self.0)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use sea_orm::{DatabaseBackend, DbErr, MockDatabase, RuntimeErr, Value};
+    use time::macros::{date, time};
+    use time::PrimitiveDateTime;
+
+    use hyperlane_core::{address_to_bytes, h256_to_bytes, LogMeta, MerkleTreeInsertion, H256};
+
+    use crate::db::generated::merkle_tree_insertion;
+    use crate::db::ScraperDb;
+
+    use super::StorableMerkleTreeInsertion;
+
+    fn create_mock_model(id: i64, leaf_index: i32, tx_id: i64) -> merkle_tree_insertion::Model {
+        merkle_tree_insertion::Model {
+            id,
+            time_created: PrimitiveDateTime::new(date!(2024 - 01 - 01), time!(0:00)),
+            domain: 1,
+            merkle_tree_hook: address_to_bytes(&H256::from_low_u64_be(999)),
+            leaf_index,
+            msg_id: h256_to_bytes(&H256::from_low_u64_be(leaf_index as u64 + 1000)),
+            tx_id,
+            log_index: 0,
+        }
+    }
+
+    fn row(column: &'static str, value: Value) -> BTreeMap<&'static str, Value> {
+        [(column, value)].into_iter().collect()
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_merkle_tree_insertion_by_leaf_index_found() {
+        let mock_db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([[create_mock_model(1, 7, 42)]])
+            .into_connection();
+        let scraper_db = ScraperDb::with_connection(mock_db);
+
+        let result = scraper_db
+            .retrieve_merkle_tree_insertion_by_leaf_index(1, &H256::from_low_u64_be(999), 7)
+            .await;
+
+        assert_eq!(
+            result.unwrap(),
+            Some(MerkleTreeInsertion::new(7, H256::from_low_u64_be(1007)))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_merkle_tree_insertion_by_leaf_index_not_found() {
+        let mock_db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<merkle_tree_insertion::Model>::new()])
+            .into_connection();
+        let scraper_db = ScraperDb::with_connection(mock_db);
+
+        let result = scraper_db
+            .retrieve_merkle_tree_insertion_by_leaf_index(1, &H256::from_low_u64_be(999), 7)
+            .await;
+
+        assert_eq!(result.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_merkle_tree_insertion_tx_id() {
+        let mock_db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([[create_mock_model(1, 7, 42)]])
+            .append_query_results([Vec::<merkle_tree_insertion::Model>::new()])
+            .into_connection();
+        let scraper_db = ScraperDb::with_connection(mock_db);
+        let merkle_tree_hook = H256::from_low_u64_be(999);
+
+        let found = scraper_db
+            .retrieve_merkle_tree_insertion_tx_id(1, &merkle_tree_hook, 7)
+            .await;
+        let not_found = scraper_db
+            .retrieve_merkle_tree_insertion_tx_id(1, &merkle_tree_hook, 8)
+            .await;
+
+        assert_eq!(found.unwrap(), Some(42));
+        assert_eq!(not_found.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_store_merkle_tree_insertions_empty_returns_zero() {
+        let mock_db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([[row("max_id", Value::BigInt(None))]]) // latest_merkle_tree_insertion_id
+            .into_connection();
+        let scraper_db = ScraperDb::with_connection(mock_db);
+
+        let result = scraper_db
+            .store_merkle_tree_insertions(1, &H256::from_low_u64_be(999), &[])
+            .await;
+
+        assert_eq!(result.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_store_merkle_tree_insertions_returns_new_count() {
+        const INSERTION_COUNT: usize = 10;
+
+        let mock_results = (0..INSERTION_COUNT)
+            .map(|i| create_mock_model(i as i64 + 1, i as i32, i as i64 + 1))
+            .collect::<Vec<_>>();
+        let mock_db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([[row("max_id", Value::BigInt(None))]]) // latest_merkle_tree_insertion_id
+            .append_query_results([mock_results]) // INSERT
+            .append_query_results([[row(
+                "num_items",
+                Value::BigInt(Some(INSERTION_COUNT as i64)),
+            )]]) // merkle_tree_insertions_count_since_id
+            .into_connection();
+        let scraper_db = ScraperDb::with_connection(mock_db);
+
+        let insertions = (0..INSERTION_COUNT)
+            .map(|i| MerkleTreeInsertion::new(i as u32, H256::from_low_u64_be(i as u64 + 1000)))
+            .collect::<Vec<_>>();
+        let meta = LogMeta::default();
+        let storables = insertions
+            .iter()
+            .enumerate()
+            .map(|(i, insertion)| StorableMerkleTreeInsertion {
+                insertion,
+                meta: &meta,
+                txn_id: i as i64 + 1,
+            })
+            .collect::<Vec<_>>();
+
+        let result = scraper_db
+            .store_merkle_tree_insertions(1, &H256::from_low_u64_be(999), &storables)
+            .await;
+
+        assert!(result.is_ok(), "Expected Ok, got Err: {:?}", result.err());
+        assert_eq!(result.unwrap(), INSERTION_COUNT as u64);
+    }
+
+    #[tokio::test]
+    async fn test_store_merkle_tree_insertions_handles_db_error() {
+        let mock_db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([[row("max_id", Value::BigInt(Some(0)))]]) // latest_merkle_tree_insertion_id
+            .append_query_errors([DbErr::Exec(RuntimeErr::Internal(
+                "Database connection lost".to_string(),
+            ))])
+            .into_connection();
+        let scraper_db = ScraperDb::with_connection(mock_db);
+
+        let insertion = MerkleTreeInsertion::new(0, H256::from_low_u64_be(1000));
+        let meta = LogMeta::default();
+        let storables = [StorableMerkleTreeInsertion {
+            insertion: &insertion,
+            meta: &meta,
+            txn_id: 1,
+        }];
+
+        let result = scraper_db
+            .store_merkle_tree_insertions(1, &H256::from_low_u64_be(999), &storables)
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -3,19 +3,21 @@
 use eyre::Result;
 use itertools::Itertools;
 use sea_orm::{
-    prelude::*, ActiveValue::*, DeriveColumn, EnumIter, Insert, QuerySelect, TransactionTrait,
+    prelude::*, sea_query::Query, ActiveValue::*, DeriveColumn, EnumIter, Insert, QueryOrder,
+    QuerySelect, TransactionTrait,
 };
 use tracing::{debug, instrument, trace};
 
 use hyperlane_core::{
-    address_to_bytes, bytes_to_address, h256_to_bytes, Delivery, HyperlaneMessage, LogMeta, H256,
+    address_to_bytes, bytes_to_address, bytes_to_h512, h256_to_bytes, h512_to_bytes, Delivery,
+    HyperlaneMessage, LogMeta, H256, H512,
 };
 use migration::OnConflict;
 
 use crate::date_time;
 use crate::db::ScraperDb;
 
-use super::generated::{delivered_message, message};
+use super::generated::{delivered_message, message, transaction};
 
 #[derive(Debug, Clone)]
 pub struct StorableDelivery<'a> {
@@ -33,6 +35,43 @@ pub struct StorableMessage<'a> {
     pub txn_id: i64,
 }
 
+/// Filters applied when listing dispatched messages. Unset fields match
+/// everything.
+#[derive(Debug, Clone, Default)]
+pub struct MessageFilter {
+    pub msg_id: Option<H256>,
+    pub sender: Option<H256>,
+    pub recipient: Option<H256>,
+    pub origin_tx_hash: Option<H512>,
+    pub origin: Option<u32>,
+    pub destination: Option<u32>,
+    /// Only return messages without a matching `delivered_message` row
+    pub undelivered_only: bool,
+}
+
+/// A dispatched message as read back from the database.
+#[derive(Debug, Clone)]
+pub struct DispatchedMessage {
+    /// The database id of the message, used as a pagination cursor
+    pub id: i64,
+    pub message: HyperlaneMessage,
+    pub origin_mailbox: H256,
+    pub origin_tx_hash: Option<H512>,
+}
+
+fn message_from_model(message: message::Model) -> Result<HyperlaneMessage> {
+    Ok(HyperlaneMessage {
+        // We do not write version to the DB.
+        version: 3,
+        origin: message.origin as u32,
+        destination: message.destination as u32,
+        nonce: message.nonce as u32,
+        sender: bytes_to_address(message.sender)?,
+        recipient: bytes_to_address(message.recipient)?,
+        body: message.msg_body.unwrap_or(Vec::new()),
+    })
+}
+
 impl ScraperDb {
     /// Used for store_dispatched_messages()
     /// message::ActiveModel has 11 fields, on conflict has 3,
@@ -201,16 +240,7 @@ impl ScraperDb {
             .one(&self.0)
             .await?
         {
-            Ok(Some(HyperlaneMessage {
-                // We do not write version to the DB.
-                version: 3,
-                origin: message.origin as u32,
-                destination: message.destination as u32,
-                nonce: message.nonce as u32,
-                sender: bytes_to_address(message.sender)?,
-                recipient: bytes_to_address(message.recipient)?,
-                body: message.msg_body.unwrap_or(Vec::new()),
-            }))
+            Ok(Some(message_from_model(message)?))
         } else {
             Ok(None)
         }
@@ -242,6 +272,81 @@ impl ScraperDb {
         Ok(tx_id)
     }
 
+    /// List dispatched messages matching `filter`, ordered by database id.
+    /// Only messages with an id greater than `after_id` are returned, which
+    /// allows callers to page through results with a stable cursor.
+    #[instrument(skip(self))]
+    pub async fn retrieve_messages(
+        &self,
+        filter: &MessageFilter,
+        after_id: Option<i64>,
+        limit: u64,
+    ) -> Result<Vec<DispatchedMessage>> {
+        let mut query = message::Entity::find().find_also_related(transaction::Entity);
+        if let Some(msg_id) = filter.msg_id {
+            query = query.filter(message::Column::MsgId.eq(h256_to_bytes(&msg_id)));
+        }
+        if let Some(sender) = filter.sender {
+            query = query.filter(message::Column::Sender.eq(address_to_bytes(&sender)));
+        }
+        if let Some(recipient) = filter.recipient {
+            query = query.filter(message::Column::Recipient.eq(address_to_bytes(&recipient)));
+        }
+        if let Some(tx_hash) = filter.origin_tx_hash {
+            query = query.filter(transaction::Column::Hash.eq(h512_to_bytes(&tx_hash)));
+        }
+        if let Some(origin) = filter.origin {
+            query = query.filter(message::Column::Origin.eq(origin));
+        }
+        if let Some(destination) = filter.destination {
+            query = query.filter(message::Column::Destination.eq(destination));
+        }
+        if filter.undelivered_only {
+            query = query.filter(
+                Expr::exists(
+                    Query::select()
+                        .expr(Expr::val(1))
+                        .from(delivered_message::Entity)
+                        .and_where(
+                            Expr::col((
+                                delivered_message::Entity,
+                                delivered_message::Column::MsgId,
+                            ))
+                            .equals((message::Entity, message::Column::MsgId)),
+                        )
+                        .and_where(
+                            Expr::col((
+                                delivered_message::Entity,
+                                delivered_message::Column::Domain,
+                            ))
+                            .equals((message::Entity, message::Column::Destination)),
+                        )
+                        .to_owned(),
+                )
+                .not(),
+            );
+        }
+        if let Some(after_id) = after_id {
+            query = query.filter(message::Column::Id.gt(after_id));
+        }
+
+        query
+            .order_by_asc(message::Column::Id)
+            .limit(limit)
+            .all(&self.0)
+            .await?
+            .into_iter()
+            .map(|(message, txn)| {
+                Ok(DispatchedMessage {
+                    id: message.id,
+                    origin_mailbox: bytes_to_address(message.origin_mailbox.clone())?,
+                    origin_tx_hash: txn.map(|txn| bytes_to_h512(&txn.hash)),
+                    message: message_from_model(message)?,
+                })
+            })
+            .collect()
+    }
+
     async fn latest_dispatched_id(&self, domain: u32, origin_mailbox: Vec<u8>) -> Result<i64> {
         let result = message::Entity::find()
             .select_only()
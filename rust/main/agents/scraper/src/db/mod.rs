@@ -1,12 +1,15 @@
 pub use block::*;
 pub use block_cursor::BlockCursor;
 use eyre::Result;
+pub use merkle_tree_insertion::*;
 pub use message::*;
 pub use payment::*;
 pub use raw_message_dispatch::*;
 use sea_orm::{Database, DatabaseConnection, DbConn};
 use tracing::instrument;
 pub use txn::*;
+pub use validator_announcement::*;
+pub use warp_route_transfer::*;
 
 #[allow(clippy::all)]
 mod generated;
@@ -14,10 +17,13 @@ mod generated;
 // These modules implement additional functionality for the ScraperDb
 mod block;
 mod block_cursor;
+mod merkle_tree_insertion;
 mod message;
 mod payment;
 mod raw_message_dispatch;
 mod txn;
+mod validator_announcement;
+mod warp_route_transfer;
 
 /// Database interface to the message explorer database for the scraper. This is
 /// focused on writing data to the database.
@@ -3,7 +3,7 @@ use itertools::Itertools;
 use sea_orm::{prelude::*, ActiveValue::*, Insert, QuerySelect};
 use tracing::{debug, instrument};
 
-use hyperlane_core::{address_to_bytes, h256_to_bytes, InterchainGasPayment, LogMeta, H256};
+use hyperlane_core::{address_to_bytes, h256_to_bytes, InterchainGasPayment, LogMeta, H256, U256};
 use migration::OnConflict;
 
 use crate::conversions::{decimal_to_u256, u256_to_decimal};
@@ -21,7 +21,59 @@ pub struct StorablePayment<'a> {
     pub txn_id: i64,
 }
 
+/// Filters applied when aggregating gas payments. Unset fields match
+/// everything.
+#[derive(Debug, Clone, Default)]
+pub struct GasPaymentFilter {
+    pub msg_id: Option<H256>,
+    pub origin: Option<u32>,
+    pub destination: Option<u32>,
+}
+
+/// Sum of all gas payments matching a [`GasPaymentFilter`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GasPaymentTotals {
+    pub payment: U256,
+    pub gas_amount: U256,
+    pub count: u64,
+}
+
 impl ScraperDb {
+    /// Aggregate the gas payments matching `filter`.
+    #[instrument(skip(self))]
+    pub async fn retrieve_gas_payment_totals(
+        &self,
+        filter: &GasPaymentFilter,
+    ) -> Result<GasPaymentTotals> {
+        let mut query = gas_payment::Entity::find()
+            .select_only()
+            .column_as(gas_payment::Column::Payment.sum(), "payment")
+            .column_as(gas_payment::Column::GasAmount.sum(), "gas_amount")
+            .column_as(gas_payment::Column::Id.count(), "count");
+        if let Some(msg_id) = filter.msg_id {
+            query = query.filter(gas_payment::Column::MsgId.eq(h256_to_bytes(&msg_id)));
+        }
+        if let Some(origin) = filter.origin {
+            query = query.filter(gas_payment::Column::Origin.eq(origin));
+        }
+        if let Some(destination) = filter.destination {
+            query = query.filter(gas_payment::Column::Destination.eq(destination));
+        }
+
+        let (payment, gas_amount, count) = query
+            .into_tuple::<(Option<BigDecimal>, Option<BigDecimal>, i64)>()
+            .one(&self.0)
+            .await?
+            .ok_or_else(|| eyre!("Error aggregating gas payments"))?;
+
+        Ok(GasPaymentTotals {
+            // sums are NULL when no rows match the filter
+            payment: payment.map(decimal_to_u256).unwrap_or_default(),
+            gas_amount: gas_amount.map(decimal_to_u256).unwrap_or_default(),
+            count: count as u64,
+        })
+    }
+
     /// Get the payment associated with a sequence.
     #[instrument(skip(self))]
     pub async fn retrieve_payment_by_sequence(
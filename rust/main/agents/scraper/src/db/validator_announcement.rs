@@ -0,0 +1,286 @@
+use eyre::{eyre, Result};
+use itertools::Itertools;
+use sea_orm::{prelude::*, ActiveValue::*, Insert, Order, QueryOrder, QuerySelect};
+use tracing::{debug, instrument, trace};
+
+use hyperlane_core::{address_to_bytes, LogMeta, ValidatorAnnouncement, H256};
+use migration::OnConflict;
+
+use crate::date_time;
+use crate::db::ScraperDb;
+
+use super::generated::validator_announcement;
+
+#[derive(Debug)]
+pub struct StorableValidatorAnnouncement<'a> {
+    pub announcement: &'a ValidatorAnnouncement,
+    pub meta: &'a LogMeta,
+    /// The database id of the transaction the announcement was made in
+    pub txn_id: i64,
+}
+
+impl ScraperDb {
+    /// Get the transaction id of the latest validator announcement made through
+    /// the given validator announce contract.
+    #[instrument(skip(self))]
+    pub async fn retrieve_latest_validator_announcement_tx_id(
+        &self,
+        domain: u32,
+        validator_announce: &H256,
+    ) -> Result<Option<i64>> {
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
+        enum QueryAs {
+            TxId,
+        }
+        let tx_id = validator_announcement::Entity::find()
+            .filter(validator_announcement::Column::Domain.eq(domain))
+            .filter(
+                validator_announcement::Column::ValidatorAnnounce
+                    .eq(address_to_bytes(validator_announce)),
+            )
+            .order_by(validator_announcement::Column::TxId, Order::Desc)
+            .select_only()
+            .column_as(validator_announcement::Column::TxId, QueryAs::TxId)
+            .into_values::<i64, QueryAs>()
+            .one(&self.0)
+            .await?;
+        Ok(tx_id)
+    }
+
+    /// Store validator announcements into the database (or update an existing
+    /// one).
+    #[instrument(skip_all)]
+    pub async fn store_validator_announcements(
+        &self,
+        domain: u32,
+        validator_announce: &H256,
+        announcements: &[StorableValidatorAnnouncement<'_>],
+    ) -> Result<u64> {
+        let latest_id_before = self.latest_validator_announcement_id(domain).await?;
+        let validator_announce = address_to_bytes(validator_announce);
+
+        let models = announcements
+            .iter()
+            .map(|storable| validator_announcement::ActiveModel {
+                id: NotSet,
+                time_created: Set(date_time::now()),
+                domain: Unchanged(domain as i32),
+                validator_announce: Unchanged(validator_announce.clone()),
+                validator: Unchanged(address_to_bytes(&storable.announcement.validator)),
+                storage_location: Unchanged(storable.announcement.storage_location.clone()),
+                tx_id: Set(storable.txn_id),
+                log_index: Set(storable.meta.log_index.as_u64() as i64),
+            })
+            .collect_vec();
+
+        trace!(?models, "Writing validator announcements to database");
+
+        if models.is_empty() {
+            debug!("Wrote zero new validator announcements to database");
+            return Ok(0);
+        }
+
+        Insert::many(models)
+            .on_conflict(
+                OnConflict::columns([
+                    validator_announcement::Column::Domain,
+                    validator_announcement::Column::ValidatorAnnounce,
+                    validator_announcement::Column::Validator,
+                    validator_announcement::Column::StorageLocation,
+                ])
+                .update_columns([
+                    validator_announcement::Column::TimeCreated,
+                    validator_announcement::Column::TxId,
+                    validator_announcement::Column::LogIndex,
+                ])
+                .to_owned(),
+            )
+            .exec(&self.0)
+            .await?;
+
+        let new_announcements_count = self
+            .validator_announcements_count_since_id(domain, latest_id_before)
+            .await?;
+
+        debug!(
+            announcements = new_announcements_count,
+            "Wrote new validator announcements to database"
+        );
+        Ok(new_announcements_count)
+    }
+
+    async fn latest_validator_announcement_id(&self, domain: u32) -> Result<i64> {
+        let result = validator_announcement::Entity::find()
+            .select_only()
+            .column_as(validator_announcement::Column::Id.max(), "max_id")
+            .filter(validator_announcement::Column::Domain.eq(domain))
+            .into_tuple::<Option<i64>>()
+            .one(&self.0)
+            .await?;
+
+        Ok(result
+            // Top level Option indicates some kind of error
+            .ok_or_else(|| eyre!("Error getting latest validator announcement id"))?
+            // Inner Option indicates whether there was any data in the filter -
+            // just default to 0 if there was no data
+            .unwrap_or(0))
+    }
+
+    async fn validator_announcements_count_since_id(
+        &self,
+        domain: u32,
+        prev_id: i64,
+    ) -> Result<u64> {
+        Ok(validator_announcement::Entity::find()
+            .filter(validator_announcement::Column::Domain.eq(domain))
+            .filter(validator_announcement::Column::Id.gt(prev_id))
+            .count(&self.0)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use sea_orm::{DatabaseBackend, DbErr, MockDatabase, RuntimeErr, Value};
+    use time::macros::{date, time};
+    use time::PrimitiveDateTime;
+
+    use hyperlane_core::{address_to_bytes, LogMeta, ValidatorAnnouncement, H256, U256};
+
+    use crate::db::generated::validator_announcement;
+    use crate::db::ScraperDb;
+
+    use super::StorableValidatorAnnouncement;
+
+    fn create_test_announcement(validator: u64) -> ValidatorAnnouncement {
+        ValidatorAnnouncement {
+            validator: H256::from_low_u64_be(validator),
+            storage_location: format!("s3://validator-{validator}/us-east-1"),
+        }
+    }
+
+    fn create_test_meta(block_number: u64, log_index: u64) -> LogMeta {
+        LogMeta {
+            block_number,
+            block_hash: H256::from_low_u64_be(block_number),
+            log_index: U256::from(log_index),
+            ..Default::default()
+        }
+    }
+
+    fn create_mock_model(id: i64, tx_id: i64) -> validator_announcement::Model {
+        validator_announcement::Model {
+            id,
+            time_created: PrimitiveDateTime::new(date!(2024 - 01 - 01), time!(0:00)),
+            domain: 1,
+            validator_announce: address_to_bytes(&H256::from_low_u64_be(999)),
+            validator: vec![0u8; 32],
+            storage_location: "s3://validator/us-east-1".to_owned(),
+            tx_id,
+            log_index: 0,
+        }
+    }
+
+    fn row(column: &'static str, value: Value) -> BTreeMap<&'static str, Value> {
+        [(column, value)].into_iter().collect()
+    }
+
+    #[tokio::test]
+    async fn test_store_validator_announcements_empty_returns_zero() {
+        let mock_db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([[row("max_id", Value::BigInt(None))]]) // latest_validator_announcement_id
+            .into_connection();
+        let scraper_db = ScraperDb::with_connection(mock_db);
+
+        let result = scraper_db
+            .store_validator_announcements(1, &H256::from_low_u64_be(999), &[])
+            .await;
+
+        assert_eq!(result.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_store_validator_announcements_returns_new_count() {
+        let mock_db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([[row("max_id", Value::BigInt(Some(4)))]]) // latest_validator_announcement_id
+            .append_query_results([[create_mock_model(5, 1), create_mock_model(6, 2)]]) // INSERT
+            .append_query_results([[row("num_items", Value::BigInt(Some(2)))]]) // validator_announcements_count_since_id
+            .into_connection();
+        let scraper_db = ScraperDb::with_connection(mock_db);
+
+        let announcements = [create_test_announcement(1), create_test_announcement(2)];
+        let metas = [create_test_meta(100, 0), create_test_meta(101, 0)];
+        let storables = announcements
+            .iter()
+            .zip(metas.iter())
+            .enumerate()
+            .map(|(i, (announcement, meta))| StorableValidatorAnnouncement {
+                announcement,
+                meta,
+                txn_id: i as i64 + 1,
+            })
+            .collect::<Vec<_>>();
+
+        let result = scraper_db
+            .store_validator_announcements(1, &H256::from_low_u64_be(999), &storables)
+            .await;
+
+        assert!(result.is_ok(), "Expected Ok, got Err: {:?}", result.err());
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_store_validator_announcements_handles_db_error() {
+        let mock_db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([[row("max_id", Value::BigInt(Some(0)))]]) // latest_validator_announcement_id
+            .append_query_errors([DbErr::Exec(RuntimeErr::Internal(
+                "Database connection lost".to_string(),
+            ))])
+            .into_connection();
+        let scraper_db = ScraperDb::with_connection(mock_db);
+
+        let announcement = create_test_announcement(1);
+        let meta = create_test_meta(100, 0);
+        let storables = [StorableValidatorAnnouncement {
+            announcement: &announcement,
+            meta: &meta,
+            txn_id: 1,
+        }];
+
+        let result = scraper_db
+            .store_validator_announcements(1, &H256::from_low_u64_be(999), &storables)
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_latest_validator_announcement_tx_id_found() {
+        let mock_db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([[row("tx_id", Value::BigInt(Some(42)))]])
+            .into_connection();
+        let scraper_db = ScraperDb::with_connection(mock_db);
+
+        let result = scraper_db
+            .retrieve_latest_validator_announcement_tx_id(1, &H256::from_low_u64_be(999))
+            .await;
+
+        assert_eq!(result.unwrap(), Some(42));
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_latest_validator_announcement_tx_id_not_found() {
+        let mock_db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<BTreeMap<&str, Value>>::new()])
+            .into_connection();
+        let scraper_db = ScraperDb::with_connection(mock_db);
+
+        let result = scraper_db
+            .retrieve_latest_validator_announcement_tx_id(1, &H256::from_low_u64_be(999))
+            .await;
+
+        assert_eq!(result.unwrap(), None);
+    }
+}
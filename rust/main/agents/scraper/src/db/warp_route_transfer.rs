@@ -0,0 +1,149 @@
+use eyre::Result;
+use hyperlane_warp_route::TokenMessage;
+use itertools::Itertools;
+use sea_orm::{prelude::*, ActiveValue::*, Insert};
+use tracing::{debug, instrument, trace};
+
+use hyperlane_core::{address_to_bytes, h256_to_bytes, HyperlaneMessage};
+use migration::OnConflict;
+
+use crate::conversions::u256_to_decimal;
+use crate::date_time;
+use crate::db::ScraperDb;
+
+use super::generated::warp_route_transfer;
+
+#[derive(Debug)]
+pub struct StorableWarpRouteTransfer<'a> {
+    pub msg: &'a HyperlaneMessage,
+    /// The transfer decoded from the message body
+    pub token_message: TokenMessage,
+    /// The database id of the transaction the transfer was dispatched in
+    pub txn_id: i64,
+}
+
+impl ScraperDb {
+    /// Store decoded warp route transfers into the database (or update an
+    /// existing one).
+    #[instrument(skip_all)]
+    pub async fn store_warp_route_transfers(
+        &self,
+        transfers: &[StorableWarpRouteTransfer<'_>],
+    ) -> Result<u64> {
+        let models = transfers
+            .iter()
+            .map(|storable| warp_route_transfer::ActiveModel {
+                id: NotSet,
+                time_created: Set(date_time::now()),
+                msg_id: Unchanged(h256_to_bytes(&storable.msg.id())),
+                origin: Unchanged(storable.msg.origin as i32),
+                destination: Unchanged(storable.msg.destination as i32),
+                origin_token: Unchanged(address_to_bytes(&storable.msg.sender)),
+                destination_token: Unchanged(address_to_bytes(&storable.msg.recipient)),
+                recipient: Unchanged(address_to_bytes(&storable.token_message.recipient())),
+                amount: Unchanged(u256_to_decimal(storable.token_message.amount())),
+                metadata: Unchanged(storable.token_message.metadata().to_vec()),
+                origin_tx_id: Set(storable.txn_id),
+            })
+            .collect_vec();
+
+        trace!(?models, "Writing warp route transfers to database");
+
+        if models.is_empty() {
+            debug!("Wrote zero new warp route transfers to database");
+            return Ok(0);
+        }
+
+        let stored = Insert::many(models)
+            .on_conflict(
+                OnConflict::columns([warp_route_transfer::Column::MsgId])
+                    .update_columns([
+                        warp_route_transfer::Column::TimeCreated,
+                        warp_route_transfer::Column::OriginTxId,
+                    ])
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.0)
+            .await?;
+
+        debug!(transfers = stored, "Wrote warp route transfers to database");
+        Ok(stored)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{DatabaseBackend, DbErr, MockDatabase, MockExecResult, RuntimeErr};
+
+    use hyperlane_core::{Encode, HyperlaneMessage, H256, U256};
+    use hyperlane_warp_route::TokenMessage;
+
+    use crate::db::ScraperDb;
+
+    use super::StorableWarpRouteTransfer;
+
+    fn create_test_message(nonce: u32) -> HyperlaneMessage {
+        HyperlaneMessage {
+            nonce,
+            body: TokenMessage::new(H256::repeat_byte(0x44), U256::from(1000), vec![]).to_vec(),
+            ..Default::default()
+        }
+    }
+
+    fn create_storables(messages: &[HyperlaneMessage]) -> Vec<StorableWarpRouteTransfer<'_>> {
+        messages
+            .iter()
+            .map(|msg| StorableWarpRouteTransfer {
+                msg,
+                token_message: TokenMessage::new(H256::repeat_byte(0x44), U256::from(1000), vec![]),
+                txn_id: 1,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_store_warp_route_transfers_empty_returns_zero() {
+        let mock_db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let scraper_db = ScraperDb::with_connection(mock_db);
+
+        let result = scraper_db.store_warp_route_transfers(&[]).await;
+
+        assert_eq!(result.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_store_warp_route_transfers_returns_affected_rows() {
+        let mock_db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }]) // INSERT
+            .into_connection();
+        let scraper_db = ScraperDb::with_connection(mock_db);
+
+        // the count comes from the database rather than from the input
+        let messages = [create_test_message(1), create_test_message(2)];
+        let result = scraper_db
+            .store_warp_route_transfers(&create_storables(&messages))
+            .await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_store_warp_route_transfers_handles_db_error() {
+        let mock_db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_errors([DbErr::Exec(RuntimeErr::Internal(
+                "Database connection lost".to_string(),
+            ))])
+            .into_connection();
+        let scraper_db = ScraperDb::with_connection(mock_db);
+
+        let messages = [create_test_message(1)];
+        let result = scraper_db
+            .store_warp_route_transfers(&create_storables(&messages))
+            .await;
+
+        assert!(result.is_err());
+    }
+}
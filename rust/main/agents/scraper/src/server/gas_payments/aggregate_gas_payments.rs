@@ -0,0 +1,112 @@
+use axum::extract::{Query, State};
+use serde::{Deserialize, Serialize};
+
+use hyperlane_base::server::utils::{ServerResult, ServerSuccessResponse};
+use hyperlane_core::U256;
+
+use crate::db::GasPaymentFilter;
+use crate::server::{gas_payments::ServerState, internal_error, parse_h256};
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct QueryParams {
+    pub message_id: Option<String>,
+    pub origin: Option<u32>,
+    pub destination: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ResponseBody {
+    pub payment: U256,
+    pub gas_amount: U256,
+    pub count: u64,
+}
+
+/// Sum the gas payments made for a message or a route
+pub async fn handler(
+    State(state): State<ServerState>,
+    Query(query_params): Query<QueryParams>,
+) -> ServerResult<ServerSuccessResponse<ResponseBody>> {
+    tracing::debug!(?query_params, "Aggregating gas payments");
+
+    let filter = GasPaymentFilter {
+        msg_id: parse_h256("message_id", query_params.message_id.as_deref())?,
+        origin: query_params.origin,
+        destination: query_params.destination,
+    };
+    let totals = state
+        .db
+        .retrieve_gas_payment_totals(&filter)
+        .await
+        .map_err(internal_error)?;
+
+    Ok(ServerSuccessResponse::new(ResponseBody {
+        payment: totals.payment,
+        gas_amount: totals.gas_amount,
+        count: totals.count,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use axum::{
+        body::{to_bytes, Body},
+        http::{Request, StatusCode},
+    };
+    use sea_orm::{prelude::BigDecimal, DatabaseBackend, MockDatabase, Value};
+    use tower::ServiceExt;
+
+    use crate::db::ScraperDb;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_aggregate_gas_payments() {
+        let row: BTreeMap<&str, Value> = [
+            ("payment", Value::from(BigDecimal::from(300))),
+            ("gas_amount", Value::from(BigDecimal::from(150_000))),
+            ("count", Value::from(2i64)),
+        ]
+        .into_iter()
+        .collect();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([[row]])
+            .into_connection();
+        let app = ServerState::new(ScraperDb::with_connection(db)).router();
+
+        let request = Request::builder()
+            .uri("/gas_payments?origin=1&destination=2")
+            .body(Body::empty())
+            .expect("Failed to build request");
+        let response = app.oneshot(request).await.expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("Failed to read body");
+        let resp: ResponseBody =
+            serde_json::from_slice(&body).expect("Failed to deserialize response body");
+        assert_eq!(
+            resp,
+            ResponseBody {
+                payment: U256::from(300),
+                gas_amount: U256::from(150_000),
+                count: 2,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_gas_payments_invalid_message_id() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let app = ServerState::new(ScraperDb::with_connection(db)).router();
+
+        let request = Request::builder()
+            .uri("/gas_payments?message_id=not-a-message-id")
+            .body(Body::empty())
+            .expect("Failed to build request");
+        let response = app.oneshot(request).await.expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
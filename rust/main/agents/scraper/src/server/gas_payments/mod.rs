@@ -0,0 +1,19 @@
+use axum::{routing::get, Router};
+use derive_new::new;
+
+use crate::db::ScraperDb;
+
+pub mod aggregate_gas_payments;
+
+#[derive(Clone, Debug, new)]
+pub struct ServerState {
+    pub db: ScraperDb,
+}
+
+impl ServerState {
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/gas_payments", get(aggregate_gas_payments::handler))
+            .with_state(self)
+    }
+}
@@ -0,0 +1,94 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use hyperlane_base::server::utils::{
+    ServerErrorBody, ServerErrorResponse, ServerResult, ServerSuccessResponse,
+};
+
+use crate::db::MessageFilter;
+use crate::server::{
+    internal_error,
+    messages::{MessageResponse, ServerState},
+    parse_h256,
+};
+
+/// Fetch a single dispatched message by its message id
+pub async fn handler(
+    State(state): State<ServerState>,
+    Path(message_id): Path<String>,
+) -> ServerResult<ServerSuccessResponse<MessageResponse>> {
+    tracing::debug!(message_id, "Fetching message");
+
+    let filter = MessageFilter {
+        msg_id: parse_h256("message_id", Some(&message_id))?,
+        ..Default::default()
+    };
+    let message = state
+        .db
+        .retrieve_messages(&filter, None, 1)
+        .await
+        .map_err(internal_error)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            ServerErrorResponse::new(
+                StatusCode::NOT_FOUND,
+                ServerErrorBody {
+                    message: "Message not found".to_string(),
+                },
+            )
+        })?;
+
+    Ok(ServerSuccessResponse::new(message.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use axum::{
+        body::Body,
+        http::{Request, Response, StatusCode},
+    };
+    use sea_orm::{DatabaseBackend, MockDatabase, Value};
+    use tower::ServiceExt;
+
+    use crate::db::ScraperDb;
+
+    use super::*;
+
+    async fn send_request(db: ScraperDb, message_id: &str) -> Response<Body> {
+        let app = ServerState::new(db).router();
+        let request = Request::builder()
+            .uri(format!("/messages/{message_id}"))
+            .body(Body::empty())
+            .expect("Failed to build request");
+        app.oneshot(request).await.expect("Failed to send request")
+    }
+
+    #[tokio::test]
+    async fn test_get_message_not_found() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<BTreeMap<&str, Value>>::new()])
+            .into_connection();
+
+        let response = send_request(
+            ScraperDb::with_connection(db),
+            "0x1111111111111111111111111111111111111111111111111111111111111111",
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_get_message_invalid_id() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+
+        let response = send_request(ScraperDb::with_connection(db), "0x1234").await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
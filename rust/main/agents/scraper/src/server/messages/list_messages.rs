@@ -0,0 +1,50 @@
+use axum::extract::{Query, State};
+use serde::Deserialize;
+
+use hyperlane_base::server::utils::{ServerResult, ServerSuccessResponse};
+
+use crate::db::MessageFilter;
+use crate::server::{
+    internal_error,
+    messages::{MessagePage, ServerState},
+    page_limit, parse_h256, parse_tx_hash,
+};
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct QueryParams {
+    pub sender: Option<String>,
+    pub recipient: Option<String>,
+    pub origin_tx_hash: Option<String>,
+    pub origin: Option<u32>,
+    pub destination: Option<u32>,
+    /// Only return messages with a database id greater than this cursor
+    pub after: Option<i64>,
+    pub limit: Option<u64>,
+}
+
+/// List dispatched messages, optionally filtered by sender, recipient,
+/// origin transaction hash and route
+pub async fn handler(
+    State(state): State<ServerState>,
+    Query(query_params): Query<QueryParams>,
+) -> ServerResult<ServerSuccessResponse<MessagePage>> {
+    tracing::debug!(?query_params, "Listing messages");
+
+    let filter = MessageFilter {
+        sender: parse_h256("sender", query_params.sender.as_deref())?,
+        recipient: parse_h256("recipient", query_params.recipient.as_deref())?,
+        origin_tx_hash: parse_tx_hash("origin_tx_hash", query_params.origin_tx_hash.as_deref())?,
+        origin: query_params.origin,
+        destination: query_params.destination,
+        ..Default::default()
+    };
+    let limit = page_limit(query_params.limit);
+    let messages = state
+        .db
+        .retrieve_messages(&filter, query_params.after, limit)
+        .await
+        .map_err(internal_error)?;
+
+    let page = MessagePage::new(messages, limit);
+    Ok(ServerSuccessResponse::new(page))
+}
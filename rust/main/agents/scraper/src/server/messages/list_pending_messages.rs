@@ -0,0 +1,97 @@
+use axum::extract::{Query, State};
+use serde::Deserialize;
+
+use hyperlane_base::server::utils::{ServerResult, ServerSuccessResponse};
+
+use crate::db::MessageFilter;
+use crate::server::{
+    internal_error,
+    messages::{MessagePage, ServerState},
+    page_limit,
+};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct QueryParams {
+    pub origin: u32,
+    pub destination: u32,
+    /// Only return messages with a database id greater than this cursor
+    pub after: Option<i64>,
+    pub limit: Option<u64>,
+}
+
+/// List messages dispatched on a route which have not been seen delivered
+/// on the destination yet
+pub async fn handler(
+    State(state): State<ServerState>,
+    Query(query_params): Query<QueryParams>,
+) -> ServerResult<ServerSuccessResponse<MessagePage>> {
+    tracing::debug!(?query_params, "Listing pending messages");
+
+    let filter = MessageFilter {
+        origin: Some(query_params.origin),
+        destination: Some(query_params.destination),
+        undelivered_only: true,
+        ..Default::default()
+    };
+    let limit = page_limit(query_params.limit);
+    let messages = state
+        .db
+        .retrieve_messages(&filter, query_params.after, limit)
+        .await
+        .map_err(internal_error)?;
+
+    let page = MessagePage::new(messages, limit);
+    Ok(ServerSuccessResponse::new(page))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use axum::{
+        body::{to_bytes, Body},
+        http::{Request, StatusCode},
+    };
+    use sea_orm::{DatabaseBackend, MockDatabase, Value};
+    use tower::ServiceExt;
+
+    use crate::db::ScraperDb;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_list_pending_messages_empty() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<BTreeMap<&str, Value>>::new()])
+            .into_connection();
+        let app = ServerState::new(ScraperDb::with_connection(db)).router();
+
+        let request = Request::builder()
+            .uri("/messages/pending?origin=1&destination=2&limit=10")
+            .body(Body::empty())
+            .expect("Failed to build request");
+        let response = app.oneshot(request).await.expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("Failed to read body");
+        let page: MessagePage =
+            serde_json::from_slice(&body).expect("Failed to deserialize response body");
+        assert!(page.messages.is_empty());
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn test_list_pending_messages_requires_route() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let app = ServerState::new(ScraperDb::with_connection(db)).router();
+
+        let request = Request::builder()
+            .uri("/messages/pending?origin=1")
+            .body(Body::empty())
+            .expect("Failed to build request");
+        let response = app.oneshot(request).await.expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,70 @@
+use axum::{routing::get, Router};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use hyperlane_core::{HyperlaneMessage, H256, H512};
+
+use crate::db::{DispatchedMessage, ScraperDb};
+
+pub mod get_message;
+pub mod list_messages;
+pub mod list_pending_messages;
+
+#[derive(Clone, Debug, new)]
+pub struct ServerState {
+    pub db: ScraperDb,
+}
+
+impl ServerState {
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/messages", get(list_messages::handler))
+            .route("/messages/pending", get(list_pending_messages::handler))
+            .route("/messages/{message_id}", get(get_message::handler))
+            .with_state(self)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct MessageResponse {
+    /// Database id of the message, usable as a pagination cursor
+    pub id: i64,
+    pub message_id: String,
+    pub message: HyperlaneMessage,
+    pub origin_mailbox: H256,
+    pub origin_tx_hash: Option<H512>,
+}
+
+impl From<DispatchedMessage> for MessageResponse {
+    fn from(dispatched: DispatchedMessage) -> Self {
+        Self {
+            id: dispatched.id,
+            message_id: format!("{:x}", dispatched.message.id()),
+            message: dispatched.message,
+            origin_mailbox: dispatched.origin_mailbox,
+            origin_tx_hash: dispatched.origin_tx_hash,
+        }
+    }
+}
+
+/// A page of messages. `next_cursor` is set when more results may be
+/// available and should be passed back as `after` to fetch the next page.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessagePage {
+    pub messages: Vec<MessageResponse>,
+    pub next_cursor: Option<i64>,
+}
+
+impl MessagePage {
+    pub fn new(messages: Vec<DispatchedMessage>, limit: u64) -> Self {
+        let next_cursor = if messages.len() as u64 >= limit {
+            messages.last().map(|m| m.id)
+        } else {
+            None
+        };
+        Self {
+            messages: messages.into_iter().map(Into::into).collect(),
+            next_cursor,
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Read-only HTTP API over the data the scraper writes to its database.
+
+use std::str::FromStr;
+
+use axum::{http::StatusCode, Router};
+use derive_new::new;
+
+use hyperlane_base::server::utils::{ServerErrorBody, ServerErrorResponse, ServerResult};
+use hyperlane_core::{bytes_to_h512, utils::hex_or_base58_or_bech32_to_h256, H256, H512};
+
+use crate::db::ScraperDb;
+
+pub mod gas_payments;
+pub mod messages;
+
+/// Number of items returned per page when the caller does not ask for a limit
+pub const DEFAULT_PAGE_LIMIT: u64 = 100;
+/// Upper bound on the number of items returned per page
+pub const MAX_PAGE_LIMIT: u64 = 1000;
+
+#[derive(Clone, Debug, new)]
+pub struct Server {
+    db: ScraperDb,
+}
+
+impl Server {
+    // return a custom router that can be used in combination with other routers
+    pub fn router(self) -> Router {
+        Router::new()
+            .merge(messages::ServerState::new(self.db.clone()).router())
+            .merge(gas_payments::ServerState::new(self.db).router())
+    }
+}
+
+/// Clamp a requested page size to `1..=MAX_PAGE_LIMIT`
+pub fn page_limit(limit: Option<u64>) -> u64 {
+    limit.unwrap_or(DEFAULT_PAGE_LIMIT).clamp(1, MAX_PAGE_LIMIT)
+}
+
+pub fn bad_request(message: impl Into<String>) -> ServerErrorResponse {
+    ServerErrorResponse::new(
+        StatusCode::BAD_REQUEST,
+        ServerErrorBody {
+            message: message.into(),
+        },
+    )
+}
+
+pub fn internal_error(err: eyre::Report) -> ServerErrorResponse {
+    let error_msg = "Failed to query database";
+    tracing::warn!(?err, "{error_msg}");
+    ServerErrorResponse::new(
+        StatusCode::INTERNAL_SERVER_ERROR,
+        ServerErrorBody {
+            message: error_msg.to_string(),
+        },
+    )
+}
+
+/// Parse an optional address or message id query parameter
+pub fn parse_h256(name: &str, value: Option<&str>) -> ServerResult<Option<H256>> {
+    value
+        .map(|value| {
+            hex_or_base58_or_bech32_to_h256(value).map_err(|err| {
+                tracing::debug!(name, value, ?err, "Failed to parse query parameter");
+                bad_request(format!("Failed to parse {name}"))
+            })
+        })
+        .transpose()
+}
+
+/// Parse an optional transaction hash query parameter. Accepts 32 or 64 byte
+/// hashes, either hex encoded with a `0x` prefix or base58 encoded.
+pub fn parse_tx_hash(name: &str, value: Option<&str>) -> ServerResult<Option<H512>> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    let parsed = if value.starts_with("0x") {
+        match value.len() {
+            66 => H256::from_str(value)
+                .map(|hash| bytes_to_h512(hash.as_bytes()))
+                .ok(),
+            130 => H512::from_str(value).ok(),
+            _ => None,
+        }
+    } else {
+        bs58::decode(value)
+            .into_vec()
+            .ok()
+            .filter(|bytes| bytes.len() == 32 || bytes.len() == 64)
+            .map(|bytes| bytes_to_h512(&bytes))
+    };
+    parsed.map(Some).ok_or_else(|| {
+        tracing::debug!(name, value, "Failed to parse query parameter");
+        bad_request(format!("Failed to parse {name}"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_limit() {
+        assert_eq!(page_limit(None), DEFAULT_PAGE_LIMIT);
+        assert_eq!(page_limit(Some(0)), 1);
+        assert_eq!(page_limit(Some(10)), 10);
+        assert_eq!(page_limit(Some(MAX_PAGE_LIMIT + 1)), MAX_PAGE_LIMIT);
+    }
+
+    #[test]
+    fn test_parse_tx_hash() {
+        let evm = "0x4b6a7e3a1b4b0c2d4e6f8091a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5";
+        let parsed = parse_tx_hash("tx_hash", Some(evm)).unwrap().unwrap();
+        assert_eq!(
+            parsed,
+            bytes_to_h512(H256::from_str(evm).unwrap().as_bytes())
+        );
+
+        let signature = bs58::encode([7u8; 64]).into_string();
+        let parsed = parse_tx_hash("tx_hash", Some(&signature)).unwrap().unwrap();
+        assert_eq!(parsed, H512::from_slice(&[7u8; 64]));
+
+        assert!(parse_tx_hash("tx_hash", Some("0x1234")).is_err());
+        assert!(parse_tx_hash("tx_hash", None).unwrap().is_none());
+    }
+}
@@ -11,11 +11,11 @@ use eyre::Context;
 use hyperlane_base::{
     impl_loadable_from_settings,
     settings::{
-        parser::{RawAgentConf, ValueParser},
+        parser::{parse_matching_list, RawAgentConf, ValueParser},
         Settings,
     },
 };
-use hyperlane_core::{cfg_unwrap_all, config::*, HyperlaneDomain};
+use hyperlane_core::{cfg_unwrap_all, config::*, matching_list::MatchingList, HyperlaneDomain};
 use serde::Deserialize;
 use serde_json::Value;
 
@@ -30,6 +30,9 @@ pub struct ScraperSettings {
 
     pub db: String,
     pub chains_to_scrape: Vec<HyperlaneDomain>,
+    /// Messages sent between warp route contracts, whose bodies are decoded
+    /// into transfers. No transfers are decoded if this is empty.
+    pub warp_routes: MatchingList,
 }
 
 #[derive(Debug, Deserialize)]
@@ -85,12 +88,19 @@ impl FromRawConf<RawScraperSettings> for ScraperSettings {
             Default::default()
         };
 
+        let warp_routes = p
+            .chain(&mut err)
+            .get_opt_key("warpRoutes")
+            .and_then(parse_matching_list)
+            .unwrap_or_default();
+
         cfg_unwrap_all!(&p.cwp, err: [base, db]);
 
         err.into_result(Self {
             base,
             db,
             chains_to_scrape,
+            warp_routes,
         })
     }
 }
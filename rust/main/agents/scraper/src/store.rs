@@ -1,6 +1,11 @@
+pub use announcements::HyperlaneDbAnnouncementStore;
 pub use storage::HyperlaneDbStore;
 
+mod announcements;
 mod deliveries;
 mod dispatches;
+mod insertions;
 mod payments;
 mod storage;
+#[cfg(test)]
+mod test_utils;
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use eyre::Result;
+use itertools::Itertools;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+use hyperlane_core::{
+    unwrap_or_none_result, HyperlaneLogStore, HyperlaneWatermarkedLogStore, Indexed, LogMeta,
+    ValidatorAnnouncement, H512,
+};
+
+use crate::db::StorableValidatorAnnouncement;
+use crate::store::storage::{HyperlaneDbStore, TxnWithId};
+
+/// Store for validator announcements.
+///
+/// Announcements are indexed with a watermark of their own instead of the
+/// block cursor shared by the other events of the domain. That cursor is
+/// usually far past the validator announce deployment by the time
+/// announcements are scraped, so sharing it would skip every announcement
+/// made before then.
+#[derive(Debug, Clone)]
+pub struct HyperlaneDbAnnouncementStore {
+    store: HyperlaneDbStore,
+    watermark: Arc<RwLock<Option<u32>>>,
+}
+
+impl HyperlaneDbAnnouncementStore {
+    pub fn new(store: HyperlaneDbStore) -> Self {
+        Self {
+            store,
+            watermark: Default::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl HyperlaneLogStore<ValidatorAnnouncement> for HyperlaneDbAnnouncementStore {
+    /// Store validator announcements into the database.
+    /// We store only announcements from blocks and transaction which we could
+    /// successfully insert into database.
+    async fn store_logs(
+        &self,
+        announcements: &[(Indexed<ValidatorAnnouncement>, LogMeta)],
+    ) -> Result<u32> {
+        if announcements.is_empty() {
+            return Ok(0);
+        }
+        let txns: HashMap<H512, TxnWithId> = self
+            .store
+            .ensure_blocks_and_txns(announcements.iter().map(|r| &r.1))
+            .await?
+            .map(|t| (t.hash, t))
+            .collect();
+        let storable = announcements
+            .iter()
+            .filter_map(|(announcement, meta)| {
+                txns.get(&meta.transaction_id)
+                    .map(|txn| StorableValidatorAnnouncement {
+                        announcement: announcement.inner(),
+                        meta,
+                        txn_id: txn.id,
+                    })
+            })
+            .collect_vec();
+
+        debug!(
+            domain = self.store.domain.id(),
+            validator_announce_address = ?self.store.validator_announce_address,
+            ?storable,
+            "storable validator announcements",
+        );
+
+        let stored = self
+            .store
+            .db
+            .store_validator_announcements(
+                self.store.domain.id(),
+                &self.store.validator_announce_address,
+                &storable,
+            )
+            .await?;
+        Ok(stored as u32)
+    }
+}
+
+#[async_trait]
+impl HyperlaneWatermarkedLogStore<ValidatorAnnouncement> for HyperlaneDbAnnouncementStore {
+    /// Gets the block number high watermark. Until one is stored, this is the
+    /// block of the latest announcement in the database, if any, so that
+    /// indexing otherwise starts from the configured `from` block.
+    async fn retrieve_high_watermark(&self) -> Result<Option<u32>> {
+        if let Some(watermark) = *self.watermark.read().await {
+            return Ok(Some(watermark));
+        }
+        let db = &self.store.db;
+        let tx_id = unwrap_or_none_result!(
+            db.retrieve_latest_validator_announcement_tx_id(
+                self.store.domain.id(),
+                &self.store.validator_announce_address,
+            )
+            .await?
+        );
+        let block_id = unwrap_or_none_result!(db.retrieve_block_id(tx_id).await?);
+        let block_number = unwrap_or_none_result!(db.retrieve_block_number(block_id).await?);
+        Ok(Some(block_number.try_into()?))
+    }
+
+    /// Stores the block number high watermark
+    async fn store_high_watermark(&self, block_number: u32) -> Result<()> {
+        let mut watermark = self.watermark.write().await;
+        *watermark = Some(watermark.map_or(block_number, |w| w.max(block_number)));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use sea_orm::Value;
+
+    use hyperlane_core::{HyperlaneLogStore, HyperlaneWatermarkedLogStore, ValidatorAnnouncement};
+
+    use crate::store::test_utils::{mock_db, row, test_store};
+
+    use super::HyperlaneDbAnnouncementStore;
+
+    #[tokio::test]
+    async fn test_store_logs_empty_returns_zero() {
+        let store = HyperlaneDbAnnouncementStore::new(test_store(mock_db()).await);
+
+        let stored = HyperlaneLogStore::<ValidatorAnnouncement>::store_logs(&store, &[])
+            .await
+            .unwrap();
+
+        assert_eq!(stored, 0);
+    }
+
+    #[tokio::test]
+    async fn test_watermark_is_none_without_announcements() {
+        // retrieve_latest_validator_announcement_tx_id
+        let db = mock_db().append_query_results([Vec::<BTreeMap<&str, Value>>::new()]);
+        let store = HyperlaneDbAnnouncementStore::new(test_store(db).await);
+
+        let watermark = store.retrieve_high_watermark().await.unwrap();
+
+        // the cursor then starts from the configured `from` block, rather than
+        // the block cursor shared with the other events of the domain
+        assert_eq!(watermark, None);
+    }
+
+    #[tokio::test]
+    async fn test_watermark_resumes_from_latest_announcement_block() {
+        let db = mock_db()
+            .append_query_results([[row("tx_id", Value::BigInt(Some(42)))]]) // retrieve_latest_validator_announcement_tx_id
+            .append_query_results([[row("block_id", Value::BigInt(Some(7)))]]) // retrieve_block_id
+            .append_query_results([[row("height", Value::BigInt(Some(1234)))]]); // retrieve_block_number
+        let store = HyperlaneDbAnnouncementStore::new(test_store(db).await);
+
+        let watermark = store.retrieve_high_watermark().await.unwrap();
+
+        assert_eq!(watermark, Some(1234));
+    }
+
+    #[tokio::test]
+    async fn test_stored_watermark_takes_precedence_and_never_decreases() {
+        let store = HyperlaneDbAnnouncementStore::new(test_store(mock_db()).await);
+
+        store.store_high_watermark(500).await.unwrap();
+        store.store_high_watermark(300).await.unwrap();
+
+        // no database query is made once a watermark was stored
+        assert_eq!(store.retrieve_high_watermark().await.unwrap(), Some(500));
+    }
+}
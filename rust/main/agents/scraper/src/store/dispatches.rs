@@ -3,12 +3,14 @@ use std::collections::HashMap;
 use async_trait::async_trait;
 use eyre::{Report, Result};
 use hyperlane_core::{
-    unwrap_or_none_result, HyperlaneLogStore, HyperlaneMessage,
+    unwrap_or_none_result, Decode, HyperlaneLogStore, HyperlaneMessage,
     HyperlaneSequenceAwareIndexerStoreReader, Indexed, LogMeta, H512,
 };
+use hyperlane_warp_route::TokenMessage;
+use itertools::Itertools;
 use tracing::warn;
 
-use crate::db::{StorableMessage, StorableRawMessageDispatch};
+use crate::db::{StorableMessage, StorableRawMessageDispatch, StorableWarpRouteTransfer};
 use crate::store::storage::{HyperlaneDbStore, TxnWithId};
 
 /// Label for raw message dispatch metrics
@@ -86,8 +88,40 @@ impl HyperlaneDbStore {
             .db
             .store_dispatched_messages(self.domain.id(), &self.mailbox_address, storable)
             .await?;
+
+        let transfers = messages
+            .iter()
+            .filter_map(|(message, meta)| {
+                txns.get(&meta.transaction_id)
+                    .and_then(|t| self.decode_warp_route_transfer(message.inner(), t.id))
+            })
+            .collect_vec();
+        self.db.store_warp_route_transfers(&transfers).await?;
+
         Ok(stored as u32)
     }
+
+    /// Decode the transfer of a message sent by a configured warp route
+    fn decode_warp_route_transfer<'a>(
+        &self,
+        msg: &'a HyperlaneMessage,
+        txn_id: i64,
+    ) -> Option<StorableWarpRouteTransfer<'a>> {
+        if !self.warp_routes.msg_matches(msg, false) {
+            return None;
+        }
+        match TokenMessage::read_from(&mut msg.body.as_slice()) {
+            Ok(token_message) => Some(StorableWarpRouteTransfer {
+                msg,
+                token_message,
+                txn_id,
+            }),
+            Err(err) => {
+                warn!(?err, msg_id = ?msg.id(), "Failed to decode warp route transfer");
+                None
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -112,3 +146,73 @@ impl HyperlaneSequenceAwareIndexerStoreReader<HyperlaneMessage> for HyperlaneDbS
         Ok(self.db.retrieve_block_number(block_id).await?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use hyperlane_core::{matching_list::MatchingList, Encode, H256, U256};
+    use hyperlane_warp_route::TokenMessage;
+
+    use crate::store::test_utils::{mock_db, test_store_with_warp_routes};
+
+    use super::*;
+
+    const WARP_ROUTE: H256 = H256::repeat_byte(0x33);
+
+    fn warp_routes() -> MatchingList {
+        serde_json::from_str(&format!(r#"[{{"senderaddress": "{WARP_ROUTE:?}"}}]"#)).unwrap()
+    }
+
+    fn message(sender: H256, body: Vec<u8>) -> HyperlaneMessage {
+        HyperlaneMessage {
+            sender,
+            body,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_decode_warp_route_transfer() {
+        let store = test_store_with_warp_routes(mock_db(), warp_routes()).await;
+        let body =
+            TokenMessage::new(H256::repeat_byte(0x44), U256::from(1000), vec![1, 2]).to_vec();
+        let msg = message(WARP_ROUTE, body);
+
+        let transfer = store.decode_warp_route_transfer(&msg, 7).unwrap();
+
+        assert_eq!(transfer.msg.id(), msg.id());
+        assert_eq!(transfer.txn_id, 7);
+        assert_eq!(transfer.token_message.recipient(), H256::repeat_byte(0x44));
+        assert_eq!(transfer.token_message.amount(), U256::from(1000));
+        assert_eq!(transfer.token_message.metadata(), &[1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_decode_warp_route_transfer_truncated_body() {
+        let store = test_store_with_warp_routes(mock_db(), warp_routes()).await;
+        let mut body =
+            TokenMessage::new(H256::repeat_byte(0x44), U256::from(1000), vec![]).to_vec();
+        body.truncate(40);
+        let msg = message(WARP_ROUTE, body);
+
+        assert!(store.decode_warp_route_transfer(&msg, 7).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_decode_warp_route_transfer_ignores_other_senders() {
+        let store = test_store_with_warp_routes(mock_db(), warp_routes()).await;
+        let body = TokenMessage::new(H256::repeat_byte(0x44), U256::from(1000), vec![]).to_vec();
+        let msg = message(H256::repeat_byte(0x55), body);
+
+        assert!(store.decode_warp_route_transfer(&msg, 7).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_decode_warp_route_transfer_without_warp_routes() {
+        let store = test_store_with_warp_routes(mock_db(), MatchingList::default()).await;
+        let body = TokenMessage::new(H256::repeat_byte(0x44), U256::from(1000), vec![]).to_vec();
+        let msg = message(WARP_ROUTE, body);
+
+        // no message is a warp route transfer unless warp routes are configured
+        assert!(store.decode_warp_route_transfer(&msg, 7).is_none());
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use eyre::Result;
+use itertools::Itertools;
+use tracing::debug;
+
+use hyperlane_core::{
+    unwrap_or_none_result, HyperlaneLogStore, HyperlaneSequenceAwareIndexerStoreReader, Indexed,
+    LogMeta, MerkleTreeInsertion, H512,
+};
+
+use crate::db::StorableMerkleTreeInsertion;
+use crate::store::storage::{HyperlaneDbStore, TxnWithId};
+
+#[async_trait]
+impl HyperlaneLogStore<MerkleTreeInsertion> for HyperlaneDbStore {
+    /// Store merkle tree insertions into the database.
+    /// We store only insertions from blocks and transaction which we could
+    /// successfully insert into database.
+    async fn store_logs(
+        &self,
+        insertions: &[(Indexed<MerkleTreeInsertion>, LogMeta)],
+    ) -> Result<u32> {
+        if insertions.is_empty() {
+            return Ok(0);
+        }
+        let txns: HashMap<H512, TxnWithId> = self
+            .ensure_blocks_and_txns(insertions.iter().map(|r| &r.1))
+            .await?
+            .map(|t| (t.hash, t))
+            .collect();
+        let storable = insertions
+            .iter()
+            .filter_map(|(insertion, meta)| {
+                txns.get(&meta.transaction_id)
+                    .map(|txn| StorableMerkleTreeInsertion {
+                        insertion: insertion.inner(),
+                        meta,
+                        txn_id: txn.id,
+                    })
+            })
+            .collect_vec();
+
+        debug!(
+            domain = self.domain.id(),
+            merkle_tree_hook_address = ?self.merkle_tree_hook_address,
+            ?storable,
+            "storable merkle tree insertions",
+        );
+
+        let stored = self
+            .db
+            .store_merkle_tree_insertions(
+                self.domain.id(),
+                &self.merkle_tree_hook_address,
+                &storable,
+            )
+            .await?;
+        Ok(stored as u32)
+    }
+}
+
+#[async_trait]
+impl HyperlaneSequenceAwareIndexerStoreReader<MerkleTreeInsertion> for HyperlaneDbStore {
+    /// Gets a merkle tree insertion by its leaf index.
+    async fn retrieve_by_sequence(&self, sequence: u32) -> Result<Option<MerkleTreeInsertion>> {
+        let insertion = self
+            .db
+            .retrieve_merkle_tree_insertion_by_leaf_index(
+                self.domain.id(),
+                &self.merkle_tree_hook_address,
+                sequence,
+            )
+            .await?;
+        Ok(insertion)
+    }
+
+    /// Gets the block number at which the log occurred.
+    async fn retrieve_log_block_number_by_sequence(&self, sequence: u32) -> Result<Option<u64>> {
+        let tx_id = unwrap_or_none_result!(
+            self.db
+                .retrieve_merkle_tree_insertion_tx_id(
+                    self.domain.id(),
+                    &self.merkle_tree_hook_address,
+                    sequence,
+                )
+                .await?
+        );
+        let block_id = unwrap_or_none_result!(self.db.retrieve_block_id(tx_id).await?);
+        Ok(self.db.retrieve_block_number(block_id).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use sea_orm::Value;
+    use time::macros::{date, time};
+    use time::PrimitiveDateTime;
+
+    use hyperlane_core::{
+        address_to_bytes, h256_to_bytes, HyperlaneLogStore,
+        HyperlaneSequenceAwareIndexerStoreReader, MerkleTreeInsertion, H256,
+    };
+
+    use crate::store::test_utils::{mock_db, row, test_store, MERKLE_TREE_HOOK};
+
+    /// A `merkle_tree_insertion` row
+    fn insertion_row(leaf_index: i32, tx_id: i64) -> BTreeMap<&'static str, Value> {
+        [
+            ("id", Value::from(1i64)),
+            (
+                "time_created",
+                Value::from(PrimitiveDateTime::new(date!(2024 - 01 - 01), time!(0:00))),
+            ),
+            ("domain", Value::from(42161i32)),
+            (
+                "merkle_tree_hook",
+                Value::from(address_to_bytes(&MERKLE_TREE_HOOK)),
+            ),
+            ("leaf_index", Value::from(leaf_index)),
+            (
+                "msg_id",
+                Value::from(h256_to_bytes(&H256::from_low_u64_be(1000))),
+            ),
+            ("tx_id", Value::from(tx_id)),
+            ("log_index", Value::from(0i64)),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[tokio::test]
+    async fn test_store_logs_empty_returns_zero() {
+        let store = test_store(mock_db()).await;
+
+        let stored = HyperlaneLogStore::<MerkleTreeInsertion>::store_logs(&store, &[])
+            .await
+            .unwrap();
+
+        assert_eq!(stored, 0);
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_by_sequence() {
+        let db = mock_db().append_query_results([[insertion_row(7, 42)]]);
+        let store = test_store(db).await;
+
+        let insertion = store.retrieve_by_sequence(7).await.unwrap();
+
+        assert_eq!(
+            insertion,
+            Some(MerkleTreeInsertion::new(7, H256::from_low_u64_be(1000)))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_log_block_number_by_sequence() {
+        let db = mock_db()
+            .append_query_results([[insertion_row(7, 42)]]) // retrieve_merkle_tree_insertion_tx_id
+            .append_query_results([[row("block_id", Value::BigInt(Some(3)))]]) // retrieve_block_id
+            .append_query_results([[row("height", Value::BigInt(Some(1234)))]]); // retrieve_block_number
+        let store = test_store(db).await;
+
+        let block_number = store
+            .retrieve_log_block_number_by_sequence(7)
+            .await
+            .unwrap();
+
+        assert_eq!(block_number, Some(1234));
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_log_block_number_by_sequence_not_found() {
+        let db = mock_db().append_query_results([Vec::<BTreeMap<&str, Value>>::new()]);
+        let store = test_store(db).await;
+
+        let block_number = store
+            .retrieve_log_block_number_by_sequence(7)
+            .await
+            .unwrap();
+
+        assert_eq!(block_number, None);
+    }
+}
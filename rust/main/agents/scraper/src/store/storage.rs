@@ -13,8 +13,8 @@ use tracing::{trace, warn};
 
 use hyperlane_base::settings::IndexSettings;
 use hyperlane_core::{
-    BlockId, BlockInfo, HyperlaneDomain, HyperlaneLogStore, HyperlaneProvider,
-    HyperlaneWatermarkedLogStore, LogMeta, H256, H512,
+    matching_list::MatchingList, BlockId, BlockInfo, HyperlaneDomain, HyperlaneLogStore,
+    HyperlaneProvider, HyperlaneWatermarkedLogStore, LogMeta, H256, H512,
 };
 
 use crate::db::{BasicBlock, BlockCursor, ScraperDb, StorableTxn};
@@ -33,6 +33,10 @@ pub struct HyperlaneDbStore {
     pub(crate) domain: HyperlaneDomain,
     pub(crate) mailbox_address: H256,
     pub(crate) interchain_gas_paymaster_address: H256,
+    pub(crate) merkle_tree_hook_address: H256,
+    pub(crate) validator_announce_address: H256,
+    /// Messages sent between warp route contracts, which are decoded into transfers
+    pub(crate) warp_routes: Arc<MatchingList>,
     provider: Arc<dyn HyperlaneProvider>,
     cursor: Arc<BlockCursor>,
     /// Metric for tracking raw message dispatches stored (used for CCTP availability)
@@ -46,6 +50,9 @@ impl HyperlaneDbStore {
         domain: HyperlaneDomain,
         mailbox_address: H256,
         interchain_gas_paymaster_address: H256,
+        merkle_tree_hook_address: H256,
+        validator_announce_address: H256,
+        warp_routes: Arc<MatchingList>,
         provider: Arc<dyn HyperlaneProvider>,
         index_settings: &IndexSettings,
         stored_events_metric: Option<IntCounterVec>,
@@ -59,6 +66,9 @@ impl HyperlaneDbStore {
             domain,
             mailbox_address,
             interchain_gas_paymaster_address,
+            merkle_tree_hook_address,
+            validator_announce_address,
+            warp_routes,
             provider,
             cursor,
             stored_events_metric,
@@ -0,0 +1,99 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::{DatabaseBackend, MockDatabase, Value};
+
+use hyperlane_base::settings::IndexSettings;
+use hyperlane_core::{
+    matching_list::MatchingList, BlockInfo, ChainInfo, ChainResult, HyperlaneChain,
+    HyperlaneDomain, HyperlaneProvider, IndexMode, KnownHyperlaneDomain, TxnInfo, H256, H512, U256,
+};
+
+use crate::db::ScraperDb;
+use crate::store::HyperlaneDbStore;
+
+pub(crate) const MERKLE_TREE_HOOK: H256 = H256::repeat_byte(0x11);
+pub(crate) const VALIDATOR_ANNOUNCE: H256 = H256::repeat_byte(0x22);
+
+/// A provider for stores whose tests never reach the chain
+#[derive(Debug, Clone)]
+struct TestProvider {
+    domain: HyperlaneDomain,
+}
+
+impl HyperlaneChain for TestProvider {
+    fn domain(&self) -> &HyperlaneDomain {
+        &self.domain
+    }
+
+    fn provider(&self) -> Box<dyn HyperlaneProvider> {
+        Box::new(self.clone())
+    }
+}
+
+#[async_trait]
+impl HyperlaneProvider for TestProvider {
+    async fn get_block_by_height(&self, _height: u64) -> ChainResult<BlockInfo> {
+        unimplemented!()
+    }
+
+    async fn get_txn_by_hash(&self, _hash: &H512) -> ChainResult<TxnInfo> {
+        unimplemented!()
+    }
+
+    async fn is_contract(&self, _address: &H256) -> ChainResult<bool> {
+        unimplemented!()
+    }
+
+    async fn get_balance(&self, _address: String) -> ChainResult<U256> {
+        unimplemented!()
+    }
+
+    async fn get_chain_metrics(&self) -> ChainResult<Option<ChainInfo>> {
+        unimplemented!()
+    }
+}
+
+/// A single row mock query result
+pub(crate) fn row(column: &'static str, value: Value) -> BTreeMap<&'static str, Value> {
+    [(column, value)].into_iter().collect()
+}
+
+/// A mock database which has already answered the block cursor query made
+/// when the store is created
+pub(crate) fn mock_db() -> MockDatabase {
+    MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([[row("height", Value::BigInt(Some(100)))]])
+}
+
+/// Creates a store on top of the given mock database, see [`mock_db`]
+pub(crate) async fn test_store(db: MockDatabase) -> HyperlaneDbStore {
+    test_store_with_warp_routes(db, MatchingList::default()).await
+}
+
+/// Like [`test_store`], with the given warp routes
+pub(crate) async fn test_store_with_warp_routes(
+    db: MockDatabase,
+    warp_routes: MatchingList,
+) -> HyperlaneDbStore {
+    let domain = HyperlaneDomain::Known(KnownHyperlaneDomain::Arbitrum);
+    HyperlaneDbStore::new(
+        ScraperDb::with_connection(db.into_connection()),
+        domain.clone(),
+        H256::zero(),
+        H256::zero(),
+        MERKLE_TREE_HOOK,
+        VALIDATOR_ANNOUNCE,
+        Arc::new(warp_routes),
+        Arc::new(TestProvider { domain }),
+        &IndexSettings {
+            from: 0,
+            chunk_size: 1,
+            mode: IndexMode::Block,
+        },
+        None,
+    )
+    .await
+    .unwrap()
+}
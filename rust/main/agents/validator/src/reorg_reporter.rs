@@ -166,7 +166,13 @@ impl LatestCheckpointReorgReporter {
                 updated_conn.rpc_connection = RpcConnectionConf::Http { url };
                 Ethereum(updated_conn)
             }),
-            Fuel(_) => todo!("Fuel connection not implemented"),
+            Fuel(conn) => {
+                Self::map_urls_to_connections(vec![conn.url.clone()], conn, |conn, url| {
+                    let mut updated_conn = conn.clone();
+                    updated_conn.url = url;
+                    Fuel(updated_conn)
+                })
+            }
             Sealevel(conn) => {
                 Self::map_urls_to_connections(conn.urls.clone(), conn, |conn, url| {
                     let mut updated_conn = conn.clone();
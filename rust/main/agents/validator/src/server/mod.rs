@@ -1,5 +1,6 @@
 pub mod eigen_node;
 pub mod merkle_tree_insertions;
+pub mod reorg_reconciliation;
 
 pub use eigen_node::EigenNodeApi;
 
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, routing::post, Router};
+use derive_new::new;
+
+use hyperlane_base::{
+    db::{ReorgReconciler, ReorgReconciliation},
+    server::utils::{ServerErrorBody, ServerErrorResponse, ServerResult, ServerSuccessResponse},
+};
+
+#[derive(Clone, Debug, new)]
+pub struct ServerState {
+    pub reconciler: Arc<ReorgReconciler>,
+}
+
+impl ServerState {
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/reorg_reconciliation", post(handler))
+            .with_state(self)
+    }
+}
+
+/// Prune merkle tree insertions indexed from reorged blocks. The validator's
+/// in-memory merkle tree only drops them on restart.
+pub async fn handler(
+    State(state): State<ServerState>,
+) -> ServerResult<ServerSuccessResponse<ReorgReconciliation>> {
+    let reconciliation = state.reconciler.reconcile().await.map_err(|err| {
+        tracing::warn!(?err, "Failed to reconcile reorged data");
+        ServerErrorResponse::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            ServerErrorBody {
+                message: format!("Failed to reconcile reorged data: {err}"),
+            },
+        )
+    })?;
+    Ok(ServerSuccessResponse::new(reconciliation))
+}
@@ -4,7 +4,7 @@
 //! and validations it defines are not applied here, we should mirror them.
 //! ANY CHANGES HERE NEED TO BE REFLECTED IN THE TYPESCRIPT SDK.
 
-use std::{collections::HashSet, ops::Add, path::PathBuf, time::Duration};
+use std::{collections::HashSet, env, ops::Add, path::PathBuf, time::Duration};
 
 use aws_config::Region;
 use derive_more::{AsMut, AsRef, Deref, DerefMut};
@@ -15,6 +15,7 @@ use hyperlane_base::{
         parser::{RawAgentConf, RawAgentSignerConf, ValueParser},
         CheckpointSyncerConf, Settings, SignerConf,
     },
+    AZURE_STORAGE_ACCOUNT_KEY, AZURE_STORAGE_ENDPOINT, AZURE_STORAGE_SAS_TOKEN,
 };
 use hyperlane_core::{
     cfg_unwrap_all, config::*, HyperlaneDomain, HyperlaneDomainProtocol, ReorgPeriod,
@@ -45,8 +46,11 @@ pub struct ValidatorSettings {
     pub origin_chain: HyperlaneDomain,
     /// The validator attestation signer
     pub validator: SignerConf,
-    /// The checkpoint syncer configuration
-    pub checkpoint_syncer: CheckpointSyncerConf,
+    /// The checkpoint syncer configurations. Checkpoints are written to all of
+    /// them, the first one is the primary syncer.
+    pub checkpoint_syncers: Vec<CheckpointSyncerConf>,
+    /// How many checkpoint syncers must accept a write for it to succeed
+    pub checkpoint_syncer_write_quorum: usize,
     /// The reorg configuration
     pub reorg_period: ReorgPeriod,
     /// How frequently to check for new checkpoints
@@ -131,11 +135,45 @@ impl FromRawConf<RawValidatorSettings> for ValidatorSettings {
                 curr_dir.join(format!("validator_db_{}", origin_chain_name.unwrap_or("")))
             });
 
-        let checkpoint_syncer = p
+        // Either a list of redundant checkpoint syncers or a single one
+        let checkpoint_syncers = match p
             .chain(&mut err)
-            .get_key("checkpointSyncer")
-            .and_then(parse_checkpoint_syncer)
-            .end();
+            .get_opt_key("checkpointSyncers")
+            .into_array_iter()
+        {
+            Some(syncers) => syncers
+                .filter_map(|syncer| parse_checkpoint_syncer(syncer).take_config_err(&mut err))
+                .collect_vec(),
+            None => p
+                .chain(&mut err)
+                .get_key("checkpointSyncer")
+                .and_then(parse_checkpoint_syncer)
+                .end()
+                .into_iter()
+                .collect_vec(),
+        };
+
+        let checkpoint_syncer_write_quorum = p
+            .chain(&mut err)
+            .get_opt_key("checkpointSyncerWriteQuorum")
+            .parse_u64()
+            .map(|quorum| quorum as usize)
+            .unwrap_or(1);
+        if checkpoint_syncers.is_empty() {
+            err.push(
+                cwp.add("checkpoint_syncers"),
+                eyre!("At least one checkpoint syncer must be configured"),
+            );
+        } else if checkpoint_syncer_write_quorum == 0
+            || checkpoint_syncer_write_quorum > checkpoint_syncers.len()
+        {
+            err.push(
+                cwp.add("checkpoint_syncer_write_quorum"),
+                eyre!(
+                    "Checkpoint syncer write quorum must be between 1 and the number of checkpoint syncers"
+                ),
+            );
+        }
 
         let interval = p
             .chain(&mut err)
@@ -189,7 +227,7 @@ impl FromRawConf<RawValidatorSettings> for ValidatorSettings {
             &mut err,
         ));
 
-        cfg_unwrap_all!(cwp, err: [base, origin_chain, validator, checkpoint_syncer]);
+        cfg_unwrap_all!(cwp, err: [base, origin_chain, validator]);
 
         let mut base: Settings = base;
         // If the origin chain is an EVM chain, then we can use the validator as the signer if needed.
@@ -204,7 +242,8 @@ impl FromRawConf<RawValidatorSettings> for ValidatorSettings {
             db,
             origin_chain,
             validator,
-            checkpoint_syncer,
+            checkpoint_syncers,
+            checkpoint_syncer_write_quorum,
             reorg_period,
             interval,
             rpcs,
@@ -271,7 +310,7 @@ fn get_rpc_urls(
     overrides.unwrap_or(base)
 }
 
-/// Expects ValidatorAgentConfig.checkpointSyncer
+/// Expects ValidatorAgentConfig.checkpointSyncer or an entry of ValidatorAgentConfig.checkpointSyncers
 fn parse_checkpoint_syncer(syncer: ValueParser) -> ConfigResult<CheckpointSyncerConf> {
     let mut err = ConfigParsingError::default();
     let syncer_type = syncer.chain(&mut err).get_key("type").parse_string().end();
@@ -347,6 +386,67 @@ fn parse_checkpoint_syncer(syncer: ValueParser) -> ConfigResult<CheckpointSyncer
                 user_secrets,
             })
         }
+        Some("azure") => {
+            let account = syncer
+                .chain(&mut err)
+                .get_key("account")
+                .parse_string()
+                .end()
+                .map(str::to_owned);
+            let container = syncer
+                .chain(&mut err)
+                .get_key("container")
+                .parse_string()
+                .end()
+                .map(str::to_owned);
+            let folder = syncer
+                .chain(&mut err)
+                .get_opt_key("folder")
+                .parse_string()
+                .end()
+                .map(str::to_owned);
+            let endpoint = syncer
+                .chain(&mut err)
+                .get_opt_key("endpoint")
+                .parse_from_str("Expected Azure Blob Storage endpoint url")
+                .end()
+                .or_else(|| {
+                    let endpoint = env::var(AZURE_STORAGE_ENDPOINT).ok()?;
+                    endpoint
+                        .parse()
+                        .map_err(|e| {
+                            err.push(
+                                (&syncer.cwp).add("endpoint"),
+                                eyre!("Invalid {AZURE_STORAGE_ENDPOINT}: {e}"),
+                            )
+                        })
+                        .ok()
+                });
+            let sas_token = syncer
+                .chain(&mut err)
+                .get_opt_key("sas_token")
+                .parse_string()
+                .end()
+                .map(str::to_owned)
+                .or_else(|| env::var(AZURE_STORAGE_SAS_TOKEN).ok());
+            let account_key = syncer
+                .chain(&mut err)
+                .get_opt_key("account_key")
+                .parse_string()
+                .end()
+                .map(str::to_owned)
+                .or_else(|| env::var(AZURE_STORAGE_ACCOUNT_KEY).ok());
+
+            cfg_unwrap_all!(&syncer.cwp, err: [account, container]);
+            err.into_result(CheckpointSyncerConf::Azure {
+                account,
+                container,
+                folder,
+                endpoint,
+                sas_token,
+                account_key,
+            })
+        }
         Some(_) => Err(eyre!("Unknown checkpoint syncer type"))
             .into_config_result(|| (&syncer.cwp).add("type")),
         None => Err(err),
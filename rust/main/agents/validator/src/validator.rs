@@ -5,7 +5,7 @@ use axum::Router;
 use derive_more::AsRef;
 use ethers::utils::keccak256;
 use eyre::{eyre, Result};
-use futures_util::future::try_join_all;
+use futures_util::future::join_all;
 use itertools::Itertools;
 use serde::Serialize;
 use tokio::{task::JoinHandle, time::sleep};
@@ -13,13 +13,13 @@ use tracing::{error, info, info_span, warn, Instrument};
 use url::Url;
 
 use hyperlane_base::{
-    db::{HyperlaneDb, HyperlaneRocksDB, DB},
+    db::{HyperlaneDb, HyperlaneRocksDB, ReorgReconciler, DB, DEFAULT_MAX_REORG_DEPTH},
     git_sha,
     metrics::AgentMetrics,
     settings::{ChainConf, CheckpointSyncerBuildError},
     BaseAgent, ChainMetrics, ChainSpecificMetricsUpdater, CheckpointSyncer, ContractSyncMetrics,
-    ContractSyncer, CoreMetrics, HyperlaneAgentCore, MetadataFromSettings, RuntimeMetrics,
-    SequencedDataContractSync,
+    ContractSyncer, CoreMetrics, HyperlaneAgentCore, MetadataFromSettings,
+    RedundantCheckpointSyncer, RuntimeMetrics, SequencedDataContractSync,
 };
 use hyperlane_core::{
     rpc_clients::RPC_RETRY_SLEEP_DURATION, Announcement, ChainResult, HyperlaneChain,
@@ -31,7 +31,7 @@ use hyperlane_ethereum::{Signers, SingletonSigner, SingletonSignerHandle};
 use crate::reorg_reporter::{
     LatestCheckpointReorgReporter, LatestCheckpointReorgReporterWithStorageWriter, ReorgReporter,
 };
-use crate::server::{self as validator_server, merkle_tree_insertions};
+use crate::server::{self as validator_server, merkle_tree_insertions, reorg_reconciliation};
 use crate::{
     settings::ValidatorSettings,
     submit::{ValidatorSubmitter, ValidatorSubmitterMetrics},
@@ -58,6 +58,8 @@ pub struct Validator {
     reorg_period: ReorgPeriod,
     interval: Duration,
     checkpoint_syncer: Arc<dyn CheckpointSyncer>,
+    /// The individual checkpoint syncers, each of which is announced
+    checkpoint_syncers: Vec<Arc<dyn CheckpointSyncer>>,
     core_metrics: Arc<CoreMetrics>,
     agent_metrics: AgentMetrics,
     chain_metrics: ChainMetrics,
@@ -65,6 +67,7 @@ pub struct Validator {
     agent_metadata: ValidatorMetadata,
     max_sign_concurrency: usize,
     reorg_reporter: Arc<dyn ReorgReporter>,
+    reorg_reconciler: Option<Arc<ReorgReconciler>>,
 }
 
 /// Metadata for `validator`
@@ -148,19 +151,32 @@ impl BaseAgent for Validator {
             LatestCheckpointReorgReporter::from_settings(&settings, &metrics).await?;
         let reorg_reporter = Arc::new(reorg_reporter) as Arc<dyn ReorgReporter>;
 
-        let checkpoint_syncer_result = settings.checkpoint_syncer.build_and_validate(None).await;
-
-        Self::report_latest_checkpoints_from_each_endpoint(
-            &reorg_reporter,
-            &checkpoint_syncer_result,
+        let checkpoint_syncer_results = join_all(
+            settings
+                .checkpoint_syncers
+                .iter()
+                .map(|conf| conf.build_and_validate(None)),
         )
         .await;
 
-        // Be extra sure to panic when checkpoint syncer fails, which indicates
-        // a fatal startup error.
-        let checkpoint_syncer: Arc<dyn CheckpointSyncer> = checkpoint_syncer_result
-            .expect("Failed to build checkpoint syncer")
-            .into();
+        for result in &checkpoint_syncer_results {
+            Self::report_latest_checkpoints_from_each_endpoint(&reorg_reporter, result).await;
+        }
+
+        // Be extra sure to panic when a reorg was flagged or too few checkpoint
+        // syncers could be built, which indicates a fatal startup error.
+        let checkpoint_syncers = Self::usable_checkpoint_syncers(
+            checkpoint_syncer_results,
+            settings.checkpoint_syncer_write_quorum,
+        )
+        .expect("Failed to build checkpoint syncers");
+        let checkpoint_syncer: Arc<dyn CheckpointSyncer> = match checkpoint_syncers.as_slice() {
+            [checkpoint_syncer] => checkpoint_syncer.clone(),
+            _ => Arc::new(RedundantCheckpointSyncer::new(
+                checkpoint_syncers.clone(),
+                settings.checkpoint_syncer_write_quorum,
+            )?),
+        };
 
         // If checkpoint syncer initialization was successful, use a reorg-reporter which
         // writes to the storage location in addition to the logs.
@@ -177,6 +193,18 @@ impl BaseAgent for Validator {
 
         let mailbox = origin_chain_conf.build_mailbox(&metrics).await?;
 
+        let reorg_reconciler =
+            if ReorgReconciler::supports_protocol(settings.origin_chain.domain_protocol()) {
+                let provider = origin_chain_conf.build_provider(&metrics).await?;
+                Some(Arc::new(ReorgReconciler::new(
+                    msg_db.clone(),
+                    provider.into(),
+                    DEFAULT_MAX_REORG_DEPTH,
+                )))
+            } else {
+                None
+            };
+
         let merkle_tree_hook = settings
             .build_merkle_tree_hook(&settings.origin_chain, &metrics)
             .await?;
@@ -213,6 +241,7 @@ impl BaseAgent for Validator {
             reorg_period: settings.reorg_period,
             interval: settings.interval,
             checkpoint_syncer,
+            checkpoint_syncers,
             agent_metrics,
             chain_metrics,
             core_metrics: metrics,
@@ -220,6 +249,7 @@ impl BaseAgent for Validator {
             agent_metadata,
             max_sign_concurrency: settings.max_sign_concurrency,
             reorg_reporter,
+            reorg_reconciler,
         })
     }
 
@@ -228,7 +258,7 @@ impl BaseAgent for Validator {
         let mut tasks = vec![];
 
         // run server
-        let router = Router::new()
+        let mut router = Router::new()
             .merge(validator_server::router(
                 self.origin_chain.clone(),
                 self.core.metrics.clone(),
@@ -239,6 +269,10 @@ impl BaseAgent for Validator {
                 )
                 .router(),
             );
+        // the block hashes of logs on other chains can't be checked for reorgs
+        if let Some(reconciler) = self.reorg_reconciler.clone() {
+            router = router.merge(reorg_reconciliation::ServerState::new(reconciler).router());
+        }
 
         let server = self
             .core
@@ -469,18 +503,28 @@ impl Validator {
     }
 
     async fn announce(&self) -> Result<()> {
+        // Relayers prefer the most recently announced location, so the primary
+        // checkpoint syncer is announced last
+        for checkpoint_syncer in self.checkpoint_syncers.iter().rev() {
+            self.announce_location(checkpoint_syncer.as_ref()).await?;
+        }
+        self.core_metrics.set_announced(self.origin_chain.clone());
+        Ok(())
+    }
+
+    async fn announce_location(&self, checkpoint_syncer: &dyn CheckpointSyncer) -> Result<()> {
         let address = self.signer.eth_address();
-        let announcement_location = self.checkpoint_syncer.announcement_location();
+        let announcement_location = checkpoint_syncer.announcement_location();
 
         // Sign and post the validator announcement
         let announcement = Announcement {
             validator: address,
             mailbox_address: self.mailbox.address(),
             mailbox_domain: self.mailbox.domain().id(),
-            storage_location: self.announcement_location()?, // Use formatted location for the signed announcement
+            storage_location: self.announcement_location(checkpoint_syncer)?, // Use formatted location for the signed announcement
         };
         let signed_announcement = self.signer.sign(announcement.clone()).await?;
-        self.checkpoint_syncer
+        checkpoint_syncer
             .write_announcement(&signed_announcement)
             .await?;
 
@@ -504,8 +548,6 @@ impl Validator {
                         "Validator has announced signature storage location"
                     );
 
-                    self.core_metrics.set_announced(self.origin_chain.clone());
-
                     break;
                 }
                 info!(
@@ -551,9 +593,9 @@ impl Validator {
         Ok(())
     }
 
-    async fn report_latest_checkpoints_from_each_endpoint(
+    async fn report_latest_checkpoints_from_each_endpoint<T>(
         reorg_reporter: &Arc<dyn ReorgReporter>,
-        checkpoint_syncer_result: &Result<Box<dyn CheckpointSyncer>, CheckpointSyncerBuildError>,
+        checkpoint_syncer_result: &Result<T, CheckpointSyncerBuildError>,
     ) {
         if let Err(CheckpointSyncerBuildError::ReorgFlag(reorg_resp)) =
             checkpoint_syncer_result.as_ref()
@@ -576,8 +618,39 @@ impl Validator {
         }
     }
 
-    fn announcement_location(&self) -> Result<String> {
-        let location = self.checkpoint_syncer.announcement_location();
+    /// The checkpoint syncers which could be built. Syncers which failed to
+    /// build are skipped, as long as enough are left to reach the write quorum.
+    /// A reorg flagged by any syncer is fatal.
+    fn usable_checkpoint_syncers(
+        results: Vec<Result<Box<dyn CheckpointSyncer>, CheckpointSyncerBuildError>>,
+        write_quorum: usize,
+    ) -> Result<Vec<Arc<dyn CheckpointSyncer>>, CheckpointSyncerBuildError> {
+        let configured = results.len();
+        let mut checkpoint_syncers: Vec<Arc<dyn CheckpointSyncer>> = Vec::new();
+        for (index, result) in results.into_iter().enumerate() {
+            match result {
+                Ok(checkpoint_syncer) => checkpoint_syncers.push(checkpoint_syncer.into()),
+                Err(err @ CheckpointSyncerBuildError::ReorgFlag(_)) => return Err(err),
+                Err(err) => {
+                    warn!(
+                        ?err,
+                        index, "Failed to build checkpoint syncer, skipping it"
+                    );
+                }
+            }
+        }
+        if checkpoint_syncers.len() < write_quorum {
+            return Err(eyre!(
+                "Only {} of {configured} checkpoint syncers could be built, fewer than the write quorum of {write_quorum}",
+                checkpoint_syncers.len()
+            )
+            .into());
+        }
+        Ok(checkpoint_syncers)
+    }
+
+    fn announcement_location(&self, checkpoint_syncer: &dyn CheckpointSyncer) -> Result<String> {
+        let location = checkpoint_syncer.announcement_location();
         if self.origin_chain.domain_protocol() == hyperlane_core::HyperlaneDomainProtocol::Aleo {
             Self::aleo_announcement_location(location)
         } else {
@@ -608,7 +681,73 @@ impl Validator {
 
 #[cfg(test)]
 mod tests {
+    use hyperlane_base::LocalStorage;
+    use hyperlane_core::ReorgEventResponse;
+
     use super::*;
+
+    fn local_storage(
+        dir: &tempfile::TempDir,
+    ) -> Result<Box<dyn CheckpointSyncer>, CheckpointSyncerBuildError> {
+        Ok(Box::new(
+            LocalStorage::new(dir.path().to_path_buf(), None).unwrap(),
+        ))
+    }
+
+    fn unreachable_storage() -> Result<Box<dyn CheckpointSyncer>, CheckpointSyncerBuildError> {
+        Err(eyre!("connection refused").into())
+    }
+
+    #[test]
+    fn usable_checkpoint_syncers_skips_failed_syncers() {
+        let dir = tempfile::tempdir().unwrap();
+        let results = vec![
+            local_storage(&dir),
+            unreachable_storage(),
+            local_storage(&dir),
+        ];
+
+        let syncers = Validator::usable_checkpoint_syncers(results, 2).unwrap();
+
+        assert_eq!(syncers.len(), 2);
+    }
+
+    #[test]
+    fn usable_checkpoint_syncers_requires_write_quorum() {
+        let dir = tempfile::tempdir().unwrap();
+        let results = vec![
+            local_storage(&dir),
+            unreachable_storage(),
+            unreachable_storage(),
+        ];
+
+        let result = Validator::usable_checkpoint_syncers(results, 2);
+
+        assert!(matches!(result, Err(CheckpointSyncerBuildError::Other(_))));
+    }
+
+    #[test]
+    fn usable_checkpoint_syncers_fails_on_reorg_flag() {
+        let dir = tempfile::tempdir().unwrap();
+        let reorg = ReorgEventResponse {
+            exists: true,
+            event: None,
+            content: None,
+        };
+        let results = vec![
+            local_storage(&dir),
+            local_storage(&dir),
+            Err(CheckpointSyncerBuildError::ReorgFlag(reorg)),
+        ];
+
+        let result = Validator::usable_checkpoint_syncers(results, 1);
+
+        assert!(matches!(
+            result,
+            Err(CheckpointSyncerBuildError::ReorgFlag(_))
+        ));
+    }
+
     #[test]
     fn aleo_announcement_location_exactly_max_minus_null() -> Result<()> {
         // 479 bytes input should be padded to 480 with a single null
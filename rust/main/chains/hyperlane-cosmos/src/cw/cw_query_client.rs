@@ -187,14 +187,19 @@ impl CwQueryClient {
     where
         T: Serialize + Send + Sync,
     {
+        let msg = serde_json::to_string(&payload)?.as_bytes().to_vec();
+        self.wasm_execute_msg(self.contract_address.address(), msg)
+    }
+
+    /// Builds a `MsgExecuteContract` for an already encoded message sent to any contract
+    pub fn wasm_execute_msg(&self, contract_address: String, msg: Vec<u8>) -> ChainResult<Any> {
         // Estimating gas requires a signer, which we can reasonably expect to have
         // since we need one to send a tx with the estimated gas anyways.
         let signer = self.get_signer()?;
-        let contract_address = self.contract_address.address();
         let msg = MsgExecuteContract {
             sender: signer.address_string.clone(),
             contract: contract_address,
-            msg: serde_json::to_string(&payload)?.as_bytes().to_vec(),
+            msg,
             funds: vec![],
         };
 
@@ -206,8 +211,18 @@ impl CwQueryClient {
     where
         T: Serialize + Send + Sync + Clone + std::fmt::Debug,
     {
-        let contract_address = self.contract_address.address();
         let query_data = serde_json::to_string(&payload)?.as_bytes().to_vec();
+        self.wasm_query_contract(self.contract_address.address(), query_data, block_height)
+            .await
+    }
+
+    /// Executes an already encoded state query on any contract
+    pub async fn wasm_query_contract(
+        &self,
+        contract_address: String,
+        query_data: Vec<u8>,
+        block_height: Option<u64>,
+    ) -> ChainResult<Vec<u8>> {
         let response = self
             .grpc
             .call(move |provider| {
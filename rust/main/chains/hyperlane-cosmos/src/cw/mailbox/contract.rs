@@ -2,6 +2,7 @@ use std::str::FromStr;
 
 use async_trait::async_trait;
 use hyperlane_core::Metadata;
+use serde::Serialize;
 use tracing::instrument;
 
 use hyperlane_core::{
@@ -15,7 +16,9 @@ use super::super::payloads::general;
 use super::super::payloads::mailbox::{
     GeneralMailboxQuery, ProcessMessageRequest, ProcessMessageRequestInner,
 };
-use crate::{cw::CwQueryClient, utils, ConnectionConf, CosmosAddress, CosmosProvider};
+use crate::{
+    cw::CwQueryClient, utils, ConnectionConf, CosmWasmTxCalldata, CosmosAddress, CosmosProvider,
+};
 
 #[derive(Clone, Debug)]
 /// A reference to a Mailbox contract on some Cosmos chain
@@ -198,18 +201,46 @@ impl Mailbox for CwMailbox {
 
     async fn process_calldata(
         &self,
-        _message: &HyperlaneMessage,
+        message: &HyperlaneMessage,
         metadata: &Metadata,
     ) -> ChainResult<Vec<u8>> {
-        todo!() // not required
+        let process_message = ProcessMessageRequest {
+            process: ProcessMessageRequestInner {
+                message: hex::encode(RawHyperlaneMessage::from(message)),
+                metadata: hex::encode(metadata),
+            },
+        };
+        self.encode_calldata(process_message)
     }
 
-    fn delivered_calldata(&self, _message_id: H256) -> ChainResult<Option<Vec<u8>>> {
-        todo!()
+    /// Data required to query the mailbox for whether a message
+    /// was delivered or not on-chain.
+    fn delivered_calldata(&self, message_id: H256) -> ChainResult<Option<Vec<u8>>> {
+        let payload = payloads::mailbox::DeliveredRequest {
+            message_delivered: payloads::mailbox::DeliveredRequestInner {
+                id: hex::encode(message_id),
+            },
+        };
+        self.encode_calldata(GeneralMailboxQuery { mailbox: payload })
+            .map(Some)
     }
 }
 
 impl CwMailbox {
+    /// Serializes a mailbox execute or query message into calldata for the lander
+    fn encode_calldata<T: Serialize>(&self, msg: T) -> ChainResult<Vec<u8>> {
+        let calldata = CosmWasmTxCalldata {
+            contract_address: CosmosAddress::from_h256(
+                self.address,
+                &self.bech32_prefix(),
+                self.contract_address_bytes(),
+            )?
+            .address(),
+            msg: serde_json::to_vec(&msg)?,
+        };
+        Ok(serde_json::to_vec(&calldata)?)
+    }
+
     #[instrument(level = "debug", err, ret, skip(self))]
     pub(crate) async fn nonce_at_block(&self, block_height: u64) -> ChainResult<u32> {
         let payload = payloads::mailbox::NonceRequest {
@@ -77,6 +77,16 @@ pub enum HyperlaneCosmosError {
     /// Parsing attempt failed
     #[error("Parsing attempt failed. (Errors: {0:?})")]
     ParsingAttemptsFailed(Vec<HyperlaneCosmosError>),
+    /// Simulating a transaction failed
+    #[error("Simulation failed: codespace={codespace}, code={code}, log={log}")]
+    SimulationFailed {
+        /// Codespace of the error
+        codespace: String,
+        /// Error code within the codespace
+        code: u32,
+        /// Log of the failed simulation
+        log: String,
+    },
 }
 
 impl From<cosmrs::ErrorReport> for HyperlaneCosmosError {
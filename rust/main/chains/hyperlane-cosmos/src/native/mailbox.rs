@@ -12,7 +12,7 @@ use hyperlane_core::{
     ReorgPeriod, TxCostEstimate, TxOutcome, H256, U256,
 };
 
-use crate::{utils, CosmosProvider};
+use crate::{utils, CosmosNativeDeliveredCalldata, CosmosNativeTxCalldata, CosmosProvider};
 
 use super::module_query_client::ModuleQueryClient;
 
@@ -173,13 +173,22 @@ impl Mailbox for CosmosNativeMailbox {
     /// against the provided signed checkpoint
     async fn process_calldata(
         &self,
-        _message: &HyperlaneMessage,
+        message: &HyperlaneMessage,
         metadata: &Metadata,
     ) -> ChainResult<Vec<u8>> {
-        todo!() // we dont need this for now
+        let any_encoded = self.encode_hyperlane_message(message, metadata)?;
+        let calldata = CosmosNativeTxCalldata {
+            type_url: any_encoded.type_url,
+            value: any_encoded.value,
+        };
+        Ok(serde_json::to_vec(&calldata)?)
     }
 
-    fn delivered_calldata(&self, _message_id: H256) -> ChainResult<Option<Vec<u8>>> {
-        todo!()
+    fn delivered_calldata(&self, message_id: H256) -> ChainResult<Option<Vec<u8>>> {
+        let calldata = CosmosNativeDeliveredCalldata {
+            mailbox_id: self.address.encode_hex(),
+            message_id: message_id.encode_hex(),
+        };
+        Ok(Some(serde_json::to_vec(&calldata)?))
     }
 }
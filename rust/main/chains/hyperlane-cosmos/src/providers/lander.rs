@@ -0,0 +1,233 @@
+use cosmrs::Any;
+use serde::{Deserialize, Serialize};
+use tonic::async_trait;
+
+use hyperlane_core::{rpc_clients::BlockNumberGetter, ChainResult, H256, H512};
+
+use crate::cw::{payloads::mailbox::DeliveredResponse, CwQueryClient};
+use crate::native::ModuleQueryClient;
+use crate::CosmosProvider;
+
+/// Data required to execute or query a CosmWasm contract.
+/// Produced by the mailbox for the lander.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CosmWasmTxCalldata {
+    /// Bech32 address of the contract
+    pub contract_address: String,
+    /// JSON encoded execute or query message
+    pub msg: Vec<u8>,
+}
+
+/// Message of a Cosmos Native transaction, encoded as `Any`.
+/// Produced by the mailbox for the lander.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CosmosNativeTxCalldata {
+    /// Type URL of the message
+    pub type_url: String,
+    /// Protobuf encoded message
+    pub value: Vec<u8>,
+}
+
+impl From<CosmosNativeTxCalldata> for Any {
+    fn from(calldata: CosmosNativeTxCalldata) -> Self {
+        Any {
+            type_url: calldata.type_url,
+            value: calldata.value,
+        }
+    }
+}
+
+/// Data required to check whether a message was delivered by a Cosmos Native mailbox.
+/// Produced by the mailbox for the lander.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CosmosNativeDeliveredCalldata {
+    /// Hex encoded id of the mailbox
+    pub mailbox_id: String,
+    /// Hex encoded id of the message
+    pub message_id: String,
+}
+
+/// Result of a transaction which was included in a block
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CosmosTxResult {
+    /// Height of the block which includes the transaction
+    pub height: u64,
+    /// Whether the transaction was executed successfully
+    pub success: bool,
+    /// Log of the transaction execution
+    pub log: String,
+}
+
+/// Trait defining the necessary methods for a Cosmos SDK provider to be used by the lander.
+///
+/// CosmWasm and Cosmos Native chains only differ in the messages they execute and in
+/// how message delivery is checked, which is described by the associated calldata types.
+#[async_trait]
+pub trait CosmosProviderForLander: Send + Sync {
+    /// Calldata of the transactions landed through the provider
+    type Calldata: Send + Sync;
+
+    /// Calldata of the query checking whether a message was delivered
+    type DeliveredCalldata: Send + Sync;
+
+    /// Simulates executing the calldata and returns the gas estimate,
+    /// including the configured gas multiplier. Fails with a
+    /// `ChainCommunicationError::ContractError` if executing the calldata fails.
+    async fn simulate(&self, calldata: &Self::Calldata) -> ChainResult<u64>;
+
+    /// Returns the fee, in the canonical asset, for spending `gas_limit` gas
+    /// at the configured minimum gas price.
+    fn fee_for_gas(&self, gas_limit: u64) -> ChainResult<u128>;
+
+    /// Returns the current account sequence of the signer.
+    async fn account_sequence(&self) -> ChainResult<u64>;
+
+    /// Returns the latest block height.
+    async fn get_block_number(&self) -> ChainResult<u64>;
+
+    /// Signs and broadcasts a transaction executing the calldata.
+    /// Returns once the transaction has been accepted into the mempool.
+    async fn broadcast(
+        &self,
+        calldata: &Self::Calldata,
+        gas_limit: u64,
+        fee_amount: u128,
+        sequence: u64,
+        timeout_height: u64,
+    ) -> ChainResult<H256>;
+
+    /// Gets the result of an included transaction.
+    /// Returns an error if the transaction can not be found.
+    async fn get_tx(&self, hash: &H512) -> ChainResult<CosmosTxResult>;
+
+    /// Checks whether the mailbox has delivered the message.
+    async fn check_delivered(&self, calldata: &Self::DeliveredCalldata) -> ChainResult<bool>;
+}
+
+#[async_trait]
+impl CosmosProviderForLander for CosmosProvider<CwQueryClient> {
+    type Calldata = CosmWasmTxCalldata;
+    /// The `message_delivered` query of the mailbox contract
+    type DeliveredCalldata = CosmWasmTxCalldata;
+
+    async fn simulate(&self, calldata: &CosmWasmTxCalldata) -> ChainResult<u64> {
+        let msg = self
+            .query()
+            .wasm_execute_msg(calldata.contract_address.clone(), calldata.msg.clone())?;
+        self.rpc().estimate_gas(vec![msg]).await
+    }
+
+    fn fee_for_gas(&self, gas_limit: u64) -> ChainResult<u128> {
+        self.rpc().fee_for_gas(gas_limit)
+    }
+
+    async fn account_sequence(&self) -> ChainResult<u64> {
+        self.rpc().get_signer_sequence().await
+    }
+
+    async fn get_block_number(&self) -> ChainResult<u64> {
+        self.rpc().get_block_number().await
+    }
+
+    async fn broadcast(
+        &self,
+        calldata: &CosmWasmTxCalldata,
+        gas_limit: u64,
+        fee_amount: u128,
+        sequence: u64,
+        timeout_height: u64,
+    ) -> ChainResult<H256> {
+        let msg = self
+            .query()
+            .wasm_execute_msg(calldata.contract_address.clone(), calldata.msg.clone())?;
+        self.rpc()
+            .broadcast_sync(
+                vec![msg],
+                gas_limit,
+                fee_amount,
+                sequence,
+                u32::try_from(timeout_height)?,
+            )
+            .await
+    }
+
+    async fn get_tx(&self, hash: &H512) -> ChainResult<CosmosTxResult> {
+        let response = self.rpc().get_tx(hash).await?;
+        Ok(CosmosTxResult {
+            height: response.height.value(),
+            success: response.tx_result.code.is_ok(),
+            log: response.tx_result.log,
+        })
+    }
+
+    async fn check_delivered(&self, calldata: &CosmWasmTxCalldata) -> ChainResult<bool> {
+        let data = self
+            .query()
+            .wasm_query_contract(
+                calldata.contract_address.clone(),
+                calldata.msg.clone(),
+                None,
+            )
+            .await?;
+        let response: DeliveredResponse = serde_json::from_slice(&data)?;
+        Ok(response.delivered)
+    }
+}
+
+#[async_trait]
+impl CosmosProviderForLander for CosmosProvider<ModuleQueryClient> {
+    type Calldata = CosmosNativeTxCalldata;
+    type DeliveredCalldata = CosmosNativeDeliveredCalldata;
+
+    async fn simulate(&self, calldata: &CosmosNativeTxCalldata) -> ChainResult<u64> {
+        self.rpc().estimate_gas(vec![calldata.clone().into()]).await
+    }
+
+    fn fee_for_gas(&self, gas_limit: u64) -> ChainResult<u128> {
+        self.rpc().fee_for_gas(gas_limit)
+    }
+
+    async fn account_sequence(&self) -> ChainResult<u64> {
+        self.rpc().get_signer_sequence().await
+    }
+
+    async fn get_block_number(&self) -> ChainResult<u64> {
+        self.rpc().get_block_number().await
+    }
+
+    async fn broadcast(
+        &self,
+        calldata: &CosmosNativeTxCalldata,
+        gas_limit: u64,
+        fee_amount: u128,
+        sequence: u64,
+        timeout_height: u64,
+    ) -> ChainResult<H256> {
+        self.rpc()
+            .broadcast_sync(
+                vec![calldata.clone().into()],
+                gas_limit,
+                fee_amount,
+                sequence,
+                u32::try_from(timeout_height)?,
+            )
+            .await
+    }
+
+    async fn get_tx(&self, hash: &H512) -> ChainResult<CosmosTxResult> {
+        let response = self.rpc().get_tx(hash).await?;
+        Ok(CosmosTxResult {
+            height: response.height.value(),
+            success: response.tx_result.code.is_ok(),
+            log: response.tx_result.log,
+        })
+    }
+
+    async fn check_delivered(&self, calldata: &CosmosNativeDeliveredCalldata) -> ChainResult<bool> {
+        let response = self
+            .query()
+            .delivered(calldata.mailbox_id.clone(), calldata.message_id.clone())
+            .await?;
+        Ok(response.delivered)
+    }
+}
@@ -1,5 +1,6 @@
 mod cosmos;
 mod grpc;
+mod lander;
 mod prometheus;
 mod rpc;
 
@@ -8,5 +9,6 @@ mod tests;
 
 pub use cosmos::*;
 pub use grpc::*;
+pub use lander::*;
 pub use prometheus::*;
 pub use rpc::*;
@@ -6,8 +6,9 @@ use cometbft::{hash::Algorithm, Hash};
 use cometbft_rpc::{
     client::CompatMode,
     endpoint::{
-        block::Response as BlockResponse, block_results::Response as BlockResultsResponse,
-        broadcast::tx_commit, tx::Response as TxResponse,
+        abci_query::AbciQuery, block::Response as BlockResponse,
+        block_results::Response as BlockResultsResponse, broadcast::tx_commit,
+        tx::Response as TxResponse,
     },
     Client, Error, HttpClient,
 };
@@ -40,6 +41,10 @@ use crate::{ConnectionConf, CosmosAmount, HyperlaneCosmosError, Signer};
 
 const TX_TIMEOUT_BLOCKS: u32 = 100;
 
+/// Codespace of the errors raised by the Cosmos SDK itself, such as a wrong
+/// sequence or insufficient fees, rather than by executing the messages
+const SDK_CODESPACE: &str = "sdk";
+
 #[derive(Debug)]
 pub(crate) struct CosmosHttpClient {
     client: HttpClient,
@@ -263,6 +268,26 @@ impl RpcProvider {
     where
         T: Message + prost::Name,
         R: Message + std::default::Default,
+    {
+        let response = self.abci_query_response(path, request).await?;
+
+        if response.code.is_err() {
+            return Err(ChainCommunicationError::from_other_str(&format!(
+                "ABCI query failed: path={}, code={}, log={}",
+                path,
+                response.code.value(),
+                response.log
+            )));
+        }
+
+        let response = R::decode(response.value.as_slice()).map_err(HyperlaneCosmosError::from)?;
+        Ok(response)
+    }
+
+    /// Sends an ABCI query and returns its response, whether or not the query succeeded
+    async fn abci_query_response<T>(&self, path: &str, request: T) -> ChainResult<AbciQuery>
+    where
+        T: Message + prost::Name,
     {
         let bytes = request.encode_to_vec();
         let response = self
@@ -281,17 +306,6 @@ impl RpcProvider {
                 Box::pin(future)
             })
             .await?;
-
-        if response.code.is_err() {
-            return Err(ChainCommunicationError::from_other_str(&format!(
-                "ABCI query failed: path={}, code={}, log={}",
-                path,
-                response.code.value(),
-                response.log
-            )));
-        }
-
-        let response = R::decode(response.value.as_slice()).map_err(HyperlaneCosmosError::from)?;
         Ok(response)
     }
 
@@ -382,6 +396,22 @@ impl RpcProvider {
         self.gas_price.amount.clone()
     }
 
+    /// Returns the fee, in the canonical asset, for spending `gas_limit` gas
+    /// at the configured gas price
+    pub fn fee_for_gas(&self, gas_limit: u64) -> ChainResult<u128> {
+        let amount: u128 = (FixedPointNumber::from(gas_limit).mul(self.gas_price()))
+            .ceil_to_integer()
+            .try_into()?;
+        Ok(amount)
+    }
+
+    /// Returns the current account sequence of the signer
+    pub async fn get_signer_sequence(&self) -> ChainResult<u64> {
+        let signer = self.get_signer()?;
+        let account_info = self.get_account(signer.address_string.clone()).await?;
+        Ok(account_info.sequence)
+    }
+
     /// Generates an unsigned SignDoc for a transaction and the Coin amount
     /// required to pay for tx fees.
     async fn generate_sign_doc(
@@ -395,20 +425,36 @@ impl RpcProvider {
         let account_info = self.get_account(signer.address_string.clone()).await?;
 
         let current_height = self.get_block_number().await? as u32;
+        let fee_amount = self.fee_for_gas(gas_limit)?;
 
-        let tx_body = tx::Body::new(
+        self.build_sign_doc(
             msgs,
-            String::default(),
+            &account_info,
+            account_info.sequence,
+            gas_limit,
+            fee_amount,
             current_height.saturating_add(TX_TIMEOUT_BLOCKS),
-        );
-        let signer_info = SignerInfo::single_direct(Some(signer.public_key), account_info.sequence);
+        )
+    }
+
+    /// Builds an unsigned SignDoc with an explicit sequence, fee and timeout height
+    fn build_sign_doc(
+        &self,
+        msgs: Vec<cosmrs::Any>,
+        account_info: &BaseAccount,
+        sequence: u64,
+        gas_limit: u64,
+        fee_amount: u128,
+        timeout_height: u32,
+    ) -> ChainResult<SignDoc> {
+        let signer = self.get_signer()?;
+
+        let tx_body = tx::Body::new(msgs, String::default(), timeout_height);
+        let signer_info = SignerInfo::single_direct(Some(signer.public_key), sequence);
 
-        let amount: u128 = (FixedPointNumber::from(gas_limit).mul(self.gas_price()))
-            .ceil_to_integer()
-            .try_into()?;
         let fee_coin = Coin::new(
             // The fee to pay is the gas limit * the gas price
-            amount,
+            fee_amount,
             self.conf.get_canonical_asset().as_str(),
         )
         .map_err(HyperlaneCosmosError::from)?;
@@ -431,6 +477,9 @@ impl RpcProvider {
     /// Estimates the gas that will be used when a transaction with msgs is sent.
     ///
     /// Note: that simulated result will be multiplied by the gas multiplier in the gas config
+    ///
+    /// If executing the msgs fails, the error is a `ChainCommunicationError::ContractError`,
+    /// so that it can be told apart from failing to reach the chain.
     pub async fn estimate_gas(&self, msgs: Vec<Any>) -> ChainResult<u64> {
         // Get a sign doc with 0 gas, because we plan to simulate
         let sign_doc = self.generate_sign_doc(msgs, 0).await?;
@@ -445,12 +494,21 @@ impl RpcProvider {
             .map_err(ChainCommunicationError::from_other)?;
 
         #[allow(deprecated)]
-        let response: SimulateResponse = self
-            .abci_query(
+        let response = self
+            .abci_query_response(
                 "/cosmos.tx.v1beta1.Service/Simulate",
                 SimulateRequest { tx_bytes, tx: None },
             )
             .await?;
+        if response.code.is_err() {
+            return Err(simulation_error(
+                response.codespace,
+                response.code.value(),
+                response.log,
+            ));
+        }
+        let response = SimulateResponse::decode(response.value.as_slice())
+            .map_err(HyperlaneCosmosError::from)?;
 
         let gas_used = response
             .gas_info
@@ -499,6 +557,60 @@ impl RpcProvider {
             })
             .await
     }
+
+    /// Signs the msgs with the given sequence and fee and broadcasts the transaction
+    /// without waiting for it to be included in a block.
+    ///
+    /// Returns the hash of the transaction once it has passed `CheckTx`.
+    pub async fn broadcast_sync(
+        &self,
+        msgs: Vec<Any>,
+        gas_limit: u64,
+        fee_amount: u128,
+        sequence: u64,
+        timeout_height: u32,
+    ) -> ChainResult<H256> {
+        let signer = self.get_signer()?;
+        let account_info = self.get_account(signer.address_string.clone()).await?;
+
+        let sign_doc = self.build_sign_doc(
+            msgs,
+            &account_info,
+            sequence,
+            gas_limit,
+            fee_amount,
+            timeout_height,
+        )?;
+
+        let signed_tx = sign_doc
+            .sign(&signer.signing_key()?)
+            .map_err(HyperlaneCosmosError::from)?;
+        let signed_tx = signed_tx.to_bytes()?;
+
+        let response = self
+            .provider
+            .call(|client| {
+                let signed_tx = signed_tx.clone();
+                let future = async move {
+                    Self::track_metric_call(&client, "broadcast_sync", || {
+                        client.client.broadcast_tx_sync(signed_tx.clone())
+                    })
+                    .await
+                };
+                Box::pin(future)
+            })
+            .await?;
+
+        if response.code.is_err() {
+            return Err(ChainCommunicationError::from_other_str(&format!(
+                "transaction rejected: code={}, log={}",
+                response.code.value(),
+                response.log
+            )));
+        }
+
+        Ok(H256::from_slice(response.hash.as_bytes()))
+    }
 }
 
 #[async_trait]
@@ -512,3 +624,47 @@ impl BlockNumberGetter for RpcProvider {
             .await
     }
 }
+
+/// The error of a failed simulation. Errors of the Cosmos SDK are raised before
+/// the messages are executed, so only the others mean that the messages revert.
+fn simulation_error(codespace: String, code: u32, log: String) -> ChainCommunicationError {
+    let reverted = codespace != SDK_CODESPACE;
+    let err = HyperlaneCosmosError::SimulationFailed {
+        codespace,
+        code,
+        log,
+    };
+    if reverted {
+        ChainCommunicationError::from_contract_error(err)
+    } else {
+        err.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyperlane_core::ChainCommunicationError;
+
+    use super::simulation_error;
+
+    #[test]
+    fn test_simulation_error_of_executed_msgs_is_contract_error() {
+        let err = simulation_error(
+            "wasm".to_string(),
+            5,
+            "execute wasm contract failed".to_string(),
+        );
+        assert!(matches!(err, ChainCommunicationError::ContractError(_)));
+    }
+
+    #[test]
+    fn test_simulation_error_of_sdk_is_not_contract_error() {
+        // account sequence mismatch
+        let err = simulation_error(
+            "sdk".to_string(),
+            32,
+            "incorrect account sequence".to_string(),
+        );
+        assert!(matches!(err, ChainCommunicationError::Other(_)));
+    }
+}
@@ -53,6 +53,12 @@ pub struct ConnectionConf {
     /// we will try other providers and see if another provider returns something
     /// non-null
     pub consider_null_transaction_receipt: bool,
+    /// Websocket url to subscribe to contract logs with, used by indexers
+    /// running in subscription mode
+    pub log_subscription_url: Option<Url>,
+    /// If set, the lander sends signed transactions to a private RPC or bundle
+    /// relay instead of broadcasting them through the public mempool
+    pub private_submission: Option<PrivateSubmissionConf>,
 }
 
 impl ConnectionConf {
@@ -79,6 +85,30 @@ impl ConnectionConf {
     }
 }
 
+/// Private transaction submission configuration
+#[derive(Debug, Clone)]
+pub struct PrivateSubmissionConf {
+    /// Url of the private RPC or bundle relay
+    pub url: Url,
+    /// JSON-RPC method used to send signed transactions to `url`
+    pub method: PrivateSubmissionMethod,
+    /// Number of blocks after the first private submission of a transaction
+    /// after which it is broadcast through the public mempool instead.
+    /// If unspecified, the transaction is only ever sent privately.
+    pub fallback_after_blocks: Option<u32>,
+}
+
+/// JSON-RPC method used for private transaction submission
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PrivateSubmissionMethod {
+    /// `eth_sendPrivateTransaction`, supported by private RPCs such as Flashbots Protect
+    #[default]
+    PrivateTransaction,
+    /// `eth_sendBundle` with a single transaction bundle targeting the next block.
+    /// Relays which require signed requests (`X-Flashbots-Signature`) are not supported.
+    Bundle,
+}
+
 /// Ethereum transaction overrides.
 #[derive(Debug, Clone, Default)]
 pub struct TransactionOverrides {
@@ -11,10 +11,11 @@ use hyperlane_core::rpc_clients::call_and_retry_indefinitely;
 use hyperlane_core::{
     ChainResult, ContractLocator, HyperlaneAbi, HyperlaneChain, HyperlaneContract, HyperlaneDomain,
     HyperlaneProvider, Indexed, Indexer, InterchainGasPaymaster, InterchainGasPayment, LogMeta,
-    SequenceAwareIndexer, H160, H256, H512,
+    LogSubscription, SequenceAwareIndexer, H160, H256, H512,
 };
 
 use super::utils::{fetch_raw_logs_and_meta, get_finalized_block_number};
+use super::LogSubscriber;
 use crate::interfaces::i_interchain_gas_paymaster::{
     GasPaymentFilter, IInterchainGasPaymaster as EthereumInterchainGasPaymasterInternal,
     IINTERCHAINGASPAYMASTER_ABI,
@@ -43,14 +44,18 @@ impl BuildableWithProvider for InterchainGasPaymasterIndexerBuilder {
     async fn build_with_provider<M: Middleware + 'static>(
         &self,
         provider: M,
-        _conn: &ConnectionConf,
+        conn: &ConnectionConf,
         locator: &ContractLocator,
     ) -> Self::Output {
-        Box::new(EthereumInterchainGasPaymasterIndexer::new(
+        let indexer = EthereumInterchainGasPaymasterIndexer::new(
             Arc::new(provider),
             locator,
             self.reorg_period,
-        ))
+        );
+        match LogSubscriber::from_conf(conn, locator) {
+            Some(subscriber) => Box::new(indexer.with_log_subscriber(subscriber)),
+            None => Box::new(indexer),
+        }
     }
 }
 
@@ -63,6 +68,7 @@ where
     contract: Arc<EthereumInterchainGasPaymasterInternal<M>>,
     provider: Arc<M>,
     reorg_period: EthereumReorgPeriod,
+    log_subscriber: Option<LogSubscriber>,
 }
 
 impl<M> EthereumInterchainGasPaymasterIndexer<M>
@@ -82,8 +88,15 @@ where
             )),
             provider,
             reorg_period,
+            log_subscriber: None,
         }
     }
+
+    /// Subscribe to gas payments with the given subscriber
+    pub fn with_log_subscriber(mut self, log_subscriber: LogSubscriber) -> Self {
+        self.log_subscriber = Some(log_subscriber);
+        self
+    }
 }
 
 #[async_trait]
@@ -155,6 +168,25 @@ where
             .collect();
         Ok(logs)
     }
+
+    async fn subscribe_logs(
+        &self,
+    ) -> ChainResult<Option<Box<dyn LogSubscription<InterchainGasPayment>>>> {
+        let Some(subscriber) = &self.log_subscriber else {
+            return Ok(None);
+        };
+        let subscription = subscriber
+            .subscribe::<GasPaymentFilter, _, _>(self.provider.clone(), self.reorg_period, |log| {
+                Indexed::new(InterchainGasPayment {
+                    message_id: H256::from(log.message_id),
+                    destination: log.destination_domain,
+                    payment: log.payment.into(),
+                    gas_amount: log.gas_amount.into(),
+                })
+            })
+            .await?;
+        Ok(Some(subscription))
+    }
 }
 
 #[async_trait]
@@ -0,0 +1,381 @@
+use std::{collections::VecDeque, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use ethers::{
+    abi::RawLog,
+    providers::{Middleware, Provider, Ws},
+    types::{Filter, Log, H160 as EthersH160},
+};
+use ethers_contract::{EthEvent, LogMeta as EthersLogMeta};
+use futures_util::StreamExt;
+use tokio::{
+    sync::mpsc,
+    task::JoinHandle,
+    time::{interval_at, Instant, Interval, MissedTickBehavior},
+};
+use tracing::{debug, warn};
+use url::Url;
+
+use hyperlane_core::{
+    ChainCommunicationError, ChainResult, ContractLocator, Indexed, LogMeta, LogSubscription,
+};
+
+use crate::{ConnectionConf, EthereumReorgPeriod};
+
+use super::utils::get_finalized_block_number;
+
+/// How often the finalized block is polled while logs are waiting for finality
+const FINALITY_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Logs received but not yet consumed before the websocket task is backpressured
+const LOG_CHANNEL_CAPACITY: usize = 1_000;
+
+/// Subscribes to the logs of a contract over a websocket connection
+#[derive(Debug, Clone)]
+pub struct LogSubscriber {
+    url: Url,
+    address: EthersH160,
+}
+
+impl LogSubscriber {
+    /// Returns a subscriber if the connection has a log subscription url
+    pub fn from_conf(conn: &ConnectionConf, locator: &ContractLocator) -> Option<Self> {
+        conn.log_subscription_url.clone().map(|url| Self {
+            url,
+            address: locator.address.into(),
+        })
+    }
+
+    /// Subscribe to the `E` events of the contract. Logs are only returned once
+    /// the block they were included in is final according to `reorg_period`,
+    /// which is checked against `provider`.
+    pub async fn subscribe<E, T, M>(
+        &self,
+        provider: Arc<M>,
+        reorg_period: EthereumReorgPeriod,
+        convert: fn(E) -> Indexed<T>,
+    ) -> ChainResult<Box<dyn LogSubscription<T>>>
+    where
+        E: EthEvent + Send + 'static,
+        T: Send + 'static,
+        M: Middleware + 'static,
+    {
+        let ws = Provider::<Ws>::connect(self.url.as_str())
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+        let filter = Filter::new().address(self.address).topic0(E::signature());
+        let (sender, receiver) = mpsc::channel(LOG_CHANNEL_CAPACITY);
+
+        // the stream borrows the websocket provider, so both live in the task
+        let task = tokio::spawn(async move {
+            let mut stream = match ws.subscribe_logs(&filter).await {
+                Ok(stream) => stream,
+                Err(err) => {
+                    let _ = sender
+                        .send(Err(ChainCommunicationError::from_other(err)))
+                        .await;
+                    return;
+                }
+            };
+            while let Some(log) = stream.next().await {
+                if sender.send(Ok(log)).await.is_err() {
+                    break;
+                }
+            }
+            debug!("Websocket log stream ended");
+        });
+
+        Ok(Box::new(EthereumLogSubscription::new(
+            receiver,
+            Some(task),
+            provider,
+            reorg_period,
+            convert,
+        )))
+    }
+}
+
+/// Logs of a subscription, buffered until they are final
+struct EthereumLogSubscription<E, T, M> {
+    receiver: mpsc::Receiver<ChainResult<Log>>,
+    task: Option<JoinHandle<()>>,
+    provider: Arc<M>,
+    reorg_period: EthereumReorgPeriod,
+    convert: fn(E) -> Indexed<T>,
+    /// Received logs in the order they were emitted, waiting for finality
+    pending: VecDeque<Log>,
+    finalized_block: u64,
+    finality_poll: Interval,
+}
+
+impl<E, T, M> EthereumLogSubscription<E, T, M>
+where
+    E: EthEvent,
+    M: Middleware + 'static,
+{
+    fn new(
+        receiver: mpsc::Receiver<ChainResult<Log>>,
+        task: Option<JoinHandle<()>>,
+        provider: Arc<M>,
+        reorg_period: EthereumReorgPeriod,
+        convert: fn(E) -> Indexed<T>,
+    ) -> Self {
+        let mut finality_poll = interval_at(
+            Instant::now() + FINALITY_POLL_INTERVAL,
+            FINALITY_POLL_INTERVAL,
+        );
+        finality_poll.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        Self {
+            receiver,
+            task,
+            provider,
+            reorg_period,
+            convert,
+            pending: VecDeque::new(),
+            finalized_block: 0,
+            finality_poll,
+        }
+    }
+
+    async fn refresh_finalized_block(&mut self) -> ChainResult<()> {
+        self.finalized_block =
+            get_finalized_block_number(&self.provider, &self.reorg_period).await? as u64;
+        Ok(())
+    }
+
+    /// Buffer a received log, or drop the buffered log it reverts
+    fn receive(&mut self, log: Log) {
+        if log.removed == Some(true) {
+            let removed = self.pending.iter().position(|pending| {
+                pending.transaction_hash == log.transaction_hash
+                    && pending.log_index == log.log_index
+            });
+            if let Some(index) = removed {
+                debug!(tx_hash = ?log.transaction_hash, "Dropping log removed by a reorg");
+                self.pending.remove(index);
+            }
+            return;
+        }
+        if log.block_number.is_none() {
+            // logs of pending blocks are never final
+            return;
+        }
+        self.pending.push_back(log);
+    }
+
+    /// Pop the oldest buffered log if its block is final
+    fn pop_finalized(&mut self) -> Option<Log> {
+        let block_number = self.pending.front()?.block_number?;
+        if block_number.as_u64() > self.finalized_block {
+            return None;
+        }
+        self.pending.pop_front()
+    }
+
+    fn decode(&self, log: &Log) -> Option<(Indexed<T>, LogMeta)> {
+        let raw_log = RawLog {
+            topics: log.topics.clone(),
+            data: log.data.to_vec(),
+        };
+        match E::decode_log(&raw_log) {
+            Ok(event) => {
+                let log_meta: EthersLogMeta = log.into();
+                Some(((self.convert)(event), log_meta.into()))
+            }
+            Err(err) => {
+                warn!(?err, tx_hash = ?log.transaction_hash, "Failed to decode subscribed log");
+                None
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<E, T, M> LogSubscription<T> for EthereumLogSubscription<E, T, M>
+where
+    E: EthEvent + Send,
+    T: Send,
+    M: Middleware + 'static,
+{
+    async fn next_log(&mut self) -> Option<ChainResult<(Indexed<T>, LogMeta)>> {
+        loop {
+            while let Some(log) = self.pop_finalized() {
+                if let Some(decoded) = self.decode(&log) {
+                    return Some(Ok(decoded));
+                }
+            }
+
+            tokio::select! {
+                // drain received logs first, they may revert buffered ones
+                biased;
+                received = self.receiver.recv() => match received {
+                    Some(Ok(log)) => {
+                        let was_empty = self.pending.is_empty();
+                        self.receive(log);
+                        // the log may already be final, e.g. on chains with instant finality
+                        if was_empty && !self.pending.is_empty() {
+                            if let Err(err) = self.refresh_finalized_block().await {
+                                return Some(Err(err));
+                            }
+                        }
+                    }
+                    Some(Err(err)) => return Some(Err(err)),
+                    // logs still waiting for finality are dropped, the block
+                    // based cursor indexes them once they are final
+                    None => return None,
+                },
+                _ = self.finality_poll.tick(), if !self.pending.is_empty() => {
+                    if let Err(err) = self.refresh_finalized_block().await {
+                        return Some(Err(err));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<E, T, M> Drop for EthereumLogSubscription<E, T, M> {
+    fn drop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::{
+        abi::Token,
+        providers::MockProvider,
+        types::{Bytes, H256 as EthersH256, U256 as EthersU256, U64},
+    };
+    use hyperlane_core::{HyperlaneMessage, RawHyperlaneMessage};
+
+    use crate::interfaces::mailbox::DispatchFilter;
+
+    use super::*;
+
+    fn dispatch_log(message: &HyperlaneMessage, block_number: u64, removed: bool) -> Log {
+        let data = ethers::abi::encode(&[Token::Bytes(RawHyperlaneMessage::from(message))]);
+        Log {
+            topics: vec![
+                DispatchFilter::signature(),
+                EthersH256::from(message.sender.0),
+                EthersH256::from_low_u64_be(message.destination as u64),
+                EthersH256::from(message.recipient.0),
+            ],
+            data: Bytes::from(data),
+            block_hash: Some(EthersH256::from_low_u64_be(block_number)),
+            block_number: Some(U64::from(block_number)),
+            transaction_hash: Some(EthersH256::from_low_u64_be(message.nonce as u64 + 1)),
+            transaction_index: Some(U64::zero()),
+            log_index: Some(EthersU256::zero()),
+            removed: Some(removed),
+            ..Default::default()
+        }
+    }
+
+    fn subscription(
+        reorg_period: u32,
+    ) -> (
+        EthereumLogSubscription<DispatchFilter, HyperlaneMessage, Provider<Arc<MockProvider>>>,
+        mpsc::Sender<ChainResult<Log>>,
+        Arc<MockProvider>,
+    ) {
+        let mock_provider = Arc::new(MockProvider::new());
+        let provider = Arc::new(Provider::new(mock_provider.clone()));
+        let (sender, receiver) = mpsc::channel(16);
+        let subscription = EthereumLogSubscription::new(
+            receiver,
+            None,
+            provider,
+            EthereumReorgPeriod::Blocks(reorg_period),
+            |event: DispatchFilter| HyperlaneMessage::from(event.message.to_vec()).into(),
+        );
+        (subscription, sender, mock_provider)
+    }
+
+    #[tokio::test]
+    async fn test_logs_are_returned_once_final() {
+        let (mut subscription, sender, mock_provider) = subscription(2);
+        let message = HyperlaneMessage {
+            nonce: 7,
+            ..Default::default()
+        };
+
+        // MockProvider responses are returned in LIFO order
+        mock_provider.push(U64::from(12)).unwrap();
+        mock_provider.push(U64::from(11)).unwrap();
+        sender
+            .send(Ok(dispatch_log(&message, 10, false)))
+            .await
+            .unwrap();
+
+        let (indexed, meta) = subscription.next_log().await.unwrap().unwrap();
+        assert_eq!(indexed.inner(), &message);
+        assert_eq!(meta.block_number, 10);
+        // the first block number didn't make the log final
+        assert_eq!(subscription.finalized_block, 10);
+    }
+
+    #[tokio::test]
+    async fn test_removed_logs_are_dropped() {
+        let (mut subscription, sender, mock_provider) = subscription(2);
+        let reorged = HyperlaneMessage {
+            nonce: 1,
+            ..Default::default()
+        };
+        let kept = HyperlaneMessage {
+            nonce: 2,
+            ..Default::default()
+        };
+
+        mock_provider.push(U64::from(20)).unwrap();
+        mock_provider.push(U64::from(11)).unwrap();
+        sender
+            .send(Ok(dispatch_log(&reorged, 10, false)))
+            .await
+            .unwrap();
+        sender
+            .send(Ok(dispatch_log(&reorged, 10, true)))
+            .await
+            .unwrap();
+        sender
+            .send(Ok(dispatch_log(&kept, 11, false)))
+            .await
+            .unwrap();
+
+        let (indexed, _) = subscription.next_log().await.unwrap().unwrap();
+        assert_eq!(indexed.inner(), &kept);
+    }
+
+    #[tokio::test]
+    async fn test_ends_when_stream_closes() {
+        let (mut subscription, sender, _) = subscription(2);
+        drop(sender);
+        assert!(subscription.next_log().await.is_none());
+    }
+
+    /// Requires a local anvil node, e.g. `anvil --block-time 1`
+    #[tokio::test]
+    #[ignore]
+    async fn test_subscribe_against_anvil() {
+        let provider = Arc::new(
+            Provider::<Ws>::connect("ws://127.0.0.1:8545")
+                .await
+                .unwrap(),
+        );
+        let subscriber = LogSubscriber {
+            url: "ws://127.0.0.1:8545".parse().unwrap(),
+            address: EthersH160::zero(),
+        };
+        let subscription = subscriber
+            .subscribe::<DispatchFilter, HyperlaneMessage, _>(
+                provider,
+                EthereumReorgPeriod::Blocks(1),
+                |event| HyperlaneMessage::from(event.message.to_vec()).into(),
+            )
+            .await;
+        assert!(subscription.is_ok());
+    }
+}
@@ -23,14 +23,14 @@ use hyperlane_core::{
     rpc_clients::call_and_retry_indefinitely, BatchItem, BatchResult, ChainCommunicationError,
     ChainResult, ContractLocator, HyperlaneAbi, HyperlaneChain, HyperlaneContract, HyperlaneDomain,
     HyperlaneMessage, HyperlaneProtocolError, HyperlaneProvider, Indexed, Indexer, LogMeta,
-    Mailbox, QueueOperation, RawHyperlaneMessage, ReorgPeriod, SequenceAwareIndexer,
-    TxCostEstimate, TxOutcome, H160, H256, H512, U256,
+    LogSubscription, Mailbox, QueueOperation, RawHyperlaneMessage, ReorgPeriod,
+    SequenceAwareIndexer, TxCostEstimate, TxOutcome, H160, H256, H512, U256,
 };
 
 use crate::error::HyperlaneEthereumError;
 use crate::interfaces::arbitrum_node_interface::ArbitrumNodeInterface;
 use crate::interfaces::i_mailbox::{IMailbox as EthereumMailboxInternal, IMAILBOX_ABI};
-use crate::interfaces::mailbox::DispatchFilter;
+use crate::interfaces::mailbox::{DispatchFilter, ProcessIdFilter};
 use crate::tx::{
     call_with_reorg_period, estimate_eip1559_fees, fill_tx_gas_params, report_tx, Eip1559Fee,
 };
@@ -41,6 +41,7 @@ use crate::{
 
 use super::multicall::{self, build_multicall, BatchCache};
 use super::utils::{fetch_raw_logs_and_meta, get_finalized_block_number};
+use super::LogSubscriber;
 
 impl<M> std::fmt::Display for EthereumMailboxInternal<M>
 where
@@ -63,14 +64,14 @@ impl BuildableWithProvider for SequenceIndexerBuilder {
     async fn build_with_provider<M: Middleware + 'static>(
         &self,
         provider: M,
-        _conn: &ConnectionConf,
+        conn: &ConnectionConf,
         locator: &ContractLocator,
     ) -> Self::Output {
-        Box::new(EthereumMailboxIndexer::new(
-            Arc::new(provider),
-            locator,
-            self.reorg_period,
-        ))
+        let indexer = EthereumMailboxIndexer::new(Arc::new(provider), locator, self.reorg_period);
+        match LogSubscriber::from_conf(conn, locator) {
+            Some(subscriber) => Box::new(indexer.with_log_subscriber(subscriber)),
+            None => Box::new(indexer),
+        }
     }
 }
 
@@ -86,14 +87,14 @@ impl BuildableWithProvider for DeliveryIndexerBuilder {
     async fn build_with_provider<M: Middleware + 'static>(
         &self,
         provider: M,
-        _conn: &ConnectionConf,
+        conn: &ConnectionConf,
         locator: &ContractLocator,
     ) -> Self::Output {
-        Box::new(EthereumMailboxIndexer::new(
-            Arc::new(provider),
-            locator,
-            self.reorg_period,
-        ))
+        let indexer = EthereumMailboxIndexer::new(Arc::new(provider), locator, self.reorg_period);
+        match LogSubscriber::from_conf(conn, locator) {
+            Some(subscriber) => Box::new(indexer.with_log_subscriber(subscriber)),
+            None => Box::new(indexer),
+        }
     }
 }
 
@@ -106,6 +107,7 @@ where
     contract: Arc<EthereumMailboxInternal<M>>,
     provider: Arc<M>,
     reorg_period: EthereumReorgPeriod,
+    log_subscriber: Option<LogSubscriber>,
 }
 
 impl<M> EthereumMailboxIndexer<M>
@@ -126,9 +128,16 @@ where
             contract,
             provider,
             reorg_period,
+            log_subscriber: None,
         }
     }
 
+    /// Subscribe to dispatches and deliveries with the given subscriber
+    pub fn with_log_subscriber(mut self, log_subscriber: LogSubscriber) -> Self {
+        self.log_subscriber = Some(log_subscriber);
+        self
+    }
+
     async fn get_finalized_block_number(&self) -> ChainResult<u32> {
         get_finalized_block_number(&self.provider, &self.reorg_period).await
     }
@@ -192,6 +201,20 @@ where
             .collect();
         Ok(logs)
     }
+
+    async fn subscribe_logs(
+        &self,
+    ) -> ChainResult<Option<Box<dyn LogSubscription<HyperlaneMessage>>>> {
+        let Some(subscriber) = &self.log_subscriber else {
+            return Ok(None);
+        };
+        let subscription = subscriber
+            .subscribe::<DispatchFilter, _, _>(self.provider.clone(), self.reorg_period, |event| {
+                HyperlaneMessage::from(event.message.to_vec()).into()
+            })
+            .await?;
+        Ok(Some(subscription))
+    }
 }
 
 #[async_trait]
@@ -232,6 +255,18 @@ where
             .map(|(event, meta)| (Indexed::new(H256::from(event.message_id)), meta.into()))
             .collect())
     }
+
+    async fn subscribe_logs(&self) -> ChainResult<Option<Box<dyn LogSubscription<H256>>>> {
+        let Some(subscriber) = &self.log_subscriber else {
+            return Ok(None);
+        };
+        let subscription = subscriber
+            .subscribe::<ProcessIdFilter, _, _>(self.provider.clone(), self.reorg_period, |event| {
+                Indexed::new(H256::from(event.message_id))
+            })
+            .await?;
+        Ok(Some(subscription))
+    }
 }
 
 #[async_trait]
@@ -760,6 +795,8 @@ mod test {
             transaction_overrides: Default::default(),
             op_submission_config: Default::default(),
             consider_null_transaction_receipt: false,
+            log_subscription_url: None,
+            private_submission: None,
         };
 
         let mailbox = EthereumMailbox::new(
@@ -13,7 +13,8 @@ use tracing::instrument;
 use hyperlane_core::{
     ChainResult, Checkpoint, CheckpointAtBlock, ContractLocator, HyperlaneChain, HyperlaneContract,
     HyperlaneDomain, HyperlaneProvider, IncrementalMerkleAtBlock, Indexed, Indexer, LogMeta,
-    MerkleTreeHook, MerkleTreeInsertion, ReorgPeriod, SequenceAwareIndexer, H256, H512,
+    LogSubscription, MerkleTreeHook, MerkleTreeInsertion, ReorgPeriod, SequenceAwareIndexer, H256,
+    H512,
 };
 
 use crate::interfaces::merkle_tree_hook::{
@@ -23,6 +24,7 @@ use crate::tx::call_with_reorg_period;
 use crate::{BuildableWithProvider, ConnectionConf, EthereumProvider, EthereumReorgPeriod};
 
 use super::utils::{fetch_raw_logs_and_meta, get_finalized_block_number};
+use super::LogSubscriber;
 
 // We don't need the reverse of this impl, so it's ok to disable the clippy lint
 #[allow(clippy::from_over_into)]
@@ -70,14 +72,15 @@ impl BuildableWithProvider for MerkleTreeHookIndexerBuilder {
     async fn build_with_provider<M: Middleware + 'static>(
         &self,
         provider: M,
-        _conn: &ConnectionConf,
+        conn: &ConnectionConf,
         locator: &ContractLocator,
     ) -> Self::Output {
-        Box::new(EthereumMerkleTreeHookIndexer::new(
-            Arc::new(provider),
-            locator,
-            self.reorg_period,
-        ))
+        let indexer =
+            EthereumMerkleTreeHookIndexer::new(Arc::new(provider), locator, self.reorg_period);
+        match LogSubscriber::from_conf(conn, locator) {
+            Some(subscriber) => Box::new(indexer.with_log_subscriber(subscriber)),
+            None => Box::new(indexer),
+        }
     }
 }
 
@@ -90,6 +93,7 @@ where
     contract: Arc<MerkleTreeHookContract<M>>,
     provider: Arc<M>,
     reorg_period: EthereumReorgPeriod,
+    log_subscriber: Option<LogSubscriber>,
 }
 
 impl<M> EthereumMerkleTreeHookIndexer<M>
@@ -109,8 +113,15 @@ where
             )),
             provider,
             reorg_period,
+            log_subscriber: None,
         }
     }
+
+    /// Subscribe to tree insertions with the given subscriber
+    pub fn with_log_subscriber(mut self, log_subscriber: LogSubscriber) -> Self {
+        self.log_subscriber = Some(log_subscriber);
+        self
+    }
 }
 
 #[async_trait]
@@ -173,6 +184,22 @@ where
             .collect();
         Ok(logs)
     }
+
+    async fn subscribe_logs(
+        &self,
+    ) -> ChainResult<Option<Box<dyn LogSubscription<MerkleTreeInsertion>>>> {
+        let Some(subscriber) = &self.log_subscriber else {
+            return Ok(None);
+        };
+        let subscription = subscriber
+            .subscribe::<InsertedIntoTreeFilter, _, _>(
+                self.provider.clone(),
+                self.reorg_period,
+                |log| MerkleTreeInsertion::new(log.index, H256::from(log.message_id)).into(),
+            )
+            .await?;
+        Ok(Some(subscription))
+    }
 }
 
 #[async_trait]
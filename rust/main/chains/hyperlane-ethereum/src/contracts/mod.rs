@@ -1,8 +1,12 @@
-pub use {interchain_gas::*, mailbox::*, merkle_tree_hook::*, validator_announce::*};
+pub use {
+    interchain_gas::*, log_subscription::LogSubscriber, mailbox::*, merkle_tree_hook::*,
+    validator_announce::*,
+};
 
 pub(crate) use utils::get_finalized_block_number;
 
 mod interchain_gas;
+mod log_subscription;
 mod mailbox;
 mod merkle_tree_hook;
 /// This module contains the implementation of batching
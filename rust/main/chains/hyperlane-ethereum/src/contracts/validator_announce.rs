@@ -1,25 +1,30 @@
 #![allow(clippy::enum_variant_names)]
 #![allow(missing_docs)]
 
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, ops::RangeInclusive, sync::Arc};
 
 use async_trait::async_trait;
 use ethers::providers::Middleware;
 use ethers_contract::builders::ContractCall;
 use hyperlane_core::{
-    Announcement, ChainResult, ContractLocator, HyperlaneAbi, HyperlaneChain, HyperlaneContract,
-    HyperlaneDomain, HyperlaneProvider, SignedType, TxOutcome, ValidatorAnnounce, H160, H256, U256,
+    rpc_clients::call_and_retry_indefinitely, Announcement, ChainResult, ContractLocator,
+    HyperlaneAbi, HyperlaneChain, HyperlaneContract, HyperlaneDomain, HyperlaneProvider, Indexed,
+    Indexer, LogMeta, SequenceAwareIndexer, SignedType, TxOutcome, ValidatorAnnounce,
+    ValidatorAnnouncement, H160, H256, H512, U256,
 };
 use tracing::{instrument, trace};
 
 use crate::{
     interfaces::i_validator_announce::{
-        IValidatorAnnounce as EthereumValidatorAnnounceInternal, IVALIDATORANNOUNCE_ABI,
+        IValidatorAnnounce as EthereumValidatorAnnounceInternal, ValidatorAnnouncementFilter,
+        IVALIDATORANNOUNCE_ABI,
     },
     tx::{fill_tx_gas_params, report_tx},
-    BuildableWithProvider, ConnectionConf, EthereumProvider,
+    BuildableWithProvider, ConnectionConf, EthereumProvider, EthereumReorgPeriod,
 };
 
+use super::utils::{fetch_raw_logs_and_meta, get_finalized_block_number};
+
 impl<M> std::fmt::Display for EthereumValidatorAnnounceInternal<M>
 where
     M: Middleware,
@@ -50,6 +55,136 @@ impl BuildableWithProvider for ValidatorAnnounceBuilder {
     }
 }
 
+pub struct ValidatorAnnouncementIndexerBuilder {
+    pub reorg_period: EthereumReorgPeriod,
+}
+
+#[async_trait]
+impl BuildableWithProvider for ValidatorAnnouncementIndexerBuilder {
+    type Output = Box<dyn SequenceAwareIndexer<ValidatorAnnouncement>>;
+    const NEEDS_SIGNER: bool = false;
+
+    async fn build_with_provider<M: Middleware + 'static>(
+        &self,
+        provider: M,
+        _conn: &ConnectionConf,
+        locator: &ContractLocator,
+    ) -> Self::Output {
+        Box::new(EthereumValidatorAnnouncementIndexer::new(
+            Arc::new(provider),
+            locator,
+            self.reorg_period,
+        ))
+    }
+}
+
+impl From<ValidatorAnnouncementFilter> for ValidatorAnnouncement {
+    fn from(log: ValidatorAnnouncementFilter) -> Self {
+        ValidatorAnnouncement {
+            validator: H256::from(log.validator),
+            storage_location: log.storage_location,
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Struct that retrieves announcements made on an Ethereum ValidatorAnnounce
+pub struct EthereumValidatorAnnouncementIndexer<M>
+where
+    M: Middleware,
+{
+    contract: Arc<EthereumValidatorAnnounceInternal<M>>,
+    provider: Arc<M>,
+    reorg_period: EthereumReorgPeriod,
+}
+
+impl<M> EthereumValidatorAnnouncementIndexer<M>
+where
+    M: Middleware + 'static,
+{
+    /// Create new EthereumValidatorAnnouncementIndexer
+    pub fn new(
+        provider: Arc<M>,
+        locator: &ContractLocator,
+        reorg_period: EthereumReorgPeriod,
+    ) -> Self {
+        Self {
+            contract: Arc::new(EthereumValidatorAnnounceInternal::new(
+                locator.address,
+                provider.clone(),
+            )),
+            provider,
+            reorg_period,
+        }
+    }
+}
+
+#[async_trait]
+impl<M> Indexer<ValidatorAnnouncement> for EthereumValidatorAnnouncementIndexer<M>
+where
+    M: Middleware + 'static,
+{
+    /// Note: This call may return duplicates depending on the provider used
+    #[allow(clippy::blocks_in_conditions)] // TODO: `rustc` 1.80.1 clippy issue
+    async fn fetch_logs_in_range(
+        &self,
+        range: RangeInclusive<u32>,
+    ) -> ChainResult<Vec<(Indexed<ValidatorAnnouncement>, LogMeta)>> {
+        let events = self
+            .contract
+            .validator_announcement_filter()
+            .from_block(*range.start())
+            .to_block(*range.end())
+            .query_with_meta()
+            .await?;
+
+        Ok(events
+            .into_iter()
+            .map(|(log, log_meta)| (ValidatorAnnouncement::from(log).into(), log_meta.into()))
+            .collect())
+    }
+
+    #[allow(clippy::blocks_in_conditions)] // TODO: `rustc` 1.80.1 clippy issue
+    async fn get_finalized_block_number(&self) -> ChainResult<u32> {
+        get_finalized_block_number(&self.provider, &self.reorg_period).await
+    }
+
+    async fn fetch_logs_by_tx_hash(
+        &self,
+        tx_hash: H512,
+    ) -> ChainResult<Vec<(Indexed<ValidatorAnnouncement>, LogMeta)>> {
+        let raw_logs_and_meta = call_and_retry_indefinitely(|| {
+            let provider = self.provider.clone();
+            let contract = self.contract.address();
+            Box::pin(async move {
+                fetch_raw_logs_and_meta::<ValidatorAnnouncementFilter, M>(
+                    tx_hash, provider, contract,
+                )
+                .await
+            })
+        })
+        .await;
+
+        Ok(raw_logs_and_meta
+            .into_iter()
+            .map(|(log, log_meta)| (ValidatorAnnouncement::from(log).into(), log_meta))
+            .collect())
+    }
+}
+
+#[async_trait]
+impl<M> SequenceAwareIndexer<ValidatorAnnouncement> for EthereumValidatorAnnouncementIndexer<M>
+where
+    M: Middleware + 'static,
+{
+    async fn latest_sequence_count_and_tip(&self) -> ChainResult<(Option<u32>, u32)> {
+        // Announcements aren't sequenced, so they're only indexed with the
+        // rate limited cursor, which uses the `Indexer` supertrait.
+        let tip = self.get_finalized_block_number().await?;
+        Ok((None, tip))
+    }
+}
+
 /// A reference to a ValidatorAnnounce contract on some Ethereum chain
 #[derive(Debug)]
 pub struct EthereumValidatorAnnounce<M>
@@ -191,3 +326,75 @@ impl HyperlaneAbi for EthereumValidatorAnnounceAbi {
         crate::extract_fn_map(&IVALIDATORANNOUNCE_ABI)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use ethers::{
+        abi::Token,
+        providers::{MockProvider, Provider},
+        types::{Bytes, Log, H160 as EthersH160, H256 as EthersH256, U256 as EthersU256, U64},
+    };
+    use ethers_contract::EthEvent;
+    use hyperlane_core::{ContractLocator, HyperlaneDomain, Indexer, KnownHyperlaneDomain, H256};
+
+    use crate::{
+        interfaces::i_validator_announce::ValidatorAnnouncementFilter, EthereumReorgPeriod,
+    };
+
+    use super::EthereumValidatorAnnouncementIndexer;
+
+    fn announcement_log(validator: EthersH160, storage_location: &str, block_number: u64) -> Log {
+        let data = ethers::abi::encode(&[Token::String(storage_location.to_owned())]);
+        Log {
+            topics: vec![
+                ValidatorAnnouncementFilter::signature(),
+                EthersH256::from(validator),
+            ],
+            data: Bytes::from(data),
+            block_hash: Some(EthersH256::from_low_u64_be(block_number)),
+            block_number: Some(U64::from(block_number)),
+            transaction_hash: Some(EthersH256::from_low_u64_be(block_number + 1)),
+            transaction_index: Some(U64::zero()),
+            log_index: Some(EthersU256::from(3)),
+            removed: Some(false),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_logs_in_range_decodes_announcements() {
+        let mock_provider = Arc::new(MockProvider::new());
+        let provider = Arc::new(Provider::new(mock_provider.clone()));
+        let domain = HyperlaneDomain::Known(KnownHyperlaneDomain::Ethereum);
+        let indexer = EthereumValidatorAnnouncementIndexer::new(
+            provider,
+            &ContractLocator {
+                domain: &domain,
+                // Address doesn't matter because we're using a MockProvider
+                address: H256::default(),
+            },
+            EthereumReorgPeriod::Blocks(0),
+        );
+
+        let validator = EthersH160::from_low_u64_be(0xabcd);
+        let storage_location = "s3://hyperlane-validator-signatures/us-east-1";
+        mock_provider
+            .push(vec![announcement_log(validator, storage_location, 100)])
+            .unwrap();
+
+        let logs = indexer.fetch_logs_in_range(90..=110).await.unwrap();
+
+        assert_eq!(logs.len(), 1);
+        let (announcement, meta) = &logs[0];
+        // the validator address is left padded into a H256
+        assert_eq!(
+            announcement.inner().validator,
+            H256::from_low_u64_be(0xabcd)
+        );
+        assert_eq!(announcement.inner().storage_location, storage_location);
+        assert_eq!(meta.block_number, 100);
+        assert_eq!(meta.log_index, 3.into());
+    }
+}
@@ -5,12 +5,12 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use ethers::providers::Middleware;
+use ethers::{abi::AbiDecode, providers::Middleware, types::Bytes};
 use tracing::instrument;
 
 use hyperlane_core::{
-    CcipReadIsm, ChainResult, ContractLocator, HyperlaneAbi, HyperlaneChain, HyperlaneContract,
-    HyperlaneDomain, HyperlaneProvider, H256,
+    CcipReadIsm, ChainCommunicationError, ChainResult, ContractLocator, HyperlaneAbi,
+    HyperlaneChain, HyperlaneContract, HyperlaneDomain, HyperlaneProvider, H256,
 };
 
 pub use crate::interfaces::i_ccip_read_ism::{
@@ -89,17 +89,69 @@ impl<M> CcipReadIsm for EthereumCcipReadIsm<M>
 where
     M: Middleware + 'static,
 {
+    /// `getOffchainVerifyInfo` always reverts with an `OffchainLookup` custom error
+    /// (EIP-3668), which is decoded here into a VM-neutral lookup.
     #[instrument(err)]
     #[allow(clippy::blocks_in_conditions)] // TODO: `rustc` 1.80.1 clippy issue
-    async fn get_offchain_verify_info(&self, message: Vec<u8>) -> ChainResult<()> {
-        self.contract
+    async fn get_offchain_verify_info(
+        &self,
+        message: Vec<u8>,
+    ) -> ChainResult<hyperlane_core::OffchainLookup> {
+        let err = match self
+            .contract
             .get_offchain_verify_info(message.into())
             .call()
-            .await?;
-        Ok(())
+            .await
+        {
+            Ok(_) => {
+                return Err(ChainCommunicationError::from_other_str(
+                    "incorrectly configured getOffchainVerifyInfo, expected revert",
+                ))
+            }
+            Err(err) => err,
+        };
+
+        // Depending on the middleware, the revert data is either surfaced as such
+        // or only included in the error message
+        let revert_data = err
+            .as_revert()
+            .cloned()
+            .or_else(|| revert_data_from_error_message(&err.to_string()))
+            .ok_or_else(|| {
+                ChainCommunicationError::from_other_str(&format!(
+                    "unable to parse custom error out of revert: {err}"
+                ))
+            })?;
+
+        let lookup = OffchainLookup::decode(revert_data).map_err(|err| {
+            ChainCommunicationError::from_other_str(&format!(
+                "Failed to decode offchain lookup struct: {err}"
+            ))
+        })?;
+        Ok(lookup.into())
+    }
+}
+
+impl From<OffchainLookup> for hyperlane_core::OffchainLookup {
+    fn from(lookup: OffchainLookup) -> Self {
+        Self {
+            sender: lookup.sender.as_bytes().to_vec(),
+            urls: lookup.urls,
+            call_data: lookup.call_data.to_vec(),
+        }
     }
 }
 
+/// Extracts the first hex string (e.g. `0x556f1830...`) from an error message
+fn revert_data_from_error_message(message: &str) -> Option<Bytes> {
+    let start = message.find("0x")? + 2;
+    let hex_str: String = message[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_hexdigit())
+        .collect();
+    hex::decode(hex_str).ok().map(Into::into)
+}
+
 pub struct EthereumCcipReadIsmAbi;
 
 impl HyperlaneAbi for EthereumCcipReadIsmAbi {
@@ -109,3 +161,18 @@ impl HyperlaneAbi for EthereumCcipReadIsmAbi {
         crate::extract_fn_map(&ICCIPREADISM_ABI)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_revert_data_from_error_message() {
+        let message = "Contract call reverted with data: 0x556f1830abcd, some other context 0x1234";
+        assert_eq!(
+            revert_data_from_error_message(message),
+            Some(Bytes::from(vec![0x55, 0x6f, 0x18, 0x30, 0xab, 0xcd]))
+        );
+        assert_eq!(revert_data_from_error_message("execution reverted"), None);
+    }
+}
@@ -7,7 +7,7 @@ use std::time::Duration;
 use async_trait::async_trait;
 use derive_new::new;
 use ethers::prelude::Middleware;
-use ethers::types::{Block, TransactionReceipt, H160, H256 as EthersH256};
+use ethers::types::{Block, Bytes, TransactionReceipt, H160, H256 as EthersH256};
 use ethers_contract::{builders::ContractCall, Multicall, MulticallResult};
 use ethers_core::abi::{Address, Function};
 use ethers_core::types::transaction::eip2718::TypedTransaction;
@@ -120,6 +120,10 @@ pub trait EvmProviderForLander: Send + Sync {
     /// Send transaction into blockchain
     async fn send(&self, tx: &TypedTransaction, function: &Function) -> ChainResult<H256>;
 
+    /// Fill and sign a transaction without broadcasting it.
+    /// Returns the RLP encoded signed transaction.
+    async fn sign(&self, tx: &TypedTransaction) -> ChainResult<Bytes>;
+
     /// Read-only call into blockchain which returns a boolean
     async fn check(&self, tx: &TypedTransaction, function: &Function) -> ChainResult<bool>;
 
@@ -247,6 +251,24 @@ where
         Ok(pending.tx_hash().into())
     }
 
+    async fn sign(&self, tx: &TypedTransaction) -> ChainResult<Bytes> {
+        let mut tx = tx.clone();
+        self.provider
+            .fill_transaction(&mut tx, None)
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+        let from = *tx.from().ok_or_else(|| {
+            ChainCommunicationError::CustomError("Transaction missing from address".into())
+        })?;
+        let signature = self
+            .provider
+            .sign_transaction(&tx, from)
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+
+        Ok(tx.rlp_signed(&signature))
+    }
+
     async fn check(&self, tx: &TypedTransaction, function: &Function) -> ChainResult<bool> {
         let contract_call = self.build_contract_call::<bool>(tx.clone(), function.clone());
         let success = contract_call
@@ -8,8 +8,14 @@ use hyperlane_core::{
     HyperlaneSigner, HyperlaneSignerError, Signature as HyperlaneSignature, H160, H256,
 };
 
+mod remote;
+mod service;
 mod singleton;
+mod threshold;
+pub use remote::*;
+pub use service::RemoteSignerError;
 pub use singleton::*;
+pub use threshold::*;
 
 /// Ethereum-supported signer types
 #[derive(Debug, Clone)]
@@ -18,6 +24,10 @@ pub enum Signers {
     Local(LocalWallet),
     /// A signer using a key stored in aws kms
     Aws(AwsSigner),
+    /// A signer using a key held by a remote signing service
+    Remote(RemoteSigner),
+    /// A signer using a key shared between the parties of a threshold signing scheme
+    Threshold(ThresholdSigner),
 }
 
 impl From<LocalWallet> for Signers {
@@ -32,6 +42,18 @@ impl From<AwsSigner> for Signers {
     }
 }
 
+impl From<RemoteSigner> for Signers {
+    fn from(s: RemoteSigner) -> Self {
+        Signers::Remote(s)
+    }
+}
+
+impl From<ThresholdSigner> for Signers {
+    fn from(s: ThresholdSigner) -> Self {
+        Signers::Threshold(s)
+    }
+}
+
 #[async_trait]
 impl Signer for Signers {
     type Error = SignersError;
@@ -43,6 +65,8 @@ impl Signer for Signers {
         match self {
             Signers::Local(signer) => Ok(signer.sign_message(message).await?),
             Signers::Aws(signer) => Ok(signer.sign_message(message).await?),
+            Signers::Remote(signer) => Ok(signer.sign_message(message).await?),
+            Signers::Threshold(signer) => Ok(signer.sign_message(message).await?),
         }
     }
 
@@ -50,6 +74,8 @@ impl Signer for Signers {
         match self {
             Signers::Local(signer) => Ok(signer.sign_transaction(message).await?),
             Signers::Aws(signer) => Ok(signer.sign_transaction(message).await?),
+            Signers::Remote(signer) => Ok(signer.sign_transaction(message).await?),
+            Signers::Threshold(signer) => Ok(signer.sign_transaction(message).await?),
         }
     }
 
@@ -60,6 +86,8 @@ impl Signer for Signers {
         match self {
             Signers::Local(signer) => Ok(signer.sign_typed_data(payload).await?),
             Signers::Aws(signer) => Ok(signer.sign_typed_data(payload).await?),
+            Signers::Remote(signer) => Ok(signer.sign_typed_data(payload).await?),
+            Signers::Threshold(signer) => Ok(signer.sign_typed_data(payload).await?),
         }
     }
 
@@ -67,6 +95,8 @@ impl Signer for Signers {
         match self {
             Signers::Local(signer) => signer.address(),
             Signers::Aws(signer) => signer.address(),
+            Signers::Remote(signer) => signer.address(),
+            Signers::Threshold(signer) => signer.address(),
         }
     }
 
@@ -74,6 +104,8 @@ impl Signer for Signers {
         match self {
             Signers::Local(signer) => signer.chain_id(),
             Signers::Aws(signer) => signer.chain_id(),
+            Signers::Remote(signer) => signer.chain_id(),
+            Signers::Threshold(signer) => signer.chain_id(),
         }
     }
 
@@ -81,6 +113,8 @@ impl Signer for Signers {
         match self {
             Signers::Local(signer) => signer.with_chain_id(chain_id).into(),
             Signers::Aws(signer) => signer.with_chain_id(chain_id).into(),
+            Signers::Remote(signer) => signer.with_chain_id(chain_id).into(),
+            Signers::Threshold(signer) => signer.with_chain_id(chain_id).into(),
         }
     }
 }
@@ -109,6 +143,9 @@ pub enum SignersError {
     /// Wallet Signer Error
     #[error("{0}")]
     WalletError(#[from] WalletError),
+    /// Remote or Threshold Signer Error
+    #[error("{0}")]
+    RemoteSignerError(#[from] RemoteSignerError),
 }
 
 #[cfg(test)]
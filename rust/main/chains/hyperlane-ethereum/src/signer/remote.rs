@@ -0,0 +1,210 @@
+use std::{str::FromStr, time::Duration};
+
+use async_trait::async_trait;
+use ethers::prelude::{Address, Signature, H256};
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::types::transaction::eip712::Eip712;
+use ethers::utils::{hash_message, keccak256};
+use ethers_signers::Signer;
+use serde::Serialize;
+use url::Url;
+
+use super::service::{eip155_v, RemoteSignerError, SigningService};
+
+const REMOTE_SIGNER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A signer that delegates signing to a remote service implementing the
+/// Web3Signer eth1 signing API (`POST /api/v1/eth1/sign/{identifier}`).
+///
+/// The service signs the keccak256 hash of the raw data it receives, so every
+/// kind of payload is pre-encoded locally. Each returned signature is checked
+/// to recover to the configured address.
+#[derive(Clone, Debug)]
+pub struct RemoteSigner {
+    service: SigningService,
+}
+
+#[derive(Serialize)]
+struct SignRequest {
+    data: String,
+}
+
+impl RemoteSigner {
+    /// Create a new remote signer for the key of `address`, served by the
+    /// signing service at `url`.
+    pub fn new(url: &Url, address: Address) -> Result<Self, RemoteSignerError> {
+        let identifier = format!("{address:?}");
+        let service = SigningService::new(
+            url,
+            &["api", "v1", "eth1", "sign", &identifier],
+            address,
+            REMOTE_SIGNER_TIMEOUT,
+        )?;
+        Ok(Self { service })
+    }
+
+    /// Asks the remote service to sign `data` and checks that the signature
+    /// over `hash`, which is `keccak256(data)`, recovers to our address.
+    async fn sign_data(&self, data: &[u8], hash: H256) -> Result<Signature, RemoteSignerError> {
+        let body = self
+            .service
+            .post(&SignRequest {
+                data: format!("0x{}", hex::encode(data)),
+            })
+            .await?
+            .text()
+            .await?;
+
+        // the signature is returned as a plain hex string, some
+        // implementations wrap it in a JSON string
+        let mut signature = Signature::from_str(body.trim().trim_matches('"'))
+            .map_err(|err| RemoteSignerError::InvalidSignature(err.to_string()))?;
+        if signature.v < 27 {
+            signature.v += 27;
+        }
+
+        let recovered = signature
+            .recover(hash)
+            .map_err(|err| RemoteSignerError::InvalidSignature(err.to_string()))?;
+        if recovered != self.service.address {
+            return Err(RemoteSignerError::AddressMismatch {
+                expected: self.service.address,
+                recovered: vec![recovered],
+            });
+        }
+        Ok(signature)
+    }
+}
+
+#[async_trait]
+impl Signer for RemoteSigner {
+    type Error = RemoteSignerError;
+
+    async fn sign_message<S: Send + Sync + AsRef<[u8]>>(
+        &self,
+        message: S,
+    ) -> Result<Signature, Self::Error> {
+        let message = message.as_ref();
+        let mut data = format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+        data.extend_from_slice(message);
+        self.sign_data(&data, hash_message(message)).await
+    }
+
+    async fn sign_transaction(&self, tx: &TypedTransaction) -> Result<Signature, Self::Error> {
+        let (tx, chain_id) = self.service.with_chain_id(tx);
+        let mut signature = self.sign_data(&tx.rlp(), tx.sighash()).await?;
+        signature.v = eip155_v(signature.v, chain_id);
+        Ok(signature)
+    }
+
+    async fn sign_typed_data<T: Eip712 + Send + Sync>(
+        &self,
+        payload: &T,
+    ) -> Result<Signature, Self::Error> {
+        let domain_separator = payload
+            .domain_separator()
+            .map_err(|err| RemoteSignerError::Eip712Error(err.to_string()))?;
+        let struct_hash = payload
+            .struct_hash()
+            .map_err(|err| RemoteSignerError::Eip712Error(err.to_string()))?;
+
+        let data = [&[0x19, 0x01], &domain_separator[..], &struct_hash[..]].concat();
+        let hash = H256::from(keccak256(&data));
+        self.sign_data(&data, hash).await
+    }
+
+    fn address(&self) -> Address {
+        self.service.address
+    }
+
+    fn chain_id(&self) -> u64 {
+        self.service.chain_id
+    }
+
+    fn with_chain_id<T: Into<u64>>(mut self, chain_id: T) -> Self {
+        self.service.chain_id = chain_id.into();
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use axum::{extract::State, routing::post, Json, Router};
+    use ethers::utils::keccak256;
+    use ethers_signers::{LocalWallet, Signer};
+    use hyperlane_core::{HyperlaneSigner, HyperlaneSignerExt};
+    use serde::Deserialize;
+    use url::Url;
+
+    use super::RemoteSigner;
+    use crate::signer::service::test_utils::{checkpoint, serve, transaction, KEY, OTHER_KEY};
+    use crate::{RemoteSignerError, Signers};
+
+    #[derive(Deserialize)]
+    struct MockSignRequest {
+        data: String,
+    }
+
+    async fn mock_sign(
+        State(wallet): State<LocalWallet>,
+        Json(request): Json<MockSignRequest>,
+    ) -> String {
+        let data = hex::decode(request.data.trim_start_matches("0x")).unwrap();
+        let signature = wallet.sign_hash(keccak256(data).into()).unwrap();
+        format!("0x{signature}")
+    }
+
+    /// A minimal Web3Signer stand-in that signs with a local key
+    async fn mock_signer(wallet: LocalWallet) -> Url {
+        serve(
+            Router::new()
+                .route("/api/v1/eth1/sign/{identifier}", post(mock_sign))
+                .with_state(wallet),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn it_matches_local_wallet() {
+        let wallet: LocalWallet = KEY.parse().unwrap();
+        let remote = RemoteSigner::new(&mock_signer(wallet.clone()).await, wallet.address())
+            .unwrap()
+            .with_chain_id(5u64);
+        let wallet = wallet.with_chain_id(5u64);
+
+        assert_eq!(
+            remote.sign_message("hello").await.unwrap(),
+            wallet.sign_message("hello").await.unwrap()
+        );
+
+        let tx = transaction(wallet.address());
+        assert_eq!(
+            remote.sign_transaction(&tx).await.unwrap(),
+            wallet.sign_transaction(&tx).await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn it_signs_checkpoints() {
+        let wallet: LocalWallet = KEY.parse().unwrap();
+        let signer: Signers =
+            RemoteSigner::new(&mock_signer(wallet.clone()).await, wallet.address())
+                .unwrap()
+                .into();
+
+        let signed = signer.sign(checkpoint()).await.expect("!sign");
+        signed.verify(signer.eth_address()).expect("!verify");
+    }
+
+    #[tokio::test]
+    async fn it_rejects_signatures_from_other_keys() {
+        let wallet: LocalWallet = KEY.parse().unwrap();
+        let other: LocalWallet = OTHER_KEY.parse().unwrap();
+        let remote = RemoteSigner::new(&mock_signer(other).await, wallet.address()).unwrap();
+
+        assert!(matches!(
+            remote.sign_message("hello").await,
+            Err(RemoteSignerError::AddressMismatch { .. })
+        ));
+    }
+}
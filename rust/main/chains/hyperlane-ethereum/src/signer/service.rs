@@ -0,0 +1,223 @@
+use std::{fmt, time::Duration};
+
+use ethers::prelude::Address;
+use ethers::types::transaction::eip2718::TypedTransaction;
+use reqwest::{Client, Response};
+use serde::Serialize;
+use url::Url;
+
+/// The signing endpoint of a remote service holding the key of `address`,
+/// shared by the signers which delegate signing over HTTP.
+#[derive(Clone)]
+pub(crate) struct SigningService {
+    client: Client,
+    sign_url: Url,
+    pub(crate) address: Address,
+    pub(crate) chain_id: u64,
+}
+
+impl fmt::Debug for SigningService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningService")
+            .field("sign_url", &self.sign_url.as_str())
+            .field("address", &self.address)
+            .field("chain_id", &self.chain_id)
+            .finish()
+    }
+}
+
+impl SigningService {
+    /// Create a client for the endpoint at `path` below the base `url` of the
+    /// service. Each element of `path` is a single, percent-encoded segment.
+    pub(crate) fn new(
+        url: &Url,
+        path: &[&str],
+        address: Address,
+        timeout: Duration,
+    ) -> Result<Self, RemoteSignerError> {
+        let mut sign_url = url.clone();
+        sign_url
+            .path_segments_mut()
+            .map_err(|_| RemoteSignerError::InvalidUrl(url.clone()))?
+            .pop_if_empty()
+            .extend(path);
+        let client = Client::builder().timeout(timeout).build()?;
+        Ok(Self {
+            client,
+            sign_url,
+            address,
+            chain_id: 1,
+        })
+    }
+
+    /// Posts `request` to the signing endpoint, failing unless the service
+    /// responds with a success status.
+    pub(crate) async fn post<T: Serialize>(
+        &self,
+        request: &T,
+    ) -> Result<Response, RemoteSignerError> {
+        let response = self
+            .client
+            .post(self.sign_url.clone())
+            .json(request)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await?;
+            return Err(RemoteSignerError::UnexpectedStatus { status, body });
+        }
+        Ok(response)
+    }
+
+    /// Returns `tx` with its chain id, which defaults to ours.
+    pub(crate) fn with_chain_id(&self, tx: &TypedTransaction) -> (TypedTransaction, u64) {
+        let mut tx = tx.clone();
+        let chain_id = tx.chain_id().map(|id| id.as_u64()).unwrap_or(self.chain_id);
+        tx.set_chain_id(chain_id);
+        (tx, chain_id)
+    }
+}
+
+/// Converts the `v` of 27 or 28 of a transaction signature to its EIP-155
+/// form. This is the same normalization as `ethers::signers::Wallet` applies to
+/// all transaction types, and is converted back when encoding typed transactions.
+pub(crate) fn eip155_v(v: u64, chain_id: u64) -> u64 {
+    v.saturating_sub(27)
+        .saturating_add(35)
+        .saturating_add(chain_id.saturating_mul(2))
+}
+
+/// Error types for the signers delegating to a remote signing service
+#[derive(Debug, thiserror::Error)]
+pub enum RemoteSignerError {
+    /// Error sending the request to the signing service
+    #[error("Remote signer request failed: {0}")]
+    RequestError(#[from] reqwest::Error),
+    /// The signing service url can't have a path
+    #[error("Invalid remote signer url: {0}")]
+    InvalidUrl(Url),
+    /// The signing service rejected the request
+    #[error("Remote signer responded with {status}: {body}")]
+    UnexpectedStatus {
+        /// Response status
+        status: reqwest::StatusCode,
+        /// Response body
+        body: String,
+    },
+    /// The signing service returned something that is not a signature
+    #[error("Remote signer returned an invalid signature: {0}")]
+    InvalidSignature(String),
+    /// The signature does not recover to the configured address
+    #[error("Remote signature recovers to {recovered:?}, expected {expected:?}")]
+    AddressMismatch {
+        /// The configured address
+        expected: Address,
+        /// The addresses recovered from the signature
+        recovered: Vec<Address>,
+    },
+    /// Error encoding typed data
+    #[error("Failed to encode EIP-712 typed data: {0}")]
+    Eip712Error(String),
+}
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use axum::Router;
+    use ethers::types::{transaction::eip2718::TypedTransaction, Address, TransactionRequest};
+    use hyperlane_core::{Checkpoint, CheckpointWithMessageId, H256};
+    use url::Url;
+
+    /// The key the mock services sign with
+    pub(crate) const KEY: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+    /// A key which is not the one of the signer under test
+    pub(crate) const OTHER_KEY: &str =
+        "2222222222222222222222222222222222222222222222222222222222222222";
+
+    /// Serves `app` on a random local port and returns its url
+    pub(crate) async fn serve(app: Router) -> Url {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        Url::parse(&format!("http://{addr}")).unwrap()
+    }
+
+    pub(crate) fn transaction(to: Address) -> TypedTransaction {
+        TransactionRequest::new()
+            .to(to)
+            .value(1)
+            .nonce(3)
+            .gas(21000)
+            .gas_price(1)
+            .into()
+    }
+
+    pub(crate) fn checkpoint() -> CheckpointWithMessageId {
+        CheckpointWithMessageId {
+            checkpoint: Checkpoint {
+                merkle_tree_hook_address: H256::repeat_byte(2),
+                mailbox_domain: 5,
+                root: H256::repeat_byte(1),
+                index: 123,
+            },
+            message_id: H256::repeat_byte(3),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use ethers::prelude::Address;
+    use url::Url;
+
+    use super::{eip155_v, RemoteSignerError, SigningService};
+
+    fn sign_url(url: &str, path: &[&str]) -> Result<String, RemoteSignerError> {
+        let url = Url::parse(url).unwrap();
+        SigningService::new(&url, path, Address::zero(), Duration::from_secs(1))
+            .map(|service| service.sign_url.to_string())
+    }
+
+    #[test]
+    fn it_appends_the_path_to_the_base_url() {
+        let path = ["v1", "keys", "key", "sign"];
+        assert_eq!(
+            sign_url("http://signer:8080", &path).unwrap(),
+            "http://signer:8080/v1/keys/key/sign"
+        );
+        assert_eq!(
+            sign_url("http://signer/prefix", &path).unwrap(),
+            "http://signer/prefix/v1/keys/key/sign"
+        );
+        assert_eq!(
+            sign_url("http://signer/prefix/", &path).unwrap(),
+            "http://signer/prefix/v1/keys/key/sign"
+        );
+    }
+
+    #[test]
+    fn it_encodes_path_segments() {
+        assert_eq!(
+            sign_url("http://signer", &["keys", "a/b?c#d", "sign"]).unwrap(),
+            "http://signer/keys/a%2Fb%3Fc%23d/sign"
+        );
+    }
+
+    #[test]
+    fn it_rejects_urls_without_a_path() {
+        assert!(matches!(
+            sign_url("mailto:signer@example.com", &["sign"]),
+            Err(RemoteSignerError::InvalidUrl(_))
+        ));
+    }
+
+    #[test]
+    fn it_converts_v_to_eip155() {
+        assert_eq!(eip155_v(27, 1), 37);
+        assert_eq!(eip155_v(28, 1), 38);
+        assert_eq!(eip155_v(27, 5), 45);
+    }
+}
@@ -0,0 +1,310 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use ethers::prelude::{Address, Signature, H256, U256};
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::types::transaction::eip712::Eip712;
+use ethers::utils::hash_message;
+use ethers_signers::Signer;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use super::service::{eip155_v, RemoteSignerError, SigningService};
+
+/// A threshold signing session involves several rounds between the parties,
+/// so it takes longer than a single key signing a hash.
+const THRESHOLD_SIGNER_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Order of the secp256k1 curve
+const SECP256K1_ORDER: U256 = U256([
+    0xBFD25E8CD0364141,
+    0xBAAEDCE6AF48A03B,
+    0xFFFFFFFFFFFFFFFE,
+    0xFFFFFFFFFFFFFFFF,
+]);
+
+/// Largest `s` accepted by `ecrecover` based verifiers, i.e. half the curve order
+const SECP256K1_HALF_ORDER: U256 = U256([
+    0xDFE92F46681B20A0,
+    0x5D576E7357A4501D,
+    0xFFFFFFFFFFFFFFFF,
+    0x7FFFFFFFFFFFFFFF,
+]);
+
+/// A signer whose key is split between the parties of a threshold ECDSA
+/// scheme (e.g. GG20 or a FROST-style coordinator), so that no single machine
+/// holds the whole key.
+///
+/// Digests are sent to the coordinator (`POST /v1/keys/{keyId}/sign`), which
+/// runs a t-of-n signing session and returns the signature. Coordinators
+/// usually return `r || s` without a recovery id and do not normalize `s`, so
+/// the signature is brought into the low-s form expected on chain and its
+/// recovery id is found by recovering the configured group address. The result
+/// is indistinguishable from a signature by a single secp256k1 key.
+#[derive(Clone, Debug)]
+pub struct ThresholdSigner {
+    service: SigningService,
+}
+
+#[derive(Serialize)]
+struct SignRequest {
+    digest: String,
+}
+
+#[derive(Deserialize)]
+struct SignResponse {
+    signature: String,
+}
+
+impl ThresholdSigner {
+    /// Create a new threshold signer for the shared key `key_id` of the
+    /// coordinator at `url`. `address` is the address of the group public key.
+    pub fn new(url: &Url, key_id: &str, address: Address) -> Result<Self, RemoteSignerError> {
+        let service = SigningService::new(
+            url,
+            &["v1", "keys", key_id, "sign"],
+            address,
+            THRESHOLD_SIGNER_TIMEOUT,
+        )?;
+        Ok(Self { service })
+    }
+
+    /// Asks the coordinator to sign `digest` and returns the low-s signature
+    /// with a `v` of 27 or 28 which recovers to our address.
+    async fn sign_digest(&self, digest: H256) -> Result<Signature, RemoteSignerError> {
+        let response: SignResponse = self
+            .service
+            .post(&SignRequest {
+                digest: format!("{digest:?}"),
+            })
+            .await?
+            .json()
+            .await?;
+
+        let bytes = hex::decode(response.signature.trim_start_matches("0x"))
+            .map_err(|err| RemoteSignerError::InvalidSignature(err.to_string()))?;
+        // a trailing recovery id is ignored, it is recomputed below
+        let (r, s) = match bytes.len() {
+            64 | 65 => (
+                U256::from_big_endian(&bytes[..32]),
+                U256::from_big_endian(&bytes[32..64]),
+            ),
+            len => {
+                return Err(RemoteSignerError::InvalidSignature(format!(
+                    "expected 64 or 65 bytes, got {len}"
+                )))
+            }
+        };
+        if r.is_zero() || s.is_zero() || r >= SECP256K1_ORDER || s >= SECP256K1_ORDER {
+            return Err(RemoteSignerError::InvalidSignature(
+                "signature scalars out of range".to_owned(),
+            ));
+        }
+        let s = if s > SECP256K1_HALF_ORDER {
+            SECP256K1_ORDER.saturating_sub(s)
+        } else {
+            s
+        };
+
+        let mut recovered = Vec::with_capacity(2);
+        for v in [27, 28] {
+            let signature = Signature { r, s, v };
+            match signature.recover(digest) {
+                Ok(address) if address == self.service.address => return Ok(signature),
+                Ok(address) => recovered.push(address),
+                Err(_) => {}
+            }
+        }
+        Err(RemoteSignerError::AddressMismatch {
+            expected: self.service.address,
+            recovered,
+        })
+    }
+}
+
+#[async_trait]
+impl Signer for ThresholdSigner {
+    type Error = RemoteSignerError;
+
+    async fn sign_message<S: Send + Sync + AsRef<[u8]>>(
+        &self,
+        message: S,
+    ) -> Result<Signature, Self::Error> {
+        self.sign_digest(hash_message(message)).await
+    }
+
+    async fn sign_transaction(&self, tx: &TypedTransaction) -> Result<Signature, Self::Error> {
+        let (tx, chain_id) = self.service.with_chain_id(tx);
+        let mut signature = self.sign_digest(tx.sighash()).await?;
+        signature.v = eip155_v(signature.v, chain_id);
+        Ok(signature)
+    }
+
+    async fn sign_typed_data<T: Eip712 + Send + Sync>(
+        &self,
+        payload: &T,
+    ) -> Result<Signature, Self::Error> {
+        let digest = payload
+            .encode_eip712()
+            .map_err(|err| RemoteSignerError::Eip712Error(err.to_string()))?;
+        self.sign_digest(digest.into()).await
+    }
+
+    fn address(&self) -> Address {
+        self.service.address
+    }
+
+    fn chain_id(&self) -> u64 {
+        self.service.chain_id
+    }
+
+    fn with_chain_id<T: Into<u64>>(mut self, chain_id: T) -> Self {
+        self.service.chain_id = chain_id.into();
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use axum::{
+        extract::{Path, State},
+        http::StatusCode,
+        routing::post,
+        Json, Router,
+    };
+    use ethers_signers::{LocalWallet, Signer};
+    use hyperlane_core::{HyperlaneSigner, HyperlaneSignerExt};
+    use serde::Deserialize;
+    use serde_json::{json, Value};
+    use url::Url;
+
+    use super::{ThresholdSigner, SECP256K1_ORDER};
+    use crate::signer::service::test_utils::{checkpoint, serve, transaction, KEY, OTHER_KEY};
+    use crate::{RemoteSignerError, Signers};
+
+    const KEY_ID: &str = "validator-key";
+
+    #[derive(Deserialize)]
+    struct MockSignRequest {
+        digest: String,
+    }
+
+    /// How the mock coordinator encodes its signatures
+    #[derive(Clone, Copy)]
+    enum Encoding {
+        /// `r || s || v`, as produced by a single key
+        Recoverable,
+        /// `r || s` with `s` in the high half of the curve order
+        HighS,
+    }
+
+    async fn mock_sign(
+        State((wallet, encoding)): State<(LocalWallet, Encoding)>,
+        Path(key_id): Path<String>,
+        Json(request): Json<MockSignRequest>,
+    ) -> Result<Json<Value>, StatusCode> {
+        if key_id != KEY_ID {
+            return Err(StatusCode::NOT_FOUND);
+        }
+        let digest = request.digest.parse().unwrap();
+        let signature = wallet.sign_hash(digest).unwrap();
+        let bytes = match encoding {
+            Encoding::Recoverable => signature.to_vec(),
+            Encoding::HighS => {
+                let mut bytes = [0u8; 64];
+                signature.r.to_big_endian(&mut bytes[..32]);
+                SECP256K1_ORDER
+                    .saturating_sub(signature.s)
+                    .to_big_endian(&mut bytes[32..]);
+                bytes.to_vec()
+            }
+        };
+        Ok(Json(
+            json!({ "signature": format!("0x{}", hex::encode(bytes)) }),
+        ))
+    }
+
+    /// A coordinator stand-in that signs with a single local key
+    async fn mock_coordinator(wallet: LocalWallet, encoding: Encoding) -> Url {
+        serve(
+            Router::new()
+                .route("/v1/keys/{key_id}/sign", post(mock_sign))
+                .with_state((wallet, encoding)),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn it_matches_local_wallet() {
+        let wallet: LocalWallet = KEY.parse().unwrap();
+        for encoding in [Encoding::Recoverable, Encoding::HighS] {
+            let url = mock_coordinator(wallet.clone(), encoding).await;
+            let threshold = ThresholdSigner::new(&url, KEY_ID, wallet.address())
+                .unwrap()
+                .with_chain_id(5u64);
+            let wallet = wallet.clone().with_chain_id(5u64);
+
+            assert_eq!(
+                threshold.sign_message("hello").await.unwrap(),
+                wallet.sign_message("hello").await.unwrap()
+            );
+
+            let tx = transaction(wallet.address());
+            assert_eq!(
+                threshold.sign_transaction(&tx).await.unwrap(),
+                wallet.sign_transaction(&tx).await.unwrap()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn it_signs_checkpoints() {
+        let wallet: LocalWallet = KEY.parse().unwrap();
+        let url = mock_coordinator(wallet.clone(), Encoding::HighS).await;
+        let signer: Signers = ThresholdSigner::new(&url, KEY_ID, wallet.address())
+            .unwrap()
+            .into();
+        let signed = signer.sign(checkpoint()).await.expect("!sign");
+        assert!(signed.signature.v == 27 || signed.signature.v == 28);
+        signed.verify(signer.eth_address()).expect("!verify");
+    }
+
+    #[tokio::test]
+    async fn it_rejects_signatures_from_other_keys() {
+        let wallet: LocalWallet = KEY.parse().unwrap();
+        let other: LocalWallet = OTHER_KEY.parse().unwrap();
+        let url = mock_coordinator(other, Encoding::Recoverable).await;
+        let threshold = ThresholdSigner::new(&url, KEY_ID, wallet.address()).unwrap();
+
+        assert!(matches!(
+            threshold.sign_message("hello").await,
+            Err(RemoteSignerError::AddressMismatch { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_reports_failed_sessions() {
+        let wallet: LocalWallet = KEY.parse().unwrap();
+        let url = mock_coordinator(wallet.clone(), Encoding::Recoverable).await;
+        let threshold = ThresholdSigner::new(&url, "unknown-key", wallet.address()).unwrap();
+
+        assert!(matches!(
+            threshold.sign_message("hello").await,
+            Err(RemoteSignerError::UnexpectedStatus { status, .. }) if status == 404
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_encodes_key_ids_into_a_single_segment() {
+        let wallet: LocalWallet = KEY.parse().unwrap();
+        let url = mock_coordinator(wallet.clone(), Encoding::Recoverable).await;
+        // without encoding, this would reach the coordinator as `/v1/keys/validator-key/sign`
+        let threshold =
+            ThresholdSigner::new(&url, "../keys/validator-key", wallet.address()).unwrap();
+
+        assert!(matches!(
+            threshold.sign_message("hello").await,
+            Err(RemoteSignerError::UnexpectedStatus { status, .. }) if status == 404
+        ));
+    }
+}
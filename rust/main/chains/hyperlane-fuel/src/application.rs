@@ -0,0 +1,3 @@
+pub use operation_verifier::FuelApplicationOperationVerifier;
+
+mod operation_verifier;
@@ -0,0 +1,135 @@
+use std::io::Cursor;
+
+use async_trait::async_trait;
+use derive_new::new;
+use tracing::trace;
+
+use hyperlane_core::{Decode, HyperlaneMessage, U256};
+use hyperlane_operation_verifier::{
+    ApplicationOperationVerifier, ApplicationOperationVerifierReport,
+};
+use hyperlane_warp_route::TokenMessage;
+use ApplicationOperationVerifierReport::{MalformedMessage, ZeroAmount};
+
+const WARP_ROUTE_MARKER: &str = "/";
+
+/// Application operation verifier for Fuel
+#[derive(new)]
+pub struct FuelApplicationOperationVerifier {}
+
+#[async_trait]
+impl ApplicationOperationVerifier for FuelApplicationOperationVerifier {
+    async fn verify(
+        &self,
+        app_context: &Option<String>,
+        message: &HyperlaneMessage,
+    ) -> Option<ApplicationOperationVerifierReport> {
+        trace!(
+            ?app_context,
+            ?message,
+            "Fuel application operation verifier",
+        );
+
+        Self::verify_message(app_context, message)
+    }
+}
+
+impl FuelApplicationOperationVerifier {
+    fn verify_message(
+        app_context: &Option<String>,
+        message: &HyperlaneMessage,
+    ) -> Option<ApplicationOperationVerifierReport> {
+        let context = match app_context {
+            Some(c) => c,
+            None => return None,
+        };
+
+        if !context.contains(WARP_ROUTE_MARKER) {
+            return None;
+        }
+
+        // Starting from this point we assume that we are in a warp route context
+
+        let mut reader = Cursor::new(message.body.as_slice());
+        let token_message = match TokenMessage::read_from(&mut reader) {
+            Ok(m) => m,
+            Err(_) => return Some(MalformedMessage(message.clone())),
+        };
+
+        if token_message.amount() == U256::zero() {
+            return Some(ZeroAmount);
+        }
+
+        // Fuel assets are u64 denominated, larger amounts can never be minted or released
+        if token_message.amount() > U256::from(u64::MAX) {
+            return Some(MalformedMessage(message.clone()));
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use hyperlane_core::Encode;
+    use hyperlane_core::H256;
+
+    fn message_with_amount(amount: U256) -> HyperlaneMessage {
+        let token_message = TokenMessage::new(H256::zero(), amount, vec![]);
+        let mut encoded = vec![];
+        token_message.write_to(&mut encoded).unwrap();
+        HyperlaneMessage {
+            body: encoded,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_amount_fits_u64() {
+        let app_context = Some("H/warp-route".to_string());
+        let message = message_with_amount(U256::from(u64::MAX));
+
+        // when
+        let report = FuelApplicationOperationVerifier::verify_message(&app_context, &message);
+
+        // then
+        assert_eq!(report, None);
+    }
+
+    #[test]
+    fn test_amount_too_big() {
+        let app_context = Some("H/warp-route".to_string());
+        let message = message_with_amount(U256::from(u64::MAX).saturating_add(U256::one()));
+
+        // when
+        let report = FuelApplicationOperationVerifier::verify_message(&app_context, &message);
+
+        // then
+        assert_eq!(report.unwrap(), MalformedMessage(message));
+    }
+
+    #[test]
+    fn test_zero_amount() {
+        let app_context = Some("H/warp-route".to_string());
+        let message = message_with_amount(U256::zero());
+
+        // when
+        let report = FuelApplicationOperationVerifier::verify_message(&app_context, &message);
+
+        // then
+        assert_eq!(report.unwrap(), ZeroAmount);
+    }
+
+    #[test]
+    fn test_not_warp_route() {
+        let app_context = Some("not-a-warp-route".to_string());
+        let message = HyperlaneMessage::default();
+
+        // when
+        let report = FuelApplicationOperationVerifier::verify_message(&app_context, &message);
+
+        // then
+        assert_eq!(report, None);
+    }
+}
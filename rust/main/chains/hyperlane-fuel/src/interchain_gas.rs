@@ -1,29 +1,64 @@
 use std::ops::RangeInclusive;
 
 use async_trait::async_trait;
+use fuels::prelude::{Bech32ContractId, WalletUnlocked};
 
 use hyperlane_core::{
-    ChainResult, HyperlaneChain, HyperlaneContract, Indexed, Indexer, InterchainGasPaymaster,
+    ChainResult, ContractLocator, HyperlaneChain, HyperlaneContract, Indexed, Indexer,
+    InterchainGasPaymaster, SequenceAwareIndexer, U256,
 };
 use hyperlane_core::{HyperlaneDomain, HyperlaneProvider, InterchainGasPayment, LogMeta, H256};
 
+use crate::{
+    contracts::interchain_gas_paymaster::{
+        GasPaymentEvent, InterchainGasPaymaster as FuelInterchainGasPaymasterInner,
+    },
+    conversions::*,
+    provider::decode_log,
+    ConnectionConf, FuelProvider,
+};
+
 /// A reference to an IGP contract on some Fuel chain
 #[derive(Debug)]
-pub struct FuelInterchainGasPaymaster {}
+pub struct FuelInterchainGasPaymaster {
+    contract: FuelInterchainGasPaymasterInner<WalletUnlocked>,
+    domain: HyperlaneDomain,
+    provider: FuelProvider,
+}
+
+impl FuelInterchainGasPaymaster {
+    /// Create a new fuel IGP contract interface
+    pub async fn new(
+        conf: &ConnectionConf,
+        locator: ContractLocator<'_>,
+        mut wallet: WalletUnlocked,
+    ) -> ChainResult<Self> {
+        let fuel_provider = FuelProvider::new(locator.domain.clone(), conf).await;
+
+        wallet.set_provider(fuel_provider.provider().clone());
+        let address = Bech32ContractId::from_h256(&locator.address);
+
+        Ok(FuelInterchainGasPaymaster {
+            contract: FuelInterchainGasPaymasterInner::new(address, wallet),
+            domain: locator.domain.clone(),
+            provider: fuel_provider,
+        })
+    }
+}
 
 impl HyperlaneContract for FuelInterchainGasPaymaster {
     fn address(&self) -> H256 {
-        todo!()
+        self.contract.contract_id().into_h256()
     }
 }
 
 impl HyperlaneChain for FuelInterchainGasPaymaster {
     fn domain(&self) -> &HyperlaneDomain {
-        todo!()
+        &self.domain
     }
 
     fn provider(&self) -> Box<dyn HyperlaneProvider> {
-        todo!()
+        Box::new(self.provider.clone())
     }
 }
 
@@ -31,7 +66,40 @@ impl InterchainGasPaymaster for FuelInterchainGasPaymaster {}
 
 /// Struct that retrieves event data for a Fuel IGP contract
 #[derive(Debug)]
-pub struct FuelInterchainGasPaymasterIndexer {}
+pub struct FuelInterchainGasPaymasterIndexer {
+    contract: FuelInterchainGasPaymasterInner<WalletUnlocked>,
+    provider: FuelProvider,
+}
+
+impl FuelInterchainGasPaymasterIndexer {
+    /// Create a new FuelInterchainGasPaymasterIndexer
+    pub async fn new(
+        conf: &ConnectionConf,
+        locator: ContractLocator<'_>,
+        wallet: WalletUnlocked,
+    ) -> ChainResult<Self> {
+        let fuel_provider = FuelProvider::new(locator.domain.clone(), conf).await;
+
+        let address = Bech32ContractId::from_h256(&locator.address);
+        let contract = FuelInterchainGasPaymasterInner::new(address, wallet);
+
+        Ok(FuelInterchainGasPaymasterIndexer {
+            contract,
+            provider: fuel_provider,
+        })
+    }
+}
+
+impl From<GasPaymentEvent> for InterchainGasPayment {
+    fn from(event: GasPaymentEvent) -> Self {
+        InterchainGasPayment {
+            message_id: event.message_id.into_h256(),
+            destination: event.destination_domain,
+            payment: U256::from(event.payment),
+            gas_amount: U256::from(event.gas_amount),
+        }
+    }
+}
 
 #[async_trait]
 impl Indexer<InterchainGasPayment> for FuelInterchainGasPaymasterIndexer {
@@ -39,10 +107,31 @@ impl Indexer<InterchainGasPayment> for FuelInterchainGasPaymasterIndexer {
         &self,
         range: RangeInclusive<u32>,
     ) -> ChainResult<Vec<(Indexed<InterchainGasPayment>, LogMeta)>> {
-        todo!()
+        let decoder = self.contract.log_decoder();
+        let logs = self
+            .provider
+            .index_contract_logs_in_range(range, self.contract.contract_id(), |receipt| {
+                decode_log::<GasPaymentEvent>(&decoder, receipt)
+            })
+            .await?;
+
+        Ok(logs
+            .into_iter()
+            .map(|(event, log_meta)| (InterchainGasPayment::from(event).into(), log_meta))
+            .collect())
     }
 
     async fn get_finalized_block_number(&self) -> ChainResult<u32> {
-        todo!()
+        self.provider.get_finalized_block_number().await
+    }
+}
+
+#[async_trait]
+impl SequenceAwareIndexer<InterchainGasPayment> for FuelInterchainGasPaymasterIndexer {
+    async fn latest_sequence_count_and_tip(&self) -> ChainResult<(Option<u32>, u32)> {
+        let tip = Indexer::<InterchainGasPayment>::get_finalized_block_number(&self).await?;
+
+        // The Fuel IGP does not keep a payment counter, so gas payments are indexed by block.
+        Ok((None, tip))
     }
 }
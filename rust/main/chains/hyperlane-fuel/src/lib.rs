@@ -2,19 +2,23 @@
 
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
-// TODO: Remove once we start filling things in
-#![allow(unused_variables)]
 #![deny(clippy::arithmetic_side_effects)]
 
+pub mod application;
+
 pub use self::{
-    interchain_gas::*, mailbox::*, multisig_ism::*, provider::*, routing_ism::*, trait_builder::*,
+    aggregation_ism::*, interchain_gas::*, interchain_security_module::*, mailbox::*,
+    merkle_tree_hook::*, multisig_ism::*, provider::*, routing_ism::*, trait_builder::*,
     validator_announce::*,
 };
 
+mod aggregation_ism;
 mod contracts;
 mod conversions;
 mod interchain_gas;
+mod interchain_security_module;
 mod mailbox;
+mod merkle_tree_hook;
 mod multisig_ism;
 mod provider;
 mod routing_ism;
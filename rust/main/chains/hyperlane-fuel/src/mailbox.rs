@@ -1,17 +1,19 @@
 use crate::{
-    contracts::mailbox::Mailbox as FuelMailboxInner, conversions::*, ConnectionConf, FuelProvider,
+    contracts::mailbox::{Mailbox as FuelMailboxInner, ProcessEvent},
+    conversions::*,
+    provider::{call_response_to_tx_outcome, decode_log},
+    ConnectionConf, FuelProvider,
 };
 use async_trait::async_trait;
 use fuels::{
     prelude::{Bech32ContractId, WalletUnlocked},
-    tx::{Receipt, ScriptExecutionResult},
     types::{transaction::TxPolicies, Bytes},
 };
 use hyperlane_core::{
     utils::bytes_to_hex, ChainCommunicationError, ChainResult, ContractLocator, HyperlaneAbi,
     HyperlaneChain, HyperlaneContract, HyperlaneDomain, HyperlaneMessage, HyperlaneProvider,
     Indexed, Indexer, LogMeta, Mailbox, Metadata, RawHyperlaneMessage, ReorgPeriod,
-    SequenceAwareIndexer, TxCostEstimate, TxOutcome, H256, H512, U256,
+    SequenceAwareIndexer, TxCostEstimate, TxOutcome, H256, U256,
 };
 use std::{
     collections::HashMap,
@@ -164,23 +166,7 @@ impl Mailbox for FuelMailbox {
             .await
             .map_err(ChainCommunicationError::from_other)?;
 
-        // Extract transaction success from the receipts
-        let success = call_res
-            .receipts
-            .iter()
-            .filter_map(|r| match r {
-                Receipt::ScriptResult { result, .. } => Some(result),
-                _ => None,
-            })
-            .any(|result| matches!(result, ScriptExecutionResult::Success));
-
-        let tx_id = H512::from(call_res.tx_id.unwrap().into_h256());
-        Ok(TxOutcome {
-            transaction_id: tx_id,
-            executed: success,
-            gas_used: call_res.gas_used.into(),
-            gas_price: gas_price.into(),
-        })
+        Ok(call_response_to_tx_outcome(&call_res, gas_price))
     }
 
     // Process cost of the `process` method
@@ -215,14 +201,17 @@ impl Mailbox for FuelMailbox {
     async fn process_calldata(
         &self,
         _message: &HyperlaneMessage,
-        metadata: &Metadata,
+        _metadata: &Metadata,
     ) -> ChainResult<Vec<u8>> {
-        // Seems like this is not needed for Fuel, as it's only used in mocks
-        todo!()
+        // Fuel is not supported by the payload dispatcher, which is the only user of the calldata
+        Err(ChainCommunicationError::CustomError(
+            "Building process calldata is not supported on Fuel".to_owned(),
+        ))
     }
 
-    fn delivered_calldata(&self, message_id: H256) -> ChainResult<Option<Vec<u8>>> {
-        todo!()
+    fn delivered_calldata(&self, _message_id: H256) -> ChainResult<Option<Vec<u8>>> {
+        // Fuel is not supported by the payload dispatcher, so there is no calldata to build
+        Ok(None)
     }
 }
 
@@ -275,7 +264,18 @@ impl Indexer<H256> for FuelMailboxIndexer {
         &self,
         range: RangeInclusive<u32>,
     ) -> ChainResult<Vec<(Indexed<H256>, LogMeta)>> {
-        todo!() // Needed for scraper
+        let decoder = self.contract.log_decoder();
+        let logs = self
+            .provider
+            .index_contract_logs_in_range(range, self.contract.contract_id(), |receipt| {
+                decode_log::<ProcessEvent>(&decoder, receipt)
+            })
+            .await?;
+
+        Ok(logs
+            .into_iter()
+            .map(|(event, log_meta)| (event.message_id.into_h256().into(), log_meta))
+            .collect())
     }
 
     async fn get_finalized_block_number(&self) -> ChainResult<u32> {
@@ -317,7 +317,8 @@ impl HyperlaneAbi for FuelMailboxAbi {
     const SELECTOR_SIZE_BYTES: usize = 8;
 
     fn fn_map() -> HashMap<Vec<u8>, &'static str> {
-        // Can't support this without Fuels exporting it in the generated code
-        todo!()
+        // Can't support this without Fuels exporting it in the generated code,
+        // so no function names are known
+        HashMap::new()
     }
 }
@@ -0,0 +1,228 @@
+use std::ops::RangeInclusive;
+
+use async_trait::async_trait;
+use fuels::prelude::{Bech32ContractId, WalletUnlocked};
+
+use hyperlane_core::{
+    accumulator::incremental::IncrementalMerkle, ChainCommunicationError, ChainResult, Checkpoint,
+    CheckpointAtBlock, ContractLocator, HyperlaneChain, HyperlaneContract, HyperlaneDomain,
+    HyperlaneProvider, IncrementalMerkleAtBlock, Indexed, Indexer, LogMeta, MerkleTreeHook,
+    MerkleTreeInsertion, ReorgPeriod, SequenceAwareIndexer, H256,
+};
+use tracing::instrument;
+
+use crate::{
+    contracts::merkle_tree_hook::{
+        InsertedIntoTreeEvent, MerkleTreeHook as FuelMerkleTreeHookInner,
+    },
+    conversions::*,
+    provider::decode_log,
+    ConnectionConf, FuelProvider,
+};
+
+/// Fuel has no way to query contract state at a past block height, so only
+/// the latest state can be read.
+fn ensure_latest_state(reorg_period: &ReorgPeriod) -> ChainResult<()> {
+    if reorg_period.is_none() {
+        Ok(())
+    } else {
+        Err(ChainCommunicationError::InvalidReorgPeriod(
+            reorg_period.clone(),
+        ))
+    }
+}
+
+/// A reference to a MerkleTreeHook contract on some Fuel chain
+#[derive(Debug)]
+pub struct FuelMerkleTreeHook {
+    contract: FuelMerkleTreeHookInner<WalletUnlocked>,
+    domain: HyperlaneDomain,
+    provider: FuelProvider,
+}
+
+impl FuelMerkleTreeHook {
+    /// Create a new fuel merkle tree hook contract interface
+    pub async fn new(
+        conf: &ConnectionConf,
+        locator: ContractLocator<'_>,
+        mut wallet: WalletUnlocked,
+    ) -> ChainResult<Self> {
+        let fuel_provider = FuelProvider::new(locator.domain.clone(), conf).await;
+
+        wallet.set_provider(fuel_provider.provider().clone());
+        let address = Bech32ContractId::from_h256(&locator.address);
+
+        Ok(FuelMerkleTreeHook {
+            contract: FuelMerkleTreeHookInner::new(address, wallet),
+            domain: locator.domain.clone(),
+            provider: fuel_provider,
+        })
+    }
+
+    async fn latest_checkpoint_at_tip(&self) -> ChainResult<CheckpointAtBlock> {
+        let tip = self.provider.get_finalized_block_number().await?;
+        let (root, index) = self
+            .contract
+            .methods()
+            .latest_checkpoint()
+            .simulate()
+            .await
+            .map(|r| r.value)
+            .map_err(ChainCommunicationError::from_other)?;
+
+        Ok(CheckpointAtBlock {
+            checkpoint: Checkpoint {
+                merkle_tree_hook_address: self.address(),
+                mailbox_domain: self.domain.id(),
+                root: root.into_h256(),
+                index,
+            },
+            block_height: Some(tip.into()),
+        })
+    }
+}
+
+impl HyperlaneContract for FuelMerkleTreeHook {
+    fn address(&self) -> H256 {
+        self.contract.contract_id().into_h256()
+    }
+}
+
+impl HyperlaneChain for FuelMerkleTreeHook {
+    fn domain(&self) -> &HyperlaneDomain {
+        &self.domain
+    }
+
+    fn provider(&self) -> Box<dyn HyperlaneProvider> {
+        Box::new(self.provider.clone())
+    }
+}
+
+#[async_trait]
+impl MerkleTreeHook for FuelMerkleTreeHook {
+    #[instrument(level = "debug", err, ret, skip(self))]
+    #[allow(clippy::blocks_in_conditions)] // TODO: `rustc` 1.80.1 clippy issue
+    async fn tree(&self, reorg_period: &ReorgPeriod) -> ChainResult<IncrementalMerkleAtBlock> {
+        ensure_latest_state(reorg_period)?;
+
+        let tip = self.provider.get_finalized_block_number().await?;
+        let tree = self
+            .contract
+            .methods()
+            .tree()
+            .simulate()
+            .await
+            .map(|r| r.value)
+            .map_err(ChainCommunicationError::from_other)?;
+
+        let count = usize::try_from(tree.count)?;
+
+        Ok(IncrementalMerkleAtBlock {
+            tree: IncrementalMerkle {
+                branch: tree.branch.map(|node| node.into_h256()),
+                count,
+            },
+            block_height: Some(tip.into()),
+        })
+    }
+
+    #[instrument(level = "debug", err, ret, skip(self))]
+    #[allow(clippy::blocks_in_conditions)] // TODO: `rustc` 1.80.1 clippy issue
+    async fn count(&self, reorg_period: &ReorgPeriod) -> ChainResult<u32> {
+        ensure_latest_state(reorg_period)?;
+
+        self.contract
+            .methods()
+            .count()
+            .simulate()
+            .await
+            .map(|r| r.value)
+            .map_err(ChainCommunicationError::from_other)
+    }
+
+    #[instrument(level = "debug", err, ret, skip(self))]
+    #[allow(clippy::blocks_in_conditions)] // TODO: `rustc` 1.80.1 clippy issue
+    async fn latest_checkpoint(
+        &self,
+        reorg_period: &ReorgPeriod,
+    ) -> ChainResult<CheckpointAtBlock> {
+        ensure_latest_state(reorg_period)?;
+        self.latest_checkpoint_at_tip().await
+    }
+
+    async fn latest_checkpoint_at_block(&self, height: u64) -> ChainResult<CheckpointAtBlock> {
+        Err(ChainCommunicationError::CustomError(format!(
+            "Fuel does not support querying point-in-time, requested height {height}"
+        )))
+    }
+}
+
+/// Struct that retrieves event data for a Fuel MerkleTreeHook contract
+#[derive(Debug)]
+pub struct FuelMerkleTreeHookIndexer {
+    contract: FuelMerkleTreeHookInner<WalletUnlocked>,
+    provider: FuelProvider,
+}
+
+impl FuelMerkleTreeHookIndexer {
+    /// Create a new FuelMerkleTreeHookIndexer
+    pub async fn new(
+        conf: &ConnectionConf,
+        locator: ContractLocator<'_>,
+        mut wallet: WalletUnlocked,
+    ) -> ChainResult<Self> {
+        let fuel_provider = FuelProvider::new(locator.domain.clone(), conf).await;
+
+        wallet.set_provider(fuel_provider.provider().clone());
+        let address = Bech32ContractId::from_h256(&locator.address);
+        let contract = FuelMerkleTreeHookInner::new(address, wallet);
+
+        Ok(FuelMerkleTreeHookIndexer {
+            contract,
+            provider: fuel_provider,
+        })
+    }
+}
+
+#[async_trait]
+impl Indexer<MerkleTreeInsertion> for FuelMerkleTreeHookIndexer {
+    async fn fetch_logs_in_range(
+        &self,
+        range: RangeInclusive<u32>,
+    ) -> ChainResult<Vec<(Indexed<MerkleTreeInsertion>, LogMeta)>> {
+        let decoder = self.contract.log_decoder();
+        let logs = self
+            .provider
+            .index_contract_logs_in_range(range, self.contract.contract_id(), |receipt| {
+                decode_log::<InsertedIntoTreeEvent>(&decoder, receipt)
+            })
+            .await?;
+
+        Ok(logs
+            .into_iter()
+            .map(|(event, log_meta)| {
+                let insertion = MerkleTreeInsertion::new(event.index, event.message_id.into_h256());
+                (insertion.into(), log_meta)
+            })
+            .collect())
+    }
+
+    async fn get_finalized_block_number(&self) -> ChainResult<u32> {
+        self.provider.get_finalized_block_number().await
+    }
+}
+
+#[async_trait]
+impl SequenceAwareIndexer<MerkleTreeInsertion> for FuelMerkleTreeHookIndexer {
+    async fn latest_sequence_count_and_tip(&self) -> ChainResult<(Option<u32>, u32)> {
+        let tip = Indexer::<MerkleTreeInsertion>::get_finalized_block_number(&self).await?;
+
+        self.contract
+            .methods()
+            .count()
+            .simulate()
+            .await
+            .map(|r| (Some(r.value), tip))
+            .map_err(ChainCommunicationError::from_other)
+    }
+}
@@ -1,10 +1,18 @@
-use std::{collections::HashMap, ops::Deref};
+use std::{
+    collections::HashMap,
+    ops::{Deref, RangeInclusive},
+};
 
 use async_trait::async_trait;
 use fuels::{
     client::{FuelClient, PageDirection, PaginationRequest},
+    core::{
+        codec::LogDecoder,
+        traits::{Parameterize, Tokenizable},
+    },
     prelude::Provider,
-    tx::Receipt,
+    programs::responses::CallResponse,
+    tx::{Receipt, ScriptExecutionResult},
     types::{
         bech32::Bech32ContractId,
         block::Block,
@@ -15,15 +23,18 @@ use fuels::{
         Address, BlockHeight, Bytes32, ContractId,
     },
 };
-use futures::future::join_all;
+use futures::{future::join_all, stream, StreamExt};
 use hyperlane_core::{
     h512_to_bytes, BlockInfo, ChainCommunicationError, ChainInfo, ChainResult, HyperlaneChain,
     HyperlaneDomain, HyperlaneMessage, HyperlaneProvider, HyperlaneProviderError, Indexed, LogMeta,
-    TxnInfo, H256, H512, U256,
+    TxOutcome, TxnInfo, H256, H512, U256,
 };
 
 use crate::{make_client, make_provider, prelude::FuelIntoH256, ConnectionConf};
 
+/// Maximum number of transactions fetched concurrently while indexing a block range
+const MAX_CONCURRENT_TX_FETCHES: usize = 10;
+
 /// A wrapper around a fuel provider to get generic blockchain information.
 #[derive(Debug, Clone)]
 pub struct FuelProvider {
@@ -149,6 +160,28 @@ impl FuelProvider {
         Ok((blocks.results, transaction_map))
     }
 
+    /// Fetch transactions by id, with a bounded number of requests in flight.
+    /// Results are returned in the order of `tx_ids`.
+    async fn get_transactions(
+        &self,
+        tx_ids: impl IntoIterator<Item = Bytes32>,
+    ) -> ChainResult<Vec<(Bytes32, Option<TransactionResponse>)>> {
+        let provider = &self.provider;
+        stream::iter(tx_ids)
+            .map(|tx_id| async move {
+                provider
+                    .get_transaction_by_id(&tx_id)
+                    .await
+                    .map(|tx| (tx_id, tx))
+                    .map_err(ChainCommunicationError::from_other)
+            })
+            .buffered(MAX_CONCURRENT_TX_FETCHES)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect()
+    }
+
     /// Get the finalized block number
     /// XXX might be inaccurate as we do not know the block finality
     pub async fn get_finalized_block_number(&self) -> ChainResult<u32> {
@@ -269,6 +302,103 @@ impl FuelProvider {
             .collect::<Vec<_>>();
         Ok(indexed_logs)
     }
+
+    /// Index the `LogData` receipts emitted by `contract` within a block range,
+    /// keeping the ones `decode` can turn into an event.
+    pub async fn index_contract_logs_in_range<T, F>(
+        &self,
+        range: RangeInclusive<u32>,
+        contract: &Bech32ContractId,
+        decode: F,
+    ) -> ChainResult<Vec<(T, LogMeta)>>
+    where
+        F: Fn(&Receipt) -> Option<T>,
+    {
+        let (blocks, transaction_map) = self.get_block_data(range).await?;
+        let contract_id = ContractId::new(*contract.hash);
+        let block_heights = blocks
+            .iter()
+            .map(|block| (block.id, u64::from(block.header.height)))
+            .collect::<HashMap<_, _>>();
+
+        let transactions = self
+            .get_transactions(
+                blocks
+                    .iter()
+                    .flat_map(|block| block.transactions.iter().copied()),
+            )
+            .await?;
+
+        let mut logs = Vec::new();
+        for (tx_id, tx) in transactions {
+            let Some(tx) = tx else {
+                continue;
+            };
+            let TxStatus::Success { receipts } = tx.status else {
+                continue;
+            };
+            let Some((block_hash, transaction_index)) = transaction_map.get(&tx_id) else {
+                continue;
+            };
+            let block_number = block_heights.get(block_hash).copied().unwrap_or_default();
+
+            for (log_index, receipt) in receipts.iter().enumerate() {
+                if !matches!(receipt, Receipt::LogData { id, .. } if *id == contract_id) {
+                    continue;
+                }
+                let Some(event) = decode(receipt) else {
+                    continue;
+                };
+                let log_meta = LogMeta {
+                    address: contract.into_h256(),
+                    block_number,
+                    block_hash: block_hash.into_h256(),
+                    transaction_id: H512::from(tx_id.into_h256()),
+                    transaction_index: *transaction_index,
+                    log_index: U256::from(log_index),
+                };
+                logs.push((event, log_meta));
+            }
+        }
+        Ok(logs)
+    }
+}
+
+/// Decode a single receipt as a log of type `T`, if it is one.
+pub(crate) fn decode_log<T>(decoder: &LogDecoder, receipt: &Receipt) -> Option<T>
+where
+    T: Tokenizable + Parameterize + 'static,
+{
+    decoder
+        .decode_logs_with_type::<T>(std::slice::from_ref(receipt))
+        .ok()?
+        .pop()
+}
+
+/// Build a `TxOutcome` from the response of a contract call.
+pub(crate) fn call_response_to_tx_outcome<T>(
+    response: &CallResponse<T>,
+    gas_price: u64,
+) -> TxOutcome {
+    // Extract transaction success from the receipts
+    let executed = response
+        .receipts
+        .iter()
+        .filter_map(|r| match r {
+            Receipt::ScriptResult { result, .. } => Some(result),
+            _ => None,
+        })
+        .any(|result| matches!(result, ScriptExecutionResult::Success));
+
+    TxOutcome {
+        transaction_id: response
+            .tx_id
+            .map(|id| H512::from(id.into_h256()))
+            .unwrap_or_default(),
+        executed,
+        gas_used: response.gas_used.into(),
+        gas_price: gas_price.into(),
+    }
 }
 
 impl HyperlaneChain for FuelProvider {
@@ -290,7 +420,7 @@ impl HyperlaneProvider for FuelProvider {
             .provider
             .block_by_height(BlockHeight::new(height as u32))
             .await
-            .map_err(|e| HyperlaneProviderError::CouldNotFindBlockByHeight(height))?;
+            .map_err(|_| HyperlaneProviderError::CouldNotFindBlockByHeight(height))?;
 
         let block_info = match block_res {
             Some(block) => BlockInfo {
@@ -1,27 +1,78 @@
 use async_trait::async_trait;
+use fuels::{
+    accounts::ViewOnlyAccount,
+    prelude::{Bech32ContractId, WalletUnlocked},
+    types::{Bits256, Bytes, EvmAddress},
+};
+use tracing::{instrument, warn};
 
 use hyperlane_core::{
-    Announcement, ChainResult, HyperlaneChain, HyperlaneContract, HyperlaneDomain,
-    HyperlaneProvider, SignedType, TxOutcome, ValidatorAnnounce, H256, U256,
+    Announcement, ChainCommunicationError, ChainResult, ContractLocator, HyperlaneChain,
+    HyperlaneContract, HyperlaneDomain, HyperlaneProvider, SignedType, TxOutcome,
+    ValidatorAnnounce, H256, U256,
+};
+
+use crate::{
+    contracts::validator_announce::ValidatorAnnounce as FuelValidatorAnnounceInner, conversions::*,
+    provider::call_response_to_tx_outcome, ConnectionConf, FuelProvider,
 };
 
 /// A reference to a ValidatorAnnounce contract on some Fuel chain
 #[derive(Debug)]
-pub struct FuelValidatorAnnounce {}
+pub struct FuelValidatorAnnounce {
+    contract: FuelValidatorAnnounceInner<WalletUnlocked>,
+    domain: HyperlaneDomain,
+    provider: FuelProvider,
+    has_signer: bool,
+}
+
+impl FuelValidatorAnnounce {
+    /// Create a new fuel validator announce contract interface.
+    /// Without a signer the contract can only be read, and announcing fails.
+    pub async fn new(
+        conf: &ConnectionConf,
+        locator: ContractLocator<'_>,
+        signer: Option<WalletUnlocked>,
+    ) -> ChainResult<Self> {
+        let fuel_provider = FuelProvider::new(locator.domain.clone(), conf).await;
+
+        let has_signer = signer.is_some();
+        // Fuel contract instances need an account even for read-only calls
+        let mut wallet = signer.unwrap_or_else(|| WalletUnlocked::new_random(None));
+        wallet.set_provider(fuel_provider.provider().clone());
+        let address = Bech32ContractId::from_h256(&locator.address);
+
+        Ok(FuelValidatorAnnounce {
+            contract: FuelValidatorAnnounceInner::new(address, wallet),
+            domain: locator.domain.clone(),
+            provider: fuel_provider,
+            has_signer,
+        })
+    }
+
+    fn announce_args(announcement: &SignedType<Announcement>) -> (EvmAddress, String, Bytes) {
+        let validator = EvmAddress::from(Bits256::from_h256(&H256::from(
+            announcement.value.validator,
+        )));
+        let storage_location = announcement.value.storage_location.clone();
+        let signature = Bytes(announcement.signature.to_vec());
+        (validator, storage_location, signature)
+    }
+}
 
 impl HyperlaneContract for FuelValidatorAnnounce {
     fn address(&self) -> H256 {
-        todo!()
+        self.contract.contract_id().into_h256()
     }
 }
 
 impl HyperlaneChain for FuelValidatorAnnounce {
     fn domain(&self) -> &HyperlaneDomain {
-        todo!()
+        &self.domain
     }
 
     fn provider(&self) -> Box<dyn HyperlaneProvider> {
-        todo!()
+        Box::new(self.provider.clone())
     }
 }
 
@@ -31,11 +82,35 @@ impl ValidatorAnnounce for FuelValidatorAnnounce {
         &self,
         validators: &[H256],
     ) -> ChainResult<Vec<Vec<String>>> {
-        todo!()
+        let validators = validators.iter().map(Bits256::from_h256).collect();
+
+        self.contract
+            .methods()
+            .get_announced_storage_locations(validators)
+            .simulate()
+            .await
+            .map(|r| r.value)
+            .map_err(ChainCommunicationError::from_other)
     }
 
+    #[instrument(err, ret, skip(self))]
+    #[allow(clippy::blocks_in_conditions)] // TODO: `rustc` 1.80.1 clippy issue
     async fn announce(&self, announcement: SignedType<Announcement>) -> ChainResult<TxOutcome> {
-        todo!()
+        if !self.has_signer {
+            return Err(ChainCommunicationError::SignerUnavailable);
+        }
+        let (validator, storage_location, signature) = Self::announce_args(&announcement);
+        let gas_price = self.provider.get_gas_price().await?;
+
+        let call_res = self
+            .contract
+            .methods()
+            .announce(validator, storage_location, signature)
+            .call()
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+
+        Ok(call_response_to_tx_outcome(&call_res, gas_price))
     }
 
     async fn announce_tokens_needed(
@@ -43,6 +118,34 @@ impl ValidatorAnnounce for FuelValidatorAnnounce {
         announcement: SignedType<Announcement>,
         _chain_signer: H256,
     ) -> Option<U256> {
-        todo!()
+        if !self.has_signer {
+            warn!("No signer configured to announce with");
+            return None;
+        }
+        let (validator, storage_location, signature) = Self::announce_args(&announcement);
+
+        let Ok(cost) = self
+            .contract
+            .methods()
+            .announce(validator, storage_location, signature)
+            .estimate_transaction_cost(None, None)
+            .await
+        else {
+            warn!("Unable to estimate announce transaction cost");
+            return None;
+        };
+
+        let base_asset_id = *self.provider.provider().base_asset_id();
+        let Ok(balance) = self
+            .contract
+            .account()
+            .get_asset_balance(&base_asset_id)
+            .await
+        else {
+            warn!("Unable to query balance");
+            return None;
+        };
+
+        Some(U256::from(cost.total_fee.saturating_sub(balance)))
     }
 }
@@ -2,13 +2,14 @@ use async_trait::async_trait;
 use scrypto::types::ComponentAddress;
 
 use hyperlane_core::{
-    ChainResult, ContractLocator, Encode, HyperlaneChain, HyperlaneContract, HyperlaneDomain,
-    HyperlaneMessage, HyperlaneProvider, InterchainSecurityModule, Metadata, ModuleType,
-    MultisigIsm, RoutingIsm, H160, H256, U256,
+    AggregationIsm, ChainResult, ContractLocator, Encode, HyperlaneChain, HyperlaneContract,
+    HyperlaneDomain, HyperlaneMessage, HyperlaneProvider, InterchainSecurityModule, Metadata,
+    ModuleType, MultisigIsm, RoutingIsm, H160, H256, U256,
 };
 
 use crate::{
-    address_to_h256, encode_component_address, ConnectionConf, EthAddress, IsmTypes, RadixProvider,
+    address_to_h256, encode_component_address, parse_aggregation_ism_state, ConnectionConf,
+    EthAddress, IsmTypes, RadixProvider,
 };
 
 /// Radix ISM
@@ -81,7 +82,7 @@ impl InterchainSecurityModule for RadixIsm {
         _message: &HyperlaneMessage,
         _metadata: &Metadata,
     ) -> ChainResult<Option<U256>> {
-        Ok(Some(U256::one())) // NOTE: we don't need to implement this, as there is no aggregation ism and we can't save any costs
+        Ok(Some(U256::one())) // NOTE: not implemented, the relayer only uses the estimate to pick the cheapest aggregation submodules
     }
 }
 
@@ -122,3 +123,19 @@ impl RoutingIsm for RadixIsm {
         Ok(address_to_h256(route))
     }
 }
+
+#[async_trait]
+impl AggregationIsm for RadixIsm {
+    /// Returns the `m` ISMs and `n` threshold needed to n-of-m verify the message
+    async fn modules_and_threshold(
+        &self,
+        _message: &HyperlaneMessage,
+    ) -> ChainResult<(Vec<H256>, u8)> {
+        // the modules and threshold are static, so we can read them directly from the component state
+        let state = self
+            .provider
+            .get_component_state(&self.encoded_address)
+            .await?;
+        parse_aggregation_ism_state(state)
+    }
+}
@@ -4,12 +4,13 @@ use std::fmt;
 use gateway_api_client::models::ProgrammaticScryptoSborValue;
 use hex::FromHex;
 use scrypto::math::Decimal;
+use scrypto::types::NodeId;
 
-use hyperlane_core::ChainResult;
+use hyperlane_core::{ChainResult, H256};
 
 use crate::events::Bytes32;
 use crate::events::{DispatchEvent, InsertedIntoTreeEvent, ProcessIdEvent};
-use crate::{GasPayment, HyperlaneRadixError};
+use crate::{decode_bech32, radix_address_bytes_to_h256, GasPayment, HyperlaneRadixError};
 
 /// Radix event parse errors
 #[derive(Debug)]
@@ -580,3 +581,196 @@ pub fn parse_gas_payment_event(value: ProgrammaticScryptoSborValue) -> ChainResu
         ),
     }
 }
+
+/// parses the state of an aggregation ism component into its modules and threshold
+pub fn parse_aggregation_ism_state(
+    value: ProgrammaticScryptoSborValue,
+) -> ChainResult<(Vec<H256>, u8)> {
+    let ProgrammaticScryptoSborValue::Tuple(tuple) = value else {
+        return Err(
+            Into::<HyperlaneRadixError>::into(EventParseError::InvalidEventType("Tuple")).into(),
+        );
+    };
+
+    // the component state may contain more fields than the ones we care about,
+    // so we look them up by name instead of by position
+    let field = |name: &'static str| {
+        tuple
+            .fields
+            .iter()
+            .find(|field| field_name(field) == Some(name))
+            .ok_or_else(|| Into::<HyperlaneRadixError>::into(EventParseError::MissingField(name)))
+    };
+
+    // Parse the modules field (Vec<ComponentAddress>)
+    let modules = match field("modules")? {
+        ProgrammaticScryptoSborValue::Array(array) => array
+            .elements
+            .iter()
+            .map(|element| match element {
+                ProgrammaticScryptoSborValue::Reference(reference) => {
+                    let bytes = decode_bech32(&reference.value)?;
+                    if bytes.len() != NodeId::LENGTH {
+                        return Err(Into::<HyperlaneRadixError>::into(EventParseError::Other(
+                            format!("Invalid module address: {}", reference.value),
+                        ))
+                        .into());
+                    }
+                    Ok(radix_address_bytes_to_h256(&bytes))
+                }
+                _ => Err(
+                    Into::<HyperlaneRadixError>::into(EventParseError::InvalidFieldType("modules"))
+                        .into(),
+                ),
+            })
+            .collect::<ChainResult<Vec<_>>>()?,
+        _ => {
+            return Err(
+                Into::<HyperlaneRadixError>::into(EventParseError::InvalidFieldType("modules"))
+                    .into(),
+            )
+        }
+    };
+
+    // Parse the threshold field (usize)
+    let threshold = match field("threshold")? {
+        ProgrammaticScryptoSborValue::U8(val) => val.value.parse::<u8>().ok().map(u64::from),
+        ProgrammaticScryptoSborValue::U32(val) => val.value.parse::<u32>().ok().map(u64::from),
+        ProgrammaticScryptoSborValue::U64(val) => val.value.parse::<u64>().ok(),
+        _ => {
+            return Err(
+                Into::<HyperlaneRadixError>::into(EventParseError::InvalidFieldType("threshold"))
+                    .into(),
+            )
+        }
+    };
+    let threshold = threshold
+        .and_then(|threshold| u8::try_from(threshold).ok())
+        .ok_or_else(|| {
+            Into::<HyperlaneRadixError>::into(EventParseError::Other(
+                "Failed to parse threshold value".to_owned(),
+            ))
+        })?;
+    // a zero threshold would let any metadata pass verification
+    if threshold == 0 {
+        return Err(Into::<HyperlaneRadixError>::into(EventParseError::Other(
+            "Aggregation ISM threshold must not be zero".to_owned(),
+        ))
+        .into());
+    }
+
+    Ok((modules, threshold))
+}
+
+fn field_name(value: &ProgrammaticScryptoSborValue) -> Option<&str> {
+    let field_name = match value {
+        ProgrammaticScryptoSborValue::Array(val) => &val.field_name,
+        ProgrammaticScryptoSborValue::Reference(val) => &val.field_name,
+        ProgrammaticScryptoSborValue::U8(val) => &val.field_name,
+        ProgrammaticScryptoSborValue::U32(val) => &val.field_name,
+        ProgrammaticScryptoSborValue::U64(val) => &val.field_name,
+        _ => return None,
+    };
+    field_name.as_ref().and_then(|name| name.as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use scrypto::network::NetworkDefinition;
+    use serde_json::json;
+
+    use super::*;
+    use crate::encode_component_address;
+
+    fn module_address(byte: u8) -> H256 {
+        let mut bytes = [byte; 32];
+        bytes[0] = 0;
+        bytes[1] = 0;
+        H256::from(bytes)
+    }
+
+    fn encoded_module(address: H256) -> String {
+        encode_component_address(&NetworkDefinition::mainnet(), address).unwrap()
+    }
+
+    fn aggregation_ism_state(
+        modules: serde_json::Value,
+        threshold: serde_json::Value,
+    ) -> ProgrammaticScryptoSborValue {
+        serde_json::from_value(json!({
+            "kind": "Tuple",
+            "type_name": "AggregationIsm",
+            "fields": [
+                {
+                    "kind": "Array",
+                    "field_name": "modules",
+                    "element_kind": "Reference",
+                    "elements": modules
+                },
+                threshold
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_aggregation_ism_state() {
+        let modules = [module_address(1), module_address(2), module_address(3)];
+        let state = aggregation_ism_state(
+            json!(modules
+                .iter()
+                .map(|module| json!({
+                    "kind": "Reference",
+                    "type_name": "ComponentAddress",
+                    "value": encoded_module(*module)
+                }))
+                .collect::<Vec<_>>()),
+            json!({ "kind": "U64", "field_name": "threshold", "value": "2" }),
+        );
+
+        let (parsed_modules, threshold) = parse_aggregation_ism_state(state).unwrap();
+
+        assert_eq!(parsed_modules, modules.to_vec());
+        assert_eq!(threshold, 2);
+    }
+
+    #[test]
+    fn test_parse_aggregation_ism_state_zero_threshold() {
+        let state = aggregation_ism_state(
+            json!([]),
+            json!({ "kind": "U64", "field_name": "threshold", "value": "0" }),
+        );
+
+        assert!(parse_aggregation_ism_state(state).is_err());
+    }
+
+    #[test]
+    fn test_parse_aggregation_ism_state_threshold_overflow() {
+        let state = aggregation_ism_state(
+            json!([]),
+            json!({ "kind": "U64", "field_name": "threshold", "value": "256" }),
+        );
+
+        assert!(parse_aggregation_ism_state(state).is_err());
+    }
+
+    #[test]
+    fn test_parse_aggregation_ism_state_missing_threshold() {
+        let state = aggregation_ism_state(
+            json!([]),
+            json!({ "kind": "U64", "field_name": "not_threshold", "value": "1" }),
+        );
+
+        assert!(parse_aggregation_ism_state(state).is_err());
+    }
+
+    #[test]
+    fn test_parse_aggregation_ism_state_invalid_module() {
+        let state = aggregation_ism_state(
+            json!([{ "kind": "U32", "value": "1" }]),
+            json!({ "kind": "U64", "field_name": "threshold", "value": "1" }),
+        );
+
+        assert!(parse_aggregation_ism_state(state).is_err());
+    }
+}
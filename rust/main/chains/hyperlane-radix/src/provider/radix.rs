@@ -18,7 +18,7 @@ use gateway_api_client::{
     apis::configuration::Configuration as GatewayConfig,
     models::{
         self, CommittedTransactionInfo, CompiledPreviewTransaction, LedgerStateSelector,
-        ProgrammaticScryptoSborValue, StreamTransactionsRequest,
+        ProgrammaticScryptoSborValue, StateEntityDetailsRequest, StreamTransactionsRequest,
         TransactionCommittedDetailsRequest, TransactionDetailsOptIns, TransactionPreviewV2Request,
         TransactionStatusResponse,
     },
@@ -248,6 +248,40 @@ impl RadixProvider {
             .0)
     }
 
+    /// Returns the programmatic SBOR representation of a component's state
+    pub async fn get_component_state(
+        &self,
+        component: &str,
+    ) -> ChainResult<ProgrammaticScryptoSborValue> {
+        let response = self
+            .provider
+            .entity_details(StateEntityDetailsRequest::new(vec![component.to_owned()]))
+            .await?;
+
+        let Some(details) = response
+            .items
+            .into_iter()
+            .find(|item| item.address == component)
+            .and_then(|item| item.details)
+        else {
+            return Err(HyperlaneRadixError::ParsingError(format!(
+                "no entity details found for {component}"
+            ))
+            .into());
+        };
+
+        // the details are a tagged union over all entity types, only components carry a state
+        let details = serde_json::to_value(details).map_err(HyperlaneRadixError::from)?;
+        let Some(state) = details.get("state").cloned() else {
+            return Err(HyperlaneRadixError::ParsingError(format!(
+                "no component state found for {component}"
+            ))
+            .into());
+        };
+
+        Ok(serde_json::from_value(state).map_err(HyperlaneRadixError::from)?)
+    }
+
     /// Returns the latest ledger state of the chain
     pub async fn get_state_version(&self, reorg: Option<&ReorgPeriod>) -> ChainResult<u64> {
         let status = self.core_status().await?;
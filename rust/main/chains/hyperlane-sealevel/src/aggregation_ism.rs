@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use account_utils::DiscriminatorEncode;
+use async_trait::async_trait;
+use hyperlane_core::{
+    AggregationIsm, ChainCommunicationError, ChainResult, ContractLocator, HyperlaneChain,
+    HyperlaneContract, HyperlaneDomain, HyperlaneMessage, HyperlaneProvider, H256,
+};
+use hyperlane_sealevel_aggregation_ism::{
+    instruction::{Instruction as AggregationIsmInstruction, ModulesAndThreshold},
+    modules_and_threshold_pda_seeds,
+};
+use serializable_account_meta::SimulationReturnData;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signer::Signer,
+};
+
+use crate::{SealevelKeypair, SealevelProvider};
+
+/// A reference to an AggregationIsm contract on some Sealevel chain
+#[derive(Debug)]
+pub struct SealevelAggregationIsm {
+    payer: Option<SealevelKeypair>,
+    program_id: Pubkey,
+    domain: HyperlaneDomain,
+    provider: Arc<SealevelProvider>,
+}
+
+impl SealevelAggregationIsm {
+    /// Create a new Sealevel AggregationIsm.
+    pub fn new(
+        provider: Arc<SealevelProvider>,
+        locator: ContractLocator,
+        payer: Option<SealevelKeypair>,
+    ) -> Self {
+        let program_id = Pubkey::from(<[u8; 32]>::from(locator.address));
+
+        Self {
+            payer,
+            program_id,
+            domain: locator.domain.clone(),
+            provider,
+        }
+    }
+}
+
+impl HyperlaneContract for SealevelAggregationIsm {
+    fn address(&self) -> H256 {
+        self.program_id.to_bytes().into()
+    }
+}
+
+impl HyperlaneChain for SealevelAggregationIsm {
+    fn domain(&self) -> &HyperlaneDomain {
+        &self.domain
+    }
+
+    fn provider(&self) -> Box<dyn HyperlaneProvider> {
+        Box::new(self.provider.clone())
+    }
+}
+
+#[async_trait]
+impl AggregationIsm for SealevelAggregationIsm {
+    /// Returns the modules and threshold needed to verify message.
+    /// The configuration is the same for all messages.
+    async fn modules_and_threshold(
+        &self,
+        _message: &HyperlaneMessage,
+    ) -> ChainResult<(Vec<H256>, u8)> {
+        let (modules_and_threshold_pda_key, _) =
+            Pubkey::try_find_program_address(modules_and_threshold_pda_seeds!(), &self.program_id)
+                .ok_or_else(|| {
+                    ChainCommunicationError::from_other_str(
+                        "Could not find program address for modules and threshold",
+                    )
+                })?;
+
+        let instruction = Instruction::new_with_bytes(
+            self.program_id,
+            &AggregationIsmInstruction::GetModulesAndThreshold
+                .encode()
+                .map_err(ChainCommunicationError::from_other)?[..],
+            vec![AccountMeta::new_readonly(
+                modules_and_threshold_pda_key,
+                false,
+            )],
+        );
+
+        let payer = self
+            .payer
+            .as_ref()
+            .map(|p| p.pubkey())
+            .ok_or_else(|| ChainCommunicationError::SignerUnavailable)?;
+        let modules_and_threshold = self
+            .provider
+            .simulate_instruction::<SimulationReturnData<ModulesAndThreshold>>(&payer, instruction)
+            .await?
+            .ok_or_else(|| {
+                ChainCommunicationError::from_other_str(
+                    "No return data was returned from the aggregation ism",
+                )
+            })?
+            .return_data;
+
+        let modules = modules_and_threshold
+            .modules
+            .into_iter()
+            .map(|module| module.to_bytes().into())
+            .collect();
+
+        Ok((modules, modules_and_threshold.threshold))
+    }
+}
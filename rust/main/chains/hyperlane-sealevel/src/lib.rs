@@ -7,6 +7,7 @@
 #![deny(clippy::arithmetic_side_effects)]
 
 pub use crate::multisig_ism::*;
+pub use aggregation_ism::*;
 pub use interchain_gas::*;
 pub use interchain_security_module::*;
 pub use keypair::*;
@@ -15,6 +16,7 @@ pub use mailbox_indexer::*;
 pub use merkle_tree_hook::*;
 pub use priority_fee::PriorityFeeOracle;
 pub use provider::*;
+pub use routing_ism::*;
 pub use rpc::*;
 pub use signer::create_keypair;
 pub use solana_sdk::signer::keypair::Keypair;
@@ -24,6 +26,7 @@ pub use tx_type::*;
 pub use validator_announce::*;
 
 mod account;
+mod aggregation_ism;
 /// Address Lookup Table support for transaction size reduction
 pub mod alt;
 /// Hyperlane Application specific functionality
@@ -40,6 +43,7 @@ mod metric;
 mod multisig_ism;
 mod priority_fee;
 mod provider;
+mod routing_ism;
 mod rpc;
 mod signer;
 mod trait_builder;
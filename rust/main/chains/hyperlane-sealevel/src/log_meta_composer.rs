@@ -94,7 +94,10 @@ pub fn is_message_dispatch_instruction(instruction_data: &[u8]) -> bool {
         Err(_) => return false,
     };
 
-    matches!(instruction, Instruction::OutboxDispatch(_))
+    matches!(
+        instruction,
+        Instruction::OutboxDispatch(_) | Instruction::OutboxDispatchWithHook(_)
+    )
 }
 
 pub fn is_message_delivery_instruction(instruction_data: &[u8]) -> bool {
@@ -3,8 +3,14 @@
 
 use std::{collections::HashMap, str::FromStr as _, sync::Arc};
 
+use account_utils::DiscriminatorEncode as _;
 use async_trait::async_trait;
 use borsh::{BorshDeserialize, BorshSerialize};
+use hyperlane_sealevel_aggregation_ism::{
+    instruction::{Instruction as AggregationIsmInstruction, ModulesAndThreshold},
+    metadata::AggregationIsmMetadata,
+};
+use hyperlane_sealevel_domain_routing_ism::instruction::Instruction as DomainRoutingIsmInstruction;
 use hyperlane_sealevel_interchain_security_module_interface::{
     InterchainSecurityModuleInstruction, VerifyInstruction,
 };
@@ -18,6 +24,7 @@ use hyperlane_sealevel_message_recipient_interface::{
     HandleInstruction, MessageRecipientInstruction,
 };
 use lazy_static::lazy_static;
+use num_traits::FromPrimitive as _;
 use serializable_account_meta::SimulationReturnData;
 use solana_commitment_config::CommitmentConfig;
 use solana_program::pubkey;
@@ -32,7 +39,8 @@ use tracing::{debug, instrument, warn};
 use hyperlane_core::{
     ChainCommunicationError, ChainResult, ContractLocator, Encode as _, FixedPointNumber,
     HyperlaneChain, HyperlaneContract, HyperlaneDomain, HyperlaneMessage, HyperlaneProvider,
-    Mailbox, MerkleTreeHook, Metadata, ReorgPeriod, TxCostEstimate, TxOutcome, H256, U256,
+    Mailbox, MerkleTreeHook, Metadata, ModuleType, ReorgPeriod, TxCostEstimate, TxOutcome, H256,
+    U256,
 };
 
 use crate::priority_fee::PriorityFeeOracle;
@@ -126,7 +134,7 @@ impl SealevelMailbox {
             inbox,
             outbox,
             payer,
-            priority_fee_oracle: conf.priority_fee_oracle.create_oracle(),
+            priority_fee_oracle: conf.priority_fee_oracle.create_oracle()?,
             tx_submitter,
             mailbox_process_alt: conf.mailbox_process_alt,
             process_alt_overrides: conf.process_alt_overrides.clone(),
@@ -243,14 +251,133 @@ impl SealevelMailbox {
                 metadata,
                 message,
             });
-        self.get_non_signer_account_metas_with_instruction_bytes(
+        let mut account_metas = self
+            .get_non_signer_account_metas_with_instruction_bytes(
+                ism,
+                &instruction
+                    .encode()
+                    .map_err(ChainCommunicationError::from_other)?,
+                hyperlane_sealevel_interchain_security_module_interface::VERIFY_ACCOUNT_METAS_PDA_SEEDS,
+            )
+            .await?;
+
+        // Composite ISMs only return their own accounts, and rely on the caller
+        // to append the program IDs and accounts of the ISMs they call into.
+        match self.get_ism_module_type(ism).await? {
+            ModuleType::Aggregation => {
+                let modules_and_threshold_account =
+                    account_metas.first().cloned().ok_or_else(|| {
+                        ChainCommunicationError::from_other_str("No aggregation ISM account metas")
+                    })?;
+                let modules = self
+                    .get_aggregation_ism_modules(ism, modules_and_threshold_account)
+                    .await?;
+                let aggregation_metadata = AggregationIsmMetadata::from(metadata);
+                // Modules without metadata are skipped by the aggregation ISM, so their
+                // accounts must not be provided.
+                for (index, module) in modules.into_iter().enumerate() {
+                    let Some(module_metadata) = aggregation_metadata
+                        .module_metadata(index)
+                        .map_err(ChainCommunicationError::from_other)?
+                    else {
+                        continue;
+                    };
+                    account_metas.push(AccountMeta::new_readonly(module, false));
+                    account_metas.extend(
+                        Box::pin(self.get_ism_verify_account_metas(
+                            module,
+                            module_metadata.to_vec(),
+                            message.clone(),
+                        ))
+                        .await?,
+                    );
+                }
+            }
+            ModuleType::Routing => {
+                let domain_data_account = account_metas.first().cloned().ok_or_else(|| {
+                    ChainCommunicationError::from_other_str("No routing ISM account metas")
+                })?;
+                let origin = HyperlaneMessage::from(&message).origin;
+                let routed_ism = self
+                    .get_routing_ism_route(ism, domain_data_account, origin)
+                    .await?;
+                account_metas.push(AccountMeta::new_readonly(routed_ism, false));
+                account_metas.extend(
+                    Box::pin(self.get_ism_verify_account_metas(routed_ism, metadata, message))
+                        .await?,
+                );
+            }
+            _ => {}
+        }
+
+        Ok(account_metas)
+    }
+
+    /// Gets the module type of the ISM.
+    async fn get_ism_module_type(&self, ism: Pubkey) -> ChainResult<ModuleType> {
+        let instruction = Instruction::new_with_bytes(
             ism,
-            &instruction
+            &InterchainSecurityModuleInstruction::Type
                 .encode()
                 .map_err(ChainCommunicationError::from_other)?,
-            hyperlane_sealevel_interchain_security_module_interface::VERIFY_ACCOUNT_METAS_PDA_SEEDS,
-        )
-        .await
+            vec![],
+        );
+        let module_type = self
+            .simulate_instruction::<SimulationReturnData<u32>>(instruction)
+            .await?
+            .ok_or(ChainCommunicationError::from_other_str(
+                "No return data from ISM Type instruction",
+            ))?
+            .return_data;
+        ModuleType::from_u32(module_type)
+            .ok_or_else(|| ChainCommunicationError::from_other_str("Unknown ISM module type"))
+    }
+
+    /// Gets the modules of an aggregation ISM.
+    async fn get_aggregation_ism_modules(
+        &self,
+        ism: Pubkey,
+        modules_and_threshold_account: AccountMeta,
+    ) -> ChainResult<Vec<Pubkey>> {
+        let instruction = Instruction::new_with_bytes(
+            ism,
+            &AggregationIsmInstruction::GetModulesAndThreshold
+                .encode()
+                .map_err(ChainCommunicationError::from_other)?,
+            vec![modules_and_threshold_account],
+        );
+        let modules_and_threshold = self
+            .simulate_instruction::<SimulationReturnData<ModulesAndThreshold>>(instruction)
+            .await?
+            .ok_or(ChainCommunicationError::from_other_str(
+                "No return data from aggregation ISM GetModulesAndThreshold instruction",
+            ))?
+            .return_data;
+        Ok(modules_and_threshold.modules)
+    }
+
+    /// Gets the ISM a routing ISM routes messages from the origin domain to.
+    async fn get_routing_ism_route(
+        &self,
+        ism: Pubkey,
+        domain_data_account: AccountMeta,
+        origin: u32,
+    ) -> ChainResult<Pubkey> {
+        let instruction = Instruction::new_with_bytes(
+            ism,
+            &DomainRoutingIsmInstruction::Route(origin)
+                .encode()
+                .map_err(ChainCommunicationError::from_other)?,
+            vec![domain_data_account],
+        );
+        let routed_ism = self
+            .simulate_instruction::<SimulationReturnData<Pubkey>>(instruction)
+            .await?
+            .ok_or(ChainCommunicationError::from_other_str(
+                "No return data from routing ISM Route instruction",
+            ))?
+            .return_data;
+        Ok(routed_ism)
     }
 
     /// Gets the account metas required for the recipient's `MessageRecipientInstruction::Handle` instruction.
@@ -1,12 +1,28 @@
+use std::time::Duration;
+
 use async_trait::async_trait;
 use derive_new::new;
 use hyperlane_core::{ChainCommunicationError, ChainResult};
 use reqwest::Client;
 use serde::Deserialize;
-use solana_sdk::bs58;
+use solana_sdk::{bs58, message::VersionedMessage, pubkey::Pubkey};
 
 use crate::tx_type::SealevelTxType;
-use crate::{HeliusPriorityFeeLevel, HeliusPriorityFeeOracleConfig};
+use crate::{HeliusPriorityFeeLevel, HeliusPriorityFeeOracleConfig, RpcPriorityFeeOracleConfig};
+
+/// The maximum number of accounts accepted by `getRecentPrioritizationFees`
+const MAX_PRIORITIZATION_FEE_ACCOUNTS: usize = 128;
+
+/// How long to wait for a priority fee estimate before giving up on the oracle
+const PRIORITY_FEE_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Builds the HTTP client the oracles query their fee estimates with
+fn http_client() -> ChainResult<Client> {
+    Client::builder()
+        .timeout(PRIORITY_FEE_REQUEST_TIMEOUT)
+        .build()
+        .map_err(ChainCommunicationError::from_other)
+}
 
 /// A trait for fetching the priority fee for a transaction.
 #[async_trait]
@@ -37,11 +53,11 @@ pub struct HeliusPriorityFeeOracle {
 }
 
 impl HeliusPriorityFeeOracle {
-    pub fn new(config: HeliusPriorityFeeOracleConfig) -> Self {
-        Self {
-            client: reqwest::Client::new(),
+    pub fn new(config: HeliusPriorityFeeOracleConfig) -> ChainResult<Self> {
+        Ok(Self {
+            client: http_client()?,
             config,
-        }
+        })
     }
 
     fn get_priority_fee_estimate_options(&self) -> serde_json::Value {
@@ -107,7 +123,107 @@ impl PriorityFeeOracle for HeliusPriorityFeeOracle {
     }
 }
 
-/// The result of a JSON-RPC request to the Helius API.
+/// A priority fee oracle that uses the `getRecentPrioritizationFees` RPC method,
+/// which is supported by any Solana RPC.
+/// https://solana.com/docs/rpc/http/getrecentprioritizationfees
+#[derive(Debug, Clone)]
+pub struct RpcPriorityFeeOracle {
+    client: Client,
+    config: RpcPriorityFeeOracleConfig,
+}
+
+impl RpcPriorityFeeOracle {
+    pub fn new(config: RpcPriorityFeeOracleConfig) -> ChainResult<Self> {
+        Ok(Self {
+            client: http_client()?,
+            config,
+        })
+    }
+
+    /// The fee at the configured percentile of `fees`, clamped between the
+    /// configured floor and ceiling.
+    fn fee_at_percentile(&self, mut fees: Vec<u64>) -> u64 {
+        fees.sort_unstable();
+        let percentile = usize::from(self.config.percentile.min(100));
+        // nearest-rank percentile, so the 0th percentile is the lowest fee
+        let rank = percentile.saturating_mul(fees.len()).div_ceil(100);
+        let fee = fees
+            .get(rank.saturating_sub(1))
+            .copied()
+            .unwrap_or_default()
+            .max(self.config.floor);
+        match self.config.ceiling {
+            Some(ceiling) => fee.min(ceiling),
+            None => fee,
+        }
+    }
+}
+
+/// The accounts a transaction may write to, which are the accounts whose recent
+/// prioritization fees it competes with. Accounts loaded from address lookup
+/// tables are not included.
+fn writable_accounts(transaction: &SealevelTxType) -> Vec<Pubkey> {
+    let message = match transaction {
+        SealevelTxType::Legacy(tx) => VersionedMessage::Legacy(tx.message.clone()),
+        SealevelTxType::Versioned(tx) => tx.message.clone(),
+    };
+
+    message
+        .static_account_keys()
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| message.is_maybe_writable(*index, None))
+        .map(|(_, key)| *key)
+        .take(MAX_PRIORITIZATION_FEE_ACCOUNTS)
+        .collect()
+}
+
+#[async_trait]
+impl PriorityFeeOracle for RpcPriorityFeeOracle {
+    async fn get_priority_fee(&self, transaction: &SealevelTxType) -> ChainResult<u64> {
+        let accounts = writable_accounts(transaction)
+            .iter()
+            .map(|account| account.to_string())
+            .collect::<Vec<_>>();
+
+        let request_body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": "1",
+            "method": "getRecentPrioritizationFees",
+            "params": [accounts],
+        });
+
+        let response = self
+            .client
+            .post(self.config.url.clone())
+            .json(&request_body)
+            .send()
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+
+        let response: JsonRpcResult<Vec<RecentPrioritizationFee>> = response
+            .json()
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+
+        let fees = response
+            .result
+            .into_iter()
+            .map(|fee| fee.prioritization_fee)
+            .collect::<Vec<_>>();
+        let fee = self.fee_at_percentile(fees);
+
+        tracing::debug!(
+            fee,
+            percentile = self.config.percentile,
+            "Computed priority fee from recent prioritization fees"
+        );
+
+        Ok(fee)
+    }
+}
+
+/// The result of a JSON-RPC request.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct JsonRpcResult<T> {
@@ -118,6 +234,15 @@ struct JsonRpcResult<T> {
     result: T,
 }
 
+/// An entry of the result of a `getRecentPrioritizationFees` request.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct RecentPrioritizationFee {
+    #[allow(dead_code)]
+    slot: u64,
+    prioritization_fee: u64,
+}
+
 /// The result of a `getPriorityFeeEstimate` request to the Helius API.
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -127,15 +252,23 @@ struct GetPriorityFeeEstimateResult {
 
 #[cfg(test)]
 mod test {
-    use solana_sdk::{bs58, transaction::Transaction};
+    use solana_sdk::{
+        bs58,
+        instruction::{AccountMeta, Instruction},
+        message::Message,
+        pubkey::Pubkey,
+        transaction::Transaction,
+    };
 
     use crate::{
-        priority_fee::{HeliusPriorityFeeOracle, PriorityFeeOracle},
+        priority_fee::{HeliusPriorityFeeOracle, PriorityFeeOracle, RpcPriorityFeeOracle},
         tx_type::SealevelTxType,
-        HeliusPriorityFeeLevel, HeliusPriorityFeeOracleConfig,
+        HeliusPriorityFeeLevel, HeliusPriorityFeeOracleConfig, RpcPriorityFeeOracleConfig,
     };
 
-    use super::{GetPriorityFeeEstimateResult, JsonRpcResult};
+    use super::{
+        writable_accounts, GetPriorityFeeEstimateResult, JsonRpcResult, RecentPrioritizationFee,
+    };
 
     #[tokio::test]
     async fn test_helius_get_priority_fee() {
@@ -149,7 +282,8 @@ mod test {
         let oracle = super::HeliusPriorityFeeOracle::new(super::HeliusPriorityFeeOracleConfig {
             url: url::Url::parse(&helius_url).unwrap(),
             fee_level: super::HeliusPriorityFeeLevel::Medium,
-        });
+        })
+        .unwrap();
 
         // Example process transaction (legacy format)
         // https://solscan.io/tx/W9fXtRD8mPkkUmuoLi9QxSCgFuy32rCVa8kfxtPjWXWRH2D1AWzuDEGuvexWGyWhQDXnEmaADZMeYu5RVjWZyAB
@@ -168,6 +302,7 @@ mod test {
                 url: url::Url::parse("http://localhost:8080").unwrap(),
                 fee_level,
             })
+            .unwrap()
         };
 
         // When the fee level is Recommended, ensure `recommended` is set to true
@@ -224,4 +359,108 @@ mod test {
         };
         assert_eq!(response.result, expected);
     }
+
+    fn rpc_oracle(percentile: u8, floor: u64, ceiling: Option<u64>) -> RpcPriorityFeeOracle {
+        RpcPriorityFeeOracle::new(RpcPriorityFeeOracleConfig {
+            url: url::Url::parse("http://localhost:8899").unwrap(),
+            percentile,
+            floor,
+            ceiling,
+        })
+        .unwrap()
+    }
+
+    fn transaction(writable: Pubkey, readonly: Pubkey, payer: Pubkey) -> SealevelTxType {
+        let instruction = Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &[],
+            vec![
+                AccountMeta::new(writable, false),
+                AccountMeta::new_readonly(readonly, false),
+            ],
+        );
+        let message = Message::new(&[instruction], Some(&payer));
+        SealevelTxType::Legacy(Transaction::new_unsigned(message))
+    }
+
+    #[tokio::test]
+    async fn test_rpc_get_priority_fee() {
+        // e.g. http://127.0.0.1:8899 for a local test validator
+        let rpc_url = if let Ok(url) = std::env::var("SEALEVEL_RPC_URL") {
+            url
+        } else {
+            // Skip test if SEALEVEL_RPC_URL is not set
+            return;
+        };
+
+        let oracle = RpcPriorityFeeOracle::new(RpcPriorityFeeOracleConfig {
+            url: url::Url::parse(&rpc_url).unwrap(),
+            percentile: 75,
+            floor: 1,
+            ceiling: None,
+        })
+        .unwrap();
+        let transaction = transaction(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        let fee = oracle.get_priority_fee(&transaction).await.unwrap();
+        assert!(fee >= 1);
+    }
+
+    #[test]
+    fn test_rpc_fee_at_percentile() {
+        let fees = || vec![400, 0, 300, 100, 200];
+
+        assert_eq!(rpc_oracle(0, 0, None).fee_at_percentile(fees()), 0);
+        assert_eq!(rpc_oracle(20, 0, None).fee_at_percentile(fees()), 0);
+        assert_eq!(rpc_oracle(21, 0, None).fee_at_percentile(fees()), 100);
+        assert_eq!(rpc_oracle(50, 0, None).fee_at_percentile(fees()), 200);
+        assert_eq!(rpc_oracle(75, 0, None).fee_at_percentile(fees()), 300);
+        assert_eq!(rpc_oracle(100, 0, None).fee_at_percentile(fees()), 400);
+
+        // floor and ceiling
+        assert_eq!(rpc_oracle(0, 50, None).fee_at_percentile(fees()), 50);
+        assert_eq!(rpc_oracle(100, 0, Some(250)).fee_at_percentile(fees()), 250);
+        assert_eq!(rpc_oracle(50, 0, Some(250)).fee_at_percentile(fees()), 200);
+
+        // no recent fees
+        assert_eq!(rpc_oracle(50, 0, None).fee_at_percentile(vec![]), 0);
+        assert_eq!(rpc_oracle(50, 10, None).fee_at_percentile(vec![]), 10);
+    }
+
+    #[test]
+    fn test_writable_accounts() {
+        let writable = Pubkey::new_unique();
+        let readonly = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let mut accounts = writable_accounts(&transaction(writable, readonly, payer));
+        accounts.sort();
+        let mut expected = vec![payer, writable];
+        expected.sort();
+
+        assert_eq!(accounts, expected);
+    }
+
+    #[test]
+    fn test_recent_prioritization_fees_deser() {
+        let text = r#"{"jsonrpc":"2.0","result":[{"slot":348125,"prioritizationFee":0},{"slot":348126,"prioritizationFee":1000}],"id":"1"}"#;
+        let response: JsonRpcResult<Vec<RecentPrioritizationFee>> =
+            serde_json::from_str(text).unwrap();
+
+        let expected = vec![
+            RecentPrioritizationFee {
+                slot: 348125,
+                prioritization_fee: 0,
+            },
+            RecentPrioritizationFee {
+                slot: 348126,
+                prioritization_fee: 1000,
+            },
+        ];
+        assert_eq!(response.result, expected);
+    }
 }
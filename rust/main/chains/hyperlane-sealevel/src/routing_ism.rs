@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use account_utils::DiscriminatorEncode;
+use async_trait::async_trait;
+use hyperlane_core::{
+    ChainCommunicationError, ChainResult, ContractLocator, HyperlaneChain, HyperlaneContract,
+    HyperlaneDomain, HyperlaneMessage, HyperlaneProvider, RoutingIsm, H256,
+};
+use hyperlane_sealevel_domain_routing_ism::{
+    domain_data_pda_seeds, instruction::Instruction as DomainRoutingIsmInstruction,
+};
+use serializable_account_meta::SimulationReturnData;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signer::Signer,
+};
+
+use crate::{SealevelKeypair, SealevelProvider};
+
+/// A reference to a RoutingIsm contract on some Sealevel chain
+#[derive(Debug)]
+pub struct SealevelRoutingIsm {
+    payer: Option<SealevelKeypair>,
+    program_id: Pubkey,
+    domain: HyperlaneDomain,
+    provider: Arc<SealevelProvider>,
+}
+
+impl SealevelRoutingIsm {
+    /// Create a new Sealevel RoutingIsm.
+    pub fn new(
+        provider: Arc<SealevelProvider>,
+        locator: ContractLocator,
+        payer: Option<SealevelKeypair>,
+    ) -> Self {
+        let program_id = Pubkey::from(<[u8; 32]>::from(locator.address));
+
+        Self {
+            payer,
+            program_id,
+            domain: locator.domain.clone(),
+            provider,
+        }
+    }
+}
+
+impl HyperlaneContract for SealevelRoutingIsm {
+    fn address(&self) -> H256 {
+        self.program_id.to_bytes().into()
+    }
+}
+
+impl HyperlaneChain for SealevelRoutingIsm {
+    fn domain(&self) -> &HyperlaneDomain {
+        &self.domain
+    }
+
+    fn provider(&self) -> Box<dyn HyperlaneProvider> {
+        Box::new(self.provider.clone())
+    }
+}
+
+#[async_trait]
+impl RoutingIsm for SealevelRoutingIsm {
+    /// Returns the ISM configured for the message's origin domain
+    async fn route(&self, message: &HyperlaneMessage) -> ChainResult<H256> {
+        let (domain_data_pda_key, _) = Pubkey::try_find_program_address(
+            domain_data_pda_seeds!(message.origin),
+            &self.program_id,
+        )
+        .ok_or_else(|| {
+            ChainCommunicationError::from_other_str(
+                "Could not find program address for domain data",
+            )
+        })?;
+
+        let instruction = Instruction::new_with_bytes(
+            self.program_id,
+            &DomainRoutingIsmInstruction::Route(message.origin)
+                .encode()
+                .map_err(ChainCommunicationError::from_other)?[..],
+            vec![AccountMeta::new_readonly(domain_data_pda_key, false)],
+        );
+
+        let payer = self
+            .payer
+            .as_ref()
+            .map(|p| p.pubkey())
+            .ok_or_else(|| ChainCommunicationError::SignerUnavailable)?;
+        let ism = self
+            .provider
+            .simulate_instruction::<SimulationReturnData<Pubkey>>(&payer, instruction)
+            .await?
+            .ok_or_else(|| {
+                ChainCommunicationError::from_other_str(
+                    "No return data was returned from the routing ism",
+                )
+            })?
+            .return_data;
+
+        Ok(ism.to_bytes().into())
+    }
+}
@@ -1,14 +1,17 @@
 use std::sync::Arc;
 
 use hyperlane_core::{
-    config::OpSubmissionConfig, matching_list::MatchingList, ChainCommunicationError, NativeToken,
+    config::OpSubmissionConfig, matching_list::MatchingList, ChainCommunicationError, ChainResult,
+    NativeToken,
 };
 use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
 use url::Url;
 
 use crate::{
-    priority_fee::{ConstantPriorityFeeOracle, HeliusPriorityFeeOracle, PriorityFeeOracle},
+    priority_fee::{
+        ConstantPriorityFeeOracle, HeliusPriorityFeeOracle, PriorityFeeOracle, RpcPriorityFeeOracle,
+    },
     tx_submitter::config::TransactionSubmitterConfig,
 };
 
@@ -61,6 +64,8 @@ pub enum PriorityFeeOracleConfig {
     Constant(u64),
     /// A Helius priority fee oracle
     Helius(HeliusPriorityFeeOracleConfig),
+    /// An oracle based on the `getRecentPrioritizationFees` RPC method
+    Rpc(RpcPriorityFeeOracleConfig),
 }
 
 impl Default for PriorityFeeOracleConfig {
@@ -71,15 +76,19 @@ impl Default for PriorityFeeOracleConfig {
 
 impl PriorityFeeOracleConfig {
     /// Create a new priority fee oracle from the configuration
-    pub fn create_oracle(&self) -> Arc<dyn PriorityFeeOracle> {
-        match self {
+    pub fn create_oracle(&self) -> ChainResult<Arc<dyn PriorityFeeOracle>> {
+        let oracle: Arc<dyn PriorityFeeOracle> = match self {
             PriorityFeeOracleConfig::Constant(fee) => {
                 Arc::new(ConstantPriorityFeeOracle::new(*fee))
             }
             PriorityFeeOracleConfig::Helius(config) => {
-                Arc::new(HeliusPriorityFeeOracle::new(config.clone()))
+                Arc::new(HeliusPriorityFeeOracle::new(config.clone())?)
             }
-        }
+            PriorityFeeOracleConfig::Rpc(config) => {
+                Arc::new(RpcPriorityFeeOracle::new(config.clone())?)
+            }
+        };
+        Ok(oracle)
     }
 }
 
@@ -92,6 +101,19 @@ pub struct HeliusPriorityFeeOracleConfig {
     pub fee_level: HeliusPriorityFeeLevel,
 }
 
+/// Configuration for the `getRecentPrioritizationFees` priority fee oracle
+#[derive(Debug, Clone)]
+pub struct RpcPriorityFeeOracleConfig {
+    /// The RPC URL to query. Any Solana RPC supports the method.
+    pub url: Url,
+    /// The percentile of recent prioritization fees to use, from 0 to 100
+    pub percentile: u8,
+    /// The minimum fee to use, in micro lamports
+    pub floor: u64,
+    /// The maximum fee to use, in micro lamports
+    pub ceiling: Option<u64>,
+}
+
 /// The priority fee level to use
 #[derive(Debug, Clone, Serialize, Default)]
 pub enum HeliusPriorityFeeLevel {
@@ -205,7 +205,7 @@ impl ValidatorAnnounce for SealevelValidatorAnnounce {
                 instruction,
                 payer,
                 self.tx_submitter.clone(),
-                self.conn.priority_fee_oracle.create_oracle(),
+                self.conn.priority_fee_oracle.create_oracle()?,
                 None, // ALT not used for validator announce
             )
             .await?;
@@ -14,6 +14,7 @@ use hyperlane_core::{
 };
 use starknet::accounts::{Account, ExecutionV3, SingleOwnerAccount};
 use starknet::core::types::Felt;
+use starknet::macros::selector;
 
 use starknet::signers::LocalWallet;
 use tracing::instrument;
@@ -23,7 +24,7 @@ use crate::error::HyperlaneStarknetError;
 use crate::types::HyH256;
 use crate::{
     build_single_owner_account, get_block_height_for_reorg_period, send_and_confirm,
-    ConnectionConf, JsonProvider, Signer, StarknetProvider,
+    ConnectionConf, JsonProvider, Signer, StarknetProvider, StarknetTxCalldata,
 };
 
 /// A reference to a Mailbox contract on some Starknet chain
@@ -172,16 +173,30 @@ impl Mailbox for StarknetMailbox {
 
     async fn process_calldata(
         &self,
-        _message: &HyperlaneMessage,
-        _metadata: &Metadata,
+        message: &HyperlaneMessage,
+        metadata: &Metadata,
     ) -> ChainResult<Vec<u8>> {
-        // This function is only relevant for the new submitter
-        // TODO: Revisit with new submitter changes
-        Ok(Vec::new())
+        let call = self
+            .contract
+            .process_getcall(&metadata.as_ref().into(), &message.into());
+        let calldata = StarknetTxCalldata::from(call);
+        Ok(serde_json::to_vec(&calldata)?)
     }
 
-    fn delivered_calldata(&self, _message_id: H256) -> ChainResult<Option<Vec<u8>>> {
-        todo!()
+    fn delivered_calldata(&self, message_id: H256) -> ChainResult<Option<Vec<u8>>> {
+        let bytes = message_id.as_bytes();
+        let (high_bytes, low_bytes) = bytes.split_at(16);
+        let high = BigEndian::read_u128(high_bytes);
+        let low = BigEndian::read_u128(low_bytes);
+        let calldata = StarknetTxCalldata {
+            to: self.address(),
+            selector: HyH256::from(selector!("delivered")).0,
+            calldata: vec![
+                HyH256::from(Felt::from(low)).0,
+                HyH256::from(Felt::from(high)).0,
+            ],
+        };
+        Ok(Some(serde_json::to_vec(&calldata)?))
     }
 
     /// True if the destination chain supports batching
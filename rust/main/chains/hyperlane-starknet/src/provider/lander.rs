@@ -0,0 +1,269 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+use starknet::accounts::{Account, AccountError, ConnectedAccount, SingleOwnerAccount};
+use starknet::core::types::{
+    BlockId, BlockTag, Call, ExecutionResult, Felt, FunctionCall, ReceiptBlock, StarknetError,
+    TransactionFinalityStatus,
+};
+use starknet::providers::{Provider, ProviderError};
+use starknet::signers::LocalWallet;
+
+use hyperlane_core::{h512_to_bytes, ChainCommunicationError, ChainResult, H256, H512};
+
+use crate::types::HyH256;
+use crate::StarknetProvider;
+use crate::{build_single_owner_account, HyperlaneStarknetError, JsonProvider, Signer};
+
+/// A contract call, produced by the mailbox for the lander.
+/// Addresses, selectors and arguments are field elements, stored as `H256`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct StarknetTxCalldata {
+    /// Address of the contract to call
+    pub to: H256,
+    /// Selector of the entry point to call
+    pub selector: H256,
+    /// Serialized arguments of the call
+    pub calldata: Vec<H256>,
+}
+
+impl From<Call> for StarknetTxCalldata {
+    fn from(call: Call) -> Self {
+        Self {
+            to: HyH256::from(call.to).0,
+            selector: HyH256::from(call.selector).0,
+            calldata: call
+                .calldata
+                .into_iter()
+                .map(|felt| HyH256::from(felt).0)
+                .collect(),
+        }
+    }
+}
+
+impl From<&StarknetTxCalldata> for Call {
+    fn from(calldata: &StarknetTxCalldata) -> Self {
+        Call {
+            to: HyH256(calldata.to).into(),
+            selector: HyH256(calldata.selector).into(),
+            calldata: calldata
+                .calldata
+                .iter()
+                .map(|arg| HyH256(*arg).into())
+                .collect(),
+        }
+    }
+}
+
+/// Resource bounds of a V3 invoke transaction
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct StarknetResourceBounds {
+    /// Maximum amount of L1 gas
+    pub l1_gas: u64,
+    /// Maximum price per unit of L1 gas
+    pub l1_gas_price: u128,
+    /// Maximum amount of L2 gas
+    pub l2_gas: u64,
+    /// Maximum price per unit of L2 gas
+    pub l2_gas_price: u128,
+    /// Maximum amount of L1 data gas
+    pub l1_data_gas: u64,
+    /// Maximum price per unit of L1 data gas
+    pub l1_data_gas_price: u128,
+}
+
+/// Receipt of a transaction known to the sequencer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StarknetTxReceipt {
+    /// Number of the block which includes the transaction, if it was included
+    pub block_number: Option<u64>,
+    /// Whether the block was proven on L1
+    pub accepted_on_l1: bool,
+    /// Whether the execution of the transaction reverted
+    pub reverted: bool,
+}
+
+/// Trait defining the necessary methods for a Starknet provider to be used by the lander.
+#[async_trait]
+pub trait StarknetProviderForLander: Send + Sync {
+    /// Estimates the resources consumed by the call and their current prices,
+    /// if sent with the given nonce. Fails with a
+    /// `ChainCommunicationError::ContractError` if executing the call fails.
+    async fn estimate_fee(
+        &self,
+        calldata: &StarknetTxCalldata,
+        nonce: u64,
+    ) -> ChainResult<StarknetResourceBounds>;
+
+    /// Returns the nonce of the signer, including transactions in the pending block.
+    async fn get_nonce(&self) -> ChainResult<u64>;
+
+    /// Returns the latest block number.
+    async fn get_block_number(&self) -> ChainResult<u64>;
+
+    /// Signs and sends a V3 invoke transaction executing the call.
+    /// Returns once the transaction has been received by the sequencer.
+    async fn send(
+        &self,
+        calldata: &StarknetTxCalldata,
+        nonce: u64,
+        bounds: &StarknetResourceBounds,
+    ) -> ChainResult<H256>;
+
+    /// Gets the receipt of a transaction.
+    /// Returns an error if the transaction can not be found.
+    async fn get_tx_receipt(&self, hash: &H512) -> ChainResult<StarknetTxReceipt>;
+
+    /// Calls a view function returning a boolean, such as the `delivered` function of the mailbox.
+    async fn call_bool(&self, calldata: &StarknetTxCalldata) -> ChainResult<bool>;
+}
+
+/// The error of a failed fee estimation. Only execution errors of the call are
+/// contract errors, unlike errors such as a stale nonce or an unreachable node.
+fn estimate_fee_error<S: Debug>(err: AccountError<S>) -> ChainCommunicationError {
+    let reverted = matches!(
+        &err,
+        AccountError::Provider(ProviderError::StarknetError(
+            StarknetError::TransactionExecutionError(_) | StarknetError::ContractError(_)
+        ))
+    );
+    let err = HyperlaneStarknetError::from(err);
+    if reverted {
+        ChainCommunicationError::from_contract_error(err)
+    } else {
+        err.into()
+    }
+}
+
+/// Starknet provider holding the account of the signer, used by the lander
+#[derive(Debug)]
+pub struct StarknetLanderProvider {
+    provider: StarknetProvider,
+    account: SingleOwnerAccount<JsonProvider, LocalWallet>,
+}
+
+impl StarknetLanderProvider {
+    /// Create a new lander provider for the account of the signer
+    pub async fn new(provider: StarknetProvider, signer: Signer) -> ChainResult<Self> {
+        let account = build_single_owner_account(Some(signer), provider.rpc_client()).await?;
+        Ok(Self { provider, account })
+    }
+}
+
+#[async_trait]
+impl StarknetProviderForLander for StarknetLanderProvider {
+    async fn estimate_fee(
+        &self,
+        calldata: &StarknetTxCalldata,
+        nonce: u64,
+    ) -> ChainResult<StarknetResourceBounds> {
+        let estimate = self
+            .account
+            .execute_v3(vec![calldata.into()])
+            .nonce(Felt::from(nonce))
+            .estimate_fee()
+            .await
+            .map_err(estimate_fee_error)?;
+
+        Ok(StarknetResourceBounds {
+            l1_gas: estimate.l1_gas_consumed,
+            l1_gas_price: estimate.l1_gas_price,
+            l2_gas: estimate.l2_gas_consumed,
+            l2_gas_price: estimate.l2_gas_price,
+            l1_data_gas: estimate.l1_data_gas_consumed,
+            l1_data_gas_price: estimate.l1_data_gas_price,
+        })
+    }
+
+    async fn get_nonce(&self) -> ChainResult<u64> {
+        let nonce = self
+            .provider
+            .rpc_client()
+            .get_nonce(BlockId::Tag(BlockTag::Pending), self.account.address())
+            .await
+            .map_err(HyperlaneStarknetError::from)?;
+        nonce
+            .try_into()
+            .map_err(HyperlaneStarknetError::from_other)
+            .map_err(Into::into)
+    }
+
+    async fn get_block_number(&self) -> ChainResult<u64> {
+        let block_number = self
+            .account
+            .provider()
+            .block_number()
+            .await
+            .map_err(HyperlaneStarknetError::from)?;
+        Ok(block_number)
+    }
+
+    async fn send(
+        &self,
+        calldata: &StarknetTxCalldata,
+        nonce: u64,
+        bounds: &StarknetResourceBounds,
+    ) -> ChainResult<H256> {
+        let result = self
+            .account
+            .execute_v3(vec![calldata.into()])
+            .nonce(Felt::from(nonce))
+            .l1_gas(bounds.l1_gas)
+            .l1_gas_price(bounds.l1_gas_price)
+            .l2_gas(bounds.l2_gas)
+            .l2_gas_price(bounds.l2_gas_price)
+            .l1_data_gas(bounds.l1_data_gas)
+            .l1_data_gas_price(bounds.l1_data_gas_price)
+            .send()
+            .await
+            .map_err(HyperlaneStarknetError::from)?;
+        Ok(HyH256::from(result.transaction_hash).0)
+    }
+
+    async fn get_tx_receipt(&self, hash: &H512) -> ChainResult<StarknetTxReceipt> {
+        let hash = Felt::from_bytes_be_slice(&h512_to_bytes(hash));
+        let receipt = self
+            .provider
+            .rpc_client()
+            .get_transaction_receipt(hash)
+            .await
+            .map_err(HyperlaneStarknetError::from)?;
+
+        let block_number = match receipt.block {
+            ReceiptBlock::Block { block_number, .. } => Some(block_number),
+            ReceiptBlock::Pending => None,
+        };
+
+        Ok(StarknetTxReceipt {
+            block_number,
+            accepted_on_l1: matches!(
+                receipt.receipt.finality_status(),
+                TransactionFinalityStatus::AcceptedOnL1
+            ),
+            reverted: matches!(
+                receipt.receipt.execution_result(),
+                ExecutionResult::Reverted { .. }
+            ),
+        })
+    }
+
+    async fn call_bool(&self, calldata: &StarknetTxCalldata) -> ChainResult<bool> {
+        let call = Call::from(calldata);
+        let result = self
+            .provider
+            .rpc_client()
+            .call(
+                FunctionCall {
+                    contract_address: call.to,
+                    entry_point_selector: call.selector,
+                    calldata: call.calldata,
+                },
+                BlockId::Tag(BlockTag::Latest),
+            )
+            .await
+            .map_err(HyperlaneStarknetError::from)?;
+
+        Ok(result.first().is_some_and(|value| *value != Felt::ZERO))
+    }
+}
@@ -1,7 +1,9 @@
 mod client;
 mod fallback;
+mod lander;
 mod metric;
 
 pub use client::*;
 pub use fallback::*;
+pub use lander::*;
 pub use metric::*;
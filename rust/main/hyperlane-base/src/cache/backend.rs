@@ -0,0 +1,224 @@
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use url::Url;
+
+use crate::cache::FunctionCallCache;
+
+use super::{moka::CacheResult, LayeredCache, LocalCache, RedisCache};
+
+/// Default prefix for the keys written to a remote cache
+pub const DEFAULT_REMOTE_CACHE_KEY_PREFIX: &str = "hyperlane:";
+
+/// Default maximum size, in bytes, of an entry written to or read from a remote cache
+pub const DEFAULT_REMOTE_CACHE_MAX_ENTRY_SIZE: usize = 4 * 1024 * 1024;
+
+/// Configuration for the storage backing a function call cache
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum CacheBackendConf {
+    /// In-memory cache, private to the agent
+    #[default]
+    Local,
+    /// Cache shared between agents through a server speaking the Redis protocol
+    Redis {
+        /// Url of the server, e.g. `redis://[[username]:password@]host[:port][/db]`,
+        /// or `rediss://` for TLS.
+        url: Url,
+        /// Whether to keep an in-memory cache in front of the remote one
+        layered: bool,
+        /// Prefix for all keys, to share one server between deployments
+        key_prefix: Option<String>,
+        /// Maximum size of an entry in bytes. Larger entries are not cached, and
+        /// larger replies from the server are treated as errors.
+        max_entry_size: usize,
+    },
+}
+
+/// A function call cache using one of the supported storage backends
+#[derive(Debug, Clone)]
+pub enum CacheBackend {
+    /// In-memory cache
+    Local(LocalCache),
+    /// Remote cache only
+    Remote(RedisCache),
+    /// In-memory cache in front of a remote cache
+    Layered(LayeredCache),
+}
+
+impl CacheBackend {
+    /// Build the cache backend for the given configuration
+    pub fn build(conf: &CacheBackendConf, name: &str) -> CacheResult<Self> {
+        Ok(match conf {
+            CacheBackendConf::Local => Self::Local(LocalCache::new(name)),
+            CacheBackendConf::Redis {
+                url,
+                layered,
+                key_prefix,
+                max_entry_size,
+            } => {
+                let prefix = key_prefix
+                    .as_deref()
+                    .unwrap_or(DEFAULT_REMOTE_CACHE_KEY_PREFIX);
+                let remote = RedisCache::new(url, &format!("{prefix}{name}:"), *max_entry_size)?;
+                if *layered {
+                    Self::Layered(LayeredCache::new(LocalCache::new(name), remote))
+                } else {
+                    Self::Remote(remote)
+                }
+            }
+        })
+    }
+}
+
+impl From<LocalCache> for CacheBackend {
+    fn from(cache: LocalCache) -> Self {
+        Self::Local(cache)
+    }
+}
+
+#[async_trait]
+impl FunctionCallCache for CacheBackend {
+    /// Cache a call result with the given parameters
+    async fn cache_call_result(
+        &self,
+        domain_name: &str,
+        fn_key: &str,
+        fn_params: &(impl Serialize + Send + Sync),
+        result: &(impl Serialize + Send + Sync),
+    ) -> CacheResult<()> {
+        match self {
+            Self::Local(cache) => {
+                cache
+                    .cache_call_result(domain_name, fn_key, fn_params, result)
+                    .await
+            }
+            Self::Remote(cache) => {
+                cache
+                    .cache_call_result(domain_name, fn_key, fn_params, result)
+                    .await
+            }
+            Self::Layered(cache) => {
+                cache
+                    .cache_call_result(domain_name, fn_key, fn_params, result)
+                    .await
+            }
+        }
+    }
+
+    /// Get a cached call result with the given parameters
+    async fn get_cached_call_result<T>(
+        &self,
+        domain_name: &str,
+        method: &str,
+        fn_params: &(impl Serialize + Send + Sync),
+    ) -> CacheResult<Option<T>>
+    where
+        T: DeserializeOwned,
+    {
+        match self {
+            Self::Local(cache) => {
+                cache
+                    .get_cached_call_result(domain_name, method, fn_params)
+                    .await
+            }
+            Self::Remote(cache) => {
+                cache
+                    .get_cached_call_result(domain_name, method, fn_params)
+                    .await
+            }
+            Self::Layered(cache) => {
+                cache
+                    .get_cached_call_result(domain_name, method, fn_params)
+                    .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn local_backend_round_trip() {
+        let cache = CacheBackend::build(&CacheBackendConf::Local, "test-cache").unwrap();
+        assert!(matches!(cache, CacheBackend::Local(_)));
+
+        cache
+            .cache_call_result("chain", "method", &1u32, &"value")
+            .await
+            .unwrap();
+        let value = cache
+            .get_cached_call_result::<String>("chain", "method", &1u32)
+            .await
+            .unwrap();
+        assert_eq!(value.as_deref(), Some("value"));
+    }
+
+    #[tokio::test]
+    async fn layered_backend_survives_unreachable_remote() {
+        let conf = CacheBackendConf::Redis {
+            // nothing listens on the discard port
+            url: "redis://127.0.0.1:9".parse().unwrap(),
+            layered: true,
+            key_prefix: None,
+            max_entry_size: DEFAULT_REMOTE_CACHE_MAX_ENTRY_SIZE,
+        };
+        let cache = CacheBackend::build(&conf, "test-cache").unwrap();
+        assert!(matches!(cache, CacheBackend::Layered(_)));
+
+        assert!(cache
+            .get_cached_call_result::<String>("chain", "method", &1u32)
+            .await
+            .unwrap()
+            .is_none());
+        cache
+            .cache_call_result("chain", "method", &1u32, &"value")
+            .await
+            .unwrap();
+        let value = cache
+            .get_cached_call_result::<String>("chain", "method", &1u32)
+            .await
+            .unwrap();
+        assert_eq!(value.as_deref(), Some("value"));
+    }
+
+    #[tokio::test]
+    async fn remote_backend_reports_errors() {
+        let conf = CacheBackendConf::Redis {
+            url: "redis://127.0.0.1:9".parse().unwrap(),
+            layered: false,
+            key_prefix: Some("test:".to_owned()),
+            max_entry_size: DEFAULT_REMOTE_CACHE_MAX_ENTRY_SIZE,
+        };
+        let cache = CacheBackend::build(&conf, "test-cache").unwrap();
+
+        assert!(cache
+            .get_cached_call_result::<String>("chain", "method", &1u32)
+            .await
+            .is_err());
+    }
+
+    /// Requires a local Redis instance, e.g. `docker run -p 6379:6379 redis`
+    #[tokio::test]
+    #[ignore]
+    async fn layered_backends_share_entries() {
+        let conf = CacheBackendConf::Redis {
+            url: "redis://127.0.0.1:6379".parse().unwrap(),
+            layered: true,
+            key_prefix: Some("hyperlane-test:".to_owned()),
+            max_entry_size: DEFAULT_REMOTE_CACHE_MAX_ENTRY_SIZE,
+        };
+        let first = CacheBackend::build(&conf, "test-cache").unwrap();
+        let second = CacheBackend::build(&conf, "test-cache").unwrap();
+
+        first
+            .cache_call_result("chain", "method", &"shared", &42u64)
+            .await
+            .unwrap();
+        let value = second
+            .get_cached_call_result::<u64>("chain", "method", &"shared")
+            .await
+            .unwrap();
+        assert_eq!(value, Some(42));
+    }
+}
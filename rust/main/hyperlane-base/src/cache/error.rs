@@ -10,4 +10,12 @@ pub enum CacheError {
     /// expected vs actual type of the entity.
     #[error("Failed to deserialize output: {0}")]
     FailedToDeserializeOutput(#[source] serde_json::Error),
+
+    /// Error when communicating with a remote cache server.
+    #[error("Remote cache error: {0}")]
+    RemoteCacheError(String),
+
+    /// The remote cache server is skipped after recent failures.
+    #[error("Remote cache is unavailable, backing off after recent failures")]
+    RemoteCacheUnavailable,
 }
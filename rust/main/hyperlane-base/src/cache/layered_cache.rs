@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use derive_new::new;
+use serde::{de::DeserializeOwned, Serialize};
+use tracing::warn;
+
+use crate::cache::{CacheError, FunctionCallCache};
+
+use super::{
+    moka::{CacheResult, ExpirationType},
+    LocalCache, RedisCache,
+};
+
+/// A two-level cache which keeps hot entries in memory in front of a shared remote cache.
+///
+/// Reads hit the local cache first and fall back to the remote one, backfilling the local
+/// cache with the remaining lifetime of the remote entry. Writes go to both levels.
+/// The remote cache is best effort: its errors are logged and treated as misses, and while
+/// it is backing off after failures only the local cache is used.
+#[derive(new, Debug, Clone)]
+pub struct LayeredCache {
+    local: LocalCache,
+    remote: RedisCache,
+}
+
+#[async_trait]
+impl FunctionCallCache for LayeredCache {
+    /// Cache a call result in both the local and the remote cache
+    async fn cache_call_result(
+        &self,
+        domain_name: &str,
+        method: &str,
+        fn_params: &(impl Serialize + Send + Sync),
+        result: &(impl Serialize + Send + Sync),
+    ) -> CacheResult<()> {
+        self.local
+            .cache_call_result(domain_name, method, fn_params, result)
+            .await?;
+        match self
+            .remote
+            .cache_call_result(domain_name, method, fn_params, result)
+            .await
+        {
+            Ok(()) | Err(CacheError::RemoteCacheUnavailable) => {}
+            Err(err) => warn!(?err, domain_name, method, "Failed to write to remote cache"),
+        }
+        Ok(())
+    }
+
+    /// Get a cached call result from the local cache, or the remote cache if it isn't cached locally
+    async fn get_cached_call_result<T>(
+        &self,
+        domain_name: &str,
+        method: &str,
+        fn_params: &(impl Serialize + Send + Sync),
+    ) -> CacheResult<Option<T>>
+    where
+        T: DeserializeOwned,
+    {
+        if let Some(value) = self
+            .local
+            .get_cached_call_result::<T>(domain_name, method, fn_params)
+            .await?
+        {
+            return Ok(Some(value));
+        }
+
+        let key = (domain_name, method, fn_params);
+        let (value, expiration) = match self.remote.get::<serde_json::Value>(&key).await {
+            Ok(Some(entry)) => entry,
+            Ok(None) | Err(CacheError::RemoteCacheUnavailable) => return Ok(None),
+            Err(err) => {
+                warn!(
+                    ?err,
+                    domain_name, method, "Failed to read from remote cache"
+                );
+                return Ok(None);
+            }
+        };
+
+        // don't let the local copy outlive the remote entry
+        let ttl = match (expiration.as_duration(), expiration.time_to_live()) {
+            (None, _) => Some(ExpirationType::Never),
+            (Some(_), Some(ttl)) => Some(ExpirationType::AfterDuration(ttl)),
+            (Some(_), None) => None,
+        };
+        if let Some(ttl) = ttl {
+            self.local
+                .cache_call_result_with_expiration(domain_name, method, fn_params, &value, ttl)
+                .await?;
+        }
+
+        serde_json::from_value(value)
+            .map(Some)
+            .map_err(CacheError::FailedToDeserializeOutput)
+    }
+}
@@ -1,18 +1,27 @@
+mod backend;
 mod error;
+mod layered_cache;
 mod metered_cache;
 mod moka;
 mod optional_cache;
+mod redis_cache;
 
 use async_trait::async_trait;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
+pub use backend::{
+    CacheBackend, CacheBackendConf, DEFAULT_REMOTE_CACHE_KEY_PREFIX,
+    DEFAULT_REMOTE_CACHE_MAX_ENTRY_SIZE,
+};
 pub use error::CacheError;
+pub use layered_cache::LayeredCache;
 pub use metered_cache::{
     MeteredCache, MeteredCacheConfig, MeteredCacheMetrics, MeteredCacheMetricsBuilder,
     HIT_COUNT_HELP, HIT_COUNT_LABELS, MISS_COUNT_HELP, MISS_COUNT_LABELS,
 };
 pub use moka::{CacheResult, Expiration, LocalCache};
 pub use optional_cache::OptionalCache;
+pub use redis_cache::RedisCache;
 
 /// Should be used as the `fn_params` when the function has no parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,6 +16,19 @@ impl LocalCache {
     pub fn new(name: &str) -> Self {
         Self(BaseCache::new(name))
     }
+
+    /// Cache a call result with the given parameters and a custom expiration
+    pub(crate) async fn cache_call_result_with_expiration(
+        &self,
+        domain_name: &str,
+        method: &str,
+        fn_params: &(impl Serialize + Send + Sync),
+        result: &(impl Serialize + Send + Sync),
+        ttl: ExpirationType,
+    ) -> CacheResult<()> {
+        let key = (domain_name, method, fn_params);
+        self.0.set(&key, result, ttl).await.map(|_| ())
+    }
 }
 
 #[async_trait]
@@ -28,11 +41,14 @@ impl FunctionCallCache for LocalCache {
         fn_params: &(impl Serialize + Send + Sync),
         result: &(impl Serialize + Send + Sync),
     ) -> CacheResult<()> {
-        let key = (domain_name, method, fn_params);
-        self.0
-            .set(&key, result, ExpirationType::Default)
-            .await
-            .map(|_| ())
+        self.cache_call_result_with_expiration(
+            domain_name,
+            method,
+            fn_params,
+            result,
+            ExpirationType::Default,
+        )
+        .await
     }
 
     /// Get a cached call result with the given parameters
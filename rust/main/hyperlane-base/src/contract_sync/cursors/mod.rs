@@ -1,5 +1,6 @@
 use hyperlane_core::{
     Delivery, HyperlaneDomainProtocol, HyperlaneMessage, InterchainGasPayment, MerkleTreeInsertion,
+    ValidatorAnnouncement,
 };
 
 pub(crate) mod sequence_aware;
@@ -39,7 +40,7 @@ impl Indexable for HyperlaneMessage {
     fn indexing_cursor(domain: HyperlaneDomainProtocol) -> CursorType {
         match domain {
             HyperlaneDomainProtocol::Ethereum => CursorType::SequenceAware,
-            HyperlaneDomainProtocol::Fuel => todo!(),
+            HyperlaneDomainProtocol::Fuel => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Sealevel => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Cosmos => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Starknet => CursorType::SequenceAware,
@@ -64,7 +65,7 @@ impl Indexable for InterchainGasPayment {
     fn indexing_cursor(domain: HyperlaneDomainProtocol) -> CursorType {
         match domain {
             HyperlaneDomainProtocol::Ethereum => CursorType::RateLimited,
-            HyperlaneDomainProtocol::Fuel => todo!(),
+            HyperlaneDomainProtocol::Fuel => CursorType::RateLimited,
             HyperlaneDomainProtocol::Sealevel => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Cosmos => CursorType::RateLimited,
             HyperlaneDomainProtocol::Starknet => CursorType::RateLimited,
@@ -84,7 +85,7 @@ impl Indexable for MerkleTreeInsertion {
     fn indexing_cursor(domain: HyperlaneDomainProtocol) -> CursorType {
         match domain {
             HyperlaneDomainProtocol::Ethereum => CursorType::SequenceAware,
-            HyperlaneDomainProtocol::Fuel => todo!(),
+            HyperlaneDomainProtocol::Fuel => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Sealevel => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Cosmos => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Starknet => CursorType::SequenceAware,
@@ -104,7 +105,7 @@ impl Indexable for Delivery {
     fn indexing_cursor(domain: HyperlaneDomainProtocol) -> CursorType {
         match domain {
             HyperlaneDomainProtocol::Ethereum => CursorType::RateLimited,
-            HyperlaneDomainProtocol::Fuel => todo!(),
+            HyperlaneDomainProtocol::Fuel => CursorType::RateLimited,
             HyperlaneDomainProtocol::Sealevel => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Cosmos => CursorType::RateLimited,
             HyperlaneDomainProtocol::Starknet => CursorType::RateLimited,
@@ -119,3 +120,23 @@ impl Indexable for Delivery {
         "delivery"
     }
 }
+
+impl Indexable for ValidatorAnnouncement {
+    fn indexing_cursor(domain: HyperlaneDomainProtocol) -> CursorType {
+        match domain {
+            HyperlaneDomainProtocol::Ethereum => CursorType::RateLimited,
+            HyperlaneDomainProtocol::Fuel => CursorType::RateLimited,
+            HyperlaneDomainProtocol::Sealevel => CursorType::RateLimited,
+            HyperlaneDomainProtocol::Cosmos => CursorType::RateLimited,
+            HyperlaneDomainProtocol::Starknet => CursorType::RateLimited,
+            HyperlaneDomainProtocol::CosmosNative => CursorType::RateLimited,
+            HyperlaneDomainProtocol::Radix => CursorType::RateLimited,
+            HyperlaneDomainProtocol::Aleo => CursorType::RateLimited,
+            HyperlaneDomainProtocol::Tron => CursorType::RateLimited,
+        }
+    }
+
+    fn name() -> &'static str {
+        "validator_announcement"
+    }
+}
@@ -149,7 +149,9 @@ impl<T: Debug + Clone + Sync + Send + Indexable + 'static> BackwardSequenceAware
         match self.current_indexing_snapshot.as_ref() {
             Some(current_indexing_snapshot) => {
                 let range = match &self.index_mode {
-                    IndexMode::Block => self.get_next_block_range(current_indexing_snapshot).await,
+                    IndexMode::Block | IndexMode::Subscription => {
+                        self.get_next_block_range(current_indexing_snapshot).await
+                    }
                     IndexMode::Sequence => {
                         self.get_next_sequence_range(current_indexing_snapshot)
                             .await
@@ -239,7 +241,7 @@ impl<T: Debug + Clone + Sync + Send + Indexable + 'static> BackwardSequenceAware
             .await
             .ok()?;
         match self.index_mode {
-            IndexMode::Block => {
+            IndexMode::Block | IndexMode::Subscription => {
                 let lowest_block_height =
                     (latest_tip as i64).saturating_add(self.lowest_block_height_or_sequence);
                 if lowest_block_height < 0 {
@@ -560,7 +562,7 @@ impl<T: Debug + Clone + Sync + Send + Indexable + 'static> ContractSyncCursor<T>
                 range,
                 current_indexing_snapshot,
             )?,
-            IndexMode::Block => {
+            IndexMode::Block | IndexMode::Subscription => {
                 self.update_block_range(logs, &all_log_sequences, range, current_indexing_snapshot)?
             }
         }
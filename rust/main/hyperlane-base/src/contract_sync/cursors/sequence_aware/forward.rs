@@ -43,6 +43,9 @@ pub(crate) struct ForwardSequenceAwareSyncCursor<T> {
     target_snapshot: Option<TargetSnapshot>,
     /// The mode of indexing.
     index_mode: IndexMode,
+    /// The id of the last reorg rewind that was applied, or that already
+    /// existed when the cursor was created.
+    reorg_rewind_id: Option<u64>,
     /// The domain the cursor is indexing.
     domain: HyperlaneDomain,
     /// Cursor metrics.
@@ -75,6 +78,7 @@ impl<T: Debug + Clone + Sync + Send + Indexable + 'static> ForwardSequenceAwareS
         next_sequence: u32,
         start_block: u32,
         index_mode: IndexMode,
+        reorg_rewind_id: Option<u64>,
         metrics_data: MetricsData,
     ) -> Self {
         // If the next sequence is 0, we're starting from the beginning and haven't
@@ -96,6 +100,7 @@ impl<T: Debug + Clone + Sync + Send + Indexable + 'static> ForwardSequenceAwareS
             },
             target_snapshot: None,
             index_mode,
+            reorg_rewind_id,
             domain,
             metrics,
         }
@@ -111,6 +116,8 @@ impl<T: Debug + Clone + Sync + Send + Indexable + 'static> ForwardSequenceAwareS
     /// If there are logs to index, returns the range of logs, either by sequence or block number
     /// depending on the mode.
     pub async fn get_next_range(&mut self) -> Result<Option<RangeInclusive<u32>>> {
+        // Index again any logs that were pruned because their blocks were reorged.
+        self.rewind_if_reorged().await?;
         // Skip any already indexed logs.
         self.skip_indexed().await?;
 
@@ -154,7 +161,7 @@ impl<T: Debug + Clone + Sync + Send + Indexable + 'static> ForwardSequenceAwareS
                 });
 
                 match &self.index_mode {
-                    IndexMode::Block => self.get_next_block_range(tip),
+                    IndexMode::Block | IndexMode::Subscription => self.get_next_block_range(tip),
                     IndexMode::Sequence => {
                         Some(self.get_next_sequence_range(current_sequence, target_sequence))
                     }
@@ -246,6 +253,35 @@ impl<T: Debug + Clone + Sync + Send + Indexable + 'static> ForwardSequenceAwareS
         Ok(())
     }
 
+    /// Rewinds the cursor to the lowest sequence pruned by a reorg rewind that
+    /// hasn't been applied yet, if the cursor already went past it.
+    async fn rewind_if_reorged(&mut self) -> Result<()> {
+        let Some(reorg_rewind) = self.store.retrieve_reorg_rewind().await? else {
+            return Ok(());
+        };
+        if self.reorg_rewind_id == Some(reorg_rewind.id) {
+            return Ok(());
+        }
+        self.reorg_rewind_id = Some(reorg_rewind.id);
+        if self.current_indexing_snapshot.sequence <= reorg_rewind.sequence {
+            return Ok(());
+        }
+
+        warn!(
+            ?reorg_rewind,
+            current_indexing_snapshot=?self.current_indexing_snapshot,
+            last_indexed_snapshot=?self.last_indexed_snapshot,
+            "Indexed logs were pruned after a reorg, rewinding to the lowest pruned sequence",
+        );
+        self.last_indexed_snapshot = LastIndexedSnapshot {
+            sequence: reorg_rewind.sequence.checked_sub(1),
+            at_block: reorg_rewind.block_number.try_into()?,
+        };
+        self.current_indexing_snapshot = self.last_indexed_snapshot.next_target();
+        self.target_snapshot = None;
+        Ok(())
+    }
+
     /// Gets the log block number of a previously indexed sequence. Returns None if the
     /// log for the sequence number hasn't been indexed.
     async fn get_sequence_log_block_number(&self, sequence: u32) -> Result<Option<u32>> {
@@ -521,7 +557,9 @@ impl<T: Send + Sync + Clone + Debug + Indexable + 'static> ContractSyncCursor<T>
             .collect::<HashSet<_>>();
 
         match &self.index_mode {
-            IndexMode::Block => self.update_block_range(logs, &all_log_sequences, range)?,
+            IndexMode::Block | IndexMode::Subscription => {
+                self.update_block_range(logs, &all_log_sequences, range)?
+            }
             IndexMode::Sequence => self.update_sequence_range(logs, &all_log_sequences, range)?,
         };
         Ok(())
@@ -532,7 +570,8 @@ impl<T: Send + Sync + Clone + Debug + Indexable + 'static> ContractSyncCursor<T>
 pub(crate) mod test {
     use derive_new::new;
     use hyperlane_core::{
-        ChainResult, HyperlaneDomainProtocol, HyperlaneLogStore, Indexed, Indexer, Sequenced,
+        ChainResult, HyperlaneDomainProtocol, HyperlaneLogStore, Indexed, Indexer, ReorgRewind,
+        Sequenced,
     };
 
     use crate::cursors::CursorType;
@@ -722,6 +761,7 @@ pub(crate) mod test {
             3,
             70,
             mode,
+            None,
             metrics_data,
         );
 
@@ -1114,6 +1154,80 @@ pub(crate) mod test {
                 }
             );
         }
+
+        #[derive(Debug)]
+        struct MockReorgedStore {
+            store: MockHyperlaneSequenceAwareIndexerStore<MockSequencedData>,
+            reorg_rewind: ReorgRewind,
+        }
+
+        #[async_trait]
+        impl HyperlaneSequenceAwareIndexerStoreReader<MockSequencedData> for MockReorgedStore {
+            async fn retrieve_by_sequence(
+                &self,
+                sequence: u32,
+            ) -> eyre::Result<Option<MockSequencedData>> {
+                self.store.retrieve_by_sequence(sequence).await
+            }
+
+            async fn retrieve_log_block_number_by_sequence(
+                &self,
+                sequence: u32,
+            ) -> eyre::Result<Option<u64>> {
+                self.store
+                    .retrieve_log_block_number_by_sequence(sequence)
+                    .await
+            }
+
+            async fn retrieve_reorg_rewind(&self) -> eyre::Result<Option<ReorgRewind>> {
+                Ok(Some(self.reorg_rewind))
+            }
+        }
+
+        /// Tests that the cursor indexes pruned logs again after a reorg, and only rewinds once.
+        #[tracing_test::traced_test]
+        #[tokio::test]
+        async fn test_rewinds_after_reorg() {
+            let mut cursor = get_cursor().await;
+
+            // Sequences 3 and 4 were pruned because their blocks were reorged
+            cursor.store = Arc::new(MockReorgedStore {
+                store: MockHyperlaneSequenceAwareIndexerStore {
+                    logs: vec![
+                        (MockSequencedData::new(0), log_meta_with_block(50)),
+                        (MockSequencedData::new(1), log_meta_with_block(60)),
+                        (MockSequencedData::new(2), log_meta_with_block(70)),
+                    ],
+                },
+                reorg_rewind: ReorgRewind::new(1, 3, 79),
+            });
+
+            let range = cursor.get_next_range().await.unwrap().unwrap();
+            assert_eq!(range, 79..=100);
+            assert_eq!(
+                cursor.last_indexed_snapshot,
+                LastIndexedSnapshot {
+                    sequence: Some(2),
+                    at_block: 79,
+                }
+            );
+
+            cursor
+                .update(
+                    vec![
+                        (MockSequencedData::new(3).into(), log_meta_with_block(85)),
+                        (MockSequencedData::new(4).into(), log_meta_with_block(95)),
+                    ],
+                    range,
+                )
+                .await
+                .unwrap();
+
+            // The rewind was applied already, so the cursor stays synced
+            let range = cursor.get_next_range().await.unwrap();
+            assert_eq!(range, None);
+            assert_eq!(cursor.current_indexing_snapshot.sequence, 5);
+        }
     }
 
     mod sequence_range {
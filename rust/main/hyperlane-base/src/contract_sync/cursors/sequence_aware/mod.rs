@@ -104,6 +104,8 @@ impl<T: Debug + Indexable + Clone + Sync + Send + 'static>
             domain: domain.to_owned(),
             metrics,
         };
+        // rewinds that happened before startup don't apply, the cursor starts at the tip
+        let reorg_rewind_id = store.retrieve_reorg_rewind().await?.map(|rewind| rewind.id);
         let forward_cursor = ForwardSequenceAwareSyncCursor::new(
             chunk_size,
             latest_sequence_querier.clone(),
@@ -111,6 +113,7 @@ impl<T: Debug + Indexable + Clone + Sync + Send + 'static>
             sequence_count,
             tip,
             mode,
+            reorg_rewind_id,
             metrics_data.clone(),
         );
 
@@ -8,7 +8,10 @@ use broadcast::BroadcastMpscSender;
 use cursors::*;
 use derive_new::new;
 use eyre::Result;
-use prometheus::core::{AtomicI64, AtomicU64, GenericCounter, GenericGauge};
+use prometheus::{
+    core::{AtomicI64, AtomicU64, GenericCounter, GenericGauge},
+    IntGaugeVec,
+};
 use tokio::sync::{mpsc::Receiver as MpscReceiver, Mutex};
 use tokio::time::sleep;
 use tracing::{debug, info, instrument, trace, warn, Instrument};
@@ -179,7 +182,36 @@ where
             None => tokio::task::spawn(async {}),
         };
 
-        let res = tokio::join!(tx_id_task, cursor_task);
+        // subscription task for logs pushed by the node, the cursor task fills any gaps
+        let subscription_task = {
+            let domain_clone = self.domain.clone();
+            let indexer_clone = self.indexer.clone();
+            let store_clone = shared_store.clone();
+            let broadcast_sender = self.broadcast_sender.clone();
+            let liveness_metrics = self.metrics.liveness_metrics.clone();
+
+            tokio::task::spawn(
+                async move {
+                    Self::subscription_indexer_task(
+                        domain_clone,
+                        label,
+                        indexer_clone,
+                        store_clone,
+                        broadcast_sender,
+                        stored_logs_metric,
+                        liveness_metrics,
+                    )
+                    .await
+                }
+                .instrument(tracing::info_span!(
+                    "spawn_subscription_indexer_task",
+                    domain = self.domain().name(),
+                    label
+                )),
+            )
+        };
+
+        let res = tokio::join!(tx_id_task, cursor_task, subscription_task);
 
         // we should never reach this because the 2 tasks should never end
         tracing::error!(chain = chain_name, label, ?res, "contract sync loop exit");
@@ -318,6 +350,73 @@ where
         }
     }
 
+    /// Stores the logs pushed by a subscription and broadcasts their tx ids.
+    /// Returns right away if the indexer doesn't support subscriptions.
+    #[allow(clippy::too_many_arguments)]
+    #[instrument(fields(domain=domain.name()), skip(indexer, store, broadcast_sender, stored_logs_metric, liveness_metrics))]
+    async fn subscription_indexer_task(
+        domain: HyperlaneDomain,
+        label: &'static str,
+        indexer: I,
+        store: Arc<Mutex<S>>,
+        broadcast_sender: Option<BroadcastMpscSender<H512>>,
+        stored_logs_metric: GenericCounter<AtomicU64>,
+        liveness_metrics: IntGaugeVec,
+    ) {
+        // only created once subscribed, so indexers without subscriptions don't report a stale task
+        let mut liveness_metric = None;
+        loop {
+            let mut subscription = match indexer.subscribe_logs().await {
+                Ok(Some(subscription)) => subscription,
+                Ok(None) => {
+                    trace!("Indexer doesn't support log subscriptions");
+                    return;
+                }
+                Err(err) => {
+                    warn!(?err, "Error subscribing to logs");
+                    sleep(SLEEP_DURATION).await;
+                    continue;
+                }
+            };
+            info!("Subscribed to logs");
+            let liveness_metric = liveness_metric.get_or_insert_with(|| {
+                liveness_metrics.with_label_values(&[label, domain.name(), "subscription_task"])
+            });
+
+            loop {
+                Self::update_liveness_metric(liveness_metric);
+                let log = match subscription.next_log().await {
+                    Some(Ok(log)) => log,
+                    Some(Err(err)) => {
+                        warn!(?err, "Error receiving subscribed log");
+                        continue;
+                    }
+                    None => break,
+                };
+
+                let logs = {
+                    let store = store.lock().await;
+                    Self::dedupe_and_store_logs(&domain, &store, vec![log], &stored_logs_metric)
+                        .await
+                };
+                for (log, meta) in logs {
+                    debug!(
+                        log = ?IndexedTxIdAndSequence::new(meta.transaction_id, log.sequence),
+                        "Received subscribed log"
+                    );
+                    if let Some(tx) = broadcast_sender.as_ref() {
+                        if let Err(err) = tx.send(meta.transaction_id).await {
+                            trace!(?err, "Error sending txid to receiver");
+                        }
+                    }
+                }
+            }
+
+            warn!("Log subscription ended, resubscribing");
+            sleep(SLEEP_DURATION).await;
+        }
+    }
+
     async fn dedupe_and_store_logs(
         domain: &HyperlaneDomain,
         store: &S,
@@ -1,4 +1,6 @@
-pub use self::storage_types::{InterchainGasExpenditureData, InterchainGasPaymentData};
+pub use self::storage_types::{
+    IndexedGasPaymentData, InterchainGasExpenditureData, InterchainGasPaymentData,
+};
 pub use error::*;
 pub use rocks::*;
 
@@ -8,11 +8,14 @@ use hyperlane_core::{
     identifiers::UniqueIdentifier, Decode, Encode, GasPaymentKey, HyperlaneDomain,
     HyperlaneLogStore, HyperlaneMessage, HyperlaneSequenceAwareIndexerStoreReader,
     HyperlaneWatermarkedLogStore, Indexed, InterchainGasExpenditure, InterchainGasPayment,
-    InterchainGasPaymentMeta, LogMeta, MerkleTreeInsertion, PendingOperationStatus, H256, H512,
+    InterchainGasPaymentMeta, LogMeta, MerkleTreeInsertion, PendingOperationStatus, ReorgRewind,
+    H256, H512,
 };
 
 use crate::db::{
-    storage_types::{InterchainGasExpenditureData, InterchainGasPaymentData},
+    storage_types::{
+        IndexedGasPaymentData, InterchainGasExpenditureData, InterchainGasPaymentData,
+    },
     HyperlaneDb,
 };
 
@@ -41,6 +44,15 @@ const MERKLE_TREE_INSERTION_BLOCK_NUMBER_BY_LEAF_INDEX: &str =
 const LATEST_INDEXED_GAS_PAYMENT_BLOCK: &str = "latest_indexed_gas_payment_block";
 const PAYLOAD_UUIDS_BY_MESSAGE_ID: &str = "payload_uuids_by_message_id_";
 const MESSAGE_DISPATCHED_TX_HASH_BY_MESSAGE_ID: &str = "message_dispatched_tx_hash_by_message_id_";
+const MESSAGE_DISPATCHED_BLOCK_HASH: &str = "message_dispatched_block_hash_";
+const MERKLE_TREE_INSERTION_BLOCK_HASH_BY_LEAF_INDEX: &str =
+    "merkle_tree_insertion_block_hash_by_leaf_index_";
+const HIGHEST_SEEN_MERKLE_LEAF_INDEX: &str = "highest_seen_merkle_leaf_index_";
+const GAS_PAYMENTS_BY_BLOCK_NUMBER: &str = "gas_payments_by_block_number_";
+const HIGHEST_GAS_PAYMENT_BLOCK_NUMBER: &str = "highest_gas_payment_block_number";
+const MESSAGE_REORG_REWIND: &str = "message_reorg_rewind";
+const MERKLE_TREE_INSERTION_REORG_REWIND: &str = "merkle_tree_insertion_reorg_rewind";
+const GAS_PAYMENT_REORG_REWIND: &str = "gas_payment_reorg_rewind";
 
 /// Rocks DB result type
 pub type DbResult<T> = std::result::Result<T, DbError>;
@@ -154,6 +166,9 @@ impl HyperlaneRocksDB {
     ) -> DbResult<bool> {
         let payment = *(indexed_payment.inner());
         let gas_processing_successful = self.process_gas_payment(payment, log_meta)?;
+        if gas_processing_successful {
+            self.store_gas_payment_by_block_number(indexed_payment, log_meta)?;
+        }
 
         // only store the payment and return early if there's no sequence
         let Some(gas_payment_sequence) = indexed_payment.sequence else {
@@ -239,10 +254,213 @@ impl HyperlaneRocksDB {
             &insertion.index(),
             &insertion_block_number,
         )?;
+        self.try_update_max_seen_merkle_leaf_index(insertion.index())?;
         // Return true to indicate the tree insertion was processed
         Ok(true)
     }
 
+    /// Update the highest merkle leaf index we're aware of
+    fn try_update_max_seen_merkle_leaf_index(&self, leaf_index: u32) -> DbResult<()> {
+        let current_max = self
+            .retrieve_highest_seen_merkle_leaf_index()?
+            .unwrap_or_default();
+        if leaf_index >= current_max {
+            self.store_encodable("", HIGHEST_SEEN_MERKLE_LEAF_INDEX, &leaf_index)?;
+        }
+        Ok(())
+    }
+
+    /// Retrieve the highest merkle leaf index we're aware of
+    pub fn retrieve_highest_seen_merkle_leaf_index(&self) -> DbResult<Option<u32>> {
+        self.retrieve_decodable("", HIGHEST_SEEN_MERKLE_LEAF_INDEX)
+    }
+
+    /// Record a newly processed gas payment under the block it was indexed
+    /// from, so it can be reverted if the block is reorged
+    fn store_gas_payment_by_block_number(
+        &self,
+        payment: Indexed<InterchainGasPayment>,
+        log_meta: &LogMeta,
+    ) -> DbResult<()> {
+        let mut payments = self.retrieve_gas_payments_by_block_number(log_meta.block_number)?;
+        payments.push(IndexedGasPaymentData {
+            payment,
+            meta: log_meta.into(),
+            block_hash: log_meta.block_hash,
+        });
+        self.store_value_by_key(
+            GAS_PAYMENTS_BY_BLOCK_NUMBER,
+            &log_meta.block_number,
+            &payments,
+        )?;
+
+        let highest = self
+            .retrieve_highest_gas_payment_block_number()?
+            .unwrap_or_default();
+        if log_meta.block_number >= highest {
+            self.store_encodable("", HIGHEST_GAS_PAYMENT_BLOCK_NUMBER, &log_meta.block_number)?;
+        }
+        Ok(())
+    }
+
+    /// Retrieve the gas payments processed from logs of a block
+    pub fn retrieve_gas_payments_by_block_number(
+        &self,
+        block_number: u64,
+    ) -> DbResult<Vec<IndexedGasPaymentData>> {
+        Ok(self
+            .retrieve_value_by_key(GAS_PAYMENTS_BY_BLOCK_NUMBER, &block_number)?
+            .unwrap_or_default())
+    }
+
+    /// Retrieve the highest block number gas payments were processed from
+    pub fn retrieve_highest_gas_payment_block_number(&self) -> DbResult<Option<u64>> {
+        self.retrieve_decodable("", HIGHEST_GAS_PAYMENT_BLOCK_NUMBER)
+    }
+
+    /// Store the hash of the block a message was dispatched in
+    pub fn store_dispatched_block_hash_by_nonce(&self, nonce: &u32, hash: &H256) -> DbResult<()> {
+        self.store_value_by_key(MESSAGE_DISPATCHED_BLOCK_HASH, nonce, hash)
+    }
+
+    /// Retrieve the hash of the block a message was dispatched in
+    pub fn retrieve_dispatched_block_hash_by_nonce(&self, nonce: &u32) -> DbResult<Option<H256>> {
+        self.retrieve_value_by_key(MESSAGE_DISPATCHED_BLOCK_HASH, nonce)
+    }
+
+    /// Store the hash of the block a merkle tree insertion happened in
+    pub fn store_merkle_tree_insertion_block_hash_by_leaf_index(
+        &self,
+        leaf_index: &u32,
+        hash: &H256,
+    ) -> DbResult<()> {
+        self.store_value_by_key(
+            MERKLE_TREE_INSERTION_BLOCK_HASH_BY_LEAF_INDEX,
+            leaf_index,
+            hash,
+        )
+    }
+
+    /// Retrieve the hash of the block a merkle tree insertion happened in
+    pub fn retrieve_merkle_tree_insertion_block_hash_by_leaf_index(
+        &self,
+        leaf_index: &u32,
+    ) -> DbResult<Option<H256>> {
+        self.retrieve_value_by_key(MERKLE_TREE_INSERTION_BLOCK_HASH_BY_LEAF_INDEX, leaf_index)
+    }
+
+    /// Delete a message indexed from a reorged block, and lower the highest
+    /// seen nonce below it. Messages must be pruned from the highest nonce down.
+    pub fn prune_message(&self, nonce: u32) -> DbResult<()> {
+        if let Some(id) = self.retrieve_message_id_by_nonce(&nonce)? {
+            self.delete_value_by_key(MESSAGE, &id)?;
+            self.delete_value_by_key(MESSAGE_DISPATCHED_TX_HASH_BY_MESSAGE_ID, &id)?;
+        }
+        self.delete_value_by_key(MESSAGE_ID, &nonce)?;
+        self.delete_value_by_key(MESSAGE_DISPATCHED_BLOCK_NUMBER, &nonce)?;
+        self.delete_value_by_key(MESSAGE_DISPATCHED_BLOCK_HASH, &nonce)?;
+        match nonce.checked_sub(1) {
+            Some(highest) => self.store_highest_seen_message_nonce_number(&highest),
+            None => self.delete_value_by_key(HIGHEST_SEEN_MESSAGE_NONCE, &bool::default()),
+        }
+    }
+
+    /// Delete a merkle tree insertion indexed from a reorged block, and lower
+    /// the highest seen leaf index below it. Insertions must be pruned from the
+    /// highest leaf index down.
+    pub fn prune_tree_insertion(&self, leaf_index: u32) -> DbResult<()> {
+        if let Some(insertion) = self.retrieve_merkle_tree_insertion_by_leaf_index(&leaf_index)? {
+            let message_id = insertion.message_id();
+            if self.retrieve_merkle_leaf_index_by_message_id(&message_id)? == Some(leaf_index) {
+                self.delete_value_by_key(MERKLE_LEAF_INDEX_BY_MESSAGE_ID, &message_id)?;
+            }
+        }
+        self.delete_value_by_key(MERKLE_TREE_INSERTION, &leaf_index)?;
+        self.delete_value_by_key(
+            MERKLE_TREE_INSERTION_BLOCK_NUMBER_BY_LEAF_INDEX,
+            &leaf_index,
+        )?;
+        self.delete_value_by_key(MERKLE_TREE_INSERTION_BLOCK_HASH_BY_LEAF_INDEX, &leaf_index)?;
+        match leaf_index.checked_sub(1) {
+            Some(highest) => self.store_encodable("", HIGHEST_SEEN_MERKLE_LEAF_INDEX, &highest),
+            None => self.delete_value("", HIGHEST_SEEN_MERKLE_LEAF_INDEX),
+        }
+    }
+
+    /// Revert the gas payments of a block that were indexed from a reorged
+    /// version of it. Payments in `kept` stay recorded for the block.
+    pub fn revert_gas_payments(
+        &self,
+        block_number: u64,
+        reverted: &[IndexedGasPaymentData],
+        kept: &[IndexedGasPaymentData],
+    ) -> DbResult<()> {
+        for data in reverted {
+            self.revert_gas_payment(data)?;
+        }
+        if kept.is_empty() {
+            self.delete_value_by_key(GAS_PAYMENTS_BY_BLOCK_NUMBER, &block_number)
+        } else {
+            self.store_value_by_key(GAS_PAYMENTS_BY_BLOCK_NUMBER, &block_number, &kept.to_vec())
+        }
+    }
+
+    /// Subtract a gas payment from the total of its message and forget that it
+    /// was processed, so it's processed again if it's still part of the chain
+    fn revert_gas_payment(&self, data: &IndexedGasPaymentData) -> DbResult<()> {
+        let payment = *data.payment.inner();
+        let gas_payment_key: GasPaymentKey = payment.into();
+        if let Some(total) =
+            self.retrieve_interchain_gas_payment_data_by_gas_payment_key(&gas_payment_key)?
+        {
+            let remaining = InterchainGasPaymentData {
+                payment: total.payment.saturating_sub(payment.payment),
+                gas_amount: total.gas_amount.saturating_sub(payment.gas_amount),
+            };
+            debug!(?payment, ?remaining, "Reverting reorged gas payment");
+            self.store_interchain_gas_payment_data_by_gas_payment_key(
+                &gas_payment_key,
+                &remaining,
+            )?;
+        }
+        self.delete_value_by_key(GAS_PAYMENT_META_PROCESSED, &data.meta)?;
+        if let Some(sequence) = data.payment.sequence {
+            self.delete_value_by_key(GAS_PAYMENT_BY_SEQUENCE, &sequence)?;
+            self.delete_value_by_key(GAS_PAYMENT_BLOCK_BY_SEQUENCE, &sequence)?;
+        }
+        Ok(())
+    }
+
+    /// Store where message cursors rewind to after messages were pruned
+    pub fn store_message_reorg_rewind(&self, rewind: &ReorgRewind) -> DbResult<()> {
+        self.store_encodable("", MESSAGE_REORG_REWIND, rewind)
+    }
+
+    /// Retrieve where message cursors rewind to after messages were pruned
+    pub fn retrieve_message_reorg_rewind(&self) -> DbResult<Option<ReorgRewind>> {
+        self.retrieve_decodable("", MESSAGE_REORG_REWIND)
+    }
+
+    /// Store where merkle tree insertion cursors rewind to after insertions were pruned
+    pub fn store_merkle_tree_insertion_reorg_rewind(&self, rewind: &ReorgRewind) -> DbResult<()> {
+        self.store_encodable("", MERKLE_TREE_INSERTION_REORG_REWIND, rewind)
+    }
+
+    /// Retrieve where merkle tree insertion cursors rewind to after insertions were pruned
+    pub fn retrieve_merkle_tree_insertion_reorg_rewind(&self) -> DbResult<Option<ReorgRewind>> {
+        self.retrieve_decodable("", MERKLE_TREE_INSERTION_REORG_REWIND)
+    }
+
+    /// Store where gas payment cursors rewind to after sequenced payments were reverted
+    pub fn store_gas_payment_reorg_rewind(&self, rewind: &ReorgRewind) -> DbResult<()> {
+        self.store_encodable("", GAS_PAYMENT_REORG_REWIND, rewind)
+    }
+
+    /// Retrieve where gas payment cursors rewind to after sequenced payments were reverted
+    pub fn retrieve_gas_payment_reorg_rewind(&self) -> DbResult<Option<ReorgRewind>> {
+        self.retrieve_decodable("", GAS_PAYMENT_REORG_REWIND)
+    }
+
     /// Processes the gas expenditure and store the total expenditure for the
     /// message.
     pub fn process_gas_expenditure(&self, expenditure: InterchainGasExpenditure) -> DbResult<()> {
@@ -318,6 +536,10 @@ impl HyperlaneLogStore<HyperlaneMessage> for HyperlaneRocksDB {
         for (message, meta) in messages {
             let stored_message = self.store_message(message.inner(), meta.block_number)?;
             if stored_message {
+                self.store_dispatched_block_hash_by_nonce(
+                    &message.inner().nonce,
+                    &meta.block_hash,
+                )?;
                 stored = stored.saturating_add(1);
             }
             self.store_dispatched_tx_hash_by_message_id(
@@ -376,6 +598,10 @@ impl HyperlaneLogStore<MerkleTreeInsertion> for HyperlaneRocksDB {
         let mut insertions: u32 = 0;
         for (insertion, meta) in leaves {
             if self.process_tree_insertion(insertion.inner(), meta.block_number)? {
+                self.store_merkle_tree_insertion_block_hash_by_leaf_index(
+                    &insertion.inner().index(),
+                    &meta.block_hash,
+                )?;
                 insertions = insertions.saturating_add(1);
             }
         }
@@ -396,6 +622,11 @@ impl HyperlaneSequenceAwareIndexerStoreReader<HyperlaneMessage> for HyperlaneRoc
         let number = self.retrieve_dispatched_block_number_by_nonce(&sequence)?;
         Ok(number)
     }
+
+    /// Gets where cursors rewind to after messages were pruned.
+    async fn retrieve_reorg_rewind(&self) -> Result<Option<ReorgRewind>> {
+        Ok(self.retrieve_message_reorg_rewind()?)
+    }
 }
 
 #[async_trait]
@@ -411,6 +642,11 @@ impl HyperlaneSequenceAwareIndexerStoreReader<MerkleTreeInsertion> for Hyperlane
         let number = self.retrieve_merkle_tree_insertion_block_number_by_leaf_index(&sequence)?;
         Ok(number)
     }
+
+    /// Gets where cursors rewind to after insertions were pruned.
+    async fn retrieve_reorg_rewind(&self) -> Result<Option<ReorgRewind>> {
+        Ok(self.retrieve_merkle_tree_insertion_reorg_rewind()?)
+    }
 }
 
 // TODO: replace this blanket implementation to be able to do sequence-aware indexing
@@ -425,6 +661,11 @@ impl HyperlaneSequenceAwareIndexerStoreReader<InterchainGasPayment> for Hyperlan
     async fn retrieve_log_block_number_by_sequence(&self, sequence: u32) -> Result<Option<u64>> {
         Ok(self.retrieve_gas_payment_block_by_sequence(&sequence)?)
     }
+
+    /// Gets where cursors rewind to after sequenced payments were reverted.
+    async fn retrieve_reorg_rewind(&self) -> Result<Option<ReorgRewind>> {
+        Ok(self.retrieve_gas_payment_reorg_rewind()?)
+    }
 }
 
 #[async_trait]
@@ -743,4 +984,13 @@ impl HyperlaneRocksDB {
     ) -> DbResult<Option<V>> {
         self.retrieve_decodable(prefix, key.to_vec())
     }
+
+    /// Delete a value by key
+    pub fn delete_value_by_key<K: Encode>(
+        &self,
+        prefix: impl AsRef<[u8]>,
+        key: &K,
+    ) -> DbResult<()> {
+        self.delete_value(prefix, key.to_vec())
+    }
 }
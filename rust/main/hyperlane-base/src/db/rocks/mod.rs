@@ -5,6 +5,7 @@ use rocksdb::{Options, DB as Rocks};
 use tracing::info;
 
 pub use hyperlane_db::*;
+pub use reorg::*;
 pub use typed_db::*;
 
 /// Shared functionality surrounding use of rocksdb
@@ -12,6 +13,8 @@ pub mod iterator;
 
 /// DB operations tied to specific Mailbox
 mod hyperlane_db;
+/// Pruning of logs indexed from reorged blocks
+mod reorg;
 /// Type-specific db operations
 mod typed_db;
 
@@ -74,4 +77,9 @@ impl DB {
     pub fn retrieve(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
         Ok(self.0.get(key)?)
     }
+
+    /// Delete a value from the DB
+    pub fn delete(&self, key: &[u8]) -> Result<()> {
+        Ok(self.0.delete(key)?)
+    }
 }
@@ -0,0 +1,534 @@
+use std::{collections::HashMap, sync::Arc};
+
+use eyre::Result;
+use serde::{Deserialize, Serialize};
+use tracing::{info, instrument};
+
+use hyperlane_core::{
+    HyperlaneDomainProtocol, HyperlaneProvider, HyperlaneWatermarkedLogStore, InterchainGasPayment,
+    ReorgRewind, H256,
+};
+
+use crate::db::HyperlaneDb;
+
+use super::{DbResult, HyperlaneRocksDB};
+
+/// How many blocks below the highest block data was indexed from are checked
+/// by default. The walk back stops at the first canonical log or after this
+/// many blocks, so a chain which never matches can't prune everything.
+pub const DEFAULT_MAX_REORG_DEPTH: u64 = 1_000;
+
+/// The data pruned because the blocks it was indexed from were reorged
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReorgReconciliation {
+    /// The lowest reorged block that data was indexed from
+    pub reorged_from_block: Option<u64>,
+    /// Nonces of the pruned messages
+    pub pruned_message_nonces: Vec<u32>,
+    /// Leaf indices of the pruned merkle tree insertions
+    pub pruned_merkle_leaf_indices: Vec<u32>,
+    /// Message ids of the reverted gas payments
+    pub reverted_gas_payments: Vec<H256>,
+}
+
+impl ReorgReconciliation {
+    fn record_reorged_block(&mut self, block_number: u64) {
+        self.reorged_from_block = Some(
+            self.reorged_from_block
+                .map_or(block_number, |lowest| lowest.min(block_number)),
+        );
+    }
+}
+
+/// Prunes data indexed from blocks that are no longer part of the canonical
+/// chain, and rewinds the sequence-aware cursors so the canonical logs are
+/// indexed instead.
+///
+/// Only the database is reconciled. Data already loaded by running tasks, such
+/// as the relayer's message queues or the validator's in-memory merkle tree, is
+/// kept until the agent restarts. The same goes for the lowered gas payment
+/// watermark, which the rate limited cursor only reads at startup.
+#[derive(Debug)]
+pub struct ReorgReconciler {
+    db: HyperlaneRocksDB,
+    provider: Arc<dyn HyperlaneProvider>,
+    max_depth: u64,
+}
+
+impl ReorgReconciler {
+    /// Create a reconciler which walks back at most `max_depth` blocks below
+    /// the highest block data was indexed from.
+    pub fn new(db: HyperlaneRocksDB, provider: Arc<dyn HyperlaneProvider>, max_depth: u64) -> Self {
+        Self {
+            db,
+            provider,
+            max_depth,
+        }
+    }
+
+    /// Whether the logs indexed on chains of `protocol` record the hash of the
+    /// block they were included in. Other chains store a placeholder, such as
+    /// a zero hash or the block height, which never matches the canonical hash.
+    pub fn supports_protocol(protocol: HyperlaneDomainProtocol) -> bool {
+        use HyperlaneDomainProtocol::*;
+        match protocol {
+            Ethereum | Cosmos | CosmosNative | Starknet => true,
+            Fuel | Sealevel | Radix | Aleo | Tron => false,
+        }
+    }
+
+    /// Compare the block hashes of the latest indexed data with the canonical
+    /// chain, and prune whatever was indexed from reorged blocks. Data indexed
+    /// before block hashes were recorded is assumed to be canonical.
+    #[instrument(skip(self), fields(domain = %self.db.domain()))]
+    pub async fn reconcile(&self) -> Result<ReorgReconciliation> {
+        let mut blocks = CanonicalBlocks::new(self.provider.as_ref());
+        let mut reconciliation = ReorgReconciliation::default();
+
+        let db = &self.db;
+        let (nonces, reorged_block) = prune_sequenced(
+            &mut blocks,
+            self.max_depth,
+            db.retrieve_highest_seen_message_nonce()?,
+            |nonce| {
+                Ok(db
+                    .retrieve_dispatched_block_number_by_nonce(&nonce)?
+                    .zip(db.retrieve_dispatched_block_hash_by_nonce(&nonce)?))
+            },
+            |nonce| db.prune_message(nonce),
+        )
+        .await?;
+        if let (Some(&lowest), Some(block)) = (nonces.last(), reorged_block) {
+            let rewind = next_rewind(db.retrieve_message_reorg_rewind()?, lowest, block);
+            db.store_message_reorg_rewind(&rewind)?;
+            reconciliation.record_reorged_block(block);
+        }
+        reconciliation.pruned_message_nonces = nonces;
+
+        let (leaf_indices, reorged_block) = prune_sequenced(
+            &mut blocks,
+            self.max_depth,
+            db.retrieve_highest_seen_merkle_leaf_index()?,
+            |leaf_index| {
+                Ok(db
+                    .retrieve_merkle_tree_insertion_block_number_by_leaf_index(&leaf_index)?
+                    .zip(db.retrieve_merkle_tree_insertion_block_hash_by_leaf_index(&leaf_index)?))
+            },
+            |leaf_index| db.prune_tree_insertion(leaf_index),
+        )
+        .await?;
+        if let (Some(&lowest), Some(block)) = (leaf_indices.last(), reorged_block) {
+            let rewind = next_rewind(
+                db.retrieve_merkle_tree_insertion_reorg_rewind()?,
+                lowest,
+                block,
+            );
+            db.store_merkle_tree_insertion_reorg_rewind(&rewind)?;
+            reconciliation.record_reorged_block(block);
+        }
+        reconciliation.pruned_merkle_leaf_indices = leaf_indices;
+
+        self.revert_gas_payments(&mut blocks, &mut reconciliation)
+            .await?;
+
+        if let Some(block) = reconciliation.reorged_from_block {
+            info!(
+                reorged_from_block = block,
+                messages = reconciliation.pruned_message_nonces.len(),
+                merkle_tree_insertions = reconciliation.pruned_merkle_leaf_indices.len(),
+                gas_payments = reconciliation.reverted_gas_payments.len(),
+                "Pruned data indexed from reorged blocks"
+            );
+        }
+        Ok(reconciliation)
+    }
+
+    async fn revert_gas_payments(
+        &self,
+        blocks: &mut CanonicalBlocks<'_>,
+        reconciliation: &mut ReorgReconciliation,
+    ) -> Result<()> {
+        let Some(highest) = self.db.retrieve_highest_gas_payment_block_number()? else {
+            return Ok(());
+        };
+        let mut reorged_block = None;
+        let mut lowest_sequence: Option<u32> = None;
+        // unsequenced gas payments have no order to walk back in, so every
+        // block within the maximum depth is checked
+        for block_number in (highest.saturating_sub(self.max_depth)..=highest).rev() {
+            let payments = self
+                .db
+                .retrieve_gas_payments_by_block_number(block_number)?;
+            if payments.is_empty() {
+                continue;
+            }
+            let mut reverted = Vec::new();
+            let mut kept = Vec::new();
+            for payment in payments {
+                if blocks
+                    .is_canonical(block_number, payment.block_hash)
+                    .await?
+                {
+                    kept.push(payment);
+                } else {
+                    reverted.push(payment);
+                }
+            }
+            // blocks below a canonical one are canonical too
+            if reverted.is_empty() {
+                break;
+            }
+            self.db
+                .revert_gas_payments(block_number, &reverted, &kept)?;
+            reorged_block = Some(block_number);
+            lowest_sequence = reverted
+                .iter()
+                .filter_map(|data| data.payment.sequence)
+                .chain(lowest_sequence)
+                .min();
+            reconciliation
+                .reverted_gas_payments
+                .extend(reverted.iter().map(|data| data.payment.inner().message_id));
+        }
+
+        let Some(block) = reorged_block else {
+            return Ok(());
+        };
+        reconciliation.record_reorged_block(block);
+        if let Some(sequence) = lowest_sequence {
+            let rewind = next_rewind(
+                self.db.retrieve_gas_payment_reorg_rewind()?,
+                sequence,
+                block,
+            );
+            self.db.store_gas_payment_reorg_rewind(&rewind)?;
+        }
+        let watermark = u32::try_from(block.saturating_sub(1))?;
+        let current =
+            HyperlaneWatermarkedLogStore::<InterchainGasPayment>::retrieve_high_watermark(&self.db)
+                .await?;
+        if current.is_some_and(|current| current > watermark) {
+            HyperlaneWatermarkedLogStore::<InterchainGasPayment>::store_high_watermark(
+                &self.db, watermark,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+/// Canonical block hashes, fetched at most once per height
+struct CanonicalBlocks<'a> {
+    provider: &'a dyn HyperlaneProvider,
+    hashes: HashMap<u64, H256>,
+}
+
+impl<'a> CanonicalBlocks<'a> {
+    fn new(provider: &'a dyn HyperlaneProvider) -> Self {
+        Self {
+            provider,
+            hashes: HashMap::new(),
+        }
+    }
+
+    /// Whether `hash` is the canonical hash at `block_number`. A zero hash is
+    /// unknown rather than reorged, so it's treated as canonical to stop the walk.
+    async fn is_canonical(&mut self, block_number: u64, hash: H256) -> Result<bool> {
+        if hash.is_zero() {
+            return Ok(true);
+        }
+        let canonical = match self.hashes.get(&block_number) {
+            Some(canonical) => *canonical,
+            None => {
+                let canonical = self.provider.get_block_by_height(block_number).await?.hash;
+                self.hashes.insert(block_number, canonical);
+                canonical
+            }
+        };
+        Ok(canonical.is_zero() || canonical == hash)
+    }
+}
+
+/// Walk back from the highest sequence, pruning logs until one indexed from a
+/// canonical block is found, or until logs are more than `max_depth` blocks
+/// below the first one. Returns the pruned sequences from the highest down,
+/// and the lowest block they were indexed from.
+async fn prune_sequenced(
+    blocks: &mut CanonicalBlocks<'_>,
+    max_depth: u64,
+    highest: Option<u32>,
+    indexed_block: impl Fn(u32) -> DbResult<Option<(u64, H256)>>,
+    prune: impl Fn(u32) -> DbResult<()>,
+) -> Result<(Vec<u32>, Option<u64>)> {
+    let mut pruned = Vec::new();
+    let mut reorged_block: Option<u64> = None;
+    let mut lowest_block: Option<u64> = None;
+    let mut next = highest;
+    while let Some(sequence) = next {
+        let Some((block_number, hash)) = indexed_block(sequence)? else {
+            break;
+        };
+        let lowest = *lowest_block.get_or_insert(block_number.saturating_sub(max_depth));
+        if block_number < lowest {
+            break;
+        }
+        if blocks.is_canonical(block_number, hash).await? {
+            break;
+        }
+        prune(sequence)?;
+        pruned.push(sequence);
+        reorged_block = Some(reorged_block.map_or(block_number, |lowest| lowest.min(block_number)));
+        next = sequence.checked_sub(1);
+    }
+    Ok((pruned, reorged_block))
+}
+
+/// Cursors resume from the lowest pruned sequence, at the block before the reorg
+fn next_rewind(previous: Option<ReorgRewind>, sequence: u32, reorged_block: u64) -> ReorgRewind {
+    let id = previous.map_or(0, |rewind| rewind.id).saturating_add(1);
+    ReorgRewind::new(id, sequence, reorged_block.saturating_sub(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use hyperlane_core::{
+        BlockInfo, ChainInfo, ChainResult, HyperlaneChain, HyperlaneDomain, HyperlaneLogStore,
+        HyperlaneMessage, HyperlaneSequenceAwareIndexerStoreReader, Indexed, LogMeta,
+        MerkleTreeInsertion, TxnInfo, H512, U256,
+    };
+
+    use crate::db::test_utils::run_test_db;
+
+    use super::*;
+
+    /// A chain whose blocks have the hash of their number, unless reorged
+    #[derive(Debug, Clone)]
+    struct TestProvider {
+        domain: HyperlaneDomain,
+        reorged: HashMap<u64, H256>,
+    }
+
+    impl HyperlaneChain for TestProvider {
+        fn domain(&self) -> &HyperlaneDomain {
+            &self.domain
+        }
+
+        fn provider(&self) -> Box<dyn HyperlaneProvider> {
+            Box::new(self.clone())
+        }
+    }
+
+    #[async_trait]
+    impl HyperlaneProvider for TestProvider {
+        async fn get_block_by_height(&self, height: u64) -> ChainResult<BlockInfo> {
+            Ok(BlockInfo {
+                hash: self
+                    .reorged
+                    .get(&height)
+                    .copied()
+                    .unwrap_or_else(|| H256::from_low_u64_be(height)),
+                timestamp: 0,
+                number: height,
+            })
+        }
+
+        async fn get_txn_by_hash(&self, _hash: &H512) -> ChainResult<TxnInfo> {
+            unimplemented!()
+        }
+
+        async fn is_contract(&self, _address: &H256) -> ChainResult<bool> {
+            unimplemented!()
+        }
+
+        async fn get_balance(&self, _address: String) -> ChainResult<U256> {
+            unimplemented!()
+        }
+
+        async fn get_chain_metrics(&self) -> ChainResult<Option<ChainInfo>> {
+            unimplemented!()
+        }
+    }
+
+    fn log_meta(block_number: u64, log_index: u64) -> LogMeta {
+        LogMeta {
+            block_number,
+            block_hash: H256::from_low_u64_be(block_number),
+            transaction_id: H512::from_low_u64_be(block_number),
+            log_index: U256::from(log_index),
+            ..Default::default()
+        }
+    }
+
+    fn reconciler(db: &HyperlaneRocksDB, reorged_blocks: &[u64]) -> ReorgReconciler {
+        reconciler_with_depth(db, reorged_blocks, DEFAULT_MAX_REORG_DEPTH)
+    }
+
+    fn reconciler_with_depth(
+        db: &HyperlaneRocksDB,
+        reorged_blocks: &[u64],
+        max_depth: u64,
+    ) -> ReorgReconciler {
+        let provider = TestProvider {
+            domain: db.domain().clone(),
+            reorged: reorged_blocks
+                .iter()
+                .map(|&number| (number, H256::repeat_byte(0xff)))
+                .collect(),
+        };
+        ReorgReconciler::new(db.clone(), Arc::new(provider), max_depth)
+    }
+
+    #[tokio::test]
+    async fn test_prunes_messages_and_insertions_from_reorged_blocks() {
+        run_test_db(|db| async move {
+            let db = HyperlaneRocksDB::new(&HyperlaneDomain::new_test_domain("reorg_prune"), db);
+            // nonce / leaf index `i` is indexed from block `10 + i`
+            for (i, block_number) in (0..5u32).zip(10u64..) {
+                let message = HyperlaneMessage {
+                    nonce: i,
+                    ..Default::default()
+                };
+                let meta = log_meta(block_number, 0);
+                db.store_logs(&[(Indexed::new(message.clone()), meta.clone())])
+                    .await
+                    .unwrap();
+                let insertion = MerkleTreeInsertion::new(i, message.id());
+                db.store_logs(&[(Indexed::new(insertion), meta)])
+                    .await
+                    .unwrap();
+            }
+
+            let reconciliation = reconciler(&db, &[13, 14]).reconcile().await.unwrap();
+            assert_eq!(reconciliation.reorged_from_block, Some(13));
+            assert_eq!(reconciliation.pruned_message_nonces, vec![4, 3]);
+            assert_eq!(reconciliation.pruned_merkle_leaf_indices, vec![4, 3]);
+
+            assert!(db.retrieve_message_by_nonce(3).unwrap().is_none());
+            assert!(db.retrieve_message_by_nonce(2).unwrap().is_some());
+            assert_eq!(db.retrieve_highest_seen_message_nonce().unwrap(), Some(2));
+            assert!(db
+                .retrieve_merkle_tree_insertion_by_leaf_index(&3)
+                .unwrap()
+                .is_none());
+            assert_eq!(db.retrieve_highest_seen_merkle_leaf_index().unwrap(), Some(2));
+
+            let rewind = HyperlaneSequenceAwareIndexerStoreReader::<HyperlaneMessage>::retrieve_reorg_rewind(&db)
+                .await
+                .unwrap();
+            assert_eq!(rewind, Some(ReorgRewind::new(1, 3, 12)));
+
+            // the chain is canonical now, so nothing else is pruned
+            let reconciliation = reconciler(&db, &[]).reconcile().await.unwrap();
+            assert_eq!(reconciliation, ReorgReconciliation::default());
+        })
+        .await;
+    }
+
+    /// Store a message indexed from each of `blocks`, with nonces from 0
+    async fn store_messages(db: &HyperlaneRocksDB, blocks: impl IntoIterator<Item = LogMeta>) {
+        for (nonce, meta) in (0u32..).zip(blocks) {
+            let message = HyperlaneMessage {
+                nonce,
+                ..Default::default()
+            };
+            db.store_logs(&[(Indexed::new(message), meta)])
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stops_at_logs_without_block_hash() {
+        run_test_db(|db| async move {
+            let db = HyperlaneRocksDB::new(&HyperlaneDomain::new_test_domain("reorg_zero"), db);
+            let metas = (10u64..15).map(|block_number| LogMeta {
+                block_hash: H256::zero(),
+                ..log_meta(block_number, 0)
+            });
+            store_messages(&db, metas).await;
+
+            // a zero hash never matches the canonical one, but isn't a reorg
+            let reconciliation = reconciler(&db, &[10, 11, 12, 13, 14])
+                .reconcile()
+                .await
+                .unwrap();
+            assert_eq!(reconciliation, ReorgReconciliation::default());
+            assert_eq!(db.retrieve_highest_seen_message_nonce().unwrap(), Some(4));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_walk_back_is_capped_at_max_depth() {
+        run_test_db(|db| async move {
+            let db = HyperlaneRocksDB::new(&HyperlaneDomain::new_test_domain("reorg_depth"), db);
+            store_messages(
+                &db,
+                (10u64..15).map(|block_number| log_meta(block_number, 0)),
+            )
+            .await;
+
+            let reconciliation = reconciler_with_depth(&db, &[10, 11, 12, 13, 14], 2)
+                .reconcile()
+                .await
+                .unwrap();
+            assert_eq!(reconciliation.reorged_from_block, Some(12));
+            assert_eq!(reconciliation.pruned_message_nonces, vec![4, 3, 2]);
+            assert_eq!(db.retrieve_highest_seen_message_nonce().unwrap(), Some(1));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_reverts_gas_payments_from_reorged_blocks() {
+        run_test_db(|db| async move {
+            let db = HyperlaneRocksDB::new(&HyperlaneDomain::new_test_domain("reorg_gas"), db);
+            let payment = InterchainGasPayment {
+                message_id: H256::from_low_u64_be(1),
+                destination: 2,
+                payment: U256::from(100),
+                gas_amount: U256::from(10),
+            };
+            db.store_logs(&[
+                (Indexed::new(payment), log_meta(20, 0)),
+                (Indexed::new(payment), log_meta(21, 0)),
+            ])
+            .await
+            .unwrap();
+            HyperlaneWatermarkedLogStore::<InterchainGasPayment>::store_high_watermark(&db, 30)
+                .await
+                .unwrap();
+
+            let reconciliation = reconciler(&db, &[21]).reconcile().await.unwrap();
+            assert_eq!(reconciliation.reorged_from_block, Some(21));
+            assert_eq!(
+                reconciliation.reverted_gas_payments,
+                vec![payment.message_id]
+            );
+
+            let total = db
+                .retrieve_gas_payment_by_gas_payment_key(payment.into())
+                .unwrap()
+                .unwrap();
+            assert_eq!(total.payment, U256::from(100));
+            assert_eq!(total.gas_amount, U256::from(10));
+            assert_eq!(
+                HyperlaneWatermarkedLogStore::<InterchainGasPayment>::retrieve_high_watermark(&db)
+                    .await
+                    .unwrap(),
+                Some(20)
+            );
+
+            // the payment is processed again if it's included in the canonical chain
+            let reindexed = LogMeta {
+                block_hash: H256::repeat_byte(0xff),
+                ..log_meta(21, 0)
+            };
+            assert!(db
+                .process_indexed_gas_payment(Indexed::new(payment), &reindexed)
+                .unwrap());
+        })
+        .await;
+    }
+}
@@ -1,8 +1,8 @@
 use std::io::{Read, Write};
 
 use hyperlane_core::{
-    Decode, Encode, HyperlaneProtocolError, InterchainGasExpenditure, InterchainGasPayment, H256,
-    U256,
+    Decode, Encode, HyperlaneProtocolError, Indexed, InterchainGasExpenditure,
+    InterchainGasPayment, InterchainGasPaymentMeta, H256, U256,
 };
 
 /// Subset of `InterchainGasPayment` excluding the message id which is stored in
@@ -15,6 +15,18 @@ pub struct InterchainGasPaymentData {
     pub gas_amount: U256,
 }
 
+/// A processed gas payment and the log it was indexed from, kept so the
+/// payment can be reverted if its block is reorged.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedGasPaymentData {
+    /// The gas payment, with its sequence if it has one
+    pub payment: Indexed<InterchainGasPayment>,
+    /// The log the payment was indexed from
+    pub meta: InterchainGasPaymentMeta,
+    /// Hash of the block the payment was indexed from
+    pub block_hash: H256,
+}
+
 /// Subset of `InterchainGasExpenditure` excluding the message id which is
 /// stored in the key.
 #[allow(missing_docs)]
@@ -134,3 +146,31 @@ impl Decode for InterchainGasExpenditureData {
         })
     }
 }
+
+impl Encode for IndexedGasPaymentData {
+    fn write_to<W>(&self, writer: &mut W) -> std::io::Result<usize>
+    where
+        W: Write,
+    {
+        let written = self
+            .payment
+            .write_to(writer)?
+            .saturating_add(self.meta.write_to(writer)?)
+            .saturating_add(self.block_hash.write_to(writer)?);
+        Ok(written)
+    }
+}
+
+impl Decode for IndexedGasPaymentData {
+    fn read_from<R>(reader: &mut R) -> Result<Self, HyperlaneProtocolError>
+    where
+        R: Read,
+        Self: Sized,
+    {
+        Ok(Self {
+            payment: Indexed::<InterchainGasPayment>::read_from(reader)?,
+            meta: InterchainGasPaymentMeta::read_from(reader)?,
+            block_hash: H256::read_from(reader)?,
+        })
+    }
+}
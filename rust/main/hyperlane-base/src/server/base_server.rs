@@ -1,18 +1,30 @@
 use axum::{http::StatusCode, response::IntoResponse, routing::get, Router};
 use derive_new::new;
+use eyre::Result;
 use std::sync::Arc;
 use tokio::task::JoinHandle;
+use tokio_rustls::rustls::ServerConfig;
 
 use crate::CoreMetrics;
 
+use super::tls::{PeerInfo, ServerTlsConf, TlsListener};
+
 /// A server that serves agent-specific routes
 #[derive(new, Debug)]
 pub struct Server {
     listen_port: u16,
     core_metrics: Arc<CoreMetrics>,
+    #[new(default)]
+    tls: Option<Arc<ServerConfig>>,
 }
 
 impl Server {
+    /// Serve over TLS instead of plain HTTP. Fails if the certificates can't be loaded.
+    pub fn with_tls(mut self, tls: &ServerTlsConf) -> Result<Self> {
+        self.tls = Some(tls.load()?);
+        Ok(self)
+    }
+
     /// Run an HTTP server
     pub fn run(self: Arc<Self>) -> JoinHandle<()> {
         self.run_with_custom_router(Router::new())
@@ -24,9 +36,11 @@ impl Server {
     ///  - metrics - serving OpenMetrics format reports on `/metrics`
     ///    (this is compatible with Prometheus, which ought to be configured to scrape this endpoint)
     ///  - custom_routes - additional routes to be served by the server as per the specific agent
+    ///
+    /// Routes can extract `ConnectInfo<PeerInfo>` to learn about the client.
     pub fn run_with_custom_router(self: Arc<Self>, router: Router) -> JoinHandle<()> {
         let port = self.listen_port;
-        tracing::info!(port, "starting server on 0.0.0.0");
+        tracing::info!(port, tls = self.tls.is_some(), "starting server on 0.0.0.0");
 
         let core_metrics_clone = self.core_metrics.clone();
 
@@ -35,7 +49,9 @@ impl Server {
                 "/metrics",
                 get(move || Self::gather_metrics(core_metrics_clone)),
             )
-            .merge(router);
+            .merge(router)
+            .into_make_service_with_connect_info::<PeerInfo>();
+        let tls = self.tls.clone();
 
         tokio::task::Builder::new()
             .name("agent::server")
@@ -44,9 +60,14 @@ impl Server {
                 let listener = tokio::net::TcpListener::bind(url)
                     .await
                     .expect("Failed to bind to TCP port");
-                axum::serve(listener, app)
-                    .await
-                    .expect("Failed to start server");
+                match tls {
+                    Some(config) => axum::serve(TlsListener::new(listener, config), app)
+                        .await
+                        .expect("Failed to start server"),
+                    None => axum::serve(listener, app)
+                        .await
+                        .expect("Failed to start server"),
+                }
             })
             .expect("spawning tokio task from Builder is infallible")
     }
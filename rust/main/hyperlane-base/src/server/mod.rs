@@ -1,4 +1,5 @@
 mod base_server;
+mod tls;
 
 /// merkle tree insertion functions
 pub mod merkle_tree_insertions;
@@ -8,3 +9,4 @@ pub mod messages;
 pub mod utils;
 
 pub use base_server::Server;
+pub use tls::{certificate_fingerprint, PeerInfo, ServerTlsConf, TlsListener};
@@ -0,0 +1,237 @@
+use std::{
+    fs::File,
+    io::{self, BufReader},
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use axum::{extract::connect_info::Connected, serve::IncomingStream, serve::Listener};
+use ethers::utils::hex;
+use eyre::{eyre, Context, Result};
+use sha2::{Digest, Sha256};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    task::JoinSet,
+};
+use tokio_rustls::{
+    rustls::{
+        pki_types::{CertificateDer, PrivateKeyDer},
+        server::WebPkiClientVerifier,
+        RootCertStore, ServerConfig,
+    },
+    server::TlsStream,
+    TlsAcceptor,
+};
+use tracing::debug;
+
+/// How long a client may take to complete the TLS handshake
+const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Connections are no longer accepted while this many handshakes are in flight
+const MAX_CONCURRENT_TLS_HANDSHAKES: usize = 1024;
+
+/// TLS configuration for the agent server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerTlsConf {
+    /// PEM file with the server certificate chain
+    pub cert_path: PathBuf,
+    /// PEM file with the server private key
+    pub key_path: PathBuf,
+    /// PEM file with the CAs client certificates are verified against.
+    /// If set, clients may authenticate with a certificate. Presenting one is
+    /// optional at the TLS level, it's up to the routes to require it.
+    pub client_ca_path: Option<PathBuf>,
+}
+
+impl ServerTlsConf {
+    /// Load the certificates and keys into a rustls server config
+    pub fn load(&self) -> Result<Arc<ServerConfig>> {
+        let certs = load_certs(&self.cert_path)?;
+        let key = load_key(&self.key_path)?;
+
+        let builder = match &self.client_ca_path {
+            Some(client_ca_path) => {
+                let mut roots = RootCertStore::empty();
+                for cert in load_certs(client_ca_path)? {
+                    roots.add(cert).with_context(|| {
+                        format!("Invalid client CA certificate in {client_ca_path:?}")
+                    })?;
+                }
+                let verifier = WebPkiClientVerifier::builder(Arc::new(roots))
+                    .allow_unauthenticated()
+                    .build()
+                    .context("Failed to build client certificate verifier")?;
+                ServerConfig::builder().with_client_cert_verifier(verifier)
+            }
+            None => ServerConfig::builder().with_no_client_auth(),
+        };
+
+        let mut config = builder
+            .with_single_cert(certs, key)
+            .context("Invalid server certificate or key")?;
+        config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+        Ok(Arc::new(config))
+    }
+}
+
+fn load_certs(path: &Path) -> Result<Vec<CertificateDer<'static>>> {
+    let mut reader = BufReader::new(
+        File::open(path).with_context(|| format!("Failed to open certificate file {path:?}"))?,
+    );
+    let certs = rustls_pemfile::certs(&mut reader)
+        .collect::<io::Result<Vec<_>>>()
+        .with_context(|| format!("Failed to parse certificates in {path:?}"))?;
+    if certs.is_empty() {
+        return Err(eyre!("No certificates found in {path:?}"));
+    }
+    Ok(certs)
+}
+
+fn load_key(path: &Path) -> Result<PrivateKeyDer<'static>> {
+    let mut reader = BufReader::new(
+        File::open(path).with_context(|| format!("Failed to open key file {path:?}"))?,
+    );
+    rustls_pemfile::private_key(&mut reader)
+        .with_context(|| format!("Failed to parse private key in {path:?}"))?
+        .ok_or_else(|| eyre!("No private key found in {path:?}"))
+}
+
+/// Hex encoded SHA-256 fingerprint of a DER encoded certificate, the same
+/// as `openssl x509 -fingerprint -sha256` prints without the colons.
+pub fn certificate_fingerprint(cert: &[u8]) -> String {
+    hex::encode(Sha256::digest(cert))
+}
+
+/// A listener that terminates TLS on accepted TCP connections. Each handshake
+/// runs in a task of its own, so a client that stalls it doesn't hold up the
+/// other connections.
+pub struct TlsListener {
+    tcp: TcpListener,
+    acceptor: TlsAcceptor,
+    handshakes: JoinSet<Option<(TlsStream<TcpStream>, SocketAddr)>>,
+}
+
+impl TlsListener {
+    /// Wrap a TCP listener
+    pub fn new(tcp: TcpListener, config: Arc<ServerConfig>) -> Self {
+        Self {
+            tcp,
+            acceptor: TlsAcceptor::from(config),
+            handshakes: JoinSet::new(),
+        }
+    }
+}
+
+async fn handshake(
+    acceptor: TlsAcceptor,
+    stream: TcpStream,
+    addr: SocketAddr,
+) -> Option<(TlsStream<TcpStream>, SocketAddr)> {
+    match tokio::time::timeout(TLS_HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+        Ok(Ok(stream)) => Some((stream, addr)),
+        Ok(Err(err)) => {
+            debug!(?err, %addr, "TLS handshake failed");
+            None
+        }
+        Err(_) => {
+            debug!(%addr, "TLS handshake timed out");
+            None
+        }
+    }
+}
+
+impl Listener for TlsListener {
+    type Io = TlsStream<TcpStream>;
+    type Addr = SocketAddr;
+
+    async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+        loop {
+            tokio::select! {
+                (stream, addr) = Listener::accept(&mut self.tcp),
+                    if self.handshakes.len() < MAX_CONCURRENT_TLS_HANDSHAKES =>
+                {
+                    self.handshakes
+                        .spawn(handshake(self.acceptor.clone(), stream, addr));
+                }
+                Some(result) = self.handshakes.join_next() => {
+                    match result {
+                        Ok(Some(connection)) => return connection,
+                        Ok(None) => {}
+                        Err(err) => debug!(?err, "TLS handshake task failed"),
+                    }
+                }
+            }
+        }
+    }
+
+    fn local_addr(&self) -> io::Result<Self::Addr> {
+        self.tcp.local_addr()
+    }
+}
+
+/// Information about the peer of a server connection, available to
+/// routes through the `ConnectInfo` extractor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeerInfo {
+    /// The address of the peer
+    pub remote_addr: SocketAddr,
+    /// Fingerprint of the verified client certificate, if the peer presented one
+    pub client_cert_fingerprint: Option<String>,
+}
+
+impl Connected<IncomingStream<'_, TcpListener>> for PeerInfo {
+    fn connect_info(stream: IncomingStream<'_, TcpListener>) -> Self {
+        Self {
+            remote_addr: *stream.remote_addr(),
+            client_cert_fingerprint: None,
+        }
+    }
+}
+
+impl Connected<IncomingStream<'_, TlsListener>> for PeerInfo {
+    fn connect_info(stream: IncomingStream<'_, TlsListener>) -> Self {
+        let (_, connection) = stream.io().get_ref();
+        Self {
+            remote_addr: *stream.remote_addr(),
+            client_cert_fingerprint: connection
+                .peer_certificates()
+                .and_then(|certs| certs.first())
+                .map(|cert| certificate_fingerprint(cert)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_certificate_fingerprint() {
+        assert_eq!(
+            certificate_fingerprint(b"hello"),
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+    }
+
+    #[test]
+    fn test_missing_files() {
+        let conf = ServerTlsConf {
+            cert_path: "/nonexistent/cert.pem".into(),
+            key_path: "/nonexistent/key.pem".into(),
+            client_ca_path: None,
+        };
+        assert!(conf.load().is_err());
+
+        let dir = tempfile::tempdir().unwrap();
+        let empty = dir.path().join("empty.pem");
+        std::fs::write(&empty, "").unwrap();
+        let conf = ServerTlsConf {
+            cert_path: empty.clone(),
+            key_path: empty,
+            client_ca_path: None,
+        };
+        assert!(conf.load().is_err());
+    }
+}
@@ -14,7 +14,7 @@ use hyperlane_core::{
     HyperlaneAbi, HyperlaneDomain, HyperlaneDomainProtocol, HyperlaneMessage, HyperlaneProvider,
     IndexMode, InterchainGasPaymaster, InterchainGasPayment, InterchainSecurityModule, Mailbox,
     MerkleTreeHook, MerkleTreeInsertion, MultisigIsm, NativeToken, ReorgPeriod, RoutingIsm,
-    SequenceAwareIndexer, SubmitterType, ValidatorAnnounce, H256,
+    SequenceAwareIndexer, SubmitterType, ValidatorAnnounce, ValidatorAnnouncement, H256,
 };
 use hyperlane_metric::prometheus_metric::ChainInfo;
 use hyperlane_operation_verifier::ApplicationOperationVerifier;
@@ -75,6 +75,9 @@ pub struct ChainConf {
     pub domain: HyperlaneDomain,
     /// Signer configuration for this chain
     pub signer: Option<SignerConf>,
+    /// Additional signers which may submit transactions in parallel with
+    /// `signer`. Only used by the EVM lander adapter.
+    pub signer_pool: Vec<SignerConf>,
     /// Submitter type for this chain
     pub submitter: SubmitterType,
     /// The estimated block time, i.e. the average time the next block is added to the chain
@@ -114,6 +117,9 @@ pub type IgpIndexer = Arc<dyn SequenceAwareIndexer<InterchainGasPayment>>;
 /// A sequence-aware indexer for merkle tree hooks
 pub type MerkleTreeHookIndexer = Arc<dyn SequenceAwareIndexer<MerkleTreeInsertion>>;
 
+/// A sequence-aware indexer for validator announcements
+pub type ValidatorAnnouncementIndexer = Arc<dyn SequenceAwareIndexer<ValidatorAnnouncement>>;
+
 #[async_trait]
 impl TryFromWithMetrics<ChainConf> for MessageIndexer {
     async fn try_from_with_metrics(
@@ -166,6 +172,19 @@ impl TryFromWithMetrics<ChainConf> for MerkleTreeHookIndexer {
     }
 }
 
+#[async_trait]
+impl TryFromWithMetrics<ChainConf> for ValidatorAnnouncementIndexer {
+    async fn try_from_with_metrics(
+        conf: &ChainConf,
+        metrics: &CoreMetrics,
+        _advanced_log_meta: bool,
+    ) -> Result<Self> {
+        conf.build_validator_announcement_indexer(metrics)
+            .await
+            .map(Into::into)
+    }
+}
+
 /// A connection to _some_ blockchain.
 #[derive(Clone, Debug)]
 // TODO: re-enable this clippy check once the new submitter is shipped,
@@ -269,7 +288,10 @@ impl ChainConf {
                 h_eth::application::EthereumApplicationOperationVerifier::new(),
             )
                 as Box<dyn ApplicationOperationVerifier>),
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(_) => Ok(Box::new(
+                h_fuel::application::FuelApplicationOperationVerifier::new(),
+            )
+                as Box<dyn ApplicationOperationVerifier>),
             ChainConnectionConf::Sealevel(conf) => {
                 let provider =
                     Arc::new(build_sealevel_provider(self, &locator, &[], conf, metrics));
@@ -315,7 +337,10 @@ impl ChainConf {
                 self.build_ethereum(conf, &locator, metrics, h_eth::HyperlaneProviderBuilder {})
                     .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let provider = h_fuel::FuelProvider::new(self.domain.clone(), conf).await;
+                Ok(Box::new(provider) as Box<dyn HyperlaneProvider>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let provider = build_sealevel_provider(
                     self,
@@ -452,8 +477,12 @@ impl ChainConf {
                 self.build_ethereum(conf, &locator, metrics, h_eth::MerkleTreeHookBuilder {})
                     .await
             }
-            ChainConnectionConf::Fuel(_conf) => {
-                todo!("Fuel does not support merkle tree hooks yet")
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_reader_wallet().await.context(ctx)?;
+                h_fuel::FuelMerkleTreeHook::new(conf, locator, wallet)
+                    .await
+                    .map(|m| Box::new(m) as Box<dyn MerkleTreeHook>)
+                    .map_err(Into::into)
             }
             ChainConnectionConf::Sealevel(conf) => {
                 let provider =
@@ -528,7 +557,12 @@ impl ChainConf {
                 )
                 .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_reader_wallet().await.context(ctx)?;
+                let indexer =
+                    Box::new(h_fuel::FuelMailboxIndexer::new(conf, locator, wallet).await?);
+                Ok(indexer as Box<dyn SequenceAwareIndexer<HyperlaneMessage>>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let provider =
                     Arc::new(build_sealevel_provider(self, &locator, &[], conf, metrics));
@@ -617,7 +651,12 @@ impl ChainConf {
                 )
                 .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_reader_wallet().await.context(ctx)?;
+                let indexer =
+                    Box::new(h_fuel::FuelMailboxIndexer::new(conf, locator, wallet).await?);
+                Ok(indexer as Box<dyn SequenceAwareIndexer<H256>>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let provider =
                     Arc::new(build_sealevel_provider(self, &locator, &[], conf, metrics));
@@ -700,7 +739,12 @@ impl ChainConf {
                 )
                 .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_reader_wallet().await.context(ctx)?;
+                let paymaster =
+                    Box::new(h_fuel::FuelInterchainGasPaymaster::new(conf, locator, wallet).await?);
+                Ok(paymaster as Box<dyn InterchainGasPaymaster>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let provider =
                     Arc::new(build_sealevel_provider(self, &locator, &[], conf, metrics));
@@ -782,7 +826,13 @@ impl ChainConf {
                 )
                 .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_reader_wallet().await.context(ctx)?;
+                let indexer = Box::new(
+                    h_fuel::FuelInterchainGasPaymasterIndexer::new(conf, locator, wallet).await?,
+                );
+                Ok(indexer as Box<dyn SequenceAwareIndexer<InterchainGasPayment>>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let provider =
                     Arc::new(build_sealevel_provider(self, &locator, &[], conf, metrics));
@@ -859,7 +909,12 @@ impl ChainConf {
                 )
                 .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_reader_wallet().await.context(ctx)?;
+                let indexer =
+                    Box::new(h_fuel::FuelMerkleTreeHookIndexer::new(conf, locator, wallet).await?);
+                Ok(indexer as Box<dyn SequenceAwareIndexer<MerkleTreeInsertion>>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let provider =
                     Arc::new(build_sealevel_provider(self, &locator, &[], conf, metrics));
@@ -926,6 +981,60 @@ impl ChainConf {
         .context(ctx)
     }
 
+    /// Try to convert the chain settings into a validator announcement indexer
+    pub async fn build_validator_announcement_indexer(
+        &self,
+        metrics: &CoreMetrics,
+    ) -> Result<Box<dyn SequenceAwareIndexer<ValidatorAnnouncement>>> {
+        let ctx = "Building validator announcement indexer";
+        let locator = self.locator(self.addresses.validator_announce);
+
+        match &self.connection {
+            ChainConnectionConf::Ethereum(conf) => {
+                let reorg_period =
+                    EthereumReorgPeriod::try_from(&self.reorg_period).context(ctx)?;
+                self.build_ethereum(
+                    conf,
+                    &locator,
+                    metrics,
+                    h_eth::ValidatorAnnouncementIndexerBuilder { reorg_period },
+                )
+                .await
+            }
+            ChainConnectionConf::Fuel(_) => Err(eyre!(
+                "Fuel does not support validator announcement indexing yet"
+            ))
+            .context(ctx),
+            ChainConnectionConf::Sealevel(_) => Err(eyre!(
+                "Sealevel does not support validator announcement indexing yet"
+            ))
+            .context(ctx),
+            ChainConnectionConf::Cosmos(_) => Err(eyre!(
+                "Cosmos does not support validator announcement indexing yet"
+            ))
+            .context(ctx),
+            ChainConnectionConf::Starknet(_) => Err(eyre!(
+                "Starknet does not support validator announcement indexing yet"
+            ))
+            .context(ctx),
+            ChainConnectionConf::CosmosNative(_) => Err(eyre!(
+                "Cosmos Native does not support validator announcement indexing yet"
+            ))
+            .context(ctx),
+            ChainConnectionConf::Radix(_) => Err(eyre!(
+                "Radix does not support validator announcement indexing yet"
+            ))
+            .context(ctx),
+            ChainConnectionConf::Tron(_) => Err(eyre!(
+                "Tron does not support validator announcement indexing yet"
+            ))
+            .context(ctx),
+            #[cfg(feature = "aleo")]
+            ChainConnectionConf::Aleo(_) => Err(eyre!("Aleo support missing")).context(ctx),
+        }
+        .context(ctx)
+    }
+
     /// Try to convert the chain settings into a ValidatorAnnounce
     pub async fn build_validator_announce(
         &self,
@@ -938,7 +1047,11 @@ impl ChainConf {
                 self.build_ethereum(conf, &locator, metrics, h_eth::ValidatorAnnounceBuilder {})
                     .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let signer = self.signer().await.context(ctx)?;
+                let va = Box::new(h_fuel::FuelValidatorAnnounce::new(conf, locator, signer).await?);
+                Ok(va as Box<dyn ValidatorAnnounce>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let signer = self.sealevel_signer().await.context(ctx)?;
                 let provider =
@@ -1034,7 +1147,13 @@ impl ChainConf {
                 )
                 .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_reader_wallet().await.context(ctx)?;
+                let ism = Box::new(
+                    h_fuel::FuelInterchainSecurityModule::new(conf, locator, wallet).await?,
+                );
+                Ok(ism as Box<dyn InterchainSecurityModule>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let keypair = self.sealevel_signer().await.context(ctx)?;
                 let provider =
@@ -1102,7 +1221,11 @@ impl ChainConf {
                 self.build_ethereum(conf, &locator, metrics, h_eth::MultisigIsmBuilder {})
                     .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_reader_wallet().await.context(ctx)?;
+                let ism = Box::new(h_fuel::FuelMultisigIsm::new(conf, locator, wallet).await?);
+                Ok(ism as Box<dyn MultisigIsm>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let keypair = self.sealevel_signer().await.context(ctx)?;
                 let provider =
@@ -1170,9 +1293,21 @@ impl ChainConf {
                 self.build_ethereum(conf, &locator, metrics, h_eth::RoutingIsmBuilder {})
                     .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
-            ChainConnectionConf::Sealevel(_) => {
-                Err(eyre!("Sealevel does not support routing ISM yet")).context(ctx)
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_reader_wallet().await.context(ctx)?;
+                let ism = Box::new(h_fuel::FuelRoutingIsm::new(conf, locator, wallet).await?);
+                Ok(ism as Box<dyn RoutingIsm>)
+            }
+            ChainConnectionConf::Sealevel(conf) => {
+                let keypair = self.sealevel_signer().await.context(ctx)?;
+                let provider =
+                    Arc::new(build_sealevel_provider(self, &locator, &[], conf, metrics));
+                let ism = Box::new(h_sealevel::SealevelRoutingIsm::new(
+                    provider,
+                    locator,
+                    keypair.map(h_sealevel::SealevelKeypair::new),
+                ));
+                Ok(ism as Box<dyn RoutingIsm>)
             }
             ChainConnectionConf::Cosmos(conf) => {
                 let signer = self.cosmos_signer().await.context(ctx)?;
@@ -1230,9 +1365,21 @@ impl ChainConf {
                 self.build_ethereum(conf, &locator, metrics, h_eth::AggregationIsmBuilder {})
                     .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
-            ChainConnectionConf::Sealevel(_) => {
-                Err(eyre!("Sealevel does not support aggregation ISM yet")).context(ctx)
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_reader_wallet().await.context(ctx)?;
+                let ism = Box::new(h_fuel::FuelAggregationIsm::new(conf, locator, wallet).await?);
+                Ok(ism as Box<dyn AggregationIsm>)
+            }
+            ChainConnectionConf::Sealevel(conf) => {
+                let keypair = self.sealevel_signer().await.context(ctx)?;
+                let provider =
+                    Arc::new(build_sealevel_provider(self, &locator, &[], conf, metrics));
+                let ism = Box::new(h_sealevel::SealevelAggregationIsm::new(
+                    provider,
+                    locator,
+                    keypair.map(h_sealevel::SealevelKeypair::new),
+                ));
+                Ok(ism as Box<dyn AggregationIsm>)
             }
             ChainConnectionConf::Cosmos(conf) => {
                 let signer = self.cosmos_signer().await.context(ctx)?;
@@ -1255,8 +1402,10 @@ impl ChainConf {
             ChainConnectionConf::CosmosNative(_) => {
                 Err(eyre!("Cosmos Native does not support aggregation ISM yet")).context(ctx)
             }
-            ChainConnectionConf::Radix(_) => {
-                todo!("Radix aggregation ISM not yet implemented")
+            ChainConnectionConf::Radix(conf) => {
+                let provider = build_radix_provider(self, conf, metrics, &locator, None)?;
+                let ism = h_radix::RadixIsm::new(provider, &locator, conf)?;
+                Ok(Box::new(ism) as Box<dyn AggregationIsm>)
             }
             ChainConnectionConf::Tron(conf) => {
                 let provider = build_tron_provider(self, conf, metrics, &locator, None)?;
@@ -1286,7 +1435,9 @@ impl ChainConf {
                 self.build_ethereum(conf, &locator, metrics, h_eth::CcipReadIsmBuilder {})
                     .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(_) => {
+                Err(eyre!("Fuel does not support CCIP read ISM yet")).context(ctx)
+            }
             ChainConnectionConf::Sealevel(_) => {
                 Err(eyre!("Sealevel does not support CCIP read ISM yet")).context(ctx)
             }
@@ -1361,6 +1512,17 @@ impl ChainConf {
         })
     }
 
+    /// Fuel contract instances need an account even for read-only calls, so
+    /// fall back to a throwaway wallet when no signer is configured. Only use
+    /// this for contracts the agents never send transactions to; write paths
+    /// must go through `fuel_signer`.
+    async fn fuel_reader_wallet(&self) -> Result<fuels::prelude::WalletUnlocked> {
+        Ok(self
+            .signer()
+            .await?
+            .unwrap_or_else(|| fuels::prelude::WalletUnlocked::new_random(None)))
+    }
+
     async fn sealevel_signer(&self) -> Result<Option<h_sealevel::Keypair>> {
         self.signer().await
     }
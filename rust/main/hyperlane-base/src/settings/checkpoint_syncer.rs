@@ -5,13 +5,14 @@ use core::str::FromStr;
 use eyre::{eyre, Report, Result};
 use prometheus::IntGauge;
 use tracing::error;
+use url::Url;
 use ya_gcp::{AuthFlow, ServiceAccountAuth};
 
 use hyperlane_core::{ChainCommunicationError, ReorgEventResponse};
 
 use crate::{
-    CheckpointSyncer, GcsStorageClientBuilder, LocalStorage, S3Storage, GCS_SERVICE_ACCOUNT_KEY,
-    GCS_USER_SECRET,
+    AzureBlobAuth, AzureBlobStorage, CheckpointSyncer, GcsStorageClientBuilder, HttpStorage,
+    LocalStorage, S3Storage, GCS_SERVICE_ACCOUNT_KEY, GCS_USER_SECRET,
 };
 
 /// Checkpoint Syncer types
@@ -43,6 +44,26 @@ pub enum CheckpointSyncerConf {
         /// `gcloud auth application-default login`
         user_secrets: Option<String>,
     },
+    /// A checkpoint syncer on Azure Blob Storage
+    Azure {
+        /// Storage account name
+        account: String,
+        /// Container name
+        container: String,
+        /// Folder name inside container - defaults to the root of the container
+        folder: Option<String>,
+        /// Blob service endpoint - defaults to `https://{account}.blob.core.windows.net`
+        endpoint: Option<Url>,
+        /// Shared access signature used for authentication
+        sas_token: Option<String>,
+        /// Base64 encoded account access key used for authentication
+        account_key: Option<String>,
+    },
+    /// A read-only checkpoint syncer served by any HTTP(S) server or CDN
+    Http {
+        /// Base url of the checkpoints
+        url: Url,
+    },
 }
 
 /// Checkpoint Syncer errors
@@ -111,6 +132,28 @@ impl FromStr for CheckpointSyncerConf {
                     }),
                 }
             }
+            // storage locations are announced by other validators, so they are
+            // read anonymously from the default endpoint of their account,
+            // rather than with the credentials of this agent
+            "azure" => {
+                let url_components = suffix.split('/').collect::<Vec<&str>>();
+                let (account, container, folder): (&str, &str, Option<String>) = match url_components.len() {
+                    2 => Ok((url_components[0], url_components[1], None)),
+                    3 .. => Ok((url_components[0], url_components[1], Some(url_components[2..].join("/")))),
+                    _ => Err(eyre!("Error parsing storage location; could not split account, container and folder ({suffix})"))
+                }?;
+                Ok(CheckpointSyncerConf::Azure {
+                    account: account.into(),
+                    container: container.into(),
+                    folder,
+                    endpoint: None,
+                    sas_token: None,
+                    account_key: None,
+                })
+            }
+            "http" | "https" => Ok(CheckpointSyncerConf::Http {
+                url: Url::parse(s)?,
+            }),
             _ => Err(eyre!("Unknown storage location prefix `{prefix}`")),
         }
     }
@@ -180,6 +223,35 @@ impl CheckpointSyncerConf {
                         .await?,
                 )
             }
+            CheckpointSyncerConf::Azure {
+                account,
+                container,
+                folder,
+                endpoint,
+                sas_token,
+                account_key,
+            } => {
+                let auth = if let Some(sas_token) = sas_token {
+                    AzureBlobAuth::SasToken(sas_token.trim_start_matches('?').to_owned())
+                } else if let Some(account_key) = account_key {
+                    AzureBlobAuth::SharedKey(account_key.clone())
+                } else {
+                    // Public data access only - no writes
+                    AzureBlobAuth::Anonymous
+                };
+
+                Box::new(AzureBlobStorage::new(
+                    account.clone(),
+                    container.clone(),
+                    folder.clone(),
+                    endpoint.clone(),
+                    auth,
+                    latest_index_gauge,
+                )?)
+            }
+            CheckpointSyncerConf::Http { url } => {
+                Box::new(HttpStorage::new(url.clone(), latest_index_gauge)?)
+            }
         })
     }
 }
@@ -300,4 +372,48 @@ mod test {
             _ => panic!("Expected S3 checkpoint syncer"),
         }
     }
+
+    #[test]
+    fn test_parse_azure_storage_location() {
+        use super::*;
+        let conf =
+            CheckpointSyncerConf::from_str("azure://myaccount/mycontainer/folder/nested").unwrap();
+        match conf {
+            CheckpointSyncerConf::Azure {
+                account,
+                container,
+                folder,
+                endpoint,
+                sas_token,
+                account_key,
+            } => {
+                assert_eq!(account, "myaccount");
+                assert_eq!(container, "mycontainer");
+                assert_eq!(folder.as_deref(), Some("folder/nested"));
+                // never the credentials or endpoint of this agent
+                assert_eq!(endpoint, None);
+                assert_eq!(sas_token, None);
+                assert_eq!(account_key, None);
+            }
+            _ => panic!("Expected Azure checkpoint syncer"),
+        }
+
+        assert!(CheckpointSyncerConf::from_str("azure://myaccount").is_err());
+    }
+
+    #[test]
+    fn test_parse_http_storage_location() {
+        use super::*;
+        let conf = CheckpointSyncerConf::from_str("https://cdn.example.com/validator/checkpoints")
+            .unwrap();
+        match conf {
+            CheckpointSyncerConf::Http { url } => {
+                assert_eq!(
+                    url.as_str(),
+                    "https://cdn.example.com/validator/checkpoints"
+                );
+            }
+            _ => panic!("Expected HTTP checkpoint syncer"),
+        }
+    }
 }
@@ -3,7 +3,7 @@ use std::{ops::Add, str::FromStr};
 use eyre::eyre;
 use hyperlane_sealevel::{
     HeliusPriorityFeeLevel, HeliusPriorityFeeOracleConfig, PriorityFeeOracleConfig,
-    ProcessAltOverride,
+    ProcessAltOverride, RpcPriorityFeeOracleConfig,
 };
 use solana_sdk::pubkey::Pubkey;
 use url::Url;
@@ -129,14 +129,70 @@ pub fn build_ethereum_connection_conf(
         .parse_bool()
         .unwrap_or(false);
 
+    let log_subscription_url = chain
+        .chain(err)
+        .get_opt_key("index")
+        .get_opt_key("subscriptionUrl")
+        .parse_from_str("Invalid subscriptionUrl")
+        .end();
+
+    let private_submission = parse_private_submission_conf(chain, err);
+
     Some(ChainConnectionConf::Ethereum(h_eth::ConnectionConf {
         rpc_connection: rpc_connection_conf?,
         transaction_overrides,
         op_submission_config: operation_batch,
         consider_null_transaction_receipt,
+        log_subscription_url,
+        private_submission,
     }))
 }
 
+fn parse_private_submission_conf(
+    chain: &ValueParser,
+    err: &mut ConfigParsingError,
+) -> Option<h_eth::PrivateSubmissionConf> {
+    let private_submission = chain
+        .get_opt_key("privateSubmission")
+        .take_err(err, || (&chain.cwp).add("private_submission"))
+        .flatten()?;
+
+    let url = private_submission
+        .chain(err)
+        .get_key("url")
+        .parse_from_str("Invalid privateSubmission url")
+        .end();
+
+    let method = private_submission
+        .chain(err)
+        .get_opt_key("method")
+        .parse_string()
+        .end();
+    let method = match method.map(str::to_lowercase).as_deref() {
+        None | Some("privatetransaction") => h_eth::PrivateSubmissionMethod::PrivateTransaction,
+        Some("bundle") => h_eth::PrivateSubmissionMethod::Bundle,
+        Some(_) => {
+            err.push(
+                (&private_submission.cwp).add("method"),
+                eyre!("Unknown private submission method"),
+            );
+            return None;
+        }
+    };
+
+    let fallback_after_blocks = private_submission
+        .chain(err)
+        .get_opt_key("fallbackAfterBlocks")
+        .parse_u32()
+        .end();
+
+    Some(h_eth::PrivateSubmissionConf {
+        url: url?,
+        method,
+        fallback_after_blocks,
+    })
+}
+
 pub fn build_cosmos_connection_conf(
     rpcs: &[Url],
     chain: &ValueParser,
@@ -292,7 +348,8 @@ fn build_sealevel_connection_conf(
     let mut local_err = ConfigParsingError::default();
 
     let native_token = parse_native_token(chain, err, 9);
-    let priority_fee_oracle = parse_sealevel_priority_fee_oracle_config(chain, &mut local_err);
+    let priority_fee_oracle =
+        parse_sealevel_priority_fee_oracle_config(urls, chain, &mut local_err);
     let transaction_submitter = parse_transaction_submitter_config(chain, &mut local_err);
     let mailbox_process_alt = parse_sealevel_mailbox_process_alt(chain, &mut local_err);
     let process_alt_overrides = parse_sealevel_process_alt_overrides(chain, &mut local_err);
@@ -431,6 +488,7 @@ fn parse_native_token(
 }
 
 fn parse_sealevel_priority_fee_oracle_config(
+    urls: &[Url],
     chain: &ValueParser,
     err: &mut ConfigParsingError,
 ) -> Option<PriorityFeeOracleConfig> {
@@ -475,6 +533,52 @@ fn parse_sealevel_priority_fee_oracle_config(
                 let config = HeliusPriorityFeeOracleConfig { url, fee_level };
                 Some(PriorityFeeOracleConfig::Helius(config))
             }
+            "rpc" => {
+                // default to the chain's own RPC, which supports the method too
+                let url = value_parser
+                    .chain(err)
+                    .get_opt_key("url")
+                    .parse_from_str("Invalid url")
+                    .end()
+                    .or_else(|| urls.first().cloned());
+                let percentile = value_parser
+                    .chain(err)
+                    .get_opt_key("percentile")
+                    .parse_u64()
+                    .unwrap_or(50);
+                let floor = value_parser
+                    .chain(err)
+                    .get_opt_key("floor")
+                    .parse_u64()
+                    .unwrap_or(0);
+                let ceiling = value_parser
+                    .chain(err)
+                    .get_opt_key("ceiling")
+                    .parse_u64()
+                    .end();
+
+                let Some(url) = url else {
+                    err.push(
+                        (&value_parser.cwp).add("url"),
+                        eyre!("Missing url for rpc priority fee oracle"),
+                    );
+                    return None;
+                };
+                let Some(percentile) = u8::try_from(percentile).ok().filter(|p| *p <= 100) else {
+                    err.push(
+                        (&value_parser.cwp).add("percentile"),
+                        eyre!("Priority fee percentile must be between 0 and 100"),
+                    );
+                    return None;
+                };
+                let config = RpcPriorityFeeOracleConfig {
+                    url,
+                    percentile,
+                    floor,
+                    ceiling,
+                };
+                Some(PriorityFeeOracleConfig::Rpc(config))
+            }
             _ => {
                 err.push(
                     (&value_parser.cwp).add("type"),
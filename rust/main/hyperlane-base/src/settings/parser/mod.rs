@@ -30,7 +30,7 @@ use crate::settings::{
     chains::IndexSettings,
     parser::connection_parser::{build_connection_conf, is_protocol_supported},
     trace::TracingConfig,
-    ChainConf, CoreContractAddresses, Settings, SignerConf,
+    ChainConf, ChainConnectionConf, CoreContractAddresses, Settings, SignerConf,
 };
 
 pub use super::envs::*;
@@ -170,6 +170,16 @@ fn parse_chain(
         .get_opt_key("signer")
         .and_then(parse_signer)
         .end();
+    let signer_pool = chain
+        .chain(&mut err)
+        .get_opt_key("signerPool")
+        .into_array_iter()
+        .map(|signers| {
+            signers
+                .filter_map(|s| parse_signer(s).take_config_err(&mut err))
+                .collect_vec()
+        })
+        .unwrap_or_default();
 
     // measured in seconds (with fractions)
     let estimated_block_time = chain
@@ -263,6 +273,12 @@ fn parse_chain(
         .parse_u32()
         .end();
 
+    let signer_assignment = chain
+        .chain(&mut err)
+        .get_opt_key("signerAssignment")
+        .parse_value("Invalid signerAssignment")
+        .unwrap_or_default();
+
     let ignore_reorg_reports = chain
         .chain(&mut err)
         .get_opt_key("ignoreReorgReports")
@@ -323,11 +339,38 @@ fn parse_chain(
             max_batch_size,
             bypass_batch_simulation,
             max_submit_queue_length,
+            signer_assignment,
         },
     );
 
     cfg_unwrap_all!(&chain.cwp, err: [connection, mailbox, interchain_gas_paymaster, validator_announce, merkle_tree_hook]);
 
+    // logs are only subscribed to in subscription mode, even if a url is configured
+    let connection = match connection {
+        ChainConnectionConf::Ethereum(conf) if matches!(mode, IndexMode::Subscription) => {
+            if conf.log_subscription_url.is_none() {
+                err.push(
+                    (&chain.cwp).add("index").join("subscription_url"),
+                    eyre!("Subscription indexing requires `index.subscriptionUrl` to be set"),
+                );
+            }
+            ChainConnectionConf::Ethereum(conf)
+        }
+        ChainConnectionConf::Ethereum(mut conf) => {
+            conf.log_subscription_url = None;
+            ChainConnectionConf::Ethereum(conf)
+        }
+        connection => {
+            if matches!(mode, IndexMode::Subscription) {
+                err.push(
+                    (&chain.cwp).add("index").join("mode"),
+                    eyre!("Subscription indexing is only supported on EVM chains"),
+                );
+            }
+            connection
+        }
+    };
+
     let submitter = chain
         .chain(&mut err)
         .get_opt_key("submitter")
@@ -348,6 +391,7 @@ fn parse_chain(
     err.into_result(ChainConf {
         domain,
         signer,
+        signer_pool,
         submitter,
         estimated_block_time,
         reorg_period,
@@ -461,6 +505,46 @@ fn parse_signer(signer: ValueParser) -> ConfigResult<SignerConf> {
                 .to_owned();
             err.into_result(SignerConf::Aws { id, region })
         }};
+        (remoteSigner) => {{
+            let url = signer
+                .chain(&mut err)
+                .get_key("url")
+                .parse_from_str("Expected remote signer url")
+                .end();
+            let address = signer
+                .chain(&mut err)
+                .get_key("address")
+                .parse_address_hash()
+                .end();
+            cfg_unwrap_all!(&signer.cwp, err: [url, address]);
+            err.into_result(SignerConf::RemoteSigner {
+                url,
+                address: address.into(),
+            })
+        }};
+        (thresholdSigner) => {{
+            let url = signer
+                .chain(&mut err)
+                .get_key("url")
+                .parse_from_str("Expected threshold signer coordinator url")
+                .end();
+            let key_id = signer
+                .chain(&mut err)
+                .get_key("keyId")
+                .parse_string()
+                .end();
+            let address = signer
+                .chain(&mut err)
+                .get_key("address")
+                .parse_address_hash()
+                .end();
+            cfg_unwrap_all!(&signer.cwp, err: [url, key_id, address]);
+            err.into_result(SignerConf::ThresholdSigner {
+                url,
+                key_id: key_id.to_owned(),
+                address: address.into(),
+            })
+        }};
         (cosmosKey) => {{
             let key = signer
                 .chain(&mut err)
@@ -527,6 +611,8 @@ fn parse_signer(signer: ValueParser) -> ConfigResult<SignerConf> {
     match signer_type {
         Some("hexKey") => parse_signer!(hexKey),
         Some("aws") => parse_signer!(aws),
+        Some("remoteSigner") => parse_signer!(remoteSigner),
+        Some("thresholdSigner") => parse_signer!(thresholdSigner),
         Some("cosmosKey") => parse_signer!(cosmosKey),
         Some("starkKey") => parse_signer!(starkKey),
         Some("radixKey") => parse_signer!(radixKey),
@@ -725,4 +811,164 @@ mod test {
         let value_parser = ValueParser::new(Default::default(), &val);
         parse_matching_list(value_parser).unwrap();
     }
+
+    fn chain_json(index: Value) -> Value {
+        serde_json::json!({
+            "name": "subscriptiontest",
+            "domainid": 987654,
+            "protocol": "ethereum",
+            "rpcurls": [{ "http": "http://127.0.0.1:8545" }],
+            "mailbox": "0x0000000000000000000000000000000000000001",
+            "interchaingaspaymaster": "0x0000000000000000000000000000000000000002",
+            "validatorannounce": "0x0000000000000000000000000000000000000003",
+            "merkletreehook": "0x0000000000000000000000000000000000000004",
+            "index": index,
+        })
+    }
+
+    #[test]
+    fn subscription_mode_requires_subscription_url() {
+        let val = chain_json(serde_json::json!({ "mode": "subscription" }));
+        let chain = ValueParser::new(Default::default(), &val);
+        assert!(parse_chain(chain, "subscriptiontest", "fallback").is_err());
+
+        let val = chain_json(serde_json::json!({
+            "mode": "subscription",
+            "subscriptionurl": "ws://127.0.0.1:8546",
+        }));
+        let chain = ValueParser::new(Default::default(), &val);
+        let conf = parse_chain(chain, "subscriptiontest", "fallback").unwrap();
+        assert!(matches!(conf.index.mode, IndexMode::Subscription));
+        let ChainConnectionConf::Ethereum(connection) = conf.connection else {
+            panic!("Expected an ethereum connection");
+        };
+        assert_eq!(
+            connection.log_subscription_url,
+            Some("ws://127.0.0.1:8546".parse().unwrap())
+        );
+
+        // the url alone doesn't enable subscriptions
+        let val = chain_json(serde_json::json!({
+            "mode": "block",
+            "subscriptionurl": "ws://127.0.0.1:8546",
+        }));
+        let chain = ValueParser::new(Default::default(), &val);
+        let conf = parse_chain(chain, "subscriptiontest", "fallback").unwrap();
+        let ChainConnectionConf::Ethereum(connection) = conf.connection else {
+            panic!("Expected an ethereum connection");
+        };
+        assert!(connection.log_subscription_url.is_none());
+    }
+
+    #[test]
+    fn parses_private_submission() {
+        let val = chain_json(serde_json::json!({}));
+        let chain = ValueParser::new(Default::default(), &val);
+        let conf = parse_chain(chain, "subscriptiontest", "fallback").unwrap();
+        let ChainConnectionConf::Ethereum(connection) = conf.connection else {
+            panic!("Expected an ethereum connection");
+        };
+        assert!(connection.private_submission.is_none());
+
+        let mut val = chain_json(serde_json::json!({}));
+        val["privatesubmission"] = serde_json::json!({
+            "url": "http://127.0.0.1:8547",
+            "method": "bundle",
+            "fallbackafterblocks": 5,
+        });
+        let chain = ValueParser::new(Default::default(), &val);
+        let conf = parse_chain(chain, "subscriptiontest", "fallback").unwrap();
+        let ChainConnectionConf::Ethereum(connection) = conf.connection else {
+            panic!("Expected an ethereum connection");
+        };
+        let private_submission = connection.private_submission.unwrap();
+        assert_eq!(
+            private_submission.url,
+            "http://127.0.0.1:8547".parse().unwrap()
+        );
+        assert_eq!(
+            private_submission.method,
+            h_eth::PrivateSubmissionMethod::Bundle
+        );
+        assert_eq!(private_submission.fallback_after_blocks, Some(5));
+
+        let mut val = chain_json(serde_json::json!({}));
+        val["privatesubmission"] = serde_json::json!({
+            "url": "http://127.0.0.1:8547",
+            "method": "carrierpigeon",
+        });
+        let chain = ValueParser::new(Default::default(), &val);
+        assert!(parse_chain(chain, "subscriptiontest", "fallback").is_err());
+    }
+
+    #[test]
+    fn parses_rpc_priority_fee_oracle() {
+        let sealevel_chain = |oracle: Value| {
+            let mut val = chain_json(serde_json::json!({}));
+            val["protocol"] = serde_json::json!("sealevel");
+            val["priorityfeeoracle"] = oracle;
+            val
+        };
+
+        let val = sealevel_chain(serde_json::json!({
+            "type": "rpc",
+            "percentile": 75,
+            "floor": 100,
+        }));
+        let chain = ValueParser::new(Default::default(), &val);
+        let conf = parse_chain(chain, "subscriptiontest", "fallback").unwrap();
+        let ChainConnectionConf::Sealevel(connection) = conf.connection else {
+            panic!("Expected a sealevel connection");
+        };
+        let h_sealevel::PriorityFeeOracleConfig::Rpc(oracle) = connection.priority_fee_oracle
+        else {
+            panic!("Expected an rpc priority fee oracle");
+        };
+        // defaults to the chain's rpc
+        assert_eq!(oracle.url, "http://127.0.0.1:8545".parse().unwrap());
+        assert_eq!(oracle.percentile, 75);
+        assert_eq!(oracle.floor, 100);
+        assert_eq!(oracle.ceiling, None);
+
+        let val = sealevel_chain(serde_json::json!({
+            "type": "rpc",
+            "percentile": 101,
+        }));
+        let chain = ValueParser::new(Default::default(), &val);
+        assert!(parse_chain(chain, "subscriptiontest", "fallback").is_err());
+    }
+
+    #[test]
+    fn parses_threshold_signer() {
+        let val = serde_json::json!({
+            "type": "thresholdSigner",
+            "url": "http://127.0.0.1:9100",
+            "keyid": "validator-key",
+            "address": "0x0bec35c9af305b1b8849d652f4b542d19ef7e8f9",
+        });
+        let conf = parse_signer(ValueParser::new(Default::default(), &val)).unwrap();
+        let SignerConf::ThresholdSigner {
+            url,
+            key_id,
+            address,
+        } = conf
+        else {
+            panic!("Expected a threshold signer");
+        };
+        assert_eq!(url, "http://127.0.0.1:9100".parse().unwrap());
+        assert_eq!(key_id, "validator-key");
+        assert_eq!(
+            address,
+            "0x0bec35c9af305b1b8849d652f4b542d19ef7e8f9"
+                .parse()
+                .unwrap()
+        );
+
+        let val = serde_json::json!({
+            "type": "thresholdSigner",
+            "url": "http://127.0.0.1:9100",
+            "address": "0x0bec35c9af305b1b8849d652f4b542d19ef7e8f9",
+        });
+        assert!(parse_signer(ValueParser::new(Default::default(), &val)).is_err());
+    }
 }
@@ -9,8 +9,9 @@ use rusoto_core::Region;
 use rusoto_kms::KmsClient;
 use std::str::FromStr;
 use tracing::instrument;
+use url::Url;
 
-use hyperlane_core::{AccountAddressType, H256};
+use hyperlane_core::{AccountAddressType, H160, H256};
 
 use super::aws_credentials::AwsChainCredentialsProvider;
 use crate::types::utils;
@@ -43,6 +44,23 @@ pub enum SignerConf {
         /// The AWS region
         region: String,
     },
+    /// A remote signing service implementing the Web3Signer eth1 signing API
+    RemoteSigner {
+        /// Base url of the signing service
+        url: Url,
+        /// Address of the key to sign with
+        address: H160,
+    },
+    /// A key shared between the parties of a threshold ECDSA scheme, signing
+    /// through the scheme's HTTP coordinator
+    ThresholdSigner {
+        /// Base url of the signing coordinator
+        url: Url,
+        /// Identifier of the shared key at the coordinator
+        key_id: String,
+        /// Address of the group public key
+        address: H160,
+    },
     /// Cosmos Specific key
     CosmosKey {
         /// Private key value
@@ -116,6 +134,18 @@ impl BuildableWithSignerConf for hyperlane_ethereum::Signers {
                 let signer = AwsSigner::new(client, id, 0, Some(AWS_SIGNER_TIMEOUT)).await?;
                 hyperlane_ethereum::Signers::Aws(signer)
             }
+            SignerConf::RemoteSigner { url, address } => hyperlane_ethereum::Signers::Remote(
+                hyperlane_ethereum::RemoteSigner::new(url, (*address).into())?,
+            ),
+            SignerConf::ThresholdSigner {
+                url,
+                key_id,
+                address,
+            } => hyperlane_ethereum::Signers::Threshold(hyperlane_ethereum::ThresholdSigner::new(
+                url,
+                key_id,
+                (*address).into(),
+            )?),
             SignerConf::CosmosKey { .. } => {
                 bail!("cosmosKey signer is not supported by Ethereum")
             }
@@ -446,6 +476,38 @@ mod tests {
         assert_eq!(chain_signer.address_h256(), address_h256);
     }
 
+    #[tokio::test]
+    async fn address_h256_http_signers() {
+        use crate::settings::signers::BuildableWithSignerConf;
+
+        const ADDRESS: &str = "0000000000000000000000000bec35c9af305b1b8849d652f4b542d19ef7e8f9";
+        let address_h256 = H256::from_slice(
+            hex::decode(ADDRESS)
+                .expect("Failed to decode address")
+                .as_slice(),
+        );
+        let url: url::Url = "http://127.0.0.1:9000".parse().unwrap();
+
+        let signer_configs = [
+            SignerConf::RemoteSigner {
+                url: url.clone(),
+                address: address_h256.into(),
+            },
+            SignerConf::ThresholdSigner {
+                url,
+                key_id: "validator".to_owned(),
+                address: address_h256.into(),
+            },
+        ];
+        for signer_config in signer_configs {
+            let chain_signer = hyperlane_ethereum::Signers::build(&signer_config)
+                .await
+                .unwrap_or_else(|err| panic!("Failed to build {signer_config:?}: {err}"));
+
+            assert_eq!(chain_signer.address_h256(), address_h256);
+        }
+    }
+
     #[tokio::test]
     async fn address_h256_tron() {
         use crate::settings::signers::BuildableWithSignerConf;
@@ -2,5 +2,7 @@
 pub mod mock_checkpoint_syncer;
 /// Mock HyperlaneDb for testing
 pub mod mock_hyperlane_db;
+/// Dummy signed checkpoints
+pub mod test_checkpoints;
 /// Dummy Validators
 pub mod test_validators;
@@ -0,0 +1,25 @@
+use hyperlane_core::{
+    Checkpoint, CheckpointWithMessageId, Signature, SignedCheckpointWithMessageId, SignedType,
+    H256, U256,
+};
+
+/// A checkpoint at `index` with a dummy signature, for tests which only store
+/// and read checkpoints back
+pub fn dummy_checkpoint(index: u32) -> SignedCheckpointWithMessageId {
+    SignedType {
+        value: CheckpointWithMessageId {
+            checkpoint: Checkpoint {
+                merkle_tree_hook_address: H256::repeat_byte(1),
+                mailbox_domain: 1,
+                root: H256::repeat_byte(2),
+                index,
+            },
+            message_id: H256::repeat_byte(3),
+        },
+        signature: Signature {
+            r: U256::one(),
+            s: U256::one(),
+            v: 27,
+        },
+    }
+}
@@ -0,0 +1,285 @@
+use std::{fmt, time::Duration};
+
+use async_trait::async_trait;
+use eyre::{bail, Result};
+use prometheus::IntGauge;
+use reqwest::{Client, StatusCode};
+use tracing::error;
+use url::Url;
+
+use hyperlane_core::{
+    ReorgEvent, ReorgEventResponse, SignedAnnouncement, SignedCheckpointWithMessageId,
+};
+
+use crate::CheckpointSyncer;
+
+/// The timeout for all HTTP requests.
+const HTTP_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const HTTP_MAX_OBJECT_SIZE: u64 = 50 * 1024; // 50KiB
+
+#[derive(Clone)]
+/// Type for reading checkpoints from any static web server or CDN.
+/// Objects are expected to follow the same key layout as `S3Storage`.
+/// This syncer is read-only, all writes fail.
+pub struct HttpStorage {
+    /// The base url all keys are resolved against
+    base_url: Url,
+    client: Client,
+    /// The latest seen signed checkpoint index.
+    latest_index: Option<IntGauge>,
+}
+
+impl fmt::Debug for HttpStorage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpStorage")
+            .field("base_url", &self.base_url.as_str())
+            .finish()
+    }
+}
+
+impl HttpStorage {
+    /// Create a new HttpStorage checkpoint syncer instance.
+    pub fn new(base_url: Url, latest_index: Option<IntGauge>) -> Result<Self> {
+        let client = Client::builder().timeout(HTTP_REQUEST_TIMEOUT).build()?;
+        Ok(Self {
+            base_url,
+            client,
+            latest_index,
+        })
+    }
+
+    fn object_url(&self, key: String) -> Result<Url> {
+        // `Url::join` replaces the last path segment unless the base ends with a slash
+        let mut base_url = self.base_url.clone();
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+        Ok(base_url.join(&key)?)
+    }
+
+    async fn read_object(&self, key: String) -> Result<Option<Vec<u8>>> {
+        let url = self.object_url(key.clone())?;
+        let response = self.client.get(url).send().await?;
+
+        match response.status() {
+            StatusCode::NOT_FOUND => return Ok(None),
+            status if !status.is_success() => {
+                bail!("Failed to fetch {key}, unexpected status code {status}")
+            }
+            _ => {}
+        }
+
+        if let Some(length) = response.content_length() {
+            if length >= HTTP_MAX_OBJECT_SIZE {
+                bail!("Object size for key {key} is too big: {}KiB", length / 1024);
+            }
+        }
+        let body = response.bytes().await?;
+        // the content length header is optional, so also check the actual body size
+        if body.len() as u64 >= HTTP_MAX_OBJECT_SIZE {
+            bail!(
+                "Object size for key {key} is too big: {}KiB",
+                body.len() / 1024
+            );
+        }
+        Ok(Some(body.to_vec()))
+    }
+
+    fn read_only_error<T>(&self) -> Result<T> {
+        bail!("HTTP checkpoint syncer at {} is read-only", self.base_url)
+    }
+
+    fn checkpoint_key(index: u32) -> String {
+        format!("checkpoint_{index}_with_id.json")
+    }
+
+    fn latest_index_key() -> String {
+        "checkpoint_latest_index.json".to_owned()
+    }
+
+    fn reorg_flag_key() -> String {
+        "reorg_flag.json".to_owned()
+    }
+}
+
+#[async_trait]
+impl CheckpointSyncer for HttpStorage {
+    async fn latest_index(&self) -> Result<Option<u32>> {
+        let ret = self
+            .read_object(HttpStorage::latest_index_key())
+            .await?
+            .map(|data| serde_json::from_slice(&data))
+            .transpose()
+            .map_err(Into::into);
+
+        if let Ok(Some(latest_index)) = ret {
+            if let Some(gauge) = &self.latest_index {
+                gauge.set(latest_index as i64);
+            }
+        }
+
+        ret
+    }
+
+    async fn write_latest_index(&self, _index: u32) -> Result<()> {
+        self.read_only_error()
+    }
+
+    async fn fetch_checkpoint(&self, index: u32) -> Result<Option<SignedCheckpointWithMessageId>> {
+        self.read_object(HttpStorage::checkpoint_key(index))
+            .await?
+            .map(|data| serde_json::from_slice(&data))
+            .transpose()
+            .map_err(Into::into)
+    }
+
+    async fn write_checkpoint(
+        &self,
+        _signed_checkpoint: &SignedCheckpointWithMessageId,
+    ) -> Result<()> {
+        self.read_only_error()
+    }
+
+    async fn write_metadata(&self, _serialized_metadata: &str) -> Result<()> {
+        self.read_only_error()
+    }
+
+    async fn write_announcement(&self, _signed_announcement: &SignedAnnouncement) -> Result<()> {
+        self.read_only_error()
+    }
+
+    fn announcement_location(&self) -> String {
+        self.base_url.to_string()
+    }
+
+    async fn write_reorg_status(&self, _reorged_event: &ReorgEvent) -> Result<()> {
+        self.read_only_error()
+    }
+
+    async fn reorg_status(&self) -> Result<ReorgEventResponse> {
+        let contents = match self.read_object(HttpStorage::reorg_flag_key()).await? {
+            Some(s) => s,
+            None => {
+                return Ok(ReorgEventResponse {
+                    exists: false,
+                    event: None,
+                    content: None,
+                })
+            }
+        };
+        match serde_json::from_slice(&contents) {
+            Ok(s) => Ok(ReorgEventResponse {
+                exists: true,
+                event: Some(s),
+                content: Some(String::from_utf8_lossy(&contents).to_string()),
+            }),
+            Err(err) => {
+                error!(?err, "Failed to parse reorg event");
+                Ok(ReorgEventResponse {
+                    exists: true,
+                    event: None,
+                    content: Some(String::from_utf8_lossy(&contents).to_string()),
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use axum::{extract::Path, http::StatusCode, routing::get, Router};
+
+    use super::*;
+    use crate::tests::test_checkpoints::dummy_checkpoint;
+
+    /// Serves the given objects under `/checkpoints/{key}` on a random local port
+    async fn serve(objects: HashMap<String, String>) -> Url {
+        let app = Router::new().route(
+            "/checkpoints/{key}",
+            get(move |Path(key): Path<String>| {
+                let objects = objects.clone();
+                async move { objects.get(&key).cloned().ok_or(StatusCode::NOT_FOUND) }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        Url::parse(&format!("http://{addr}/checkpoints")).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_read_checkpoints() {
+        let checkpoint = dummy_checkpoint(5);
+        let objects = HashMap::from([
+            ("checkpoint_latest_index.json".to_owned(), "5".to_owned()),
+            (
+                "checkpoint_5_with_id.json".to_owned(),
+                serde_json::to_string(&checkpoint).unwrap(),
+            ),
+        ]);
+        let storage = HttpStorage::new(serve(objects).await, None).unwrap();
+
+        assert_eq!(storage.latest_index().await.unwrap(), Some(5));
+        assert_eq!(storage.fetch_checkpoint(5).await.unwrap(), Some(checkpoint));
+        assert_eq!(storage.fetch_checkpoint(6).await.unwrap(), None);
+        assert!(!storage.reorg_status().await.unwrap().exists);
+    }
+
+    #[tokio::test]
+    async fn test_object_too_big() {
+        let objects = HashMap::from([(
+            "checkpoint_latest_index.json".to_owned(),
+            " ".repeat(HTTP_MAX_OBJECT_SIZE as usize),
+        )]);
+        let storage = HttpStorage::new(serve(objects).await, None).unwrap();
+
+        assert!(storage.latest_index().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_writes_fail() {
+        let storage = HttpStorage::new(serve(HashMap::new()).await, None).unwrap();
+
+        assert!(storage.write_latest_index(1).await.is_err());
+        assert!(storage
+            .write_checkpoint(&dummy_checkpoint(1))
+            .await
+            .is_err());
+        assert!(storage.write_metadata("{}").await.is_err());
+    }
+
+    #[test]
+    fn test_object_url() {
+        let storage = HttpStorage::new(
+            Url::parse("https://cdn.example.com/validator").unwrap(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            storage
+                .object_url(HttpStorage::checkpoint_key(1))
+                .unwrap()
+                .as_str(),
+            "https://cdn.example.com/validator/checkpoint_1_with_id.json"
+        );
+
+        let storage = HttpStorage::new(
+            Url::parse("https://cdn.example.com/validator/").unwrap(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            storage.announcement_location(),
+            "https://cdn.example.com/validator/"
+        );
+        assert_eq!(
+            storage
+                .object_url(HttpStorage::latest_index_key())
+                .unwrap()
+                .as_str(),
+            "https://cdn.example.com/validator/checkpoint_latest_index.json"
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use std::{fmt::Debug, future::Future, sync::Arc};
+
+use async_trait::async_trait;
+use eyre::{bail, eyre, Result};
+use futures::future::join_all;
+use tracing::warn;
+
+use hyperlane_core::{
+    ReorgEvent, ReorgEventResponse, SignedAnnouncement, SignedCheckpointWithMessageId,
+};
+
+use crate::CheckpointSyncer;
+
+/// A checkpoint syncer that mirrors every write to several storage backends.
+///
+/// Writes succeed once at least `write_quorum` backends accepted them, failures
+/// of the remaining backends are only logged. Reads go through the backends in
+/// order and fall back to the next one if a backend errors or has no data.
+#[derive(Debug, Clone)]
+pub struct RedundantCheckpointSyncer {
+    syncers: Vec<Arc<dyn CheckpointSyncer>>,
+    write_quorum: usize,
+}
+
+impl RedundantCheckpointSyncer {
+    /// Create a new RedundantCheckpointSyncer. The first syncer is the primary one
+    /// and is preferred for reads.
+    pub fn new(syncers: Vec<Arc<dyn CheckpointSyncer>>, write_quorum: usize) -> Result<Self> {
+        if syncers.is_empty() {
+            bail!("At least one checkpoint syncer is required");
+        }
+        if write_quorum == 0 || write_quorum > syncers.len() {
+            bail!(
+                "Write quorum must be between 1 and the number of checkpoint syncers ({}), got {write_quorum}",
+                syncers.len()
+            );
+        }
+        Ok(Self {
+            syncers,
+            write_quorum,
+        })
+    }
+
+    /// The underlying checkpoint syncers, in order of preference
+    pub fn syncers(&self) -> &[Arc<dyn CheckpointSyncer>] {
+        &self.syncers
+    }
+
+    /// Runs `write` against all syncers concurrently and checks that enough of them succeeded
+    async fn write_all<'a, F, Fut>(&'a self, operation: &str, write: F) -> Result<()>
+    where
+        F: Fn(&'a Arc<dyn CheckpointSyncer>) -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        let results = join_all(self.syncers.iter().map(write)).await;
+
+        let mut successes = 0;
+        for (syncer, result) in self.syncers.iter().zip(results) {
+            match result {
+                Ok(()) => successes += 1,
+                Err(err) => warn!(
+                    ?err,
+                    location = %syncer.announcement_location(),
+                    operation,
+                    "Failed to write to checkpoint syncer"
+                ),
+            }
+        }
+
+        if successes < self.write_quorum {
+            bail!(
+                "Failed to {operation}: only {successes} of {} checkpoint syncers succeeded, quorum is {}",
+                self.syncers.len(),
+                self.write_quorum
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the first non-empty read result, falling back to the next syncer on errors
+    async fn read_first<'a, T, F, Fut>(&'a self, operation: &str, read: F) -> Result<Option<T>>
+    where
+        F: Fn(&'a Arc<dyn CheckpointSyncer>) -> Fut,
+        Fut: Future<Output = Result<Option<T>>>,
+    {
+        let mut last_err = None;
+        let mut any_succeeded = false;
+        for syncer in &self.syncers {
+            match read(syncer).await {
+                Ok(Some(value)) => return Ok(Some(value)),
+                Ok(None) => any_succeeded = true,
+                Err(err) => {
+                    warn!(
+                        ?err,
+                        location = %syncer.announcement_location(),
+                        operation,
+                        "Failed to read from checkpoint syncer, falling back to the next one"
+                    );
+                    last_err = Some(err);
+                }
+            }
+        }
+
+        match last_err {
+            Some(err) if !any_succeeded => {
+                Err(err.wrap_err(format!("Failed to {operation} from all checkpoint syncers")))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl CheckpointSyncer for RedundantCheckpointSyncer {
+    async fn latest_index(&self) -> Result<Option<u32>> {
+        self.read_first("read latest index", |syncer| syncer.latest_index())
+            .await
+    }
+
+    async fn write_latest_index(&self, index: u32) -> Result<()> {
+        self.write_all("write latest index", |syncer| {
+            syncer.write_latest_index(index)
+        })
+        .await
+    }
+
+    async fn update_latest_index(&self, index: u32) -> Result<()> {
+        // each backend may lag behind differently, so let each of them decide
+        self.write_all("update latest index", |syncer| {
+            syncer.update_latest_index(index)
+        })
+        .await
+    }
+
+    async fn fetch_checkpoint(&self, index: u32) -> Result<Option<SignedCheckpointWithMessageId>> {
+        self.read_first("fetch checkpoint", |syncer| syncer.fetch_checkpoint(index))
+            .await
+    }
+
+    async fn write_checkpoint(
+        &self,
+        signed_checkpoint: &SignedCheckpointWithMessageId,
+    ) -> Result<()> {
+        self.write_all("write checkpoint", |syncer| {
+            syncer.write_checkpoint(signed_checkpoint)
+        })
+        .await
+    }
+
+    async fn write_metadata(&self, serialized_metadata: &str) -> Result<()> {
+        self.write_all("write metadata", |syncer| {
+            syncer.write_metadata(serialized_metadata)
+        })
+        .await
+    }
+
+    async fn write_announcement(&self, signed_announcement: &SignedAnnouncement) -> Result<()> {
+        self.write_all("write announcement", |syncer| {
+            syncer.write_announcement(signed_announcement)
+        })
+        .await
+    }
+
+    /// The location of the primary syncer
+    fn announcement_location(&self) -> String {
+        self.syncers[0].announcement_location()
+    }
+
+    async fn write_reorg_status(&self, reorg_event: &ReorgEvent) -> Result<()> {
+        self.write_all("write reorg status", |syncer| {
+            syncer.write_reorg_status(reorg_event)
+        })
+        .await
+    }
+
+    async fn write_reorg_rpc_responses(&self, log: String) -> Result<()> {
+        self.write_all("write reorg rpc responses", |syncer| {
+            syncer.write_reorg_rpc_responses(log.clone())
+        })
+        .await
+    }
+
+    /// A reorg flag in any of the backends is reported, since it must never be missed
+    async fn reorg_status(&self) -> Result<ReorgEventResponse> {
+        let results = join_all(self.syncers.iter().map(|syncer| syncer.reorg_status())).await;
+
+        let mut last_err = None;
+        let mut status = None;
+        for (syncer, result) in self.syncers.iter().zip(results) {
+            match result {
+                Ok(response) if response.exists => return Ok(response),
+                Ok(response) => status = Some(response),
+                Err(err) => {
+                    warn!(
+                        ?err,
+                        location = %syncer.announcement_location(),
+                        "Failed to read reorg status from checkpoint syncer"
+                    );
+                    last_err = Some(err);
+                }
+            }
+        }
+
+        status.ok_or_else(|| last_err.unwrap_or_else(|| eyre!("No checkpoint syncers")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyperlane_core::H256;
+
+    use super::*;
+    use crate::{
+        tests::{mock_checkpoint_syncer::MockCheckpointSyncer, test_checkpoints::dummy_checkpoint},
+        LocalStorage,
+    };
+
+    fn local_syncer(dir: &tempfile::TempDir) -> Arc<dyn CheckpointSyncer> {
+        Arc::new(LocalStorage::new(dir.path().to_path_buf(), None).unwrap())
+    }
+
+    /// A syncer whose next checkpoint write and latest index read fail
+    fn failing_syncer() -> Arc<dyn CheckpointSyncer> {
+        let syncer = MockCheckpointSyncer::new();
+        let responses = &syncer.responses;
+        responses
+            .write_checkpoint
+            .lock()
+            .unwrap()
+            .push_back(Err(eyre!("backend unavailable")));
+        responses
+            .latest_index
+            .lock()
+            .unwrap()
+            .push_back(Err(eyre!("backend unavailable")));
+        // used when logging the failure
+        responses
+            .announcement_location
+            .lock()
+            .unwrap()
+            .push_back("mock://failing".to_owned());
+        Arc::new(syncer)
+    }
+
+    #[test]
+    fn test_invalid_quorum() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(RedundantCheckpointSyncer::new(vec![], 1).is_err());
+        assert!(RedundantCheckpointSyncer::new(vec![local_syncer(&dir)], 0).is_err());
+        assert!(RedundantCheckpointSyncer::new(vec![local_syncer(&dir)], 2).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_writes_to_all_syncers() {
+        let (first, second) = (tempfile::tempdir().unwrap(), tempfile::tempdir().unwrap());
+        let syncers = vec![local_syncer(&first), local_syncer(&second)];
+        let syncer = RedundantCheckpointSyncer::new(syncers.clone(), 2).unwrap();
+
+        syncer.write_checkpoint(&dummy_checkpoint(3)).await.unwrap();
+        syncer.update_latest_index(3).await.unwrap();
+
+        for inner in syncers {
+            assert_eq!(inner.latest_index().await.unwrap(), Some(3));
+            assert_eq!(
+                inner.fetch_checkpoint(3).await.unwrap(),
+                Some(dummy_checkpoint(3))
+            );
+        }
+        assert_eq!(
+            syncer.announcement_location(),
+            local_syncer(&first).announcement_location()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_write_quorum() {
+        let dir = tempfile::tempdir().unwrap();
+        let syncer =
+            RedundantCheckpointSyncer::new(vec![failing_syncer(), local_syncer(&dir)], 1).unwrap();
+        syncer.write_checkpoint(&dummy_checkpoint(1)).await.unwrap();
+
+        let syncer =
+            RedundantCheckpointSyncer::new(vec![failing_syncer(), local_syncer(&dir)], 2).unwrap();
+        assert!(syncer.write_checkpoint(&dummy_checkpoint(2)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reads_fall_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let fallback = local_syncer(&dir);
+        fallback.write_latest_index(7).await.unwrap();
+
+        let syncer = RedundantCheckpointSyncer::new(vec![failing_syncer(), fallback], 1).unwrap();
+        assert_eq!(syncer.latest_index().await.unwrap(), Some(7));
+
+        let syncer = RedundantCheckpointSyncer::new(vec![failing_syncer()], 1).unwrap();
+        assert!(syncer.latest_index().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reorg_flag_in_any_syncer() {
+        let (first, second) = (tempfile::tempdir().unwrap(), tempfile::tempdir().unwrap());
+        let syncers = vec![local_syncer(&first), local_syncer(&second)];
+        let syncer = RedundantCheckpointSyncer::new(syncers.clone(), 1).unwrap();
+        assert!(!syncer.reorg_status().await.unwrap().exists);
+
+        let reorg_event = ReorgEvent {
+            local_merkle_root: H256::repeat_byte(1),
+            canonical_merkle_root: H256::repeat_byte(2),
+            checkpoint_index: 5,
+            unix_timestamp: 1620000000,
+            reorg_period: hyperlane_core::ReorgPeriod::from_blocks(5),
+        };
+        syncers[1].write_reorg_status(&reorg_event).await.unwrap();
+
+        let status = syncer.reorg_status().await.unwrap();
+        assert!(status.exists);
+        assert_eq!(status.event, Some(reorg_event));
+    }
+}
@@ -7,6 +7,7 @@ use std::fmt::{Debug, Display, Formatter};
 
 pub use config_path::ConfigPath;
 use eyre::Report;
+use serde::{Deserialize, Serialize};
 pub use str_or_int::{StrOrInt, StrOrIntParseError};
 pub use trait_ext::*;
 
@@ -36,6 +37,21 @@ pub struct OpSubmissionConfig {
 
     /// max submit queue length
     pub max_submit_queue_length: Option<u32>,
+
+    /// How transactions are distributed across signers when the chain is
+    /// configured with a signer pool
+    pub signer_assignment: SignerAssignment,
+}
+
+/// Strategy for distributing transactions across a pool of signers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SignerAssignment {
+    /// Assign each transaction to the signer with the fewest transactions in flight
+    #[default]
+    LeastPending,
+    /// Cycle through the signers in order
+    RoundRobin,
 }
 
 /// A trait that allows for constructing `Self` from a raw config type.
@@ -12,7 +12,8 @@ use derive_new::new;
 use regex::Regex;
 use serde::{
     de::{Error, SeqAccess, Visitor},
-    Deserialize, Deserializer,
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
 };
 
 use crate::{config::StrOrInt, utils::hex_or_base58_or_bech32_to_h256, HyperlaneMessage, H256};
@@ -243,6 +244,39 @@ impl<'de> Deserialize<'de> for Filter<H256> {
     }
 }
 
+impl Serialize for MatchingList {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(s)
+    }
+}
+
+impl Serialize for Filter<u32> {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Filter::Wildcard => s.serialize_str("*"),
+            Filter::Enumerated(values) => values.serialize(s),
+        }
+    }
+}
+
+impl Serialize for Filter<H256> {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Filter::Wildcard => s.serialize_str("*"),
+            Filter::Enumerated(values) => s.collect_seq(values.iter().map(|v| format!("{v:?}"))),
+        }
+    }
+}
+
 /// Wrapper around Regex so we can impl traits for it
 #[derive(Clone, Debug)]
 pub struct RegexWrapper(pub Regex);
@@ -256,6 +290,15 @@ impl<'de> Deserialize<'de> for RegexWrapper {
     }
 }
 
+impl Serialize for RegexWrapper {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(self.0.as_str())
+    }
+}
+
 impl PartialEq for RegexWrapper {
     fn eq(&self, other: &Self) -> bool {
         self.0.as_str() == other.0.as_str()
@@ -280,6 +323,35 @@ pub struct ListElement {
     body_regex: Option<RegexWrapper>,
 }
 
+/// Serialized in the same flat shape it is deserialized from, wildcards are omitted
+impl Serialize for ListElement {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = s.serialize_map(None)?;
+        if self.message_id != Filter::Wildcard {
+            map.serialize_entry("messageid", &self.message_id)?;
+        }
+        if self.origin_domain != Filter::Wildcard {
+            map.serialize_entry("origindomain", &self.origin_domain)?;
+        }
+        if self.sender_address != Filter::Wildcard {
+            map.serialize_entry("senderaddress", &self.sender_address)?;
+        }
+        if self.destination_domain != Filter::Wildcard {
+            map.serialize_entry("destinationdomain", &self.destination_domain)?;
+        }
+        if self.recipient_address != Filter::Wildcard {
+            map.serialize_entry("recipientaddress", &self.recipient_address)?;
+        }
+        if let Some(body_regex) = &self.body_regex {
+            map.serialize_entry("bodyregex", body_regex)?;
+        }
+        map.end()
+    }
+}
+
 impl Display for ListElement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
@@ -534,6 +606,25 @@ mod test {
         assert!(whitelist.0.is_none());
     }
 
+    #[test]
+    fn serialization_round_trip() {
+        let list: MatchingList = serde_json::from_str(r#"[{"origindomain": [1, "0x2"], "senderaddress": "0x9d4454B023096f34B160D6B654540c56A1F81688", "bodyregex": "^0x00"}, {}]"#).unwrap();
+        let serialized = serde_json::to_value(&list).unwrap();
+        assert_eq!(
+            serialized,
+            serde_json::json!([
+                {
+                    "origindomain": [1, 2],
+                    "senderaddress": ["0x0000000000000000000000009d4454b023096f34b160d6b654540c56a1f81688"],
+                    "bodyregex": "^0x00"
+                },
+                {}
+            ])
+        );
+        let deserialized: MatchingList = serde_json::from_value(serialized).unwrap();
+        assert_eq!(deserialized.0, list.0);
+    }
+
     #[test]
     fn matches_empty_list() {
         let info = MatchInfo {
@@ -2,13 +2,27 @@ use std::fmt::Debug;
 
 use async_trait::async_trait;
 use auto_impl::auto_impl;
+use serde::{Deserialize, Serialize};
 
 use crate::{ChainResult, HyperlaneContract};
 
+/// A VM-neutral description of how to query for offchain information
+/// needed to build the metadata of a CcipReadIsm.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OffchainLookup {
+    /// The address of the ISM requesting the lookup, in the native
+    /// byte width of the destination chain (e.g. 20 bytes on EVM chains)
+    pub sender: Vec<u8>,
+    /// The urls of the offchain lookup servers, tried in order
+    pub urls: Vec<String>,
+    /// The data forwarded to the offchain lookup server
+    pub call_data: Vec<u8>,
+}
+
 /// Interface for the CcipReadIsm chain contract
 #[async_trait]
 #[auto_impl(&, Box, Arc)]
 pub trait CcipReadIsm: HyperlaneContract + Send + Sync + Debug {
-    /// Reverts with a custom error specifying how to query for offchain information
-    async fn get_offchain_verify_info(&self, message: Vec<u8>) -> ChainResult<()>;
+    /// Returns how to query for offchain information to verify the message
+    async fn get_offchain_verify_info(&self, message: Vec<u8>) -> ChainResult<OffchainLookup>;
 }
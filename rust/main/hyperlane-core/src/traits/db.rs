@@ -4,7 +4,7 @@ use async_trait::async_trait;
 use auto_impl::auto_impl;
 use eyre::Result;
 
-use crate::{Indexed, LogMeta};
+use crate::{Indexed, LogMeta, ReorgRewind};
 
 /// Interface for a HyperlaneLogStore that ingests logs.
 #[async_trait]
@@ -32,6 +32,12 @@ pub trait HyperlaneSequenceAwareIndexerStoreReader<T>: Send + Sync + Debug {
 
     /// Gets the block number at which the log occurred.
     async fn retrieve_log_block_number_by_sequence(&self, sequence: u32) -> Result<Option<u64>>;
+
+    /// Gets the latest point cursors must rewind to because indexed logs were
+    /// pruned after a reorg.
+    async fn retrieve_reorg_rewind(&self) -> Result<Option<ReorgRewind>> {
+        Ok(None)
+    }
 }
 
 /// Extension of HyperlaneLogStore trait for sequence-aware indexer stores.
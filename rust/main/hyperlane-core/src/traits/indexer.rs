@@ -22,6 +22,16 @@ pub enum IndexMode {
     Block,
     /// Sequence based indexing.
     Sequence,
+    /// Logs are pushed by the node as they are emitted, with block based
+    /// indexing filling any gaps, e.g. while the subscription reconnects.
+    Subscription,
+}
+
+/// A stream of logs pushed by the node as they are emitted
+#[async_trait]
+pub trait LogSubscription<T>: Send {
+    /// Wait for the next log. Returns `None` once the subscription has ended.
+    async fn next_log(&mut self) -> Option<ChainResult<(Indexed<T>, LogMeta)>>;
 }
 
 /// Interface for an indexer.
@@ -44,6 +54,12 @@ pub trait Indexer<T: Sized>: Send + Sync + Debug {
     ) -> ChainResult<Vec<(Indexed<T>, LogMeta)>> {
         Ok(vec![])
     }
+
+    /// Subscribe to logs as they are emitted. Only logs that reached finality are
+    /// delivered. Returns `None` if the indexer isn't configured for subscriptions.
+    async fn subscribe_logs(&self) -> ChainResult<Option<Box<dyn LogSubscription<T>>>> {
+        Ok(None)
+    }
 }
 
 /// Interface for indexing data in sequence.
@@ -50,3 +50,12 @@ impl Signable for Announcement {
 
 /// An announcement that has been signed.
 pub type SignedAnnouncement = SignedType<Announcement>;
+
+/// A storage location announcement made on the ValidatorAnnounce contract
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ValidatorAnnouncement {
+    /// The address of the announcing validator
+    pub validator: H256,
+    /// The announced location of signed checkpoints
+    pub storage_location: String,
+}
@@ -1,6 +1,9 @@
 use derive_new::new;
 
-use crate::{HyperlaneMessage, InterchainGasPayment, MerkleTreeInsertion, Sequenced, H256};
+use crate::{
+    HyperlaneMessage, InterchainGasPayment, MerkleTreeInsertion, Sequenced, ValidatorAnnouncement,
+    H256,
+};
 
 /// Wrapper struct that adds indexing information to a type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, new)]
@@ -85,3 +88,9 @@ impl From<InterchainGasPayment> for Indexed<InterchainGasPayment> {
         Indexed::new(value)
     }
 }
+
+impl From<ValidatorAnnouncement> for Indexed<ValidatorAnnouncement> {
+    fn from(value: ValidatorAnnouncement) -> Self {
+        Indexed::new(value)
+    }
+}
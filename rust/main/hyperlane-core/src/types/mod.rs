@@ -214,7 +214,7 @@ impl Add for InterchainGasExpenditure {
 }
 
 /// Uniquely identifying metadata for an InterchainGasPayment
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct InterchainGasPaymentMeta {
     /// The transaction id/hash in which the GasPayment log was emitted
     pub transaction_id: H512,
@@ -1,7 +1,9 @@
+use std::io::{Read, Write};
+
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 
-use crate::{ReorgPeriod, H256};
+use crate::{Decode, Encode, HyperlaneProtocolError, ReorgPeriod, H256};
 
 /// A response about a chain reorg, from an agent's perspective
 #[derive(Debug, Clone, PartialEq)]
@@ -29,3 +31,54 @@ pub struct ReorgEvent {
     /// the reorg period configured for the agent
     pub reorg_period: ReorgPeriod,
 }
+
+/// Where sequence-aware cursors resume indexing after logs indexed from
+/// reorged blocks were pruned
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, new, PartialEq, Eq)]
+pub struct ReorgRewind {
+    /// Incremented by every pruning, so cursors apply each rewind once
+    pub id: u64,
+    /// The lowest pruned sequence, which is indexed again
+    pub sequence: u32,
+    /// The highest block that wasn't reorged
+    pub block_number: u64,
+}
+
+impl Encode for ReorgRewind {
+    fn write_to<W>(&self, writer: &mut W) -> std::io::Result<usize>
+    where
+        W: Write,
+    {
+        let mut written: usize = 0;
+        written = written.saturating_add(self.id.write_to(writer)?);
+        written = written.saturating_add(self.sequence.write_to(writer)?);
+        written = written.saturating_add(self.block_number.write_to(writer)?);
+        Ok(written)
+    }
+}
+
+impl Decode for ReorgRewind {
+    fn read_from<R>(reader: &mut R) -> Result<Self, HyperlaneProtocolError>
+    where
+        R: Read,
+        Self: Sized,
+    {
+        Ok(Self {
+            id: u64::read_from(reader)?,
+            sequence: u32::read_from(reader)?,
+            block_number: u64::read_from(reader)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reorg_rewind_encoding() {
+        let rewind = ReorgRewind::new(3, 42, 1_000);
+        let decoded = ReorgRewind::read_from(&mut rewind.to_vec().as_slice()).unwrap();
+        assert_eq!(rewind, decoded);
+    }
+}
@@ -1,9 +1,12 @@
 #[cfg(feature = "aleo")]
 pub use aleo::AleoTxPrecursor;
+pub use cosmos::CosmWasmTxPrecursor;
+pub use cosmos_native::CosmosNativeTxPrecursor;
 pub use ethereum::EthereumTxPrecursor;
 pub use factory::AdapterFactory;
 pub use radix::RadixTxPrecursor;
 pub use sealevel::SealevelTxPrecursor;
+pub use starknet::StarknetTxPrecursor;
 pub use tron::TronTxPrecursor;
 
 mod factory;
@@ -11,10 +14,12 @@ mod factory;
 // chains modules below
 #[cfg(feature = "aleo")]
 mod aleo;
-mod cosmos;
+pub mod cosmos;
+pub mod cosmos_native;
 pub mod ethereum;
 pub mod radix;
 pub mod sealevel;
+pub mod starknet;
 pub mod tron;
 
 #[cfg(all(test, feature = "aleo"))]
@@ -0,0 +1,228 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use tracing::{debug, warn};
+
+use hyperlane_base::{settings::ChainConf, CoreMetrics};
+use hyperlane_core::{ChainCommunicationError, H512, U256};
+use hyperlane_cosmos::{cw::CwQueryClient, CosmWasmTxCalldata, CosmosProviderForLander};
+
+use crate::adapter::chains::cosmos::{
+    conf::create_provider, precursor::CosmosCalldata, sequence::SequenceManager,
+    submit::submit_transaction, CosmosTxPrecursor,
+};
+use crate::{
+    adapter::{AdaptsChain, GasLimit, TxBuildingResult},
+    payload::PayloadDetails,
+    transaction::Transaction,
+    DispatcherMetrics, FullPayload, LanderError, TransactionStatus,
+};
+
+/// Adapter for Cosmos SDK chains, generic over the provider which builds the
+/// messages of the chain's Hyperlane module.
+pub struct CosmosSdkAdapter<P: ?Sized> {
+    pub provider: Arc<P>,
+    pub sequences: SequenceManager,
+    pub estimated_block_time: Duration,
+}
+
+/// Adapter for chains running the CosmWasm Hyperlane contracts
+pub type CosmosAdapter = CosmosSdkAdapter<
+    dyn CosmosProviderForLander<
+        Calldata = CosmWasmTxCalldata,
+        DeliveredCalldata = CosmWasmTxCalldata,
+    >,
+>;
+
+impl<P: ?Sized> CosmosSdkAdapter<P> {
+    pub fn new(provider: Arc<P>, estimated_block_time: Duration) -> Self {
+        Self {
+            provider,
+            sequences: SequenceManager::default(),
+            estimated_block_time,
+        }
+    }
+}
+
+impl CosmosAdapter {
+    pub async fn from_conf(
+        conf: &ChainConf,
+        metrics: &CoreMetrics,
+        connection_conf: &hyperlane_cosmos::ConnectionConf,
+    ) -> Result<Self, LanderError> {
+        let provider = create_provider::<CwQueryClient>(conf, metrics, connection_conf).await?;
+        Ok(Self::new(Arc::new(provider), conf.estimated_block_time))
+    }
+}
+
+#[async_trait]
+impl<P> AdaptsChain for CosmosSdkAdapter<P>
+where
+    P: CosmosProviderForLander + ?Sized,
+    P::Calldata: CosmosCalldata,
+    P::DeliveredCalldata: DeserializeOwned,
+{
+    /// Simulates Payload and returns its gas limit. Called in the Building Stage (PayloadDispatcher)
+    async fn estimate_gas_limit(
+        &self,
+        payload: &FullPayload,
+    ) -> Result<Option<GasLimit>, LanderError> {
+        let precursor = CosmosTxPrecursor::<P::Calldata>::from_data(&payload.data)?;
+        let gas_limit = self.provider.simulate(&precursor.calldata).await?;
+        Ok(Some(U256::from(gas_limit)))
+    }
+
+    /// Performs batching if available. Internally estimates gas limit for batch as well. Called in the Building Stage (PayloadDispatcher)
+    async fn build_transactions(&self, payloads: &[FullPayload]) -> Vec<TxBuildingResult> {
+        // we only support single payload transactions for now
+        payloads
+            .iter()
+            .map(|payload| {
+                let tx = CosmosTxPrecursor::<P::Calldata>::from_data(&payload.data)
+                    .map(|precursor| Transaction::new(precursor, vec![payload.details.clone()]))
+                    .ok();
+                TxBuildingResult {
+                    payloads: vec![payload.details.clone()],
+                    maybe_tx: tx,
+                }
+            })
+            .collect::<Vec<_>>()
+    }
+
+    /// Simulates a Transaction before submitting it for the first time. Called in the Inclusion Stage (PayloadDispatcher)
+    /// The gas estimate of the simulation is kept, so that `estimate_tx` does not simulate again.
+    /// Payloads are only reported as reverted if executing them failed, not if the chain couldn't be reached.
+    async fn simulate_tx(&self, tx: &mut Transaction) -> Result<Vec<PayloadDetails>, LanderError> {
+        let precursor = P::Calldata::precursor_mut(tx);
+        match self.provider.simulate(&precursor.calldata).await {
+            Ok(gas_limit) => {
+                precursor.gas_limit = Some(gas_limit);
+                Ok(vec![])
+            }
+            // a transaction only carries a single payload, so it has reverted
+            Err(err @ ChainCommunicationError::ContractError(_)) => {
+                warn!(?err, "Cosmos transaction reverted in simulation");
+                Ok(tx.payload_details.clone())
+            }
+            Err(err) => {
+                warn!(?err, "failed to simulate Cosmos transaction");
+                Err(LanderError::SimulationFailed(vec![err.to_string()]))
+            }
+        }
+    }
+
+    /// Estimates a Transaction's gas limit. Called in the Inclusion Stage (PayloadDispatcher)
+    /// Skips estimation if the Transaction has already been estimated
+    async fn estimate_tx(&self, tx: &mut Transaction) -> Result<(), LanderError> {
+        let precursor = P::Calldata::precursor_mut(tx);
+        if precursor.gas_limit.is_some() {
+            debug!(
+                ?tx,
+                "skipping gas limit estimation for transaction, as it was already estimated"
+            );
+            return Ok(());
+        }
+
+        let gas_limit = self
+            .provider
+            .simulate(&precursor.calldata)
+            .await
+            .map_err(|_| LanderError::EstimationFailed)?;
+        precursor.gas_limit = Some(gas_limit);
+
+        Ok(())
+    }
+
+    /// Sets / escalates the fee, sets sequence / timeout height and broadcasts the Transaction. Even if broadcasting fails, the Transaction struct remains mutated with the new values. Called in the Inclusion Stage (PayloadDispatcher)
+    async fn submit(&self, tx: &mut Transaction) -> Result<(), LanderError> {
+        if !tx.tx_hashes.is_empty() {
+            // A resubmitted transaction has timed out, so the sequences reserved
+            // after it were never consumed either
+            self.sequences.reset().await;
+        }
+        submit_transaction(&*self.provider, &self.sequences, tx).await
+    }
+
+    /// CometBFT has instant finality, so an included transaction is final.
+    async fn get_tx_hash_status(&self, hash: H512) -> Result<TransactionStatus, LanderError> {
+        let result = self
+            .provider
+            .get_tx(&hash)
+            .await
+            .map_err(|err| LanderError::TxHashNotFound(err.to_string()))?;
+
+        debug!(?hash, ?result, "tx result");
+        if !result.success {
+            // the payloads of the transaction are reported as reverted in the Finality Stage
+            warn!(
+                ?hash,
+                log = result.log,
+                "Cosmos transaction failed on chain"
+            );
+        }
+        Ok(TransactionStatus::Finalized)
+    }
+
+    /// Returns the payloads which were not delivered by a finalized Transaction.
+    /// Called in the Finality Stage (PayloadDispatcher).
+    async fn reverted_payloads(
+        &self,
+        tx: &Transaction,
+    ) -> Result<Vec<PayloadDetails>, LanderError> {
+        let payloads = tx
+            .payload_details
+            .iter()
+            .filter_map(|x| x.success_criteria.clone().map(|data| (data, x)));
+
+        let mut reverted = Vec::new();
+
+        for (data, payload) in payloads {
+            let calldata = serde_json::from_slice::<P::DeliveredCalldata>(&data)
+                .map_err(|err| eyre::eyre!("Failed to deserialize success criteria: {err}"))?;
+            let delivered = self.provider.check_delivered(&calldata).await?;
+            if !delivered {
+                reverted.push(payload.clone());
+            }
+        }
+
+        Ok(reverted)
+    }
+
+    /// Return true if the transaction can be resubmitted. Called in the Inclusion Stage (PayloadDispatcher).
+    /// A submitted transaction can only be resubmitted once its timeout height has passed,
+    /// since until then it may still be included with its current sequence.
+    async fn tx_ready_for_resubmission(&self, tx: &Transaction) -> bool {
+        let Some(timeout_height) = P::Calldata::precursor(tx).timeout_height else {
+            return true;
+        };
+
+        match self.provider.get_block_number().await {
+            Ok(block_number) => block_number > timeout_height,
+            Err(err) => {
+                warn!(?err, "failed to get block number, not resubmitting tx");
+                false
+            }
+        }
+    }
+
+    /// Transactions are tied to a sequence only until they time out, so gaps fill themselves.
+    async fn nonce_gap_exists(&self) -> bool {
+        false
+    }
+
+    async fn replace_tx(&self, _tx: &Transaction) -> Result<(), LanderError> {
+        Ok(())
+    }
+
+    /// Returns the estimated block time of the chain. Used for polling pending transactions. Called in the Inclusion and Finality Stages of the PayloadDispatcher
+    fn estimated_block_time(&self) -> &Duration {
+        &self.estimated_block_time
+    }
+
+    /// Update any metrics related to sent transactions, such as gas price, nonce, etc.
+    fn update_vm_specific_metrics(&self, _tx: &Transaction, _metrics: &DispatcherMetrics) {}
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,227 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use hyperlane_core::{H512, U256};
+use hyperlane_cosmos::CosmosTxResult;
+
+use crate::adapter::chains::cosmos::{CosmWasmTxPrecursor, CosmosAdapter, Precursor};
+use crate::adapter::AdaptsChain;
+use crate::payload::{FullPayload, PayloadDetails};
+use crate::tests::cosmos::MockCosmosProvider;
+use crate::transaction::{Transaction, TransactionStatus};
+use crate::LanderError;
+
+const CONTRACT_ADDRESS: &str = "neutron1sjzzd4gwkggy6hrrs8kxxatexzcuz3jecsxm3wqgregkulzj8r7qlnuef4";
+
+fn adapter(provider: MockCosmosProvider) -> CosmosAdapter {
+    CosmosAdapter {
+        provider: Arc::new(provider),
+        sequences: Default::default(),
+        estimated_block_time: Duration::from_secs(6),
+    }
+}
+
+fn calldata() -> hyperlane_cosmos::CosmWasmTxCalldata {
+    hyperlane_cosmos::CosmWasmTxCalldata {
+        contract_address: CONTRACT_ADDRESS.to_string(),
+        msg: br#"{"process":{"metadata":"","message":""}}"#.to_vec(),
+    }
+}
+
+fn payload() -> FullPayload {
+    FullPayload {
+        data: serde_json::to_vec(&calldata()).unwrap(),
+        details: PayloadDetails {
+            success_criteria: Some(serde_json::to_vec(&calldata()).unwrap()),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn transaction() -> Transaction {
+    let payload = payload();
+    Transaction::new(
+        CosmWasmTxPrecursor::new(calldata()),
+        vec![payload.details.clone()],
+    )
+}
+
+#[tokio::test]
+async fn test_build_transactions() {
+    let adapter = adapter(MockCosmosProvider::new());
+    let valid = payload();
+    let invalid = FullPayload {
+        data: b"not calldata".to_vec(),
+        ..Default::default()
+    };
+
+    let results = adapter
+        .build_transactions(&[valid.clone(), invalid.clone()])
+        .await;
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].payloads, vec![valid.details.clone()]);
+    let tx = results[0].maybe_tx.as_ref().expect("tx should be built");
+    assert_eq!(tx.precursor(), &CosmWasmTxPrecursor::new(calldata()));
+    assert_eq!(results[1].payloads, vec![invalid.details.clone()]);
+    assert!(results[1].maybe_tx.is_none());
+}
+
+#[tokio::test]
+async fn test_estimate_gas_limit() {
+    let adapter = adapter(MockCosmosProvider::new().with_gas_estimate(123_456));
+
+    let gas_limit = adapter.estimate_gas_limit(&payload()).await.unwrap();
+
+    assert_eq!(gas_limit, Some(U256::from(123_456)));
+}
+
+#[tokio::test]
+async fn test_simulate_tx_sets_gas_limit() {
+    let adapter = adapter(MockCosmosProvider::new().with_gas_estimate(90_000));
+    let mut tx = transaction();
+
+    let reverted = adapter.simulate_tx(&mut tx).await.unwrap();
+
+    assert!(reverted.is_empty());
+    assert_eq!(tx.precursor().gas_limit, Some(90_000));
+}
+
+#[tokio::test]
+async fn test_simulate_tx_revert_reverts_payloads() {
+    let adapter =
+        adapter(MockCosmosProvider::new().with_simulation_revert("message already delivered"));
+    let mut tx = transaction();
+
+    let reverted = adapter.simulate_tx(&mut tx).await.unwrap();
+
+    assert_eq!(reverted, tx.payload_details);
+    assert_eq!(tx.precursor().gas_limit, None);
+}
+
+#[tokio::test]
+async fn test_simulate_tx_network_failure_keeps_payloads() {
+    let adapter = adapter(MockCosmosProvider::new().with_simulation_error("request timed out"));
+    let mut tx = transaction();
+
+    let result = adapter.simulate_tx(&mut tx).await;
+
+    assert!(matches!(result, Err(LanderError::SimulationFailed(_))));
+    assert_eq!(tx.precursor().gas_limit, None);
+}
+
+#[tokio::test]
+async fn test_estimate_tx_skips_estimated_tx() {
+    let adapter = adapter(MockCosmosProvider::new().with_simulation_error("must not simulate"));
+    let mut tx = transaction();
+    tx.precursor_mut().gas_limit = Some(50_000);
+
+    adapter.estimate_tx(&mut tx).await.unwrap();
+
+    assert_eq!(tx.precursor().gas_limit, Some(50_000));
+}
+
+#[tokio::test]
+async fn test_estimate_tx_failure() {
+    let adapter = adapter(MockCosmosProvider::new().with_simulation_error("out of gas"));
+    let mut tx = transaction();
+
+    let result = adapter.estimate_tx(&mut tx).await;
+
+    assert!(matches!(result, Err(LanderError::EstimationFailed)));
+}
+
+#[tokio::test]
+async fn test_get_tx_hash_status() {
+    let included = H512::random();
+    let failed = H512::random();
+    let provider = MockCosmosProvider::new()
+        .with_included_tx(
+            included,
+            CosmosTxResult {
+                height: 10,
+                success: true,
+                log: String::new(),
+            },
+        )
+        .with_included_tx(
+            failed,
+            CosmosTxResult {
+                height: 10,
+                success: false,
+                log: "out of gas".to_string(),
+            },
+        );
+    let adapter = adapter(provider);
+
+    // CometBFT has instant finality, failed transactions are handled by the finality stage
+    assert_eq!(
+        adapter.get_tx_hash_status(included).await.unwrap(),
+        TransactionStatus::Finalized
+    );
+    assert_eq!(
+        adapter.get_tx_hash_status(failed).await.unwrap(),
+        TransactionStatus::Finalized
+    );
+    assert!(matches!(
+        adapter.get_tx_hash_status(H512::random()).await,
+        Err(LanderError::TxHashNotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_tx_ready_for_resubmission() {
+    let provider = MockCosmosProvider::new().with_block_number(100);
+    let adapter = adapter(provider.clone());
+    let mut tx = transaction();
+
+    // never submitted
+    assert!(adapter.tx_ready_for_resubmission(&tx).await);
+
+    tx.precursor_mut().timeout_height = Some(100);
+    assert!(!adapter.tx_ready_for_resubmission(&tx).await);
+
+    provider.set_block_number(101);
+    assert!(adapter.tx_ready_for_resubmission(&tx).await);
+}
+
+#[tokio::test]
+async fn test_reverted_payloads() {
+    let tx = transaction();
+
+    let delivered = adapter(MockCosmosProvider::new().with_delivered(true));
+    assert!(delivered.reverted_payloads(&tx).await.unwrap().is_empty());
+
+    let not_delivered = adapter(MockCosmosProvider::new().with_delivered(false));
+    assert_eq!(
+        not_delivered.reverted_payloads(&tx).await.unwrap(),
+        tx.payload_details
+    );
+
+    let failing = adapter(MockCosmosProvider::new().with_delivered_error("connection refused"));
+    assert!(failing.reverted_payloads(&tx).await.is_err());
+}
+
+#[tokio::test]
+async fn test_submit_resets_sequence_on_resubmission() {
+    let provider = MockCosmosProvider::new()
+        .with_sequence(4)
+        .without_inclusion();
+    let adapter = adapter(provider.clone());
+
+    let mut first = transaction();
+    first.precursor_mut().gas_limit = Some(100_000);
+    adapter.submit(&mut first).await.unwrap();
+
+    // the first tx timed out, so its sequence is used again
+    adapter.submit(&mut first).await.unwrap();
+
+    let sequences = provider
+        .broadcasts()
+        .iter()
+        .map(|broadcast| broadcast.sequence)
+        .collect::<Vec<_>>();
+    assert_eq!(sequences, vec![4, 4]);
+    assert_eq!(first.tx_hashes.len(), 2);
+}
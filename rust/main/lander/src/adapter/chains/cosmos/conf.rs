@@ -0,0 +1,43 @@
+use eyre::ContextCompat;
+
+use hyperlane_base::{
+    settings::{BuildableWithSignerConf, ChainConf},
+    CoreMetrics,
+};
+use hyperlane_core::{ContractLocator, H256};
+use hyperlane_cosmos::{BuildableQueryClient, ConnectionConf, CosmosProvider, Signer};
+
+use crate::LanderError;
+
+pub async fn create_signer(conf: &ChainConf) -> eyre::Result<Signer> {
+    let signer_conf = conf.signer.as_ref().wrap_err("Signer is missing")?;
+    signer_conf.build::<Signer>().await
+}
+
+/// Creates a provider which signs with the signer of the chain, querying the
+/// Hyperlane module of the chain with `Q`
+pub async fn create_provider<Q: BuildableQueryClient>(
+    conf: &ChainConf,
+    metrics: &CoreMetrics,
+    connection_conf: &ConnectionConf,
+) -> Result<CosmosProvider<Q>, LanderError> {
+    // We must have a signer if we want to land transactions.
+    let signer = create_signer(conf).await?;
+
+    let locator = ContractLocator {
+        domain: &conf.domain,
+        address: H256::zero(),
+    };
+
+    let chain_info = conf.metrics_conf().chain;
+    let client_metrics = metrics.client_metrics();
+
+    let provider = CosmosProvider::<Q>::new(
+        connection_conf,
+        &locator,
+        Some(signer),
+        client_metrics,
+        chain_info,
+    )?;
+    Ok(provider)
+}
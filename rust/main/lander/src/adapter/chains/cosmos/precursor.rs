@@ -0,0 +1,96 @@
+use std::fmt::Debug;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use hyperlane_cosmos::CosmWasmTxCalldata;
+
+use crate::transaction::{Transaction, VmSpecificTxData};
+use crate::LanderError;
+
+/// Precursor of a transaction landed on a Cosmos SDK chain
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CosmosTxPrecursor<C> {
+    /// Calldata of the transaction
+    pub calldata: C,
+    /// Gas limit obtained by simulating the transaction
+    pub gas_limit: Option<u64>,
+    /// Fee paid by the last submission, in the canonical asset
+    pub fee_amount: Option<u128>,
+    /// Account sequence used by the last submission
+    pub sequence: Option<u64>,
+    /// Height after which the last submission can no longer be included
+    pub timeout_height: Option<u64>,
+}
+
+/// Precursor of a transaction executing a CosmWasm contract
+pub type CosmWasmTxPrecursor = CosmosTxPrecursor<CosmWasmTxCalldata>;
+
+impl<C: CosmosCalldata> CosmosTxPrecursor<C> {
+    pub fn new(calldata: C) -> Self {
+        Self {
+            calldata,
+            gas_limit: None,
+            fee_amount: None,
+            sequence: None,
+            timeout_height: None,
+        }
+    }
+
+    pub fn from_data(data: &[u8]) -> Result<Self, LanderError> {
+        let calldata = serde_json::from_slice::<C>(data)
+            .map_err(|err| eyre::eyre!("Failed to deserialize calldata from data: {err}"))?;
+        Ok(Self::new(calldata))
+    }
+}
+
+impl<C: CosmosCalldata> From<CosmosTxPrecursor<C>> for VmSpecificTxData {
+    fn from(value: CosmosTxPrecursor<C>) -> Self {
+        C::into_vm_specific_data(value)
+    }
+}
+
+/// Calldata of the transactions landed on a Cosmos SDK chain.
+/// Ties the precursor of the calldata to its variant of `VmSpecificTxData`.
+pub trait CosmosCalldata:
+    Clone + Debug + PartialEq + Serialize + DeserializeOwned + Send + Sync + 'static
+{
+    fn into_vm_specific_data(precursor: CosmosTxPrecursor<Self>) -> VmSpecificTxData;
+    fn precursor(tx: &Transaction) -> &CosmosTxPrecursor<Self>;
+    fn precursor_mut(tx: &mut Transaction) -> &mut CosmosTxPrecursor<Self>;
+}
+
+#[allow(clippy::panic)]
+impl CosmosCalldata for CosmWasmTxCalldata {
+    fn into_vm_specific_data(precursor: CosmosTxPrecursor<Self>) -> VmSpecificTxData {
+        VmSpecificTxData::CosmWasm(Box::new(precursor))
+    }
+
+    fn precursor(tx: &Transaction) -> &CosmosTxPrecursor<Self> {
+        match &tx.vm_specific_data {
+            VmSpecificTxData::CosmWasm(precursor) => precursor,
+            _ => panic!(),
+        }
+    }
+
+    fn precursor_mut(tx: &mut Transaction) -> &mut CosmosTxPrecursor<Self> {
+        match &mut tx.vm_specific_data {
+            VmSpecificTxData::CosmWasm(precursor) => precursor,
+            _ => panic!(),
+        }
+    }
+}
+
+pub trait Precursor {
+    fn precursor(&self) -> &CosmWasmTxPrecursor;
+    fn precursor_mut(&mut self) -> &mut CosmWasmTxPrecursor;
+}
+
+impl Precursor for Transaction {
+    fn precursor(&self) -> &CosmWasmTxPrecursor {
+        CosmWasmTxCalldata::precursor(self)
+    }
+
+    fn precursor_mut(&mut self) -> &mut CosmWasmTxPrecursor {
+        CosmWasmTxCalldata::precursor_mut(self)
+    }
+}
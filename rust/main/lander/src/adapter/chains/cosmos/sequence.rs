@@ -0,0 +1,33 @@
+use tokio::sync::Mutex;
+
+/// Hands out account sequences for the signer of the adapter.
+///
+/// The on-chain sequence only moves once a transaction is included in a block,
+/// so sequences are reserved locally to allow several transactions to sit in
+/// the mempool at the same time. The local state is dropped whenever a
+/// submission shows that it no longer matches the chain.
+#[derive(Debug, Default)]
+pub struct SequenceManager {
+    next: Mutex<Option<u64>>,
+}
+
+impl SequenceManager {
+    /// Reserves the next sequence, never going below the on-chain sequence
+    pub async fn reserve(&self, onchain: u64) -> u64 {
+        let mut next = self.next.lock().await;
+
+        let sequence = match *next {
+            Some(local) if local > onchain => local,
+            _ => onchain,
+        };
+        *next = Some(sequence.saturating_add(1));
+
+        sequence
+    }
+
+    /// Forgets locally reserved sequences, so that the next reservation
+    /// starts from the on-chain sequence again
+    pub async fn reset(&self) {
+        *self.next.lock().await = None;
+    }
+}
@@ -0,0 +1,137 @@
+use tracing::{info, warn};
+
+use hyperlane_core::ChainCommunicationError;
+use hyperlane_cosmos::CosmosProviderForLander;
+
+use crate::adapter::chains::cosmos::{sequence::SequenceManager, CosmosCalldata};
+use crate::transaction::Transaction;
+use crate::LanderError;
+
+/// Number of blocks after which a submitted transaction can no longer be
+/// included. Once passed, the transaction is resubmitted with a higher fee.
+pub const TX_TIMEOUT_BLOCKS: u64 = 30;
+
+/// Each resubmission pays this many tenths of the previous fee
+const FEE_ESCALATION_TENTHS: u128 = 11;
+
+/// Escalated fees never exceed the base fee times this multiplier
+const MAX_FEE_MULTIPLIER: u128 = 3;
+
+/// Classifies CosmWasm transaction submission errors into appropriate LanderError variants
+///
+/// Based on the error codes of the Cosmos SDK:
+/// https://github.com/cosmos/cosmos-sdk/blob/main/types/errors/errors.go
+pub fn classify_cosmos_error(err: ChainCommunicationError) -> LanderError {
+    let err_str = err.to_string();
+
+    if err_str.contains("tx already exists in cache") {
+        return LanderError::TxAlreadyExists;
+    }
+
+    // Check for retryable errors (temporary conditions)
+    if err_str.contains("account sequence mismatch")
+        || err_str.contains("insufficient fee")
+        || err_str.contains("mempool is full")
+    {
+        return LanderError::TxSubmissionError(err_str);
+    }
+
+    // Check for non-retryable errors (permanent failures)
+    if err_str.contains("signature verification failed")
+        || err_str.contains("unauthorized")
+        || err_str.contains("tx too large")
+    {
+        return LanderError::NonRetryableError(err_str);
+    }
+
+    // Default: convert to ChainCommunicationError for generic handling
+    LanderError::ChainCommunicationError(err)
+}
+
+/// Returns the fee for the next submission of a transaction.
+///
+/// The first submission pays the base fee. Every resubmission pays 10% more than
+/// the previous one, but never less than the base fee nor more than
+/// `MAX_FEE_MULTIPLIER` times the base fee.
+pub fn escalate_fee(base_fee: u128, previous_fee: Option<u128>) -> u128 {
+    let Some(previous_fee) = previous_fee else {
+        return base_fee;
+    };
+
+    let escalated = previous_fee
+        .saturating_mul(FEE_ESCALATION_TENTHS)
+        .saturating_div(10);
+    let cap = base_fee.saturating_mul(MAX_FEE_MULTIPLIER);
+
+    escalated.max(base_fee).min(cap)
+}
+
+/// Sets the fee, sequence and timeout height of a Cosmos SDK transaction and broadcasts it.
+/// The transaction keeps the new values even if broadcasting fails.
+///
+/// CosmWasm and Cosmos Native chains run the same Cosmos SDK ante handlers,
+/// so fees, sequences and broadcast errors are handled the same way for both.
+pub async fn submit_transaction<P>(
+    provider: &P,
+    sequences: &SequenceManager,
+    tx: &mut Transaction,
+) -> Result<(), LanderError>
+where
+    P: CosmosProviderForLander + ?Sized,
+    P::Calldata: CosmosCalldata,
+{
+    let gas_limit = P::Calldata::precursor(tx)
+        .gas_limit
+        .ok_or(LanderError::EstimationFailed)?;
+
+    let base_fee = provider.fee_for_gas(gas_limit)?;
+    let sequence = sequences.reserve(provider.account_sequence().await?).await;
+    let block_number = provider.get_block_number().await?;
+
+    let fee_amount = escalate_fee(base_fee, P::Calldata::precursor(tx).fee_amount);
+    let timeout_height = block_number.saturating_add(TX_TIMEOUT_BLOCKS);
+
+    let precursor = P::Calldata::precursor_mut(tx);
+    precursor.fee_amount = Some(fee_amount);
+    precursor.sequence = Some(sequence);
+    precursor.timeout_height = Some(timeout_height);
+
+    info!(?tx, "submitting Cosmos transaction");
+
+    let result = provider
+        .broadcast(
+            &P::Calldata::precursor(tx).calldata,
+            gas_limit,
+            fee_amount,
+            sequence,
+            timeout_height,
+        )
+        .await;
+
+    let tx_hash = match result {
+        Ok(tx_hash) => tx_hash,
+        Err(err) => {
+            let err = classify_cosmos_error(err);
+            if !matches!(err, LanderError::TxAlreadyExists) {
+                // The reserved sequence was not consumed, so the local state
+                // can no longer be trusted
+                warn!(?err, tx_uuid=?tx.uuid, sequence, "failed to broadcast Cosmos transaction");
+                sequences.reset().await;
+            }
+            return Err(err);
+        }
+    };
+
+    tx.last_submission_attempt = Some(chrono::Utc::now());
+
+    // Store transaction hash
+    if !tx.tx_hashes.contains(&tx_hash.into()) {
+        tx.tx_hashes.push(tx_hash.into());
+    }
+
+    info!(tx_uuid=?tx.uuid, ?tx_hash, "submitted Cosmos transaction");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
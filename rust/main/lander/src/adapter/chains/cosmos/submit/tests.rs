@@ -0,0 +1,126 @@
+use hyperlane_core::ChainCommunicationError;
+
+use crate::adapter::chains::cosmos::{sequence::SequenceManager, CosmWasmTxPrecursor, Precursor};
+use crate::tests::cosmos::MockCosmosProvider;
+use crate::transaction::Transaction;
+use crate::LanderError;
+
+use super::{classify_cosmos_error, escalate_fee, submit_transaction, TX_TIMEOUT_BLOCKS};
+
+fn classify(message: &str) -> LanderError {
+    classify_cosmos_error(ChainCommunicationError::from_other_str(message))
+}
+
+fn estimated_tx(gas_limit: u64) -> Transaction {
+    let mut tx = Transaction::new(CosmWasmTxPrecursor::default(), vec![]);
+    tx.precursor_mut().gas_limit = Some(gas_limit);
+    tx
+}
+
+#[test]
+fn test_classify_retryable_errors() {
+    for message in [
+        "account sequence mismatch, expected 5, got 6: incorrect account sequence",
+        "insufficient fee; got: 100untrn required: 200untrn",
+        "mempool is full",
+    ] {
+        assert!(
+            matches!(classify(message), LanderError::TxSubmissionError(_)),
+            "{message} should be retryable"
+        );
+    }
+}
+
+#[test]
+fn test_classify_already_exists() {
+    assert!(matches!(
+        classify("tx already exists in cache"),
+        LanderError::TxAlreadyExists
+    ));
+}
+
+#[test]
+fn test_classify_non_retryable_errors() {
+    for message in [
+        "signature verification failed; please verify account number (1) and chain-id (neutron-1): unauthorized",
+        "tx too large",
+    ] {
+        assert!(
+            matches!(classify(message), LanderError::NonRetryableError(_)),
+            "{message} should not be retryable"
+        );
+    }
+}
+
+#[test]
+fn test_classify_unknown_error() {
+    assert!(matches!(
+        classify("connection reset by peer"),
+        LanderError::ChainCommunicationError(_)
+    ));
+}
+
+#[test]
+fn test_escalate_fee() {
+    // first submission pays the base fee
+    assert_eq!(escalate_fee(1_000, None), 1_000);
+    // resubmissions pay 10% more
+    assert_eq!(escalate_fee(1_000, Some(1_000)), 1_100);
+    assert_eq!(escalate_fee(1_000, Some(1_100)), 1_210);
+    // never less than the base fee, e.g. if the gas limit went up
+    assert_eq!(escalate_fee(2_000, Some(1_000)), 2_000);
+    // never more than three times the base fee
+    assert_eq!(escalate_fee(1_000, Some(2_900)), 3_000);
+    assert_eq!(escalate_fee(u128::MAX, Some(u128::MAX)), u128::MAX);
+}
+
+#[tokio::test]
+async fn test_submit_transaction() {
+    let provider = MockCosmosProvider::new()
+        .with_sequence(11)
+        .with_block_number(500);
+    let sequences = SequenceManager::default();
+    let mut tx = estimated_tx(100_000);
+
+    submit_transaction(&provider, &sequences, &mut tx)
+        .await
+        .unwrap();
+
+    let precursor = tx.precursor();
+    assert_eq!(precursor.fee_amount, Some(200_000));
+    assert_eq!(precursor.sequence, Some(11));
+    assert_eq!(precursor.timeout_height, Some(500 + TX_TIMEOUT_BLOCKS));
+    assert_eq!(tx.tx_hashes.len(), 1);
+    assert!(tx.last_submission_attempt.is_some());
+}
+
+#[tokio::test]
+async fn test_submit_transaction_requires_gas_limit() {
+    let provider = MockCosmosProvider::new();
+    let sequences = SequenceManager::default();
+    let mut tx = Transaction::new(CosmWasmTxPrecursor::default(), vec![]);
+
+    let result = submit_transaction(&provider, &sequences, &mut tx).await;
+
+    assert!(matches!(result, Err(LanderError::EstimationFailed)));
+    assert!(provider.broadcasts().is_empty());
+}
+
+#[tokio::test]
+async fn test_failed_submission_keeps_new_values_and_releases_sequence() {
+    let provider = MockCosmosProvider::new()
+        .with_sequence(3)
+        .with_broadcast_errors(&["mempool is full"]);
+    let sequences = SequenceManager::default();
+    let mut tx = estimated_tx(100_000);
+
+    let result = submit_transaction(&provider, &sequences, &mut tx).await;
+
+    assert!(matches!(result, Err(LanderError::TxSubmissionError(_))));
+    assert!(tx.tx_hashes.is_empty());
+    assert_eq!(tx.precursor().fee_amount, Some(200_000));
+    assert_eq!(tx.precursor().sequence, Some(3));
+
+    // the unused sequence is handed out again
+    assert_eq!(sequences.reserve(3).await, 3);
+}
@@ -0,0 +1,6 @@
+pub mod adapter;
+
+mod precursor;
+
+pub use adapter::CosmosNativeAdapter;
+pub use precursor::*;
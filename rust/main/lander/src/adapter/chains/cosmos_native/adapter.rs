@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use hyperlane_base::{settings::ChainConf, CoreMetrics};
+use hyperlane_cosmos::{
+    native::ModuleQueryClient, CosmosNativeDeliveredCalldata, CosmosNativeTxCalldata,
+    CosmosProviderForLander,
+};
+
+use crate::adapter::chains::cosmos::{conf::create_provider, CosmosSdkAdapter};
+use crate::LanderError;
+
+/// Adapter for chains running the Hyperlane Cosmos SDK module. Submission is shared
+/// with CosmWasm chains, only the messages and the delivery check differ.
+pub type CosmosNativeAdapter = CosmosSdkAdapter<
+    dyn CosmosProviderForLander<
+        Calldata = CosmosNativeTxCalldata,
+        DeliveredCalldata = CosmosNativeDeliveredCalldata,
+    >,
+>;
+
+impl CosmosNativeAdapter {
+    pub async fn from_conf(
+        conf: &ChainConf,
+        metrics: &CoreMetrics,
+        connection_conf: &hyperlane_cosmos::ConnectionConf,
+    ) -> Result<Self, LanderError> {
+        let provider = create_provider::<ModuleQueryClient>(conf, metrics, connection_conf).await?;
+        Ok(Self::new(Arc::new(provider), conf.estimated_block_time))
+    }
+}
+
+#[cfg(test)]
+mod tests;
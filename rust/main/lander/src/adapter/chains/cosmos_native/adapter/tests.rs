@@ -0,0 +1,272 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use hyperlane_core::{ChainCommunicationError, ChainResult, H256, H512, U256};
+use hyperlane_cosmos::{
+    CosmosNativeDeliveredCalldata, CosmosNativeTxCalldata, CosmosProviderForLander, CosmosTxResult,
+};
+
+use crate::adapter::chains::cosmos::SequenceManager;
+use crate::adapter::chains::cosmos_native::{
+    CosmosNativeAdapter, CosmosNativeTxPrecursor, Precursor,
+};
+use crate::adapter::AdaptsChain;
+use crate::payload::{FullPayload, PayloadDetails};
+use crate::transaction::{Transaction, TransactionStatus};
+use crate::LanderError;
+
+const GAS_PRICE: u128 = 3;
+
+mockall::mock! {
+    pub CosmosNativeProvider {}
+
+    #[async_trait::async_trait]
+    impl CosmosProviderForLander for CosmosNativeProvider {
+        type Calldata = CosmosNativeTxCalldata;
+        type DeliveredCalldata = CosmosNativeDeliveredCalldata;
+
+        async fn simulate(&self, calldata: &CosmosNativeTxCalldata) -> ChainResult<u64>;
+        fn fee_for_gas(&self, gas_limit: u64) -> ChainResult<u128>;
+        async fn account_sequence(&self) -> ChainResult<u64>;
+        async fn get_block_number(&self) -> ChainResult<u64>;
+        async fn broadcast(
+            &self,
+            calldata: &CosmosNativeTxCalldata,
+            gas_limit: u64,
+            fee_amount: u128,
+            sequence: u64,
+            timeout_height: u64,
+        ) -> ChainResult<H256>;
+        async fn get_tx(&self, hash: &H512) -> ChainResult<CosmosTxResult>;
+        async fn check_delivered(&self, calldata: &CosmosNativeDeliveredCalldata) -> ChainResult<bool>;
+    }
+}
+
+fn adapter(provider: MockCosmosNativeProvider) -> CosmosNativeAdapter {
+    CosmosNativeAdapter {
+        provider: Arc::new(provider),
+        sequences: SequenceManager::default(),
+        estimated_block_time: Duration::from_secs(5),
+    }
+}
+
+fn calldata() -> CosmosNativeTxCalldata {
+    CosmosNativeTxCalldata {
+        type_url: "/hyperlane.core.v1.MsgProcessMessage".to_string(),
+        value: vec![1, 2, 3],
+    }
+}
+
+fn delivered_calldata() -> CosmosNativeDeliveredCalldata {
+    CosmosNativeDeliveredCalldata {
+        mailbox_id: "0x68797065726c616e650000000000000000000000000000000000000000000000"
+            .to_string(),
+        message_id: "0x0000000000000000000000000000000000000000000000000000000000000001"
+            .to_string(),
+    }
+}
+
+fn payload() -> FullPayload {
+    FullPayload {
+        data: serde_json::to_vec(&calldata()).unwrap(),
+        details: PayloadDetails {
+            success_criteria: Some(serde_json::to_vec(&delivered_calldata()).unwrap()),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn transaction() -> Transaction {
+    Transaction::new(
+        CosmosNativeTxPrecursor::new(calldata()),
+        vec![payload().details],
+    )
+}
+
+/// Provider with a chain at height 100, which accepts every broadcast
+fn accepting_provider(onchain_sequence: u64) -> MockCosmosNativeProvider {
+    let mut provider = MockCosmosNativeProvider::new();
+    provider
+        .expect_fee_for_gas()
+        .returning(|gas_limit| Ok(u128::from(gas_limit) * GAS_PRICE));
+    provider
+        .expect_account_sequence()
+        .returning(move || Ok(onchain_sequence));
+    provider.expect_get_block_number().returning(|| Ok(100));
+    provider
+}
+
+#[tokio::test]
+async fn test_build_transactions() {
+    let adapter = adapter(MockCosmosNativeProvider::new());
+    let invalid = FullPayload {
+        data: b"not calldata".to_vec(),
+        ..Default::default()
+    };
+
+    let results = adapter.build_transactions(&[payload(), invalid]).await;
+
+    assert_eq!(results.len(), 2);
+    let tx = results[0].maybe_tx.as_ref().expect("tx should be built");
+    assert_eq!(tx.precursor(), &CosmosNativeTxPrecursor::new(calldata()));
+    assert!(results[1].maybe_tx.is_none());
+}
+
+#[tokio::test]
+async fn test_estimate_gas_limit() {
+    let mut provider = MockCosmosNativeProvider::new();
+    provider
+        .expect_simulate()
+        .withf(|calldata| calldata == &self::calldata())
+        .returning(|_| Ok(80_000));
+    let adapter = adapter(provider);
+
+    let gas_limit = adapter.estimate_gas_limit(&payload()).await.unwrap();
+
+    assert_eq!(gas_limit, Some(U256::from(80_000)));
+}
+
+#[tokio::test]
+async fn test_simulate_tx_revert_reverts_payloads() {
+    let mut provider = MockCosmosNativeProvider::new();
+    provider.expect_simulate().returning(|_| {
+        Err(ChainCommunicationError::from_contract_error_str(
+            "already processed",
+        ))
+    });
+    let adapter = adapter(provider);
+    let mut tx = transaction();
+
+    let reverted = adapter.simulate_tx(&mut tx).await.unwrap();
+
+    assert_eq!(reverted, tx.payload_details);
+}
+
+#[tokio::test]
+async fn test_submit() {
+    let mut provider = accepting_provider(9);
+    provider
+        .expect_broadcast()
+        .withf(
+            |calldata, gas_limit, fee_amount, sequence, timeout_height| {
+                calldata == &self::calldata()
+                    && *gas_limit == 80_000
+                    && *fee_amount == 240_000
+                    && *sequence == 9
+                    && *timeout_height == 130
+            },
+        )
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(H256::random()));
+    let adapter = adapter(provider);
+    let mut tx = transaction();
+    tx.precursor_mut().gas_limit = Some(80_000);
+
+    adapter.submit(&mut tx).await.unwrap();
+
+    assert_eq!(tx.tx_hashes.len(), 1);
+    assert_eq!(tx.precursor().sequence, Some(9));
+    assert_eq!(tx.precursor().fee_amount, Some(240_000));
+    assert_eq!(tx.precursor().timeout_height, Some(130));
+}
+
+#[tokio::test]
+async fn test_submit_escalates_fee_on_resubmission() {
+    let mut provider = accepting_provider(9);
+    provider
+        .expect_broadcast()
+        .returning(|_, _, _, _, _| Ok(H256::random()));
+    let adapter = adapter(provider);
+    let mut tx = transaction();
+    tx.precursor_mut().gas_limit = Some(80_000);
+
+    adapter.submit(&mut tx).await.unwrap();
+    adapter.submit(&mut tx).await.unwrap();
+
+    // the first submission timed out, so its sequence is reused with a higher fee
+    assert_eq!(tx.tx_hashes.len(), 2);
+    assert_eq!(tx.precursor().sequence, Some(9));
+    assert_eq!(tx.precursor().fee_amount, Some(264_000));
+}
+
+#[tokio::test]
+async fn test_submit_classifies_errors() {
+    let mut provider = accepting_provider(9);
+    provider.expect_broadcast().returning(|_, _, _, _, _| {
+        Err(ChainCommunicationError::from_other_str(
+            "account sequence mismatch, expected 10, got 9: incorrect account sequence",
+        ))
+    });
+    let adapter = adapter(provider);
+    let mut tx = transaction();
+    tx.precursor_mut().gas_limit = Some(80_000);
+
+    let result = adapter.submit(&mut tx).await;
+
+    assert!(matches!(result, Err(LanderError::TxSubmissionError(_))));
+    assert!(tx.tx_hashes.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_tx_hash_status() {
+    let mut provider = MockCosmosNativeProvider::new();
+    let included = H512::random();
+    provider
+        .expect_get_tx()
+        .withf(move |hash| *hash == included)
+        .returning(|_| {
+            Ok(CosmosTxResult {
+                height: 10,
+                success: true,
+                log: String::new(),
+            })
+        });
+    provider
+        .expect_get_tx()
+        .withf(move |hash| *hash != included)
+        .returning(|_| Err(ChainCommunicationError::from_other_str("tx not found")));
+    let adapter = adapter(provider);
+
+    assert_eq!(
+        adapter.get_tx_hash_status(included).await.unwrap(),
+        TransactionStatus::Finalized
+    );
+    assert!(matches!(
+        adapter.get_tx_hash_status(H512::random()).await,
+        Err(LanderError::TxHashNotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_tx_ready_for_resubmission() {
+    let mut provider = MockCosmosNativeProvider::new();
+    provider.expect_get_block_number().returning(|| Ok(100));
+    let adapter = adapter(provider);
+    let mut tx = transaction();
+
+    assert!(adapter.tx_ready_for_resubmission(&tx).await);
+
+    tx.precursor_mut().timeout_height = Some(100);
+    assert!(!adapter.tx_ready_for_resubmission(&tx).await);
+
+    tx.precursor_mut().timeout_height = Some(99);
+    assert!(adapter.tx_ready_for_resubmission(&tx).await);
+}
+
+#[tokio::test]
+async fn test_reverted_payloads() {
+    let tx = transaction();
+
+    let mut provider = MockCosmosNativeProvider::new();
+    provider
+        .expect_check_delivered()
+        .withf(|calldata| calldata == &delivered_calldata())
+        .returning(|_| Ok(false));
+    let adapter = adapter(provider);
+
+    assert_eq!(
+        adapter.reverted_payloads(&tx).await.unwrap(),
+        tx.payload_details
+    );
+}
@@ -0,0 +1,43 @@
+use hyperlane_cosmos::CosmosNativeTxCalldata;
+
+use crate::adapter::chains::cosmos::{CosmosCalldata, CosmosTxPrecursor};
+use crate::transaction::{Transaction, VmSpecificTxData};
+
+/// Precursor of a transaction executing a message of the Hyperlane module, encoded as `Any`
+pub type CosmosNativeTxPrecursor = CosmosTxPrecursor<CosmosNativeTxCalldata>;
+
+#[allow(clippy::panic)]
+impl CosmosCalldata for CosmosNativeTxCalldata {
+    fn into_vm_specific_data(precursor: CosmosTxPrecursor<Self>) -> VmSpecificTxData {
+        VmSpecificTxData::CosmosNative(Box::new(precursor))
+    }
+
+    fn precursor(tx: &Transaction) -> &CosmosTxPrecursor<Self> {
+        match &tx.vm_specific_data {
+            VmSpecificTxData::CosmosNative(precursor) => precursor,
+            _ => panic!(),
+        }
+    }
+
+    fn precursor_mut(tx: &mut Transaction) -> &mut CosmosTxPrecursor<Self> {
+        match &mut tx.vm_specific_data {
+            VmSpecificTxData::CosmosNative(precursor) => precursor,
+            _ => panic!(),
+        }
+    }
+}
+
+pub trait Precursor {
+    fn precursor(&self) -> &CosmosNativeTxPrecursor;
+    fn precursor_mut(&mut self) -> &mut CosmosNativeTxPrecursor;
+}
+
+impl Precursor for Transaction {
+    fn precursor(&self) -> &CosmosNativeTxPrecursor {
+        CosmosNativeTxCalldata::precursor(self)
+    }
+
+    fn precursor_mut(&mut self) -> &mut CosmosNativeTxPrecursor {
+        CosmosNativeTxCalldata::precursor_mut(self)
+    }
+}
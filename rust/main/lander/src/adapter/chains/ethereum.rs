@@ -8,6 +8,7 @@ mod metrics;
 mod nonce;
 mod payload;
 mod precursor;
+mod signer_pool;
 mod transaction;
 
 #[cfg(test)]
@@ -15,6 +16,8 @@ pub use adapter::apply_estimate_buffer_to_ethers;
 #[cfg(test)]
 pub(crate) use nonce::{NonceDb, NonceManager, NonceManagerState, NonceUpdater};
 #[cfg(test)]
+pub(crate) use signer_pool::{SignerLane, SignerPool};
+#[cfg(test)]
 pub use transaction::Precursor;
 #[cfg(test)]
 pub use transaction::TransactionFactory;
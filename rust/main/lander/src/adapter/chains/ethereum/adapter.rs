@@ -2,10 +2,12 @@ use std::ops::Sub;
 use std::{marker::PhantomData, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
-use ethers::prelude::{Address, H160};
+use ethers::prelude::H160;
 use ethers::{
-    contract::builders::ContractCall, prelude::U64, providers::Middleware,
-    types::transaction::eip2718::TypedTransaction,
+    contract::builders::ContractCall,
+    prelude::U64,
+    providers::Middleware,
+    types::{transaction::eip2718::TypedTransaction, BlockNumber},
 };
 use ethers_core::abi::Function;
 use ethers_core::types::Eip1559TransactionRequest;
@@ -47,12 +49,19 @@ use crate::{
 };
 
 use super::{
-    gas_price::GasPrice, metrics::EthereumAdapterMetrics, nonce::NonceManager,
-    transaction::Precursor, EthereumTxPrecursor,
+    gas_price::GasPrice,
+    metrics::EthereumAdapterMetrics,
+    nonce::NonceManager,
+    signer_pool::{SignerLane, SignerPool},
+    transaction::Precursor,
+    EthereumTxPrecursor,
 };
 
+use self::private_submitter::PrivateSubmitter;
+
 mod gas_limit_estimator;
 mod gas_price;
+mod private_submitter;
 mod tx_status_checker;
 
 /// Error strings that indicate a nonce conflict or duplicate transaction.
@@ -73,13 +82,13 @@ pub struct EthereumAdapter {
     pub submission_config: OpSubmissionConfig,
     pub provider: Arc<dyn EvmProviderForLander>,
     pub reorg_period: EthereumReorgPeriod,
-    pub nonce_manager: NonceManager,
+    pub signer_pool: SignerPool,
     pub batch_cache: Arc<Mutex<BatchCache>>,
     pub batch_contract_address: H256,
     pub payload_db: Arc<dyn PayloadDb>,
-    pub signer: H160,
     pub minimum_time_between_resubmissions: Duration,
     pub metrics: EthereumAdapterMetrics,
+    pub private_submitter: Option<PrivateSubmitter>,
 }
 
 impl EthereumAdapter {
@@ -91,37 +100,51 @@ impl EthereumAdapter {
         metrics: &CoreMetrics,
         dispatcher_metrics: DispatcherMetrics,
     ) -> eyre::Result<Self> {
-        let domain = conf.domain.name();
+        let primary = Self::build_signer_lane(
+            &conf,
+            &connection_conf,
+            db.clone(),
+            metrics,
+            &dispatcher_metrics,
+        )
+        .await?;
 
-        let locator = ContractLocator {
-            domain: &conf.domain,
-            address: H256::zero(),
-        };
-        let provider = conf
-            .build_ethereum(
+        let mut additional: Vec<SignerLane> = Vec::new();
+        for signer_conf in &conf.signer_pool {
+            let lane_conf = ChainConf {
+                signer: Some(signer_conf.clone()),
+                ..conf.clone()
+            };
+            let lane = Self::build_signer_lane(
+                &lane_conf,
                 &connection_conf,
-                &locator,
+                db.clone(),
                 metrics,
-                LanderProviderBuilder {},
+                &dispatcher_metrics,
             )
             .await?;
+            if lane.signer == primary.signer || additional.iter().any(|l| l.signer == lane.signer) {
+                warn!(signer = ?lane.signer, "ignoring duplicate signer in signer pool");
+                continue;
+            }
+            additional.push(lane);
+        }
 
-        let signer = provider
-            .get_signer()
-            .ok_or_else(|| eyre!("No signer found in provider for domain {}", domain))?;
-
-        let metrics = EthereumAdapterMetrics::new(
-            conf.domain.clone(),
-            dispatcher_metrics.get_batched_transactions(),
-            dispatcher_metrics.get_finalized_nonce(domain, &signer.to_string()),
-            dispatcher_metrics.get_upper_nonce(domain, &signer.to_string()),
-            dispatcher_metrics.get_mismatched_nonce(domain, &signer.to_string()),
-        );
-
+        let provider = primary.provider.clone();
+        let metrics = primary.metrics.clone();
         let payload_db = db.clone() as Arc<dyn PayloadDb>;
 
         let reorg_period = EthereumReorgPeriod::try_from(&conf.reorg_period)?;
-        let nonce_manager = NonceManager::new(&conf, db, provider.clone(), metrics.clone()).await?;
+        let signer_pool = SignerPool::new(
+            primary,
+            additional,
+            connection_conf.op_submission_config.signer_assignment,
+        );
+        info!(
+            domain = conf.domain.name(),
+            signers = signer_pool.len(),
+            "created signer pool"
+        );
 
         let adapter = Self {
             estimated_block_time: conf.estimated_block_time,
@@ -130,20 +153,101 @@ impl EthereumAdapter {
             submission_config: connection_conf.op_submission_config.clone(),
             provider,
             reorg_period,
-            nonce_manager,
+            signer_pool,
             batch_cache: Default::default(),
             batch_contract_address: connection_conf.batch_contract_address(),
             payload_db,
-            signer,
             minimum_time_between_resubmissions: DEFAULT_MINIMUM_TIME_BETWEEN_RESUBMISSIONS,
             metrics,
+            private_submitter: connection_conf
+                .private_submission
+                .clone()
+                .map(PrivateSubmitter::new)
+                .transpose()?,
         };
 
         Ok(adapter)
     }
 
-    async fn calculate_nonce(&self, tx: &Transaction) -> Result<U256, LanderError> {
-        self.nonce_manager.calculate_next_nonce(tx).await
+    /// Builds the provider, nonce manager and metrics for the signer configured in `conf`.
+    async fn build_signer_lane(
+        conf: &ChainConf,
+        connection_conf: &ConnectionConf,
+        db: Arc<HyperlaneRocksDB>,
+        metrics: &CoreMetrics,
+        dispatcher_metrics: &DispatcherMetrics,
+    ) -> eyre::Result<SignerLane> {
+        let domain = conf.domain.name();
+
+        let locator = ContractLocator {
+            domain: &conf.domain,
+            address: H256::zero(),
+        };
+        let provider = conf
+            .build_ethereum(connection_conf, &locator, metrics, LanderProviderBuilder {})
+            .await?;
+
+        let signer = provider
+            .get_signer()
+            .ok_or_else(|| eyre!("No signer found in provider for domain {}", domain))?;
+
+        let metrics = EthereumAdapterMetrics::new(
+            conf.domain.clone(),
+            dispatcher_metrics.get_batched_transactions(),
+            dispatcher_metrics.get_finalized_nonce(domain, &signer.to_string()),
+            dispatcher_metrics.get_upper_nonce(domain, &signer.to_string()),
+            dispatcher_metrics.get_mismatched_nonce(domain, &signer.to_string()),
+            dispatcher_metrics.get_assigned_transactions(domain, &signer.to_string()),
+        );
+
+        let nonce_manager = NonceManager::new(conf, db, provider.clone(), metrics.clone()).await?;
+
+        Ok(SignerLane::new(signer, provider, nonce_manager, metrics))
+    }
+
+    async fn send_publicly(lane: &SignerLane, tx: &Transaction) -> Result<H256, LanderError> {
+        let precursor = tx.precursor();
+        let hash = lane
+            .provider
+            .send(&precursor.tx, &precursor.function)
+            .await?;
+        Ok(hash)
+    }
+
+    /// Sends the transaction through the private submission endpoint, or through
+    /// the public mempool once its fallback window has passed.
+    async fn send_privately(
+        submitter: &PrivateSubmitter,
+        lane: &SignerLane,
+        tx: &mut Transaction,
+    ) -> Result<H256, LanderError> {
+        let latest_block = lane
+            .provider
+            .get_block(BlockNumber::Latest)
+            .await?
+            .and_then(|block| block.number)
+            .ok_or_else(|| LanderError::NetworkError("Latest block number missing".to_string()))?
+            .as_u64();
+
+        let precursor = tx.precursor_mut();
+        let first_block = *precursor
+            .private_submission_block
+            .get_or_insert(latest_block);
+
+        if submitter.should_fall_back(first_block, latest_block) {
+            info!(
+                first_block,
+                latest_block, "private submission window passed, broadcasting transaction publicly"
+            );
+            return Self::send_publicly(lane, tx).await;
+        }
+
+        let raw_tx = lane.provider.sign(&precursor.tx).await?;
+        submitter.submit(&raw_tx, first_block, latest_block).await
+    }
+
+    async fn calculate_nonce(lane: &SignerLane, tx: &Transaction) -> Result<U256, LanderError> {
+        lane.nonce_manager.calculate_next_nonce(tx).await
     }
 
     async fn estimate_gas_price(&self, tx: &Transaction) -> Result<GasPrice, LanderError> {
@@ -313,10 +417,19 @@ impl EthereumAdapter {
         Ok(payloads)
     }
 
-    fn create_precursors(&self, payloads: &[FullPayload]) -> Vec<EthereumTxPrecursor> {
+    /// The address the transaction is sent from, defaulting to the primary signer.
+    fn tx_signer(&self, tx: &Transaction) -> H160 {
+        tx.precursor()
+            .tx
+            .from()
+            .copied()
+            .unwrap_or(self.signer_pool.primary().signer)
+    }
+
+    fn create_precursors(payloads: &[FullPayload], signer: H160) -> Vec<EthereumTxPrecursor> {
         payloads
             .iter()
-            .map(|p| EthereumTxPrecursor::from_payload(p, self.signer))
+            .map(|p| EthereumTxPrecursor::from_payload(p, signer))
             .collect::<Vec<_>>()
     }
 
@@ -360,6 +473,7 @@ impl EthereumAdapter {
         &self,
         precursors: Vec<(TypedTransaction, Function)>,
         payload_details: Vec<PayloadDetails>,
+        signer: H160,
     ) -> ChainResult<Vec<TxBuildingResult>> {
         use super::transaction::TransactionFactory;
 
@@ -369,7 +483,7 @@ impl EthereumAdapter {
                 self.batch_cache.clone(),
                 self.batch_contract_address,
                 precursors,
-                self.signer,
+                signer,
             )
             .await
             .map(|(tx, f)| EthereumTxPrecursor::new(tx, f))?;
@@ -386,41 +500,8 @@ impl EthereumAdapter {
     pub fn metrics(&self) -> &EthereumAdapterMetrics {
         &self.metrics
     }
-}
-
-#[async_trait]
-impl AdaptsChain for EthereumAdapter {
-    async fn estimate_gas_limit(
-        &self,
-        _payload: &FullPayload,
-    ) -> Result<Option<GasLimit>, LanderError> {
-        todo!()
-    }
 
-    async fn tx_ready_for_resubmission(&self, tx: &Transaction) -> bool {
-        let estimated_block_time = self.estimated_block_time();
-        let ready_time = estimated_block_time.max(&self.minimum_time_between_resubmissions);
-
-        let Some(last_submission_time) = tx.last_submission_attempt else {
-            // If the transaction has never been submitted, it is ready for resubmission
-            return true;
-        };
-        let elapsed = chrono::Utc::now().sub(last_submission_time);
-        let elapsed = match elapsed.to_std() {
-            Ok(duration) => duration,
-            Err(err) => {
-                warn!(
-                    ?elapsed,
-                    ?err,
-                    "Failed to convert elapsed time to std::time::Duration, defaulting to considering the tx ready for resubmission"
-                );
-                return true;
-            }
-        };
-        elapsed > *ready_time
-    }
-
-    /// Builds transactions for the given payloads.
+    /// Builds transactions for the given payloads, sent from `signer`.
     ///
     /// If there is only one payload, it builds a transaction without batching.
     /// If there are multiple payloads, it batches them into a single transaction.
@@ -430,10 +511,12 @@ impl AdaptsChain for EthereumAdapter {
     /// depend on the order of payloads.
     /// If batching fails, it will fallback to building multiple transactions with a
     /// single payload for each of them.
-    async fn build_transactions(&self, payloads: &[FullPayload]) -> Vec<TxBuildingResult> {
-        use super::transaction::TransactionFactory;
-
-        info!(?payloads, "building transactions for payloads");
+    async fn build_transactions_with_signer(
+        &self,
+        payloads: &[FullPayload],
+        signer: H160,
+    ) -> Vec<TxBuildingResult> {
+        info!(?payloads, ?signer, "building transactions for payloads");
 
         if payloads.is_empty() {
             error!("No payloads found! Cannot build transactions");
@@ -446,7 +529,7 @@ impl AdaptsChain for EthereumAdapter {
         ) = payloads
             .iter()
             .map(|payload| {
-                let precursor = EthereumTxPrecursor::from_payload(payload, self.signer);
+                let precursor = EthereumTxPrecursor::from_payload(payload, signer);
                 (payload.details.clone(), (precursor.tx, precursor.function))
             })
             .unzip();
@@ -465,7 +548,7 @@ impl AdaptsChain for EthereumAdapter {
         }
 
         match self
-            .build_batched_transaction(precursors.clone(), payload_details.clone())
+            .build_batched_transaction(precursors.clone(), payload_details.clone(), signer)
             .await
         {
             Ok(results) => {
@@ -497,7 +580,7 @@ impl AdaptsChain for EthereumAdapter {
         let results: Vec<_> = payloads
             .iter()
             .map(|payload| {
-                let precursor = EthereumTxPrecursor::from_payload(payload, self.signer);
+                let precursor = EthereumTxPrecursor::from_payload(payload, signer);
                 self.build_single_transaction(precursor, vec![payload.details.clone()])
             })
             .collect();
@@ -509,6 +592,108 @@ impl AdaptsChain for EthereumAdapter {
         results
     }
 
+    /// Transactions which were finalized before a reorg on the given lane and
+    /// have to be processed again.
+    async fn get_reprocess_txs_for_lane(
+        lane: &SignerLane,
+    ) -> Result<Vec<Transaction>, LanderError> {
+        let nonce_manager = &lane.nonce_manager;
+        let old_finalized_nonce = nonce_manager
+            .state
+            .get_finalized_nonce()
+            .await?
+            .unwrap_or_default();
+        nonce_manager.nonce_updater.update_boundaries().await?;
+        let new_finalized_nonce = nonce_manager
+            .state
+            .get_finalized_nonce()
+            .await?
+            .unwrap_or_default();
+
+        if new_finalized_nonce >= old_finalized_nonce {
+            return Ok(Vec::new());
+        }
+
+        warn!(
+            signer = ?lane.signer,
+            ?old_finalized_nonce,
+            ?new_finalized_nonce,
+            "New finalized nonce is lower than old finalized nonce"
+        );
+
+        let mut txs = Vec::new();
+        let mut nonce = new_finalized_nonce.saturating_add(U256::one());
+        while nonce <= old_finalized_nonce {
+            let tx_uuid = nonce_manager.state.get_tracked_tx_uuid(&nonce).await?;
+            if tx_uuid == TransactionUuid::default() {
+                debug!(
+                    ?nonce,
+                    "No tracked transaction UUID for nonce in reorg range"
+                );
+            } else if let Some(tx) = nonce_manager.state.get_tracked_tx(&tx_uuid).await? {
+                txs.push(tx);
+            } else {
+                debug!(
+                    ?nonce,
+                    ?tx_uuid,
+                    "No transaction found for nonce in reorg range"
+                );
+            }
+            nonce = nonce.saturating_add(U256::one());
+        }
+        Ok(txs)
+    }
+}
+
+#[async_trait]
+impl AdaptsChain for EthereumAdapter {
+    async fn estimate_gas_limit(
+        &self,
+        _payload: &FullPayload,
+    ) -> Result<Option<GasLimit>, LanderError> {
+        todo!()
+    }
+
+    async fn tx_ready_for_resubmission(&self, tx: &Transaction) -> bool {
+        let estimated_block_time = self.estimated_block_time();
+        let ready_time = estimated_block_time.max(&self.minimum_time_between_resubmissions);
+
+        let Some(last_submission_time) = tx.last_submission_attempt else {
+            // If the transaction has never been submitted, it is ready for resubmission
+            return true;
+        };
+        let elapsed = chrono::Utc::now().sub(last_submission_time);
+        let elapsed = match elapsed.to_std() {
+            Ok(duration) => duration,
+            Err(err) => {
+                warn!(
+                    ?elapsed,
+                    ?err,
+                    "Failed to convert elapsed time to std::time::Duration, defaulting to considering the tx ready for resubmission"
+                );
+                return true;
+            }
+        };
+        elapsed > *ready_time
+    }
+
+    /// Assigns the payloads to a signer of the signer pool and builds
+    /// transactions for them, see `build_transactions_with_signer`.
+    async fn build_transactions(&self, payloads: &[FullPayload]) -> Vec<TxBuildingResult> {
+        let lane = self.signer_pool.assign();
+        let results = self
+            .build_transactions_with_signer(payloads, lane.signer)
+            .await;
+        // `assign` counted a single transaction, so correct it for the ones actually built
+        let built = results
+            .iter()
+            .filter(|result| result.maybe_tx.is_some())
+            .count();
+        lane.track_in_flight(built);
+        lane.release_in_flight(1);
+        results
+    }
+
     #[instrument(
         skip_all,
         name = "EthereumAdapter::simulate_tx",
@@ -560,7 +745,11 @@ impl AdaptsChain for EthereumAdapter {
             return Err(LanderError::SimulationFailed(reasons));
         }
 
-        let tx_building_results = self.build_transactions(&payloads_successful).await;
+        // Keep the rebuilt transaction on the lane it was assigned to
+        let signer = self.tx_signer(tx);
+        let tx_building_results = self
+            .build_transactions_with_signer(&payloads_successful, signer)
+            .await;
         let Some(tx_building_result) = tx_building_results.first() else {
             error!(
                 ?payloads_successful,
@@ -620,7 +809,8 @@ impl AdaptsChain for EthereumAdapter {
 
         // Batching case, estimate batch
         let payloads = self.load_payloads(tx).await?;
-        let mut precursors = self.create_precursors(&payloads);
+        let signer = self.tx_signer(tx);
+        let mut precursors = Self::create_precursors(&payloads, signer);
 
         let payload_estimate_futures = precursors
             .iter_mut()
@@ -657,11 +847,12 @@ impl AdaptsChain for EthereumAdapter {
         use super::transaction::Precursor;
         use LanderError::TxAlreadyExists;
 
+        let lane = self.signer_pool.lane_for_tx(tx)?;
         let tx_for_nonce = tx.clone();
         let tx_for_gas_price = tx.clone();
 
         let (nonce, gas_price) = try_join!(
-            self.calculate_nonce(&tx_for_nonce),
+            Self::calculate_nonce(lane, &tx_for_nonce),
             self.estimate_gas_price(&tx_for_gas_price)
         )?;
 
@@ -675,9 +866,10 @@ impl AdaptsChain for EthereumAdapter {
 
         info!(?tx, "submitting transaction");
 
-        let precursor = tx.precursor();
-
-        let send_result = self.provider.send(&precursor.tx, &precursor.function).await;
+        let send_result = match &self.private_submitter {
+            Some(submitter) => Self::send_privately(submitter, lane, tx).await,
+            None => Self::send_publicly(lane, tx).await,
+        };
         let hash = match send_result {
             Ok(hash) => hash,
             Err(e) => {
@@ -686,7 +878,7 @@ impl AdaptsChain for EthereumAdapter {
                 return if NONCE_TOO_LOW_ERRORS.iter().any(|s| err_str.contains(s)) {
                     Err(TxAlreadyExists)
                 } else {
-                    Err(e.into())
+                    Err(e)
                 };
             }
         };
@@ -709,12 +901,11 @@ impl AdaptsChain for EthereumAdapter {
         &self,
         tx: &Transaction,
     ) -> Result<Vec<PayloadDetails>, LanderError> {
+        let signer = self.tx_signer(tx);
         let payload_details_and_precursors = tx
             .payload_details
             .iter()
-            .filter_map(|d| {
-                EthereumTxPrecursor::from_success_criteria(d, self.signer).map(|p| (d, p))
-            })
+            .filter_map(|d| EthereumTxPrecursor::from_success_criteria(d, signer).map(|p| (d, p)))
             .collect::<Vec<_>>();
 
         let mut reverted = Vec::new();
@@ -739,51 +930,13 @@ impl AdaptsChain for EthereumAdapter {
     }
 
     async fn get_reprocess_txs(&self) -> Result<Vec<Transaction>, LanderError> {
-        let old_finalized_nonce = self
-            .nonce_manager
-            .state
-            .get_finalized_nonce()
-            .await?
-            .unwrap_or_default();
-        self.nonce_manager.nonce_updater.update_boundaries().await?;
-        let new_finalized_nonce = self
-            .nonce_manager
-            .state
-            .get_finalized_nonce()
-            .await?
-            .unwrap_or_default();
-
-        if new_finalized_nonce >= old_finalized_nonce {
-            return Ok(Vec::new());
-        }
-
-        warn!(
-            ?old_finalized_nonce,
-            ?new_finalized_nonce,
-            "New finalized nonce is lower than old finalized nonce"
-        );
-
-        let mut txs = Vec::new();
-        let mut nonce = new_finalized_nonce.saturating_add(U256::one());
-        while nonce <= old_finalized_nonce {
-            let tx_uuid = self.nonce_manager.state.get_tracked_tx_uuid(&nonce).await?;
-            if tx_uuid == TransactionUuid::default() {
-                debug!(
-                    ?nonce,
-                    "No tracked transaction UUID for nonce in reorg range"
-                );
-            } else if let Some(tx) = self.nonce_manager.state.get_tracked_tx(&tx_uuid).await? {
-                txs.push(tx);
-            } else {
-                debug!(
-                    ?nonce,
-                    ?tx_uuid,
-                    "No transaction found for nonce in reorg range"
-                );
-            }
-            nonce = nonce.saturating_add(U256::one());
-        }
-        Ok(txs)
+        let txs_per_lane = future::try_join_all(
+            self.signer_pool
+                .lanes()
+                .map(Self::get_reprocess_txs_for_lane),
+        )
+        .await?;
+        Ok(txs_per_lane.into_iter().flatten().collect())
     }
 
     fn estimated_block_time(&self) -> &std::time::Duration {
@@ -800,17 +953,28 @@ impl AdaptsChain for EthereumAdapter {
     }
 
     async fn post_finalized(&self) -> Result<(), LanderError> {
-        self.nonce_manager
-            .nonce_updater
-            .update_boundaries()
-            .await
-            .map_err(Into::into)
+        self.signer_pool.update_boundaries().await
+    }
+
+    async fn on_tx_completed(&self, tx: &Transaction) {
+        self.signer_pool.release(tx);
     }
 
     async fn run_command(&self, action: AdaptsChainAction) -> Result<(), LanderError> {
         match action {
-            AdaptsChainAction::OverwriteUpperNonce { nonce } => {
-                self.nonce_manager
+            AdaptsChainAction::OverwriteUpperNonce { nonce, signer } => {
+                let lane = match signer {
+                    Some(signer) => self
+                        .signer_pool
+                        .lane(&H160::from(signer.to_fixed_bytes()))
+                        .ok_or_else(|| {
+                            LanderError::NonRetryableError(format!(
+                                "Signer {signer:?} is not in the signer pool"
+                            ))
+                        })?,
+                    None => self.signer_pool.primary(),
+                };
+                lane.nonce_manager
                     .state
                     .overwrite_upper_nonce(nonce)
                     .await?;
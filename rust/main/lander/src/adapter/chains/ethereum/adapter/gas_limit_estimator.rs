@@ -124,6 +124,7 @@ pub mod tests {
                 constant: None,
                 state_mutability: ethers::abi::StateMutability::Payable,
             },
+            private_submission_block: None,
         };
         let mut provider = MockEvmProvider::new();
 
@@ -171,6 +172,7 @@ pub mod tests {
                 constant: None,
                 state_mutability: ethers::abi::StateMutability::Payable,
             },
+            private_submission_block: None,
         };
         let mut provider = MockEvmProvider::new();
 
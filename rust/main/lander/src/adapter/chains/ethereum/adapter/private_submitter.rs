@@ -0,0 +1,245 @@
+use std::time::Duration;
+
+use ethers::types::{Bytes, U64};
+use ethers::utils::keccak256;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tracing::debug;
+
+use hyperlane_core::H256;
+use hyperlane_ethereum::{PrivateSubmissionConf, PrivateSubmissionMethod};
+
+use crate::LanderError;
+
+/// How long to wait for the private RPC or bundle relay to respond
+const PRIVATE_SUBMISSION_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Deserialize)]
+struct JsonRpcResponse {
+    #[serde(default)]
+    error: Option<Value>,
+}
+
+/// Sends signed transactions to a private RPC or bundle relay instead of
+/// the public mempool.
+#[derive(Debug, Clone)]
+pub struct PrivateSubmitter {
+    client: reqwest::Client,
+    conf: PrivateSubmissionConf,
+}
+
+impl PrivateSubmitter {
+    pub fn new(conf: PrivateSubmissionConf) -> Result<Self, LanderError> {
+        let client = reqwest::Client::builder()
+            .timeout(PRIVATE_SUBMISSION_TIMEOUT)
+            .build()
+            .map_err(|err| LanderError::NetworkError(err.to_string()))?;
+        Ok(Self { client, conf })
+    }
+
+    /// Whether a transaction first sent privately when the latest block was
+    /// `first_block` should now be broadcast through the public mempool.
+    pub fn should_fall_back(&self, first_block: u64, latest_block: u64) -> bool {
+        self.conf
+            .fallback_after_blocks
+            .map(|blocks| latest_block.saturating_sub(first_block) >= u64::from(blocks))
+            .unwrap_or(false)
+    }
+
+    /// Sends the signed transaction and returns its hash.
+    pub async fn submit(
+        &self,
+        raw_tx: &Bytes,
+        first_block: u64,
+        latest_block: u64,
+    ) -> Result<H256, LanderError> {
+        let (method, params) = match self.conf.method {
+            PrivateSubmissionMethod::PrivateTransaction => {
+                let mut request = json!({ "tx": raw_tx });
+                // let the relay drop the transaction once we fall back to the public mempool
+                if let Some(blocks) = self.conf.fallback_after_blocks {
+                    let max_block = first_block.saturating_add(u64::from(blocks));
+                    request["maxBlockNumber"] = json!(U64::from(max_block));
+                }
+                ("eth_sendPrivateTransaction", json!([request]))
+            }
+            PrivateSubmissionMethod::Bundle => {
+                // bundles are only valid for a single block, so every resubmission
+                // targets the block after the latest one
+                let target_block = latest_block.saturating_add(1);
+                let request = json!({
+                    "txs": [raw_tx],
+                    "blockNumber": U64::from(target_block),
+                });
+                ("eth_sendBundle", json!([request]))
+            }
+        };
+
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+        let response = self
+            .client
+            .post(self.conf.url.clone())
+            .json(&body)
+            .send()
+            .await
+            .map_err(|err| LanderError::NetworkError(err.to_string()))?
+            .error_for_status()
+            .map_err(|err| LanderError::NetworkError(err.to_string()))?
+            .json::<JsonRpcResponse>()
+            .await
+            .map_err(|err| LanderError::NetworkError(err.to_string()))?;
+
+        if let Some(error) = response.error {
+            return Err(LanderError::TxSubmissionError(format!(
+                "{method} failed: {error}"
+            )));
+        }
+
+        let hash = H256::from(keccak256(raw_tx));
+        debug!(?hash, method, url = %self.conf.url, "sent transaction privately");
+        Ok(hash)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::sync::Arc;
+
+    use axum::{extract::State, routing::post, Json, Router};
+    use tokio::sync::Mutex;
+    use url::Url;
+
+    use super::*;
+
+    pub type Requests = Arc<Mutex<Vec<Value>>>;
+
+    async fn mock_rpc(
+        State((requests, response)): State<(Requests, Value)>,
+        Json(request): Json<Value>,
+    ) -> Json<Value> {
+        requests.lock().await.push(request);
+        Json(response)
+    }
+
+    /// Starts a JSON-RPC server which records all requests and answers them with `response`.
+    pub async fn mock_server(response: Value) -> (Url, Requests) {
+        let requests = Requests::default();
+        let app = Router::new()
+            .route("/", post(mock_rpc))
+            .with_state((requests.clone(), response));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (Url::parse(&format!("http://{addr}")).unwrap(), requests)
+    }
+
+    fn submitter(
+        url: Url,
+        method: PrivateSubmissionMethod,
+        fallback_after_blocks: Option<u32>,
+    ) -> PrivateSubmitter {
+        PrivateSubmitter::new(PrivateSubmissionConf {
+            url,
+            method,
+            fallback_after_blocks,
+        })
+        .unwrap()
+    }
+
+    fn raw_tx() -> Bytes {
+        Bytes::from(vec![0x02, 0xf8, 0x01, 0x02, 0x03])
+    }
+
+    #[tokio::test]
+    async fn test_send_private_transaction() {
+        let (url, requests) = mock_server(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": "0x1234",
+        }))
+        .await;
+        let submitter = submitter(url, PrivateSubmissionMethod::PrivateTransaction, Some(5));
+
+        let hash = submitter.submit(&raw_tx(), 100, 102).await.unwrap();
+
+        assert_eq!(hash, H256::from(keccak256(raw_tx())));
+        let requests = requests.lock().await;
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0]["method"], "eth_sendPrivateTransaction");
+        assert_eq!(
+            requests[0]["params"],
+            json!([{ "tx": "0x02f8010203", "maxBlockNumber": "0x69" }])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_private_transaction_without_fallback() {
+        let (url, requests) = mock_server(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": "0x1234",
+        }))
+        .await;
+        let submitter = submitter(url, PrivateSubmissionMethod::PrivateTransaction, None);
+
+        submitter.submit(&raw_tx(), 100, 102).await.unwrap();
+
+        let requests = requests.lock().await;
+        assert_eq!(requests[0]["params"], json!([{ "tx": "0x02f8010203" }]));
+    }
+
+    #[tokio::test]
+    async fn test_send_bundle() {
+        let (url, requests) = mock_server(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": { "bundleHash": "0x1234" },
+        }))
+        .await;
+        let submitter = submitter(url, PrivateSubmissionMethod::Bundle, Some(5));
+
+        let hash = submitter.submit(&raw_tx(), 100, 102).await.unwrap();
+
+        assert_eq!(hash, H256::from(keccak256(raw_tx())));
+        let requests = requests.lock().await;
+        assert_eq!(requests[0]["method"], "eth_sendBundle");
+        assert_eq!(
+            requests[0]["params"],
+            json!([{ "txs": ["0x02f8010203"], "blockNumber": "0x67" }])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_json_rpc_error() {
+        let (url, _) = mock_server(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "error": { "code": -32000, "message": "nonce too low" },
+        }))
+        .await;
+        let submitter = submitter(url, PrivateSubmissionMethod::PrivateTransaction, None);
+
+        let err = submitter.submit(&raw_tx(), 100, 102).await.unwrap_err();
+
+        assert!(matches!(err, LanderError::TxSubmissionError(_)));
+        assert!(err.to_string().contains("nonce too low"));
+    }
+
+    #[test]
+    fn test_should_fall_back() {
+        let url = Url::parse("http://localhost").unwrap();
+
+        let with_fallback = submitter(url.clone(), PrivateSubmissionMethod::Bundle, Some(3));
+        assert!(!with_fallback.should_fall_back(100, 100));
+        assert!(!with_fallback.should_fall_back(100, 102));
+        assert!(with_fallback.should_fall_back(100, 103));
+
+        let without_fallback = submitter(url, PrivateSubmissionMethod::Bundle, None);
+        assert!(!without_fallback.should_fall_back(100, 1_000));
+    }
+}
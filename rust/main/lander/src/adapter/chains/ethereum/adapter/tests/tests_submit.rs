@@ -4,13 +4,26 @@ use std::time::Duration;
 use ethers::abi::{Function, StateMutability};
 use ethers::types::transaction::eip2718::TypedTransaction;
 use ethers::types::transaction::eip2930::AccessList;
-use ethers::types::{Address, Eip1559TransactionRequest, NameOrAddress, U256 as EthersU256, U64};
-use hyperlane_core::{ChainCommunicationError, H256, U256};
-
+use ethers::types::{
+    Address, Bytes, Eip1559TransactionRequest, NameOrAddress, U256 as EthersU256, U64,
+};
+use ethers::utils::keccak256;
+use hyperlane_core::config::SignerAssignment;
+use hyperlane_core::{ChainCommunicationError, H256, H512, U256};
+use hyperlane_ethereum::{PrivateSubmissionConf, PrivateSubmissionMethod};
+use url::Url;
+
+use crate::adapter::chains::ethereum::adapter::private_submitter::{
+    tests::mock_server, PrivateSubmitter,
+};
 use crate::adapter::chains::ethereum::tests::MockEvmProvider;
 use crate::adapter::chains::ethereum::transaction::Precursor;
+use crate::adapter::chains::ethereum::SignerPool;
 use crate::adapter::{AdaptsChain, AdaptsChainAction};
-use crate::tests::evm::test_utils::mock_ethereum_adapter;
+use crate::tests::evm::test_utils::{
+    mock_ethereum_adapter, mock_ethereum_adapter_with_signer_pool, mock_fee_history,
+    mock_signer_lane,
+};
 use crate::tests::test_utils::tmp_dbs;
 use crate::{FullPayload, LanderError, TransactionUuid};
 
@@ -181,12 +194,16 @@ async fn test_submit_assigns_correct_nonce_after_reset() {
     // Set up initial state: finalized = 90, upper = 150
     // This simulates a situation where we have many pending transactions
     adapter
+        .signer_pool
+        .primary()
         .nonce_manager
         .state
         .set_finalized_nonce_test(&hyperlane_core::U256::from(90))
         .await
         .unwrap();
     adapter
+        .signer_pool
+        .primary()
         .nonce_manager
         .state
         .set_upper_nonce_test(&hyperlane_core::U256::from(150))
@@ -211,6 +228,8 @@ async fn test_submit_assigns_correct_nonce_after_reset() {
         let tx_nonce = U256::from(i);
         let tx_uuid = TransactionUuid::random();
         adapter
+            .signer_pool
+            .primary()
             .nonce_manager
             .state
             .set_tracked_tx_uuid_test(&tx_nonce, &tx_uuid)
@@ -243,7 +262,10 @@ async fn test_submit_assigns_correct_nonce_after_reset() {
 
     // Overwrite upper nonce to 100 using run_command
     adapter
-        .run_command(AdaptsChainAction::OverwriteUpperNonce { nonce: Some(100) })
+        .run_command(AdaptsChainAction::OverwriteUpperNonce {
+            nonce: Some(100),
+            signer: None,
+        })
         .await
         .expect("Failed to overwrite nonce");
 
@@ -269,6 +291,8 @@ async fn test_submit_assigns_correct_nonce_after_reset() {
 
     // Verify the upper nonce was incremented to 101
     let new_upper_nonce = adapter
+        .signer_pool
+        .primary()
         .nonce_manager
         .state
         .get_upper_nonce_test()
@@ -332,12 +356,16 @@ async fn test_multiple_submissions_after_reset() {
 
     // Set up initial state: finalized = 100, upper = 150
     adapter
+        .signer_pool
+        .primary()
         .nonce_manager
         .state
         .set_finalized_nonce_test(&hyperlane_core::U256::from(100))
         .await
         .unwrap();
     adapter
+        .signer_pool
+        .primary()
         .nonce_manager
         .state
         .set_upper_nonce_test(&hyperlane_core::U256::from(150))
@@ -346,7 +374,10 @@ async fn test_multiple_submissions_after_reset() {
 
     // Overwrite upper nonce to 101 using run_command
     adapter
-        .run_command(AdaptsChainAction::OverwriteUpperNonce { nonce: Some(101) })
+        .run_command(AdaptsChainAction::OverwriteUpperNonce {
+            nonce: Some(101),
+            signer: None,
+        })
         .await
         .expect("Failed to overwrite nonce");
 
@@ -356,6 +387,8 @@ async fn test_multiple_submissions_after_reset() {
         let tx_nonce = U256::from(i);
         let tx_uuid = TransactionUuid::random();
         adapter
+            .signer_pool
+            .primary()
             .nonce_manager
             .state
             .set_tracked_tx_uuid_test(&tx_nonce, &tx_uuid)
@@ -407,6 +440,8 @@ async fn test_multiple_submissions_after_reset() {
 
     // Verify the final upper nonce
     let db_upper_nonce = adapter
+        .signer_pool
+        .primary()
         .nonce_manager
         .state
         .get_upper_nonce_test()
@@ -541,12 +576,16 @@ async fn test_submit_after_reset_to_finalized_plus_one() {
 
     // Set up initial state: finalized = 75, upper = 200
     adapter
+        .signer_pool
+        .primary()
         .nonce_manager
         .state
         .set_finalized_nonce_test(&hyperlane_core::U256::from(75))
         .await
         .unwrap();
     adapter
+        .signer_pool
+        .primary()
         .nonce_manager
         .state
         .set_upper_nonce_test(&hyperlane_core::U256::from(200))
@@ -555,7 +594,10 @@ async fn test_submit_after_reset_to_finalized_plus_one() {
 
     // Overwrite upper nonce to None (finalized + 1 = 76)
     adapter
-        .run_command(AdaptsChainAction::OverwriteUpperNonce { nonce: None })
+        .run_command(AdaptsChainAction::OverwriteUpperNonce {
+            nonce: None,
+            signer: None,
+        })
         .await
         .expect("Failed to overwrite nonce to finalized + 1");
 
@@ -592,6 +634,8 @@ async fn test_submit_after_reset_to_finalized_plus_one() {
 
     // Verify the upper nonce
     let new_upper_nonce = adapter
+        .signer_pool
+        .primary()
         .nonce_manager
         .state
         .get_upper_nonce_test()
@@ -655,12 +699,16 @@ async fn test_submit_with_interleaved_resets() {
 
     // Set up initial state: finalized = 50, upper = 100
     adapter
+        .signer_pool
+        .primary()
         .nonce_manager
         .state
         .set_finalized_nonce_test(&hyperlane_core::U256::from(50))
         .await
         .unwrap();
     adapter
+        .signer_pool
+        .primary()
         .nonce_manager
         .state
         .set_upper_nonce_test(&hyperlane_core::U256::from(100))
@@ -676,6 +724,8 @@ async fn test_submit_with_interleaved_resets() {
         let tx_nonce = U256::from(i);
         let tx_uuid = TransactionUuid::random();
         adapter
+            .signer_pool
+            .primary()
             .nonce_manager
             .state
             .set_tracked_tx_uuid_test(&tx_nonce, &tx_uuid)
@@ -698,7 +748,10 @@ async fn test_submit_with_interleaved_resets() {
 
     // Reset to 80 - effectively rolling back the nonce
     adapter
-        .run_command(AdaptsChainAction::OverwriteUpperNonce { nonce: Some(80) })
+        .run_command(AdaptsChainAction::OverwriteUpperNonce {
+            nonce: Some(80),
+            signer: None,
+        })
         .await
         .expect("Failed to overwrite nonce to 80");
 
@@ -730,6 +783,8 @@ async fn test_submit_with_interleaved_resets() {
 
     // Final upper nonce should be 82
     let db_upper_nonce = adapter
+        .signer_pool
+        .primary()
         .nonce_manager
         .state
         .get_upper_nonce_test()
@@ -742,6 +797,217 @@ async fn test_submit_with_interleaved_resets() {
     );
 }
 
+/// Test that each transaction takes its nonce from, and is sent through, the signer lane
+/// it was assigned to when it was built
+#[tokio::test]
+async fn test_submit_uses_assigned_signer_lane() {
+    let (payload_db, tx_db, nonce_db) = tmp_dbs();
+
+    // The primary provider estimates gas prices for all lanes and finalized nonce 50
+    let mut primary_provider = MockEvmProvider::new();
+    primary_provider
+        .expect_get_finalized_block_number()
+        .returning(|_| Ok(43));
+    primary_provider
+        .expect_get_next_nonce_on_finalized_block()
+        .returning(|_, _| Ok(U256::from(51)));
+    primary_provider.expect_get_block().returning(|_| {
+        Ok(Some(ethers::types::Block {
+            number: Some(42.into()),
+            base_fee_per_gas: Some(100.into()),
+            gas_limit: 30000000.into(),
+            ..Default::default()
+        }))
+    });
+    primary_provider.expect_fee_history().returning(|_, _, _| {
+        Ok(ethers::types::FeeHistory {
+            oldest_block: 0.into(),
+            reward: vec![vec![10.into()]],
+            base_fee_per_gas: vec![200000.into()],
+            gas_used_ratio: vec![0.0],
+        })
+    });
+    primary_provider
+        .expect_send()
+        .times(1)
+        .returning(|_, _| Ok(H256::random()));
+
+    // The second lane has finalized nonce 10
+    let mut lane_provider = MockEvmProvider::new();
+    lane_provider
+        .expect_get_next_nonce_on_finalized_block()
+        .returning(|_, _| Ok(U256::from(11)));
+    lane_provider
+        .expect_send()
+        .times(1)
+        .returning(|_, _| Ok(H256::random()));
+
+    let block_time = Duration::from_millis(100);
+    let primary_provider = Arc::new(primary_provider);
+    let primary_signer = Address::random();
+    let lane_signer = Address::random();
+    let signer_pool = SignerPool::new(
+        mock_signer_lane(
+            primary_provider.clone(),
+            tx_db.clone(),
+            nonce_db.clone(),
+            primary_signer,
+            block_time,
+        ),
+        vec![mock_signer_lane(
+            Arc::new(lane_provider),
+            tx_db.clone(),
+            nonce_db.clone(),
+            lane_signer,
+            block_time,
+        )],
+        SignerAssignment::RoundRobin,
+    );
+    let adapter = mock_ethereum_adapter_with_signer_pool(
+        primary_provider,
+        payload_db.clone(),
+        signer_pool,
+        block_time,
+        block_time,
+    );
+
+    let mut primary_tx = build_and_store_transaction(&adapter, &payload_db).await;
+    let mut lane_tx = build_and_store_transaction(&adapter, &payload_db).await;
+    assert_eq!(primary_tx.precursor().tx.from(), Some(&primary_signer));
+    assert_eq!(lane_tx.precursor().tx.from(), Some(&lane_signer));
+
+    adapter.submit(&mut lane_tx).await.unwrap();
+    adapter.submit(&mut primary_tx).await.unwrap();
+
+    assert_eq!(
+        *lane_tx.precursor().tx.nonce().unwrap(),
+        EthersU256::from(11),
+        "Transaction should take the first nonce of its own lane"
+    );
+    assert_eq!(
+        *primary_tx.precursor().tx.nonce().unwrap(),
+        EthersU256::from(51),
+        "Transaction should take the first nonce of its own lane"
+    );
+}
+
+/// Provider which can estimate nonces and gas prices, with `latest_block` as the latest block
+fn mock_provider_at_block(latest_block: u64) -> MockEvmProvider {
+    let mut provider = MockEvmProvider::new();
+    provider
+        .expect_get_finalized_block_number()
+        .returning(|_| Ok(40));
+    provider
+        .expect_get_next_nonce_on_finalized_block()
+        .returning(|_, _| Ok(hyperlane_core::U256::from(51)));
+    provider.expect_get_block().returning(move |_| {
+        Ok(Some(ethers::types::Block {
+            number: Some(latest_block.into()),
+            base_fee_per_gas: Some(100.into()),
+            gas_limit: 30000000.into(),
+            ..Default::default()
+        }))
+    });
+    provider
+        .expect_fee_history()
+        .returning(|_, _, _| Ok(mock_fee_history(200000, 10)));
+    provider
+}
+
+fn private_submitter(url: Url, fallback_after_blocks: u32) -> PrivateSubmitter {
+    PrivateSubmitter::new(PrivateSubmissionConf {
+        url,
+        method: PrivateSubmissionMethod::PrivateTransaction,
+        fallback_after_blocks: Some(fallback_after_blocks),
+    })
+    .unwrap()
+}
+
+/// Test that submit sends signed transactions to the private submission endpoint
+#[tokio::test]
+async fn test_submit_sends_transaction_privately() {
+    let (payload_db, tx_db, nonce_db) = tmp_dbs();
+    let (url, requests) = mock_server(serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "result": "0x1234",
+    }))
+    .await;
+
+    let raw_tx = Bytes::from(vec![0x02, 0xf8, 0x01]);
+    let mut provider = mock_provider_at_block(42);
+    let signed = raw_tx.clone();
+    provider
+        .expect_sign()
+        .times(1)
+        .returning(move |_| Ok(signed.clone()));
+    provider.expect_send().never();
+
+    let block_time = Duration::from_millis(100);
+    let mut adapter = mock_ethereum_adapter(
+        provider,
+        payload_db.clone(),
+        tx_db,
+        nonce_db,
+        Address::random(),
+        block_time,
+        block_time,
+    );
+    adapter.private_submitter = Some(private_submitter(url, 3));
+
+    let mut tx = build_and_store_transaction(&adapter, &payload_db).await;
+    adapter.submit(&mut tx).await.unwrap();
+
+    assert_eq!(requests.lock().await.len(), 1);
+    assert_eq!(tx.precursor().private_submission_block, Some(42));
+    assert_eq!(
+        tx.tx_hashes,
+        vec![H512::from(H256::from(keccak256(&raw_tx)))],
+        "Transaction hash should be the hash of the signed transaction"
+    );
+}
+
+/// Test that submit broadcasts transactions publicly once the fallback window has passed
+#[tokio::test]
+async fn test_submit_falls_back_to_public_mempool() {
+    let (payload_db, tx_db, nonce_db) = tmp_dbs();
+    let (url, requests) = mock_server(serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "result": "0x1234",
+    }))
+    .await;
+
+    let hash = H256::random();
+    let mut provider = mock_provider_at_block(45);
+    provider.expect_sign().never();
+    provider
+        .expect_send()
+        .times(1)
+        .returning(move |_, _| Ok(hash));
+
+    let block_time = Duration::from_millis(100);
+    let mut adapter = mock_ethereum_adapter(
+        provider,
+        payload_db.clone(),
+        tx_db,
+        nonce_db,
+        Address::random(),
+        block_time,
+        block_time,
+    );
+    adapter.private_submitter = Some(private_submitter(url, 3));
+
+    let mut tx = build_and_store_transaction(&adapter, &payload_db).await;
+    // first sent privately 3 blocks ago
+    tx.precursor_mut().private_submission_block = Some(42);
+    adapter.submit(&mut tx).await.unwrap();
+
+    assert!(requests.lock().await.is_empty());
+    assert_eq!(tx.precursor().private_submission_block, Some(42));
+    assert_eq!(tx.tx_hashes, vec![H512::from(hash)]);
+}
+
 // Helper function to build and store a transaction
 async fn build_and_store_transaction(
     adapter: &crate::adapter::chains::ethereum::EthereumAdapter,
@@ -1,7 +1,7 @@
 use ethers_core::abi::Int;
 use prometheus::{
     opts, register_int_gauge_vec_with_registry, register_int_gauge_with_registry, Encoder,
-    IntCounterVec, IntGauge, IntGaugeVec, Registry,
+    IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Registry,
 };
 use serde_json::to_string;
 
@@ -22,6 +22,8 @@ pub struct EthereumAdapterMetrics {
     /// Counts how many times we've noticed the nonce in tx is different from nonce
     /// stored in db
     mismatch_nonce: IntGauge,
+    /// Number of transactions assigned to the signer
+    assigned_transactions: IntCounter,
 }
 
 impl EthereumAdapterMetrics {
@@ -31,6 +33,7 @@ impl EthereumAdapterMetrics {
         finalized_nonce: IntGauge,
         upper_nonce: IntGauge,
         mismatch_nonce: IntGauge,
+        assigned_transactions: IntCounter,
     ) -> Self {
         Self {
             domain,
@@ -38,6 +41,7 @@ impl EthereumAdapterMetrics {
             finalized_nonce,
             upper_nonce,
             mismatch_nonce,
+            assigned_transactions,
         }
     }
 
@@ -66,6 +70,10 @@ impl EthereumAdapterMetrics {
     pub fn get_mismatched_nonce(&self) -> &IntGauge {
         &self.mismatch_nonce
     }
+
+    pub fn increment_assigned_transactions(&self) {
+        self.assigned_transactions.inc();
+    }
 }
 
 #[cfg(test)]
@@ -83,6 +91,7 @@ impl EthereumAdapterMetrics {
             dispatcher_metrics.get_finalized_nonce(domain, signer),
             dispatcher_metrics.get_upper_nonce(domain, signer),
             dispatcher_metrics.get_mismatched_nonce(domain, signer),
+            dispatcher_metrics.get_assigned_transactions(domain, signer),
         )
     }
 
@@ -93,4 +102,8 @@ impl EthereumAdapterMetrics {
     pub fn get_upper_nonce(&self) -> i64 {
         self.upper_nonce.get()
     }
+
+    pub fn get_assigned_transactions(&self) -> u64 {
+        self.assigned_transactions.get()
+    }
 }
@@ -23,6 +23,7 @@ pub fn dummy_tx(
             constant: None,
             state_mutability: Default::default(),
         },
+        private_submission_block: None,
     };
     if let Some(n) = nonce {
         precursor.tx.set_nonce(n);
@@ -13,6 +13,10 @@ use ethers_core::types::transaction::eip2718::TypedTransaction::{Eip1559, Eip293
 pub struct EthereumTxPrecursor {
     pub tx: TypedTransaction,
     pub function: Function,
+    /// Latest block number at the time the transaction was first sent
+    /// through the private submission endpoint, if it was
+    #[serde(default)]
+    pub private_submission_block: Option<u64>,
 }
 
 impl Debug for EthereumTxPrecursor {
@@ -29,6 +33,7 @@ impl Debug for EthereumTxPrecursor {
             .field("tx.chain_id", &self.tx.chain_id())
             .field("tx.value", &self.tx.value())
             .field("function.name", &self.function.name)
+            .field("private_submission_block", &self.private_submission_block)
             .finish()
     }
 }
@@ -53,7 +58,11 @@ impl From<EthereumTxPrecursor> for VmSpecificTxData {
 
 impl EthereumTxPrecursor {
     pub fn new(tx: TypedTransaction, function: Function) -> Self {
-        Self { tx, function }
+        Self {
+            tx,
+            function,
+            private_submission_block: None,
+        }
     }
 
     pub fn from_payload(payload: &FullPayload, signer: H160) -> Self {
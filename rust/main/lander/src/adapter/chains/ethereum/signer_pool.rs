@@ -0,0 +1,177 @@
+use std::iter;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use ethers::prelude::H160;
+use futures_util::future;
+use tracing::debug;
+
+use hyperlane_core::config::SignerAssignment;
+use hyperlane_ethereum::EvmProviderForLander;
+
+use crate::transaction::Transaction;
+use crate::LanderError;
+
+use super::metrics::EthereumAdapterMetrics;
+use super::nonce::NonceManager;
+use super::transaction::Precursor;
+
+/// A signer together with the provider which signs its transactions and
+/// the nonce sequence it owns.
+pub struct SignerLane {
+    pub signer: H160,
+    pub provider: Arc<dyn EvmProviderForLander>,
+    pub nonce_manager: NonceManager,
+    pub metrics: EthereumAdapterMetrics,
+    in_flight: AtomicUsize,
+}
+
+impl SignerLane {
+    pub fn new(
+        signer: H160,
+        provider: Arc<dyn EvmProviderForLander>,
+        nonce_manager: NonceManager,
+        metrics: EthereumAdapterMetrics,
+    ) -> Self {
+        Self {
+            signer,
+            provider,
+            nonce_manager,
+            metrics,
+            in_flight: AtomicUsize::new(0),
+        }
+    }
+
+    /// Number of transactions assigned to this lane which are not finalized or dropped yet.
+    /// Transactions which were in flight before a restart are not counted.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::Relaxed)
+    }
+
+    pub fn track_in_flight(&self, count: usize) {
+        self.in_flight.fetch_add(count, Ordering::Relaxed);
+    }
+
+    pub fn release_in_flight(&self, count: usize) {
+        // transactions from before a restart were never tracked, so don't go below zero
+        let _ = self
+            .in_flight
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |in_flight| {
+                Some(in_flight.saturating_sub(count))
+            });
+    }
+}
+
+/// The signers a chain submits transactions with. Every transaction stays on
+/// the lane it was assigned to when it was built, so a stuck nonce only
+/// blocks the transactions of its own lane.
+pub struct SignerPool {
+    primary: SignerLane,
+    additional: Vec<SignerLane>,
+    assignment: SignerAssignment,
+    next_lane: AtomicUsize,
+}
+
+impl SignerPool {
+    pub fn new(
+        primary: SignerLane,
+        additional: Vec<SignerLane>,
+        assignment: SignerAssignment,
+    ) -> Self {
+        Self {
+            primary,
+            additional,
+            assignment,
+            next_lane: AtomicUsize::new(0),
+        }
+    }
+
+    /// The lane of the chain's configured `signer`
+    pub fn primary(&self) -> &SignerLane {
+        &self.primary
+    }
+
+    pub fn lanes(&self) -> impl Iterator<Item = &SignerLane> {
+        iter::once(&self.primary).chain(self.additional.iter())
+    }
+
+    pub fn len(&self) -> usize {
+        self.additional.len().saturating_add(1)
+    }
+
+    pub fn lane(&self, signer: &H160) -> Option<&SignerLane> {
+        self.lanes().find(|lane| &lane.signer == signer)
+    }
+
+    /// Returns the lane which sends the given transaction, based on its `from` address.
+    pub fn lane_for_tx(&self, tx: &Transaction) -> Result<&SignerLane, LanderError> {
+        let from = tx
+            .precursor()
+            .tx
+            .from()
+            .ok_or(LanderError::TxSubmissionError(
+                "Transaction missing address".to_string(),
+            ))?;
+        self.lane(from).ok_or_else(|| {
+            LanderError::TxSubmissionError(format!(
+                "Transaction from address {from:?} is not in the signer pool"
+            ))
+        })
+    }
+
+    /// Picks the lane a new transaction should be sent from and counts the
+    /// transaction as in flight on it, see `release`.
+    pub fn assign(&self) -> &SignerLane {
+        let lane = if self.additional.is_empty() {
+            &self.primary
+        } else {
+            match self.assignment {
+                SignerAssignment::RoundRobin => self.next_round_robin(),
+                SignerAssignment::LeastPending => self.least_pending(),
+            }
+        };
+        lane.track_in_flight(1);
+        lane.metrics.increment_assigned_transactions();
+        debug!(signer = ?lane.signer, assignment = ?self.assignment, "assigned signer lane");
+        lane
+    }
+
+    fn next_round_robin(&self) -> &SignerLane {
+        let index = self
+            .next_lane
+            .fetch_add(1, Ordering::Relaxed)
+            .checked_rem(self.len())
+            .unwrap_or_default();
+        self.lanes().nth(index).unwrap_or(&self.primary)
+    }
+
+    /// The lane with the fewest transactions in flight, preferring earlier lanes on a tie.
+    /// Assignments are counted right away, so consecutive assignments spread across
+    /// the lanes even before their transactions are sent.
+    fn least_pending(&self) -> &SignerLane {
+        self.lanes()
+            .min_by_key(|lane| lane.in_flight())
+            .unwrap_or(&self.primary)
+    }
+
+    /// Stops counting a finalized or dropped transaction as in flight on its lane.
+    pub fn release(&self, tx: &Transaction) {
+        match self.lane_for_tx(tx) {
+            Ok(lane) => lane.release_in_flight(1),
+            Err(err) => debug!(?err, tx_uuid = ?tx.uuid, "no signer lane to release"),
+        }
+    }
+
+    /// Updates the boundary nonces of every lane.
+    pub async fn update_boundaries(&self) -> Result<(), LanderError> {
+        future::try_join_all(
+            self.lanes()
+                .map(|lane| lane.nonce_manager.nonce_updater.update_boundaries()),
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use ethers::types::Address;
+
+use hyperlane_core::config::SignerAssignment;
+
+use crate::adapter::chains::ethereum::tests::{dummy_evm_tx, ExpectedTxType, MockEvmProvider};
+use crate::tests::evm::test_utils::mock_signer_lane;
+use crate::tests::test_utils::tmp_dbs;
+use crate::{LanderError, TransactionStatus};
+
+use super::{SignerLane, SignerPool};
+
+fn lanes(count: usize) -> Vec<SignerLane> {
+    let (_, tx_db, nonce_db) = tmp_dbs();
+    (0..count)
+        .map(|_| {
+            mock_signer_lane(
+                Arc::new(MockEvmProvider::new()),
+                tx_db.clone(),
+                nonce_db.clone(),
+                Address::random(),
+                Duration::from_secs(1),
+            )
+        })
+        .collect()
+}
+
+fn pool(mut lanes: Vec<SignerLane>, assignment: SignerAssignment) -> SignerPool {
+    let primary = lanes.remove(0);
+    SignerPool::new(primary, lanes, assignment)
+}
+
+#[tokio::test]
+async fn test_single_lane_is_always_assigned() {
+    let pool = pool(lanes(1), SignerAssignment::RoundRobin);
+    let primary = pool.primary().signer;
+
+    for _ in 0..3 {
+        assert_eq!(pool.assign().signer, primary);
+    }
+    assert_eq!(pool.primary().metrics.get_assigned_transactions(), 3);
+}
+
+#[tokio::test]
+async fn test_round_robin_assignment() {
+    let pool = pool(lanes(3), SignerAssignment::RoundRobin);
+    let signers: Vec<_> = pool.lanes().map(|lane| lane.signer).collect();
+
+    let mut assigned = Vec::new();
+    for _ in 0..6 {
+        assigned.push(pool.assign().signer);
+    }
+
+    let expected: Vec<_> = signers.iter().chain(signers.iter()).copied().collect();
+    assert_eq!(assigned, expected);
+    for lane in pool.lanes() {
+        assert_eq!(lane.metrics.get_assigned_transactions(), 2);
+    }
+}
+
+#[tokio::test]
+async fn test_least_pending_assignment() {
+    let pool = pool(lanes(3), SignerAssignment::LeastPending);
+    let signers: Vec<_> = pool.lanes().map(|lane| lane.signer).collect();
+
+    // assignments count as in flight right away, so they spread across the lanes
+    let assigned: Vec<_> = (0..3).map(|_| pool.assign().signer).collect();
+    assert_eq!(assigned, signers);
+
+    // the second lane has the fewest transactions in flight once its transaction is done
+    pool.lanes().nth(1).unwrap().release_in_flight(1);
+    assert_eq!(pool.assign().signer, signers[1]);
+}
+
+#[tokio::test]
+async fn test_least_pending_prefers_primary_on_tie() {
+    let pool = pool(lanes(3), SignerAssignment::LeastPending);
+    let primary = pool.primary().signer;
+
+    assert_eq!(pool.assign().signer, primary);
+}
+
+#[tokio::test]
+async fn test_release_tx() {
+    let pool = pool(lanes(2), SignerAssignment::LeastPending);
+    let lane = pool.assign();
+    assert_eq!(lane.in_flight(), 1);
+
+    let tx = dummy_evm_tx(
+        ExpectedTxType::Eip1559,
+        vec![],
+        TransactionStatus::Finalized,
+        lane.signer,
+    );
+    pool.release(&tx);
+    assert_eq!(lane.in_flight(), 0);
+
+    // transactions from before a restart were never counted
+    pool.release(&tx);
+    assert_eq!(lane.in_flight(), 0);
+}
+
+#[tokio::test]
+async fn test_lane_for_tx() {
+    let pool = pool(lanes(2), SignerAssignment::RoundRobin);
+    let second = pool.lanes().nth(1).unwrap().signer;
+
+    let tx = dummy_evm_tx(
+        ExpectedTxType::Eip1559,
+        vec![],
+        TransactionStatus::PendingInclusion,
+        second,
+    );
+    assert_eq!(pool.lane_for_tx(&tx).unwrap().signer, second);
+
+    let tx = dummy_evm_tx(
+        ExpectedTxType::Eip1559,
+        vec![],
+        TransactionStatus::PendingInclusion,
+        Address::random(),
+    );
+    assert!(matches!(
+        pool.lane_for_tx(&tx),
+        Err(LanderError::TxSubmissionError(_))
+    ));
+}
@@ -5,7 +5,9 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use ethers::abi::Function;
 use ethers::prelude::{Block, BlockNumber, FeeHistory, TransactionReceipt, U256 as EthersU256};
-use ethers::types::{transaction::eip2718::TypedTransaction, Address, H160, H256 as EthersH256};
+use ethers::types::{
+    transaction::eip2718::TypedTransaction, Address, Bytes, H160, H256 as EthersH256,
+};
 use ethers_core::abi::{Param, ParamType, StateMutability};
 use ethers_core::types::{Eip1559TransactionRequest, TransactionRequest};
 
@@ -69,6 +71,9 @@ mockall::mock! {
         /// Send transaction into blockchain
         async fn send(&self, tx: &TypedTransaction, function: &Function) -> ChainResult<H256>;
 
+        /// Fill and sign a transaction without broadcasting it
+        async fn sign(&self, tx: &TypedTransaction) -> ChainResult<Bytes>;
+
         /// Read-only call into blockchain which returns a boolean
         async fn check(&self, tx: &TypedTransaction, function: &Function) -> ChainResult<bool>;
 
@@ -165,7 +170,7 @@ pub fn dummy_tx_precursor(tx_type: ExpectedTxType, signer: H160) -> EthereumTxPr
         }),
         ExpectedTxType::Eip2930 => todo!(),
     };
-    EthereumTxPrecursor { tx, function }
+    EthereumTxPrecursor::new(tx, function)
 }
 
 pub fn dummy_evm_function() -> Function {
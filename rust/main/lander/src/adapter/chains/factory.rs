@@ -3,7 +3,7 @@
 
 use std::sync::Arc;
 
-use eyre::Result;
+use eyre::{bail, Result};
 
 use hyperlane_base::{
     db::HyperlaneRocksDB,
@@ -16,8 +16,9 @@ use hyperlane_radix::RadixProvider;
 use crate::adapter::chains::aleo::AleoAdapter;
 use crate::adapter::{
     chains::{
-        cosmos::CosmosAdapter, ethereum::EthereumAdapter, radix::adapter::RadixAdapter,
-        sealevel::SealevelAdapter, tron::adapter::TronAdapter,
+        cosmos::CosmosAdapter, cosmos_native::CosmosNativeAdapter, ethereum::EthereumAdapter,
+        radix::adapter::RadixAdapter, sealevel::SealevelAdapter, starknet::StarknetAdapter,
+        tron::adapter::TronAdapter,
     },
     AdaptsChain,
 };
@@ -45,17 +46,29 @@ impl AdapterFactory {
                 )
                 .await?,
             ),
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(_) => {
+                bail!("Fuel is not supported by the payload dispatcher")
+            }
             ChainConnectionConf::Sealevel(_) => Arc::new(SealevelAdapter::new(
                 conf.clone(),
                 raw_conf.clone(),
                 core_metrics,
             )?),
-            ChainConnectionConf::Cosmos(_) => {
-                Arc::new(CosmosAdapter::new(conf.clone(), raw_conf.clone()))
+            ChainConnectionConf::Cosmos(connection_conf) => {
+                let adapter =
+                    CosmosAdapter::from_conf(conf, core_metrics, &connection_conf).await?;
+                Arc::new(adapter)
+            }
+            ChainConnectionConf::Starknet(connection_conf) => {
+                let adapter =
+                    StarknetAdapter::from_conf(conf, core_metrics, &connection_conf).await?;
+                Arc::new(adapter)
+            }
+            ChainConnectionConf::CosmosNative(connection_conf) => {
+                let adapter =
+                    CosmosNativeAdapter::from_conf(conf, core_metrics, &connection_conf).await?;
+                Arc::new(adapter)
             }
-            ChainConnectionConf::Starknet(_) => todo!(),
-            ChainConnectionConf::CosmosNative(_) => todo!(),
             ChainConnectionConf::Radix(connection_conf) => {
                 let adapter = RadixAdapter::from_conf(conf, core_metrics, &connection_conf)?;
                 Arc::new(adapter)
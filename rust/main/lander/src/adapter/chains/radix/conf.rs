@@ -42,6 +42,7 @@ mod tests {
         ChainConf {
             domain,
             signer,
+            signer_pool: vec![],
             submitter: SubmitterType::Lander,
             estimated_block_time: Duration::from_secs(1),
             reorg_period: ReorgPeriod::None,
@@ -103,6 +104,8 @@ mod tests {
                 transaction_overrides: TransactionOverrides::default(),
                 op_submission_config: OpSubmissionConfig::default(),
                 consider_null_transaction_receipt: false,
+                log_subscription_url: None,
+                private_submission: None,
             }),
         );
 
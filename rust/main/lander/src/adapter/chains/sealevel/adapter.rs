@@ -99,7 +99,7 @@ impl SealevelAdapter {
             connection_conf,
         );
 
-        let oracle = connection_conf.priority_fee_oracle.create_oracle();
+        let oracle = connection_conf.priority_fee_oracle.create_oracle()?;
 
         let submitter = connection_conf.transaction_submitter.create_submitter(
             &Arc::new(provider.clone()),
@@ -0,0 +1,10 @@
+pub mod adapter;
+pub mod conf;
+
+mod nonce;
+mod precursor;
+mod submit;
+
+pub use adapter::StarknetAdapter;
+pub use nonce::NonceManager;
+pub use precursor::*;
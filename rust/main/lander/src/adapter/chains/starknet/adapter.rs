@@ -0,0 +1,248 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tracing::{debug, warn};
+
+use hyperlane_base::{settings::ChainConf, CoreMetrics};
+use hyperlane_core::{ChainCommunicationError, ReorgPeriod, H512, U256};
+use hyperlane_starknet::{
+    StarknetLanderProvider, StarknetProvider, StarknetProviderForLander, StarknetTxCalldata,
+};
+
+use crate::adapter::chains::starknet::{
+    conf::create_signer, precursor::Precursor, submit::submit_transaction, NonceManager,
+};
+use crate::{
+    adapter::{chains::starknet::StarknetTxPrecursor, AdaptsChain, GasLimit, TxBuildingResult},
+    payload::PayloadDetails,
+    transaction::Transaction,
+    DispatcherMetrics, FullPayload, LanderError, TransactionStatus,
+};
+
+/// Number of blocks to wait for a submitted transaction before resubmitting it with higher prices
+const RESUBMISSION_BLOCKS: u32 = 10;
+
+pub struct StarknetAdapter {
+    pub provider: Arc<dyn StarknetProviderForLander>,
+    pub nonces: NonceManager,
+    pub estimated_block_time: Duration,
+    pub reorg_period: ReorgPeriod,
+}
+
+impl StarknetAdapter {
+    pub async fn from_conf(
+        conf: &ChainConf,
+        metrics: &CoreMetrics,
+        connection_conf: &hyperlane_starknet::ConnectionConf,
+    ) -> Result<Self, LanderError> {
+        // We must have a signer if we want to land transactions.
+        let signer = create_signer(conf).await?;
+
+        let chain_info = conf.metrics_conf().chain;
+        let client_metrics = metrics.client_metrics();
+
+        let provider = StarknetProvider::new(
+            conf.domain.clone(),
+            connection_conf,
+            client_metrics,
+            chain_info,
+        )?;
+        let provider = StarknetLanderProvider::new(provider, signer).await?;
+
+        Ok(Self {
+            provider: Arc::new(provider),
+            nonces: NonceManager::default(),
+            estimated_block_time: conf.estimated_block_time,
+            reorg_period: conf.reorg_period.clone(),
+        })
+    }
+
+    /// Returns true if a transaction included in the given block can no longer be reorged
+    async fn block_is_final(&self, block_number: u64) -> Result<bool, LanderError> {
+        let reorg_blocks = match &self.reorg_period {
+            ReorgPeriod::None => 0,
+            ReorgPeriod::Blocks(blocks) => u64::from(blocks.get()),
+            // Tags are not supported by Starknet nodes, so only L1 acceptance is final
+            ReorgPeriod::Tag(_) => return Ok(false),
+        };
+
+        let tip = self.provider.get_block_number().await?;
+        Ok(block_number.saturating_add(reorg_blocks) <= tip)
+    }
+}
+
+#[async_trait]
+impl AdaptsChain for StarknetAdapter {
+    /// Estimates the L2 gas of the Payload. Called in the Building Stage (PayloadDispatcher)
+    async fn estimate_gas_limit(
+        &self,
+        payload: &FullPayload,
+    ) -> Result<Option<GasLimit>, LanderError> {
+        let precursor = StarknetTxPrecursor::from_data(&payload.data)?;
+        let nonce = self.provider.get_nonce().await?;
+        let bounds = self
+            .provider
+            .estimate_fee(&precursor.calldata, nonce)
+            .await?;
+        Ok(Some(U256::from(bounds.l2_gas)))
+    }
+
+    /// Performs batching if available. Internally estimates gas limit for batch as well. Called in the Building Stage (PayloadDispatcher)
+    async fn build_transactions(&self, payloads: &[FullPayload]) -> Vec<TxBuildingResult> {
+        // we only support single payload transactions for now
+        payloads
+            .iter()
+            .map(|payload| {
+                let tx = StarknetTxPrecursor::from_data(&payload.data)
+                    .map(|precursor| Transaction::new(precursor, vec![payload.details.clone()]))
+                    .ok();
+                TxBuildingResult {
+                    payloads: vec![payload.details.clone()],
+                    maybe_tx: tx,
+                }
+            })
+            .collect::<Vec<_>>()
+    }
+
+    /// Simulates a Transaction before submitting it for the first time. Called in the Inclusion Stage (PayloadDispatcher)
+    /// The fee estimate of the simulation is kept, so that `estimate_tx` does not simulate again.
+    /// Payloads are only reported as reverted if executing them failed, not if the chain couldn't be reached.
+    async fn simulate_tx(&self, tx: &mut Transaction) -> Result<Vec<PayloadDetails>, LanderError> {
+        let nonce = self.provider.get_nonce().await?;
+        let precursor = tx.precursor_mut();
+        match self.provider.estimate_fee(&precursor.calldata, nonce).await {
+            Ok(bounds) => {
+                precursor.estimated_bounds = Some(bounds);
+                Ok(vec![])
+            }
+            // a transaction only carries a single payload, so it has reverted
+            Err(err @ ChainCommunicationError::ContractError(_)) => {
+                warn!(?err, "Starknet transaction reverted in simulation");
+                Ok(tx.payload_details.clone())
+            }
+            Err(err) => {
+                warn!(?err, "failed to simulate Starknet transaction");
+                Err(LanderError::SimulationFailed(vec![err.to_string()]))
+            }
+        }
+    }
+
+    /// Estimates a Transaction's resource bounds. Called in the Inclusion Stage (PayloadDispatcher)
+    /// Skips estimation if the Transaction has already been estimated
+    async fn estimate_tx(&self, tx: &mut Transaction) -> Result<(), LanderError> {
+        if tx.precursor().estimated_bounds.is_some() {
+            debug!(
+                ?tx,
+                "skipping fee estimation for transaction, as it was already estimated"
+            );
+            return Ok(());
+        }
+
+        let nonce = self.provider.get_nonce().await?;
+        let precursor = tx.precursor_mut();
+        let bounds = self
+            .provider
+            .estimate_fee(&precursor.calldata, nonce)
+            .await
+            .map_err(|_| LanderError::EstimationFailed)?;
+        precursor.estimated_bounds = Some(bounds);
+
+        Ok(())
+    }
+
+    /// Sets the nonce, sets / escalates the resource bounds and sends the Transaction. Even if sending fails, the Transaction struct remains mutated with the new values. Called in the Inclusion Stage (PayloadDispatcher)
+    async fn submit(&self, tx: &mut Transaction) -> Result<(), LanderError> {
+        submit_transaction(&*self.provider, &self.nonces, tx).await
+    }
+
+    /// Transactions accepted on L1 are final. Transactions in L2 blocks are final once
+    /// they are deeper than the reorg period.
+    async fn get_tx_hash_status(&self, hash: H512) -> Result<TransactionStatus, LanderError> {
+        let receipt = self
+            .provider
+            .get_tx_receipt(&hash)
+            .await
+            .map_err(|err| LanderError::TxHashNotFound(err.to_string()))?;
+
+        debug!(?hash, ?receipt, "tx receipt");
+        if receipt.reverted {
+            // the payloads of the transaction are reported as reverted in the Finality Stage
+            warn!(?hash, "Starknet transaction reverted");
+        }
+
+        let Some(block_number) = receipt.block_number else {
+            // the transaction is only part of the pending block
+            return Ok(TransactionStatus::Mempool);
+        };
+
+        if receipt.accepted_on_l1 || self.block_is_final(block_number).await? {
+            Ok(TransactionStatus::Finalized)
+        } else {
+            Ok(TransactionStatus::Included)
+        }
+    }
+
+    /// Returns the payloads which were not delivered by a finalized Transaction.
+    /// Called in the Finality Stage (PayloadDispatcher).
+    async fn reverted_payloads(
+        &self,
+        tx: &Transaction,
+    ) -> Result<Vec<PayloadDetails>, LanderError> {
+        let payloads = tx
+            .payload_details
+            .iter()
+            .filter_map(|x| x.success_criteria.clone().map(|data| (data, x)));
+
+        let mut reverted = Vec::new();
+
+        for (data, payload) in payloads {
+            let calldata = serde_json::from_slice::<StarknetTxCalldata>(&data)
+                .map_err(|err| eyre::eyre!("Failed to deserialize success criteria: {err}"))?;
+            let delivered = self.provider.call_bool(&calldata).await?;
+            if !delivered {
+                reverted.push(payload.clone());
+            }
+        }
+
+        Ok(reverted)
+    }
+
+    /// Return true if the transaction can be resubmitted. Called in the Inclusion Stage (PayloadDispatcher).
+    /// Starknet transactions do not expire, so a submitted transaction is only replaced
+    /// with higher prices once it has been pending for `RESUBMISSION_BLOCKS` blocks.
+    async fn tx_ready_for_resubmission(&self, tx: &Transaction) -> bool {
+        let Some(last_submission_time) = tx.last_submission_attempt else {
+            return true;
+        };
+
+        let elapsed = Utc::now()
+            .signed_duration_since(last_submission_time)
+            .to_std()
+            .unwrap_or_default();
+        elapsed
+            >= self
+                .estimated_block_time
+                .saturating_mul(RESUBMISSION_BLOCKS)
+    }
+
+    /// Resubmissions replace pending transactions with the same nonce, so gaps are never left behind.
+    async fn nonce_gap_exists(&self) -> bool {
+        false
+    }
+
+    async fn replace_tx(&self, _tx: &Transaction) -> Result<(), LanderError> {
+        Ok(())
+    }
+
+    /// Returns the estimated block time of the chain. Used for polling pending transactions. Called in the Inclusion and Finality Stages of the PayloadDispatcher
+    fn estimated_block_time(&self) -> &Duration {
+        &self.estimated_block_time
+    }
+
+    /// Update any metrics related to sent transactions, such as gas price, nonce, etc.
+    fn update_vm_specific_metrics(&self, _tx: &Transaction, _metrics: &DispatcherMetrics) {}
+}
+
+#[cfg(test)]
+mod tests;
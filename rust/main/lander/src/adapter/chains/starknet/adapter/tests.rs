@@ -0,0 +1,402 @@
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use std::time::Duration;
+
+use hyperlane_core::{ChainCommunicationError, ChainResult, ReorgPeriod, H256, H512, U256};
+use hyperlane_starknet::{
+    StarknetProviderForLander, StarknetResourceBounds, StarknetTxCalldata, StarknetTxReceipt,
+};
+
+use crate::adapter::chains::starknet::{
+    NonceManager, Precursor, StarknetAdapter, StarknetTxPrecursor,
+};
+use crate::adapter::AdaptsChain;
+use crate::payload::{FullPayload, PayloadDetails};
+use crate::transaction::{Transaction, TransactionStatus};
+use crate::LanderError;
+
+mockall::mock! {
+    pub StarknetProvider {}
+
+    #[async_trait::async_trait]
+    impl StarknetProviderForLander for StarknetProvider {
+        async fn estimate_fee(
+            &self,
+            calldata: &StarknetTxCalldata,
+            nonce: u64,
+        ) -> ChainResult<StarknetResourceBounds>;
+        async fn get_nonce(&self) -> ChainResult<u64>;
+        async fn get_block_number(&self) -> ChainResult<u64>;
+        async fn send(
+            &self,
+            calldata: &StarknetTxCalldata,
+            nonce: u64,
+            bounds: &StarknetResourceBounds,
+        ) -> ChainResult<H256>;
+        async fn get_tx_receipt(&self, hash: &H512) -> ChainResult<StarknetTxReceipt>;
+        async fn call_bool(&self, calldata: &StarknetTxCalldata) -> ChainResult<bool>;
+    }
+}
+
+fn adapter(provider: MockStarknetProvider) -> StarknetAdapter {
+    StarknetAdapter {
+        provider: Arc::new(provider),
+        nonces: NonceManager::default(),
+        estimated_block_time: Duration::from_secs(2),
+        reorg_period: ReorgPeriod::Blocks(NonZeroU32::new(5).unwrap()),
+    }
+}
+
+fn calldata() -> StarknetTxCalldata {
+    StarknetTxCalldata {
+        to: H256::from_low_u64_be(1),
+        selector: H256::from_low_u64_be(2),
+        calldata: vec![H256::from_low_u64_be(3)],
+    }
+}
+
+fn delivered_calldata() -> StarknetTxCalldata {
+    StarknetTxCalldata {
+        to: H256::from_low_u64_be(1),
+        selector: H256::from_low_u64_be(4),
+        calldata: vec![H256::from_low_u64_be(5), H256::zero()],
+    }
+}
+
+fn estimated_bounds() -> StarknetResourceBounds {
+    StarknetResourceBounds {
+        l1_gas: 0,
+        l1_gas_price: 100,
+        l2_gas: 80_000,
+        l2_gas_price: 200,
+        l1_data_gas: 100,
+        l1_data_gas_price: 300,
+    }
+}
+
+fn payload() -> FullPayload {
+    FullPayload {
+        data: serde_json::to_vec(&calldata()).unwrap(),
+        details: PayloadDetails {
+            success_criteria: Some(serde_json::to_vec(&delivered_calldata()).unwrap()),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn transaction() -> Transaction {
+    Transaction::new(
+        StarknetTxPrecursor::new(calldata()),
+        vec![payload().details],
+    )
+}
+
+fn estimated_transaction() -> Transaction {
+    let mut tx = transaction();
+    tx.precursor_mut().estimated_bounds = Some(estimated_bounds());
+    tx
+}
+
+fn receipt(block_number: Option<u64>, accepted_on_l1: bool) -> StarknetTxReceipt {
+    StarknetTxReceipt {
+        block_number,
+        accepted_on_l1,
+        reverted: false,
+    }
+}
+
+#[tokio::test]
+async fn test_build_transactions() {
+    let adapter = adapter(MockStarknetProvider::new());
+    let invalid = FullPayload {
+        data: b"not calldata".to_vec(),
+        ..Default::default()
+    };
+
+    let results = adapter.build_transactions(&[payload(), invalid]).await;
+
+    assert_eq!(results.len(), 2);
+    let tx = results[0].maybe_tx.as_ref().expect("tx should be built");
+    assert_eq!(tx.precursor(), &StarknetTxPrecursor::new(calldata()));
+    assert!(results[1].maybe_tx.is_none());
+}
+
+#[tokio::test]
+async fn test_estimate_gas_limit() {
+    let mut provider = MockStarknetProvider::new();
+    provider.expect_get_nonce().returning(|| Ok(4));
+    provider
+        .expect_estimate_fee()
+        .withf(|calldata, nonce| calldata == &self::calldata() && *nonce == 4)
+        .returning(|_, _| Ok(estimated_bounds()));
+    let adapter = adapter(provider);
+
+    let gas_limit = adapter.estimate_gas_limit(&payload()).await.unwrap();
+
+    assert_eq!(gas_limit, Some(U256::from(80_000)));
+}
+
+#[tokio::test]
+async fn test_simulate_tx_keeps_estimate() {
+    let mut provider = MockStarknetProvider::new();
+    provider.expect_get_nonce().returning(|| Ok(4));
+    provider
+        .expect_estimate_fee()
+        .times(1)
+        .returning(|_, _| Ok(estimated_bounds()));
+    let adapter = adapter(provider);
+    let mut tx = transaction();
+
+    let reverted = adapter.simulate_tx(&mut tx).await.unwrap();
+    // already estimated, so the provider is not called again
+    adapter.estimate_tx(&mut tx).await.unwrap();
+
+    assert!(reverted.is_empty());
+    assert_eq!(tx.precursor().estimated_bounds, Some(estimated_bounds()));
+}
+
+#[tokio::test]
+async fn test_simulate_tx_revert_reverts_payloads() {
+    let mut provider = MockStarknetProvider::new();
+    provider.expect_get_nonce().returning(|| Ok(4));
+    provider.expect_estimate_fee().returning(|_, _| {
+        Err(ChainCommunicationError::from_contract_error_str(
+            "Error during execution: Provider(StarknetError(TransactionExecutionError))",
+        ))
+    });
+    let adapter = adapter(provider);
+    let mut tx = transaction();
+
+    let reverted = adapter.simulate_tx(&mut tx).await.unwrap();
+
+    assert_eq!(reverted, tx.payload_details);
+}
+
+#[tokio::test]
+async fn test_simulate_tx_network_failure_keeps_payloads() {
+    let mut provider = MockStarknetProvider::new();
+    provider.expect_get_nonce().returning(|| Ok(4));
+    provider.expect_estimate_fee().returning(|_, _| {
+        Err(ChainCommunicationError::from_other_str(
+            "Error during execution: Provider(RateLimited)",
+        ))
+    });
+    let adapter = adapter(provider);
+    let mut tx = transaction();
+
+    let result = adapter.simulate_tx(&mut tx).await;
+
+    assert!(matches!(result, Err(LanderError::SimulationFailed(_))));
+    assert_eq!(tx.precursor().estimated_bounds, None);
+}
+
+#[tokio::test]
+async fn test_submit() {
+    let mut provider = MockStarknetProvider::new();
+    provider.expect_get_nonce().returning(|| Ok(9));
+    provider
+        .expect_send()
+        .withf(|calldata, nonce, bounds| {
+            calldata == &self::calldata()
+                && *nonce == 9
+                && bounds.l2_gas == 120_000
+                && bounds.l2_gas_price == 300
+        })
+        .times(1)
+        .returning(|_, _, _| Ok(H256::random()));
+    let adapter = adapter(provider);
+    let mut tx = estimated_transaction();
+
+    adapter.submit(&mut tx).await.unwrap();
+
+    assert_eq!(tx.tx_hashes.len(), 1);
+    assert_eq!(tx.precursor().nonce, Some(9));
+    assert!(tx.last_submission_attempt.is_some());
+}
+
+#[tokio::test]
+async fn test_submit_reserves_consecutive_nonces() {
+    let mut provider = MockStarknetProvider::new();
+    provider.expect_get_nonce().returning(|| Ok(9));
+    provider
+        .expect_send()
+        .returning(|_, _, _| Ok(H256::random()));
+    let adapter = adapter(provider);
+    let mut first = estimated_transaction();
+    let mut second = estimated_transaction();
+
+    adapter.submit(&mut first).await.unwrap();
+    adapter.submit(&mut second).await.unwrap();
+
+    assert_eq!(first.precursor().nonce, Some(9));
+    assert_eq!(second.precursor().nonce, Some(10));
+}
+
+#[tokio::test]
+async fn test_resubmission_replaces_tx_with_higher_prices() {
+    let mut provider = MockStarknetProvider::new();
+    provider.expect_get_nonce().returning(|| Ok(9));
+    provider
+        .expect_send()
+        .returning(|_, _, _| Ok(H256::random()));
+    let adapter = adapter(provider);
+    let mut tx = estimated_transaction();
+
+    adapter.submit(&mut tx).await.unwrap();
+    adapter.submit(&mut tx).await.unwrap();
+
+    // the pending transaction is replaced, so the nonce is kept
+    assert_eq!(tx.tx_hashes.len(), 2);
+    assert_eq!(tx.precursor().nonce, Some(9));
+    let bounds = tx.precursor().resource_bounds.unwrap();
+    assert_eq!(bounds.l2_gas, 120_000);
+    assert_eq!(bounds.l2_gas_price, 330);
+}
+
+#[tokio::test]
+async fn test_resubmission_after_nonce_was_used() {
+    let mut provider = MockStarknetProvider::new();
+    provider.expect_get_nonce().returning(|| Ok(12));
+    provider
+        .expect_send()
+        .returning(|_, _, _| Ok(H256::random()));
+    let adapter = adapter(provider);
+    let mut tx = estimated_transaction();
+    tx.precursor_mut().nonce = Some(9);
+
+    adapter.submit(&mut tx).await.unwrap();
+
+    assert_eq!(tx.precursor().nonce, Some(12));
+}
+
+#[tokio::test]
+async fn test_submit_invalid_nonce_resets_nonces() {
+    let mut provider = MockStarknetProvider::new();
+    provider.expect_get_nonce().returning(|| Ok(9));
+    let mut sequence = mockall::Sequence::new();
+    provider
+        .expect_send()
+        .times(1)
+        .in_sequence(&mut sequence)
+        .returning(|_, _, _| {
+            Err(ChainCommunicationError::from_other_str(
+                "Error during execution: Provider(StarknetError(InvalidTransactionNonce(\"\")))",
+            ))
+        });
+    provider
+        .expect_send()
+        .times(1)
+        .in_sequence(&mut sequence)
+        .returning(|_, _, _| Ok(H256::random()));
+    let adapter = adapter(provider);
+    let mut first = estimated_transaction();
+    let mut second = estimated_transaction();
+
+    let result = adapter.submit(&mut first).await;
+    adapter.submit(&mut second).await.unwrap();
+
+    assert!(matches!(result, Err(LanderError::TxSubmissionError(_))));
+    assert!(first.tx_hashes.is_empty());
+    // the nonce of the failed submission is handed out again
+    assert_eq!(second.precursor().nonce, Some(9));
+}
+
+#[tokio::test]
+async fn test_submit_without_estimate_fails() {
+    let adapter = adapter(MockStarknetProvider::new());
+    let mut tx = transaction();
+
+    let result = adapter.submit(&mut tx).await;
+
+    assert!(matches!(result, Err(LanderError::EstimationFailed)));
+}
+
+#[tokio::test]
+async fn test_get_tx_hash_status() {
+    let pending = H512::random();
+    let recent = H512::random();
+    let deep = H512::random();
+    let proven = H512::random();
+
+    let mut provider = MockStarknetProvider::new();
+    provider.expect_get_block_number().returning(|| Ok(100));
+    provider
+        .expect_get_tx_receipt()
+        .withf(move |hash| *hash == pending)
+        .returning(|_| Ok(receipt(None, false)));
+    provider
+        .expect_get_tx_receipt()
+        .withf(move |hash| *hash == recent)
+        .returning(|_| Ok(receipt(Some(98), false)));
+    provider
+        .expect_get_tx_receipt()
+        .withf(move |hash| *hash == deep)
+        .returning(|_| Ok(receipt(Some(95), false)));
+    provider
+        .expect_get_tx_receipt()
+        .withf(move |hash| *hash == proven)
+        .returning(|_| Ok(receipt(Some(99), true)));
+    provider
+        .expect_get_tx_receipt()
+        .withf(move |hash| ![pending, recent, deep, proven].contains(hash))
+        .returning(|_| {
+            Err(ChainCommunicationError::from_other_str(
+                "TransactionHashNotFound",
+            ))
+        });
+    let adapter = adapter(provider);
+
+    assert_eq!(
+        adapter.get_tx_hash_status(pending).await.unwrap(),
+        TransactionStatus::Mempool
+    );
+    assert_eq!(
+        adapter.get_tx_hash_status(recent).await.unwrap(),
+        TransactionStatus::Included
+    );
+    assert_eq!(
+        adapter.get_tx_hash_status(deep).await.unwrap(),
+        TransactionStatus::Finalized
+    );
+    assert_eq!(
+        adapter.get_tx_hash_status(proven).await.unwrap(),
+        TransactionStatus::Finalized
+    );
+    assert!(matches!(
+        adapter.get_tx_hash_status(H512::random()).await,
+        Err(LanderError::TxHashNotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_tx_ready_for_resubmission() {
+    let adapter = adapter(MockStarknetProvider::new());
+    let mut tx = transaction();
+
+    assert!(adapter.tx_ready_for_resubmission(&tx).await);
+
+    tx.last_submission_attempt = Some(chrono::Utc::now());
+    assert!(!adapter.tx_ready_for_resubmission(&tx).await);
+
+    // ten blocks of two seconds have passed
+    tx.last_submission_attempt = Some(chrono::Utc::now() - chrono::Duration::seconds(21));
+    assert!(adapter.tx_ready_for_resubmission(&tx).await);
+}
+
+#[tokio::test]
+async fn test_reverted_payloads() {
+    let tx = transaction();
+
+    let mut provider = MockStarknetProvider::new();
+    provider
+        .expect_call_bool()
+        .withf(|calldata| calldata == &delivered_calldata())
+        .returning(|_| Ok(false));
+    let adapter = adapter(provider);
+
+    assert_eq!(
+        adapter.reverted_payloads(&tx).await.unwrap(),
+        tx.payload_details
+    );
+}
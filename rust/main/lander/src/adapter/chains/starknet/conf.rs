@@ -0,0 +1,9 @@
+use eyre::ContextCompat;
+
+use hyperlane_base::settings::{BuildableWithSignerConf, ChainConf};
+use hyperlane_starknet::Signer;
+
+pub async fn create_signer(conf: &ChainConf) -> eyre::Result<Signer> {
+    let signer_conf = conf.signer.as_ref().wrap_err("Signer is missing")?;
+    signer_conf.build::<Signer>().await
+}
@@ -0,0 +1,33 @@
+use tokio::sync::Mutex;
+
+/// Hands out nonces for the account of the adapter.
+///
+/// The nonce reported by the node only accounts for transactions in the pending
+/// block, so nonces are reserved locally to allow several transactions to sit in
+/// the mempool at the same time. The local state is dropped whenever a
+/// submission shows that it no longer matches the chain.
+#[derive(Debug, Default)]
+pub struct NonceManager {
+    next: Mutex<Option<u64>>,
+}
+
+impl NonceManager {
+    /// Reserves the next nonce, never going below the on-chain nonce
+    pub async fn reserve(&self, onchain: u64) -> u64 {
+        let mut next = self.next.lock().await;
+
+        let nonce = match *next {
+            Some(local) if local > onchain => local,
+            _ => onchain,
+        };
+        *next = Some(nonce.saturating_add(1));
+
+        nonce
+    }
+
+    /// Forgets locally reserved nonces, so that the next reservation
+    /// starts from the on-chain nonce again
+    pub async fn reset(&self) {
+        *self.next.lock().await = None;
+    }
+}
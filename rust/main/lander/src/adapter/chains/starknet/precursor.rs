@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use hyperlane_starknet::{StarknetResourceBounds, StarknetTxCalldata};
+
+use crate::transaction::{Transaction, VmSpecificTxData};
+use crate::LanderError;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct StarknetTxPrecursor {
+    /// Contract call executed by the transaction
+    pub calldata: StarknetTxCalldata,
+    /// Resources consumed and their prices, as estimated by the node
+    pub estimated_bounds: Option<StarknetResourceBounds>,
+    /// Resource bounds used by the last submission
+    pub resource_bounds: Option<StarknetResourceBounds>,
+    /// Account nonce used by the last submission
+    pub nonce: Option<u64>,
+}
+
+impl StarknetTxPrecursor {
+    pub fn new(calldata: StarknetTxCalldata) -> Self {
+        Self {
+            calldata,
+            ..Default::default()
+        }
+    }
+
+    pub fn from_data(data: &[u8]) -> Result<Self, LanderError> {
+        let calldata = serde_json::from_slice::<StarknetTxCalldata>(data).map_err(|err| {
+            eyre::eyre!("Failed to deserialize StarknetTxCalldata from data: {err}")
+        })?;
+        Ok(Self::new(calldata))
+    }
+}
+
+impl From<StarknetTxPrecursor> for VmSpecificTxData {
+    fn from(value: StarknetTxPrecursor) -> Self {
+        VmSpecificTxData::Starknet(Box::new(value))
+    }
+}
+
+pub trait Precursor {
+    fn precursor(&self) -> &StarknetTxPrecursor;
+    fn precursor_mut(&mut self) -> &mut StarknetTxPrecursor;
+}
+
+#[allow(clippy::panic)]
+impl Precursor for Transaction {
+    fn precursor(&self) -> &StarknetTxPrecursor {
+        match &self.vm_specific_data {
+            VmSpecificTxData::Starknet(precursor) => precursor,
+            _ => panic!(),
+        }
+    }
+
+    fn precursor_mut(&mut self) -> &mut StarknetTxPrecursor {
+        match &mut self.vm_specific_data {
+            VmSpecificTxData::Starknet(precursor) => precursor,
+            _ => panic!(),
+        }
+    }
+}
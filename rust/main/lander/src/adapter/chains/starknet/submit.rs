@@ -0,0 +1,164 @@
+use tracing::{info, warn};
+
+use hyperlane_core::ChainCommunicationError;
+use hyperlane_starknet::{StarknetProviderForLander, StarknetResourceBounds};
+
+use crate::adapter::chains::starknet::{NonceManager, Precursor};
+use crate::transaction::Transaction;
+use crate::LanderError;
+
+/// The first submission pays this many tenths of the estimated resources and prices
+const ESTIMATE_MARGIN_TENTHS: u128 = 15;
+
+/// Each resubmission pays this many tenths of the previous prices
+const PRICE_ESCALATION_TENTHS: u128 = 11;
+
+/// Escalated prices never exceed the estimated price times this multiplier
+const MAX_PRICE_MULTIPLIER: u128 = 3;
+
+/// Classifies Starknet transaction submission errors into appropriate LanderError variants
+///
+/// Based on the errors of the Starknet JSON-RPC specification:
+/// https://github.com/starkware-libs/starknet-specs/blob/master/api/starknet_write_api.json
+pub fn classify_starknet_error(err: ChainCommunicationError) -> LanderError {
+    let err_str = err.to_string();
+
+    if err_str.contains("DuplicateTx") {
+        return LanderError::TxAlreadyExists;
+    }
+
+    // Check for retryable errors (temporary conditions)
+    if err_str.contains("InvalidTransactionNonce")
+        || err_str.contains("InsufficientResourcesForValidate")
+    {
+        return LanderError::TxSubmissionError(err_str);
+    }
+
+    // Check for non-retryable errors (permanent failures)
+    if err_str.contains("ValidationFailure")
+        || err_str.contains("InsufficientAccountBalance")
+        || err_str.contains("NonAccount")
+    {
+        return LanderError::NonRetryableError(err_str);
+    }
+
+    // Default: convert to ChainCommunicationError for generic handling
+    LanderError::ChainCommunicationError(err)
+}
+
+fn with_margin(value: u128) -> u128 {
+    value
+        .saturating_mul(ESTIMATE_MARGIN_TENTHS)
+        .saturating_div(10)
+}
+
+/// Returns the price for the next submission of a transaction.
+///
+/// The first submission pays the estimated price with a margin. Every resubmission
+/// pays 10% more than the previous one, but never less than the first submission
+/// nor more than `MAX_PRICE_MULTIPLIER` times the estimated price.
+pub fn escalate_price(estimated_price: u128, previous_price: Option<u128>) -> u128 {
+    let base_price = with_margin(estimated_price);
+    let Some(previous_price) = previous_price else {
+        return base_price;
+    };
+
+    let escalated = previous_price
+        .saturating_mul(PRICE_ESCALATION_TENTHS)
+        .saturating_div(10);
+    let cap = estimated_price.saturating_mul(MAX_PRICE_MULTIPLIER);
+
+    escalated.max(base_price).min(cap)
+}
+
+fn escalate_amount(estimated_amount: u64) -> u64 {
+    u64::try_from(with_margin(u128::from(estimated_amount))).unwrap_or(u64::MAX)
+}
+
+/// Returns the resource bounds for the next submission of a transaction.
+/// Resource amounts only get the estimation margin, while prices escalate with every resubmission.
+pub fn escalate_bounds(
+    estimated: &StarknetResourceBounds,
+    previous: Option<&StarknetResourceBounds>,
+) -> StarknetResourceBounds {
+    StarknetResourceBounds {
+        l1_gas: escalate_amount(estimated.l1_gas),
+        l1_gas_price: escalate_price(estimated.l1_gas_price, previous.map(|p| p.l1_gas_price)),
+        l2_gas: escalate_amount(estimated.l2_gas),
+        l2_gas_price: escalate_price(estimated.l2_gas_price, previous.map(|p| p.l2_gas_price)),
+        l1_data_gas: escalate_amount(estimated.l1_data_gas),
+        l1_data_gas_price: escalate_price(
+            estimated.l1_data_gas_price,
+            previous.map(|p| p.l1_data_gas_price),
+        ),
+    }
+}
+
+/// Returns the nonce for the next submission of a transaction.
+///
+/// A resubmission keeps the nonce of the previous submission, so that it replaces
+/// the pending transaction in the mempool, unless that nonce was already used.
+async fn next_nonce(
+    provider: &dyn StarknetProviderForLander,
+    nonces: &NonceManager,
+    tx: &Transaction,
+) -> Result<u64, LanderError> {
+    let onchain = provider.get_nonce().await?;
+    match tx.precursor().nonce {
+        Some(nonce) if nonce >= onchain => Ok(nonce),
+        _ => Ok(nonces.reserve(onchain).await),
+    }
+}
+
+/// Sets the nonce and resource bounds of a Starknet transaction and sends it.
+/// The transaction keeps the new values even if sending fails.
+pub async fn submit_transaction(
+    provider: &dyn StarknetProviderForLander,
+    nonces: &NonceManager,
+    tx: &mut Transaction,
+) -> Result<(), LanderError> {
+    let estimated = tx
+        .precursor()
+        .estimated_bounds
+        .ok_or(LanderError::EstimationFailed)?;
+
+    let nonce = next_nonce(provider, nonces, tx).await?;
+    let resource_bounds = escalate_bounds(&estimated, tx.precursor().resource_bounds.as_ref());
+
+    let precursor = tx.precursor_mut();
+    precursor.nonce = Some(nonce);
+    precursor.resource_bounds = Some(resource_bounds);
+
+    info!(?tx, "submitting Starknet transaction");
+
+    let result = provider
+        .send(&tx.precursor().calldata, nonce, &resource_bounds)
+        .await;
+
+    let tx_hash = match result {
+        Ok(tx_hash) => tx_hash,
+        Err(err) => {
+            let err = classify_starknet_error(err);
+            if !matches!(err, LanderError::TxAlreadyExists) {
+                // The nonce was not consumed, so the local state
+                // can no longer be trusted
+                warn!(?err, tx_uuid=?tx.uuid, nonce, "failed to send Starknet transaction");
+                nonces.reset().await;
+            }
+            return Err(err);
+        }
+    };
+
+    tx.last_submission_attempt = Some(chrono::Utc::now());
+
+    // Store transaction hash
+    if !tx.tx_hashes.contains(&tx_hash.into()) {
+        tx.tx_hashes.push(tx_hash.into());
+    }
+
+    info!(tx_uuid=?tx.uuid, ?tx_hash, "submitted Starknet transaction");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,114 @@
+use hyperlane_core::ChainCommunicationError;
+use hyperlane_starknet::StarknetResourceBounds;
+
+use crate::adapter::chains::starknet::NonceManager;
+use crate::LanderError;
+
+use super::{classify_starknet_error, escalate_bounds, escalate_price};
+
+fn classify(message: &str) -> LanderError {
+    classify_starknet_error(ChainCommunicationError::from_other_str(message))
+}
+
+#[test]
+fn test_classify_retryable_errors() {
+    for message in [
+        "Error during execution: Provider(StarknetError(InvalidTransactionNonce(\"expected 5, got 6\")))",
+        "Error during execution: Provider(StarknetError(InsufficientResourcesForValidate))",
+    ] {
+        assert!(
+            matches!(classify(message), LanderError::TxSubmissionError(_)),
+            "{message} should be retryable"
+        );
+    }
+}
+
+#[test]
+fn test_classify_already_exists() {
+    assert!(matches!(
+        classify("Error during execution: Provider(StarknetError(DuplicateTx))"),
+        LanderError::TxAlreadyExists
+    ));
+}
+
+#[test]
+fn test_classify_non_retryable_errors() {
+    for message in [
+        "Error during execution: Provider(StarknetError(ValidationFailure(\"invalid signature\")))",
+        "Error during execution: Provider(StarknetError(InsufficientAccountBalance))",
+    ] {
+        assert!(
+            matches!(classify(message), LanderError::NonRetryableError(_)),
+            "{message} should not be retryable"
+        );
+    }
+}
+
+#[test]
+fn test_classify_unknown_error() {
+    assert!(matches!(
+        classify("connection reset by peer"),
+        LanderError::ChainCommunicationError(_)
+    ));
+}
+
+#[test]
+fn test_escalate_price() {
+    // first submission adds a 50% margin
+    assert_eq!(escalate_price(1_000, None), 1_500);
+    // resubmissions pay 10% more
+    assert_eq!(escalate_price(1_000, Some(2_000)), 2_200);
+    // but never less than the first submission
+    assert_eq!(escalate_price(1_000, Some(100)), 1_500);
+    // nor more than three times the estimate
+    assert_eq!(escalate_price(1_000, Some(2_900)), 3_000);
+}
+
+#[test]
+fn test_escalate_bounds_only_escalates_prices() {
+    let estimated = StarknetResourceBounds {
+        l1_gas: 10,
+        l1_gas_price: 100,
+        l2_gas: 1_000,
+        l2_gas_price: 200,
+        l1_data_gas: 20,
+        l1_data_gas_price: 300,
+    };
+
+    let first = escalate_bounds(&estimated, None);
+    let second = escalate_bounds(&estimated, Some(&first));
+
+    assert_eq!(
+        first,
+        StarknetResourceBounds {
+            l1_gas: 15,
+            l1_gas_price: 150,
+            l2_gas: 1_500,
+            l2_gas_price: 300,
+            l1_data_gas: 30,
+            l1_data_gas_price: 450,
+        }
+    );
+    assert_eq!(
+        second,
+        StarknetResourceBounds {
+            l1_gas_price: 165,
+            l2_gas_price: 330,
+            l1_data_gas_price: 495,
+            ..first
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_nonce_manager() {
+    let nonces = NonceManager::default();
+
+    assert_eq!(nonces.reserve(3).await, 3);
+    assert_eq!(nonces.reserve(3).await, 4);
+    // the chain caught up beyond the local state
+    assert_eq!(nonces.reserve(7).await, 7);
+
+    nonces.reset().await;
+    assert_eq!(nonces.reserve(3).await, 3);
+}
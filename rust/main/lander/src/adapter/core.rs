@@ -10,7 +10,7 @@ use tokio::sync::mpsc::error::SendError;
 use tracing::{info, instrument};
 use uuid::Uuid;
 
-use hyperlane_core::{H256, H512, U256};
+use hyperlane_core::{H160, H256, H512, U256};
 
 use crate::{
     error::LanderError,
@@ -23,7 +23,12 @@ pub type GasLimit = U256;
 
 #[derive(Clone, Debug)]
 pub enum AdaptsChainAction {
-    OverwriteUpperNonce { nonce: Option<u64> },
+    OverwriteUpperNonce {
+        nonce: Option<u64>,
+        /// The signer whose upper nonce is overwritten. Defaults to the
+        /// chain's primary signer.
+        signer: Option<H160>,
+    },
 }
 
 #[derive(new, Debug, Clone, PartialEq)]
@@ -116,6 +121,11 @@ pub trait AdaptsChain: Send + Sync {
         Ok(())
     }
 
+    /// Run actions that need to be performed once a transaction was finalized or dropped,
+    /// and so no longer occupies the signer it was built for.
+    /// NOP as a default implementation.
+    async fn on_tx_completed(&self, _tx: &Transaction) {}
+
     // methods below are excluded from the MVP
 
     /// Checks if a nonce gap exists in transactions submitted by the PayloadDispatcher via this adapter
@@ -7,7 +7,7 @@ use hyperlane_core::U256;
 use prometheus::{
     core::{AtomicU64, GenericGauge},
     labels, opts, register_int_counter_vec_with_registry, register_int_gauge_vec_with_registry,
-    Encoder, IntCounterVec, IntGauge, IntGaugeVec, Registry,
+    Encoder, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Registry,
 };
 use tracing::{debug, info, warn};
 
@@ -59,6 +59,8 @@ pub struct DispatcherMetrics {
     /// Counts how many times we've noticed the nonce in tx is different from nonce
     /// stored in db
     mismatched_nonce: IntGaugeVec,
+    /// Number of transactions assigned to each signer of a signer pool
+    assigned_transactions: IntCounterVec,
     /// Gas limit set for the transaction, if applicable
     pub gas_limit: IntGaugeVec,
 }
@@ -208,6 +210,14 @@ impl DispatcherMetrics {
             &["destination", "signer",],
             registry.clone()
         )?;
+        let assigned_transactions = register_int_counter_vec_with_registry!(
+            opts!(
+                namespaced("assigned_transactions"),
+                "The number of transactions assigned to each signer",
+            ),
+            &["destination", "signer",],
+            registry.clone()
+        )?;
         Ok(Self {
             registry: registry.clone(),
             task_liveness,
@@ -226,6 +236,7 @@ impl DispatcherMetrics {
             finalized_nonce,
             upper_nonce,
             mismatched_nonce,
+            assigned_transactions,
             gas_limit,
             inclusion_stage_error,
         })
@@ -339,6 +350,12 @@ impl DispatcherMetrics {
             .clone()
     }
 
+    pub fn get_assigned_transactions(&self, destination: &str, signer: &str) -> IntCounter {
+        self.assigned_transactions
+            .with_label_values(&[destination, signer])
+            .clone()
+    }
+
     pub fn set_post_inclusion_metrics(
         &self,
         vm_metrics: &PostInclusionMetricsSource,
@@ -182,6 +182,7 @@ impl FinalityStage {
                 update_tx_status(state, &mut tx, tx_status).await?;
                 Self::record_reverted_payloads(&mut tx, state).await?;
                 state.adapter.post_finalized().await?;
+                state.adapter.on_tx_completed(&tx).await;
                 let tx_uuid = tx.uuid.clone();
                 info!(?tx_uuid, "Transaction is finalized");
                 let _ = pool.remove(&tx_uuid).await;
@@ -263,6 +264,7 @@ impl FinalityStage {
                 building_stage_queue.push_front(full_payload).await;
             }
         }
+        state.adapter.on_tx_completed(&tx).await;
         let _ = pool.remove(&tx.uuid).await;
         Ok(())
     }
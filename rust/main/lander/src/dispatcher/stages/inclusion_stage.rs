@@ -487,6 +487,7 @@ impl InclusionStage {
         let new_tx_status = TransactionStatus::Dropped(reason);
         // this will drop the payloads as well
         update_tx_status(state, tx, new_tx_status.clone()).await?;
+        state.adapter.on_tx_completed(tx).await;
         pool.lock().await.remove(&tx.uuid);
         Ok(())
     }
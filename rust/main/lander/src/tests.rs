@@ -3,6 +3,7 @@ pub use aleo::MockAleoProvider;
 
 #[cfg(feature = "aleo")]
 mod aleo;
+pub mod cosmos;
 pub mod evm;
 pub mod radix;
 pub mod svm;
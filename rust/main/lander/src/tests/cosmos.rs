@@ -0,0 +1,14 @@
+#[cfg(test)]
+pub use test_utils::MockCosmosProvider;
+
+#[cfg(test)]
+mod test_utils;
+
+#[cfg(test)]
+mod tests_building_stage;
+
+#[cfg(test)]
+mod tests_inclusion_stage;
+
+#[cfg(test)]
+mod tests_finality_stage;
@@ -0,0 +1,322 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use hyperlane_core::{ChainCommunicationError, ChainResult, H256, H512};
+use hyperlane_cosmos::{CosmWasmTxCalldata, CosmosProviderForLander, CosmosTxResult};
+
+use crate::adapter::chains::cosmos::{CosmWasmTxPrecursor, CosmosAdapter};
+use crate::dispatcher::{DispatcherState, PayloadDb, TransactionDb};
+use crate::payload::PayloadDetails;
+use crate::tests::test_utils::tmp_dbs;
+use crate::transaction::{Transaction, VmSpecificTxData};
+use crate::{
+    DispatcherMetrics, FullPayload, PayloadStatus, PayloadUuid, TransactionStatus, TransactionUuid,
+};
+
+pub const TEST_CONTRACT_ADDRESS: &str =
+    "neutron1sjzzd4gwkggy6hrrs8kxxatexzcuz3jecsxm3wqgregkulzj8r7qlnuef4";
+
+/// Gas price used to derive fees from gas limits
+pub const TEST_GAS_PRICE: u128 = 2;
+
+/// A broadcast made through the mock provider
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Broadcast {
+    pub gas_limit: u64,
+    pub fee_amount: u128,
+    pub sequence: u64,
+    pub timeout_height: u64,
+}
+
+/// Mock CosmWasm provider for integration testing
+///
+/// Provides configurable behavior for all CosmosProviderForLander methods.
+/// Use the builder pattern methods to configure return values for each test scenario.
+/// The account sequence only advances when a transaction is included, as on a real chain.
+#[derive(Clone)]
+pub struct MockCosmosProvider {
+    /// Gas estimate returned by simulation
+    gas_estimate: Arc<Mutex<Result<u64, String>>>,
+    /// Whether a failed simulation executed the messages, rather than failing to reach the chain
+    simulation_reverts: Arc<Mutex<bool>>,
+    /// On-chain account sequence of the signer
+    sequence: Arc<Mutex<u64>>,
+    /// Latest block height
+    block_number: Arc<Mutex<u64>>,
+    /// Errors returned by the upcoming broadcasts, in order
+    broadcast_errors: Arc<Mutex<Vec<String>>>,
+    /// Whether broadcast transactions are included right away
+    include_broadcasts: Arc<Mutex<bool>>,
+    /// Included transactions by hash
+    included: Arc<Mutex<HashMap<H512, CosmosTxResult>>>,
+    /// Result of the `message_delivered` query
+    delivered: Arc<Mutex<Result<bool, String>>>,
+    /// All broadcasts made, including failed ones
+    broadcasts: Arc<Mutex<Vec<Broadcast>>>,
+}
+
+impl Default for MockCosmosProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockCosmosProvider {
+    pub fn new() -> Self {
+        Self {
+            gas_estimate: Arc::new(Mutex::new(Ok(200_000))),
+            simulation_reverts: Arc::new(Mutex::new(false)),
+            sequence: Arc::new(Mutex::new(0)),
+            block_number: Arc::new(Mutex::new(100)),
+            broadcast_errors: Arc::new(Mutex::new(Vec::new())),
+            include_broadcasts: Arc::new(Mutex::new(true)),
+            included: Arc::new(Mutex::new(HashMap::new())),
+            delivered: Arc::new(Mutex::new(Ok(true))),
+            broadcasts: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    pub fn with_gas_estimate(self, gas: u64) -> Self {
+        *self.gas_estimate.lock().unwrap() = Ok(gas);
+        self
+    }
+
+    /// Configure simulations to fail without reaching the chain
+    pub fn with_simulation_error(self, error: &str) -> Self {
+        *self.gas_estimate.lock().unwrap() = Err(error.to_string());
+        *self.simulation_reverts.lock().unwrap() = false;
+        self
+    }
+
+    /// Configure simulations to fail executing the messages
+    pub fn with_simulation_revert(self, error: &str) -> Self {
+        *self.gas_estimate.lock().unwrap() = Err(error.to_string());
+        *self.simulation_reverts.lock().unwrap() = true;
+        self
+    }
+
+    pub fn with_sequence(self, sequence: u64) -> Self {
+        *self.sequence.lock().unwrap() = sequence;
+        self
+    }
+
+    pub fn with_block_number(self, block_number: u64) -> Self {
+        self.set_block_number(block_number);
+        self
+    }
+
+    /// Configure the next broadcasts to fail with the given errors, in order
+    pub fn with_broadcast_errors(self, errors: &[&str]) -> Self {
+        *self.broadcast_errors.lock().unwrap() = errors.iter().map(|e| e.to_string()).collect();
+        self
+    }
+
+    /// Keep broadcast transactions in the mempool instead of including them
+    pub fn without_inclusion(self) -> Self {
+        self.set_inclusion(false);
+        self
+    }
+
+    pub fn with_included_tx(self, hash: H512, result: CosmosTxResult) -> Self {
+        self.included.lock().unwrap().insert(hash, result);
+        self
+    }
+
+    pub fn with_delivered(self, delivered: bool) -> Self {
+        *self.delivered.lock().unwrap() = Ok(delivered);
+        self
+    }
+
+    pub fn with_delivered_error(self, error: &str) -> Self {
+        *self.delivered.lock().unwrap() = Err(error.to_string());
+        self
+    }
+
+    pub fn set_block_number(&self, block_number: u64) {
+        *self.block_number.lock().unwrap() = block_number;
+    }
+
+    pub fn set_inclusion(&self, include_broadcasts: bool) {
+        *self.include_broadcasts.lock().unwrap() = include_broadcasts;
+    }
+
+    pub fn broadcasts(&self) -> Vec<Broadcast> {
+        self.broadcasts.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl CosmosProviderForLander for MockCosmosProvider {
+    type Calldata = CosmWasmTxCalldata;
+    type DeliveredCalldata = CosmWasmTxCalldata;
+
+    async fn simulate(&self, _calldata: &CosmWasmTxCalldata) -> ChainResult<u64> {
+        let reverts = *self.simulation_reverts.lock().unwrap();
+        self.gas_estimate.lock().unwrap().clone().map_err(|e| {
+            if reverts {
+                ChainCommunicationError::from_contract_error(std::io::Error::other(e))
+            } else {
+                ChainCommunicationError::from_other_str(&e)
+            }
+        })
+    }
+
+    fn fee_for_gas(&self, gas_limit: u64) -> ChainResult<u128> {
+        Ok(u128::from(gas_limit) * TEST_GAS_PRICE)
+    }
+
+    async fn account_sequence(&self) -> ChainResult<u64> {
+        Ok(*self.sequence.lock().unwrap())
+    }
+
+    async fn get_block_number(&self) -> ChainResult<u64> {
+        Ok(*self.block_number.lock().unwrap())
+    }
+
+    async fn broadcast(
+        &self,
+        _calldata: &CosmWasmTxCalldata,
+        gas_limit: u64,
+        fee_amount: u128,
+        sequence: u64,
+        timeout_height: u64,
+    ) -> ChainResult<H256> {
+        self.broadcasts.lock().unwrap().push(Broadcast {
+            gas_limit,
+            fee_amount,
+            sequence,
+            timeout_height,
+        });
+
+        let mut errors = self.broadcast_errors.lock().unwrap();
+        if !errors.is_empty() {
+            let error = errors.remove(0);
+            return Err(ChainCommunicationError::from_other_str(&error));
+        }
+
+        let mut onchain_sequence = self.sequence.lock().unwrap();
+        if sequence != *onchain_sequence && *self.include_broadcasts.lock().unwrap() {
+            return Err(ChainCommunicationError::from_other_str(&format!(
+                "account sequence mismatch, expected {}, got {}",
+                *onchain_sequence, sequence
+            )));
+        }
+
+        let hash = H256::random();
+        if *self.include_broadcasts.lock().unwrap() {
+            *onchain_sequence += 1;
+            self.included.lock().unwrap().insert(
+                hash.into(),
+                CosmosTxResult {
+                    height: *self.block_number.lock().unwrap(),
+                    success: true,
+                    log: String::new(),
+                },
+            );
+        }
+        Ok(hash)
+    }
+
+    async fn get_tx(&self, hash: &H512) -> ChainResult<CosmosTxResult> {
+        self.included
+            .lock()
+            .unwrap()
+            .get(hash)
+            .cloned()
+            .ok_or_else(|| ChainCommunicationError::from_other_str("tx not found"))
+    }
+
+    async fn check_delivered(&self, _calldata: &CosmWasmTxCalldata) -> ChainResult<bool> {
+        self.delivered
+            .lock()
+            .unwrap()
+            .clone()
+            .map_err(|e| ChainCommunicationError::from_other_str(&e))
+    }
+}
+
+pub fn adapter(provider: MockCosmosProvider, block_time: Duration) -> CosmosAdapter {
+    CosmosAdapter {
+        provider: Arc::new(provider),
+        sequences: Default::default(),
+        estimated_block_time: block_time,
+    }
+}
+
+/// Calldata of a `process` execution, as produced by the mailbox
+pub fn process_calldata() -> CosmWasmTxCalldata {
+    CosmWasmTxCalldata {
+        contract_address: TEST_CONTRACT_ADDRESS.to_string(),
+        msg: br#"{"process":{"metadata":"","message":""}}"#.to_vec(),
+    }
+}
+
+/// Calldata of a `message_delivered` query, as produced by the mailbox
+pub fn delivered_calldata() -> CosmWasmTxCalldata {
+    CosmWasmTxCalldata {
+        contract_address: TEST_CONTRACT_ADDRESS.to_string(),
+        msg: br#"{"mailbox":{"message_delivered":{"id":"00"}}}"#.to_vec(),
+    }
+}
+
+pub fn mock_dispatcher_state_with_provider(
+    provider: MockCosmosProvider,
+    block_time: Duration,
+) -> DispatcherState {
+    let (payload_db, tx_db, _) = tmp_dbs();
+    DispatcherState::new(
+        payload_db,
+        tx_db,
+        Arc::new(adapter(provider, block_time)),
+        DispatcherMetrics::dummy_instance(),
+        "test".to_string(),
+    )
+}
+
+/// Creates a payload carrying `process` calldata, as built by the relayer
+pub fn create_cosmos_payload(status: PayloadStatus) -> FullPayload {
+    let payload_uuid = PayloadUuid::random();
+    let success_criteria = serde_json::to_vec(&delivered_calldata()).unwrap();
+    FullPayload {
+        details: PayloadDetails {
+            uuid: payload_uuid.clone(),
+            metadata: format!("test-cosmos-payload-{}", payload_uuid),
+            success_criteria: Some(success_criteria),
+        },
+        data: serde_json::to_vec(&process_calldata()).unwrap(),
+        to: H256::zero(),
+        status,
+        value: None,
+        inclusion_soft_deadline: None,
+    }
+}
+
+/// Creates a CosmWasm transaction for a new payload and stores both in the DB
+pub async fn mock_cosmos_tx(
+    payload_db: &Arc<dyn PayloadDb>,
+    tx_db: &Arc<dyn TransactionDb>,
+    status: TransactionStatus,
+) -> Transaction {
+    let payload = create_cosmos_payload(PayloadStatus::InTransaction(status.clone()));
+    payload_db.store_payload_by_uuid(&payload).await.unwrap();
+
+    let tx = Transaction {
+        uuid: TransactionUuid::new(uuid::Uuid::new_v4()),
+        tx_hashes: vec![],
+        vm_specific_data: VmSpecificTxData::CosmWasm(Box::new(CosmWasmTxPrecursor::new(
+            process_calldata(),
+        ))),
+        payload_details: vec![payload.details.clone()],
+        status,
+        submission_attempts: 0,
+        creation_timestamp: chrono::Utc::now(),
+        last_submission_attempt: None,
+        last_status_check: None,
+    };
+
+    tx_db.store_transaction_by_uuid(&tx).await.unwrap();
+    tx
+}
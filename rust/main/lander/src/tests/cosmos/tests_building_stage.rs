@@ -0,0 +1,139 @@
+use std::time::Duration;
+
+use crate::dispatcher::{BuildingStage, BuildingStageQueue};
+use crate::payload::DropReason;
+use crate::transaction::{Transaction, VmSpecificTxData};
+use crate::{PayloadStatus, TransactionStatus};
+
+use super::super::test_utils::initialize_payload_db;
+use super::test_utils::{
+    create_cosmos_payload, mock_dispatcher_state_with_provider, process_calldata,
+    MockCosmosProvider,
+};
+
+fn setup_building_stage() -> (
+    BuildingStage,
+    tokio::sync::mpsc::Receiver<Transaction>,
+    BuildingStageQueue,
+) {
+    let state =
+        mock_dispatcher_state_with_provider(MockCosmosProvider::new(), Duration::from_millis(10));
+
+    let (sender, receiver) = tokio::sync::mpsc::channel(100);
+    let queue = BuildingStageQueue::new();
+    let building_stage =
+        BuildingStage::new(queue.clone(), sender, state, "test-cosmos".to_string());
+
+    (building_stage, receiver, queue)
+}
+
+async fn run_building_stage_once(
+    building_stage: &BuildingStage,
+    receiver: &mut tokio::sync::mpsc::Receiver<Transaction>,
+    expected_tx_count: usize,
+) -> Vec<Transaction> {
+    let received_txs = async {
+        let mut txs = Vec::new();
+        while txs.len() < expected_tx_count {
+            if let Some(tx) = receiver.recv().await {
+                txs.push(tx);
+            }
+        }
+        txs
+    };
+
+    tokio::select! {
+        _ = building_stage.run() => vec![],
+        txs = received_txs => txs,
+        _ = tokio::time::sleep(tokio::time::Duration::from_millis(100)) => vec![],
+    }
+}
+
+#[tokio::test]
+async fn test_building_stage_single_payload() {
+    let (building_stage, mut receiver, queue) = setup_building_stage();
+
+    let payload = create_cosmos_payload(PayloadStatus::ReadyToSubmit);
+    initialize_payload_db(&building_stage.state.payload_db, &payload).await;
+    queue.push_back(payload.clone()).await;
+
+    let txs = run_building_stage_once(&building_stage, &mut receiver, 1).await;
+
+    assert_eq!(txs.len(), 1);
+    let tx = &txs[0];
+    assert_eq!(tx.status, TransactionStatus::PendingInclusion);
+    assert_eq!(tx.submission_attempts, 0);
+    assert_eq!(tx.payload_details, vec![payload.details.clone()]);
+
+    // The precursor carries the calldata of the payload and nothing else yet
+    match &tx.vm_specific_data {
+        VmSpecificTxData::CosmWasm(precursor) => {
+            assert_eq!(precursor.calldata, process_calldata());
+            assert_eq!(precursor.gas_limit, None);
+            assert_eq!(precursor.fee_amount, None);
+            assert_eq!(precursor.sequence, None);
+            assert_eq!(precursor.timeout_height, None);
+        }
+        _ => panic!("Expected CosmWasm transaction data"),
+    }
+
+    let stored_payload = building_stage
+        .state
+        .payload_db
+        .retrieve_payload_by_uuid(&payload.details.uuid)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        stored_payload.status,
+        PayloadStatus::InTransaction(TransactionStatus::PendingInclusion)
+    );
+    assert_eq!(queue.len().await, 0);
+}
+
+#[tokio::test]
+async fn test_building_stage_multiple_payloads_no_batching() {
+    let (building_stage, mut receiver, queue) = setup_building_stage();
+
+    let payloads = (0..3)
+        .map(|_| create_cosmos_payload(PayloadStatus::ReadyToSubmit))
+        .collect::<Vec<_>>();
+    for payload in &payloads {
+        initialize_payload_db(&building_stage.state.payload_db, payload).await;
+        queue.push_back(payload.clone()).await;
+    }
+
+    // CosmWasm transactions carry a single payload each
+    let txs = run_building_stage_once(&building_stage, &mut receiver, 3).await;
+
+    assert_eq!(txs.len(), 3);
+    for tx in &txs {
+        assert_eq!(tx.payload_details.len(), 1);
+    }
+    assert_eq!(queue.len().await, 0);
+}
+
+#[tokio::test]
+async fn test_building_stage_invalid_payload_is_dropped() {
+    let (building_stage, mut receiver, queue) = setup_building_stage();
+
+    let mut payload = create_cosmos_payload(PayloadStatus::ReadyToSubmit);
+    payload.data = b"not calldata".to_vec();
+    initialize_payload_db(&building_stage.state.payload_db, &payload).await;
+    queue.push_back(payload.clone()).await;
+
+    let txs = run_building_stage_once(&building_stage, &mut receiver, 1).await;
+    assert!(txs.is_empty());
+
+    let stored_payload = building_stage
+        .state
+        .payload_db
+        .retrieve_payload_by_uuid(&payload.details.uuid)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(
+        stored_payload.status,
+        PayloadStatus::Dropped(DropReason::FailedToBuildAsTransaction)
+    ));
+}
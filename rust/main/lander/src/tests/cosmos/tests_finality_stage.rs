@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use tracing_test::traced_test;
+
+use hyperlane_core::H512;
+use hyperlane_cosmos::CosmosTxResult;
+
+use crate::dispatcher::{BuildingStageQueue, DispatcherState, FinalityStage, FinalityStagePool};
+use crate::transaction::Transaction;
+use crate::{PayloadDropReason, PayloadStatus, TransactionStatus};
+
+use super::test_utils::{mock_cosmos_tx, mock_dispatcher_state_with_provider, MockCosmosProvider};
+
+const TEST_BLOCK_TIME: Duration = Duration::from_millis(10);
+
+/// Creates a finalized transaction which was included with the given hash
+async fn finalized_tx(dispatcher_state: &DispatcherState, hash: H512) -> Transaction {
+    let mut tx = mock_cosmos_tx(
+        &dispatcher_state.payload_db,
+        &dispatcher_state.tx_db,
+        TransactionStatus::Finalized,
+    )
+    .await;
+    tx.tx_hashes.push(hash);
+    tx
+}
+
+fn included(success: bool) -> CosmosTxResult {
+    CosmosTxResult {
+        height: 100,
+        success,
+        log: String::new(),
+    }
+}
+
+async fn process_finalized_tx(
+    dispatcher_state: &DispatcherState,
+    tx: &Transaction,
+) -> Result<(), crate::LanderError> {
+    FinalityStage::try_process_tx(
+        tx.clone(),
+        FinalityStagePool::new(),
+        BuildingStageQueue::new(),
+        dispatcher_state,
+    )
+    .await
+}
+
+async fn payload_status(dispatcher_state: &DispatcherState, tx: &Transaction) -> PayloadStatus {
+    dispatcher_state
+        .payload_db
+        .retrieve_payload_by_uuid(&tx.payload_details[0].uuid)
+        .await
+        .unwrap()
+        .unwrap()
+        .status
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_cosmos_tx_finalized_happy_path() {
+    let hash = H512::random();
+    let mock_provider = MockCosmosProvider::new()
+        .with_included_tx(hash, included(true))
+        .with_delivered(true);
+    let dispatcher_state = mock_dispatcher_state_with_provider(mock_provider, TEST_BLOCK_TIME);
+    let tx = finalized_tx(&dispatcher_state, hash).await;
+
+    let result = process_finalized_tx(&dispatcher_state, &tx).await;
+    assert!(result.is_ok(), "Processing finalized tx should succeed");
+
+    assert_eq!(
+        payload_status(&dispatcher_state, &tx).await,
+        PayloadStatus::InTransaction(TransactionStatus::Finalized),
+        "Payload should remain finalized when the message was delivered"
+    );
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_cosmos_tx_finalized_but_failed() {
+    let hash = H512::random();
+    let mock_provider = MockCosmosProvider::new()
+        .with_included_tx(hash, included(false))
+        .with_delivered(false);
+    let dispatcher_state = mock_dispatcher_state_with_provider(mock_provider, TEST_BLOCK_TIME);
+    let tx = finalized_tx(&dispatcher_state, hash).await;
+
+    let result = process_finalized_tx(&dispatcher_state, &tx).await;
+    assert!(result.is_ok(), "Processing finalized tx should succeed");
+
+    assert_eq!(
+        payload_status(&dispatcher_state, &tx).await,
+        PayloadStatus::Dropped(PayloadDropReason::Reverted),
+        "Payload should be marked as reverted when the message was not delivered"
+    );
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_cosmos_tx_finalized_without_success_criteria() {
+    let hash = H512::random();
+    let mock_provider = MockCosmosProvider::new()
+        .with_included_tx(hash, included(true))
+        .with_delivered_error("delivered query must not be made");
+    let dispatcher_state = mock_dispatcher_state_with_provider(mock_provider, TEST_BLOCK_TIME);
+    let mut tx = finalized_tx(&dispatcher_state, hash).await;
+    tx.payload_details
+        .iter_mut()
+        .for_each(|detail| detail.success_criteria = None);
+
+    let result = process_finalized_tx(&dispatcher_state, &tx).await;
+    assert!(result.is_ok(), "Processing finalized tx should succeed");
+
+    assert_eq!(
+        payload_status(&dispatcher_state, &tx).await,
+        PayloadStatus::InTransaction(TransactionStatus::Finalized)
+    );
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_cosmos_tx_finalized_delivered_query_error() {
+    let hash = H512::random();
+    let mock_provider = MockCosmosProvider::new()
+        .with_included_tx(hash, included(true))
+        .with_delivered_error("connection refused");
+    let dispatcher_state = mock_dispatcher_state_with_provider(mock_provider, TEST_BLOCK_TIME);
+    let tx = finalized_tx(&dispatcher_state, hash).await;
+
+    // Provider errors should propagate, rather than marking the payload as reverted
+    let result = process_finalized_tx(&dispatcher_state, &tx).await;
+    assert!(
+        result.is_err(),
+        "Processing tx when the delivered query fails should return error"
+    );
+}
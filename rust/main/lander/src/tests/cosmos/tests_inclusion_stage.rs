@@ -0,0 +1,311 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use tracing_test::traced_test;
+
+use hyperlane_core::KnownHyperlaneDomain;
+
+use crate::adapter::chains::cosmos::Precursor;
+use crate::dispatcher::{DispatcherState, InclusionStage, InclusionStagePool};
+use crate::transaction::Transaction;
+use crate::{PayloadStatus, TransactionStatus};
+
+use super::test_utils::{
+    mock_cosmos_tx, mock_dispatcher_state_with_provider, Broadcast, MockCosmosProvider,
+    TEST_GAS_PRICE,
+};
+
+const TEST_BLOCK_TIME: Duration = Duration::from_millis(10);
+const TEST_DOMAIN: KnownHyperlaneDomain = KnownHyperlaneDomain::Arbitrum;
+
+/// Expected transaction state at each step of the inclusion process
+#[derive(Clone, Debug)]
+struct ExpectedCosmosTxState {
+    pub status: TransactionStatus,
+    pub retries: u32,
+}
+
+async fn setup_pool(
+    dispatcher_state: &DispatcherState,
+    count: usize,
+) -> (InclusionStagePool, Vec<Transaction>) {
+    let pool = Arc::new(tokio::sync::Mutex::new(HashMap::new()));
+    let mut txs = Vec::new();
+    for _ in 0..count {
+        let tx = mock_cosmos_tx(
+            &dispatcher_state.payload_db,
+            &dispatcher_state.tx_db,
+            TransactionStatus::PendingInclusion,
+        )
+        .await;
+        pool.lock().await.insert(tx.uuid.clone(), tx.clone());
+        txs.push(tx);
+    }
+    (pool, txs)
+}
+
+async fn process_step(pool: &InclusionStagePool, dispatcher_state: &DispatcherState) {
+    let (finality_stage_sender, _finality_stage_receiver) = mpsc::channel(100);
+    let result = InclusionStage::process_txs_step(
+        pool,
+        &finality_stage_sender,
+        dispatcher_state,
+        TEST_DOMAIN.into(),
+    )
+    .await;
+    assert!(result.is_ok(), "Inclusion stage step should succeed");
+}
+
+async fn retrieve_tx(dispatcher_state: &DispatcherState, tx: &Transaction) -> Transaction {
+    dispatcher_state
+        .tx_db
+        .retrieve_transaction_by_uuid(&tx.uuid)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+async fn run_and_expect_successful_inclusion(
+    expected_states: Vec<ExpectedCosmosTxState>,
+    provider: MockCosmosProvider,
+) {
+    let dispatcher_state = mock_dispatcher_state_with_provider(provider, TEST_BLOCK_TIME);
+    let (finality_stage_sender, mut finality_stage_receiver) = mpsc::channel(100);
+    let (pool, txs) = setup_pool(&dispatcher_state, 1).await;
+    let created_tx = &txs[0];
+
+    for (step, expected_state) in expected_states.iter().enumerate() {
+        let current_tx = retrieve_tx(&dispatcher_state, created_tx).await;
+        assert_eq!(
+            current_tx.status, expected_state.status,
+            "Step {}: Expected status {:?}, got {:?}",
+            step, expected_state.status, current_tx.status
+        );
+        assert_eq!(
+            current_tx.submission_attempts, expected_state.retries,
+            "Step {}: Expected {} retries, got {}",
+            step, expected_state.retries, current_tx.submission_attempts
+        );
+
+        let result = InclusionStage::process_txs_step(
+            &pool,
+            &finality_stage_sender,
+            &dispatcher_state,
+            TEST_DOMAIN.into(),
+        )
+        .await;
+        assert!(result.is_ok(), "Step {} should succeed", step);
+    }
+
+    let maybe_tx = tokio::time::timeout(Duration::from_millis(100), finality_stage_receiver.recv())
+        .await
+        .ok()
+        .flatten();
+    assert!(
+        maybe_tx.is_some(),
+        "Transaction should be sent to finality stage"
+    );
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_cosmos_inclusion_happy_path() {
+    let mock_provider = MockCosmosProvider::new();
+
+    let expected_tx_states = vec![
+        ExpectedCosmosTxState {
+            status: TransactionStatus::PendingInclusion,
+            retries: 0,
+        },
+        ExpectedCosmosTxState {
+            status: TransactionStatus::Mempool,
+            retries: 1,
+        },
+        ExpectedCosmosTxState {
+            status: TransactionStatus::Finalized,
+            retries: 1,
+        },
+    ];
+
+    run_and_expect_successful_inclusion(expected_tx_states, mock_provider).await;
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_cosmos_inclusion_sets_gas_fee_sequence_and_timeout() {
+    let mock_provider = MockCosmosProvider::new()
+        .with_gas_estimate(150_000)
+        .with_sequence(7)
+        .with_block_number(1_000);
+    let dispatcher_state = mock_dispatcher_state_with_provider(mock_provider, TEST_BLOCK_TIME);
+    let (pool, txs) = setup_pool(&dispatcher_state, 1).await;
+
+    process_step(&pool, &dispatcher_state).await;
+
+    let tx = retrieve_tx(&dispatcher_state, &txs[0]).await;
+    assert_eq!(tx.status, TransactionStatus::Mempool);
+    assert_eq!(tx.tx_hashes.len(), 1);
+
+    let precursor = tx.precursor();
+    assert_eq!(precursor.gas_limit, Some(150_000));
+    assert_eq!(precursor.fee_amount, Some(150_000 * TEST_GAS_PRICE));
+    assert_eq!(precursor.sequence, Some(7));
+    assert_eq!(precursor.timeout_height, Some(1_030));
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_cosmos_inclusion_assigns_consecutive_sequences() {
+    // transactions stay in the mempool, so the on-chain sequence does not move
+    let mock_provider = MockCosmosProvider::new()
+        .with_sequence(3)
+        .without_inclusion();
+    let dispatcher_state =
+        mock_dispatcher_state_with_provider(mock_provider.clone(), TEST_BLOCK_TIME);
+    let (pool, _) = setup_pool(&dispatcher_state, 3).await;
+
+    process_step(&pool, &dispatcher_state).await;
+
+    let mut sequences = mock_provider
+        .broadcasts()
+        .iter()
+        .map(|broadcast| broadcast.sequence)
+        .collect::<Vec<_>>();
+    sequences.sort();
+    assert_eq!(sequences, vec![3, 4, 5]);
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_cosmos_inclusion_escalates_fee_on_insufficient_fee() {
+    let mock_provider = MockCosmosProvider::new()
+        .with_gas_estimate(100_000)
+        .with_broadcast_errors(&["insufficient fee; got: 200000untrn required: 210000untrn"]);
+    let dispatcher_state =
+        mock_dispatcher_state_with_provider(mock_provider.clone(), TEST_BLOCK_TIME);
+    let (pool, txs) = setup_pool(&dispatcher_state, 1).await;
+
+    process_step(&pool, &dispatcher_state).await;
+
+    let broadcasts = mock_provider.broadcasts();
+    let fees = broadcasts
+        .iter()
+        .map(|broadcast| broadcast.fee_amount)
+        .collect::<Vec<_>>();
+    assert_eq!(fees, vec![200_000, 220_000]);
+
+    let tx = retrieve_tx(&dispatcher_state, &txs[0]).await;
+    assert_eq!(tx.status, TransactionStatus::Mempool);
+    assert_eq!(tx.precursor().fee_amount, Some(220_000));
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_cosmos_inclusion_recovers_from_sequence_mismatch() {
+    let mock_provider = MockCosmosProvider::new()
+        .with_sequence(5)
+        .without_inclusion();
+    let dispatcher_state =
+        mock_dispatcher_state_with_provider(mock_provider.clone(), TEST_BLOCK_TIME);
+
+    // the first tx reserves sequence 5 but never makes it into a block
+    let (pool, _) = setup_pool(&dispatcher_state, 1).await;
+    process_step(&pool, &dispatcher_state).await;
+
+    // the second tx is rejected with the locally reserved sequence 6,
+    // and resubmitted with the on-chain sequence
+    mock_provider.set_inclusion(true);
+    let (pool, txs) = setup_pool(&dispatcher_state, 1).await;
+    process_step(&pool, &dispatcher_state).await;
+
+    let sequences = mock_provider
+        .broadcasts()
+        .iter()
+        .map(|broadcast| broadcast.sequence)
+        .collect::<Vec<_>>();
+    assert_eq!(sequences, vec![5, 6, 5]);
+
+    let tx = retrieve_tx(&dispatcher_state, &txs[0]).await;
+    assert_eq!(tx.status, TransactionStatus::Mempool);
+    assert_eq!(tx.precursor().sequence, Some(5));
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_cosmos_inclusion_submission_failure_drops_tx() {
+    let mock_provider =
+        MockCosmosProvider::new().with_broadcast_errors(&["signature verification failed"]);
+    let dispatcher_state = mock_dispatcher_state_with_provider(mock_provider, TEST_BLOCK_TIME);
+    let (pool, txs) = setup_pool(&dispatcher_state, 1).await;
+
+    process_step(&pool, &dispatcher_state).await;
+
+    assert!(pool.lock().await.is_empty());
+
+    let tx = retrieve_tx(&dispatcher_state, &txs[0]).await;
+    assert!(
+        matches!(tx.status, TransactionStatus::Dropped(_)),
+        "Transaction should be dropped"
+    );
+
+    let payload = dispatcher_state
+        .payload_db
+        .retrieve_payload_by_uuid(&txs[0].payload_details[0].uuid)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(
+        matches!(
+            payload.status,
+            PayloadStatus::InTransaction(TransactionStatus::Dropped(_))
+        ),
+        "Payload should be dropped"
+    );
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_cosmos_tx_resubmitted_after_timeout_height() {
+    let mock_provider = MockCosmosProvider::new()
+        .with_block_number(100)
+        .without_inclusion();
+    let dispatcher_state =
+        mock_dispatcher_state_with_provider(mock_provider.clone(), TEST_BLOCK_TIME);
+    let (pool, txs) = setup_pool(&dispatcher_state, 1).await;
+
+    process_step(&pool, &dispatcher_state).await;
+
+    // the tx is still within its timeout height, so it is not resubmitted
+    mock_provider.set_block_number(130);
+    process_step(&pool, &dispatcher_state).await;
+    assert_eq!(mock_provider.broadcasts().len(), 1);
+
+    // once the timeout height has passed, the tx is resubmitted with a higher fee
+    mock_provider.set_block_number(131);
+    process_step(&pool, &dispatcher_state).await;
+
+    let broadcasts = mock_provider.broadcasts();
+    assert_eq!(
+        broadcasts,
+        vec![
+            Broadcast {
+                gas_limit: 200_000,
+                fee_amount: 400_000,
+                sequence: 0,
+                timeout_height: 130,
+            },
+            Broadcast {
+                gas_limit: 200_000,
+                fee_amount: 440_000,
+                sequence: 0,
+                timeout_height: 161,
+            },
+        ]
+    );
+
+    let tx = retrieve_tx(&dispatcher_state, &txs[0]).await;
+    assert_eq!(tx.tx_hashes.len(), 2);
+    assert_eq!(tx.submission_attempts, 2);
+}
@@ -3,14 +3,14 @@ use std::time::{Duration, Instant};
 
 use ethers::types::transaction::eip2718::TypedTransaction;
 use ethers::types::{TransactionReceipt, H160, H256 as EthersH256};
-use hyperlane_core::config::OpSubmissionConfig;
+use hyperlane_core::config::{OpSubmissionConfig, SignerAssignment};
 use hyperlane_core::{HyperlaneDomain, KnownHyperlaneDomain, H256, U256};
 use hyperlane_ethereum::EthereumReorgPeriod;
 
 use crate::adapter::chains::ethereum::{
     tests::{dummy_evm_tx, ExpectedTxType, MockEvmProvider},
     EthereumAdapter, EthereumAdapterMetrics, NonceDb, NonceManager, NonceManagerState,
-    NonceUpdater,
+    NonceUpdater, SignerLane, SignerPool,
 };
 use crate::dispatcher::{DispatcherState, PayloadDb, TransactionDb};
 use crate::tests::test_utils::tmp_dbs;
@@ -101,8 +101,60 @@ pub fn mock_ethereum_adapter(
     block_time: Duration,
     minimum_time_between_resubmissions: Duration,
 ) -> EthereumAdapter {
-    let domain: HyperlaneDomain = TEST_DOMAIN.into();
     let provider = Arc::new(provider);
+    let primary = mock_signer_lane(provider.clone(), tx_db, nonce_db, signer, block_time);
+    let signer_pool = SignerPool::new(primary, vec![], SignerAssignment::default());
+
+    mock_ethereum_adapter_with_signer_pool(
+        provider,
+        payload_db,
+        signer_pool,
+        block_time,
+        minimum_time_between_resubmissions,
+    )
+}
+
+pub fn mock_ethereum_adapter_with_signer_pool(
+    provider: Arc<MockEvmProvider>,
+    payload_db: Arc<dyn PayloadDb>,
+    signer_pool: SignerPool,
+    block_time: Duration,
+    minimum_time_between_resubmissions: Duration,
+) -> EthereumAdapter {
+    let domain: HyperlaneDomain = TEST_DOMAIN.into();
+    let reorg_period = EthereumReorgPeriod::Blocks(1);
+    let metrics = signer_pool.primary().metrics.clone();
+
+    let op_submission_config = OpSubmissionConfig::default();
+    let batch_contract_address = op_submission_config
+        .batch_contract_address
+        .unwrap_or_default();
+
+    EthereumAdapter {
+        estimated_block_time: block_time,
+        domain,
+        transaction_overrides: Default::default(),
+        submission_config: op_submission_config,
+        provider,
+        reorg_period,
+        signer_pool,
+        batch_cache: Default::default(),
+        batch_contract_address,
+        payload_db,
+        minimum_time_between_resubmissions,
+        metrics,
+        private_submitter: None,
+    }
+}
+
+/// A signer lane whose transactions are sent through `provider`
+pub fn mock_signer_lane(
+    provider: Arc<MockEvmProvider>,
+    tx_db: Arc<dyn TransactionDb>,
+    nonce_db: Arc<dyn NonceDb>,
+    signer: H160,
+    block_time: Duration,
+) -> SignerLane {
     let reorg_period = EthereumReorgPeriod::Blocks(1);
     let metrics = EthereumAdapterMetrics::dummy_instance();
     let state = Arc::new(NonceManagerState::new(
@@ -126,26 +178,7 @@ pub fn mock_ethereum_adapter(
         nonce_updater,
     };
 
-    let op_submission_config = OpSubmissionConfig::default();
-    let batch_contract_address = op_submission_config
-        .batch_contract_address
-        .unwrap_or_default();
-
-    EthereumAdapter {
-        estimated_block_time: block_time,
-        domain,
-        transaction_overrides: Default::default(),
-        submission_config: op_submission_config,
-        provider,
-        reorg_period,
-        nonce_manager,
-        batch_cache: Default::default(),
-        batch_contract_address,
-        payload_db,
-        signer,
-        minimum_time_between_resubmissions,
-        metrics,
-    }
+    SignerLane::new(signer, provider, nonce_manager, metrics)
 }
 
 pub fn mock_fee_history(base_fee: u32, prio_fee: u32) -> ethers::types::FeeHistory {
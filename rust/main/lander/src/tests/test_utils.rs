@@ -61,7 +61,7 @@ pub(crate) fn dummy_tx(payloads: Vec<FullPayload>, status: TransactionStatus) ->
     Transaction {
         uuid: UniqueIdentifier::random(),
         tx_hashes: vec![],
-        vm_specific_data: VmSpecificTxData::CosmWasm,
+        vm_specific_data: VmSpecificTxData::CosmWasm(Box::default()),
         payload_details: details.clone(),
         status,
         submission_attempts: 0,
@@ -12,7 +12,8 @@ use hyperlane_core::{identifiers::UniqueIdentifier, H256, H512};
 use crate::adapter::chains::AleoTxPrecursor;
 use crate::{
     adapter::chains::{
-        tron::TronTxPrecursor, EthereumTxPrecursor, RadixTxPrecursor, SealevelTxPrecursor,
+        tron::TronTxPrecursor, CosmWasmTxPrecursor, CosmosNativeTxPrecursor, EthereumTxPrecursor,
+        RadixTxPrecursor, SealevelTxPrecursor, StarknetTxPrecursor,
     },
     payload::PayloadDetails,
     LanderError,
@@ -54,7 +55,7 @@ impl Transaction {
     /// # Type Parameters
     ///
     /// * `P` - Any precursor type that implements `Into<VmSpecificTxData>` (AleoTxPrecursor,
-    ///   EthereumTxPrecursor, SealevelTxPrecursor, or RadixTxPrecursor)
+    ///   EthereumTxPrecursor, SealevelTxPrecursor, RadixTxPrecursor, etc.)
     ///
     /// # Arguments
     ///
@@ -170,9 +171,11 @@ impl DropReason {
 pub enum VmSpecificTxData {
     #[cfg(feature = "aleo")]
     Aleo(Box<AleoTxPrecursor>),
-    CosmWasm,
+    CosmWasm(Box<CosmWasmTxPrecursor>),
+    CosmosNative(Box<CosmosNativeTxPrecursor>),
     Evm(Box<EthereumTxPrecursor>),
     Radix(Box<RadixTxPrecursor>),
+    Starknet(Box<StarknetTxPrecursor>),
     Svm(Box<SealevelTxPrecursor>),
     Tron(Box<TronTxPrecursor>),
 }
@@ -1,3 +1,5 @@
+use base64::Engine;
+use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::{BufReader, BufWriter};
@@ -15,7 +17,9 @@ use solana_sdk::{
     signature::{Keypair, Signer},
     transaction::Transaction,
 };
-use solana_transaction_status::{EncodedConfirmedTransactionWithStatusMeta, UiTransactionEncoding};
+use solana_transaction_status::{
+    EncodedConfirmedTransactionWithStatusMeta, UiReturnDataEncoding, UiTransactionEncoding,
+};
 use std::cell::RefCell;
 use std::error::Error;
 
@@ -132,6 +136,37 @@ impl Context {
         None
     }
 
+    /// Simulates an instruction paid for by the payer and deserializes its
+    /// return data into a T, or returns None if there was no return data.
+    pub(crate) fn simulate_instruction<T: BorshDeserialize>(
+        &self,
+        instruction: Instruction,
+    ) -> Option<T> {
+        let recent_blockhash = self.client.get_latest_blockhash().unwrap();
+        let transaction = Transaction::new_unsigned(Message::new_with_blockhash(
+            &[instruction],
+            Some(&self.payer_pubkey),
+            &recent_blockhash,
+        ));
+        let simulation = self
+            .client
+            .simulate_transaction(&transaction)
+            .unwrap()
+            .value;
+        if let Some(err) = simulation.err {
+            panic!("Simulation failed: {:?}, logs: {:?}", err, simulation.logs);
+        }
+
+        simulation.return_data.map(|return_data| {
+            let bytes = match return_data.data.1 {
+                UiReturnDataEncoding::Base64 => base64::engine::general_purpose::STANDARD
+                    .decode(return_data.data.0)
+                    .unwrap(),
+            };
+            T::try_from_slice(&bytes).unwrap()
+        })
+    }
+
     pub(crate) fn payer_keypair_path(&self) -> &String {
         &self
             .payer_keypair
@@ -20,7 +20,7 @@ use solana_sdk::{
 use solana_system_interface::program as system_program;
 
 use account_utils::DiscriminatorEncode;
-use hyperlane_core::{H160, H256};
+use hyperlane_core::{Encode, HyperlaneMessage, H160, H256};
 use hyperlane_sealevel_connection_client::{
     gas_router::GasRouterConfig, router::RemoteRouterConfig,
 };
@@ -30,13 +30,16 @@ use hyperlane_sealevel_igp::{
 };
 use hyperlane_sealevel_mailbox::{
     accounts::{InboxAccount, OutboxAccount},
-    instruction::{Instruction as MailboxInstruction, OutboxDispatch},
+    instruction::{Instruction as MailboxInstruction, OutboxDispatch, VERSION as MAILBOX_VERSION},
     mailbox_dispatched_message_pda_seeds, mailbox_inbox_pda_seeds,
     mailbox_message_dispatch_authority_pda_seeds, mailbox_outbox_pda_seeds,
     mailbox_processed_message_pda_seeds,
     protocol_fee::ProtocolFee,
 };
 
+use hyperlane_sealevel_hook_interface::{
+    PostDispatchHookInstruction, PostDispatchInstruction, POST_DISPATCH_ACCOUNT_METAS_PDA_SEEDS,
+};
 use hyperlane_sealevel_token::{
     hyperlane_token_ata_payer_pda_seeds, hyperlane_token_mint_pda_seeds,
     spl_associated_token_account::get_associated_token_address_with_program_id, spl_token_2022,
@@ -45,11 +48,13 @@ use hyperlane_sealevel_token_collateral::{
     hyperlane_token_escrow_pda_seeds, plugin::CollateralPlugin,
 };
 use hyperlane_sealevel_token_lib::{
-    accounts::HyperlaneTokenAccount,
-    hyperlane_token_pda_seeds,
+    accounts::{HyperlaneTokenAccount, TransferControlsAccount},
+    hyperlane_token_pda_seeds, hyperlane_token_transfer_controls_pda_seeds,
     instruction::{
-        enroll_remote_routers_instruction, set_destination_gas_configs,
-        Instruction as HtInstruction, TransferRemote as HtTransferRemote,
+        enroll_remote_routers_instruction, set_destination_gas_configs, set_paused_instruction,
+        set_rate_limits_instruction, Instruction as HtInstruction, RateLimitConfig,
+        RateLimitParams, TransferRemote as HtTransferRemote,
+        TransferRemoteWithHooks as HtTransferRemoteWithHooks,
     },
 };
 use hyperlane_sealevel_token_native::hyperlane_token_native_collateral_pda_seeds;
@@ -62,6 +67,8 @@ use hyperlane_sealevel_validator_announce::{
     replay_protection_pda_seeds, validator_announce_pda_seeds,
     validator_storage_locations_pda_seeds,
 };
+use hyperlane_warp_route::TokenMessage;
+use serializable_account_meta::{SerializableAccountMeta, SimulationReturnData};
 use squads::{process_squads_cmd, SquadsCmd};
 use warp_route::parse_token_account_data;
 
@@ -81,7 +88,9 @@ mod warp_route;
 
 use crate::helloworld::process_helloworld_cmd;
 use crate::igp::process_igp_cmd;
-use crate::multisig_ism::process_multisig_ism_message_id_cmd;
+use crate::multisig_ism::{
+    process_multisig_ism_merkle_root_cmd, process_multisig_ism_message_id_cmd,
+};
 use crate::warp_route::process_warp_route_cmd;
 pub(crate) use crate::{context::*, core::*};
 
@@ -122,6 +131,7 @@ enum HyperlaneSealevelCmd {
     Igp(IgpCmd),
     ValidatorAnnounce(ValidatorAnnounceCmd),
     MultisigIsmMessageId(MultisigIsmMessageIdCmd),
+    MultisigIsmMerkleRoot(MultisigIsmMerkleRootCmd),
     WarpRoute(WarpRouteCmd),
     HelloWorld(HelloWorldCmd),
     Squads(SquadsCmd),
@@ -244,6 +254,12 @@ enum MailboxSubCmd {
     Delivered(Delivered),
     TransferOwnership(TransferOwnership),
     SetDefaultIsm(SetDefaultIsm),
+    /// Set the required hook. Senders must pass its accounts to dispatch once
+    /// it is set, so upgrade them first.
+    SetRequiredHook(SetHook),
+    /// Set the default hook. Senders must pass its accounts to dispatch
+    /// without a custom hook once it is set, so upgrade them first.
+    SetDefaultHook(SetHook),
     /// Simulate processing a message to debug delivery failures
     Simulate(Simulate),
 }
@@ -286,6 +302,15 @@ struct SetDefaultIsm {
     default_ism: Pubkey,
 }
 
+#[derive(Args)]
+struct SetHook {
+    #[arg(long, short)]
+    program_id: Pubkey,
+    /// The hook to set. Unsets the hook if omitted.
+    #[arg(long)]
+    hook: Option<Pubkey>,
+}
+
 #[derive(Args)]
 struct Outbox {
     #[arg(long, short, default_value_t = ECLIPSE_DOMAIN)]
@@ -346,6 +371,9 @@ enum TokenSubCmd {
     TransferOwnership(TransferOwnership),
     SetInterchainSecurityModule(SetInterchainSecurityModule),
     Igp(Igp),
+    Pause(TokenPause),
+    Unpause(TokenPause),
+    SetRateLimit(TokenSetRateLimit),
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
@@ -392,6 +420,26 @@ struct TokenSetDestinationGas {
     gas: u64,
 }
 
+#[derive(Args)]
+struct TokenPause {
+    #[arg(long, short, default_value_t = HYPERLANE_TOKEN_PROG_ID)]
+    program_id: Pubkey,
+}
+
+#[derive(Args)]
+struct TokenSetRateLimit {
+    #[arg(long, short, default_value_t = HYPERLANE_TOKEN_PROG_ID)]
+    program_id: Pubkey,
+    domain: u32,
+    /// The maximum amount, in local decimals, that can be transferred in each direction.
+    /// Omit along with --refill-per-second to remove the rate limit.
+    #[arg(long, requires = "refill_per_second")]
+    capacity: Option<u64>,
+    /// The amount, in local decimals, that becomes available again each second.
+    #[arg(long, requires = "capacity")]
+    refill_per_second: Option<u64>,
+}
+
 #[derive(Args)]
 struct SetInterchainSecurityModule {
     #[arg(long, short)]
@@ -669,16 +717,16 @@ struct MultisigIsmMessageIdCmd {
 
 #[derive(Subcommand)]
 enum MultisigIsmMessageIdSubCmd {
-    Deploy(MultisigIsmMessageIdDeploy),
+    Deploy(MultisigIsmDeploy),
     Init(MultisigIsmMessageIdInit),
     SetValidatorsAndThreshold(MultisigIsmMessageIdSetValidatorsAndThreshold),
-    Query(MultisigIsmMessageIdQuery),
+    Query(MultisigIsmQuery),
     TransferOwnership(TransferOwnership),
-    Configure(MultisigIsmMessageIdConfigure),
+    Configure(MultisigIsmConfigure),
 }
 
 #[derive(Args)]
-struct MultisigIsmMessageIdDeploy {
+struct MultisigIsmDeploy {
     #[command(flatten)]
     env_args: EnvironmentArgs,
     #[arg(long)]
@@ -692,7 +740,7 @@ struct MultisigIsmMessageIdDeploy {
 }
 
 #[derive(Args)]
-struct MultisigIsmMessageIdConfigure {
+struct MultisigIsmConfigure {
     #[arg(long)]
     program_id: Pubkey,
     #[arg(long)]
@@ -708,7 +756,7 @@ struct MultisigIsmMessageIdInit {
 }
 
 #[derive(Args)]
-struct MultisigIsmMessageIdQuery {
+struct MultisigIsmQuery {
     #[arg(long, short)]
     program_id: Pubkey,
     #[arg(long, value_delimiter = ',')]
@@ -727,6 +775,40 @@ struct MultisigIsmMessageIdSetValidatorsAndThreshold {
     threshold: u8,
 }
 
+#[derive(Args)]
+struct MultisigIsmMerkleRootCmd {
+    #[command(subcommand)]
+    cmd: MultisigIsmMerkleRootSubCmd,
+}
+
+#[derive(Subcommand)]
+enum MultisigIsmMerkleRootSubCmd {
+    Deploy(MultisigIsmDeploy),
+    Init(MultisigIsmMerkleRootInit),
+    SetValidatorsAndThreshold(MultisigIsmMerkleRootSetValidatorsAndThreshold),
+    Query(MultisigIsmQuery),
+    TransferOwnership(TransferOwnership),
+    Configure(MultisigIsmConfigure),
+}
+
+#[derive(Args)]
+struct MultisigIsmMerkleRootInit {
+    #[arg(long, short)]
+    program_id: Pubkey,
+}
+
+#[derive(Args)]
+struct MultisigIsmMerkleRootSetValidatorsAndThreshold {
+    #[arg(long, short)]
+    program_id: Pubkey,
+    #[arg(long)]
+    domain: u32,
+    #[arg(long, value_delimiter = ',')]
+    validators: Vec<H160>,
+    #[arg(long)]
+    threshold: u8,
+}
+
 #[derive(Args)]
 pub(crate) struct HelloWorldCmd {
     #[command(subcommand)]
@@ -833,6 +915,9 @@ fn main() {
         HyperlaneSealevelCmd::MultisigIsmMessageId(cmd) => {
             process_multisig_ism_message_id_cmd(ctx, cmd)
         }
+        HyperlaneSealevelCmd::MultisigIsmMerkleRoot(cmd) => {
+            process_multisig_ism_merkle_root_cmd(ctx, cmd)
+        }
         HyperlaneSealevelCmd::Core(cmd) => process_core_cmd(ctx, cmd),
         HyperlaneSealevelCmd::WarpRoute(cmd) => process_warp_route_cmd(ctx, cmd),
         HyperlaneSealevelCmd::HelloWorld(cmd) => process_helloworld_cmd(ctx, cmd),
@@ -965,6 +1050,36 @@ fn process_mailbox_cmd(ctx: Context, cmd: MailboxCmd) {
                 )
                 .send_with_payer();
         }
+        MailboxSubCmd::SetRequiredHook(set_hook) => {
+            let instruction =
+                hyperlane_sealevel_mailbox::instruction::set_required_hook_instruction(
+                    set_hook.program_id,
+                    ctx.payer_pubkey,
+                    set_hook.hook,
+                )
+                .unwrap();
+            ctx.new_txn()
+                .add_with_description(
+                    instruction,
+                    format!("Setting required hook to {:?}", set_hook.hook),
+                )
+                .send_with_payer();
+        }
+        MailboxSubCmd::SetDefaultHook(set_hook) => {
+            let instruction =
+                hyperlane_sealevel_mailbox::instruction::set_default_hook_instruction(
+                    set_hook.program_id,
+                    ctx.payer_pubkey,
+                    set_hook.hook,
+                )
+                .unwrap();
+            ctx.new_txn()
+                .add_with_description(
+                    instruction,
+                    format!("Setting default hook to {:?}", set_hook.hook),
+                )
+                .send_with_payer();
+        }
         MailboxSubCmd::Simulate(simulate) => {
             // Decode the base58 transaction
             let tx_bytes = bs58::decode(&simulate.transaction)
@@ -1130,6 +1245,29 @@ fn process_token_cmd(mut ctx: Context, cmd: TokenCmd) {
                     );
                 }
             }
+            println!("--------------------------------");
+
+            let (transfer_controls_account, transfer_controls_bump) = Pubkey::find_program_address(
+                hyperlane_token_transfer_controls_pda_seeds!(),
+                &query.program_id,
+            );
+            println!(
+                "Transfer controls: {}, bump={}",
+                transfer_controls_account, transfer_controls_bump
+            );
+            let transfer_controls = ctx
+                .client
+                .get_account_with_commitment(&transfer_controls_account, ctx.commitment)
+                .unwrap()
+                .value;
+            if let Some(info) = transfer_controls {
+                let transfer_controls = TransferControlsAccount::fetch(&mut &info.data[..])
+                    .unwrap()
+                    .into_inner();
+                println!("{:#?}", transfer_controls);
+            } else {
+                println!("Not yet created, transfers are neither paused nor rate limited");
+            }
         }
         TokenSubCmd::TransferRemote(xfer) => {
             is_keypair(&xfer.sender).unwrap();
@@ -1171,12 +1309,6 @@ fn process_token_cmd(mut ctx: Context, cmd: TokenCmd) {
             let (mailbox_outbox_account, _mailbox_outbox_bump) =
                 Pubkey::find_program_address(mailbox_outbox_pda_seeds!(), &token.mailbox);
 
-            let ixn = HtInstruction::TransferRemote(HtTransferRemote {
-                destination_domain: xfer.destination_domain,
-                recipient,
-                amount_or_id: xfer.amount.into(),
-            });
-
             // Transfers tokens to a remote.
             // Burns the tokens from the sender's associated token account and
             // then dispatches a message to the remote recipient.
@@ -1306,6 +1438,71 @@ fn process_token_cmd(mut ctx: Context, cmd: TokenCmd) {
                 }
             }
 
+            // N+1. [writeable] OPTIONAL - The transfer controls PDA account, which only
+            //      exists once the owner has configured a transfer control.
+            let (transfer_controls_account, _transfer_controls_bump) = Pubkey::find_program_address(
+                hyperlane_token_transfer_controls_pda_seeds!(),
+                &xfer.program_id,
+            );
+            if ctx
+                .client
+                .get_account_with_commitment(&transfer_controls_account, ctx.commitment)
+                .unwrap()
+                .value
+                .is_some()
+            {
+                accounts.push(AccountMeta::new(transfer_controls_account, false));
+            }
+
+            // N+2..M. [??..??] The Mailbox's hooks, each followed by the accounts
+            //         required to invoke it.
+            let outbox_account = ctx
+                .client
+                .get_account_with_commitment(&mailbox_outbox_account, ctx.commitment)
+                .unwrap()
+                .value
+                .unwrap();
+            let outbox = OutboxAccount::fetch(&mut &outbox_account.data[..])
+                .unwrap()
+                .into_inner();
+            let message = HyperlaneMessage {
+                version: MAILBOX_VERSION,
+                nonce: outbox.tree.count() as u32,
+                origin: outbox.local_domain,
+                sender: H256::from_slice(&xfer.program_id.to_bytes()[..]),
+                destination: xfer.destination_domain,
+                recipient: *token
+                    .remote_routers
+                    .get(&xfer.destination_domain)
+                    .expect("No router enrolled for destination"),
+                body: TokenMessage::new(
+                    recipient,
+                    token.local_amount_to_remote_amount(xfer.amount).unwrap(),
+                    vec![],
+                )
+                .to_vec(),
+            };
+            let (hook_accounts, hook_account_counts) = get_mailbox_hook_accounts(
+                &ctx,
+                outbox.required_hook.into_iter().chain(outbox.default_hook),
+                &message,
+            );
+            accounts.extend(hook_accounts);
+
+            let transfer = HtTransferRemote {
+                destination_domain: xfer.destination_domain,
+                recipient,
+                amount_or_id: xfer.amount.into(),
+            };
+            let ixn = if hook_account_counts.is_empty() {
+                HtInstruction::TransferRemote(transfer)
+            } else {
+                HtInstruction::TransferRemoteWithHooks(HtTransferRemoteWithHooks {
+                    transfer,
+                    hook_account_counts,
+                })
+            };
+
             eprintln!("accounts={:#?}", accounts); // FIXME remove
             let xfer_instruction = Instruction {
                 program_id: xfer.program_id,
@@ -1383,6 +1580,50 @@ fn process_token_cmd(mut ctx: Context, cmd: TokenCmd) {
                 .add_with_description(instruction, format!("Set ISM to {:?}", set_ism.ism))
                 .send_with_payer();
         }
+        TokenSubCmd::Pause(pause) => {
+            let instruction = set_paused_instruction(pause.program_id, ctx.payer_pubkey, true)
+                .expect("Failed to build set paused instruction");
+
+            ctx.new_txn()
+                .add_with_description(instruction, format!("Pause {}", pause.program_id))
+                .send_with_payer();
+        }
+        TokenSubCmd::Unpause(unpause) => {
+            let instruction = set_paused_instruction(unpause.program_id, ctx.payer_pubkey, false)
+                .expect("Failed to build set paused instruction");
+
+            ctx.new_txn()
+                .add_with_description(instruction, format!("Unpause {}", unpause.program_id))
+                .send_with_payer();
+        }
+        TokenSubCmd::SetRateLimit(set_rate_limit) => {
+            let limit = set_rate_limit
+                .capacity
+                .zip(set_rate_limit.refill_per_second)
+                .map(|(capacity, refill_per_second)| RateLimitParams {
+                    capacity,
+                    refill_per_second,
+                });
+            let instruction = set_rate_limits_instruction(
+                set_rate_limit.program_id,
+                ctx.payer_pubkey,
+                vec![RateLimitConfig {
+                    domain: set_rate_limit.domain,
+                    limit: limit.clone(),
+                }],
+            )
+            .expect("Failed to build set rate limits instruction");
+
+            ctx.new_txn()
+                .add_with_description(
+                    instruction,
+                    format!(
+                        "Set rate limit of {} for domain {} to {:?}",
+                        set_rate_limit.program_id, set_rate_limit.domain, limit
+                    ),
+                )
+                .send_with_payer();
+        }
         TokenSubCmd::Igp(args) => match args.cmd {
             GetSetCmd::Set(set_args) => {
                 let igp_type: InterchainGasPaymasterType = match set_args.igp_type {
@@ -1519,3 +1760,39 @@ fn process_validator_announce_cmd(ctx: Context, cmd: ValidatorAnnounceCmd) {
         }
     }
 }
+
+/// Gets the accounts to append to a dispatch for the Mailbox to invoke `hooks`
+/// with `message`, and the number of accounts following each hook's program
+/// account. The accounts of each hook are found by simulating its
+/// `PostDispatchAccountMetas` instruction.
+fn get_mailbox_hook_accounts(
+    ctx: &Context,
+    hooks: impl IntoIterator<Item = Pubkey>,
+    message: &HyperlaneMessage,
+) -> (Vec<AccountMeta>, Vec<u8>) {
+    let mut accounts = vec![];
+    let mut hook_account_counts = vec![];
+    for hook in hooks {
+        let (account_metas_pda_key, _account_metas_pda_bump) =
+            Pubkey::find_program_address(POST_DISPATCH_ACCOUNT_METAS_PDA_SEEDS, &hook);
+        let instruction = Instruction::new_with_bytes(
+            hook,
+            &PostDispatchHookInstruction::PostDispatchAccountMetas(PostDispatchInstruction::new(
+                vec![],
+                message.to_vec(),
+            ))
+            .encode()
+            .unwrap(),
+            vec![AccountMeta::new_readonly(account_metas_pda_key, false)],
+        );
+        let hook_account_metas = ctx
+            .simulate_instruction::<SimulationReturnData<Vec<SerializableAccountMeta>>>(instruction)
+            .map(|simulation_return_data| simulation_return_data.return_data)
+            .unwrap_or_default();
+
+        accounts.push(AccountMeta::new_readonly(hook, false));
+        hook_account_counts.push(hook_account_metas.len().try_into().unwrap());
+        accounts.extend(hook_account_metas.into_iter().map(AccountMeta::from));
+    }
+    (accounts, hook_account_counts)
+}
@@ -10,24 +10,21 @@ use crate::registry::FileSystemRegistry;
 use crate::{
     artifacts::{write_json, SingularProgramIdArtifact},
     cmd_utils::{create_new_directory, deploy_program},
-    Context, MultisigIsmMessageIdCmd, MultisigIsmMessageIdSubCmd,
+    Context, MultisigIsmConfigure, MultisigIsmDeploy, MultisigIsmMerkleRootCmd,
+    MultisigIsmMerkleRootSubCmd, MultisigIsmMessageIdCmd, MultisigIsmMessageIdSubCmd,
+    MultisigIsmQuery,
 };
 
 use hyperlane_core::H160;
 
-use hyperlane_sealevel_multisig_ism_message_id::{
-    access_control_pda_seeds,
-    accounts::{AccessControlAccount, DomainDataAccount},
-    domain_data_pda_seeds,
-    instruction::{set_validators_and_threshold_instruction, ValidatorsAndThreshold},
-};
+use hyperlane_sealevel_multisig_ism_merkle_root as merkle_root;
+use hyperlane_sealevel_multisig_ism_message_id as message_id;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct MultisigIsmConfig {
-    /// Note this type is ignored in this tooling. It'll always assume this
-    /// relates to a multisig-ism-message-id variant, which is the only type
-    /// implemented in Sealevel.
+    /// Note this type is ignored in this tooling. The multisig ISM variant
+    /// is instead determined by the subcommand being run.
     /// Commenting out for now until this is needed, and due to `infra`
     /// generating non-numeric types at the moment.
     // #[serde(rename = "type")]
@@ -36,148 +33,412 @@ pub(crate) struct MultisigIsmConfig {
     pub threshold: u8,
 }
 
-impl From<MultisigIsmConfig> for ValidatorsAndThreshold {
+impl From<MultisigIsmConfig> for message_id::instruction::ValidatorsAndThreshold {
     fn from(val: MultisigIsmConfig) -> Self {
-        ValidatorsAndThreshold {
+        Self {
             validators: val.validators,
             threshold: val.threshold,
         }
     }
 }
 
+impl From<MultisigIsmConfig> for merkle_root::instruction::ValidatorsAndThreshold {
+    fn from(val: MultisigIsmConfig) -> Self {
+        Self {
+            validators: val.validators,
+            threshold: val.threshold,
+        }
+    }
+}
+
+/// The multisig ISM programs supported by this tooling.
+/// Both share the same instructions and accounts, but use different PDA seeds.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum MultisigIsmVariant {
+    MessageId,
+    MerkleRoot,
+}
+
+impl MultisigIsmVariant {
+    fn display_name(&self) -> &'static str {
+        match self {
+            Self::MessageId => "Multisig ISM Message ID",
+            Self::MerkleRoot => "Multisig ISM Merkle Root",
+        }
+    }
+
+    fn artifacts_dir_name(&self) -> &'static str {
+        match self {
+            Self::MessageId => "multisig-ism-message-id",
+            Self::MerkleRoot => "multisig-ism-merkle-root",
+        }
+    }
+
+    fn program_name(&self) -> &'static str {
+        match self {
+            Self::MessageId => "hyperlane_sealevel_multisig_ism_message_id",
+            Self::MerkleRoot => "hyperlane_sealevel_multisig_ism_merkle_root",
+        }
+    }
+
+    fn access_control_pda_key(&self, program_id: &Pubkey) -> Pubkey {
+        match self {
+            Self::MessageId => {
+                Pubkey::find_program_address(message_id::access_control_pda_seeds!(), program_id).0
+            }
+            Self::MerkleRoot => {
+                Pubkey::find_program_address(
+                    multisig_ism::access_control_pda_seeds!(
+                        merkle_root::processor::PDA_SEED_PREFIX
+                    ),
+                    program_id,
+                )
+                .0
+            }
+        }
+    }
+
+    fn domain_data_pda_key(&self, program_id: &Pubkey, domain: u32) -> Pubkey {
+        match self {
+            Self::MessageId => {
+                Pubkey::find_program_address(message_id::domain_data_pda_seeds!(domain), program_id)
+                    .0
+            }
+            Self::MerkleRoot => {
+                Pubkey::find_program_address(
+                    multisig_ism::domain_data_pda_seeds!(
+                        merkle_root::processor::PDA_SEED_PREFIX,
+                        domain
+                    ),
+                    program_id,
+                )
+                .0
+            }
+        }
+    }
+
+    fn init_instruction(&self, program_id: Pubkey, payer: Pubkey) -> Instruction {
+        match self {
+            Self::MessageId => message_id::instruction::init_instruction(program_id, payer),
+            Self::MerkleRoot => merkle_root::instruction::init_instruction(
+                program_id,
+                merkle_root::processor::PDA_SEED_PREFIX,
+                payer,
+            ),
+        }
+        .unwrap()
+    }
+
+    fn transfer_ownership_instruction(
+        &self,
+        program_id: Pubkey,
+        owner: Pubkey,
+        new_owner: Option<Pubkey>,
+    ) -> Instruction {
+        match self {
+            Self::MessageId => message_id::instruction::transfer_ownership_instruction(
+                program_id, owner, new_owner,
+            ),
+            Self::MerkleRoot => merkle_root::instruction::transfer_ownership_instruction(
+                program_id,
+                merkle_root::processor::PDA_SEED_PREFIX,
+                owner,
+                new_owner,
+            ),
+        }
+        .unwrap()
+    }
+
+    fn set_validators_and_threshold_instruction(
+        &self,
+        program_id: Pubkey,
+        owner: Pubkey,
+        domain: u32,
+        config: MultisigIsmConfig,
+    ) -> Instruction {
+        match self {
+            Self::MessageId => message_id::instruction::set_validators_and_threshold_instruction(
+                program_id,
+                owner,
+                domain,
+                config.into(),
+            ),
+            Self::MerkleRoot => merkle_root::instruction::set_validators_and_threshold_instruction(
+                program_id,
+                merkle_root::processor::PDA_SEED_PREFIX,
+                owner,
+                domain,
+                config.into(),
+            ),
+        }
+        .unwrap()
+    }
+
+    /// Formats the access control account data for display.
+    fn format_access_control(&self, data: &[u8]) -> String {
+        match self {
+            Self::MessageId => format!(
+                "{:#?}",
+                message_id::accounts::AccessControlAccount::fetch(&mut &data[..])
+                    .unwrap()
+                    .into_inner()
+            ),
+            Self::MerkleRoot => format!(
+                "{:#?}",
+                merkle_root::accounts::AccessControlAccount::fetch(&mut &data[..])
+                    .unwrap()
+                    .into_inner()
+            ),
+        }
+    }
+
+    /// Formats the domain data account data for display.
+    fn format_domain_data(&self, data: &[u8]) -> String {
+        match self {
+            Self::MessageId => format!(
+                "{:#?}",
+                message_id::accounts::DomainDataAccount::fetch(&mut &data[..])
+                    .unwrap()
+                    .into_inner()
+            ),
+            Self::MerkleRoot => format!(
+                "{:#?}",
+                merkle_root::accounts::DomainDataAccount::fetch(&mut &data[..])
+                    .unwrap()
+                    .into_inner()
+            ),
+        }
+    }
+
+    /// Deserializes the validators and threshold from domain data account data.
+    fn validators_and_threshold(&self, data: &[u8]) -> MultisigIsmConfig {
+        let (validators, threshold) = match self {
+            Self::MessageId => {
+                let domain_data = message_id::accounts::DomainDataAccount::fetch(&mut &data[..])
+                    .unwrap()
+                    .into_inner();
+                (
+                    domain_data.validators_and_threshold.validators,
+                    domain_data.validators_and_threshold.threshold,
+                )
+            }
+            Self::MerkleRoot => {
+                let domain_data = merkle_root::accounts::DomainDataAccount::fetch(&mut &data[..])
+                    .unwrap()
+                    .into_inner();
+                (
+                    domain_data.validators_and_threshold.validators,
+                    domain_data.validators_and_threshold.threshold,
+                )
+            }
+        };
+        MultisigIsmConfig {
+            validators,
+            threshold,
+        }
+    }
+}
+
 pub(crate) fn process_multisig_ism_message_id_cmd(mut ctx: Context, cmd: MultisigIsmMessageIdCmd) {
+    let variant = MultisigIsmVariant::MessageId;
     match cmd.cmd {
         MultisigIsmMessageIdSubCmd::Deploy(deploy) => {
-            let environments_dir = create_new_directory(
-                &deploy.env_args.environments_dir,
-                &deploy.env_args.environment,
+            process_deploy(&mut ctx, variant, deploy);
+        }
+        MultisigIsmMessageIdSubCmd::Init(init) => {
+            let init_instruction = variant.init_instruction(init.program_id, ctx.payer_pubkey);
+            ctx.new_txn().add(init_instruction).send_with_payer();
+        }
+        MultisigIsmMessageIdSubCmd::SetValidatorsAndThreshold(set_config) => {
+            set_validators_and_threshold(
+                &mut ctx,
+                variant,
+                set_config.program_id,
+                set_config.domain,
+                MultisigIsmConfig {
+                    validators: set_config.validators,
+                    threshold: set_config.threshold,
+                },
             );
-            let ism_dir = create_new_directory(&environments_dir, "multisig-ism-message-id");
-            let chain_dir = create_new_directory(&ism_dir, &deploy.chain);
-            let context_dir = create_new_directory(&chain_dir, &deploy.context);
-            let key_dir = create_new_directory(&context_dir, "keys");
-
-            let registry = FileSystemRegistry::new(deploy.registry.to_path_buf());
-            let chain_metadatas = registry.get_metadata();
-            let chain_metadata = chain_metadatas.get(&deploy.chain).unwrap();
-            let local_domain = chain_metadata.domain_id;
-            println!("Local domain: {}", local_domain);
-
-            let ism_program_id = deploy_multisig_ism_message_id(
+        }
+        MultisigIsmMessageIdSubCmd::Query(query) => {
+            process_query(&mut ctx, variant, query);
+        }
+        MultisigIsmMessageIdSubCmd::TransferOwnership(transfer_ownership) => {
+            transfer_ownership_to(
                 &mut ctx,
-                &deploy.built_so_dir,
-                &key_dir,
-                local_domain,
+                variant,
+                transfer_ownership.program_id,
+                transfer_ownership.new_owner,
             );
+        }
+        MultisigIsmMessageIdSubCmd::Configure(configure) => {
+            process_configure(&mut ctx, variant, configure);
+        }
+    }
+}
 
-            write_json::<SingularProgramIdArtifact>(
-                &context_dir.join("program-ids.json"),
-                ism_program_id.into(),
-            );
+pub(crate) fn process_multisig_ism_merkle_root_cmd(
+    mut ctx: Context,
+    cmd: MultisigIsmMerkleRootCmd,
+) {
+    let variant = MultisigIsmVariant::MerkleRoot;
+    match cmd.cmd {
+        MultisigIsmMerkleRootSubCmd::Deploy(deploy) => {
+            process_deploy(&mut ctx, variant, deploy);
         }
-        MultisigIsmMessageIdSubCmd::Init(init) => {
-            let init_instruction =
-                hyperlane_sealevel_multisig_ism_message_id::instruction::init_instruction(
-                    init.program_id,
-                    ctx.payer_pubkey,
-                )
-                .unwrap();
+        MultisigIsmMerkleRootSubCmd::Init(init) => {
+            let init_instruction = variant.init_instruction(init.program_id, ctx.payer_pubkey);
             ctx.new_txn().add(init_instruction).send_with_payer();
         }
-        MultisigIsmMessageIdSubCmd::SetValidatorsAndThreshold(set_config) => {
+        MultisigIsmMerkleRootSubCmd::SetValidatorsAndThreshold(set_config) => {
             set_validators_and_threshold(
                 &mut ctx,
+                variant,
                 set_config.program_id,
                 set_config.domain,
-                ValidatorsAndThreshold {
+                MultisigIsmConfig {
                     validators: set_config.validators,
                     threshold: set_config.threshold,
                 },
             );
         }
-        MultisigIsmMessageIdSubCmd::Query(query) => {
-            let (access_control_pda_key, _access_control_pda_bump) =
-                Pubkey::find_program_address(access_control_pda_seeds!(), &query.program_id);
+        MultisigIsmMerkleRootSubCmd::Query(query) => {
+            process_query(&mut ctx, variant, query);
+        }
+        MultisigIsmMerkleRootSubCmd::TransferOwnership(transfer_ownership) => {
+            transfer_ownership_to(
+                &mut ctx,
+                variant,
+                transfer_ownership.program_id,
+                transfer_ownership.new_owner,
+            );
+        }
+        MultisigIsmMerkleRootSubCmd::Configure(configure) => {
+            process_configure(&mut ctx, variant, configure);
+        }
+    }
+}
+
+fn process_deploy(ctx: &mut Context, variant: MultisigIsmVariant, deploy: MultisigIsmDeploy) {
+    let environments_dir = create_new_directory(
+        &deploy.env_args.environments_dir,
+        &deploy.env_args.environment,
+    );
+    let ism_dir = create_new_directory(&environments_dir, variant.artifacts_dir_name());
+    let chain_dir = create_new_directory(&ism_dir, &deploy.chain);
+    let context_dir = create_new_directory(&chain_dir, &deploy.context);
+    let key_dir = create_new_directory(&context_dir, "keys");
+
+    let registry = FileSystemRegistry::new(deploy.registry.to_path_buf());
+    let chain_metadatas = registry.get_metadata();
+    let chain_metadata = chain_metadatas.get(&deploy.chain).unwrap();
+    let local_domain = chain_metadata.domain_id;
+    println!("Local domain: {}", local_domain);
+
+    let ism_program_id =
+        deploy_multisig_ism(ctx, variant, &deploy.built_so_dir, &key_dir, local_domain);
+
+    write_json::<SingularProgramIdArtifact>(
+        &context_dir.join("program-ids.json"),
+        ism_program_id.into(),
+    );
+}
+
+fn process_query(ctx: &mut Context, variant: MultisigIsmVariant, query: MultisigIsmQuery) {
+    let access_control_pda_key = variant.access_control_pda_key(&query.program_id);
+
+    let accounts = ctx
+        .client
+        .get_multiple_accounts_with_commitment(&[access_control_pda_key], ctx.commitment)
+        .unwrap()
+        .value;
+    println!(
+        "Access control: {}",
+        variant.format_access_control(&accounts[0].as_ref().unwrap().data)
+    );
+
+    if let Some(domains) = query.domains {
+        for domain in domains {
+            println!("Querying domain data for origin domain: {}", domain);
+
+            let domain_data_pda_key = variant.domain_data_pda_key(&query.program_id, domain);
 
             let accounts = ctx
                 .client
-                .get_multiple_accounts_with_commitment(&[access_control_pda_key], ctx.commitment)
+                .get_multiple_accounts_with_commitment(&[domain_data_pda_key], ctx.commitment)
                 .unwrap()
                 .value;
-            let access_control =
-                AccessControlAccount::fetch(&mut &accounts[0].as_ref().unwrap().data[..])
-                    .unwrap()
-                    .into_inner();
-            println!("Access control: {:#?}", access_control);
-
-            if let Some(domains) = query.domains {
-                for domain in domains {
-                    println!("Querying domain data for origin domain: {}", domain);
-
-                    let (domain_data_pda_key, _domain_data_pda_bump) = Pubkey::find_program_address(
-                        domain_data_pda_seeds!(domain),
-                        &query.program_id,
-                    );
-
-                    let accounts = ctx
-                        .client
-                        .get_multiple_accounts_with_commitment(
-                            &[domain_data_pda_key],
-                            ctx.commitment,
-                        )
-                        .unwrap()
-                        .value;
-
-                    if let Some(account) = &accounts[0] {
-                        let domain_data = DomainDataAccount::fetch(&mut &account.data[..])
-                            .unwrap()
-                            .into_inner();
-                        println!("Domain data for {}:\n{:#?}", domain, domain_data);
-                    } else {
-                        println!("No domain data for domain {}", domain);
-                    }
-                }
-            }
-        }
-        MultisigIsmMessageIdSubCmd::TransferOwnership(transfer_ownership) => {
-            let instruction =
-                hyperlane_sealevel_multisig_ism_message_id::instruction::transfer_ownership_instruction(
-                    transfer_ownership.program_id,
-                    ctx.payer_pubkey,
-                    Some(transfer_ownership.new_owner),
-                )
-                .unwrap();
 
-            ctx.new_txn()
-                .add_with_description(
-                    instruction,
-                    format!("Transfer ownership to {}", transfer_ownership.new_owner),
-                )
-                .send_with_payer();
-        }
-        MultisigIsmMessageIdSubCmd::Configure(configure) => {
-            configure_multisig_ism_message_id(
-                &mut ctx,
-                configure.program_id,
-                &configure.multisig_config_file,
-                &configure.registry,
-            );
+            if let Some(account) = &accounts[0] {
+                println!(
+                    "Domain data for {}:\n{}",
+                    domain,
+                    variant.format_domain_data(&account.data)
+                );
+            } else {
+                println!("No domain data for domain {}", domain);
+            }
         }
     }
 }
 
+fn transfer_ownership_to(
+    ctx: &mut Context,
+    variant: MultisigIsmVariant,
+    program_id: Pubkey,
+    new_owner: Pubkey,
+) {
+    let instruction =
+        variant.transfer_ownership_instruction(program_id, ctx.payer_pubkey, Some(new_owner));
+
+    ctx.new_txn()
+        .add_with_description(instruction, format!("Transfer ownership to {}", new_owner))
+        .send_with_payer();
+}
+
+fn process_configure(
+    ctx: &mut Context,
+    variant: MultisigIsmVariant,
+    configure: MultisigIsmConfigure,
+) {
+    configure_multisig_ism(
+        ctx,
+        variant,
+        configure.program_id,
+        &configure.multisig_config_file,
+        &configure.registry,
+    );
+}
+
 pub(crate) fn deploy_multisig_ism_message_id(
     ctx: &mut Context,
     built_so_dir: &Path,
     key_dir: &Path,
     local_domain: u32,
+) -> Pubkey {
+    deploy_multisig_ism(
+        ctx,
+        MultisigIsmVariant::MessageId,
+        built_so_dir,
+        key_dir,
+        local_domain,
+    )
+}
+
+pub(crate) fn deploy_multisig_ism(
+    ctx: &mut Context,
+    variant: MultisigIsmVariant,
+    built_so_dir: &Path,
+    key_dir: &Path,
+    local_domain: u32,
 ) -> Pubkey {
     let program_id = deploy_program(
         ctx.payer_keypair_path(),
         key_dir,
-        "hyperlane_sealevel_multisig_ism_message_id",
+        variant.program_name(),
         built_so_dir
-            .join("hyperlane_sealevel_multisig_ism_message_id.so")
+            .join(format!("{}.so", variant.program_name()))
             .to_str()
             .unwrap(),
         &ctx.client.url(),
@@ -186,41 +447,41 @@ pub(crate) fn deploy_multisig_ism_message_id(
     .unwrap();
 
     println!(
-        "Deployed Multisig ISM Message ID at program ID {}",
+        "Deployed {} at program ID {}",
+        variant.display_name(),
         program_id
     );
 
     // Initialize
-    let instruction = hyperlane_sealevel_multisig_ism_message_id::instruction::init_instruction(
-        program_id,
-        ctx.payer_pubkey,
-    )
-    .unwrap();
+    let instruction = variant.init_instruction(program_id, ctx.payer_pubkey);
 
     ctx.new_txn()
         .add_with_description(
             instruction,
             format!(
-                "Initializing Multisig ISM Message ID with payer & owner {}",
+                "Initializing {} with payer & owner {}",
+                variant.display_name(),
                 ctx.payer_pubkey
             ),
         )
         .send_with_payer();
     println!(
-        "initialized Multisig ISM Message ID at program ID {}",
+        "initialized {} at program ID {}",
+        variant.display_name(),
         program_id
     );
 
     program_id
 }
 
-/// Configures the multisig-ism-message-id program
+/// Configures the multisig ISM program
 /// with the validators and thresholds for each of the domains
 /// specified in the multisig config file.
 /// This implementation batches multiple set_validators_and_threshold instructions
 /// into single transactions where possible to reduce the number of transactions.
-fn configure_multisig_ism_message_id(
+fn configure_multisig_ism(
     ctx: &mut Context,
+    variant: MultisigIsmVariant,
     program_id: Pubkey,
     multisig_config_file_path: &Path,
     registry_path: &Path,
@@ -251,6 +512,7 @@ fn configure_multisig_ism_message_id(
 
         let matches = multisig_ism_config_matches_chain(
             ctx,
+            variant,
             program_id,
             chain_metadata.domain_id,
             multisig_ism_config,
@@ -258,22 +520,23 @@ fn configure_multisig_ism_message_id(
 
         if matches {
             println!(
-                "Multisig ISM Message ID already correctly configured for chain {}",
+                "{} already correctly configured for chain {}",
+                variant.display_name(),
                 chain_name
             );
         } else {
             println!(
-                "Multisig ISM Message ID needs configuration update for chain {}",
+                "{} needs configuration update for chain {}",
+                variant.display_name(),
                 chain_name
             );
 
-            let instruction = set_validators_and_threshold_instruction(
+            let instruction = variant.set_validators_and_threshold_instruction(
                 program_id,
                 ctx.payer_pubkey,
                 chain_metadata.domain_id,
-                multisig_ism_config.clone().into(),
-            )
-            .unwrap();
+                multisig_ism_config.clone(),
+            );
 
             ism_update_instructions.push((chain_name, instruction));
         }
@@ -306,12 +569,12 @@ fn configure_multisig_ism_message_id(
 
 fn multisig_ism_config_matches_chain(
     ctx: &mut Context,
+    variant: MultisigIsmVariant,
     program_id: Pubkey,
     remote_domain: u32,
     expected: &MultisigIsmConfig,
 ) -> bool {
-    let (domain_data_key, _domain_data_bump) =
-        Pubkey::find_program_address(domain_data_pda_seeds!(remote_domain), &program_id);
+    let domain_data_key = variant.domain_data_pda_key(&program_id, remote_domain);
 
     let domain_data_account = ctx
         .client
@@ -320,21 +583,12 @@ fn multisig_ism_config_matches_chain(
         .value;
 
     if let Some(domain_data_account) = domain_data_account {
-        let domain_data = DomainDataAccount::fetch(&mut &domain_data_account.data[..])
-            .unwrap()
-            .into_inner();
+        let actual = variant.validators_and_threshold(&domain_data_account.data);
         let expected_validator_set =
             HashSet::<H160>::from_iter(expected.validators.iter().cloned());
-        let actual_validator_set = HashSet::<H160>::from_iter(
-            domain_data
-                .validators_and_threshold
-                .validators
-                .iter()
-                .cloned(),
-        );
+        let actual_validator_set = HashSet::<H160>::from_iter(actual.validators.iter().cloned());
 
-        expected_validator_set == actual_validator_set
-            && expected.threshold == domain_data.validators_and_threshold.threshold
+        expected_validator_set == actual_validator_set && expected.threshold == actual.threshold
     } else {
         false
     }
@@ -342,23 +596,23 @@ fn multisig_ism_config_matches_chain(
 
 pub(crate) fn set_validators_and_threshold(
     ctx: &mut Context,
+    variant: MultisigIsmVariant,
     program_id: Pubkey,
     domain: u32,
-    validators_and_threshold: ValidatorsAndThreshold,
+    config: MultisigIsmConfig,
 ) {
     let description = format!(
         "Set for remote domain {} validators and threshold: {:?}",
-        domain, validators_and_threshold
+        domain, config
     );
     ctx.new_txn()
         .add_with_description(
-            set_validators_and_threshold_instruction(
+            variant.set_validators_and_threshold_instruction(
                 program_id,
                 ctx.payer_pubkey,
                 domain,
-                validators_and_threshold,
-            )
-            .unwrap(),
+                config,
+            ),
             description,
         )
         .send_with_payer();
@@ -0,0 +1,165 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+use spl_discriminator::ArrayDiscriminator as Discriminator;
+
+/// Instructions that a Hyperlane post-dispatch hook is expected to process.
+/// The first 8 bytes of the encoded instruction is a discriminator that
+/// allows programs to implement the required interface.
+#[derive(Eq, PartialEq, Debug)]
+pub enum PostDispatchHookInstruction {
+    /// Called by the Mailbox after a message has been dispatched.
+    /// The first account is always the Mailbox's Outbox PDA. It is a signer,
+    /// proving that the message was dispatched by the Mailbox, only if the hook
+    /// is the Mailbox's required or default hook. A custom hook chosen by the
+    /// message sender receives it as a non-signer.
+    PostDispatch(PostDispatchInstruction),
+    /// Gets the account metas required for the `PostDispatch` instruction,
+    /// excluding the Outbox PDA that is prepended by the Mailbox.
+    /// Intended to be simulated by an off-chain client.
+    /// The only account passed into this instruction is expected to be
+    /// the read-only PDA relating to the program ID and the seeds
+    /// `POST_DISPATCH_ACCOUNT_METAS_PDA_SEEDS`
+    PostDispatchAccountMetas(PostDispatchInstruction),
+    /// Quotes the payment in lamports that the `PostDispatch` instruction
+    /// will charge the payer, returned as little endian encoded return data.
+    /// Intended to be simulated by an off-chain client.
+    QuoteDispatch(PostDispatchInstruction),
+}
+
+#[derive(Eq, PartialEq, BorshSerialize, BorshDeserialize, Debug)]
+pub struct PostDispatchInstruction {
+    /// The metadata provided by the message sender.
+    pub metadata: Vec<u8>,
+    /// The encoded dispatched message.
+    pub message: Vec<u8>,
+}
+
+impl PostDispatchInstruction {
+    pub fn new(metadata: Vec<u8>, message: Vec<u8>) -> Self {
+        Self { metadata, message }
+    }
+}
+
+/// First 8 bytes of `hash::hashv(&[b"hyperlane-post-dispatch-hook:post-dispatch"])`
+const POST_DISPATCH_DISCRIMINATOR: [u8; Discriminator::LENGTH] =
+    [121, 67, 135, 153, 114, 129, 2, 213];
+const POST_DISPATCH_DISCRIMINATOR_SLICE: &[u8] = &POST_DISPATCH_DISCRIMINATOR;
+
+/// First 8 bytes of `hash::hashv(&[b"hyperlane-post-dispatch-hook:post-dispatch-account-metas"])`
+const POST_DISPATCH_ACCOUNT_METAS_DISCRIMINATOR: [u8; Discriminator::LENGTH] =
+    [190, 10, 209, 10, 56, 103, 110, 208];
+const POST_DISPATCH_ACCOUNT_METAS_DISCRIMINATOR_SLICE: &[u8] =
+    &POST_DISPATCH_ACCOUNT_METAS_DISCRIMINATOR;
+
+/// First 8 bytes of `hash::hashv(&[b"hyperlane-post-dispatch-hook:quote-dispatch"])`
+const QUOTE_DISPATCH_DISCRIMINATOR: [u8; Discriminator::LENGTH] =
+    [129, 95, 61, 116, 126, 140, 2, 194];
+const QUOTE_DISPATCH_DISCRIMINATOR_SLICE: &[u8] = &QUOTE_DISPATCH_DISCRIMINATOR;
+
+/// Seeds for the PDA that's expected to be passed into the `PostDispatchAccountMetas`
+/// instruction.
+pub const POST_DISPATCH_ACCOUNT_METAS_PDA_SEEDS: &[&[u8]] = &[
+    b"hyperlane_hook",
+    b"-",
+    b"post_dispatch",
+    b"-",
+    b"account_metas",
+];
+
+impl PostDispatchHookInstruction {
+    pub fn encode(&self) -> Result<Vec<u8>, ProgramError> {
+        let (discriminator, instruction) = match self {
+            PostDispatchHookInstruction::PostDispatch(instruction) => {
+                (POST_DISPATCH_DISCRIMINATOR_SLICE, instruction)
+            }
+            PostDispatchHookInstruction::PostDispatchAccountMetas(instruction) => {
+                (POST_DISPATCH_ACCOUNT_METAS_DISCRIMINATOR_SLICE, instruction)
+            }
+            PostDispatchHookInstruction::QuoteDispatch(instruction) => {
+                (QUOTE_DISPATCH_DISCRIMINATOR_SLICE, instruction)
+            }
+        };
+
+        let mut buf = vec![];
+        buf.extend_from_slice(discriminator);
+        buf.extend_from_slice(
+            &borsh::to_vec(instruction).map_err(|_| ProgramError::BorshIoError)?[..],
+        );
+        Ok(buf)
+    }
+
+    pub fn decode(buf: &[u8]) -> Result<Self, ProgramError> {
+        if buf.len() < Discriminator::LENGTH {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let (discriminator, rest) = buf.split_at(Discriminator::LENGTH);
+        let variant: fn(PostDispatchInstruction) -> Self = match discriminator {
+            POST_DISPATCH_DISCRIMINATOR_SLICE => Self::PostDispatch,
+            POST_DISPATCH_ACCOUNT_METAS_DISCRIMINATOR_SLICE => Self::PostDispatchAccountMetas,
+            QUOTE_DISPATCH_DISCRIMINATOR_SLICE => Self::QuoteDispatch,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+        let instruction = PostDispatchInstruction::try_from_slice(rest)
+            .map_err(|_| ProgramError::BorshIoError)?;
+        Ok(variant(instruction))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use solana_program::hash::hashv;
+
+    #[test]
+    fn test_discriminator_slices() {
+        assert_eq!(
+            &hashv(&[b"hyperlane-post-dispatch-hook:post-dispatch"]).to_bytes()
+                [..Discriminator::LENGTH],
+            POST_DISPATCH_DISCRIMINATOR_SLICE,
+        );
+
+        assert_eq!(
+            &hashv(&[b"hyperlane-post-dispatch-hook:post-dispatch-account-metas"]).to_bytes()
+                [..Discriminator::LENGTH],
+            POST_DISPATCH_ACCOUNT_METAS_DISCRIMINATOR_SLICE,
+        );
+
+        assert_eq!(
+            &hashv(&[b"hyperlane-post-dispatch-hook:quote-dispatch"]).to_bytes()
+                [..Discriminator::LENGTH],
+            QUOTE_DISPATCH_DISCRIMINATOR_SLICE,
+        );
+    }
+
+    #[test]
+    fn test_encode_decode_instructions() {
+        let instructions = [
+            PostDispatchHookInstruction::PostDispatch(PostDispatchInstruction::new(
+                vec![1, 2, 3],
+                vec![4, 5, 6, 7],
+            )),
+            PostDispatchHookInstruction::PostDispatchAccountMetas(PostDispatchInstruction::new(
+                vec![],
+                vec![4, 5, 6, 7],
+            )),
+            PostDispatchHookInstruction::QuoteDispatch(PostDispatchInstruction::new(
+                vec![1],
+                vec![],
+            )),
+        ];
+
+        for instruction in instructions {
+            let encoded = instruction.encode().unwrap();
+            let decoded = PostDispatchHookInstruction::decode(&encoded).unwrap();
+            assert_eq!(instruction, decoded);
+        }
+    }
+
+    #[test]
+    fn test_decode_invalid_discriminator() {
+        assert_eq!(
+            PostDispatchHookInstruction::decode(&[0u8; Discriminator::LENGTH]),
+            Err(ProgramError::InvalidInstructionData),
+        );
+    }
+}
@@ -79,6 +79,7 @@ pub trait HyperlaneGasRouterDispatch: HyperlaneGasRouter + HyperlaneRouterDispat
         dispatch_account_infos: &[AccountInfo],
         payment_account_metas: Vec<AccountMeta>,
         payment_account_infos: &[AccountInfo],
+        hook_account_counts: Vec<u8>,
     ) -> Result<H256, ProgramError> {
         HyperlaneRouterDispatch::dispatch_with_gas(
             self,
@@ -92,6 +93,7 @@ pub trait HyperlaneGasRouterDispatch: HyperlaneGasRouter + HyperlaneRouterDispat
             dispatch_account_infos,
             payment_account_metas,
             payment_account_infos,
+            hook_account_counts,
         )
     }
 }
@@ -91,6 +91,10 @@ impl<T> HyperlaneRouterAccessControl for T where T: HyperlaneRouter + AccessCont
 /// to remote routers.
 pub trait HyperlaneRouterDispatch: HyperlaneRouter + HyperlaneConnectionClient {
     /// Dispatches a message to the remote router for the provided destination domain.
+    /// The accounts of the Mailbox's hooks are expected at the end of `account_metas`,
+    /// with `hook_account_counts` being the number of accounts following each hook's
+    /// program account, see `OutboxDispatchWithHook`.
+    #[allow(clippy::too_many_arguments)]
     fn dispatch(
         &self,
         program_id: &Pubkey,
@@ -99,18 +103,22 @@ pub trait HyperlaneRouterDispatch: HyperlaneRouter + HyperlaneConnectionClient {
         message_body: Vec<u8>,
         account_metas: Vec<AccountMeta>,
         account_infos: &[AccountInfo],
+        hook_account_counts: Vec<u8>,
     ) -> Result<H256, ProgramError> {
         // The recipient is the remote router, which must be enrolled.
         let recipient = *self
             .router(destination_domain)
             .ok_or(ProgramError::InvalidArgument)?;
 
-        let dispatch_instruction = MailboxInstruction::OutboxDispatch(MailboxOutboxDispatch {
-            sender: *program_id,
-            destination_domain,
-            recipient,
-            message_body,
-        });
+        let dispatch_instruction = MailboxInstruction::outbox_dispatch(
+            MailboxOutboxDispatch {
+                sender: *program_id,
+                destination_domain,
+                recipient,
+                message_body,
+            },
+            hook_account_counts,
+        );
         let mailbox = self.mailbox();
         let mailbox_ixn = Instruction {
             program_id: *mailbox,
@@ -123,8 +131,8 @@ pub trait HyperlaneRouterDispatch: HyperlaneRouter + HyperlaneConnectionClient {
         // Parse the message ID from the return data from the prior dispatch.
         let (returning_program_id, returned_data) =
             get_return_data().ok_or(ProgramError::InvalidArgument)?;
-        // The mailbox itself doesn't make any CPIs, but as a sanity check we confirm
-        // that the return data is from the mailbox.
+        // The mailbox sets the return data after invoking any hooks, but as a sanity
+        // check we confirm that the return data is from the mailbox.
         if returning_program_id != *mailbox {
             return Err(ProgramError::InvalidArgument);
         }
@@ -149,6 +157,7 @@ pub trait HyperlaneRouterDispatch: HyperlaneRouter + HyperlaneConnectionClient {
         dispatch_account_infos: &[AccountInfo],
         payment_account_metas: Vec<AccountMeta>,
         payment_account_infos: &[AccountInfo],
+        hook_account_counts: Vec<u8>,
     ) -> Result<H256, ProgramError> {
         let message_id = self.dispatch(
             program_id,
@@ -157,6 +166,7 @@ pub trait HyperlaneRouterDispatch: HyperlaneRouter + HyperlaneConnectionClient {
             message_body,
             dispatch_account_metas,
             dispatch_account_infos,
+            hook_account_counts,
         )?;
 
         // Call the IGP to pay for gas.
@@ -12,16 +12,20 @@ use hyperlane_sealevel_connection_client::{
 };
 use hyperlane_sealevel_igp::accounts::InterchainGasPaymasterType;
 use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use solana_system_interface::program as system_program;
 use std::{cmp::Ordering, collections::HashMap, fmt::Debug};
 
-use crate::hyperlane_token_pda_seeds;
+use crate::{
+    error::Error, hyperlane_token_pda_seeds, hyperlane_token_transfer_controls_pda_seeds,
+    instruction::RateLimitConfig,
+};
 
 /// HyperlaneToken account data.
 pub type HyperlaneTokenAccount<T> = AccountData<HyperlaneToken<T>>;
 
 /// A PDA account containing the data for a Hyperlane token
 /// and any plugin-specific data.
-#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq, Default)]
+#[derive(Debug, PartialEq, Default)]
 pub struct HyperlaneToken<T> {
     /// The bump seed for this PDA.
     pub bump: u8,
@@ -47,6 +51,76 @@ pub struct HyperlaneToken<T> {
     pub remote_routers: HashMap<u32, H256>,
     /// Plugin-specific data.
     pub plugin_data: T,
+    /// The bump seed of the transfer controls PDA, set once the owner first
+    /// configures a transfer control. Until then, transfers don't take the
+    /// transfer controls PDA account.
+    pub transfer_controls_bump: Option<u8>,
+}
+
+/// The transfer controls bump is only serialized once it's set, so that tokens
+/// created before transfer controls existed keep their size and layout.
+impl<T> BorshSerialize for HyperlaneToken<T>
+where
+    T: BorshSerialize,
+{
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.bump.serialize(writer)?;
+        self.mailbox.serialize(writer)?;
+        self.mailbox_process_authority.serialize(writer)?;
+        self.dispatch_authority_bump.serialize(writer)?;
+        self.decimals.serialize(writer)?;
+        self.remote_decimals.serialize(writer)?;
+        self.owner.serialize(writer)?;
+        self.interchain_security_module.serialize(writer)?;
+        self.interchain_gas_paymaster.serialize(writer)?;
+        self.destination_gas.serialize(writer)?;
+        self.remote_routers.serialize(writer)?;
+        self.plugin_data.serialize(writer)?;
+        if self.transfer_controls_bump.is_some() {
+            self.transfer_controls_bump.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Treats a missing transfer controls bump as unset, see the `BorshSerialize` implementation.
+impl<T> BorshDeserialize for HyperlaneToken<T>
+where
+    T: BorshDeserialize,
+{
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        Ok(Self {
+            bump: u8::deserialize_reader(reader)?,
+            mailbox: Pubkey::deserialize_reader(reader)?,
+            mailbox_process_authority: Pubkey::deserialize_reader(reader)?,
+            dispatch_authority_bump: u8::deserialize_reader(reader)?,
+            decimals: u8::deserialize_reader(reader)?,
+            remote_decimals: u8::deserialize_reader(reader)?,
+            owner: Option::<Pubkey>::deserialize_reader(reader)?,
+            interchain_security_module: Option::<Pubkey>::deserialize_reader(reader)?,
+            interchain_gas_paymaster:
+                Option::<(Pubkey, InterchainGasPaymasterType)>::deserialize_reader(reader)?,
+            destination_gas: HashMap::<u32, u64>::deserialize_reader(reader)?,
+            remote_routers: HashMap::<u32, H256>::deserialize_reader(reader)?,
+            plugin_data: T::deserialize_reader(reader)?,
+            transfer_controls_bump: deserialize_optional_bump(reader)?,
+        })
+    }
+}
+
+fn deserialize_optional_bump<R: std::io::Read>(reader: &mut R) -> std::io::Result<Option<u8>> {
+    let mut variant = [0u8; 1];
+    if reader.read(&mut variant)? == 0 {
+        return Ok(None);
+    }
+    match variant[0] {
+        0 => Ok(None),
+        1 => Ok(Some(u8::deserialize_reader(reader)?)),
+        _ => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "invalid transfer controls bump variant",
+        )),
+    }
 }
 
 impl<T> HyperlaneToken<T>
@@ -124,7 +198,9 @@ where
         // remote_routers keys & values
         (self.remote_routers.len() * (std::mem::size_of::<u32>() + 32)) +
         // plugin_data
-        self.plugin_data.size()
+        self.plugin_data.size() +
+        // transfer_controls_bump, only serialized once set
+        self.transfer_controls_bump.map_or(0, |_| 1 + std::mem::size_of::<u8>())
     }
 }
 
@@ -198,6 +274,229 @@ impl<T> HyperlaneGasRouter for HyperlaneToken<T> {
     }
 }
 
+/// TransferControls account data.
+pub type TransferControlsAccount = AccountData<TransferControls>;
+
+/// A PDA account containing the owner-managed controls that gate
+/// transfers into and out of a Hyperlane token program.
+/// Until the owner first configures a control, this account does not exist
+/// and transfers are not gated.
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq, Default)]
+pub struct TransferControls {
+    /// The bump seed for this PDA.
+    pub bump: u8,
+    /// Whether transfers in both directions are paused.
+    pub paused: bool,
+    /// Rate limits keyed by remote domain.
+    pub rate_limits: HashMap<u32, RateLimit>,
+}
+
+impl TransferControls {
+    /// Deserializes the data from the provided `transfer_controls_account_info`.
+    /// Returns Ok(None) if the account has not been created yet.
+    /// Returns an Err if the provided account is not the canonical
+    /// TransferControls PDA for this program.
+    pub fn verify_account_and_fetch_inner(
+        program_id: &Pubkey,
+        transfer_controls_account_info: &AccountInfo<'_>,
+    ) -> Result<Option<Self>, ProgramError> {
+        if transfer_controls_account_info.data_is_empty()
+            && transfer_controls_account_info.owner == &system_program::ID
+        {
+            let (expected_key, _expected_bump) = Pubkey::find_program_address(
+                hyperlane_token_transfer_controls_pda_seeds!(),
+                program_id,
+            );
+            if transfer_controls_account_info.key != &expected_key {
+                return Err(ProgramError::InvalidArgument);
+            }
+            return Ok(None);
+        }
+
+        let transfer_controls =
+            TransferControlsAccount::fetch(&mut &transfer_controls_account_info.data.borrow()[..])?
+                .into_inner();
+        let expected_key = Pubkey::create_program_address(
+            hyperlane_token_transfer_controls_pda_seeds!(transfer_controls.bump),
+            program_id,
+        )?;
+        if transfer_controls_account_info.key != &expected_key {
+            return Err(ProgramError::InvalidArgument);
+        }
+        if transfer_controls_account_info.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        Ok(Some(*transfer_controls))
+    }
+
+    /// Sets or removes the rate limit for a domain.
+    /// An existing limit keeps its currently available amounts, capped at
+    /// the new capacity. A new limit starts out at full capacity.
+    pub fn set_rate_limit(&mut self, config: RateLimitConfig, now: i64) {
+        match config.limit {
+            Some(params) => match self.rate_limits.get_mut(&config.domain) {
+                Some(rate_limit) => {
+                    rate_limit.update(params.capacity, params.refill_per_second, now)
+                }
+                None => {
+                    self.rate_limits.insert(
+                        config.domain,
+                        RateLimit::new(params.capacity, params.refill_per_second, now),
+                    );
+                }
+            },
+            None => {
+                self.rate_limits.remove(&config.domain);
+            }
+        }
+    }
+
+    /// Errors if transfers are paused or if sending `amount` to `destination`
+    /// exceeds its rate limit. Otherwise consumes `amount` from the rate limit.
+    pub fn consume_outbound(
+        &mut self,
+        destination: u32,
+        amount: u64,
+        now: i64,
+    ) -> Result<(), ProgramError> {
+        if self.paused {
+            return Err(Error::TransfersPaused.into());
+        }
+        if let Some(rate_limit) = self.rate_limits.get_mut(&destination) {
+            rate_limit.outbound.consume(
+                rate_limit.capacity,
+                rate_limit.refill_per_second,
+                amount,
+                now,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Errors if transfers are paused or if receiving `amount` from `origin`
+    /// exceeds its rate limit. Otherwise consumes `amount` from the rate limit.
+    pub fn consume_inbound(
+        &mut self,
+        origin: u32,
+        amount: u64,
+        now: i64,
+    ) -> Result<(), ProgramError> {
+        if self.paused {
+            return Err(Error::TransfersPaused.into());
+        }
+        if let Some(rate_limit) = self.rate_limits.get_mut(&origin) {
+            rate_limit.inbound.consume(
+                rate_limit.capacity,
+                rate_limit.refill_per_second,
+                amount,
+                now,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl SizedData for TransferControls {
+    fn size(&self) -> usize {
+        // bump
+        std::mem::size_of::<u8>() +
+        // paused
+        std::mem::size_of::<bool>() +
+        // rate_limits length
+        std::mem::size_of::<u32>() +
+        // rate_limits keys & values
+        (self.rate_limits.len() * (std::mem::size_of::<u32>() + RateLimit::SIZE))
+    }
+}
+
+/// A rolling-window rate limit for transfers to and from a single domain.
+/// Each direction has its own bucket that holds up to `capacity` tokens
+/// and refills at `refill_per_second`. Amounts are in local decimals.
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq, Default, Clone)]
+pub struct RateLimit {
+    /// The maximum amount that can be transferred in a single burst.
+    pub capacity: u64,
+    /// The amount that becomes available again each second.
+    pub refill_per_second: u64,
+    /// The bucket for transfers to the domain.
+    pub outbound: RateLimitBucket,
+    /// The bucket for transfers from the domain.
+    pub inbound: RateLimitBucket,
+}
+
+impl RateLimit {
+    /// The serialized size of a RateLimit.
+    pub const SIZE: usize = std::mem::size_of::<u64>() * 2 + RateLimitBucket::SIZE * 2;
+
+    fn new(capacity: u64, refill_per_second: u64, now: i64) -> Self {
+        Self {
+            capacity,
+            refill_per_second,
+            outbound: RateLimitBucket::full(capacity, now),
+            inbound: RateLimitBucket::full(capacity, now),
+        }
+    }
+
+    fn update(&mut self, capacity: u64, refill_per_second: u64, now: i64) {
+        for bucket in [&mut self.outbound, &mut self.inbound] {
+            bucket.refill(self.capacity, self.refill_per_second, now);
+            bucket.available = bucket.available.min(capacity);
+        }
+        self.capacity = capacity;
+        self.refill_per_second = refill_per_second;
+    }
+}
+
+/// The state of one direction of a RateLimit.
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq, Default, Clone)]
+pub struct RateLimitBucket {
+    /// The amount available as of `last_updated`.
+    pub available: u64,
+    /// The unix timestamp at which `available` was last updated.
+    pub last_updated: i64,
+}
+
+impl RateLimitBucket {
+    /// The serialized size of a RateLimitBucket.
+    pub const SIZE: usize = std::mem::size_of::<u64>() + std::mem::size_of::<i64>();
+
+    fn full(capacity: u64, now: i64) -> Self {
+        Self {
+            available: capacity,
+            last_updated: now,
+        }
+    }
+
+    /// Gets the amount available at `now`.
+    pub fn available_at(&self, capacity: u64, refill_per_second: u64, now: i64) -> u64 {
+        let elapsed = u64::try_from(now.saturating_sub(self.last_updated)).unwrap_or_default();
+        self.available
+            .saturating_add(elapsed.saturating_mul(refill_per_second))
+            .min(capacity)
+    }
+
+    fn refill(&mut self, capacity: u64, refill_per_second: u64, now: i64) {
+        self.available = self.available_at(capacity, refill_per_second, now);
+        self.last_updated = now;
+    }
+
+    fn consume(
+        &mut self,
+        capacity: u64,
+        refill_per_second: u64,
+        amount: u64,
+        now: i64,
+    ) -> Result<(), ProgramError> {
+        self.refill(capacity, refill_per_second, now);
+        self.available = self
+            .available
+            .checked_sub(amount)
+            .ok_or(Error::RateLimitExceeded)?;
+        Ok(())
+    }
+}
+
 /// Converts an amount from one decimal representation to another.
 pub fn convert_decimals(amount: U256, from_decimals: u8, to_decimals: u8) -> Option<U256> {
     match from_decimals.cmp(&to_decimals) {
@@ -216,6 +515,7 @@ pub fn convert_decimals(amount: U256, from_decimals: u8, to_decimals: u8) -> Opt
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::instruction::RateLimitParams;
 
     #[test]
     fn test_convert_decimals() {
@@ -343,9 +643,159 @@ mod test {
             destination_gas: HashMap::from([(1000, 200000), (200, 400000)]),
             remote_routers: HashMap::from([(1000, H256::random()), (200, H256::random())]),
             plugin_data: Foo { bar: 69 },
+            transfer_controls_bump: None,
         };
         let serialized = borsh::to_vec(&hyperlane_token_foo).unwrap();
 
         assert_eq!(serialized.len(), hyperlane_token_foo.size());
+
+        let hyperlane_token_foo = HyperlaneToken::<Foo> {
+            transfer_controls_bump: Some(5),
+            ..hyperlane_token_foo
+        };
+        let serialized = borsh::to_vec(&hyperlane_token_foo).unwrap();
+
+        assert_eq!(serialized.len(), hyperlane_token_foo.size());
+    }
+
+    #[test]
+    fn test_hyperlane_token_transfer_controls_bump_serialization() {
+        let token = HyperlaneToken::<()> {
+            bump: 1,
+            ..HyperlaneToken::<()>::default()
+        };
+
+        // An unset bump isn't serialized, and trailing zeroes left by a
+        // realloc are read as an unset bump.
+        let mut serialized = borsh::to_vec(&token).unwrap();
+        serialized.extend_from_slice(&[0u8; 16]);
+        assert_eq!(
+            HyperlaneToken::<()>::deserialize(&mut &serialized[..]).unwrap(),
+            token
+        );
+
+        let token = HyperlaneToken::<()> {
+            transfer_controls_bump: Some(254),
+            ..token
+        };
+        let serialized = borsh::to_vec(&token).unwrap();
+        assert_eq!(
+            HyperlaneToken::<()>::deserialize(&mut &serialized[..]).unwrap(),
+            token
+        );
+    }
+
+    #[test]
+    fn test_transfer_controls_size() {
+        let mut transfer_controls = TransferControls {
+            bump: 1,
+            paused: true,
+            rate_limits: HashMap::new(),
+        };
+        for domain in [1000, 200] {
+            transfer_controls.set_rate_limit(
+                RateLimitConfig {
+                    domain,
+                    limit: Some(RateLimitParams {
+                        capacity: 100,
+                        refill_per_second: 1,
+                    }),
+                },
+                69,
+            );
+        }
+        let serialized = borsh::to_vec(&transfer_controls).unwrap();
+
+        assert_eq!(serialized.len(), transfer_controls.size());
+    }
+
+    #[test]
+    fn test_transfer_controls_rate_limits() {
+        let mut transfer_controls = TransferControls::default();
+        transfer_controls.set_rate_limit(
+            RateLimitConfig {
+                domain: 1000,
+                limit: Some(RateLimitParams {
+                    capacity: 100,
+                    refill_per_second: 10,
+                }),
+            },
+            0,
+        );
+
+        // Domains without a rate limit aren't limited.
+        assert_eq!(transfer_controls.consume_outbound(200, u64::MAX, 0), Ok(()));
+
+        // A new rate limit starts out at full capacity.
+        assert_eq!(transfer_controls.consume_outbound(1000, 100, 0), Ok(()));
+        assert_eq!(
+            transfer_controls.consume_outbound(1000, 1, 0),
+            Err(Error::RateLimitExceeded.into())
+        );
+
+        // The inbound direction is tracked separately.
+        assert_eq!(transfer_controls.consume_inbound(1000, 60, 0), Ok(()));
+
+        // Refills over time, up to the capacity.
+        assert_eq!(transfer_controls.consume_outbound(1000, 30, 3), Ok(()));
+        assert_eq!(
+            transfer_controls.consume_outbound(1000, 1, 3),
+            Err(Error::RateLimitExceeded.into())
+        );
+        assert_eq!(
+            transfer_controls.consume_inbound(1000, 101, 1000),
+            Err(Error::RateLimitExceeded.into())
+        );
+        assert_eq!(transfer_controls.consume_inbound(1000, 100, 1000), Ok(()));
+
+        // Lowering the capacity caps the available amount.
+        transfer_controls.set_rate_limit(
+            RateLimitConfig {
+                domain: 1000,
+                limit: Some(RateLimitParams {
+                    capacity: 50,
+                    refill_per_second: 10,
+                }),
+            },
+            2000,
+        );
+        assert_eq!(
+            transfer_controls.consume_outbound(1000, 51, 2000),
+            Err(Error::RateLimitExceeded.into())
+        );
+        assert_eq!(transfer_controls.consume_outbound(1000, 50, 2000), Ok(()));
+
+        // Removing the rate limit lifts it.
+        transfer_controls.set_rate_limit(
+            RateLimitConfig {
+                domain: 1000,
+                limit: None,
+            },
+            2000,
+        );
+        assert_eq!(
+            transfer_controls.consume_outbound(1000, u64::MAX, 2000),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_transfer_controls_paused() {
+        let mut transfer_controls = TransferControls {
+            paused: true,
+            ..TransferControls::default()
+        };
+
+        assert_eq!(
+            transfer_controls.consume_outbound(1000, 1, 0),
+            Err(Error::TransfersPaused.into())
+        );
+        assert_eq!(
+            transfer_controls.consume_inbound(1000, 1, 0),
+            Err(Error::TransfersPaused.into())
+        );
+
+        transfer_controls.paused = false;
+        assert_eq!(transfer_controls.consume_outbound(1000, 1, 0), Ok(()));
     }
 }
@@ -17,6 +17,14 @@ pub enum Error {
     /// A message decoding error occurred.
     #[error("Message decoding error")]
     MessageDecodeError = 3,
+
+    /// Transfers are paused by the owner.
+    #[error("Transfers are paused")]
+    TransfersPaused = 4,
+
+    /// The transfer exceeds the rate limit available for the domain.
+    #[error("Rate limit exceeded")]
+    RateLimitExceeded = 5,
 }
 
 impl From<Error> for ProgramError {
@@ -16,7 +16,7 @@ use solana_system_interface::program as system_program;
 
 use hyperlane_sealevel_mailbox::mailbox_message_dispatch_authority_pda_seeds;
 
-use crate::hyperlane_token_pda_seeds;
+use crate::{hyperlane_token_pda_seeds, hyperlane_token_transfer_controls_pda_seeds};
 
 /// Instructions shared by all Hyperlane Sealevel Token programs.
 #[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
@@ -37,6 +37,12 @@ pub enum Instruction {
     SetInterchainGasPaymaster(Option<(Pubkey, InterchainGasPaymasterType)>),
     /// Transfer ownership of the program. Only owner.
     TransferOwnership(Option<Pubkey>),
+    /// Pause or unpause transfers in both directions. Only owner.
+    SetPaused(bool),
+    /// Set or remove the rate limits of remote domains. Only owner.
+    SetRateLimits(Vec<RateLimitConfig>),
+    /// Transfer tokens to a remote recipient, passing accounts to the Mailbox's hooks.
+    TransferRemoteWithHooks(TransferRemoteWithHooks),
 }
 
 impl DiscriminatorData for Instruction {
@@ -69,6 +75,35 @@ pub struct TransferRemote {
     pub amount_or_id: U256,
 }
 
+/// Instruction data for transferring tokens to a remote recipient when the
+/// Mailbox's hooks require accounts.
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub struct TransferRemoteWithHooks {
+    /// The transfer.
+    pub transfer: TransferRemote,
+    /// The number of accounts following each hook's program account, in the
+    /// order the Mailbox invokes the hooks.
+    pub hook_account_counts: Vec<u8>,
+}
+
+/// Instruction data for setting the rate limit of a remote domain.
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq, Clone)]
+pub struct RateLimitConfig {
+    /// The remote domain.
+    pub domain: u32,
+    /// The rate limit. If None, the domain's rate limit is removed.
+    pub limit: Option<RateLimitParams>,
+}
+
+/// The parameters of a rate limit. Amounts are in local decimals.
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq, Clone)]
+pub struct RateLimitParams {
+    /// The maximum amount that can be transferred in a single burst.
+    pub capacity: u64,
+    /// The amount that becomes available again each second.
+    pub refill_per_second: u64,
+}
+
 /// Gets an instruction to initialize the program. This provides only the
 /// account metas required by the library, and consuming programs are expected
 /// to add the accounts for their own use.
@@ -259,3 +294,56 @@ pub fn set_igp_instruction(
 
     Ok(instruction)
 }
+
+/// Gets an instruction to pause or unpause transfers.
+pub fn set_paused_instruction(
+    program_id: Pubkey,
+    owner_payer: Pubkey,
+    paused: bool,
+) -> Result<SolanaInstruction, ProgramError> {
+    transfer_controls_instruction(program_id, owner_payer, Instruction::SetPaused(paused))
+}
+
+/// Gets an instruction to set the rate limits of remote domains.
+pub fn set_rate_limits_instruction(
+    program_id: Pubkey,
+    owner_payer: Pubkey,
+    configs: Vec<RateLimitConfig>,
+) -> Result<SolanaInstruction, ProgramError> {
+    transfer_controls_instruction(program_id, owner_payer, Instruction::SetRateLimits(configs))
+}
+
+fn transfer_controls_instruction(
+    program_id: Pubkey,
+    owner_payer: Pubkey,
+    ixn: Instruction,
+) -> Result<SolanaInstruction, ProgramError> {
+    let (token_key, _token_bump) =
+        Pubkey::try_find_program_address(hyperlane_token_pda_seeds!(), &program_id)
+            .ok_or(ProgramError::InvalidSeeds)?;
+    let (transfer_controls_key, _transfer_controls_bump) = Pubkey::try_find_program_address(
+        hyperlane_token_transfer_controls_pda_seeds!(),
+        &program_id,
+    )
+    .ok_or(ProgramError::InvalidSeeds)?;
+
+    // Accounts:
+    // 0. `[executable]` The system program.
+    // 1. `[writeable]` The token PDA account.
+    // 2. `[writeable]` The transfer controls PDA account.
+    // 3. `[signer]` The owner.
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(token_key, false),
+        AccountMeta::new(transfer_controls_key, false),
+        AccountMeta::new(owner_payer, true),
+    ];
+
+    let instruction = SolanaInstruction {
+        program_id,
+        data: ixn.encode()?,
+        accounts,
+    };
+
+    Ok(instruction)
+}
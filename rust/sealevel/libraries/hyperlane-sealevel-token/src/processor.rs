@@ -21,6 +21,7 @@ use hyperlane_warp_route::TokenMessage;
 use serializable_account_meta::{SerializableAccountMeta, SimulationReturnData};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
     entrypoint::ProgramResult,
     instruction::AccountMeta,
     msg,
@@ -34,9 +35,9 @@ use solana_system_interface::program as system_program;
 use std::collections::HashMap;
 
 use crate::{
-    accounts::{HyperlaneToken, HyperlaneTokenAccount},
+    accounts::{HyperlaneToken, HyperlaneTokenAccount, TransferControls, TransferControlsAccount},
     error::Error,
-    instruction::{Init, TransferRemote},
+    instruction::{Init, RateLimitConfig, TransferRemote},
 };
 
 /// Seeds relating to the PDA account with information about this warp route.
@@ -67,6 +68,24 @@ macro_rules! hyperlane_token_pda_seeds {
     }};
 }
 
+/// Seeds relating to the PDA account with the owner-managed controls
+/// (pausing and rate limits) that gate transfers.
+#[macro_export]
+macro_rules! hyperlane_token_transfer_controls_pda_seeds {
+    () => {{
+        &[b"hyperlane_token", b"-", b"transfer_controls"]
+    }};
+
+    ($bump_seed:expr) => {{
+        &[
+            b"hyperlane_token",
+            b"-",
+            b"transfer_controls",
+            &[$bump_seed],
+        ]
+    }};
+}
+
 /// A plugin that handles token transfers for a Hyperlane Sealevel Token program.
 pub trait HyperlaneSealevelTokenPlugin
 where
@@ -221,6 +240,7 @@ where
             remote_decimals: init.remote_decimals,
             remote_routers: HashMap::new(),
             plugin_data,
+            transfer_controls_bump: None,
         };
         let token_account_data = HyperlaneTokenAccount::<T>::from(token);
 
@@ -273,10 +293,15 @@ where
     /// - 13: `[writeable]` The IGP account.
     ///   ---- End if ----
     /// - 14..N: `[??..??]` Plugin-specific accounts.
+    /// - N+1: `[writeable]` OPTIONAL - The transfer controls PDA account, if the
+    ///   owner has configured a transfer control.
+    /// - N+2..M: `[??..??]` The Mailbox's hooks in the order they are invoked, each
+    ///   followed by the number of accounts given by `hook_account_counts`.
     pub fn transfer_remote(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
         xfer: TransferRemote,
+        hook_account_counts: Vec<u8>,
     ) -> ProgramResult {
         let accounts_iter = &mut accounts.iter();
 
@@ -429,11 +454,12 @@ where
             local_amount,
         )?;
 
-        if accounts_iter.next().is_some() {
-            return Err(ProgramError::from(Error::ExtraneousAccount));
-        }
+        // Account N+1: Transfer controls PDA, if the token has transfer controls.
+        Self::consume_transfer_controls(program_id, &token, accounts_iter, |controls, now| {
+            controls.consume_outbound(xfer.destination_domain, local_amount, now)
+        })?;
 
-        let dispatch_account_metas = vec![
+        let mut dispatch_account_metas = vec![
             AccountMeta::new(*mailbox_outbox_account.key, false),
             AccountMeta::new_readonly(*dispatch_authority_account.key, true),
             AccountMeta::new_readonly(system_program::ID, false),
@@ -442,7 +468,7 @@ where
             AccountMeta::new_readonly(*unique_message_account.key, true),
             AccountMeta::new(*dispatched_message_pda.key, false),
         ];
-        let dispatch_account_infos = &[
+        let mut dispatch_account_infos = vec![
             mailbox_outbox_account.clone(),
             dispatch_authority_account.clone(),
             system_program_account.clone(),
@@ -452,6 +478,17 @@ where
             dispatched_message_pda.clone(),
         ];
 
+        // Accounts N+2..M: The Mailbox's hooks and their accounts, which the
+        // Mailbox verifies against `hook_account_counts`.
+        for hook_account in accounts_iter {
+            dispatch_account_metas.push(AccountMeta {
+                pubkey: *hook_account.key,
+                is_signer: hook_account.is_signer,
+                is_writable: hook_account.is_writable,
+            });
+            dispatch_account_infos.push(hook_account.clone());
+        }
+
         // The token message body, which specifies the remote_amount.
         let token_transfer_message =
             TokenMessage::new(xfer.recipient, remote_amount, vec![]).to_vec();
@@ -465,9 +502,10 @@ where
                 xfer.destination_domain,
                 token_transfer_message,
                 dispatch_account_metas,
-                dispatch_account_infos,
+                &dispatch_account_infos,
                 igp_payment_account_metas,
                 &igp_payment_account_infos,
+                hook_account_counts,
             )?;
         } else {
             // Dispatch the message.
@@ -477,7 +515,8 @@ where
                 xfer.destination_domain,
                 token_transfer_message,
                 dispatch_account_metas,
-                dispatch_account_infos,
+                &dispatch_account_infos,
+                hook_account_counts,
             )?;
         }
 
@@ -497,6 +536,8 @@ where
     /// - 2: `[]` hyperlane_token storage
     /// - 3: `[depends on plugin]` recipient wallet address
     /// - 4..N: `[??..??]` Plugin-specific accounts.
+    /// - N+1: `[writeable]` OPTIONAL - The transfer controls PDA account, if the
+    ///   owner has configured a transfer control.
     pub fn transfer_from_remote(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
@@ -558,6 +599,11 @@ where
             local_amount,
         )?;
 
+        // Account N+1: Transfer controls PDA, if the token has transfer controls.
+        Self::consume_transfer_controls(program_id, &token, accounts_iter, |controls, now| {
+            controls.consume_inbound(xfer.origin, local_amount, now)
+        })?;
+
         if accounts_iter.next().is_some() {
             return Err(ProgramError::from(Error::ExtraneousAccount));
         }
@@ -607,6 +653,11 @@ where
         ];
         accounts.extend(transfer_out_account_metas);
 
+        accounts.extend(
+            Self::transfer_controls_account_meta(program_id, &token)?
+                .map(SerializableAccountMeta::from),
+        );
+
         // Wrap it in the SimulationReturnData because serialized account_metas
         // may end with zero byte(s), which are incorrectly truncated as
         // simulated transaction return data.
@@ -883,4 +934,186 @@ where
 
         Ok(())
     }
+
+    /// Lets the owner pause or unpause transfers.
+    ///
+    /// Accounts:
+    /// 0. `[executable]` The system program.
+    /// 1. `[writeable]` The token PDA account.
+    /// 2. `[writeable]` The transfer controls PDA account.
+    /// 3. `[signer]` The access control owner.
+    pub fn set_paused(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        paused: bool,
+    ) -> ProgramResult {
+        Self::update_transfer_controls(program_id, accounts, |controls, _now| {
+            controls.paused = paused;
+        })?;
+
+        msg!("Transfers paused: {}", paused);
+
+        Ok(())
+    }
+
+    /// Lets the owner set or remove the rate limits of remote domains.
+    ///
+    /// Accounts:
+    /// 0. `[executable]` The system program.
+    /// 1. `[writeable]` The token PDA account.
+    /// 2. `[writeable]` The transfer controls PDA account.
+    /// 3. `[signer]` The access control owner.
+    pub fn set_rate_limits(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        configs: Vec<RateLimitConfig>,
+    ) -> ProgramResult {
+        Self::update_transfer_controls(program_id, accounts, |controls, now| {
+            for config in configs {
+                controls.set_rate_limit(config, now);
+            }
+        })
+    }
+
+    /// Applies `update` to the transfer controls, creating the transfer
+    /// controls PDA account if it does not exist yet. Once created, the
+    /// token records its bump so that transfers must pass the PDA.
+    fn update_transfer_controls(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        update: impl FnOnce(&mut TransferControls, i64),
+    ) -> ProgramResult {
+        let accounts_iter = &mut accounts.iter();
+
+        // Account 0: System program. Used if the PDA is created or reallocated.
+        let system_program = next_account_info(accounts_iter)?;
+        if system_program.key != &system_program::ID {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Account 1: Token account
+        let token_account = next_account_info(accounts_iter)?;
+        let token = HyperlaneToken::<T>::verify_account_and_fetch_inner(program_id, token_account)?;
+
+        // Account 2: Transfer controls PDA
+        let transfer_controls_account = next_account_info(accounts_iter)?;
+        let existing_controls = TransferControls::verify_account_and_fetch_inner(
+            program_id,
+            transfer_controls_account,
+        )?;
+
+        // Account 3: Owner
+        let owner_account = next_account_info(accounts_iter)?;
+        token.ensure_owner_signer(owner_account)?;
+
+        if accounts_iter.next().is_some() {
+            return Err(ProgramError::from(Error::ExtraneousAccount));
+        }
+
+        let rent = Rent::get()?;
+        let now = Clock::get()?.unix_timestamp;
+
+        match existing_controls {
+            Some(mut controls) => {
+                update(&mut controls, now);
+
+                // Store the updated controls and realloc if necessary.
+                TransferControlsAccount::from(controls).store_with_rent_exempt_realloc(
+                    transfer_controls_account,
+                    &rent,
+                    owner_account,
+                    system_program,
+                )?;
+            }
+            None => {
+                let (_transfer_controls_key, transfer_controls_bump) = Pubkey::find_program_address(
+                    hyperlane_token_transfer_controls_pda_seeds!(),
+                    program_id,
+                );
+
+                // Record the bump in the token, which grows the token account.
+                let mut token = token;
+                token.transfer_controls_bump = Some(transfer_controls_bump);
+                HyperlaneTokenAccount::<T>::from(token).store_with_rent_exempt_realloc(
+                    token_account,
+                    &rent,
+                    owner_account,
+                    system_program,
+                )?;
+
+                let mut controls = TransferControls {
+                    bump: transfer_controls_bump,
+                    ..Default::default()
+                };
+                update(&mut controls, now);
+
+                let controls_account_data = TransferControlsAccount::from(controls);
+                create_pda_account(
+                    owner_account,
+                    &rent,
+                    controls_account_data.size(),
+                    program_id,
+                    system_program,
+                    transfer_controls_account,
+                    hyperlane_token_transfer_controls_pda_seeds!(transfer_controls_bump),
+                )?;
+                controls_account_data.store(transfer_controls_account, false)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// If the token has transfer controls, consumes the transfer controls
+    /// PDA account from `accounts_iter`, applies `consume` to the controls
+    /// and stores the result.
+    /// Transfers are not gated, and take no account, until the owner first
+    /// configures a transfer control.
+    pub fn consume_transfer_controls<'a, 'b>(
+        program_id: &Pubkey,
+        token: &HyperlaneToken<T>,
+        accounts_iter: &mut std::slice::Iter<'a, AccountInfo<'b>>,
+        consume: impl FnOnce(&mut TransferControls, i64) -> Result<(), ProgramError>,
+    ) -> ProgramResult {
+        let Some(transfer_controls_account) =
+            Self::transfer_controls_account_meta(program_id, token)?
+        else {
+            return Ok(());
+        };
+
+        let transfer_controls_account_info = next_account_info(accounts_iter)?;
+        if transfer_controls_account_info.key != &transfer_controls_account.pubkey {
+            return Err(ProgramError::InvalidArgument);
+        }
+        let mut controls = TransferControls::verify_account_and_fetch_inner(
+            program_id,
+            transfer_controls_account_info,
+        )?
+        .ok_or(ProgramError::UninitializedAccount)?;
+
+        consume(&mut controls, Clock::get()?.unix_timestamp)?;
+
+        // Consuming only updates fixed-size fields, so no realloc is needed.
+        TransferControlsAccount::from(controls).store(transfer_controls_account_info, false)?;
+
+        Ok(())
+    }
+
+    /// Gets the account meta of the transfer controls PDA account that
+    /// transfers must pass, if the token has transfer controls.
+    pub fn transfer_controls_account_meta(
+        program_id: &Pubkey,
+        token: &HyperlaneToken<T>,
+    ) -> Result<Option<AccountMeta>, ProgramError> {
+        token
+            .transfer_controls_bump
+            .map(|bump| {
+                let key = Pubkey::create_program_address(
+                    hyperlane_token_transfer_controls_pda_seeds!(bump),
+                    program_id,
+                )?;
+                Ok(AccountMeta::new(key, false))
+            })
+            .transpose()
+    }
 }
@@ -2,7 +2,10 @@
 //! Useful for use in unit & integration tests, which can't import from
 //! each other.
 
-use hyperlane_core::{Checkpoint, CheckpointWithMessageId, HyperlaneMessage, H160, H256};
+use hyperlane_core::{
+    accumulator::{merkle::MerkleTree, TREE_DEPTH},
+    Checkpoint, CheckpointWithMessageId, HyperlaneMessage, H160, H256,
+};
 use std::str::FromStr;
 
 pub struct MultisigIsmTestData {
@@ -12,6 +15,17 @@ pub struct MultisigIsmTestData {
     pub signatures: Vec<Vec<u8>>,
 }
 
+/// Test data where the checkpoint's root is the root of a merkle tree that
+/// includes the message, with a proof of the message's inclusion.
+pub struct MultisigIsmMerkleRootTestData {
+    pub message: HyperlaneMessage,
+    pub message_index: u32,
+    pub merkle_proof: [H256; TREE_DEPTH],
+    pub checkpoint: CheckpointWithMessageId,
+    pub validators: Vec<H160>,
+    pub signatures: Vec<Vec<u8>>,
+}
+
 const ORIGIN_DOMAIN: u32 = 1234u32;
 const DESTINATION_DOMAIN: u32 = 4321u32;
 
@@ -85,3 +99,59 @@ pub fn get_multisig_ism_test_data() -> MultisigIsmTestData {
         signatures: vec![signature_0, signature_1, signature_2],
     }
 }
+
+pub fn get_multisig_ism_merkle_root_test_data() -> MultisigIsmMerkleRootTestData {
+    // Same message, validators, and domains as `get_multisig_ism_test_data`.
+    let MultisigIsmTestData {
+        message,
+        checkpoint: message_id_checkpoint,
+        validators,
+        ..
+    } = get_multisig_ism_test_data();
+
+    // The message is inserted at index 1 of a tree with 3 leaves,
+    // and the checkpoint is signed at the last leaf.
+    let message_index = 1;
+    let signed_message_id =
+        H256::from_str("0x2222222222222222222222222222222222222222222222222222222222222222")
+            .unwrap();
+    let leaves = [
+        H256::from_str("0x1111111111111111111111111111111111111111111111111111111111111111")
+            .unwrap(),
+        message.id(),
+        signed_message_id,
+    ];
+    let tree = MerkleTree::create(&leaves, TREE_DEPTH);
+    let (_leaf, merkle_proof) = tree.generate_proof(message_index as usize, TREE_DEPTH);
+
+    let checkpoint = CheckpointWithMessageId {
+        checkpoint: Checkpoint {
+            merkle_tree_hook_address: message_id_checkpoint.checkpoint.merkle_tree_hook_address,
+            mailbox_domain: ORIGIN_DOMAIN,
+            // The root of `tree`.
+            root: H256::from_str(
+                "0x74c17f1b1867e27794a90646478ad119bfece4be713dda089ad13916b49c7ffc",
+            )
+            .unwrap(),
+            index: 2,
+        },
+        message_id: signed_message_id,
+    };
+
+    // checkpoint.signing_hash() is equal to:
+    // 0x657164ec62849722aec17d559bc7542088d150a0a32571af3f3e9f9a149fc662
+    // Signatures are by the same validators as `get_multisig_ism_test_data`, e.g. for validator 0:
+    // > await (new ethers.Wallet('0x788aa7213bd92ff92017d767fde0d75601425818c8e4b21e87314c2a4dcd6091')).signMessage(ethers.utils.arrayify('0x657164ec62849722aec17d559bc7542088d150a0a32571af3f3e9f9a149fc662'))
+    let signature_0 = hex::decode("bc6d3730f486ed053ecaef0b5db4f79e44a6618e4b8ff28183c81fda7b72df0f6870f7af8b33d3243c2bb5c2c129ebe80e6c43da0e53521f08af72caa04cab0b1b").unwrap();
+    let signature_1 = hex::decode("2248bef73d36ee2869654151ad015df9d84b9a2f49b0832b8360a5f84e8c801e53dd35de20659e4643fcb78374ac65a44e8ac53ca8d0734d1c798774804710331c").unwrap();
+    let signature_2 = hex::decode("35d07c48a43b9356e148b369d1092257e3da9e16974b340c0fac6f5c576cd5ce18b2926b683d79954fab48d7661712509d28ee245d70093d18bf0aecb5de89e31b").unwrap();
+
+    MultisigIsmMerkleRootTestData {
+        message,
+        message_index,
+        merkle_proof: merkle_proof.try_into().unwrap(),
+        checkpoint,
+        validators,
+        signatures: vec![signature_0, signature_1, signature_2],
+    }
+}
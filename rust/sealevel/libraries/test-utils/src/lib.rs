@@ -35,6 +35,7 @@ use serializable_account_meta::{SerializableAccountMeta, SimulationReturnData};
 
 pub mod igp;
 pub use igp::*;
+pub mod transfer_controls;
 
 // ========= Mailbox =========
 
@@ -0,0 +1,420 @@
+//! Transfer controls tests shared by the Hyperlane token programs.
+//! Each program implements `TransferControlsTest` for its own setup and
+//! runs the tests against it.
+
+use std::{collections::HashMap, future::Future};
+
+use solana_program::{
+    instruction::{AccountMeta, InstructionError},
+    pubkey::Pubkey,
+};
+use solana_program_test::*;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair, transaction::TransactionError};
+
+use hyperlane_sealevel_token_lib::{
+    accounts::{TransferControls, TransferControlsAccount},
+    error::Error as HyperlaneTokenError,
+    hyperlane_token_transfer_controls_pda_seeds,
+    instruction::{
+        set_paused_instruction, set_rate_limits_instruction, RateLimitConfig, RateLimitParams,
+    },
+};
+
+use crate::{assert_transaction_error, new_funded_keypair, process_instruction};
+
+const ONE_SOL_IN_LAMPORTS: u64 = 1000000000;
+
+/// A token program that's been set up for the shared transfer controls tests.
+/// The token has a remote router enrolled for `remote_domain`, no IGP, and
+/// a token sender holding 100 whole tokens.
+pub trait TransferControlsTest {
+    fn banks_client(&self) -> BanksClient;
+
+    /// The token's owner, which also pays for transactions.
+    fn owner(&self) -> &Keypair;
+
+    fn program_id(&self) -> Pubkey;
+
+    fn remote_domain(&self) -> u32;
+
+    /// One whole token, in local decimals.
+    fn one_token(&self) -> u64;
+
+    /// Transfers `amount` from the token sender to the remote domain, passing
+    /// `transfer_controls_accounts` where the transfer controls PDA is expected.
+    fn transfer_remote_with_transfer_controls(
+        &mut self,
+        amount: u64,
+        transfer_controls_accounts: Vec<AccountMeta>,
+    ) -> impl Future<Output = Result<(), BanksClientError>>;
+
+    /// Processes an inbound transfer of `amount` from the remote domain to `recipient`.
+    fn transfer_from_remote(
+        &mut self,
+        nonce: u32,
+        recipient: Pubkey,
+        amount: u64,
+    ) -> impl Future<Output = Result<(), BanksClientError>>;
+}
+
+/// Transfers `amount` to the remote domain, passing the transfer controls PDA
+/// only if it exists, as clients do.
+pub async fn transfer_remote<T: TransferControlsTest>(
+    test: &mut T,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let transfer_controls_accounts =
+        transfer_controls_account_metas(&mut test.banks_client(), &test.program_id()).await;
+    test.transfer_remote_with_transfer_controls(amount, transfer_controls_accounts)
+        .await
+}
+
+/// Gets the transfer controls PDA account metas that transfers must pass,
+/// which is empty until the owner first configures a transfer control.
+pub async fn transfer_controls_account_metas(
+    banks_client: &mut BanksClient,
+    program_id: &Pubkey,
+) -> Vec<AccountMeta> {
+    let transfer_controls_key = transfer_controls_pda_key(program_id);
+    banks_client
+        .get_account(transfer_controls_key)
+        .await
+        .unwrap()
+        .map(|_| AccountMeta::new(transfer_controls_key, false))
+        .into_iter()
+        .collect()
+}
+
+pub fn transfer_controls_pda_key(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(hyperlane_token_transfer_controls_pda_seeds!(), program_id).0
+}
+
+pub async fn set_paused(
+    banks_client: &mut BanksClient,
+    program_id: &Pubkey,
+    owner: &Keypair,
+    paused: bool,
+) -> Result<(), BanksClientError> {
+    process_instruction(
+        banks_client,
+        set_paused_instruction(*program_id, owner.pubkey(), paused).unwrap(),
+        owner,
+        &[owner],
+    )
+    .await?;
+    Ok(())
+}
+
+pub async fn set_rate_limit(
+    banks_client: &mut BanksClient,
+    program_id: &Pubkey,
+    owner: &Keypair,
+    domain: u32,
+    limit: Option<RateLimitParams>,
+) -> Result<(), BanksClientError> {
+    process_instruction(
+        banks_client,
+        set_rate_limits_instruction(
+            *program_id,
+            owner.pubkey(),
+            vec![RateLimitConfig { domain, limit }],
+        )
+        .unwrap(),
+        owner,
+        &[owner],
+    )
+    .await?;
+    Ok(())
+}
+
+pub async fn fetch_transfer_controls(
+    banks_client: &mut BanksClient,
+    program_id: &Pubkey,
+) -> TransferControls {
+    let transfer_controls_data = banks_client
+        .get_account(transfer_controls_pda_key(program_id))
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    *TransferControlsAccount::fetch(&mut &transfer_controls_data[..])
+        .unwrap()
+        .into_inner()
+}
+
+fn assert_token_error<T>(result: Result<T, BanksClientError>, error: HyperlaneTokenError) {
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::Custom(error as u32)),
+    );
+}
+
+pub async fn test_set_paused<T: TransferControlsTest>(test: T) {
+    let mut banks_client = test.banks_client();
+    let program_id = test.program_id();
+
+    // The transfer controls PDA is only created once a control is first set.
+    assert!(banks_client
+        .get_account(transfer_controls_pda_key(&program_id))
+        .await
+        .unwrap()
+        .is_none());
+
+    set_paused(&mut banks_client, &program_id, test.owner(), true)
+        .await
+        .unwrap();
+
+    let (_transfer_controls_key, transfer_controls_bump) =
+        Pubkey::find_program_address(hyperlane_token_transfer_controls_pda_seeds!(), &program_id);
+    assert_eq!(
+        fetch_transfer_controls(&mut banks_client, &program_id).await,
+        TransferControls {
+            bump: transfer_controls_bump,
+            paused: true,
+            rate_limits: HashMap::new(),
+        },
+    );
+
+    set_paused(&mut banks_client, &program_id, test.owner(), false)
+        .await
+        .unwrap();
+
+    assert!(
+        !fetch_transfer_controls(&mut banks_client, &program_id)
+            .await
+            .paused
+    );
+}
+
+pub async fn test_set_paused_errors_if_not_owner<T: TransferControlsTest>(test: T) {
+    let mut banks_client = test.banks_client();
+
+    let non_owner = new_funded_keypair(&mut banks_client, test.owner(), ONE_SOL_IN_LAMPORTS).await;
+
+    let result = set_paused(&mut banks_client, &test.program_id(), &non_owner, true).await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument),
+    );
+}
+
+pub async fn test_set_rate_limits<T: TransferControlsTest>(test: T) {
+    let mut banks_client = test.banks_client();
+    let program_id = test.program_id();
+    let remote_domain = test.remote_domain();
+    let one_token = test.one_token();
+
+    set_rate_limit(
+        &mut banks_client,
+        &program_id,
+        test.owner(),
+        remote_domain,
+        Some(RateLimitParams {
+            capacity: 50 * one_token,
+            refill_per_second: one_token,
+        }),
+    )
+    .await
+    .unwrap();
+
+    let transfer_controls = fetch_transfer_controls(&mut banks_client, &program_id).await;
+    assert!(!transfer_controls.paused);
+    let rate_limit = &transfer_controls.rate_limits[&remote_domain];
+    assert_eq!(rate_limit.capacity, 50 * one_token);
+    assert_eq!(rate_limit.refill_per_second, one_token);
+    // A new rate limit starts out at full capacity in both directions.
+    assert_eq!(rate_limit.outbound.available, 50 * one_token);
+    assert_eq!(rate_limit.inbound.available, 50 * one_token);
+
+    // Removing the rate limit.
+    set_rate_limit(
+        &mut banks_client,
+        &program_id,
+        test.owner(),
+        remote_domain,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert!(fetch_transfer_controls(&mut banks_client, &program_id)
+        .await
+        .rate_limits
+        .is_empty());
+
+    // Only the owner can set rate limits.
+    let non_owner = new_funded_keypair(&mut banks_client, test.owner(), ONE_SOL_IN_LAMPORTS).await;
+    let result = set_rate_limit(
+        &mut banks_client,
+        &program_id,
+        &non_owner,
+        remote_domain,
+        None,
+    )
+    .await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument),
+    );
+}
+
+pub async fn test_transfer_remote_errors_if_transfer_controls_omitted<T: TransferControlsTest>(
+    mut test: T,
+) {
+    let mut banks_client = test.banks_client();
+    let program_id = test.program_id();
+    let one_token = test.one_token();
+
+    // Transfers don't take the transfer controls PDA until a control is first set.
+    test.transfer_remote_with_transfer_controls(one_token, vec![])
+        .await
+        .unwrap();
+
+    set_paused(&mut banks_client, &program_id, test.owner(), false)
+        .await
+        .unwrap();
+
+    // Once set, the transfer controls can't be bypassed by omitting the PDA...
+    let result = test
+        .transfer_remote_with_transfer_controls(one_token, vec![])
+        .await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys),
+    );
+
+    // ...or by passing another account in its place.
+    let result = test
+        .transfer_remote_with_transfer_controls(
+            one_token,
+            vec![AccountMeta::new(Pubkey::new_unique(), false)],
+        )
+        .await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument),
+    );
+
+    transfer_remote(&mut test, one_token).await.unwrap();
+}
+
+pub async fn test_transfer_remote_errors_if_paused<T: TransferControlsTest>(mut test: T) {
+    let mut banks_client = test.banks_client();
+    let program_id = test.program_id();
+    let one_token = test.one_token();
+
+    set_paused(&mut banks_client, &program_id, test.owner(), true)
+        .await
+        .unwrap();
+
+    let result = transfer_remote(&mut test, one_token).await;
+    assert_token_error(result, HyperlaneTokenError::TransfersPaused);
+
+    // Transfers go through again once unpaused.
+    set_paused(&mut banks_client, &program_id, test.owner(), false)
+        .await
+        .unwrap();
+
+    transfer_remote(&mut test, one_token).await.unwrap();
+}
+
+pub async fn test_transfer_remote_errors_if_rate_limit_exceeded<T: TransferControlsTest>(
+    mut test: T,
+) {
+    let mut banks_client = test.banks_client();
+    let program_id = test.program_id();
+    let remote_domain = test.remote_domain();
+    let one_token = test.one_token();
+
+    set_rate_limit(
+        &mut banks_client,
+        &program_id,
+        test.owner(),
+        remote_domain,
+        Some(RateLimitParams {
+            capacity: 10 * one_token,
+            refill_per_second: 0,
+        }),
+    )
+    .await
+    .unwrap();
+
+    let result = transfer_remote(&mut test, 10 * one_token + 1).await;
+    assert_token_error(result, HyperlaneTokenError::RateLimitExceeded);
+
+    transfer_remote(&mut test, 10 * one_token).await.unwrap();
+
+    // The outbound capacity is used up.
+    let result = transfer_remote(&mut test, 1).await;
+    assert_token_error(result, HyperlaneTokenError::RateLimitExceeded);
+
+    let rate_limit = &fetch_transfer_controls(&mut banks_client, &program_id)
+        .await
+        .rate_limits[&remote_domain];
+    assert_eq!(rate_limit.outbound.available, 0);
+    assert_eq!(rate_limit.inbound.available, 10 * one_token);
+}
+
+pub async fn test_transfer_from_remote_errors_if_paused<T: TransferControlsTest>(mut test: T) {
+    let mut banks_client = test.banks_client();
+    let program_id = test.program_id();
+    let one_token = test.one_token();
+
+    set_paused(&mut banks_client, &program_id, test.owner(), true)
+        .await
+        .unwrap();
+
+    let result = test
+        .transfer_from_remote(1, Pubkey::new_unique(), one_token)
+        .await;
+    assert_token_error(result, HyperlaneTokenError::TransfersPaused);
+
+    // Transfers go through again once unpaused.
+    set_paused(&mut banks_client, &program_id, test.owner(), false)
+        .await
+        .unwrap();
+
+    test.transfer_from_remote(1, Pubkey::new_unique(), one_token)
+        .await
+        .unwrap();
+}
+
+pub async fn test_transfer_from_remote_errors_if_rate_limit_exceeded<T: TransferControlsTest>(
+    mut test: T,
+) {
+    let mut banks_client = test.banks_client();
+    let program_id = test.program_id();
+    let remote_domain = test.remote_domain();
+    let one_token = test.one_token();
+
+    set_rate_limit(
+        &mut banks_client,
+        &program_id,
+        test.owner(),
+        remote_domain,
+        Some(RateLimitParams {
+            capacity: 10 * one_token,
+            refill_per_second: 0,
+        }),
+    )
+    .await
+    .unwrap();
+
+    let result = test
+        .transfer_from_remote(1, Pubkey::new_unique(), 11 * one_token)
+        .await;
+    assert_token_error(result, HyperlaneTokenError::RateLimitExceeded);
+
+    test.transfer_from_remote(1, Pubkey::new_unique(), 10 * one_token)
+        .await
+        .unwrap();
+
+    // The inbound capacity is used up.
+    let result = test
+        .transfer_from_remote(2, Pubkey::new_unique(), one_token)
+        .await;
+    assert_token_error(result, HyperlaneTokenError::RateLimitExceeded);
+
+    // The outbound direction is limited separately.
+    transfer_remote(&mut test, 10 * one_token).await.unwrap();
+}
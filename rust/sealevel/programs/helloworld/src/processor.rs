@@ -172,6 +172,8 @@ fn init(program_id: &Pubkey, accounts: &[AccountInfo], init: Init) -> ProgramRes
 /// 12. `[]` OPTIONAL - The Overhead IGP program, if the configured IGP is an Overhead IGP.
 /// 13. `[writeable]` The IGP account.
 ///     ---- end if an IGP is configured ----
+/// 14..N. `[executable]` The Mailbox's hooks, which must not require any accounts
+///     to be invoked.
 fn send_hello_world(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -218,7 +220,7 @@ fn send_hello_world(
     // Account 8: Dispatched message PDA.
     let dispatched_message_info = next_account_info(accounts_iter)?;
 
-    let dispatch_account_metas = vec![
+    let mut dispatch_account_metas = vec![
         AccountMeta::new(*mailbox_outbox_info.key, false),
         AccountMeta::new_readonly(*dispatch_authority_info.key, true),
         AccountMeta::new_readonly(*system_program_info.key, false),
@@ -227,7 +229,7 @@ fn send_hello_world(
         AccountMeta::new_readonly(*unique_message_account_info.key, true),
         AccountMeta::new(*dispatched_message_info.key, false),
     ];
-    let dispatch_account_infos = &[
+    let mut dispatch_account_infos = vec![
         mailbox_outbox_info.clone(),
         dispatch_authority_info.clone(),
         system_program_info.clone(),
@@ -311,6 +313,12 @@ fn send_hello_world(
             None
         };
 
+    // Accounts 14..N: The Mailbox's hooks.
+    for hook_info in accounts_iter {
+        dispatch_account_metas.push(AccountMeta::new_readonly(*hook_info.key, false));
+        dispatch_account_infos.push(hook_info.clone());
+    }
+
     let dispatch_authority_seeds: &[&[u8]] =
         mailbox_message_dispatch_authority_pda_seeds!(expected_dispatch_authority_bump);
 
@@ -323,9 +331,10 @@ fn send_hello_world(
             hello_world.message.into(),
             HANDLE_GAS_AMOUNT,
             dispatch_account_metas,
-            dispatch_account_infos,
+            &dispatch_account_infos,
             igp_payment_account_metas,
             &igp_payment_account_infos,
+            vec![],
         )?;
     } else {
         // Dispatch the message.
@@ -335,7 +344,8 @@ fn send_hello_world(
             hello_world.destination,
             hello_world.message.into(),
             dispatch_account_metas,
-            dispatch_account_infos,
+            &dispatch_account_infos,
+            vec![],
         )?;
     }
 
@@ -0,0 +1,14 @@
+//! Post-dispatch hook that records the messages it's invoked with.
+//! **NOT INTENDED FOR USE IN PRODUCTION**
+
+// Allow cfg values used by solana_program::entrypoint! macro
+#![allow(unexpected_cfgs)]
+#![deny(warnings)]
+#![deny(missing_docs)]
+#![deny(unsafe_code)]
+
+pub mod program;
+#[cfg(feature = "test-client")]
+pub mod test_client;
+
+solana_program::declare_id!("B2WRHS62shsTgnfq8H81WkNutjTNfeVaNEAvYj4TSzi3");
@@ -0,0 +1,208 @@
+//! Post-dispatch hook used for testing.
+
+use account_utils::{create_pda_account, AccountData, SizedData};
+use borsh::{BorshDeserialize, BorshSerialize};
+use hyperlane_core::{Decode, HyperlaneMessage, H256};
+use hyperlane_sealevel_hook_interface::PostDispatchHookInstruction;
+use serializable_account_meta::{SerializableAccountMeta, SimulationReturnData};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    instruction::AccountMeta,
+    program::set_return_data,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+use solana_system_interface::program as system_program;
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+/// Hook metadata that makes the PostDispatch instruction fail.
+pub const REJECT_METADATA: &[u8] = &[0xff];
+
+/// Custom errors for the program.
+pub enum TestHookError {
+    /// The PostDispatch instruction was given `REJECT_METADATA`.
+    PostDispatchRejected = 69421,
+}
+
+/// The PDA seeds relating to storage
+#[macro_export]
+macro_rules! test_hook_storage_pda_seeds {
+    () => {{
+        &[b"test_hook", b"-", b"storage"]
+    }};
+
+    ($bump_seed:expr) => {{
+        &[b"test_hook", b"-", b"storage", &[$bump_seed]]
+    }};
+}
+
+/// The storage account.
+pub type TestHookStorageAccount = AccountData<TestHookStorage>;
+
+/// The storage account's data.
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Default)]
+pub struct TestHookStorage {
+    /// The number of messages the hook has been invoked with.
+    pub dispatch_count: u64,
+    /// The ID of the last message the hook was invoked with.
+    pub last_message_id: H256,
+    /// Whether the Outbox PDA signed the last PostDispatch instruction.
+    pub last_outbox_signed: bool,
+}
+
+impl SizedData for TestHookStorage {
+    fn size(&self) -> usize {
+        // 8 byte dispatch_count
+        // 32 byte last_message_id
+        // 1 byte last_outbox_signed
+        8 + 32 + 1
+    }
+}
+
+/// Instructions for the program.
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub enum TestHookInstruction {
+    /// Initializes the program.
+    Init,
+}
+
+/// Processes an instruction.
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    if let Ok(hook_instruction) = PostDispatchHookInstruction::decode(instruction_data) {
+        return match hook_instruction {
+            PostDispatchHookInstruction::PostDispatch(post_dispatch) => record_dispatch(
+                program_id,
+                accounts,
+                &post_dispatch.metadata,
+                &post_dispatch.message,
+            ),
+            PostDispatchHookInstruction::PostDispatchAccountMetas(_) => {
+                post_dispatch_account_metas(program_id)
+            }
+            PostDispatchHookInstruction::QuoteDispatch(_) => {
+                set_return_data(
+                    &borsh::to_vec(&SimulationReturnData::new(0u64))
+                        .map_err(|_| ProgramError::BorshIoError)?[..],
+                );
+                Ok(())
+            }
+        };
+    }
+
+    let instruction = TestHookInstruction::try_from_slice(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    match instruction {
+        TestHookInstruction::Init => init(program_id, accounts),
+    }
+}
+
+/// Creates the storage PDA.
+///
+/// Accounts:
+/// 0. `[executable]` System program.
+/// 1. `[signer]` Payer.
+/// 2. `[writeable]` Storage PDA.
+fn init(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Account 0: System program.
+    let system_program_info = next_account_info(accounts_iter)?;
+    if system_program_info.key != &system_program::ID {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Account 1: Payer.
+    let payer_info = next_account_info(accounts_iter)?;
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Account 2: Storage PDA.
+    let storage_info = next_account_info(accounts_iter)?;
+    let (storage_pda_key, storage_pda_bump_seed) =
+        Pubkey::find_program_address(test_hook_storage_pda_seeds!(), program_id);
+    if storage_info.key != &storage_pda_key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let storage_account = TestHookStorageAccount::from(TestHookStorage::default());
+    create_pda_account(
+        payer_info,
+        &Rent::get()?,
+        storage_account.size(),
+        program_id,
+        system_program_info,
+        storage_info,
+        test_hook_storage_pda_seeds!(storage_pda_bump_seed),
+    )?;
+    // Store it
+    storage_account.store(storage_info, false)?;
+
+    Ok(())
+}
+
+/// Records the dispatched message.
+///
+/// Accounts:
+/// 0. `[]` The Mailbox's Outbox PDA, a signer if this is the required or default hook.
+/// 1. `[writeable]` Storage PDA.
+fn record_dispatch(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    metadata: &[u8],
+    message: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Account 0: Outbox PDA.
+    // Not bothering to check for validity because this is a test program
+    let outbox_info = next_account_info(accounts_iter)?;
+
+    // Account 1: Storage PDA.
+    let storage_info = next_account_info(accounts_iter)?;
+    let mut storage =
+        TestHookStorageAccount::fetch(&mut &storage_info.data.borrow()[..])?.into_inner();
+
+    if metadata == REJECT_METADATA {
+        return Err(ProgramError::Custom(
+            TestHookError::PostDispatchRejected as u32,
+        ));
+    }
+
+    let message = HyperlaneMessage::read_from(&mut std::io::Cursor::new(message))
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    storage.dispatch_count += 1;
+    storage.last_message_id = message.id();
+    storage.last_outbox_signed = outbox_info.is_signer;
+    TestHookStorageAccount::from(storage).store(storage_info, false)?;
+
+    Ok(())
+}
+
+fn post_dispatch_account_metas(program_id: &Pubkey) -> ProgramResult {
+    let (storage_pda_key, _storage_pda_bump) =
+        Pubkey::find_program_address(test_hook_storage_pda_seeds!(), program_id);
+
+    let account_metas: Vec<SerializableAccountMeta> =
+        vec![AccountMeta::new(storage_pda_key, false).into()];
+
+    // Wrap it in the SimulationReturnData because serialized account_metas
+    // may end with zero byte(s), which are incorrectly truncated as
+    // simulated transaction return data.
+    // See `SimulationReturnData` for details.
+    let bytes = borsh::to_vec(&SimulationReturnData::new(account_metas))
+        .map_err(|_| ProgramError::BorshIoError)?;
+    set_return_data(&bytes[..]);
+
+    Ok(())
+}
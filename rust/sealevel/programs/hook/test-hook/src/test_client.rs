@@ -0,0 +1,96 @@
+//! Test client for the Test Hook program.
+
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::*;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use solana_system_interface::program as system_program;
+
+use hyperlane_test_transaction_utils::process_instruction;
+
+use crate::{
+    program::{TestHookInstruction, TestHookStorage, TestHookStorageAccount},
+    test_hook_storage_pda_seeds,
+};
+
+/// Test client for the Test Hook program.
+/// The program can be deployed at multiple program IDs, e.g. to be both
+/// a Mailbox's required and default hook.
+pub struct TestHookTestClient {
+    banks_client: BanksClient,
+    payer: Keypair,
+    program_id: Pubkey,
+}
+
+impl TestHookTestClient {
+    /// Creates a new `TestHookTestClient` for the program deployed at `program_id`.
+    pub fn new(banks_client: BanksClient, payer: Keypair, program_id: Pubkey) -> Self {
+        Self {
+            banks_client,
+            payer,
+            program_id,
+        }
+    }
+
+    /// Initializes the Test Hook program.
+    pub async fn init(&mut self) -> Result<(), BanksClientError> {
+        let payer_pubkey = self.payer.pubkey();
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            data: borsh::to_vec(&TestHookInstruction::Init).unwrap(),
+            accounts: vec![
+                // 0. `[executable]` System program.
+                // 1. `[signer]` Payer.
+                // 2. `[writeable]` Storage PDA.
+                AccountMeta::new_readonly(system_program::ID, false),
+                AccountMeta::new(payer_pubkey, true),
+                AccountMeta::new(self.storage_pda_key(), false),
+            ],
+        };
+
+        process_instruction(
+            &mut self.banks_client,
+            instruction,
+            &self.payer,
+            &[&self.payer],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the account metas to pass to the Mailbox when dispatching:
+    /// the program followed by the accounts of its PostDispatch instruction.
+    pub fn hook_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.program_id, false),
+            AccountMeta::new(self.storage_pda_key(), false),
+        ]
+    }
+
+    /// Gets the storage account's data.
+    pub async fn storage(&mut self) -> Result<TestHookStorage, BanksClientError> {
+        let account = self
+            .banks_client
+            .get_account(self.storage_pda_key())
+            .await?
+            .unwrap();
+        Ok(*TestHookStorageAccount::fetch(&mut &account.data[..])
+            .unwrap()
+            .into_inner())
+    }
+
+    fn storage_pda_key(&self) -> Pubkey {
+        let (storage_pda_key, _storage_pda_bump) =
+            Pubkey::find_program_address(test_hook_storage_pda_seeds!(), &self.program_id);
+        storage_pda_key
+    }
+
+    /// Gets the program ID.
+    pub fn id(&self) -> Pubkey {
+        self.program_id
+    }
+}
@@ -41,7 +41,8 @@ use hyperlane_sealevel_token_lib::{
 };
 use hyperlane_test_utils::{
     assert_token_balance, assert_transaction_error, igp_program_id, initialize_igp_accounts,
-    initialize_mailbox, mailbox_id, new_funded_keypair, process, transfer_lamports, IgpAccounts,
+    initialize_mailbox, mailbox_id, new_funded_keypair, process, transfer_controls,
+    transfer_lamports, IgpAccounts, MailboxAccounts,
 };
 use hyperlane_warp_route::TokenMessage;
 use solana_program_test::*;
@@ -495,6 +496,7 @@ async fn test_initialize() {
                 escrow_bump: hyperlane_token_accounts.escrow_bump,
                 ata_payer_bump: hyperlane_token_accounts.ata_payer_bump,
             },
+            transfer_controls_bump: None,
         }),
     );
 
@@ -1799,3 +1801,280 @@ async fn test_set_interchain_gas_paymaster_errors_if_owner_not_signer() {
         TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature),
     );
 }
+
+/// One whole token, in local decimals.
+const ONE_TOKEN: u64 = 10u64.pow(LOCAL_DECIMALS_U32);
+
+struct TransferControlsTest {
+    banks_client: BanksClient,
+    payer: Keypair,
+    program_id: Pubkey,
+    mailbox_accounts: MailboxAccounts,
+    hyperlane_token_accounts: HyperlaneTokenAccounts,
+    remote_router: H256,
+    mint: Pubkey,
+    token_sender: Keypair,
+    token_sender_ata: Pubkey,
+}
+
+impl transfer_controls::TransferControlsTest for TransferControlsTest {
+    fn banks_client(&self) -> BanksClient {
+        self.banks_client.clone()
+    }
+
+    fn owner(&self) -> &Keypair {
+        &self.payer
+    }
+
+    fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    fn remote_domain(&self) -> u32 {
+        REMOTE_DOMAIN
+    }
+
+    fn one_token(&self) -> u64 {
+        ONE_TOKEN
+    }
+
+    async fn transfer_remote_with_transfer_controls(
+        &mut self,
+        amount: u64,
+        transfer_controls_accounts: Vec<AccountMeta>,
+    ) -> Result<(), BanksClientError> {
+        transfer_remote(self, amount, transfer_controls_accounts).await
+    }
+
+    async fn transfer_from_remote(
+        &mut self,
+        nonce: u32,
+        recipient: Pubkey,
+        local_amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let remote_amount =
+            convert_decimals(local_amount.into(), LOCAL_DECIMALS, REMOTE_DECIMALS).unwrap();
+        let message = HyperlaneMessage {
+            version: 3,
+            nonce,
+            origin: REMOTE_DOMAIN,
+            sender: self.remote_router,
+            destination: LOCAL_DOMAIN,
+            recipient: self.program_id.to_bytes().into(),
+            body: TokenMessage::new(recipient.to_bytes().into(), remote_amount, vec![]).to_vec(),
+        };
+
+        process(
+            &mut self.banks_client,
+            &self.payer,
+            &self.mailbox_accounts,
+            vec![],
+            &message,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// Sets up a token without an IGP, with 100 tokens in escrow and a token sender
+/// holding 100 tokens.
+async fn setup_transfer_controls_test() -> TransferControlsTest {
+    let program_id = hyperlane_sealevel_token_collateral_id();
+    let mailbox_program_id = mailbox_id();
+    let spl_token_program_id = spl_token_2022::id();
+
+    let (mut banks_client, payer) = setup_client().await;
+
+    let mailbox_accounts = initialize_mailbox(
+        &mut banks_client,
+        &mailbox_program_id,
+        &payer,
+        LOCAL_DOMAIN,
+        ONE_SOL_IN_LAMPORTS,
+        ProtocolFee::default(),
+    )
+    .await
+    .unwrap();
+
+    let (mint, mint_authority) = initialize_mint(
+        &mut banks_client,
+        &payer,
+        LOCAL_DECIMALS,
+        &spl_token_program_id,
+    )
+    .await;
+
+    let hyperlane_token_accounts = initialize_hyperlane_token(
+        &program_id,
+        &mut banks_client,
+        &payer,
+        None,
+        &mint,
+        &spl_token_program_id,
+    )
+    .await
+    .unwrap();
+    // ATA payer must have a balance to create new ATAs
+    transfer_lamports(
+        &mut banks_client,
+        &payer,
+        &hyperlane_token_accounts.ata_payer,
+        ONE_SOL_IN_LAMPORTS,
+    )
+    .await;
+
+    let remote_router = H256::random();
+    enroll_remote_router(
+        &mut banks_client,
+        &program_id,
+        &payer,
+        &hyperlane_token_accounts.token,
+        REMOTE_DOMAIN,
+        remote_router,
+    )
+    .await
+    .unwrap();
+
+    // Give the escrow account a balance to be used by transfer_from_remote.
+    mint_to(
+        &mut banks_client,
+        &spl_token_program_id,
+        &mint,
+        &mint_authority,
+        &hyperlane_token_accounts.escrow,
+        100 * ONE_TOKEN,
+    )
+    .await;
+
+    let token_sender = new_funded_keypair(&mut banks_client, &payer, ONE_SOL_IN_LAMPORTS).await;
+    let token_sender_ata = create_and_mint_to_ata(
+        &mut banks_client,
+        &spl_token_program_id,
+        &mint,
+        &mint_authority,
+        &payer,
+        &token_sender.pubkey(),
+        100 * ONE_TOKEN,
+    )
+    .await;
+
+    TransferControlsTest {
+        banks_client,
+        payer,
+        program_id,
+        mailbox_accounts,
+        hyperlane_token_accounts,
+        remote_router,
+        mint,
+        token_sender,
+        token_sender_ata,
+    }
+}
+
+/// Transfers remotely, appending `transfer_controls_accounts` after the plugin accounts.
+async fn transfer_remote(
+    test: &mut TransferControlsTest,
+    amount: u64,
+    transfer_controls_accounts: Vec<AccountMeta>,
+) -> Result<(), BanksClientError> {
+    let unique_message_account_keypair = Keypair::new();
+    let (dispatched_message_key, _dispatched_message_bump) = Pubkey::find_program_address(
+        mailbox_dispatched_message_pda_seeds!(&unique_message_account_keypair.pubkey()),
+        &mailbox_id(),
+    );
+
+    let recent_blockhash = test.banks_client.get_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[Instruction::new_with_bytes(
+            test.program_id,
+            &HyperlaneTokenInstruction::TransferRemote(TransferRemote {
+                destination_domain: REMOTE_DOMAIN,
+                recipient: H256::random(),
+                amount_or_id: amount.into(),
+            })
+            .encode()
+            .unwrap(),
+            [
+                vec![
+                    AccountMeta::new_readonly(system_program::ID, false),
+                    AccountMeta::new_readonly(account_utils::SPL_NOOP_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(test.hyperlane_token_accounts.token, false),
+                    AccountMeta::new_readonly(test.mailbox_accounts.program, false),
+                    AccountMeta::new(test.mailbox_accounts.outbox, false),
+                    AccountMeta::new_readonly(
+                        test.hyperlane_token_accounts.dispatch_authority,
+                        false,
+                    ),
+                    AccountMeta::new_readonly(test.token_sender.pubkey(), true),
+                    AccountMeta::new_readonly(unique_message_account_keypair.pubkey(), true),
+                    AccountMeta::new(dispatched_message_key, false),
+                    AccountMeta::new_readonly(spl_token_2022::id(), false),
+                    AccountMeta::new(test.mint, false),
+                    AccountMeta::new(test.token_sender_ata, false),
+                    AccountMeta::new(test.hyperlane_token_accounts.escrow, false),
+                ],
+                transfer_controls_accounts,
+            ]
+            .concat(),
+        )],
+        Some(&test.token_sender.pubkey()),
+        &[&test.token_sender, &unique_message_account_keypair],
+        recent_blockhash,
+    );
+    test.banks_client.process_transaction(transaction).await
+}
+
+#[tokio::test]
+async fn test_set_paused() {
+    transfer_controls::test_set_paused(setup_transfer_controls_test().await).await;
+}
+
+#[tokio::test]
+async fn test_set_paused_errors_if_not_owner() {
+    transfer_controls::test_set_paused_errors_if_not_owner(setup_transfer_controls_test().await)
+        .await;
+}
+
+#[tokio::test]
+async fn test_set_rate_limits() {
+    transfer_controls::test_set_rate_limits(setup_transfer_controls_test().await).await;
+}
+
+#[tokio::test]
+async fn test_transfer_remote_errors_if_transfer_controls_omitted() {
+    transfer_controls::test_transfer_remote_errors_if_transfer_controls_omitted(
+        setup_transfer_controls_test().await,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_transfer_remote_errors_if_paused() {
+    transfer_controls::test_transfer_remote_errors_if_paused(setup_transfer_controls_test().await)
+        .await;
+}
+
+#[tokio::test]
+async fn test_transfer_remote_errors_if_rate_limit_exceeded() {
+    transfer_controls::test_transfer_remote_errors_if_rate_limit_exceeded(
+        setup_transfer_controls_test().await,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_transfer_from_remote_errors_if_paused() {
+    transfer_controls::test_transfer_from_remote_errors_if_paused(
+        setup_transfer_controls_test().await,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_transfer_from_remote_errors_if_rate_limit_exceeded() {
+    transfer_controls::test_transfer_from_remote_errors_if_rate_limit_exceeded(
+        setup_transfer_controls_test().await,
+    )
+    .await;
+}
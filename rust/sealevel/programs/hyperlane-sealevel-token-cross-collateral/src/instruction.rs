@@ -49,6 +49,9 @@ pub enum CrossCollateralInstruction {
     HandleLocal(HandleLocal),
     /// Returns account metas needed for HandleLocal (off-chain simulation).
     HandleLocalAccountMetas(HandleLocal),
+    /// Same as `TransferRemoteTo`, passing accounts to the Mailbox's hooks when
+    /// dispatching cross-chain.
+    TransferRemoteToWithHooks(TransferRemoteToWithHooks),
 }
 
 impl DiscriminatorData for CrossCollateralInstruction {
@@ -69,6 +72,17 @@ pub struct TransferRemoteTo {
     pub target_router: H256,
 }
 
+/// Instruction data for transferring to a specific enrolled router when the
+/// Mailbox's hooks require accounts.
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub struct TransferRemoteToWithHooks {
+    /// The transfer.
+    pub transfer: TransferRemoteTo,
+    /// The number of accounts following each hook's program account, in the
+    /// order the Mailbox invokes the hooks.
+    pub hook_account_counts: Vec<u8>,
+}
+
 /// Instruction data for same-chain CPI receive.
 #[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
 pub struct HandleLocal {
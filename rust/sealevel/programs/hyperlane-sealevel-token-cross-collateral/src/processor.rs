@@ -25,7 +25,7 @@ use hyperlane_sealevel_message_recipient_interface::{
 };
 use hyperlane_sealevel_token_lib::{
     accounts::{HyperlaneToken, HyperlaneTokenAccount},
-    instruction::{Init, Instruction as TokenIxn},
+    instruction::{Init, Instruction as TokenIxn, TransferRemoteWithHooks},
     processor::{HyperlaneSealevelToken, HyperlaneSealevelTokenPlugin},
 };
 use hyperlane_warp_route::TokenMessage;
@@ -50,6 +50,7 @@ use crate::{
     error::Error as CcError,
     instruction::{
         CrossCollateralInstruction, CrossCollateralRouterUpdate, HandleLocal, TransferRemoteTo,
+        TransferRemoteToWithHooks,
     },
     plugin::CollateralPlugin,
 };
@@ -73,7 +74,7 @@ pub fn process_instruction(
                 set_cross_collateral_routers(program_id, accounts, configs)
             }
             CrossCollateralInstruction::TransferRemoteTo(transfer) => {
-                transfer_remote_to(program_id, accounts, transfer)
+                transfer_remote_to(program_id, accounts, transfer, vec![])
             }
             CrossCollateralInstruction::HandleLocal(handle) => {
                 handle_local(program_id, accounts, handle)
@@ -81,6 +82,10 @@ pub fn process_instruction(
             CrossCollateralInstruction::HandleLocalAccountMetas(handle) => {
                 handle_local_account_metas(program_id, accounts, handle)
             }
+            CrossCollateralInstruction::TransferRemoteToWithHooks(TransferRemoteToWithHooks {
+                transfer,
+                hook_account_counts,
+            }) => transfer_remote_to(program_id, accounts, transfer, hook_account_counts),
         };
     }
 
@@ -125,8 +130,22 @@ pub fn process_instruction(
     match TokenIxn::decode(instruction_data)? {
         TokenIxn::Init(init) => initialize(program_id, accounts, init),
         TokenIxn::TransferRemote(xfer) => {
-            HyperlaneSealevelToken::<CollateralPlugin>::transfer_remote(program_id, accounts, xfer)
+            HyperlaneSealevelToken::<CollateralPlugin>::transfer_remote(
+                program_id,
+                accounts,
+                xfer,
+                vec![],
+            )
         }
+        TokenIxn::TransferRemoteWithHooks(TransferRemoteWithHooks {
+            transfer,
+            hook_account_counts,
+        }) => HyperlaneSealevelToken::<CollateralPlugin>::transfer_remote(
+            program_id,
+            accounts,
+            transfer,
+            hook_account_counts,
+        ),
         TokenIxn::EnrollRemoteRouter(config) => {
             HyperlaneSealevelToken::<CollateralPlugin>::enroll_remote_router(
                 program_id, accounts, config,
@@ -157,6 +176,14 @@ pub fn process_instruction(
                 program_id, accounts, new_igp,
             )
         }
+        TokenIxn::SetPaused(paused) => {
+            HyperlaneSealevelToken::<CollateralPlugin>::set_paused(program_id, accounts, paused)
+        }
+        TokenIxn::SetRateLimits(configs) => {
+            HyperlaneSealevelToken::<CollateralPlugin>::set_rate_limits(
+                program_id, accounts, configs,
+            )
+        }
     }
     .map_err(|err| {
         msg!("{}", err);
@@ -284,6 +311,7 @@ fn initialize(program_id: &Pubkey, accounts: &[AccountInfo], init: Init) -> Prog
         remote_decimals: init.remote_decimals,
         remote_routers: HashMap::new(),
         plugin_data,
+        transfer_controls_bump: None,
     };
     let hyperlane_token_account_data =
         HyperlaneTokenAccount::<CollateralPlugin>::from(hyperlane_token);
@@ -452,18 +480,25 @@ fn set_cross_collateral_routers(
 /// 8.    `[signer]` unique message account
 /// 9.    `[writable]` dispatched message PDA
 ///       10..N IGP accounts (optional), then plugin transfer_in accounts.
+///       N+1   `[writable]` OPTIONAL - transfer controls PDA, if the owner has configured
+///       a transfer control.
+///       N+2..M the Mailbox's hooks in the order they are invoked, each followed by
+///       the number of accounts given by `hook_account_counts`.
 ///
 /// Local path (destination_domain == local_domain):
 /// 3.    `[signer]` sender wallet / payer
 /// 4.    `[]` CC dispatch authority PDA (this program's, for CPI signing)
 /// 5.    `[executable]` target program
 ///       6..N plugin transfer_in accounts.
-///       N+1..M target HandleLocal accounts (passthrough for CPI).
+///       N+1   `[writable]` OPTIONAL - transfer controls PDA, if the owner has configured
+///       a transfer control.
+///       N+2..M target HandleLocal accounts (passthrough for CPI).
 #[allow(clippy::too_many_lines)]
 fn transfer_remote_to(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     xfer: TransferRemoteTo,
+    hook_account_counts: Vec<u8>,
 ) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
@@ -500,6 +535,10 @@ fn transfer_remote_to(
     // Account layouts diverge after the shared prefix. Each branch validates
     // its own accounts independently, so cross-branch confusion is not possible.
     if cc_state.local_domain == xfer.destination_domain {
+        // Nothing is dispatched, so there are no hooks to pass accounts to.
+        if !hook_account_counts.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
         transfer_remote_to_local(program_id, &hyperlane_token, &cc_state, accounts_iter, xfer)
     } else {
         transfer_remote_to_remote(
@@ -508,6 +547,7 @@ fn transfer_remote_to(
             system_program_account,
             accounts_iter,
             xfer,
+            hook_account_counts,
         )
     }
 }
@@ -526,6 +566,9 @@ fn transfer_remote_to(
 /// 8.    `[signer]` unique message account
 /// 9.    `[writable]` dispatched message PDA
 ///       10..N IGP accounts (optional), then plugin transfer_in accounts.
+///       N+1   `[writable]` OPTIONAL - transfer controls PDA, if the owner has configured
+///       a transfer control.
+///       N+2..M the Mailbox's hooks and their accounts.
 /// SAFETY: This function must only be called from `transfer_remote_to`, which
 /// validates that the destination is an authorized remote router. Do not call directly.
 #[allow(clippy::too_many_lines)]
@@ -535,6 +578,7 @@ fn transfer_remote_to_remote<'account_info_slice, 'account_info>(
     system_program_account: &'account_info_slice AccountInfo<'account_info>,
     accounts_iter: &mut std::slice::Iter<'account_info_slice, AccountInfo<'account_info>>,
     xfer: TransferRemoteTo,
+    hook_account_counts: Vec<u8>,
 ) -> ProgramResult {
     // Account 3: SPL Noop
     let spl_noop = next_account_info(accounts_iter)?;
@@ -643,22 +687,28 @@ fn transfer_remote_to_remote<'account_info_slice, 'account_info>(
         local_amount,
     )?;
 
-    // Extraneous account check
-    if accounts_iter.next().is_some() {
-        return Err(TokenError::ExtraneousAccount.into());
-    }
+    // Account N+1: Transfer controls PDA, if the token has transfer controls.
+    HyperlaneSealevelToken::<CollateralPlugin>::consume_transfer_controls(
+        program_id,
+        hyperlane_token,
+        accounts_iter,
+        |controls, now| controls.consume_outbound(xfer.destination_domain, local_amount, now),
+    )?;
 
     // Build token message body
     let token_transfer_message = TokenMessage::new(xfer.recipient, remote_amount, vec![]).to_vec();
 
     // Build mailbox dispatch CPI with target_router as recipient (not self.router(domain))
-    let dispatch_instruction = MailboxInstruction::OutboxDispatch(MailboxOutboxDispatch {
-        sender: *program_id,
-        destination_domain: xfer.destination_domain,
-        recipient: xfer.target_router,
-        message_body: token_transfer_message,
-    });
-    let dispatch_account_metas = vec![
+    let dispatch_instruction = MailboxInstruction::outbox_dispatch(
+        MailboxOutboxDispatch {
+            sender: *program_id,
+            destination_domain: xfer.destination_domain,
+            recipient: xfer.target_router,
+            message_body: token_transfer_message,
+        },
+        hook_account_counts,
+    );
+    let mut dispatch_account_metas = vec![
         AccountMeta::new(*mailbox_outbox_account.key, false),
         AccountMeta::new_readonly(*dispatch_authority_account.key, true),
         AccountMeta::new_readonly(system_program::ID, false),
@@ -667,7 +717,7 @@ fn transfer_remote_to_remote<'account_info_slice, 'account_info>(
         AccountMeta::new_readonly(*unique_message_account.key, true),
         AccountMeta::new(*dispatched_message_pda.key, false),
     ];
-    let dispatch_account_infos = &[
+    let mut dispatch_account_infos = vec![
         mailbox_outbox_account.clone(),
         dispatch_authority_account.clone(),
         system_program_account.clone(),
@@ -676,6 +726,15 @@ fn transfer_remote_to_remote<'account_info_slice, 'account_info>(
         unique_message_account.clone(),
         dispatched_message_pda.clone(),
     ];
+    // The Mailbox's hooks and their accounts, verified by the Mailbox.
+    for hook_account in accounts_iter {
+        dispatch_account_metas.push(AccountMeta {
+            pubkey: *hook_account.key,
+            is_signer: hook_account.is_signer,
+            is_writable: hook_account.is_writable,
+        });
+        dispatch_account_infos.push(hook_account.clone());
+    }
 
     let mailbox_ixn = Instruction {
         program_id: hyperlane_token.mailbox,
@@ -686,7 +745,7 @@ fn transfer_remote_to_remote<'account_info_slice, 'account_info>(
     };
     invoke_signed(
         &mailbox_ixn,
-        dispatch_account_infos,
+        &dispatch_account_infos,
         &[dispatch_authority_seeds],
     )?;
 
@@ -742,7 +801,9 @@ fn transfer_remote_to_remote<'account_info_slice, 'account_info>(
 /// 4.    `[]` CC dispatch authority PDA (this program's, for CPI signing)
 /// 5.    `[executable]` target program
 ///       6..N plugin transfer_in accounts.
-///       N+1..M target HandleLocal accounts (passthrough for CPI).
+///       N+1   `[writable]` OPTIONAL - transfer controls PDA, if the owner has configured
+///       a transfer control.
+///       N+2..M target HandleLocal accounts (passthrough for CPI).
 /// SAFETY: This function must only be called from `transfer_remote_to`, which
 /// validates that the destination is an authorized remote router. Do not call directly.
 fn transfer_remote_to_local(
@@ -793,6 +854,14 @@ fn transfer_remote_to_local(
         local_amount,
     )?;
 
+    // Account N+1: Transfer controls PDA, if the token has transfer controls.
+    HyperlaneSealevelToken::<CollateralPlugin>::consume_transfer_controls(
+        program_id,
+        hyperlane_token,
+        accounts_iter,
+        |controls, now| controls.consume_outbound(xfer.destination_domain, local_amount, now),
+    )?;
+
     // Build HandleLocal instruction data
     let token_message = TokenMessage::new(xfer.recipient, remote_amount, vec![]).to_vec();
     let handle_local_data = HandleLocal {
@@ -850,6 +919,8 @@ fn transfer_remote_to_local(
 /// 3.    `[]` CC state PDA account.
 /// 4.    `[depends on plugin]` recipient wallet address
 ///       5..N `[??..??]` Plugin-specific accounts (CollateralPlugin::transfer_out).
+///       N+1   `[writable]` OPTIONAL - transfer controls PDA, if the owner has configured
+///       a transfer control.
 fn transfer_from_remote_cc(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -911,6 +982,14 @@ fn transfer_from_remote_cc(
         local_amount,
     )?;
 
+    // Account N+1: Transfer controls PDA, if the token has transfer controls.
+    HyperlaneSealevelToken::<CollateralPlugin>::consume_transfer_controls(
+        program_id,
+        &hyperlane_token,
+        accounts_iter,
+        |controls, now| controls.consume_inbound(xfer.origin, local_amount, now),
+    )?;
+
     // Extraneous account check (must follow transfer_out which consumes dynamic accounts)
     if accounts_iter.next().is_some() {
         return Err(TokenError::ExtraneousAccount.into());
@@ -968,6 +1047,12 @@ fn transfer_from_remote_account_metas_cc(
         .into(),
     ];
     account_metas.extend(transfer_out_account_metas);
+    account_metas.extend(
+        HyperlaneSealevelToken::<CollateralPlugin>::transfer_controls_account_meta(
+            program_id, &token,
+        )?
+        .map(SerializableAccountMeta::from),
+    );
 
     let bytes = borsh::to_vec(&SimulationReturnData::new(account_metas))
         .map_err(|_| ProgramError::BorshIoError)?;
@@ -987,6 +1072,8 @@ fn transfer_from_remote_account_metas_cc(
 /// 3.    `[]` CC state PDA
 /// 4.    `[depends on plugin]` recipient wallet address
 ///       5..N `[??..??]` Plugin-specific accounts (CollateralPlugin::transfer_out).
+///       N+1   `[writable]` OPTIONAL - transfer controls PDA, if the owner has configured
+///       a transfer control.
 fn handle_local(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -1063,6 +1150,14 @@ fn handle_local(
         local_amount,
     )?;
 
+    // Account N+1: Transfer controls PDA, if the token has transfer controls.
+    HyperlaneSealevelToken::<CollateralPlugin>::consume_transfer_controls(
+        program_id,
+        &token,
+        accounts_iter,
+        |controls, now| controls.consume_inbound(cc_state.local_domain, local_amount, now),
+    )?;
+
     // Extraneous account check
     if accounts_iter.next().is_some() {
         return Err(TokenError::ExtraneousAccount.into());
@@ -1128,6 +1223,12 @@ fn handle_local_account_metas(
         .into(),
     ];
     account_metas.extend(transfer_out_account_metas);
+    account_metas.extend(
+        HyperlaneSealevelToken::<CollateralPlugin>::transfer_controls_account_meta(
+            program_id, &token,
+        )?
+        .map(SerializableAccountMeta::from),
+    );
 
     let bytes = borsh::to_vec(&SimulationReturnData::new(account_metas))
         .map_err(|_| ProgramError::BorshIoError)?;
@@ -45,8 +45,8 @@ use hyperlane_sealevel_token_lib::{
 };
 use hyperlane_test_utils::{
     assert_token_balance, assert_transaction_error, igp_program_id, initialize_igp_accounts,
-    initialize_mailbox, mailbox_id, new_funded_keypair, process, transfer_lamports, IgpAccounts,
-    MailboxAccounts,
+    initialize_mailbox, mailbox_id, new_funded_keypair, process, transfer_controls,
+    transfer_lamports, IgpAccounts, MailboxAccounts,
 };
 use hyperlane_warp_route::TokenMessage;
 use serializable_account_meta::{SerializableAccountMeta, SimulationReturnData};
@@ -656,6 +656,7 @@ mod init_instruction {
                     escrow_bump: ctx.cc.escrow_bump,
                     ata_payer_bump: ctx.cc.ata_payer_bump,
                 },
+                transfer_controls_bump: None,
             }),
         );
 
@@ -3114,10 +3115,12 @@ mod transfer_remote_to_instruction {
         );
         let result = ctx.banks_client.process_transaction(transaction).await;
 
-        // Custom(1) = TokenError::ExtraneousAccount
+        // Trailing accounts are passed on to the Mailbox as hook accounts, so with
+        // no hooks set the Mailbox rejects them.
+        // Custom(6) = MailboxError::ExtraneousAccount
         assert_transaction_error(
             result,
-            TransactionError::InstructionError(0, InstructionError::Custom(1)),
+            TransactionError::InstructionError(0, InstructionError::Custom(6)),
         );
     }
 
@@ -3245,6 +3248,215 @@ mod transfer_remote_to_instruction {
     }
 }
 
+mod transfer_controls_instructions {
+    use super::*;
+
+    const ONE_TOKEN: u64 = 10u64.pow(LOCAL_DECIMALS_U32);
+
+    struct TransferControlsTest {
+        ctx: TestContext,
+        remote_router: H256,
+        token_sender: Keypair,
+        token_sender_ata: Pubkey,
+    }
+
+    impl transfer_controls::TransferControlsTest for TransferControlsTest {
+        fn banks_client(&self) -> BanksClient {
+            self.ctx.banks_client.clone()
+        }
+
+        fn owner(&self) -> &Keypair {
+            &self.ctx.payer
+        }
+
+        fn program_id(&self) -> Pubkey {
+            self.ctx.program_id
+        }
+
+        fn remote_domain(&self) -> u32 {
+            REMOTE_DOMAIN
+        }
+
+        fn one_token(&self) -> u64 {
+            ONE_TOKEN
+        }
+
+        async fn transfer_remote_with_transfer_controls(
+            &mut self,
+            amount: u64,
+            transfer_controls_accounts: Vec<AccountMeta>,
+        ) -> Result<(), BanksClientError> {
+            let unique_message_account_keypair = Keypair::new();
+            let (dispatched_message_key, _) = Pubkey::find_program_address(
+                mailbox_dispatched_message_pda_seeds!(&unique_message_account_keypair.pubkey()),
+                &self.ctx.mailbox_program_id,
+            );
+
+            let ixn_data = HyperlaneTokenInstruction::TransferRemote(
+                hyperlane_sealevel_token_lib::instruction::TransferRemote {
+                    destination_domain: REMOTE_DOMAIN,
+                    recipient: H256::random(),
+                    amount_or_id: amount.into(),
+                },
+            )
+            .encode()
+            .unwrap();
+
+            let recent_blockhash = self.ctx.banks_client.get_latest_blockhash().await.unwrap();
+            let transaction = Transaction::new_signed_with_payer(
+                &[Instruction::new_with_bytes(
+                    self.ctx.program_id,
+                    &ixn_data,
+                    [
+                        vec![
+                            AccountMeta::new_readonly(system_program::ID, false),
+                            AccountMeta::new_readonly(account_utils::SPL_NOOP_PROGRAM_ID, false),
+                            AccountMeta::new_readonly(self.ctx.cc.token, false),
+                            AccountMeta::new_readonly(self.ctx.mailbox_program_id, false),
+                            AccountMeta::new(self.ctx.mailbox_accounts.outbox, false),
+                            AccountMeta::new_readonly(self.ctx.cc.dispatch_authority, false),
+                            AccountMeta::new(self.token_sender.pubkey(), true),
+                            AccountMeta::new_readonly(
+                                unique_message_account_keypair.pubkey(),
+                                true,
+                            ),
+                            AccountMeta::new(dispatched_message_key, false),
+                            AccountMeta::new_readonly(self.ctx.spl_token_program_id, false),
+                            AccountMeta::new(self.ctx.mint, false),
+                            AccountMeta::new(self.token_sender_ata, false),
+                            AccountMeta::new(self.ctx.cc.escrow, false),
+                        ],
+                        transfer_controls_accounts,
+                    ]
+                    .concat(),
+                )],
+                Some(&self.token_sender.pubkey()),
+                &[&self.token_sender, &unique_message_account_keypair],
+                recent_blockhash,
+            );
+            self.ctx.banks_client.process_transaction(transaction).await
+        }
+
+        async fn transfer_from_remote(
+            &mut self,
+            nonce: u32,
+            recipient: Pubkey,
+            local_amount: u64,
+        ) -> Result<(), BanksClientError> {
+            let remote_amount =
+                convert_decimals(local_amount.into(), LOCAL_DECIMALS, REMOTE_DECIMALS).unwrap();
+            let message = HyperlaneMessage {
+                version: 3,
+                nonce,
+                origin: REMOTE_DOMAIN,
+                sender: self.remote_router,
+                destination: LOCAL_DOMAIN,
+                recipient: self.ctx.program_id.to_bytes().into(),
+                body: TokenMessage::new(recipient.to_bytes().into(), remote_amount, vec![])
+                    .to_vec(),
+            };
+
+            process(
+                &mut self.ctx.banks_client,
+                &self.ctx.payer,
+                &self.ctx.mailbox_accounts,
+                vec![],
+                &message,
+            )
+            .await
+        }
+    }
+
+    async fn setup_transfer_controls_test() -> TransferControlsTest {
+        let mut ctx = TestContext::new(false).await;
+
+        let remote_router = H256::random();
+        enroll_remote_router(
+            &mut ctx.banks_client,
+            &ctx.program_id,
+            &ctx.payer,
+            &ctx.cc.token,
+            REMOTE_DOMAIN,
+            remote_router,
+        )
+        .await
+        .unwrap();
+
+        // Give the escrow account a balance to be used by transfer_from_remote.
+        let escrow = ctx.cc.escrow;
+        let ata_payer = ctx.cc.ata_payer;
+        ctx.fund_escrow_and_ata_payer(escrow, ata_payer, 100 * ONE_TOKEN)
+            .await;
+
+        let (token_sender, token_sender_ata) = ctx.create_funded_sender(100 * ONE_TOKEN).await;
+
+        TransferControlsTest {
+            ctx,
+            remote_router,
+            token_sender,
+            token_sender_ata,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_paused() {
+        transfer_controls::test_set_paused(setup_transfer_controls_test().await).await;
+    }
+
+    #[tokio::test]
+    async fn test_set_paused_errors_if_not_owner() {
+        transfer_controls::test_set_paused_errors_if_not_owner(
+            setup_transfer_controls_test().await,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_set_rate_limits() {
+        transfer_controls::test_set_rate_limits(setup_transfer_controls_test().await).await;
+    }
+
+    #[tokio::test]
+    async fn test_transfer_remote_errors_if_transfer_controls_omitted() {
+        transfer_controls::test_transfer_remote_errors_if_transfer_controls_omitted(
+            setup_transfer_controls_test().await,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_transfer_remote_errors_if_paused() {
+        transfer_controls::test_transfer_remote_errors_if_paused(
+            setup_transfer_controls_test().await,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_transfer_remote_errors_if_rate_limit_exceeded() {
+        transfer_controls::test_transfer_remote_errors_if_rate_limit_exceeded(
+            setup_transfer_controls_test().await,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_transfer_from_remote_errors_if_paused() {
+        transfer_controls::test_transfer_from_remote_errors_if_paused(
+            setup_transfer_controls_test().await,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_transfer_from_remote_errors_if_rate_limit_exceeded() {
+        transfer_controls::test_transfer_from_remote_errors_if_rate_limit_exceeded(
+            setup_transfer_controls_test().await,
+        )
+        .await;
+    }
+}
+
 mod account_metas_simulation {
     use super::*;
 
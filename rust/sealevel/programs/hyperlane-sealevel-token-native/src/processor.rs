@@ -9,7 +9,9 @@ use hyperlane_sealevel_message_recipient_interface::{
     HandleInstruction, MessageRecipientInstruction,
 };
 use hyperlane_sealevel_token_lib::{
-    instruction::{Init, Instruction as TokenIxn, TransferRemote},
+    instruction::{
+        Init, Instruction as TokenIxn, RateLimitConfig, TransferRemote, TransferRemoteWithHooks,
+    },
     processor::HyperlaneSealevelToken,
 };
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
@@ -62,7 +64,11 @@ pub fn process_instruction(
     // Otherwise, try decoding a "normal" token instruction
     match TokenIxn::decode(instruction_data)? {
         TokenIxn::Init(init) => initialize(program_id, accounts, init),
-        TokenIxn::TransferRemote(xfer) => transfer_remote(program_id, accounts, xfer),
+        TokenIxn::TransferRemote(xfer) => transfer_remote(program_id, accounts, xfer, vec![]),
+        TokenIxn::TransferRemoteWithHooks(TransferRemoteWithHooks {
+            transfer,
+            hook_account_counts,
+        }) => transfer_remote(program_id, accounts, transfer, hook_account_counts),
         TokenIxn::EnrollRemoteRouter(config) => enroll_remote_router(program_id, accounts, config),
         TokenIxn::EnrollRemoteRouters(configs) => {
             enroll_remote_routers(program_id, accounts, configs)
@@ -79,6 +85,8 @@ pub fn process_instruction(
         TokenIxn::SetInterchainGasPaymaster(new_igp) => {
             set_interchain_gas_paymaster(program_id, accounts, new_igp)
         }
+        TokenIxn::SetPaused(paused) => set_paused(program_id, accounts, paused),
+        TokenIxn::SetRateLimits(configs) => set_rate_limits(program_id, accounts, configs),
     }
     .map_err(|err| {
         msg!("{}", err);
@@ -121,12 +129,22 @@ fn initialize(program_id: &Pubkey, accounts: &[AccountInfo], init: Init) -> Prog
 ///      ---- End if ----
 /// 14.  `[executable]` The system program.
 /// 15.  `[writeable]` The native token collateral PDA account.
+/// 16.  `[writeable]` OPTIONAL - The transfer controls PDA account, if the
+///      owner has configured a transfer control.
+/// 17..N. `[??..??]` The Mailbox's hooks in the order they are invoked, each followed
+///      by the number of accounts given by `hook_account_counts`.
 fn transfer_remote(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     transfer: TransferRemote,
+    hook_account_counts: Vec<u8>,
 ) -> ProgramResult {
-    HyperlaneSealevelToken::<NativePlugin>::transfer_remote(program_id, accounts, transfer)
+    HyperlaneSealevelToken::<NativePlugin>::transfer_remote(
+        program_id,
+        accounts,
+        transfer,
+        hook_account_counts,
+    )
 }
 
 /// Accounts:
@@ -136,6 +154,8 @@ fn transfer_remote(
 /// 3.   `[writeable]` recipient wallet address
 /// 4.   `[executable]` The system program.
 /// 5.   `[writeable]` The native token collateral PDA account.
+/// 6.   `[writeable]` OPTIONAL - The transfer controls PDA account, if the
+///      owner has configured a transfer control.
 fn transfer_from_remote(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -260,3 +280,31 @@ fn set_interchain_gas_paymaster(
         program_id, accounts, new_igp,
     )
 }
+
+/// Lets the owner pause or unpause transfers.
+///
+/// Accounts:
+/// 0. `[executable]` The system program.
+/// 1. `[writeable]` The token PDA account.
+/// 2. `[writeable]` OPTIONAL - The transfer controls PDA account, if the
+///    owner has configured a transfer control.
+/// 3. `[signer]` The access control owner.
+fn set_paused(program_id: &Pubkey, accounts: &[AccountInfo], paused: bool) -> ProgramResult {
+    HyperlaneSealevelToken::<NativePlugin>::set_paused(program_id, accounts, paused)
+}
+
+/// Lets the owner set or remove the rate limits of remote domains.
+///
+/// Accounts:
+/// 0. `[executable]` The system program.
+/// 1. `[writeable]` The token PDA account.
+/// 2. `[writeable]` OPTIONAL - The transfer controls PDA account, if the
+///    owner has configured a transfer control.
+/// 3. `[signer]` The access control owner.
+fn set_rate_limits(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    configs: Vec<RateLimitConfig>,
+) -> ProgramResult {
+    HyperlaneSealevelToken::<NativePlugin>::set_rate_limits(program_id, accounts, configs)
+}
@@ -13,6 +13,7 @@ use hyperlane_sealevel_igp::{
 };
 use hyperlane_sealevel_mailbox::{
     accounts::{DispatchedMessage, DispatchedMessageAccount},
+    instruction::set_required_hook_instruction,
     mailbox_dispatched_message_pda_seeds, mailbox_message_dispatch_authority_pda_seeds,
     mailbox_process_authority_pda_seeds,
     protocol_fee::ProtocolFee,
@@ -20,6 +21,7 @@ use hyperlane_sealevel_mailbox::{
 use hyperlane_sealevel_message_recipient_interface::{
     HandleInstruction, MessageRecipientInstruction,
 };
+use hyperlane_sealevel_test_hook::test_client::TestHookTestClient;
 use hyperlane_sealevel_token::{
     hyperlane_token_ata_payer_pda_seeds, hyperlane_token_mint_pda_seeds, plugin::SyntheticPlugin,
     processor::process_instruction,
@@ -27,12 +29,14 @@ use hyperlane_sealevel_token::{
 use hyperlane_sealevel_token_lib::{
     accounts::{convert_decimals, HyperlaneToken, HyperlaneTokenAccount},
     hyperlane_token_pda_seeds,
-    instruction::{Init, Instruction as HyperlaneTokenInstruction, TransferRemote},
+    instruction::{
+        Init, Instruction as HyperlaneTokenInstruction, TransferRemote, TransferRemoteWithHooks,
+    },
 };
 use hyperlane_test_utils::{
     assert_token_balance, assert_transaction_error, igp_program_id, initialize_igp_accounts,
-    initialize_mailbox, mailbox_id, new_funded_keypair, process, transfer_lamports, IgpAccounts,
-    MailboxAccounts,
+    initialize_mailbox, mailbox_id, new_funded_keypair, process, process_instruction,
+    transfer_controls, transfer_lamports, IgpAccounts, MailboxAccounts,
 };
 use hyperlane_warp_route::TokenMessage;
 use solana_program::{
@@ -114,6 +118,12 @@ async fn setup_client() -> (BanksClient, Keypair) {
         processor!(hyperlane_sealevel_test_ism::program::process_instruction),
     );
 
+    program_test.add_program(
+        "hyperlane_sealevel_test_hook",
+        hyperlane_sealevel_test_hook::id(),
+        processor!(hyperlane_sealevel_test_hook::program::process_instruction),
+    );
+
     let (banks_client, payer, _recent_blockhash) = program_test.start().await;
 
     (banks_client, payer)
@@ -365,6 +375,7 @@ async fn test_initialize() {
                 mint_bump: hyperlane_token_accounts.mint_bump,
                 ata_payer_bump: hyperlane_token_accounts.ata_payer_bump,
             },
+            transfer_controls_bump: None,
         }),
     );
 
@@ -1373,3 +1384,325 @@ async fn test_set_interchain_gas_paymaster_errors_if_owner_not_signer() {
         TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature),
     );
 }
+
+/// One whole token, in local decimals.
+const ONE_TOKEN: u64 = 10u64.pow(LOCAL_DECIMALS_U32);
+
+struct TransferControlsTest {
+    banks_client: BanksClient,
+    payer: Keypair,
+    program_id: Pubkey,
+    mailbox_accounts: MailboxAccounts,
+    hyperlane_token_accounts: HyperlaneTokenAccounts,
+    remote_router: H256,
+    token_sender: Keypair,
+    token_sender_ata: Pubkey,
+}
+
+impl transfer_controls::TransferControlsTest for TransferControlsTest {
+    fn banks_client(&self) -> BanksClient {
+        self.banks_client.clone()
+    }
+
+    fn owner(&self) -> &Keypair {
+        &self.payer
+    }
+
+    fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    fn remote_domain(&self) -> u32 {
+        REMOTE_DOMAIN
+    }
+
+    fn one_token(&self) -> u64 {
+        ONE_TOKEN
+    }
+
+    async fn transfer_remote_with_transfer_controls(
+        &mut self,
+        amount: u64,
+        transfer_controls_accounts: Vec<AccountMeta>,
+    ) -> Result<(), BanksClientError> {
+        transfer_remote_with_accounts(self, amount, transfer_controls_accounts, vec![], vec![])
+            .await
+    }
+
+    async fn transfer_from_remote(
+        &mut self,
+        nonce: u32,
+        recipient: Pubkey,
+        local_amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let remote_amount =
+            convert_decimals(local_amount.into(), LOCAL_DECIMALS, REMOTE_DECIMALS).unwrap();
+        let message = HyperlaneMessage {
+            version: 3,
+            nonce,
+            origin: REMOTE_DOMAIN,
+            sender: self.remote_router,
+            destination: LOCAL_DOMAIN,
+            recipient: self.program_id.to_bytes().into(),
+            body: TokenMessage::new(recipient.to_bytes().into(), remote_amount, vec![]).to_vec(),
+        };
+
+        process(
+            &mut self.banks_client,
+            &self.payer,
+            &self.mailbox_accounts,
+            vec![],
+            &message,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// Sets up a token without an IGP and mints 100 tokens to a funded token sender.
+async fn setup_transfer_controls_test() -> TransferControlsTest {
+    let program_id = hyperlane_sealevel_token_id();
+    let mailbox_program_id = mailbox_id();
+
+    let (mut banks_client, payer) = setup_client().await;
+
+    let mailbox_accounts = initialize_mailbox(
+        &mut banks_client,
+        &mailbox_program_id,
+        &payer,
+        LOCAL_DOMAIN,
+        ONE_SOL_IN_LAMPORTS,
+        ProtocolFee::default(),
+    )
+    .await
+    .unwrap();
+
+    let hyperlane_token_accounts =
+        initialize_hyperlane_token(&program_id, &mut banks_client, &payer, None)
+            .await
+            .unwrap();
+    // ATA payer must have a balance to create new ATAs
+    transfer_lamports(
+        &mut banks_client,
+        &payer,
+        &hyperlane_token_accounts.ata_payer,
+        ONE_SOL_IN_LAMPORTS,
+    )
+    .await;
+
+    let remote_router = H256::random();
+    enroll_remote_router(
+        &mut banks_client,
+        &program_id,
+        &payer,
+        &hyperlane_token_accounts.token,
+        REMOTE_DOMAIN,
+        remote_router,
+    )
+    .await
+    .unwrap();
+
+    let token_sender = new_funded_keypair(&mut banks_client, &payer, ONE_SOL_IN_LAMPORTS).await;
+    let token_sender_ata =
+        spl_associated_token_account::get_associated_token_address_with_program_id(
+            &token_sender.pubkey(),
+            &hyperlane_token_accounts.mint,
+            &spl_token_2022::id(),
+        );
+
+    let mut test = TransferControlsTest {
+        banks_client,
+        payer,
+        program_id,
+        mailbox_accounts,
+        hyperlane_token_accounts,
+        remote_router,
+        token_sender,
+        token_sender_ata,
+    };
+
+    // Mint 100 tokens to the token sender's ATA by faking a transfer from remote.
+    let token_sender_pubkey = test.token_sender.pubkey();
+    transfer_controls::TransferControlsTest::transfer_from_remote(
+        &mut test,
+        0,
+        token_sender_pubkey,
+        100 * ONE_TOKEN,
+    )
+    .await
+    .unwrap();
+
+    test
+}
+
+/// Transfers remotely, appending `transfer_controls_accounts` and then `hook_accounts`
+/// after the plugin accounts, and using `TransferRemoteWithHooks` if
+/// `hook_account_counts` is not empty.
+async fn transfer_remote_with_accounts(
+    test: &mut TransferControlsTest,
+    amount: u64,
+    transfer_controls_accounts: Vec<AccountMeta>,
+    hook_accounts: Vec<AccountMeta>,
+    hook_account_counts: Vec<u8>,
+) -> Result<(), BanksClientError> {
+    let transfer = TransferRemote {
+        destination_domain: REMOTE_DOMAIN,
+        recipient: H256::random(),
+        amount_or_id: amount.into(),
+    };
+    let instruction = if hook_account_counts.is_empty() {
+        HyperlaneTokenInstruction::TransferRemote(transfer)
+    } else {
+        HyperlaneTokenInstruction::TransferRemoteWithHooks(TransferRemoteWithHooks {
+            transfer,
+            hook_account_counts,
+        })
+    };
+
+    let unique_message_account_keypair = Keypair::new();
+    let (dispatched_message_key, _dispatched_message_bump) = Pubkey::find_program_address(
+        mailbox_dispatched_message_pda_seeds!(&unique_message_account_keypair.pubkey()),
+        &mailbox_id(),
+    );
+
+    let recent_blockhash = test.banks_client.get_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[Instruction::new_with_bytes(
+            test.program_id,
+            &instruction.encode().unwrap(),
+            [
+                vec![
+                    AccountMeta::new_readonly(system_program::ID, false),
+                    AccountMeta::new_readonly(account_utils::SPL_NOOP_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(test.hyperlane_token_accounts.token, false),
+                    AccountMeta::new_readonly(test.mailbox_accounts.program, false),
+                    AccountMeta::new(test.mailbox_accounts.outbox, false),
+                    AccountMeta::new_readonly(
+                        test.hyperlane_token_accounts.dispatch_authority,
+                        false,
+                    ),
+                    AccountMeta::new_readonly(test.token_sender.pubkey(), true),
+                    AccountMeta::new_readonly(unique_message_account_keypair.pubkey(), true),
+                    AccountMeta::new(dispatched_message_key, false),
+                    AccountMeta::new_readonly(spl_token_2022::id(), false),
+                    AccountMeta::new(test.hyperlane_token_accounts.mint, false),
+                    AccountMeta::new(test.token_sender_ata, false),
+                ],
+                transfer_controls_accounts,
+                hook_accounts,
+            ]
+            .concat(),
+        )],
+        Some(&test.token_sender.pubkey()),
+        &[&test.token_sender, &unique_message_account_keypair],
+        recent_blockhash,
+    );
+    test.banks_client.process_transaction(transaction).await
+}
+
+#[tokio::test]
+async fn test_set_paused() {
+    transfer_controls::test_set_paused(setup_transfer_controls_test().await).await;
+}
+
+#[tokio::test]
+async fn test_set_paused_errors_if_not_owner() {
+    transfer_controls::test_set_paused_errors_if_not_owner(setup_transfer_controls_test().await)
+        .await;
+}
+
+#[tokio::test]
+async fn test_set_rate_limits() {
+    transfer_controls::test_set_rate_limits(setup_transfer_controls_test().await).await;
+}
+
+#[tokio::test]
+async fn test_transfer_remote_errors_if_transfer_controls_omitted() {
+    transfer_controls::test_transfer_remote_errors_if_transfer_controls_omitted(
+        setup_transfer_controls_test().await,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_transfer_remote_errors_if_paused() {
+    transfer_controls::test_transfer_remote_errors_if_paused(setup_transfer_controls_test().await)
+        .await;
+}
+
+#[tokio::test]
+async fn test_transfer_remote_errors_if_rate_limit_exceeded() {
+    transfer_controls::test_transfer_remote_errors_if_rate_limit_exceeded(
+        setup_transfer_controls_test().await,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_transfer_from_remote_errors_if_paused() {
+    transfer_controls::test_transfer_from_remote_errors_if_paused(
+        setup_transfer_controls_test().await,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_transfer_from_remote_errors_if_rate_limit_exceeded() {
+    transfer_controls::test_transfer_from_remote_errors_if_rate_limit_exceeded(
+        setup_transfer_controls_test().await,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_transfer_remote_invokes_mailbox_hooks() {
+    let mut test = setup_transfer_controls_test().await;
+
+    let mut test_hook = TestHookTestClient::new(
+        test.banks_client.clone(),
+        test.payer.insecure_clone(),
+        hyperlane_sealevel_test_hook::id(),
+    );
+    test_hook.init().await.unwrap();
+    process_instruction(
+        &mut test.banks_client,
+        set_required_hook_instruction(
+            test.mailbox_accounts.program,
+            test.payer.pubkey(),
+            Some(test_hook.id()),
+        )
+        .unwrap(),
+        &test.payer,
+        &[&test.payer],
+    )
+    .await
+    .unwrap();
+
+    // The required hook can't be skipped by omitting its accounts.
+    let result = transfer_controls::transfer_remote(&mut test, ONE_TOKEN).await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys),
+    );
+
+    transfer_remote_with_accounts(
+        &mut test,
+        ONE_TOKEN,
+        vec![],
+        test_hook.hook_account_metas(),
+        vec![1],
+    )
+    .await
+    .unwrap();
+
+    assert_token_balance(
+        &mut test.banks_client,
+        &test.token_sender_ata,
+        99 * ONE_TOKEN,
+    )
+    .await;
+    let storage = test_hook.storage().await.unwrap();
+    assert_eq!(storage.dispatch_count, 1);
+    assert!(storage.last_outbox_signed);
+}
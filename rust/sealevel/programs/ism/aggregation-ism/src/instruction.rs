@@ -0,0 +1,235 @@
+use account_utils::{DiscriminatorData, DiscriminatorEncode, PROGRAM_INSTRUCTION_DISCRIMINATOR};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction as SolanaInstruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_system_interface::program as system_program;
+
+use std::collections::HashSet;
+
+use crate::{access_control_pda_seeds, error::Error, modules_and_threshold_pda_seeds};
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub enum Instruction {
+    /// Initializes the program.
+    ///
+    /// Accounts:
+    /// 0. `[signer]` The new owner and payer of the access control PDA.
+    /// 1. `[writable]` The access control PDA account.
+    /// 2. `[executable]` The system program account.
+    Initialize,
+    /// Input: modules & threshold to set.
+    ///
+    /// Accounts:
+    /// 0. `[signer]` The access control owner and payer of the modules and threshold PDA.
+    /// 1. `[]` The access control PDA account.
+    /// 2. `[writable]` The modules and threshold PDA account.
+    /// 3. `[executable]` OPTIONAL - The system program account. Required if creating the modules and threshold PDA.
+    SetModulesAndThreshold(ModulesAndThreshold),
+    /// Gets the modules and threshold.
+    ///
+    /// Accounts:
+    /// 0. `[]` The modules and threshold PDA account.
+    GetModulesAndThreshold,
+    /// Gets the owner from the access control data.
+    ///
+    /// Accounts:
+    /// 0. `[]` The access control PDA account.
+    GetOwner,
+    /// Sets the owner in the access control data.
+    ///
+    /// Accounts:
+    /// 0. `[signer]` The current access control owner.
+    /// 1. `[]` The access control PDA account.
+    TransferOwnership(Option<Pubkey>),
+}
+
+impl DiscriminatorData for Instruction {
+    const DISCRIMINATOR: [u8; Self::DISCRIMINATOR_LENGTH] = PROGRAM_INSTRUCTION_DISCRIMINATOR;
+}
+
+impl TryFrom<&[u8]> for Instruction {
+    type Error = ProgramError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        Self::try_from_slice(data).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+/// A configuration of the aggregated modules and threshold.
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq, Default, Clone)]
+pub struct ModulesAndThreshold {
+    /// The program IDs of the aggregated ISMs.
+    pub modules: Vec<Pubkey>,
+    /// The number of modules that must verify a message.
+    pub threshold: u8,
+}
+
+impl ModulesAndThreshold {
+    /// Validates the modules and threshold.
+    /// Returns an error if the set is empty, the threshold is zero, the threshold exceeds the
+    /// number of modules, or if the module set has any duplicates.
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let modules_len = self.modules.len();
+
+        // Ensure the threshold is non-zero and doesn't exceed the number of modules.
+        if self.threshold == 0 || self.threshold as usize > modules_len {
+            return Err(Error::InvalidModulesAndThreshold.into());
+        }
+
+        // If the set has any duplicates, error.
+        // Duplicates would also make the accounts passed to `Verify` ambiguous.
+        let mut set = HashSet::with_capacity(modules_len);
+        for module in &self.modules {
+            if !set.insert(module) {
+                return Err(Error::InvalidModulesAndThreshold.into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn init_instruction(
+    program_id: Pubkey,
+    payer: Pubkey,
+) -> Result<SolanaInstruction, ProgramError> {
+    let (access_control_pda_key, _access_control_pda_bump) =
+        Pubkey::try_find_program_address(access_control_pda_seeds!(), &program_id)
+            .ok_or(ProgramError::InvalidSeeds)?;
+
+    let ixn = Instruction::Initialize;
+
+    // Accounts:
+    // 0. `[signer]` The new owner and payer of the access control PDA.
+    // 1. `[writable]` The access control PDA account.
+    // 2. `[executable]` The system program account.
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new(access_control_pda_key, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+
+    let instruction = SolanaInstruction {
+        program_id,
+        data: ixn.encode()?,
+        accounts,
+    };
+
+    Ok(instruction)
+}
+
+/// Creates a TransferOwnership instruction.
+pub fn transfer_ownership_instruction(
+    program_id: Pubkey,
+    owner_payer: Pubkey,
+    new_owner: Option<Pubkey>,
+) -> Result<SolanaInstruction, ProgramError> {
+    let (access_control_pda_key, _access_control_pda_bump) =
+        Pubkey::try_find_program_address(access_control_pda_seeds!(), &program_id)
+            .ok_or(ProgramError::InvalidSeeds)?;
+
+    // 0. `[signer]` The current access control owner.
+    // 1. `[writeable]` The access control PDA account.
+    let instruction = SolanaInstruction {
+        program_id,
+        data: Instruction::TransferOwnership(new_owner).encode()?,
+        accounts: vec![
+            AccountMeta::new(owner_payer, true),
+            AccountMeta::new(access_control_pda_key, false),
+        ],
+    };
+    Ok(instruction)
+}
+
+/// Creates a SetModulesAndThreshold instruction.
+pub fn set_modules_and_threshold_instruction(
+    program_id: Pubkey,
+    owner_payer: Pubkey,
+    modules_and_threshold: ModulesAndThreshold,
+) -> Result<SolanaInstruction, ProgramError> {
+    let (access_control_pda_key, _access_control_pda_bump) =
+        Pubkey::try_find_program_address(access_control_pda_seeds!(), &program_id)
+            .ok_or(ProgramError::InvalidSeeds)?;
+
+    let (modules_and_threshold_pda_key, _modules_and_threshold_pda_bump) =
+        Pubkey::try_find_program_address(modules_and_threshold_pda_seeds!(), &program_id)
+            .ok_or(ProgramError::InvalidSeeds)?;
+
+    let ixn = Instruction::SetModulesAndThreshold(modules_and_threshold);
+
+    // Accounts:
+    // 0. `[signer]` The access control owner and payer of the modules and threshold PDA.
+    // 1. `[]` The access control PDA account.
+    // 2. `[writable]` The modules and threshold PDA account.
+    // 3. `[executable]` OPTIONAL - The system program account. Required if creating the modules and threshold PDA.
+    let accounts = vec![
+        AccountMeta::new(owner_payer, true),
+        AccountMeta::new_readonly(access_control_pda_key, false),
+        AccountMeta::new(modules_and_threshold_pda_key, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+
+    let instruction = SolanaInstruction {
+        program_id,
+        data: ixn.encode()?,
+        accounts,
+    };
+    Ok(instruction)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_modules_and_threshold_validate_success() {
+        let m = ModulesAndThreshold {
+            modules: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+            threshold: 1,
+        };
+        assert!(m.validate().is_ok());
+
+        // Threshold equals module set size
+        let m = ModulesAndThreshold {
+            modules: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+            threshold: 2,
+        };
+        assert!(m.validate().is_ok());
+    }
+
+    #[test]
+    fn test_modules_and_threshold_validate_errors() {
+        let module = Pubkey::new_unique();
+
+        for m in [
+            // Threshold 0 and modules empty
+            ModulesAndThreshold {
+                modules: vec![],
+                threshold: 0,
+            },
+            // Threshold 0 and modules not empty
+            ModulesAndThreshold {
+                modules: vec![module],
+                threshold: 0,
+            },
+            // Threshold exceeds module set size
+            ModulesAndThreshold {
+                modules: vec![module],
+                threshold: 2,
+            },
+            // Module set has duplicates
+            ModulesAndThreshold {
+                modules: vec![module, module],
+                threshold: 2,
+            },
+        ] {
+            assert_eq!(
+                m.validate().unwrap_err(),
+                Error::InvalidModulesAndThreshold.into()
+            );
+        }
+    }
+}
@@ -0,0 +1,12 @@
+//! An Interchain Security Module that aggregates other Interchain Security Modules.
+//! A message is verified if at least the threshold of the configured modules verify it.
+
+#![allow(unexpected_cfgs)]
+#![deny(warnings)]
+#![deny(unsafe_code)]
+
+pub mod accounts;
+pub mod error;
+pub mod instruction;
+pub mod metadata;
+pub mod processor;
@@ -0,0 +1,121 @@
+use crate::error::Error;
+
+/// Bytes used to store one member of the (start, end) range tuple.
+const RANGE_SIZE: usize = 4;
+
+/// Metadata of the aggregation ISM, holding the metadata of each module.
+///
+/// Format of metadata, matching `AggregationIsmMetadata.sol`:
+/// [????:????] Metadata start/end uint32 ranges, packed as uint64, one per module
+/// [????:????] Module metadata, packed encoding
+/// A start of zero means that no metadata was provided for the module.
+#[derive(Debug, PartialEq)]
+pub struct AggregationIsmMetadata {
+    bytes: Vec<u8>,
+}
+
+impl From<Vec<u8>> for AggregationIsmMetadata {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+}
+
+impl AggregationIsmMetadata {
+    /// Encodes the metadata of each module. Modules without metadata are `None`.
+    pub fn encode(module_metadata: &[Option<Vec<u8>>]) -> Self {
+        let ranges_size = module_metadata.len() * RANGE_SIZE * 2;
+        let mut bytes = vec![0; ranges_size];
+
+        for (index, metadata) in module_metadata.iter().enumerate() {
+            let Some(metadata) = metadata else {
+                continue;
+            };
+            let start = bytes.len();
+            bytes.extend_from_slice(metadata);
+            let end = bytes.len();
+
+            let range_offset = index * RANGE_SIZE * 2;
+            bytes[range_offset..range_offset + RANGE_SIZE]
+                .copy_from_slice(&(start as u32).to_be_bytes());
+            bytes[range_offset + RANGE_SIZE..range_offset + RANGE_SIZE * 2]
+                .copy_from_slice(&(end as u32).to_be_bytes());
+        }
+
+        Self { bytes }
+    }
+
+    /// Returns the metadata of the module at `index`, or `None` if no metadata
+    /// was provided for it.
+    pub fn module_metadata(&self, index: usize) -> Result<Option<&[u8]>, Error> {
+        let start = self.read_range_member(index * RANGE_SIZE * 2)?;
+        if start == 0 {
+            return Ok(None);
+        }
+        let end = self.read_range_member(index * RANGE_SIZE * 2 + RANGE_SIZE)?;
+
+        self.bytes
+            .get(start..end)
+            .map(Some)
+            .ok_or(Error::InvalidMetadata)
+    }
+
+    /// Returns the encoded metadata.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn read_range_member(&self, offset: usize) -> Result<usize, Error> {
+        let bytes: [u8; RANGE_SIZE] = self
+            .bytes
+            .get(offset..offset + RANGE_SIZE)
+            .ok_or(Error::InvalidMetadata)?
+            .try_into()
+            .map_err(|_| Error::InvalidMetadata)?;
+        Ok(u32::from_be_bytes(bytes) as usize)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() {
+        let metadata =
+            AggregationIsmMetadata::encode(&[Some(vec![1, 2, 3]), None, Some(vec![4, 5])]);
+
+        assert_eq!(metadata.module_metadata(0).unwrap(), Some(&[1, 2, 3][..]));
+        assert_eq!(metadata.module_metadata(1).unwrap(), None);
+        assert_eq!(metadata.module_metadata(2).unwrap(), Some(&[4, 5][..]));
+    }
+
+    #[test]
+    fn test_encoding_matches_solidity() {
+        let metadata = AggregationIsmMetadata::encode(&[None, Some(vec![0xab, 0xcd])]);
+
+        assert_eq!(
+            metadata.as_bytes(),
+            &[
+                // module 0 has no metadata
+                0, 0, 0, 0, 0, 0, 0, 0, //
+                // module 1 metadata is at [16:18]
+                0, 0, 0, 16, 0, 0, 0, 18, //
+                0xab, 0xcd,
+            ][..]
+        );
+    }
+
+    #[test]
+    fn test_decode_out_of_range_is_err() {
+        let metadata = AggregationIsmMetadata::encode(&[Some(vec![1, 2, 3])]);
+
+        // There is no range for a second module
+        assert_eq!(metadata.module_metadata(1), Err(Error::InvalidMetadata));
+
+        // The range exceeds the metadata
+        let mut bytes = metadata.as_bytes().to_vec();
+        bytes.pop();
+        let metadata = AggregationIsmMetadata::from(bytes);
+        assert_eq!(metadata.module_metadata(0), Err(Error::InvalidMetadata));
+    }
+}
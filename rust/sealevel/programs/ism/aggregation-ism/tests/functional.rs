@@ -0,0 +1,516 @@
+//! Contains functional tests for things that cannot be done
+//! strictly in unit tests. This includes CPIs, like creating
+//! new PDA accounts and calling into the aggregated modules.
+
+use account_utils::DiscriminatorEncode;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey,
+    pubkey::Pubkey,
+};
+use solana_system_interface::program as system_program;
+
+use hyperlane_core::{Encode, HyperlaneMessage, ModuleType, H256};
+use hyperlane_sealevel_aggregation_ism::{
+    access_control_pda_seeds,
+    accounts::{ModulesAndThresholdAccount, ModulesAndThresholdData},
+    error::Error as AggregationIsmError,
+    instruction::{
+        init_instruction, set_modules_and_threshold_instruction,
+        Instruction as AggregationIsmProgramInstruction, ModulesAndThreshold,
+    },
+    metadata::AggregationIsmMetadata,
+    modules_and_threshold_pda_seeds,
+    processor::process_instruction,
+};
+use hyperlane_sealevel_interchain_security_module_interface::{
+    InterchainSecurityModuleInstruction, VerifyInstruction, VERIFY_ACCOUNT_METAS_PDA_SEEDS,
+};
+use hyperlane_sealevel_test_ism::{program::TestIsmInstruction, test_ism_storage_pda_seeds};
+use hyperlane_test_utils::{
+    assert_transaction_error, get_account_metas, new_funded_keypair,
+    process_instruction as process, simulate_instruction,
+};
+use serializable_account_meta::SimulationReturnData;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, signature::Signer, signer::keypair::Keypair,
+    transaction::TransactionError,
+};
+
+/// The error returned by the test ISM when it does not accept a message.
+const TEST_ISM_VERIFY_NOT_ACCEPTED: u32 = 69420;
+
+fn aggregation_ism_id() -> Pubkey {
+    pubkey!("7FkCeHZw5Yb3p9sRMQbTpDuCkMzR3jcQBqFrVHpyZ4k3")
+}
+
+fn test_ism_ids() -> [Pubkey; 3] {
+    [
+        pubkey!("9gxVHJ5tM7WjS4kCcXL2bRLLdPz3YxYi9DNk7eJPtmW2"),
+        pubkey!("AqJdq2zGvM2ZqUTsAQnzk8AJb6H1tF5ScLhJ3c9rQkVd"),
+        pubkey!("FQ1BkxFmHv6XtAMxRdrGvUZpZH5ZmQTrxMhvqkN1F9Lz"),
+    ]
+}
+
+fn test_ism_storage_pda_key(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(test_ism_storage_pda_seeds!(), program_id).0
+}
+
+fn modules_and_threshold_pda_key() -> Pubkey {
+    Pubkey::find_program_address(modules_and_threshold_pda_seeds!(), &aggregation_ism_id()).0
+}
+
+fn test_message() -> HyperlaneMessage {
+    HyperlaneMessage {
+        version: 3,
+        nonce: 0,
+        origin: 1234,
+        sender: H256::random(),
+        destination: 4321,
+        recipient: H256::random(),
+        body: vec![1, 2, 3, 4, 5],
+    }
+}
+
+async fn setup_client() -> (BanksClient, Keypair) {
+    let mut program_test = ProgramTest::new(
+        "hyperlane_sealevel_aggregation_ism",
+        aggregation_ism_id(),
+        processor!(process_instruction),
+    );
+    for test_ism_id in test_ism_ids() {
+        program_test.add_program(
+            "hyperlane_sealevel_test_ism",
+            test_ism_id,
+            processor!(hyperlane_sealevel_test_ism::program::process_instruction),
+        );
+    }
+
+    let (mut banks_client, payer, _recent_blockhash) = program_test.start().await;
+
+    // Initialize the test ISMs, which accept all messages by default.
+    for test_ism_id in test_ism_ids() {
+        process(
+            &mut banks_client,
+            Instruction::new_with_borsh(
+                test_ism_id,
+                &TestIsmInstruction::Init,
+                vec![
+                    AccountMeta::new_readonly(system_program::ID, false),
+                    AccountMeta::new(payer.pubkey(), true),
+                    AccountMeta::new(test_ism_storage_pda_key(&test_ism_id), false),
+                ],
+            ),
+            &payer,
+            &[&payer],
+        )
+        .await
+        .unwrap();
+    }
+
+    (banks_client, payer)
+}
+
+async fn initialize_with_modules_and_threshold(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    modules_and_threshold: ModulesAndThreshold,
+) {
+    let program_id = aggregation_ism_id();
+
+    process(
+        banks_client,
+        init_instruction(program_id, payer.pubkey()).unwrap(),
+        payer,
+        &[payer],
+    )
+    .await
+    .unwrap();
+
+    process(
+        banks_client,
+        set_modules_and_threshold_instruction(program_id, payer.pubkey(), modules_and_threshold)
+            .unwrap(),
+        payer,
+        &[payer],
+    )
+    .await
+    .unwrap();
+}
+
+async fn set_test_ism_accept(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    test_ism_id: Pubkey,
+    accept: bool,
+) {
+    process(
+        banks_client,
+        Instruction::new_with_borsh(
+            test_ism_id,
+            &TestIsmInstruction::SetAccept(accept),
+            vec![AccountMeta::new(
+                test_ism_storage_pda_key(&test_ism_id),
+                false,
+            )],
+        ),
+        payer,
+        &[payer],
+    )
+    .await
+    .unwrap();
+}
+
+/// Builds a Verify instruction providing metadata for the modules at `indices`.
+fn verify_instruction(message: &HyperlaneMessage, indices: &[usize]) -> Instruction {
+    let test_ism_ids = test_ism_ids();
+
+    let module_metadata: Vec<_> = (0..test_ism_ids.len())
+        .map(|index| indices.contains(&index).then(|| vec![index as u8]))
+        .collect();
+    let metadata = AggregationIsmMetadata::encode(&module_metadata);
+
+    let mut accounts = vec![AccountMeta::new_readonly(
+        modules_and_threshold_pda_key(),
+        false,
+    )];
+    for index in indices {
+        let test_ism_id = test_ism_ids[*index];
+        accounts.push(AccountMeta::new_readonly(test_ism_id, false));
+        accounts.push(AccountMeta::new_readonly(
+            test_ism_storage_pda_key(&test_ism_id),
+            false,
+        ));
+    }
+
+    Instruction::new_with_bytes(
+        aggregation_ism_id(),
+        &InterchainSecurityModuleInstruction::Verify(VerifyInstruction {
+            metadata: metadata.as_bytes().to_vec(),
+            message: message.to_vec(),
+        })
+        .encode()
+        .unwrap(),
+        accounts,
+    )
+}
+
+#[tokio::test]
+async fn test_set_modules_and_threshold() {
+    let program_id = aggregation_ism_id();
+    let (mut banks_client, payer) = setup_client().await;
+
+    let modules_and_threshold = ModulesAndThreshold {
+        modules: test_ism_ids().to_vec(),
+        threshold: 2,
+    };
+    initialize_with_modules_and_threshold(&mut banks_client, &payer, modules_and_threshold.clone())
+        .await;
+
+    let (modules_and_threshold_pda_key, modules_and_threshold_pda_bump_seed) =
+        Pubkey::find_program_address(modules_and_threshold_pda_seeds!(), &program_id);
+    let account_data = banks_client
+        .get_account(modules_and_threshold_pda_key)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let data = ModulesAndThresholdAccount::fetch_data(&mut &account_data[..])
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        data,
+        Box::new(ModulesAndThresholdData {
+            bump_seed: modules_and_threshold_pda_bump_seed,
+            modules_and_threshold: modules_and_threshold.clone(),
+        }),
+    );
+
+    // The modules and threshold can also be queried
+    let returned = simulate_instruction::<SimulationReturnData<ModulesAndThreshold>>(
+        &mut banks_client,
+        &payer,
+        Instruction::new_with_bytes(
+            program_id,
+            &AggregationIsmProgramInstruction::GetModulesAndThreshold
+                .encode()
+                .unwrap(),
+            vec![AccountMeta::new_readonly(
+                modules_and_threshold_pda_key,
+                false,
+            )],
+        ),
+    )
+    .await
+    .unwrap()
+    .unwrap()
+    .return_data;
+    assert_eq!(returned, modules_and_threshold);
+}
+
+#[tokio::test]
+async fn test_set_modules_and_threshold_errors_if_not_owner() {
+    let program_id = aggregation_ism_id();
+    let (mut banks_client, payer) = setup_client().await;
+
+    initialize_with_modules_and_threshold(
+        &mut banks_client,
+        &payer,
+        ModulesAndThreshold {
+            modules: test_ism_ids().to_vec(),
+            threshold: 2,
+        },
+    )
+    .await;
+
+    let non_owner = new_funded_keypair(&mut banks_client, &payer, 1000000000).await;
+    let result = process(
+        &mut banks_client,
+        set_modules_and_threshold_instruction(
+            program_id,
+            non_owner.pubkey(),
+            ModulesAndThreshold {
+                modules: test_ism_ids().to_vec(),
+                threshold: 1,
+            },
+        )
+        .unwrap(),
+        &non_owner,
+        &[&non_owner],
+    )
+    .await;
+
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument),
+    );
+}
+
+#[tokio::test]
+async fn test_type_and_verify_account_metas() {
+    let program_id = aggregation_ism_id();
+    let (mut banks_client, payer) = setup_client().await;
+
+    let module_type = simulate_instruction::<SimulationReturnData<u32>>(
+        &mut banks_client,
+        &payer,
+        Instruction::new_with_bytes(
+            program_id,
+            &InterchainSecurityModuleInstruction::Type.encode().unwrap(),
+            vec![],
+        ),
+    )
+    .await
+    .unwrap()
+    .unwrap()
+    .return_data;
+    assert_eq!(module_type, ModuleType::Aggregation as u32);
+
+    let (verify_account_metas_pda_key, _) =
+        Pubkey::find_program_address(VERIFY_ACCOUNT_METAS_PDA_SEEDS, &program_id);
+    let account_metas = get_account_metas(
+        &mut banks_client,
+        &payer,
+        Instruction::new_with_bytes(
+            program_id,
+            &InterchainSecurityModuleInstruction::VerifyAccountMetas(VerifyInstruction {
+                metadata: vec![],
+                message: test_message().to_vec(),
+            })
+            .encode()
+            .unwrap(),
+            vec![AccountMeta::new_readonly(
+                verify_account_metas_pda_key,
+                false,
+            )],
+        ),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        account_metas,
+        vec![AccountMeta::new_readonly(
+            modules_and_threshold_pda_key(),
+            false
+        )]
+    );
+}
+
+#[tokio::test]
+async fn test_verify() {
+    let (mut banks_client, payer) = setup_client().await;
+
+    initialize_with_modules_and_threshold(
+        &mut banks_client,
+        &payer,
+        ModulesAndThreshold {
+            modules: test_ism_ids().to_vec(),
+            threshold: 2,
+        },
+    )
+    .await;
+
+    // Metadata for the first and last module, skipping the one in between
+    process(
+        &mut banks_client,
+        verify_instruction(&test_message(), &[0, 2]),
+        &payer,
+        &[&payer],
+    )
+    .await
+    .unwrap();
+
+    // Metadata for all modules
+    process(
+        &mut banks_client,
+        verify_instruction(&test_message(), &[0, 1, 2]),
+        &payer,
+        &[&payer],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_verify_errors_if_threshold_not_met() {
+    let (mut banks_client, payer) = setup_client().await;
+
+    initialize_with_modules_and_threshold(
+        &mut banks_client,
+        &payer,
+        ModulesAndThreshold {
+            modules: test_ism_ids().to_vec(),
+            threshold: 2,
+        },
+    )
+    .await;
+
+    let result = process(
+        &mut banks_client,
+        verify_instruction(&test_message(), &[1]),
+        &payer,
+        &[&payer],
+    )
+    .await;
+
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AggregationIsmError::ThresholdNotMet as u32),
+        ),
+    );
+}
+
+#[tokio::test]
+async fn test_verify_errors_if_module_rejects() {
+    let (mut banks_client, payer) = setup_client().await;
+
+    initialize_with_modules_and_threshold(
+        &mut banks_client,
+        &payer,
+        ModulesAndThreshold {
+            modules: test_ism_ids().to_vec(),
+            threshold: 2,
+        },
+    )
+    .await;
+    set_test_ism_accept(&mut banks_client, &payer, test_ism_ids()[1], false).await;
+
+    let result = process(
+        &mut banks_client,
+        verify_instruction(&test_message(), &[0, 1]),
+        &payer,
+        &[&payer],
+    )
+    .await;
+
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TEST_ISM_VERIFY_NOT_ACCEPTED),
+        ),
+    );
+
+    // Without metadata for the rejecting module, the message is verified
+    process(
+        &mut banks_client,
+        verify_instruction(&test_message(), &[0, 2]),
+        &payer,
+        &[&payer],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_verify_errors_if_module_accounts_out_of_order() {
+    let (mut banks_client, payer) = setup_client().await;
+
+    initialize_with_modules_and_threshold(
+        &mut banks_client,
+        &payer,
+        ModulesAndThreshold {
+            modules: test_ism_ids().to_vec(),
+            threshold: 2,
+        },
+    )
+    .await;
+
+    let mut instruction = verify_instruction(&test_message(), &[0, 2]);
+    // Swap the first module with the last one
+    instruction.accounts.swap(1, 3);
+
+    let result = process(&mut banks_client, instruction, &payer, &[&payer]).await;
+
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AggregationIsmError::AccountOutOfOrder as u32),
+        ),
+    );
+}
+
+#[tokio::test]
+async fn test_initialize_errors_if_called_twice() {
+    let program_id = aggregation_ism_id();
+    let (mut banks_client, payer) = setup_client().await;
+
+    process(
+        &mut banks_client,
+        init_instruction(program_id, payer.pubkey()).unwrap(),
+        &payer,
+        &[&payer],
+    )
+    .await
+    .unwrap();
+
+    let (access_control_pda_key, _) =
+        Pubkey::find_program_address(access_control_pda_seeds!(), &program_id);
+    assert!(banks_client
+        .get_account(access_control_pda_key)
+        .await
+        .unwrap()
+        .is_some());
+
+    // Use a new payer to get a new tx ID, because the instruction data is the same
+    let new_payer = new_funded_keypair(&mut banks_client, &payer, 1000000000).await;
+    let result = process(
+        &mut banks_client,
+        init_instruction(program_id, new_payer.pubkey()).unwrap(),
+        &new_payer,
+        &[&new_payer],
+    )
+    .await;
+
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AggregationIsmError::AlreadyInitialized as u32),
+        ),
+    );
+}
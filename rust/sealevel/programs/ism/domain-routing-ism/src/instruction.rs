@@ -0,0 +1,155 @@
+use account_utils::{DiscriminatorData, DiscriminatorEncode, PROGRAM_INSTRUCTION_DISCRIMINATOR};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction as SolanaInstruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_system_interface::program as system_program;
+
+use crate::{access_control_pda_seeds, domain_data_pda_seeds};
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub enum Instruction {
+    /// Initializes the program.
+    ///
+    /// Accounts:
+    /// 0. `[signer]` The new owner and payer of the access control PDA.
+    /// 1. `[writable]` The access control PDA account.
+    /// 2. `[executable]` The system program account.
+    Initialize,
+    /// Input: domain ID, ISM to route messages from the domain to.
+    /// Setting the ISM to None removes the route.
+    ///
+    /// Accounts:
+    /// 0. `[signer]` The access control owner and payer of the domain PDA.
+    /// 1. `[]` The access control PDA account.
+    /// 2. `[writable]` The PDA relating to the provided domain.
+    /// 3. `[executable]` OPTIONAL - The system program account. Required if creating the domain PDA.
+    SetDomainIsm(Domained<Option<Pubkey>>),
+    /// Input: domain ID.
+    /// Gets the ISM that messages from the domain are routed to.
+    ///
+    /// Accounts:
+    /// 0. `[]` The PDA relating to the provided domain.
+    Route(u32),
+    /// Gets the owner from the access control data.
+    ///
+    /// Accounts:
+    /// 0. `[]` The access control PDA account.
+    GetOwner,
+    /// Sets the owner in the access control data.
+    ///
+    /// Accounts:
+    /// 0. `[signer]` The current access control owner.
+    /// 1. `[]` The access control PDA account.
+    TransferOwnership(Option<Pubkey>),
+}
+
+impl DiscriminatorData for Instruction {
+    const DISCRIMINATOR: [u8; Self::DISCRIMINATOR_LENGTH] = PROGRAM_INSTRUCTION_DISCRIMINATOR;
+}
+
+impl TryFrom<&[u8]> for Instruction {
+    type Error = ProgramError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        Self::try_from_slice(data).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+/// Holds data relating to a specific domain.
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq, Clone)]
+pub struct Domained<T> {
+    pub domain: u32,
+    pub data: T,
+}
+
+pub fn init_instruction(
+    program_id: Pubkey,
+    payer: Pubkey,
+) -> Result<SolanaInstruction, ProgramError> {
+    let (access_control_pda_key, _access_control_pda_bump) =
+        Pubkey::try_find_program_address(access_control_pda_seeds!(), &program_id)
+            .ok_or(ProgramError::InvalidSeeds)?;
+
+    let ixn = Instruction::Initialize;
+
+    // Accounts:
+    // 0. `[signer]` The new owner and payer of the access control PDA.
+    // 1. `[writable]` The access control PDA account.
+    // 2. `[executable]` The system program account.
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new(access_control_pda_key, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+
+    let instruction = SolanaInstruction {
+        program_id,
+        data: ixn.encode()?,
+        accounts,
+    };
+
+    Ok(instruction)
+}
+
+/// Creates a TransferOwnership instruction.
+pub fn transfer_ownership_instruction(
+    program_id: Pubkey,
+    owner_payer: Pubkey,
+    new_owner: Option<Pubkey>,
+) -> Result<SolanaInstruction, ProgramError> {
+    let (access_control_pda_key, _access_control_pda_bump) =
+        Pubkey::try_find_program_address(access_control_pda_seeds!(), &program_id)
+            .ok_or(ProgramError::InvalidSeeds)?;
+
+    // 0. `[signer]` The current access control owner.
+    // 1. `[writeable]` The access control PDA account.
+    let instruction = SolanaInstruction {
+        program_id,
+        data: Instruction::TransferOwnership(new_owner).encode()?,
+        accounts: vec![
+            AccountMeta::new(owner_payer, true),
+            AccountMeta::new(access_control_pda_key, false),
+        ],
+    };
+    Ok(instruction)
+}
+
+/// Creates a SetDomainIsm instruction.
+pub fn set_domain_ism_instruction(
+    program_id: Pubkey,
+    owner_payer: Pubkey,
+    domain: u32,
+    ism: Option<Pubkey>,
+) -> Result<SolanaInstruction, ProgramError> {
+    let (access_control_pda_key, _access_control_pda_bump) =
+        Pubkey::try_find_program_address(access_control_pda_seeds!(), &program_id)
+            .ok_or(ProgramError::InvalidSeeds)?;
+
+    let (domain_data_pda_key, _domain_data_pda_bump) =
+        Pubkey::try_find_program_address(domain_data_pda_seeds!(domain), &program_id)
+            .ok_or(ProgramError::InvalidSeeds)?;
+
+    let ixn = Instruction::SetDomainIsm(Domained { domain, data: ism });
+
+    // Accounts:
+    // 0. `[signer]` The access control owner and payer of the domain PDA.
+    // 1. `[]` The access control PDA account.
+    // 2. `[writable]` The PDA relating to the provided domain.
+    // 3. `[executable]` OPTIONAL - The system program account. Required if creating the domain PDA.
+    let accounts = vec![
+        AccountMeta::new(owner_payer, true),
+        AccountMeta::new_readonly(access_control_pda_key, false),
+        AccountMeta::new(domain_data_pda_key, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+
+    let instruction = SolanaInstruction {
+        program_id,
+        data: ixn.encode()?,
+        accounts,
+    };
+    Ok(instruction)
+}
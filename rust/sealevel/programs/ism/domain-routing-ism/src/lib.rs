@@ -0,0 +1,11 @@
+//! An Interchain Security Module that routes verification of a message to
+//! the Interchain Security Module configured for the message's origin domain.
+
+#![allow(unexpected_cfgs)]
+#![deny(warnings)]
+#![deny(unsafe_code)]
+
+pub mod accounts;
+pub mod error;
+pub mod instruction;
+pub mod processor;
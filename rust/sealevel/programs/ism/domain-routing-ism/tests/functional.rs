@@ -0,0 +1,419 @@
+//! Contains functional tests for things that cannot be done
+//! strictly in unit tests. This includes CPIs, like creating
+//! new PDA accounts and calling into the routed ISMs.
+
+use account_utils::DiscriminatorEncode;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey,
+    pubkey::Pubkey,
+};
+use solana_system_interface::program as system_program;
+
+use hyperlane_core::{Encode, HyperlaneMessage, ModuleType, H256};
+use hyperlane_sealevel_domain_routing_ism::{
+    accounts::{DomainData, DomainDataAccount},
+    domain_data_pda_seeds,
+    error::Error as DomainRoutingIsmError,
+    instruction::{
+        init_instruction, set_domain_ism_instruction, Instruction as DomainRoutingIsmInstruction,
+    },
+    processor::process_instruction,
+};
+use hyperlane_sealevel_interchain_security_module_interface::{
+    InterchainSecurityModuleInstruction, VerifyInstruction, VERIFY_ACCOUNT_METAS_PDA_SEEDS,
+};
+use hyperlane_sealevel_test_ism::{program::TestIsmInstruction, test_ism_storage_pda_seeds};
+use hyperlane_test_utils::{
+    assert_transaction_error, get_account_metas, new_funded_keypair,
+    process_instruction as process, simulate_instruction,
+};
+use serializable_account_meta::SimulationReturnData;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, signature::Signer, signer::keypair::Keypair,
+    transaction::TransactionError,
+};
+
+/// The error returned by the test ISM when it does not accept a message.
+const TEST_ISM_VERIFY_NOT_ACCEPTED: u32 = 69420;
+
+const ORIGIN_DOMAIN: u32 = 1234;
+const OTHER_ORIGIN_DOMAIN: u32 = 5678;
+
+fn domain_routing_ism_id() -> Pubkey {
+    pubkey!("HxvVEs7DS9d8fWqWPmZGNXh2VHXoUnx6cRpW4WKoqMUJ")
+}
+
+fn test_ism_ids() -> [Pubkey; 2] {
+    [
+        pubkey!("9gxVHJ5tM7WjS4kCcXL2bRLLdPz3YxYi9DNk7eJPtmW2"),
+        pubkey!("AqJdq2zGvM2ZqUTsAQnzk8AJb6H1tF5ScLhJ3c9rQkVd"),
+    ]
+}
+
+fn test_ism_storage_pda_key(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(test_ism_storage_pda_seeds!(), program_id).0
+}
+
+fn domain_data_pda_key(domain: u32) -> Pubkey {
+    Pubkey::find_program_address(domain_data_pda_seeds!(domain), &domain_routing_ism_id()).0
+}
+
+fn test_message(origin: u32) -> HyperlaneMessage {
+    HyperlaneMessage {
+        version: 3,
+        nonce: 0,
+        origin,
+        sender: H256::random(),
+        destination: 4321,
+        recipient: H256::random(),
+        body: vec![1, 2, 3, 4, 5],
+    }
+}
+
+async fn setup_client() -> (BanksClient, Keypair) {
+    let mut program_test = ProgramTest::new(
+        "hyperlane_sealevel_domain_routing_ism",
+        domain_routing_ism_id(),
+        processor!(process_instruction),
+    );
+    for test_ism_id in test_ism_ids() {
+        program_test.add_program(
+            "hyperlane_sealevel_test_ism",
+            test_ism_id,
+            processor!(hyperlane_sealevel_test_ism::program::process_instruction),
+        );
+    }
+
+    let (mut banks_client, payer, _recent_blockhash) = program_test.start().await;
+
+    // Initialize the test ISMs, which accept all messages by default.
+    for test_ism_id in test_ism_ids() {
+        process(
+            &mut banks_client,
+            Instruction::new_with_borsh(
+                test_ism_id,
+                &TestIsmInstruction::Init,
+                vec![
+                    AccountMeta::new_readonly(system_program::ID, false),
+                    AccountMeta::new(payer.pubkey(), true),
+                    AccountMeta::new(test_ism_storage_pda_key(&test_ism_id), false),
+                ],
+            ),
+            &payer,
+            &[&payer],
+        )
+        .await
+        .unwrap();
+    }
+
+    process(
+        &mut banks_client,
+        init_instruction(domain_routing_ism_id(), payer.pubkey()).unwrap(),
+        &payer,
+        &[&payer],
+    )
+    .await
+    .unwrap();
+
+    (banks_client, payer)
+}
+
+async fn set_domain_ism(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    domain: u32,
+    ism: Option<Pubkey>,
+) {
+    process(
+        banks_client,
+        set_domain_ism_instruction(domain_routing_ism_id(), payer.pubkey(), domain, ism).unwrap(),
+        payer,
+        &[payer],
+    )
+    .await
+    .unwrap();
+}
+
+/// Builds a Verify instruction routing the message to `ism`.
+fn verify_instruction(message: &HyperlaneMessage, ism: Pubkey) -> Instruction {
+    Instruction::new_with_bytes(
+        domain_routing_ism_id(),
+        &InterchainSecurityModuleInstruction::Verify(VerifyInstruction {
+            metadata: vec![],
+            message: message.to_vec(),
+        })
+        .encode()
+        .unwrap(),
+        vec![
+            AccountMeta::new_readonly(domain_data_pda_key(message.origin), false),
+            AccountMeta::new_readonly(ism, false),
+            AccountMeta::new_readonly(test_ism_storage_pda_key(&ism), false),
+        ],
+    )
+}
+
+async fn route(banks_client: &mut BanksClient, payer: &Keypair, domain: u32) -> Pubkey {
+    simulate_instruction::<SimulationReturnData<Pubkey>>(
+        banks_client,
+        payer,
+        Instruction::new_with_bytes(
+            domain_routing_ism_id(),
+            &DomainRoutingIsmInstruction::Route(domain).encode().unwrap(),
+            vec![AccountMeta::new_readonly(
+                domain_data_pda_key(domain),
+                false,
+            )],
+        ),
+    )
+    .await
+    .unwrap()
+    .unwrap()
+    .return_data
+}
+
+#[tokio::test]
+async fn test_set_domain_ism() {
+    let program_id = domain_routing_ism_id();
+    let (mut banks_client, payer) = setup_client().await;
+    let [ism, other_ism] = test_ism_ids();
+
+    set_domain_ism(&mut banks_client, &payer, ORIGIN_DOMAIN, Some(ism)).await;
+
+    let (domain_data_pda_key, domain_data_pda_bump_seed) =
+        Pubkey::find_program_address(domain_data_pda_seeds!(ORIGIN_DOMAIN), &program_id);
+    let account_data = banks_client
+        .get_account(domain_data_pda_key)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let domain_data = DomainDataAccount::fetch_data(&mut &account_data[..])
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        domain_data,
+        Box::new(DomainData {
+            bump_seed: domain_data_pda_bump_seed,
+            ism: Some(ism),
+        }),
+    );
+    assert_eq!(route(&mut banks_client, &payer, ORIGIN_DOMAIN).await, ism);
+
+    // The route can be updated
+    set_domain_ism(&mut banks_client, &payer, ORIGIN_DOMAIN, Some(other_ism)).await;
+    assert_eq!(
+        route(&mut banks_client, &payer, ORIGIN_DOMAIN).await,
+        other_ism
+    );
+}
+
+#[tokio::test]
+async fn test_set_domain_ism_errors_if_not_owner() {
+    let program_id = domain_routing_ism_id();
+    let (mut banks_client, payer) = setup_client().await;
+
+    let non_owner = new_funded_keypair(&mut banks_client, &payer, 1000000000).await;
+    let result = process(
+        &mut banks_client,
+        set_domain_ism_instruction(
+            program_id,
+            non_owner.pubkey(),
+            ORIGIN_DOMAIN,
+            Some(test_ism_ids()[0]),
+        )
+        .unwrap(),
+        &non_owner,
+        &[&non_owner],
+    )
+    .await;
+
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument),
+    );
+}
+
+#[tokio::test]
+async fn test_type_and_verify_account_metas() {
+    let program_id = domain_routing_ism_id();
+    let (mut banks_client, payer) = setup_client().await;
+
+    let module_type = simulate_instruction::<SimulationReturnData<u32>>(
+        &mut banks_client,
+        &payer,
+        Instruction::new_with_bytes(
+            program_id,
+            &InterchainSecurityModuleInstruction::Type.encode().unwrap(),
+            vec![],
+        ),
+    )
+    .await
+    .unwrap()
+    .unwrap()
+    .return_data;
+    assert_eq!(module_type, ModuleType::Routing as u32);
+
+    let (verify_account_metas_pda_key, _) =
+        Pubkey::find_program_address(VERIFY_ACCOUNT_METAS_PDA_SEEDS, &program_id);
+    let account_metas = get_account_metas(
+        &mut banks_client,
+        &payer,
+        Instruction::new_with_bytes(
+            program_id,
+            &InterchainSecurityModuleInstruction::VerifyAccountMetas(VerifyInstruction {
+                metadata: vec![],
+                message: test_message(ORIGIN_DOMAIN).to_vec(),
+            })
+            .encode()
+            .unwrap(),
+            vec![AccountMeta::new_readonly(
+                verify_account_metas_pda_key,
+                false,
+            )],
+        ),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        account_metas,
+        vec![AccountMeta::new_readonly(
+            domain_data_pda_key(ORIGIN_DOMAIN),
+            false
+        )]
+    );
+}
+
+#[tokio::test]
+async fn test_verify() {
+    let (mut banks_client, payer) = setup_client().await;
+    let [ism, other_ism] = test_ism_ids();
+
+    set_domain_ism(&mut banks_client, &payer, ORIGIN_DOMAIN, Some(ism)).await;
+    set_domain_ism(
+        &mut banks_client,
+        &payer,
+        OTHER_ORIGIN_DOMAIN,
+        Some(other_ism),
+    )
+    .await;
+
+    process(
+        &mut banks_client,
+        verify_instruction(&test_message(ORIGIN_DOMAIN), ism),
+        &payer,
+        &[&payer],
+    )
+    .await
+    .unwrap();
+
+    process(
+        &mut banks_client,
+        verify_instruction(&test_message(OTHER_ORIGIN_DOMAIN), other_ism),
+        &payer,
+        &[&payer],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_verify_errors_if_routed_ism_rejects() {
+    let (mut banks_client, payer) = setup_client().await;
+    let [ism, _] = test_ism_ids();
+
+    set_domain_ism(&mut banks_client, &payer, ORIGIN_DOMAIN, Some(ism)).await;
+    process(
+        &mut banks_client,
+        Instruction::new_with_borsh(
+            ism,
+            &TestIsmInstruction::SetAccept(false),
+            vec![AccountMeta::new(test_ism_storage_pda_key(&ism), false)],
+        ),
+        &payer,
+        &[&payer],
+    )
+    .await
+    .unwrap();
+
+    let result = process(
+        &mut banks_client,
+        verify_instruction(&test_message(ORIGIN_DOMAIN), ism),
+        &payer,
+        &[&payer],
+    )
+    .await;
+
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TEST_ISM_VERIFY_NOT_ACCEPTED),
+        ),
+    );
+}
+
+#[tokio::test]
+async fn test_verify_errors_if_wrong_ism() {
+    let (mut banks_client, payer) = setup_client().await;
+    let [ism, other_ism] = test_ism_ids();
+
+    set_domain_ism(&mut banks_client, &payer, ORIGIN_DOMAIN, Some(ism)).await;
+
+    let result = process(
+        &mut banks_client,
+        verify_instruction(&test_message(ORIGIN_DOMAIN), other_ism),
+        &payer,
+        &[&payer],
+    )
+    .await;
+
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(DomainRoutingIsmError::AccountOutOfOrder as u32),
+        ),
+    );
+}
+
+#[tokio::test]
+async fn test_verify_errors_if_no_route() {
+    let (mut banks_client, payer) = setup_client().await;
+    let [ism, _] = test_ism_ids();
+
+    // No route was ever set for the domain
+    let result = process(
+        &mut banks_client,
+        verify_instruction(&test_message(ORIGIN_DOMAIN), ism),
+        &payer,
+        &[&payer],
+    )
+    .await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(DomainRoutingIsmError::NoRouteForDomain as u32),
+        ),
+    );
+
+    // The route was removed
+    set_domain_ism(&mut banks_client, &payer, ORIGIN_DOMAIN, Some(ism)).await;
+    set_domain_ism(&mut banks_client, &payer, ORIGIN_DOMAIN, None).await;
+    let result = process(
+        &mut banks_client,
+        verify_instruction(&test_message(ORIGIN_DOMAIN), ism),
+        &payer,
+        &[&payer],
+    )
+    .await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(DomainRoutingIsmError::NoRouteForDomain as u32),
+        ),
+    );
+}
@@ -0,0 +1,14 @@
+//! A multisig Interchain Security Module that accepts signatures over
+//! a checkpoint's merkle root, and a merkle proof of the message being
+//! verified against that root.
+//! A single checkpoint can therefore be used to verify any message
+//! inserted into the merkle tree at or before the checkpoint's index.
+
+#![allow(unexpected_cfgs)]
+#![deny(warnings)]
+#![deny(unsafe_code)]
+
+pub mod metadata;
+pub mod processor;
+
+pub use multisig_ism::{accounts, error, instruction};
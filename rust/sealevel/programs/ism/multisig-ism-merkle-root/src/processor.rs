@@ -0,0 +1,224 @@
+use hyperlane_core::{
+    accumulator::incremental::IncrementalMerkle, Checkpoint, CheckpointWithMessageId,
+    HyperlaneMessage, ModuleType,
+};
+
+use ecdsa_signature::EcdsaSignature;
+use multisig_ism::{error::Error, processor::MultisigIsmProgram};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::metadata::MultisigIsmMerkleRootMetadata;
+
+/// The prefix of the seeds of this program's PDAs.
+pub const PDA_SEED_PREFIX: &[u8] = b"multisig_ism_merkle_root";
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+/// A multisig ISM that accepts signatures over a checkpoint whose merkle root
+/// the message being verified is proven to be included in.
+pub struct MerkleRootMultisigIsm;
+
+impl MultisigIsmProgram for MerkleRootMultisigIsm {
+    const ISM_TYPE: ModuleType = ModuleType::MerkleRootMultisig;
+    const PDA_SEED_PREFIX: &'static [u8] = PDA_SEED_PREFIX;
+
+    fn signed_checkpoint(
+        metadata_bytes: Vec<u8>,
+        message: &HyperlaneMessage,
+    ) -> Result<(CheckpointWithMessageId, Vec<EcdsaSignature>), ProgramError> {
+        let metadata = MultisigIsmMerkleRootMetadata::try_from(metadata_bytes)?;
+
+        // The message must have been inserted into the tree at or before the signed checkpoint.
+        if metadata.message_index > metadata.signed_index {
+            return Err(Error::InvalidMerkleIndex.into());
+        }
+
+        // The signed root is the root of the tree containing the message at the message index.
+        let signed_root = IncrementalMerkle::branch_root(
+            message.id(),
+            metadata.merkle_proof,
+            metadata.message_index as usize,
+        );
+
+        Ok((
+            CheckpointWithMessageId {
+                checkpoint: Checkpoint {
+                    merkle_tree_hook_address: metadata.origin_merkle_tree_hook,
+                    mailbox_domain: message.origin,
+                    root: signed_root,
+                    index: metadata.signed_index,
+                },
+                message_id: metadata.signed_message_id,
+            },
+            metadata.validator_signatures,
+        ))
+    }
+}
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    multisig_ism::processor::process_instruction::<MerkleRootMultisigIsm>(
+        program_id,
+        accounts,
+        instruction_data,
+    )
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    use account_utils::DiscriminatorEncode;
+    use hyperlane_core::Encode;
+    use hyperlane_sealevel_interchain_security_module_interface::{
+        InterchainSecurityModuleInstruction, VerifyInstruction,
+    };
+    use multisig_ism::{
+        accounts::{DomainData, DomainDataAccount},
+        domain_data_pda_seeds,
+        instruction::ValidatorsAndThreshold,
+        test_data::{get_multisig_ism_merkle_root_test_data, MultisigIsmMerkleRootTestData},
+    };
+    use std::str::FromStr;
+
+    const ORIGIN_DOMAIN: u32 = 1234u32;
+
+    fn id() -> Pubkey {
+        Pubkey::from_str("CF3jeg8QZoSH2M4PTCMxK6kKDuRHZENLn8UWmBFGFCH4").unwrap()
+    }
+
+    fn verify_instruction_data(
+        metadata: MultisigIsmMerkleRootMetadata,
+        message: &HyperlaneMessage,
+    ) -> Vec<u8> {
+        // Use the InterchainSecurityModuleInstruction enum to ensure the instruction
+        // is handled in compliance with what the Mailbox expects
+        InterchainSecurityModuleInstruction::Verify(VerifyInstruction {
+            metadata: metadata.to_vec(),
+            message: message.to_vec(),
+        })
+        .encode()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_verify() {
+        let program_id = id();
+
+        let (domain_pda_key, domain_pda_bump_seed) = Pubkey::find_program_address(
+            domain_data_pda_seeds!(PDA_SEED_PREFIX, ORIGIN_DOMAIN),
+            &program_id,
+        );
+
+        let MultisigIsmMerkleRootTestData {
+            message,
+            message_index,
+            merkle_proof,
+            checkpoint,
+            validators,
+            signatures,
+        } = get_multisig_ism_merkle_root_test_data();
+
+        let mut domain_account_lamports = 0;
+        let mut domain_account_data = vec![0_u8; 2048];
+        let domain_pda_account = AccountInfo::new(
+            &domain_pda_key,
+            false,
+            true,
+            &mut domain_account_lamports,
+            &mut domain_account_data,
+            &program_id,
+            false,
+        );
+        let init_domain_data = DomainData {
+            bump_seed: domain_pda_bump_seed,
+            validators_and_threshold: ValidatorsAndThreshold {
+                validators,
+                threshold: 2,
+            },
+        };
+        DomainDataAccount::from(init_domain_data)
+            .store(&domain_pda_account, false)
+            .unwrap();
+
+        let metadata = |signature_indices: &[usize]| MultisigIsmMerkleRootMetadata {
+            origin_merkle_tree_hook: checkpoint.merkle_tree_hook_address,
+            message_index,
+            signed_message_id: checkpoint.message_id,
+            merkle_proof,
+            signed_index: checkpoint.index,
+            validator_signatures: signature_indices
+                .iter()
+                .map(|i| EcdsaSignature::from_bytes(&signatures[*i]).unwrap())
+                .collect(),
+        };
+
+        // A quorum of signatures in the correct order.
+        // Expect no error.
+        let result = process_instruction(
+            &program_id,
+            &[domain_pda_account.clone()],
+            &verify_instruction_data(metadata(&[0, 1]), &message),
+        );
+        assert!(result.is_ok());
+
+        // A quorum of signatures NOT in the correct order.
+        // Expect an error.
+        let result = process_instruction(
+            &program_id,
+            &[domain_pda_account.clone()],
+            &verify_instruction_data(metadata(&[1, 0]), &message),
+        );
+        assert_eq!(result.unwrap_err(), Error::ThresholdNotMet.into());
+
+        // A quorum of signatures, but the message has a different nonce & therefore ID,
+        // so the proof results in a different root.
+        let result = process_instruction(
+            &program_id,
+            &[domain_pda_account.clone()],
+            &verify_instruction_data(
+                metadata(&[0, 1]),
+                &HyperlaneMessage {
+                    nonce: 420,
+                    ..message.clone()
+                },
+            ),
+        );
+        assert_eq!(result.unwrap_err(), Error::ThresholdNotMet.into());
+
+        // A quorum of signatures, but the proof is for a different index.
+        let result = process_instruction(
+            &program_id,
+            &[domain_pda_account.clone()],
+            &verify_instruction_data(
+                MultisigIsmMerkleRootMetadata {
+                    message_index: 0,
+                    ..metadata(&[0, 1])
+                },
+                &message,
+            ),
+        );
+        assert_eq!(result.unwrap_err(), Error::ThresholdNotMet.into());
+
+        // The message index is after the signed index.
+        let result = process_instruction(
+            &program_id,
+            &[domain_pda_account.clone()],
+            &verify_instruction_data(
+                MultisigIsmMerkleRootMetadata {
+                    message_index: checkpoint.index + 1,
+                    ..metadata(&[0, 1])
+                },
+                &message,
+            ),
+        );
+        assert_eq!(result.unwrap_err(), Error::InvalidMerkleIndex.into());
+    }
+}
@@ -7,10 +7,17 @@ use hyperlane_core::{
 use hyperlane_sealevel_mailbox::{
     accounts::{Inbox, InboxAccount, Outbox},
     error::Error as MailboxError,
-    instruction::{Instruction as MailboxInstruction, OutboxDispatch},
+    instruction::{
+        set_default_hook_instruction, set_required_hook_instruction,
+        Instruction as MailboxInstruction, OutboxDispatch, OutboxDispatchWithHook,
+    },
     mailbox_dispatched_message_pda_seeds,
     protocol_fee::ProtocolFee,
 };
+use hyperlane_sealevel_test_hook::{
+    program::{TestHookError, REJECT_METADATA},
+    test_client::TestHookTestClient,
+};
 use hyperlane_sealevel_test_ism::{program::TestIsmError, test_client::TestIsmTestClient};
 use hyperlane_sealevel_test_send_receiver::{
     program::{HandleMode, IsmReturnDataMode, TestSendReceiverError},
@@ -22,6 +29,7 @@ use hyperlane_test_utils::{
 };
 use solana_program::{
     instruction::{AccountMeta, Instruction},
+    pubkey,
     pubkey::Pubkey,
     rent::Rent,
 };
@@ -37,13 +45,16 @@ use solana_system_interface::program as system_program;
 
 use crate::utils::{
     assert_dispatched_message, assert_inbox, assert_message_not_processed, assert_outbox,
-    assert_processed_message, dispatch_from_payer,
+    assert_processed_message, dispatch_from_payer, dispatch_from_payer_with_hooks,
 };
 
 const LOCAL_DOMAIN: u32 = 13775;
 const REMOTE_DOMAIN: u32 = 69420;
 const PROTOCOL_FEE: u64 = 1_000_000_000;
 const MAX_PROTOCOL_FEE: u64 = 1_000_000_001;
+const TEST_REQUIRED_HOOK_ID: Pubkey = pubkey!("J2L5ccPusLh1V8cRUp7X2D99ZJVwBzF1FDUVDX9j9oZ5");
+const TEST_DEFAULT_HOOK_ID: Pubkey = pubkey!("7tbf4keaWRwv8qx3D9NahDxJhuQgReSHR3UxWcK2eAkL");
+const TEST_CUSTOM_HOOK_ID: Pubkey = pubkey!("5Dyc3iQDfXsBRx4bx6C9BmNeiWs3dxJmvAbqk2ZgzPwm");
 
 async fn setup_client() -> (
     BanksClient,
@@ -91,6 +102,18 @@ async fn setup_client() -> (
         processor!(hyperlane_sealevel_test_send_receiver::program::process_instruction),
     );
 
+    for hook_id in [
+        TEST_REQUIRED_HOOK_ID,
+        TEST_DEFAULT_HOOK_ID,
+        TEST_CUSTOM_HOOK_ID,
+    ] {
+        program_test.add_program(
+            "hyperlane_sealevel_test_hook",
+            hook_id,
+            processor!(hyperlane_sealevel_test_hook::program::process_instruction),
+        );
+    }
+
     let (banks_client, payer, _recent_blockhash) = program_test.start().await;
 
     let test_ism = TestIsmTestClient::new(banks_client.clone(), payer.insecure_clone());
@@ -143,6 +166,8 @@ async fn test_initialize() {
             tree: MerkleTree::default(),
             max_protocol_fee: MAX_PROTOCOL_FEE,
             protocol_fee: protocol_fee_config,
+            required_hook: None,
+            default_hook: None,
         },
     )
     .await;
@@ -271,6 +296,8 @@ async fn test_dispatch_from_eoa() {
             tree: expected_tree.clone(),
             max_protocol_fee: MAX_PROTOCOL_FEE,
             protocol_fee: protocol_fee_config.clone(),
+            required_hook: None,
+            default_hook: None,
         },
     )
     .await;
@@ -327,6 +354,8 @@ async fn test_dispatch_from_eoa() {
             tree: expected_tree.clone(),
             max_protocol_fee: MAX_PROTOCOL_FEE,
             protocol_fee: protocol_fee_config,
+            required_hook: None,
+            default_hook: None,
         },
     )
     .await;
@@ -479,6 +508,8 @@ async fn test_setting_valid_protocol_fee_config_works() {
             tree: MerkleTree::default(),
             max_protocol_fee: MAX_PROTOCOL_FEE,
             protocol_fee: new_protocol_fee,
+            required_hook: None,
+            default_hook: None,
         },
     )
     .await;
@@ -640,6 +671,8 @@ async fn test_dispatch_from_program() {
             tree: expected_tree.clone(),
             max_protocol_fee: MAX_PROTOCOL_FEE,
             protocol_fee: protocol_fee_config,
+            required_hook: None,
+            default_hook: None,
         },
     )
     .await;
@@ -1431,3 +1464,337 @@ async fn test_inbox_set_default_ism_errors_if_owner_not_signer() {
         TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature),
     );
 }
+
+fn test_outbox_dispatch(payer: &Keypair) -> OutboxDispatch {
+    OutboxDispatch {
+        sender: payer.pubkey(),
+        destination_domain: REMOTE_DOMAIN,
+        recipient: H256::random(),
+        message_body: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    }
+}
+
+#[tokio::test]
+async fn test_outbox_set_hooks() {
+    let program_id = mailbox_id();
+    let (mut banks_client, payer, _, _) = setup_client().await;
+    let protocol_fee_config = test_protocol_fee_config();
+
+    let mailbox_accounts = initialize_mailbox(
+        &mut banks_client,
+        &program_id,
+        &payer,
+        LOCAL_DOMAIN,
+        MAX_PROTOCOL_FEE,
+        protocol_fee_config.clone(),
+    )
+    .await
+    .unwrap();
+
+    let expected_outbox = |required_hook, default_hook| Outbox {
+        local_domain: LOCAL_DOMAIN,
+        outbox_bump_seed: mailbox_accounts.outbox_bump_seed,
+        owner: Some(payer.pubkey()),
+        tree: MerkleTree::default(),
+        max_protocol_fee: MAX_PROTOCOL_FEE,
+        protocol_fee: protocol_fee_config.clone(),
+        required_hook,
+        default_hook,
+    };
+
+    for instruction in [
+        set_required_hook_instruction(program_id, payer.pubkey(), Some(TEST_REQUIRED_HOOK_ID))
+            .unwrap(),
+        set_default_hook_instruction(program_id, payer.pubkey(), Some(TEST_DEFAULT_HOOK_ID))
+            .unwrap(),
+    ] {
+        process_instruction(&mut banks_client, instruction, &payer, &[&payer])
+            .await
+            .unwrap();
+    }
+    assert_outbox(
+        &mut banks_client,
+        mailbox_accounts.outbox,
+        expected_outbox(Some(TEST_REQUIRED_HOOK_ID), Some(TEST_DEFAULT_HOOK_ID)),
+    )
+    .await;
+
+    // Unsetting both hooks must not leave the previous hooks behind in the account data.
+    for instruction in [
+        set_required_hook_instruction(program_id, payer.pubkey(), None).unwrap(),
+        set_default_hook_instruction(program_id, payer.pubkey(), None).unwrap(),
+    ] {
+        process_instruction(&mut banks_client, instruction, &payer, &[&payer])
+            .await
+            .unwrap();
+    }
+    assert_outbox(
+        &mut banks_client,
+        mailbox_accounts.outbox,
+        expected_outbox(None, None),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_outbox_set_hooks_errors_if_not_owner() {
+    let program_id = mailbox_id();
+    let (mut banks_client, payer, _, _) = setup_client().await;
+
+    initialize_mailbox(
+        &mut banks_client,
+        &program_id,
+        &payer,
+        LOCAL_DOMAIN,
+        MAX_PROTOCOL_FEE,
+        test_protocol_fee_config(),
+    )
+    .await
+    .unwrap();
+
+    let non_owner = new_funded_keypair(&mut banks_client, &payer, 1000000000).await;
+
+    for instruction in [
+        set_required_hook_instruction(program_id, non_owner.pubkey(), Some(TEST_REQUIRED_HOOK_ID))
+            .unwrap(),
+        set_default_hook_instruction(program_id, non_owner.pubkey(), Some(TEST_DEFAULT_HOOK_ID))
+            .unwrap(),
+    ] {
+        let result =
+            process_instruction(&mut banks_client, instruction, &non_owner, &[&non_owner]).await;
+        assert_transaction_error(
+            result,
+            TransactionError::InstructionError(0, InstructionError::InvalidArgument),
+        );
+    }
+}
+
+/// Initializes the test hook deployed at each of `hook_ids`.
+async fn init_test_hooks(
+    banks_client: &BanksClient,
+    payer: &Keypair,
+    hook_ids: &[Pubkey],
+) -> Vec<TestHookTestClient> {
+    let mut test_hooks = vec![];
+    for hook_id in hook_ids {
+        let mut test_hook =
+            TestHookTestClient::new(banks_client.clone(), payer.insecure_clone(), *hook_id);
+        test_hook.init().await.unwrap();
+        test_hooks.push(test_hook);
+    }
+    test_hooks
+}
+
+#[tokio::test]
+async fn test_dispatch_invokes_required_and_default_hooks() {
+    let program_id = mailbox_id();
+    let (mut banks_client, payer, _, _) = setup_client().await;
+
+    let mailbox_accounts = initialize_mailbox(
+        &mut banks_client,
+        &program_id,
+        &payer,
+        LOCAL_DOMAIN,
+        MAX_PROTOCOL_FEE,
+        test_protocol_fee_config(),
+    )
+    .await
+    .unwrap();
+
+    let mut test_hooks = init_test_hooks(
+        &banks_client,
+        &payer,
+        &[TEST_REQUIRED_HOOK_ID, TEST_DEFAULT_HOOK_ID],
+    )
+    .await;
+
+    for instruction in [
+        set_required_hook_instruction(program_id, payer.pubkey(), Some(TEST_REQUIRED_HOOK_ID))
+            .unwrap(),
+        set_default_hook_instruction(program_id, payer.pubkey(), Some(TEST_DEFAULT_HOOK_ID))
+            .unwrap(),
+    ] {
+        process_instruction(&mut banks_client, instruction, &payer, &[&payer])
+            .await
+            .unwrap();
+    }
+
+    let hook_accounts: Vec<AccountMeta> = test_hooks
+        .iter()
+        .flat_map(|test_hook| test_hook.hook_account_metas())
+        .collect();
+    let dispatch_with_hooks = |dispatch: OutboxDispatch, hook_metadata: Vec<u8>| {
+        MailboxInstruction::OutboxDispatchWithHook(OutboxDispatchWithHook {
+            dispatch,
+            hook_metadata,
+            custom_hook: None,
+            hook_account_counts: vec![1, 1],
+        })
+    };
+
+    // Dispatching with both hooks succeeds, with the Outbox PDA signing for both.
+    let outbox_dispatch = test_outbox_dispatch(&payer);
+    let expected_message_id = HyperlaneMessage {
+        version: 3,
+        nonce: 0,
+        origin: LOCAL_DOMAIN,
+        sender: payer.pubkey().to_bytes().into(),
+        destination: outbox_dispatch.destination_domain,
+        recipient: outbox_dispatch.recipient,
+        body: outbox_dispatch.message_body.clone(),
+    }
+    .id();
+    dispatch_from_payer_with_hooks(
+        &mut banks_client,
+        &payer,
+        &mailbox_accounts,
+        dispatch_with_hooks(outbox_dispatch, vec![]),
+        hook_accounts.clone(),
+    )
+    .await
+    .unwrap();
+
+    for test_hook in test_hooks.iter_mut() {
+        let storage = test_hook.storage().await.unwrap();
+        assert_eq!(storage.dispatch_count, 1);
+        assert_eq!(storage.last_message_id, expected_message_id);
+        assert!(storage.last_outbox_signed);
+    }
+
+    // The hooks can't be skipped by omitting their accounts.
+    let result = dispatch_from_payer(
+        &mut banks_client,
+        &payer,
+        &mailbox_accounts,
+        test_outbox_dispatch(&payer),
+    )
+    .await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys),
+    );
+
+    // Nor by omitting the accounts they require.
+    let result = dispatch_from_payer_with_hooks(
+        &mut banks_client,
+        &payer,
+        &mailbox_accounts,
+        MailboxInstruction::OutboxDispatch(test_outbox_dispatch(&payer)),
+        test_hooks
+            .iter()
+            .map(|test_hook| AccountMeta::new_readonly(test_hook.id(), false))
+            .collect(),
+    )
+    .await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys),
+    );
+
+    // More account counts than hooks are rejected.
+    let result = dispatch_from_payer_with_hooks(
+        &mut banks_client,
+        &payer,
+        &mailbox_accounts,
+        MailboxInstruction::OutboxDispatchWithHook(OutboxDispatchWithHook {
+            dispatch: test_outbox_dispatch(&payer),
+            hook_metadata: vec![],
+            custom_hook: None,
+            hook_account_counts: vec![1, 1, 0],
+        }),
+        hook_accounts.clone(),
+    )
+    .await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::InvalidInstructionData),
+    );
+
+    // The hook metadata is passed to the hooks, whose failure fails the dispatch.
+    let result = dispatch_from_payer_with_hooks(
+        &mut banks_client,
+        &payer,
+        &mailbox_accounts,
+        dispatch_with_hooks(test_outbox_dispatch(&payer), REJECT_METADATA.to_vec()),
+        hook_accounts,
+    )
+    .await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TestHookError::PostDispatchRejected as u32),
+        ),
+    );
+}
+
+#[tokio::test]
+async fn test_dispatch_with_custom_hook_overrides_default_hook() {
+    let program_id = mailbox_id();
+    let (mut banks_client, payer, _, _) = setup_client().await;
+
+    let mailbox_accounts = initialize_mailbox(
+        &mut banks_client,
+        &program_id,
+        &payer,
+        LOCAL_DOMAIN,
+        MAX_PROTOCOL_FEE,
+        test_protocol_fee_config(),
+    )
+    .await
+    .unwrap();
+
+    let mut test_hooks = init_test_hooks(
+        &banks_client,
+        &payer,
+        &[TEST_DEFAULT_HOOK_ID, TEST_CUSTOM_HOOK_ID],
+    )
+    .await;
+
+    let instruction =
+        set_default_hook_instruction(program_id, payer.pubkey(), Some(TEST_DEFAULT_HOOK_ID))
+            .unwrap();
+    process_instruction(&mut banks_client, instruction, &payer, &[&payer])
+        .await
+        .unwrap();
+
+    let dispatch_with_custom_hook = |payer: &Keypair| {
+        MailboxInstruction::OutboxDispatchWithHook(OutboxDispatchWithHook {
+            dispatch: test_outbox_dispatch(payer),
+            hook_metadata: vec![],
+            custom_hook: Some(TEST_CUSTOM_HOOK_ID),
+            hook_account_counts: vec![1],
+        })
+    };
+
+    dispatch_from_payer_with_hooks(
+        &mut banks_client,
+        &payer,
+        &mailbox_accounts,
+        dispatch_with_custom_hook(&payer),
+        test_hooks[1].hook_account_metas(),
+    )
+    .await
+    .unwrap();
+
+    // Only the custom hook is invoked, and the Outbox PDA doesn't sign for it.
+    let default_hook_storage = test_hooks[0].storage().await.unwrap();
+    assert_eq!(default_hook_storage.dispatch_count, 0);
+    let custom_hook_storage = test_hooks[1].storage().await.unwrap();
+    assert_eq!(custom_hook_storage.dispatch_count, 1);
+    assert!(!custom_hook_storage.last_outbox_signed);
+
+    // The default hook is not accepted in place of the custom hook.
+    let result = dispatch_from_payer_with_hooks(
+        &mut banks_client,
+        &payer,
+        &mailbox_accounts,
+        dispatch_with_custom_hook(&payer),
+        test_hooks[0].hook_account_metas(),
+    )
+    .await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument),
+    );
+}
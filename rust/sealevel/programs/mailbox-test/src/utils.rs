@@ -28,6 +28,25 @@ pub async fn dispatch_from_payer(
     payer: &Keypair,
     mailbox_accounts: &MailboxAccounts,
     outbox_dispatch: OutboxDispatch,
+) -> Result<(Signature, Keypair, Pubkey), BanksClientError> {
+    dispatch_from_payer_with_hooks(
+        banks_client,
+        payer,
+        mailbox_accounts,
+        MailboxInstruction::OutboxDispatch(outbox_dispatch),
+        vec![],
+    )
+    .await
+}
+
+/// Dispatches with the given dispatch instruction, appending `hook_accounts`
+/// after the accounts required by every dispatch.
+pub async fn dispatch_from_payer_with_hooks(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    mailbox_accounts: &MailboxAccounts,
+    dispatch_instruction: MailboxInstruction,
+    hook_accounts: Vec<AccountMeta>,
 ) -> Result<(Signature, Keypair, Pubkey), BanksClientError> {
     let unique_message_account_keypair = Keypair::new();
 
@@ -36,28 +55,29 @@ pub async fn dispatch_from_payer(
         &mailbox_accounts.program,
     );
 
+    let mut accounts = vec![
+        // 0. `[writeable]` Outbox PDA.
+        // 1. `[signer]` Message sender signer.
+        // 2. `[executable]` System program.
+        // 3. `[executable]` SPL Noop program.
+        // 4. `[signer]` Payer.
+        // 5. `[signer]` Unique message account.
+        // 6. `[writeable]` Dispatched message PDA. An empty message PDA relating to the seeds
+        //    `mailbox_dispatched_message_pda_seeds` where the message contents will be stored.
+        AccountMeta::new(mailbox_accounts.outbox, false),
+        AccountMeta::new(payer.pubkey(), true),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(account_utils::SPL_NOOP_PROGRAM_ID, false),
+        AccountMeta::new(payer.pubkey(), true),
+        AccountMeta::new(unique_message_account_keypair.pubkey(), true),
+        AccountMeta::new(dispatched_message_account_key, false),
+    ];
+    accounts.extend(hook_accounts);
+
     let instruction = Instruction {
         program_id: mailbox_accounts.program,
-        data: MailboxInstruction::OutboxDispatch(outbox_dispatch)
-            .into_instruction_data()
-            .unwrap(),
-        accounts: vec![
-            // 0. `[writeable]` Outbox PDA.
-            // 1. `[signer]` Message sender signer.
-            // 2. `[executable]` System program.
-            // 3. `[executable]` SPL Noop program.
-            // 4. `[signer]` Payer.
-            // 5. `[signer]` Unique message account.
-            // 6. `[writeable]` Dispatched message PDA. An empty message PDA relating to the seeds
-            //    `mailbox_dispatched_message_pda_seeds` where the message contents will be stored.
-            AccountMeta::new(mailbox_accounts.outbox, false),
-            AccountMeta::new(payer.pubkey(), true),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(account_utils::SPL_NOOP_PROGRAM_ID, false),
-            AccountMeta::new(payer.pubkey(), true),
-            AccountMeta::new(unique_message_account_keypair.pubkey(), true),
-            AccountMeta::new(dispatched_message_account_key, false),
-        ],
+        data: dispatch_instruction.into_instruction_data().unwrap(),
+        accounts,
     };
 
     let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
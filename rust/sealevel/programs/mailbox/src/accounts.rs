@@ -89,7 +89,7 @@ impl Inbox {
 pub type OutboxAccount = AccountData<Outbox>;
 
 /// The Outbox account data, which is used when dispatching messages.
-#[derive(BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, PartialEq, Eq)]
 pub struct Outbox {
     /// The local domain.
     pub local_domain: u32,
@@ -103,6 +103,10 @@ pub struct Outbox {
     pub max_protocol_fee: u64,
     /// The protocol fee configuration.
     pub protocol_fee: ProtocolFee,
+    /// The hook invoked for every dispatched message.
+    pub required_hook: Option<Pubkey>,
+    /// The hook invoked for dispatched messages that don't specify a custom hook.
+    pub default_hook: Option<Pubkey>,
 }
 
 impl SizedData for Outbox {
@@ -113,7 +117,58 @@ impl SizedData for Outbox {
         // 1032 byte tree (32 * 32 = 1024 byte branch, 8 byte count)
         // 8 byte max_protocol_fee
         // 40 byte protocol_fee (8 byte fee, 32 byte beneficiary)
-        4 + 1 + 33 + 1032 + 8 + 40
+        // 33 byte required_hook (1 byte enum variant, 32 byte pubkey)
+        // 33 byte default_hook (1 byte enum variant, 32 byte pubkey)
+        4 + 1 + 33 + 1032 + 8 + 40 + 33 + 33
+    }
+}
+
+/// Outbox accounts created before hooks were supported end after `protocol_fee`.
+/// To leave their size unchanged, the hooks are only serialized once one has been set.
+impl BorshSerialize for Outbox {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.local_domain.serialize(writer)?;
+        self.outbox_bump_seed.serialize(writer)?;
+        self.owner.serialize(writer)?;
+        self.tree.serialize(writer)?;
+        self.max_protocol_fee.serialize(writer)?;
+        self.protocol_fee.serialize(writer)?;
+        if self.required_hook.is_some() || self.default_hook.is_some() {
+            self.required_hook.serialize(writer)?;
+            self.default_hook.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Treats missing hooks as unset, see the `BorshSerialize` implementation.
+impl BorshDeserialize for Outbox {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        Ok(Self {
+            local_domain: u32::deserialize_reader(reader)?,
+            outbox_bump_seed: u8::deserialize_reader(reader)?,
+            owner: Option::<Pubkey>::deserialize_reader(reader)?,
+            tree: MerkleTree::deserialize_reader(reader)?,
+            max_protocol_fee: u64::deserialize_reader(reader)?,
+            protocol_fee: ProtocolFee::deserialize_reader(reader)?,
+            required_hook: deserialize_optional_hook(reader)?,
+            default_hook: deserialize_optional_hook(reader)?,
+        })
+    }
+}
+
+fn deserialize_optional_hook<R: std::io::Read>(reader: &mut R) -> std::io::Result<Option<Pubkey>> {
+    let mut variant = [0u8; 1];
+    if reader.read(&mut variant)? == 0 {
+        return Ok(None);
+    }
+    match variant[0] {
+        0 => Ok(None),
+        1 => Ok(Some(Pubkey::deserialize_reader(reader)?)),
+        _ => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "invalid hook variant",
+        )),
     }
 }
 
@@ -339,6 +394,8 @@ mod test {
                 fee: 69696969,
                 beneficiary: Pubkey::new_unique(),
             },
+            required_hook: Some(Pubkey::new_unique()),
+            default_hook: Some(Pubkey::new_unique()),
         };
 
         let mut serialized = vec![];
@@ -350,6 +407,43 @@ mod test {
         assert_eq!(serialized.len(), outbox.size());
     }
 
+    #[test]
+    fn test_outbox_without_hooks_ser_deser() {
+        let mut outbox = Outbox {
+            local_domain: 420,
+            outbox_bump_seed: 69,
+            owner: Some(Pubkey::new_unique()),
+            tree: MerkleTree::default(),
+            max_protocol_fee: 100000000,
+            protocol_fee: ProtocolFee {
+                fee: 69696969,
+                beneficiary: Pubkey::new_unique(),
+            },
+            required_hook: None,
+            default_hook: None,
+        };
+
+        // Serializes to the layout used before hooks were supported.
+        let mut serialized = vec![];
+        outbox.serialize(&mut serialized).unwrap();
+        assert_eq!(serialized.len(), outbox.size() - 66);
+
+        let deserialized = Outbox::deserialize(&mut serialized.as_slice()).unwrap();
+        assert_eq!(outbox, deserialized);
+
+        // Zeroed trailing data, e.g. from an account sized for hooks, is read as unset hooks.
+        serialized.extend_from_slice(&[0u8; 66]);
+        let deserialized = Outbox::deserialize(&mut serialized.as_slice()).unwrap();
+        assert_eq!(outbox, deserialized);
+
+        // Setting only one hook still serializes both.
+        outbox.default_hook = Some(Pubkey::new_unique());
+        let mut serialized = vec![];
+        outbox.serialize(&mut serialized).unwrap();
+        let deserialized = Outbox::deserialize(&mut serialized.as_slice()).unwrap();
+        assert_eq!(outbox, deserialized);
+    }
+
     #[test]
     fn test_inbox_ser_deser() {
         let inbox = Inbox {
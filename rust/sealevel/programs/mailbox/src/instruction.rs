@@ -41,9 +41,35 @@ pub enum Instruction {
     ClaimProtocolFees,
     /// Sets the protocol fee configuration.
     SetProtocolFeeConfig(ProtocolFee),
+    /// Dispatches a message, passing metadata to the hooks and optionally
+    /// overriding the default hook.
+    OutboxDispatchWithHook(OutboxDispatchWithHook),
+    /// Sets the required hook. Once set, every dispatch, including an
+    /// `OutboxDispatch`, must pass the hook's accounts, so senders must be
+    /// upgraded to pass them before it is set.
+    OutboxSetRequiredHook(Option<Pubkey>),
+    /// Sets the default hook. Like the required hook, it must be passed by
+    /// every dispatch that doesn't specify a custom hook once set.
+    OutboxSetDefaultHook(Option<Pubkey>),
 }
 
 impl Instruction {
+    /// Creates an instruction dispatching `dispatch`. If no hook account counts
+    /// are given, this is an `OutboxDispatch`, which Mailboxes without hook
+    /// support also process.
+    pub fn outbox_dispatch(dispatch: OutboxDispatch, hook_account_counts: Vec<u8>) -> Self {
+        if hook_account_counts.is_empty() {
+            Self::OutboxDispatch(dispatch)
+        } else {
+            Self::OutboxDispatchWithHook(OutboxDispatchWithHook {
+                dispatch,
+                hook_metadata: vec![],
+                custom_hook: None,
+                hook_account_counts,
+            })
+        }
+    }
+
     /// Deserializes an instruction from a slice.
     pub fn from_instruction_data(data: &[u8]) -> Result<Self, ProgramError> {
         Self::try_from_slice(data).map_err(|_| ProgramError::InvalidInstructionData)
@@ -85,6 +111,21 @@ pub struct OutboxDispatch {
     pub message_body: Vec<u8>,
 }
 
+/// Instruction data for the OutboxDispatchWithHook instruction.
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub struct OutboxDispatchWithHook {
+    /// The message to dispatch.
+    pub dispatch: OutboxDispatch,
+    /// Metadata passed to the hooks' PostDispatch instructions.
+    pub hook_metadata: Vec<u8>,
+    /// A hook to invoke instead of the default hook. Unlike the required and
+    /// default hooks, it isn't signed for by the Outbox PDA.
+    pub custom_hook: Option<Pubkey>,
+    /// The number of accounts following each hook's program account, in the
+    /// order the hooks are invoked. Hooks without an entry take no accounts.
+    pub hook_account_counts: Vec<u8>,
+}
+
 /// Instruction data for the InboxProcess instruction.
 #[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
 pub struct InboxProcess {
@@ -179,3 +220,53 @@ pub fn set_default_ism_instruction(
     };
     Ok(instruction)
 }
+
+/// Creates an OutboxSetRequiredHook instruction.
+pub fn set_required_hook_instruction(
+    program_id: Pubkey,
+    owner_payer: Pubkey,
+    required_hook: Option<Pubkey>,
+) -> Result<SolanaInstruction, ProgramError> {
+    set_hook_instruction(
+        program_id,
+        owner_payer,
+        Instruction::OutboxSetRequiredHook(required_hook),
+    )
+}
+
+/// Creates an OutboxSetDefaultHook instruction.
+pub fn set_default_hook_instruction(
+    program_id: Pubkey,
+    owner_payer: Pubkey,
+    default_hook: Option<Pubkey>,
+) -> Result<SolanaInstruction, ProgramError> {
+    set_hook_instruction(
+        program_id,
+        owner_payer,
+        Instruction::OutboxSetDefaultHook(default_hook),
+    )
+}
+
+fn set_hook_instruction(
+    program_id: Pubkey,
+    owner_payer: Pubkey,
+    instruction: Instruction,
+) -> Result<SolanaInstruction, ProgramError> {
+    let (outbox_account, _outbox_bump) =
+        Pubkey::try_find_program_address(mailbox_outbox_pda_seeds!(), &program_id)
+            .ok_or(ProgramError::InvalidSeeds)?;
+
+    // 0. `[writeable]` The Outbox PDA account.
+    // 1. `[signer, writeable]` The owner of the Mailbox, paying for any realloc.
+    // 2. `[executable]` The system program.
+    let instruction = SolanaInstruction {
+        program_id,
+        data: instruction.into_instruction_data()?,
+        accounts: vec![
+            AccountMeta::new(outbox_account, false),
+            AccountMeta::new(owner_payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    };
+    Ok(instruction)
+}
@@ -22,6 +22,7 @@ use solana_program::{
 use solana_system_interface::{instruction as system_instruction, program as system_program};
 
 use account_utils::{create_pda_account, verify_account_uninitialized};
+use hyperlane_sealevel_hook_interface::{PostDispatchHookInstruction, PostDispatchInstruction};
 use hyperlane_sealevel_interchain_security_module_interface::{
     InterchainSecurityModuleInstruction, VerifyInstruction,
 };
@@ -36,7 +37,7 @@ use crate::{
         ProcessedMessage, ProcessedMessageAccount,
     },
     error::Error,
-    instruction::{InboxProcess, Init, Instruction as MailboxIxn, OutboxDispatch, VERSION},
+    instruction::{InboxProcess, Init, Instruction as MailboxIxn, OutboxDispatchWithHook, VERSION},
     mailbox_dispatched_message_pda_seeds, mailbox_inbox_pda_seeds,
     mailbox_message_dispatch_authority_pda_seeds, mailbox_outbox_pda_seeds,
     mailbox_process_authority_pda_seeds, mailbox_processed_message_pda_seeds,
@@ -59,7 +60,16 @@ pub fn process_instruction(
         MailboxIxn::InboxGetRecipientIsm(recipient) => {
             inbox_get_recipient_ism(program_id, accounts, recipient)
         }
-        MailboxIxn::OutboxDispatch(dispatch) => outbox_dispatch(program_id, accounts, dispatch),
+        MailboxIxn::OutboxDispatch(dispatch) => outbox_dispatch(
+            program_id,
+            accounts,
+            OutboxDispatchWithHook {
+                dispatch,
+                hook_metadata: vec![],
+                custom_hook: None,
+                hook_account_counts: vec![],
+            },
+        ),
         MailboxIxn::OutboxGetCount => outbox_get_count(program_id, accounts),
         MailboxIxn::OutboxGetLatestCheckpoint => outbox_get_latest_checkpoint(program_id, accounts),
         MailboxIxn::OutboxGetRoot => outbox_get_root(program_id, accounts),
@@ -71,6 +81,19 @@ pub fn process_instruction(
         MailboxIxn::SetProtocolFeeConfig(new_protocol_fee_config) => {
             set_protocol_fee_config(program_id, accounts, new_protocol_fee_config)
         }
+        MailboxIxn::OutboxDispatchWithHook(dispatch) => {
+            outbox_dispatch(program_id, accounts, dispatch)
+        }
+        MailboxIxn::OutboxSetRequiredHook(required_hook) => {
+            outbox_set_hook(program_id, accounts, |outbox| {
+                outbox.required_hook = required_hook
+            })
+        }
+        MailboxIxn::OutboxSetDefaultHook(default_hook) => {
+            outbox_set_hook(program_id, accounts, |outbox| {
+                outbox.default_hook = default_hook
+            })
+        }
     }
     .map_err(|err| {
         msg!("{}", err);
@@ -151,6 +174,8 @@ fn initialize(program_id: &Pubkey, accounts: &[AccountInfo], init: Init) -> Prog
         tree: MerkleTree::default(),
         max_protocol_fee: init.max_protocol_fee,
         protocol_fee: init.protocol_fee,
+        required_hook: None,
+        default_hook: None,
     });
 
     // Create the outbox PDA account.
@@ -552,6 +577,11 @@ fn inbox_set_default_ism(
 /// in order for the sender field of the message to be set to the sending program
 /// ID. Otherwise, the sender field of the message is set to the message sender signer.
 ///
+/// After the message is inserted into the tree, the required hook (if set) and then
+/// the custom hook (if provided) or the default hook (if set) are invoked with a
+/// PostDispatch instruction. The Outbox PDA is prepended to each hook's accounts,
+/// as a signer only for the required and default hooks set by the owner.
+///
 /// Sets the ID of the message as return data.
 ///
 /// Accounts:
@@ -563,11 +593,24 @@ fn inbox_set_default_ism(
 /// 5. `[signer]` Unique message account.
 /// 6. `[writeable]` Dispatched message PDA. An empty message PDA relating to the seeds
 ///    `mailbox_dispatched_message_pda_seeds` where the message contents will be stored.
+/// 7. `[executable]` The required hook, if set.
+/// 8..N. `[??]` The `hook_account_counts[0]` accounts required to invoke the required
+///    hook's PostDispatch instruction.
+/// N+1. `[executable]` The custom hook if provided, otherwise the default hook, if set.
+/// N+2..M. `[??]` The next entry of `hook_account_counts` accounts required to invoke
+///    that hook's PostDispatch instruction.
 fn outbox_dispatch(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    dispatch: OutboxDispatch,
+    dispatch_with_hook: OutboxDispatchWithHook,
 ) -> ProgramResult {
+    let OutboxDispatchWithHook {
+        dispatch,
+        hook_metadata,
+        custom_hook,
+        hook_account_counts,
+    } = dispatch_with_hook;
+
     let accounts_iter = &mut accounts.iter();
 
     // Account 0: Outbox PDA.
@@ -639,6 +682,49 @@ fn outbox_dispatch(
     // Make sure an account can't be written to that already exists.
     verify_account_uninitialized(dispatched_message_account_info)?;
 
+    // Accounts 7..M: the hooks and the accounts required to invoke them.
+    // Only the hooks set by the owner are signed for by the Outbox PDA, as
+    // anyone can dispatch with any custom hook.
+    // The owner-set hooks are invoked for a plain `OutboxDispatch` too, so
+    // that they can't be bypassed. Senders that don't pass hook accounts stop
+    // being able to dispatch once a required or default hook is set.
+    let hooks: Vec<(Pubkey, bool)> = outbox
+        .required_hook
+        .map(|hook| (hook, true))
+        .into_iter()
+        .chain(
+            custom_hook
+                .map(|hook| (hook, false))
+                .or(outbox.default_hook.map(|hook| (hook, true))),
+        )
+        .collect();
+    if hook_account_counts.len() > hooks.len() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let mut hook_invocations = vec![];
+    for (i, (hook, signed)) in hooks.into_iter().enumerate() {
+        let hook_info = next_account_info(accounts_iter)?;
+        if hook_info.key != &hook {
+            return Err(ProgramError::InvalidArgument);
+        }
+        if !hook_info.executable {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mut hook_infos = vec![outbox_info.clone()];
+        let mut hook_account_metas = vec![AccountMeta::new_readonly(*outbox_info.key, signed)];
+        for _ in 0..hook_account_counts.get(i).copied().unwrap_or_default() {
+            let account_info = next_account_info(accounts_iter)?;
+            hook_account_metas.push(AccountMeta {
+                pubkey: *account_info.key,
+                is_signer: account_info.is_signer,
+                is_writable: account_info.is_writable,
+            });
+            hook_infos.push(account_info.clone());
+        }
+        hook_invocations.push((hook, signed, hook_infos, hook_account_metas));
+    }
+
     if accounts_iter.next().is_some() {
         return Err(ProgramError::from(Error::ExtraneousAccount));
     }
@@ -678,6 +764,13 @@ fn outbox_dispatch(
     let id = message.id();
     outbox.tree.ingest(id);
 
+    let post_dispatch = PostDispatchHookInstruction::PostDispatch(PostDispatchInstruction::new(
+        hook_metadata,
+        encoded_message.clone(),
+    ))
+    .encode()?;
+    let outbox_bump_seed = outbox.outbox_bump_seed;
+
     // Create the dispatched message PDA.
     let dispatched_message_account = DispatchedMessageAccount::from(DispatchedMessage::new(
         message.nonce,
@@ -720,6 +813,24 @@ fn outbox_dispatch(
     // Store the Outbox with the new updates.
     OutboxAccount::from(outbox).store(outbox_info, true)?;
 
+    // Invoke the hooks now that the message is part of the tree. The required
+    // and default hooks are signed for with the Outbox PDA so they can verify
+    // the message was dispatched.
+    for (hook, signed, hook_infos, hook_account_metas) in hook_invocations {
+        let post_dispatch_ixn =
+            Instruction::new_with_bytes(hook, &post_dispatch, hook_account_metas);
+        if signed {
+            invoke_signed(
+                &post_dispatch_ixn,
+                &hook_infos,
+                &[mailbox_outbox_pda_seeds!(outbox_bump_seed)],
+            )?;
+        } else {
+            invoke(&post_dispatch_ixn, &hook_infos)?;
+        }
+    }
+
+    // Set after the hooks are invoked, which may set their own return data.
     set_return_data(id.as_ref());
     Ok(())
 }
@@ -926,3 +1037,49 @@ fn set_protocol_fee_config(
 
     Ok(())
 }
+
+/// Sets the required or default hook, depending on `set_hook`.
+///
+/// Accounts:
+/// 0. `[writeable]` The Outbox PDA account.
+/// 1. `[signer, writeable]` The current owner, paying for any realloc of the Outbox.
+/// 2. `[executable]` The system program.
+fn outbox_set_hook(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    set_hook: impl FnOnce(&mut Outbox),
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Account 0: Outbox PDA.
+    let outbox_info = next_account_info(accounts_iter)?;
+    let mut outbox = Outbox::verify_account_and_fetch_inner(program_id, outbox_info)?;
+
+    // Account 1: Owner
+    let owner_info = next_account_info(accounts_iter)?;
+    outbox.ensure_owner_signer(owner_info)?;
+
+    // Account 2: System program.
+    let system_program_info = next_account_info(accounts_iter)?;
+    if system_program_info.key != &system_program::ID {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if accounts_iter.next().is_some() {
+        return Err(ProgramError::from(Error::ExtraneousAccount));
+    }
+
+    set_hook(outbox.as_mut());
+
+    // Unset hooks aren't serialized, so clear any previously stored hooks
+    // before writing the updated Outbox.
+    outbox_info.try_borrow_mut_data()?.fill(0);
+    OutboxAccount::from(outbox).store_with_rent_exempt_realloc(
+        outbox_info,
+        &Rent::get()?,
+        owner_info,
+        system_program_info,
+    )?;
+
+    Ok(())
+}
@@ -343,6 +343,7 @@ export {
   AgentCosmosGasPrice,
   AgentLogFormat,
   AgentLogLevel,
+  AgentPrivateSubmissionMethod,
   AgentSealevelChainMetadata,
   AgentSealevelHeliusFeeLevel,
   AgentSealevelPriorityFeeOracle,
@@ -350,6 +351,7 @@ export {
   AgentSealevelTransactionSubmitter,
   AgentSealevelTransactionSubmitterType,
   AgentSigner,
+  AgentSignerAssignment,
   AgentSignerAwsKey,
   AgentSignerHexKey,
   AgentSignerKeyType,
@@ -360,6 +362,7 @@ export {
   IsmCacheConfig,
   IsmCachePolicy,
   IsmCacheSelectorType,
+  PriceSourceType,
   RelayerConfig,
   RpcConsensusType,
   ScraperConfig,
@@ -19,6 +19,9 @@ export enum SealevelMailboxInstructionType {
   TRANSFER_OWNERSHIP = 9,
   CLAIM_PROTOCOL_FEES = 10,
   SET_PROTOCOL_FEE_CONFIG = 11,
+  OUTBOX_DISPATCH_WITH_HOOK = 12,
+  OUTBOX_SET_REQUIRED_HOOK = 13,
+  OUTBOX_SET_DEFAULT_HOOK = 14,
 }
 
 export const SealevelMailboxInstructionName: Record<
@@ -40,6 +43,12 @@ export const SealevelMailboxInstructionName: Record<
   [SealevelMailboxInstructionType.CLAIM_PROTOCOL_FEES]: 'ClaimProtocolFees',
   [SealevelMailboxInstructionType.SET_PROTOCOL_FEE_CONFIG]:
     'SetProtocolFeeConfig',
+  [SealevelMailboxInstructionType.OUTBOX_DISPATCH_WITH_HOOK]:
+    'OutboxDispatchWithHook',
+  [SealevelMailboxInstructionType.OUTBOX_SET_REQUIRED_HOOK]:
+    'OutboxSetRequiredHook',
+  [SealevelMailboxInstructionType.OUTBOX_SET_DEFAULT_HOOK]:
+    'OutboxSetDefaultHook',
 };
 
 /**
@@ -118,3 +127,85 @@ export const SealevelMailboxTransferOwnershipInstructionSchema = new Map<
     },
   ],
 ]);
+
+/**
+ * The Outbox fields needed to dispatch through the Mailbox's hooks.
+ * Matches: Outbox in rust/sealevel/programs/mailbox/src/accounts.rs
+ */
+export interface SealevelMailboxOutboxHooks {
+  localDomain: number;
+  /// The number of dispatched messages, i.e. the nonce of the next message.
+  count: number;
+  requiredHook?: PublicKey;
+  defaultHook?: PublicKey;
+}
+
+/**
+ * Decodes the Outbox account data. The Outbox has a custom Borsh encoding
+ * where the hooks are only serialized once one has been set.
+ */
+export function decodeSealevelMailboxOutbox(
+  data: Buffer,
+): SealevelMailboxOutboxHooks {
+  // 1 byte initialized flag
+  let offset = 1;
+  const localDomain = data.readUInt32LE(offset);
+  // 4 byte local_domain, 1 byte outbox_bump_seed
+  offset += 4 + 1;
+  // owner: Option<Pubkey>
+  offset += data[offset] ? 33 : 1;
+  // tree: 32 * 32 byte branch, then 8 byte count
+  offset += 32 * 32;
+  const count = Number(data.readBigUInt64LE(offset));
+  offset += 8;
+  // 8 byte max_protocol_fee, 8 byte protocol fee, 32 byte beneficiary
+  offset += 8 + 8 + 32;
+
+  const readHook = (): PublicKey | undefined => {
+    if (offset >= data.length || !data[offset]) {
+      offset += 1;
+      return undefined;
+    }
+    const hook = new PublicKey(data.subarray(offset + 1, offset + 33));
+    offset += 33;
+    return hook;
+  };
+  const requiredHook = readHook();
+  const defaultHook = readHook();
+
+  return { localDomain, count, requiredHook, defaultHook };
+}
+
+/**
+ * First 8 bytes of sha256("hyperlane-post-dispatch-hook:post-dispatch-account-metas")
+ * Matches: rust/sealevel/libraries/hook-interface/src/lib.rs
+ */
+export const POST_DISPATCH_ACCOUNT_METAS_DISCRIMINATOR = Buffer.from([
+  190, 10, 209, 10, 56, 103, 110, 208,
+]);
+
+/**
+ * PostDispatch and PostDispatchAccountMetas instruction data for hooks
+ * Matches: PostDispatchInstruction in rust/sealevel/libraries/hook-interface/src/lib.rs
+ */
+export class SealevelPostDispatchInstruction {
+  metadata!: Uint8Array;
+  message!: Uint8Array;
+
+  constructor(fields: any) {
+    Object.assign(this, fields);
+  }
+}
+
+export const SealevelPostDispatchInstructionSchema = new Map<any, any>([
+  [
+    SealevelPostDispatchInstruction,
+    {
+      kind: 'struct',
+      fields: [
+        ['metadata', ['u8']],
+        ['message', ['u8']],
+      ],
+    },
+  ],
+]);
@@ -40,9 +40,20 @@ export enum AgentLogFormat {
   Pretty = 'pretty',
 }
 
+export enum AgentSignerAssignment {
+  LeastPending = 'leastPending',
+  RoundRobin = 'roundRobin',
+}
+
+export enum AgentPrivateSubmissionMethod {
+  PrivateTransaction = 'privateTransaction',
+  Bundle = 'bundle',
+}
+
 export enum AgentIndexMode {
   Block = 'block',
   Sequence = 'sequence',
+  Subscription = 'subscription',
 }
 
 export enum AgentSignerKeyType {
@@ -52,11 +63,14 @@ export enum AgentSignerKeyType {
   Cosmos = 'cosmosKey',
   Starknet = 'starkKey',
   Radix = 'radixKey',
+  Remote = 'remoteSigner',
+  Threshold = 'thresholdSigner',
 }
 
 export enum AgentSealevelPriorityFeeOracleType {
   Helius = 'helius',
   Constant = 'constant',
+  Rpc = 'rpc',
 }
 
 export enum AgentSealevelHeliusFeeLevel {
@@ -103,6 +117,27 @@ const AgentSignerRadixKeySchema = z
     key: ZHash,
   })
   .describe('Radix key');
+const AgentSignerRemoteSchema = z
+  .object({
+    type: z.literal(AgentSignerKeyType.Remote),
+    url: z.string().url().describe('The base url of the signing service'),
+    address: ZHash.describe('The address of the key to sign with'),
+  })
+  .describe(
+    'A remote signing service implementing the Web3Signer eth1 signing API',
+  );
+const AgentSignerThresholdSchema = z
+  .object({
+    type: z.literal(AgentSignerKeyType.Threshold),
+    url: z.string().url().describe('The base url of the signing coordinator'),
+    keyId: z
+      .string()
+      .describe('The identifier of the shared key at the coordinator'),
+    address: ZHash.describe('The address of the group public key'),
+  })
+  .describe(
+    'A key shared between the parties of a threshold ECDSA scheme, signing through its coordinator',
+  );
 const AgentSignerNodeSchema = z
   .object({
     type: z.literal(AgentSignerKeyType.Node),
@@ -115,12 +150,16 @@ const AgentSignerSchema = z.union([
   AgentSignerCosmosKeySchema,
   AgentSignerNodeSchema,
   AgentSignerRadixKeySchema,
+  AgentSignerRemoteSchema,
+  AgentSignerThresholdSchema,
 ]);
 
 export type AgentSignerHexKey = z.infer<typeof AgentSignerHexKeySchema>;
 export type AgentSignerAwsKey = z.infer<typeof AgentSignerAwsKeySchema>;
 export type AgentSignerCosmosKey = z.infer<typeof AgentSignerNodeSchema>;
 export type AgentSignerNode = z.infer<typeof AgentSignerNodeSchema>;
+export type AgentSignerRemote = z.infer<typeof AgentSignerRemoteSchema>;
+export type AgentSignerThreshold = z.infer<typeof AgentSignerThresholdSchema>;
 export type AgentSigner = z.infer<typeof AgentSignerSchema>;
 
 // Additional chain metadata for Cosmos chains required by the agents.
@@ -165,6 +204,25 @@ const AgentSealevelChainMetadataSchema = z.object({
         // In microlamports
         fee: ZUWei,
       }),
+      z.object({
+        type: z.literal(AgentSealevelPriorityFeeOracleType.Rpc),
+        url: z
+          .string()
+          .optional()
+          .describe(
+            'RPC to query getRecentPrioritizationFees from. Defaults to the first RPC of the chain.',
+          ),
+        percentile: z
+          .number()
+          .int()
+          .min(0)
+          .max(100)
+          .optional()
+          .describe('Percentile of recent prioritization fees to use.'),
+        // In microlamports
+        floor: ZUWei.optional(),
+        ceiling: ZUWei.optional(),
+      }),
     ])
     .optional(),
   transactionSubmitter: z
@@ -216,6 +274,38 @@ export const AgentChainMetadataSchema = ChainMetadataSchemaObject.merge(
     signer: AgentSignerSchema.optional().describe(
       'The signer to use for this chain',
     ),
+    signerPool: z
+      .array(AgentSignerSchema)
+      .optional()
+      .describe(
+        'Additional signers which submit transactions in parallel with `signer`. Only supported by the lander submitter on EVM chains.',
+      ),
+    signerAssignment: z
+      .nativeEnum(AgentSignerAssignment)
+      .optional()
+      .describe(
+        'How new transactions are assigned to the signers of the signer pool.',
+      ),
+    privateSubmission: z
+      .object({
+        url: z
+          .string()
+          .url()
+          .describe('URL of the private RPC or bundle relay.'),
+        method: z
+          .nativeEnum(AgentPrivateSubmissionMethod)
+          .optional()
+          .describe(
+            'JSON-RPC method used to send signed transactions. Defaults to privateTransaction.',
+          ),
+        fallbackAfterBlocks: ZNzUint.optional().describe(
+          'Number of blocks after which a privately sent transaction is broadcast through the public mempool instead.',
+        ),
+      })
+      .optional()
+      .describe(
+        'Send signed transactions to a private RPC or bundle relay instead of the public mempool. Only supported by the lander submitter on EVM chains.',
+      ),
     index: z
       .object({
         from: ZUint.optional().describe(
@@ -230,6 +320,13 @@ export const AgentChainMetadataSchema = ChainMetadataSchemaObject.merge(
           .describe(
             'The indexing method to use for this chain; will attempt to choose a suitable default if not specified.',
           ),
+        subscriptionUrl: z
+          .string()
+          .url()
+          .optional()
+          .describe(
+            'Websocket URL to subscribe to contract logs with. Required by the subscription index mode, only supported on EVM chains.',
+          ),
       })
       .optional(),
   })
@@ -341,8 +438,41 @@ export enum GasPaymentEnforcementPolicyType {
   None = 'none',
   Minimum = 'minimum',
   OnChainFeeQuoting = 'onChainFeeQuoting',
+  Profitability = 'profitability',
+}
+
+export enum PriceSourceType {
+  Static = 'static',
+  Http = 'http',
 }
 
+const PriceSourceSchema = z.union([
+  z.object({
+    type: z.literal(PriceSourceType.Static),
+    path: z
+      .string()
+      .min(1)
+      .describe(
+        'Path to a JSON file of native token prices keyed by domain ID.',
+      ),
+  }),
+  z.object({
+    type: z.literal(PriceSourceType.Http),
+    url: z
+      .string()
+      .url()
+      .describe(
+        'URL of an endpoint serving native token prices keyed by domain ID.',
+      ),
+    cacheSeconds: ZUint.optional().describe(
+      'How long fetched prices are reused before fetching again.',
+    ),
+    maxStaleSeconds: ZUint.optional().describe(
+      'How long fetched prices are still used when they cannot be refreshed. Defaults to 600.',
+    ),
+  }),
+]);
+
 const GasPaymentEnforcementBaseSchema = z.object({
   matchingList: MatchingListSchema.optional().describe(
     'An optional matching list, any message that matches will use this policy. By default all messages will match.',
@@ -363,6 +493,16 @@ const GasPaymentEnforcementSchema = z.union([
       .regex(/^\d+ ?\/ ?[1-9]\d*$/)
       .optional(),
   }),
+  GasPaymentEnforcementBaseSchema.extend({
+    type: z.literal(GasPaymentEnforcementPolicyType.Profitability),
+    minMargin: z
+      .number()
+      .optional()
+      .describe(
+        'The minimum profit margin, relative to the estimated transaction cost, required to relay. Defaults to 0 (break even).',
+      ),
+    priceSource: PriceSourceSchema,
+  }),
 ]);
 export type GasPaymentEnforcement = z.infer<typeof GasPaymentEnforcementSchema>;
 
@@ -412,6 +552,10 @@ const IsmCacheConfigSchema = z.object({
 });
 export type IsmCacheConfig = z.infer<typeof IsmCacheConfigSchema>;
 
+const ApiRoleSchema = z
+  .enum(['readOnly', 'admin'])
+  .describe('Access level of a relayer API client.');
+
 export const RelayerAgentConfigSchema = AgentConfigSchema.extend({
   db: z
     .string()
@@ -473,6 +617,36 @@ export const RelayerAgentConfigSchema = AgentConfigSchema.extend({
     .describe(
       'If true, allows caching of certain contract calls that can be appropriately cached.',
     ),
+  contractCallCache: z
+    .union([
+      z.object({ type: z.literal('local') }),
+      z.object({
+        type: z.literal('redis'),
+        url: z
+          .string()
+          .url()
+          .describe(
+            'The url of the cache server, e.g. redis://[[username]:password@]host[:port][/db], or rediss:// for TLS.',
+          ),
+        layered: z
+          .boolean()
+          .optional()
+          .describe(
+            'Whether to keep an in-memory cache in front of the shared one. Defaults to true.',
+          ),
+        keyPrefix: z
+          .string()
+          .optional()
+          .describe('Prefix for all cache keys. Defaults to "hyperlane:".'),
+        maxEntrySize: ZNzUint.optional().describe(
+          'Maximum size of a cache entry in bytes. Larger results are not cached. Defaults to 4 MiB.',
+        ),
+      }),
+    ])
+    .optional()
+    .describe(
+      'Where cached contract calls are stored. A shared Redis compatible cache lets horizontally scaled relayers reuse each other\'s results. Defaults to a local in-memory cache.',
+    ),
   txIdIndexingEnabled: z
     .boolean()
     .optional()
@@ -483,6 +657,55 @@ export const RelayerAgentConfigSchema = AgentConfigSchema.extend({
     .boolean()
     .optional()
     .describe('Whether to enable IGP indexing'),
+  serverAuth: z
+    .object({
+      tokens: z
+        .array(
+          z.object({
+            name: z.string().min(1).describe('Name used in the audit log.'),
+            token: z.string().min(1).describe('The bearer token.'),
+            role: ApiRoleSchema,
+          }),
+        )
+        .optional()
+        .describe('Clients authenticating with `Authorization: Bearer`.'),
+      clientCertificates: z
+        .array(
+          z.object({
+            name: z.string().min(1).describe('Name used in the audit log.'),
+            fingerprint: z
+              .string()
+              .min(1)
+              .describe('SHA-256 fingerprint of the client certificate.'),
+            role: ApiRoleSchema,
+          }),
+        )
+        .optional()
+        .describe(
+          'Clients authenticating with a TLS client certificate. Requires `tls.clientCaPath`.',
+        ),
+      anonymousRole: ApiRoleSchema.optional().describe(
+        'Role of clients without credentials. If not set, they are rejected.',
+      ),
+      tls: z
+        .object({
+          certPath: z.string().min(1),
+          keyPath: z.string().min(1),
+          clientCaPath: z
+            .string()
+            .min(1)
+            .optional()
+            .describe('CAs to verify client certificates against.'),
+        })
+        .optional()
+        .describe(
+          'Serve the relayer API, and the metrics endpoint sharing its port, over TLS.',
+        ),
+    })
+    .optional()
+    .describe(
+      'Authentication for the relayer API. Reading routes need the readOnly role, mutating ones the admin role.',
+    ),
 });
 
 export type RelayerConfig = z.infer<typeof RelayerAgentConfigSchema>;
@@ -492,10 +715,89 @@ export const ScraperAgentConfigSchema = AgentConfigSchema.extend({
   chainsToScrape: CommaSeparatedChainList.describe(
     'Comma separated list of chain names to scrape',
   ),
+  warpRoutes: z
+    .union([MatchingListSchema, z.string().min(1)])
+    .optional()
+    .describe(
+      'Messages sent between warp route contracts, whose bodies are decoded into transfers. If not provided, no transfers are decoded.',
+    ),
 });
 
 export type ScraperConfig = z.infer<typeof ScraperAgentConfigSchema>;
 
+const CheckpointSyncerSchema = z.discriminatedUnion('type', [
+  z
+    .object({
+      type: z.literal('localStorage'),
+      path: z.string().min(1).describe('Path to the local storage location'),
+    })
+    .describe('A local checkpoint syncer'),
+  z
+    .object({
+      type: z.literal('s3'),
+      bucket: z.string().min(1),
+      region: z.string().min(1),
+      folder: z
+        .string()
+        .min(1)
+        .optional()
+        .describe(
+          'The folder/key-prefix to use, defaults to the root of the bucket',
+        ),
+    })
+    .describe('A checkpoint syncer that uses S3'),
+  z
+    .object({
+      type: z.literal('gcs'),
+      bucket: z.string().min(1),
+      folder: z
+        .string()
+        .min(1)
+        .optional()
+        .describe('The folder to use, defaults to the root of the bucket'),
+      service_account_key: z
+        .string()
+        .min(1)
+        .optional()
+        .describe('The path to GCS service account key file'),
+      user_secrets: z
+        .string()
+        .min(1)
+        .optional()
+        .describe('The path to GCS user secret file'),
+    })
+    .describe('A checkpoint syncer that uses Google Cloud Storage'),
+  z
+    .object({
+      type: z.literal('azure'),
+      account: z.string().min(1).describe('The storage account name'),
+      container: z.string().min(1),
+      folder: z
+        .string()
+        .min(1)
+        .optional()
+        .describe('The folder to use, defaults to the root of the container'),
+      endpoint: z
+        .string()
+        .url()
+        .optional()
+        .describe(
+          'The blob service endpoint, defaults to https://{account}.blob.core.windows.net',
+        ),
+      sas_token: z
+        .string()
+        .min(1)
+        .optional()
+        .describe('A shared access signature used to authenticate writes'),
+      account_key: z
+        .string()
+        .min(1)
+        .optional()
+        .describe('The base64 encoded storage account access key'),
+    })
+    .describe('A checkpoint syncer that uses Azure Blob Storage'),
+]);
+
 export const ValidatorAgentConfigSchema = AgentConfigSchema.extend({
   db: z
     .string()
@@ -507,49 +809,21 @@ export const ValidatorAgentConfigSchema = AgentConfigSchema.extend({
     .min(1)
     .describe('Name of the chain to validate messages on'),
   validator: AgentSignerSchema.describe('The validator attestation signer'),
-  checkpointSyncer: z.discriminatedUnion('type', [
-    z
-      .object({
-        type: z.literal('localStorage'),
-        path: z.string().min(1).describe('Path to the local storage location'),
-      })
-      .describe('A local checkpoint syncer'),
-    z
-      .object({
-        type: z.literal('s3'),
-        bucket: z.string().min(1),
-        region: z.string().min(1),
-        folder: z
-          .string()
-          .min(1)
-          .optional()
-          .describe(
-            'The folder/key-prefix to use, defaults to the root of the bucket',
-          ),
-      })
-      .describe('A checkpoint syncer that uses S3'),
-    z
-      .object({
-        type: z.literal('gcs'),
-        bucket: z.string().min(1),
-        folder: z
-          .string()
-          .min(1)
-          .optional()
-          .describe('The folder to use, defaults to the root of the bucket'),
-        service_account_key: z
-          .string()
-          .min(1)
-          .optional()
-          .describe('The path to GCS service account key file'),
-        user_secrets: z
-          .string()
-          .min(1)
-          .optional()
-          .describe('The path to GCS user secret file'),
-      })
-      .describe('A checkpoint syncer that uses Google Cloud Storage'),
-  ]),
+  checkpointSyncer: CheckpointSyncerSchema.optional().describe(
+    'The checkpoint syncer, required unless checkpointSyncers is set',
+  ),
+  checkpointSyncers: z
+    .array(CheckpointSyncerSchema)
+    .min(1)
+    .optional()
+    .describe(
+      'Redundant checkpoint syncers that all receive checkpoints, the first one is the primary. Takes precedence over checkpointSyncer',
+    ),
+  checkpointSyncerWriteQuorum: ZUint.min(1)
+    .optional()
+    .describe(
+      'How many of the checkpointSyncers must accept a write for it to succeed, defaults to 1',
+    ),
   interval: ZUint.optional().describe(
     'How long to wait between checking for new checkpoints in seconds.',
   ),
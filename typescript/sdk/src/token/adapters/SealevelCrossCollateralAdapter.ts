@@ -18,7 +18,10 @@ import {
   SealevelOverheadIgpAdapter,
 } from '../../gas/adapters/SealevelIgpAdapter.js';
 
-import { SealevelInstructionWrapper } from '../../utils/sealevelSerialization.js';
+import {
+  SealevelInstructionWrapper,
+  decodeSealevelSerializableAccountMetas,
+} from '../../utils/sealevelSerialization.js';
 import type {
   IHypCrossCollateralAdapter,
   TransferRemoteToParams,
@@ -33,15 +36,14 @@ import {
   SealevelCCInstructionKind,
   SealevelCCTransferRemoteToInstruction,
   SealevelCCTransferRemoteToSchema,
+  SealevelCCTransferRemoteToWithHooksInstruction,
+  SealevelCCTransferRemoteToWithHooksSchema,
   encodeTokenMessage,
 } from './serialization.js';
 
 // CC program discriminator (8 bytes of 2s)
 const CC_DISCRIMINATOR = Buffer.from([2, 2, 2, 2, 2, 2, 2, 2]);
 
-// Each SerializableAccountMeta is: pubkey (32) + is_signer (1) + is_writable (1) = 34 bytes
-const SERIALIZABLE_ACCOUNT_META_SIZE = 34;
-
 export class SealevelHypCrossCollateralAdapter
   extends SealevelHypCollateralAdapter
   implements IHypCrossCollateralAdapter<Transaction>
@@ -285,24 +287,10 @@ export class SealevelHypCrossCollateralAdapter
       `HandleLocalAccountMetas simulation returned no data. The target program may not implement HandleLocalAccountMetas.\nLogs: ${logs?.join('\n')}`,
     );
 
-    const data = Buffer.from(base64Data, 'base64');
-    // First 4 bytes are the Vec length (little-endian u32)
-    const count = data.readUInt32LE(0);
-    const expectedLength = 4 + count * SERIALIZABLE_ACCOUNT_META_SIZE;
-    assert(
-      data.length >= expectedLength,
-      `HandleLocalAccountMetas returned truncated data: expected ${expectedLength} bytes, got ${data.length}`,
+    return decodeSealevelSerializableAccountMetas(
+      Buffer.from(base64Data, 'base64'),
+      'HandleLocalAccountMetas',
     );
-    const accountMetas: Array<AccountMeta> = [];
-    for (let i = 0; i < count; i++) {
-      const offset = 4 + i * SERIALIZABLE_ACCOUNT_META_SIZE;
-      const pubkey = new PublicKey(data.subarray(offset, offset + 32));
-      const isSigner = data[offset + 32] !== 0;
-      const isWritable = data[offset + 33] !== 0;
-      accountMetas.push({ pubkey, isSigner, isWritable });
-    }
-
-    return accountMetas;
   }
 
   // Should match rust/sealevel/programs/hyperlane-sealevel-token-cross-collateral/src/processor.rs transfer_remote_to_local
@@ -386,7 +374,9 @@ export class SealevelHypCrossCollateralAdapter
       },
       // 9.   [writeable] The escrow PDA account.
       { pubkey: this.deriveEscrowAccount(), isSigner: false, isWritable: true },
-      // 10+. Target HandleLocal accounts (from simulation).
+      // 10.  [writeable] OPTIONAL - The transfer controls PDA account.
+      ...(await this.getTransferControlsKeys()),
+      // 10/11+. Target HandleLocal accounts (from simulation).
       // Skip index 0 (cc_dispatch_authority) — transfer_remote_to_local
       // prepends it to the CPI, so remaining_accounts starts at index 1.
       ...handleLocalAccountMetas.slice(1),
@@ -445,15 +435,27 @@ export class SealevelHypCrossCollateralAdapter
         randomWallet: randomWallet.publicKey,
         igp: await this.getIgpKeys(),
       });
+      // N+4. [writeable] OPTIONAL - The transfer controls PDA account.
+      const transferControlsKeys = await this.getTransferControlsKeys();
+      // N+5..M. The Mailbox's hooks and the accounts required to invoke them.
+      const { keys: hookKeys, hookAccountCounts } =
+        await this.getMailboxHookKeys({
+          mailbox,
+          destination,
+          recipient: recipientBytes,
+          amount: BigInt(amount),
+          payer: sender,
+        });
 
       return this.createTransferRemoteToTx({
-        keys,
+        keys: [...keys, ...transferControlsKeys, ...hookKeys],
         destination,
         recipientBytes,
         amount: BigInt(amount),
         targetRouterBytes,
         sender,
         randomWallet,
+        hookAccountCounts,
       });
     }
   }
@@ -466,6 +468,7 @@ export class SealevelHypCrossCollateralAdapter
     targetRouterBytes,
     sender,
     randomWallet,
+    hookAccountCounts = [],
   }: {
     keys: Array<AccountMeta>;
     destination: number;
@@ -474,17 +477,32 @@ export class SealevelHypCrossCollateralAdapter
     targetRouterBytes: Uint8Array;
     sender: PublicKey;
     randomWallet?: Keypair;
+    hookAccountCounts?: number[];
   }): Promise<Transaction> {
-    const value = new SealevelInstructionWrapper({
-      instruction: SealevelCCInstructionKind.TransferRemoteTo,
-      data: new SealevelCCTransferRemoteToInstruction({
-        destination_domain: destination,
-        recipient: recipientBytes,
-        amount_or_id: amount,
-        target_router: targetRouterBytes,
-      }),
+    const transfer = new SealevelCCTransferRemoteToInstruction({
+      destination_domain: destination,
+      recipient: recipientBytes,
+      amount_or_id: amount,
+      target_router: targetRouterBytes,
     });
-    const serializedData = serialize(SealevelCCTransferRemoteToSchema, value);
+    const serializedData = hookAccountCounts.length
+      ? serialize(
+          SealevelCCTransferRemoteToWithHooksSchema,
+          new SealevelInstructionWrapper({
+            instruction: SealevelCCInstructionKind.TransferRemoteToWithHooks,
+            data: new SealevelCCTransferRemoteToWithHooksInstruction({
+              transfer,
+              hook_account_counts: hookAccountCounts,
+            }),
+          }),
+        )
+      : serialize(
+          SealevelCCTransferRemoteToSchema,
+          new SealevelInstructionWrapper({
+            instruction: SealevelCCInstructionKind.TransferRemoteTo,
+            data: transfer,
+          }),
+        );
 
     const transferInstruction = new TransactionInstruction({
       keys,
@@ -11,10 +11,12 @@ import {
   AccountMeta,
   ComputeBudgetProgram,
   Keypair,
+  Message,
   PublicKey,
   SystemProgram,
   Transaction,
   TransactionInstruction,
+  VersionedTransaction,
 } from '@solana/web3.js';
 import { deserializeUnchecked, serialize } from 'borsh';
 
@@ -24,10 +26,14 @@ import {
   LazyAsync,
   addressToBytes,
   assert,
+  convertDecimalsToIntegerString,
+  ensure0x,
   eqAddress,
+  formatMessage,
   isNullish,
   median,
   padBytesToLength,
+  strip0x,
 } from '@hyperlane-xyz/utils';
 
 import { BaseSealevelAdapter } from '../../app/MultiProtocolApp.js';
@@ -39,11 +45,18 @@ import {
   SealevelOverheadIgpAdapter,
 } from '../../gas/adapters/SealevelIgpAdapter.js';
 import { SealevelInterchainGasPaymasterType } from '../../gas/adapters/serialization.js';
+import {
+  POST_DISPATCH_ACCOUNT_METAS_DISCRIMINATOR,
+  SealevelPostDispatchInstruction,
+  SealevelPostDispatchInstructionSchema,
+  decodeSealevelMailboxOutbox,
+} from '../../mailbox/serialization.js';
 import type { MultiProviderAdapter } from '../../providers/MultiProviderAdapter.js';
 import { ChainName } from '../../types.js';
 import {
   SealevelAccountDataWrapper,
   SealevelInstructionWrapper,
+  decodeSealevelSerializableAccountMetas,
 } from '../../utils/sealevelSerialization.js';
 import { getLegacySPLTokenMetadata } from '../sealevel/metadata.js';
 import { TokenMetadata } from '../types.js';
@@ -62,10 +75,16 @@ import {
   SealevelHyperlaneTokenDataSchema,
   SealevelTransferRemoteInstruction,
   SealevelTransferRemoteSchema,
+  SealevelTransferRemoteWithHooksInstruction,
+  SealevelTransferRemoteWithHooksSchema,
+  encodeTokenMessage,
 } from './serialization.js';
 
 const NON_EXISTENT_ACCOUNT_ERROR = 'could not find account';
 
+// Should match VERSION in rust/sealevel/programs/mailbox/src/instruction.rs
+const MAILBOX_VERSION = 3;
+
 /**
  * The compute limit to set for the transfer remote instruction.
  * This is typically around ~160k, but can be higher depending on
@@ -465,22 +484,52 @@ export abstract class SealevelHypTokenAdapter
     const fromWalletPubKey = new PublicKey(fromAccountOwner);
     const mailboxPubKey = new PublicKey(this.addresses.mailbox);
 
-    const keys = await this.getTransferInstructionKeyList({
-      sender: fromWalletPubKey,
-      mailbox: mailboxPubKey,
-      randomWallet: randomWallet.publicKey,
-      igp: await this.getIgpKeys(),
-    });
+    const keys = [
+      ...(await this.getTransferInstructionKeyList({
+        sender: fromWalletPubKey,
+        mailbox: mailboxPubKey,
+        randomWallet: randomWallet.publicKey,
+        igp: await this.getIgpKeys(),
+      })),
+      // N+1. [writeable] OPTIONAL - The transfer controls PDA account.
+      ...(await this.getTransferControlsKeys()),
+    ];
 
-    const value = new SealevelInstructionWrapper({
-      instruction: SealevelHypTokenInstruction.TransferRemote,
-      data: new SealevelTransferRemoteInstruction({
-        destination_domain: destination,
-        recipient: padBytesToLength(addressToBytes(recipient), 32),
-        amount_or_id: BigInt(weiAmountOrId),
-      }),
+    const recipientBytes = padBytesToLength(addressToBytes(recipient), 32);
+    // N+2..M. The Mailbox's hooks and the accounts required to invoke them.
+    const { keys: hookKeys, hookAccountCounts } =
+      await this.getMailboxHookKeys({
+        mailbox: mailboxPubKey,
+        destination,
+        recipient: recipientBytes,
+        amount: BigInt(weiAmountOrId),
+        payer: fromWalletPubKey,
+      });
+    keys.push(...hookKeys);
+
+    const transfer = new SealevelTransferRemoteInstruction({
+      destination_domain: destination,
+      recipient: recipientBytes,
+      amount_or_id: BigInt(weiAmountOrId),
     });
-    const serializedData = serialize(SealevelTransferRemoteSchema, value);
+    const serializedData = hookAccountCounts.length
+      ? serialize(
+          SealevelTransferRemoteWithHooksSchema,
+          new SealevelInstructionWrapper({
+            instruction: SealevelHypTokenInstruction.TransferRemoteWithHooks,
+            data: new SealevelTransferRemoteWithHooksInstruction({
+              transfer,
+              hook_account_counts: hookAccountCounts,
+            }),
+          }),
+        )
+      : serialize(
+          SealevelTransferRemoteSchema,
+          new SealevelInstructionWrapper({
+            instruction: SealevelHypTokenInstruction.TransferRemote,
+            data: transfer,
+          }),
+        );
 
     const transferRemoteInstruction = new TransactionInstruction({
       keys,
@@ -522,6 +571,116 @@ export abstract class SealevelHypTokenAdapter
     return tx;
   }
 
+  // Simulates the PostDispatchAccountMetas instruction of each of the Mailbox's
+  // hooks to discover the accounts needed for the Mailbox to invoke them.
+  //
+  // Should match outbox_dispatch in rust/sealevel/programs/mailbox/src/processor.rs:
+  // the required hook, then the default hook, each followed by the number of
+  // accounts given by its entry of the returned `hookAccountCounts`.
+  async getMailboxHookKeys({
+    mailbox,
+    destination,
+    recipient,
+    amount,
+    payer,
+  }: {
+    mailbox: PublicKey;
+    destination: Domain;
+    recipient: Uint8Array;
+    amount: bigint;
+    payer: PublicKey;
+  }): Promise<{ keys: Array<AccountMeta>; hookAccountCounts: number[] }> {
+    const connection = this.getProvider();
+    const outboxPda = this.deriveMailboxOutboxAccount(mailbox);
+    const outboxAccountInfo = await connection.getAccountInfo(outboxPda);
+    assert(outboxAccountInfo, `No account info found for ${outboxPda}`);
+    const outbox = decodeSealevelMailboxOutbox(outboxAccountInfo.data);
+    const hooks = [outbox.requiredHook, outbox.defaultHook].filter(
+      (hook): hook is PublicKey => !!hook,
+    );
+    if (!hooks.length) return { keys: [], hookAccountCounts: [] };
+
+    // The hooks are given the message that is about to be dispatched.
+    const tokenData = await this.getTokenAccountData();
+    const remoteAmount = convertDecimalsToIntegerString(
+      tokenData.decimals,
+      tokenData.remote_decimals,
+      amount.toString(),
+    );
+    const dispatchedMessage = formatMessage(
+      MAILBOX_VERSION,
+      outbox.count,
+      outbox.localDomain,
+      ensure0x(this.warpProgramPubKey.toBuffer().toString('hex')),
+      destination,
+      ensure0x((await this.getRouterAddress(destination)).toString('hex')),
+      ensure0x(
+        encodeTokenMessage(recipient, BigInt(remoteAmount)).toString('hex'),
+      ),
+    );
+    const postDispatchData = serialize(
+      SealevelPostDispatchInstructionSchema,
+      new SealevelPostDispatchInstruction({
+        metadata: new Uint8Array(0),
+        message: Buffer.from(strip0x(dispatchedMessage), 'hex'),
+      }),
+    );
+
+    const keys: Array<AccountMeta> = [];
+    const hookAccountCounts: number[] = [];
+    for (const hook of hooks) {
+      const instruction = new TransactionInstruction({
+        keys: [
+          // 0. [] The hook's account metas PDA.
+          {
+            pubkey: this.derivePda(
+              ['hyperlane_hook', '-', 'post_dispatch', '-', 'account_metas'],
+              hook,
+            ),
+            isSigner: false,
+            isWritable: false,
+          },
+        ],
+        programId: hook,
+        data: Buffer.concat([
+          POST_DISPATCH_ACCOUNT_METAS_DISCRIMINATOR,
+          Buffer.from(postDispatchData),
+        ]),
+      });
+
+      const message = Message.compile({
+        recentBlockhash: PublicKey.default.toBase58(),
+        instructions: [instruction],
+        payerKey: payer,
+      });
+      const tx = new VersionedTransaction(message);
+      const simulationResponse = await connection.simulateTransaction(tx, {
+        replaceRecentBlockhash: true,
+        sigVerify: false,
+      });
+      const { returnData, err, logs } = simulationResponse.value;
+      assert(
+        !err,
+        `PostDispatchAccountMetas simulation failed for hook ${hook}: ${JSON.stringify(err)}\nLogs: ${logs?.join('\n')}`,
+      );
+      const base64Data = returnData?.data?.[0];
+      const hookAccountMetas = base64Data
+        ? decodeSealevelSerializableAccountMetas(
+            Buffer.from(base64Data, 'base64'),
+            'PostDispatchAccountMetas',
+          )
+        : [];
+
+      keys.push(
+        { pubkey: hook, isSigner: false, isWritable: false },
+        ...hookAccountMetas,
+      );
+      hookAccountCounts.push(hookAccountMetas.length);
+    }
+
+    return { keys, hookAccountCounts };
+  }
+
   async getIgpKeys(): Promise<IgpPaymentKeys | undefined> {
     const tokenData = await this.getTokenAccountData();
     const igpAdapter = this.getIgpAdapter(tokenData);
@@ -658,6 +817,25 @@ export abstract class SealevelHypTokenAdapter
     );
   }
 
+  // Transfers must pass the transfer controls PDA once the owner has configured
+  // a transfer control, which is when the account is created.
+  async getTransferControlsKeys(): Promise<Array<AccountMeta>> {
+    const transferControls = this.deriveTransferControlsAccount();
+    const accountInfo =
+      await this.getProvider().getAccountInfo(transferControls);
+    return accountInfo
+      ? [{ pubkey: transferControls, isSigner: false, isWritable: true }]
+      : [];
+  }
+
+  // Should match hyperlane_token_transfer_controls_pda_seeds in rust/sealevel/libraries/hyperlane-sealevel-token/src/processor.rs
+  deriveTransferControlsAccount(): PublicKey {
+    return super.derivePda(
+      ['hyperlane_token', '-', 'transfer_controls'],
+      this.warpProgramPubKey,
+    );
+  }
+
   // Should match https://github.com/hyperlane-xyz/hyperlane-monorepo/blob/4b3537470eff0139163a2a7aa1d19fc708a992c6/rust/sealevel/programs/hyperlane-sealevel-token/src/plugin.rs#L43-L51
   deriveAtaPayerAccount(): PublicKey {
     return super.derivePda(
@@ -123,6 +123,9 @@ export enum SealevelHypTokenInstruction {
   SetInterchainSecurityModule = 5,
   SetInterchainGasPaymaster = 6,
   TransferOwnership = 7,
+  SetPaused = 8,
+  SetRateLimits = 9,
+  TransferRemoteWithHooks = 10,
 }
 
 /**
@@ -143,6 +146,10 @@ export const SealevelHypTokenInstructionName: Record<
   [SealevelHypTokenInstruction.SetInterchainGasPaymaster]:
     'SetInterchainGasPaymaster',
   [SealevelHypTokenInstruction.TransferOwnership]: 'TransferOwnership',
+  [SealevelHypTokenInstruction.SetPaused]: 'SetPaused',
+  [SealevelHypTokenInstruction.SetRateLimits]: 'SetRateLimits',
+  [SealevelHypTokenInstruction.TransferRemoteWithHooks]:
+    'TransferRemoteWithHooks',
 };
 
 export class SealevelTransferRemoteInstruction {
@@ -180,6 +187,49 @@ export const SealevelTransferRemoteSchema = new Map<any, any>([
   ],
 ]);
 
+export class SealevelTransferRemoteWithHooksInstruction {
+  transfer!: SealevelTransferRemoteInstruction;
+  /// The number of accounts following each hook's program account.
+  hook_account_counts!: number[];
+  constructor(public readonly fields: any) {
+    Object.assign(this, fields);
+  }
+}
+
+export const SealevelTransferRemoteWithHooksSchema = new Map<any, any>([
+  [
+    SealevelInstructionWrapper,
+    {
+      kind: 'struct',
+      fields: [
+        ['instruction', 'u8'],
+        ['data', SealevelTransferRemoteWithHooksInstruction],
+      ],
+    },
+  ],
+  [
+    SealevelTransferRemoteWithHooksInstruction,
+    {
+      kind: 'struct',
+      fields: [
+        ['transfer', SealevelTransferRemoteInstruction],
+        ['hook_account_counts', ['u8']],
+      ],
+    },
+  ],
+  [
+    SealevelTransferRemoteInstruction,
+    {
+      kind: 'struct',
+      fields: [
+        ['destination_domain', 'u32'],
+        ['recipient', [32]],
+        ['amount_or_id', 'u256'],
+      ],
+    },
+  ],
+]);
+
 // ============================================================================
 // Cross-Collateral Instruction Schemas
 // ============================================================================
@@ -189,6 +239,7 @@ export enum SealevelCCInstructionKind {
   TransferRemoteTo = 1,
   HandleLocal = 2,
   HandleLocalAccountMetas = 3,
+  TransferRemoteToWithHooks = 4,
 }
 
 export class SealevelCCTransferRemoteToInstruction {
@@ -226,6 +277,50 @@ export const SealevelCCTransferRemoteToSchema = new Map<any, any>([
   ],
 ]);
 
+export class SealevelCCTransferRemoteToWithHooksInstruction {
+  transfer!: SealevelCCTransferRemoteToInstruction;
+  /// The number of accounts following each hook's program account.
+  hook_account_counts!: number[];
+  constructor(public readonly fields: any) {
+    Object.assign(this, fields);
+  }
+}
+
+export const SealevelCCTransferRemoteToWithHooksSchema = new Map<any, any>([
+  [
+    SealevelInstructionWrapper,
+    {
+      kind: 'struct',
+      fields: [
+        ['instruction', 'u8'],
+        ['data', SealevelCCTransferRemoteToWithHooksInstruction],
+      ],
+    },
+  ],
+  [
+    SealevelCCTransferRemoteToWithHooksInstruction,
+    {
+      kind: 'struct',
+      fields: [
+        ['transfer', SealevelCCTransferRemoteToInstruction],
+        ['hook_account_counts', ['u8']],
+      ],
+    },
+  ],
+  [
+    SealevelCCTransferRemoteToInstruction,
+    {
+      kind: 'struct',
+      fields: [
+        ['destination_domain', 'u32'],
+        ['recipient', [32]],
+        ['amount_or_id', 'u256'],
+        ['target_router', [32]],
+      ],
+    },
+  ],
+]);
+
 export class SealevelCCHandleLocalInstruction {
   sender_program_id!: Uint8Array;
   message!: Uint8Array;
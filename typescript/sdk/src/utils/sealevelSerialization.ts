@@ -1,3 +1,7 @@
+import { AccountMeta, PublicKey } from '@solana/web3.js';
+
+import { assert } from '@hyperlane-xyz/utils';
+
 export class SealevelInstructionWrapper<Instr> {
   instruction!: number;
   data!: Instr;
@@ -49,3 +53,34 @@ export function getSealevelSimulationReturnDataSchema<T>(DataClass: T) {
     ],
   };
 }
+
+// Each SerializableAccountMeta is: pubkey (32) + is_signer (1) + is_writable (1) = 34 bytes
+export const SERIALIZABLE_ACCOUNT_META_SIZE = 34;
+
+/**
+ * Decodes the Vec<SerializableAccountMeta> returned by simulating an
+ * account metas instruction, such as a recipient's HandleLocalAccountMetas
+ * or a hook's PostDispatchAccountMetas.
+ * Matches: rust/sealevel/libraries/serializable-account-meta/src/lib.rs
+ */
+export function decodeSealevelSerializableAccountMetas(
+  data: Buffer,
+  instructionName: string,
+): Array<AccountMeta> {
+  // First 4 bytes are the Vec length (little-endian u32)
+  const count = data.readUInt32LE(0);
+  const expectedLength = 4 + count * SERIALIZABLE_ACCOUNT_META_SIZE;
+  assert(
+    data.length >= expectedLength,
+    `${instructionName} returned truncated data: expected ${expectedLength} bytes, got ${data.length}`,
+  );
+  const accountMetas: Array<AccountMeta> = [];
+  for (let i = 0; i < count; i++) {
+    const offset = 4 + i * SERIALIZABLE_ACCOUNT_META_SIZE;
+    const pubkey = new PublicKey(data.subarray(offset, offset + 32));
+    const isSigner = data[offset + 32] !== 0;
+    const isWritable = data[offset + 33] !== 0;
+    accountMetas.push({ pubkey, isSigner, isWritable });
+  }
+  return accountMetas;
+}